@@ -0,0 +1,168 @@
+//! This module implements the `ArrayIterator` object, returned by `Array.prototype.values()`,
+//! `keys()`, `entries()` and `[Symbol.iterator]()`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-array-iterator-objects
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::create_iter_result_object,
+        object::ObjectData,
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+        Array,
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+/// What an `ArrayIterator` produces for each element of the array.
+#[derive(Debug, Clone, Copy, Finalize, PartialEq)]
+pub enum ArrayIterationKind {
+    Key,
+    Value,
+    KeyAndValue,
+}
+
+unsafe impl Trace for ArrayIterationKind {
+    unsafe_empty_trace!();
+}
+
+/// The internal state of an `ArrayIterator` object.
+///
+/// The iterated object is set to `undefined` once the iterator is done.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ArrayIterator {
+    array: Value,
+    next_index: usize,
+    kind: ArrayIterationKind,
+}
+
+impl ArrayIterator {
+    /// Creates a new iterator over the given array-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createarrayiterator
+    pub(crate) fn create_array_iterator(
+        interpreter: &Interpreter,
+        array: Value,
+        kind: ArrayIterationKind,
+    ) -> Value {
+        Value::new_object_from_prototype(
+            interpreter.iterator_prototypes().array_iterator().clone(),
+            ObjectData::ArrayIterator(Self {
+                array,
+                next_index: 0,
+                kind,
+            }),
+        )
+    }
+
+    /// `%ArrayIteratorPrototype%.next()`
+    ///
+    /// Gets the next result of the iterator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        let (array, index, kind) = match this.as_object_mut() {
+            Some(mut object) => match object.data {
+                ObjectData::ArrayIterator(ref mut iterator) => {
+                    let index = iterator.next_index;
+                    iterator.next_index += 1;
+                    (iterator.array.clone(), index, iterator.kind)
+                }
+                _ => return interpreter.throw_type_error("'this' is not an ArrayIterator"),
+            },
+            None => return interpreter.throw_type_error("'this' is not an ArrayIterator"),
+        };
+
+        if array.is_undefined() {
+            return Ok(create_iter_result_object(
+                interpreter,
+                Value::undefined(),
+                true,
+            ));
+        }
+
+        // Typed arrays have their own length, which isn't read from a property.
+        let typed_array = array
+            .as_object()
+            .and_then(|object| object.as_typed_array().cloned());
+        let length = match typed_array {
+            Some(ref typed_array) if typed_array.is_out_of_bounds() => {
+                return interpreter.throw_type_error("the typed array is out of bounds")
+            }
+            Some(ref typed_array) => typed_array.length(),
+            None => {
+                let length = interpreter.get_field(&array, "length")?;
+                interpreter.to_length(&length)?
+            }
+        };
+        if index >= length {
+            if let Some(mut object) = this.as_object_mut() {
+                if let ObjectData::ArrayIterator(ref mut iterator) = object.data {
+                    iterator.array = Value::undefined();
+                }
+            }
+            return Ok(create_iter_result_object(
+                interpreter,
+                Value::undefined(),
+                true,
+            ));
+        }
+
+        let value = match kind {
+            ArrayIterationKind::Key => Value::from(index),
+            ArrayIterationKind::Value => interpreter.get_field(&array, index.to_string())?,
+            ArrayIterationKind::KeyAndValue => {
+                let value = interpreter.get_field(&array, index.to_string())?;
+                let entry = Array::new_array(interpreter)?;
+                Array::add_to_array_object(&entry, &[Value::from(index), value])?
+            }
+        };
+        Ok(create_iter_result_object(interpreter, value, false))
+    }
+
+    /// Creates the `%ArrayIteratorPrototype%` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%-object
+    pub(crate) fn create_prototype(
+        interpreter: &mut Interpreter,
+        iterator_prototype: Value,
+    ) -> Value {
+        let _timer = BoaProfiler::global().start_event("ArrayIterator", "init");
+        let global = interpreter.global();
+        let prototype = Value::new_object(Some(global));
+        if let Some(mut object) = prototype.as_object_mut() {
+            object.set_prototype(iterator_prototype);
+        }
+
+        make_builtin_fn(Self::next, "next", &prototype, 0, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from("Array Iterator"),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        prototype
+    }
+}
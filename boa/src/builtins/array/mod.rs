@@ -9,9 +9,11 @@
 //! [spec]: https://tc39.es/ecma262/#sec-array-objects
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array
 
+pub mod array_iterator;
 #[cfg(test)]
 mod tests;
 
+use self::array_iterator::{ArrayIterationKind, ArrayIterator};
 use super::function::{make_builtin_fn, make_constructor_fn};
 use crate::{
     builtins::{
@@ -57,7 +59,11 @@ impl Array {
                 .borrow()
                 .get_field(PROTOTYPE),
         );
-        array.borrow().set_field("length", Value::from(0));
+        let length = Property::data_descriptor(
+            Value::from(0),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        array.set_property("length".to_string(), length);
         Ok(array)
     }
 
@@ -165,12 +171,10 @@ impl Array {
     pub(crate) fn is_array(
         _this: &Value,
         args: &[Value],
-        _interpreter: &mut Interpreter,
+        interpreter: &mut Interpreter,
     ) -> ResultValue {
-        match args.get(0).and_then(|x| x.as_object()) {
-            Some(object) => Ok(Value::from(object.is_array())),
-            None => Ok(Value::from(false)),
-        }
+        let value = args.get(0).cloned().unwrap_or_default();
+        Ok(Value::from(interpreter.is_array(&value)?))
     }
 
     /// `Array.prototype.concat(...arguments)`
@@ -1112,6 +1116,65 @@ impl Array {
         Ok(accumulator)
     }
 
+    /// `Array.prototype.values( )`
+    ///
+    /// The values method returns an iterable that iterates over the values in the array.
+    /// It is also the `[Symbol.iterator]()` method of arrays.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/values
+    pub(crate) fn values(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        let array = interpreter.to_object(this)?;
+        Ok(ArrayIterator::create_array_iterator(
+            interpreter,
+            array,
+            ArrayIterationKind::Value,
+        ))
+    }
+
+    /// `Array.prototype.keys( )`
+    ///
+    /// The keys method returns an iterable that iterates over the indexes in the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/keys
+    pub(crate) fn keys(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        let array = interpreter.to_object(this)?;
+        Ok(ArrayIterator::create_array_iterator(
+            interpreter,
+            array,
+            ArrayIterationKind::Key,
+        ))
+    }
+
+    /// `Array.prototype.entries( )`
+    ///
+    /// The entries method returns an iterable that iterates over `[index, value]` pairs for
+    /// each element of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/entries
+    pub(crate) fn entries(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        let array = interpreter.to_object(this)?;
+        Ok(ArrayIterator::create_array_iterator(
+            interpreter,
+            array,
+            ArrayIterationKind::KeyAndValue,
+        ))
+    }
+
     /// Initialise the `Array` object on the global object.
     #[inline]
     pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
@@ -1158,6 +1221,14 @@ impl Array {
             2,
             interpreter,
         );
+        make_builtin_fn(Self::values, "values", &prototype, 0, interpreter);
+        make_builtin_fn(Self::keys, "keys", &prototype, 0, interpreter);
+        make_builtin_fn(Self::entries, "entries", &prototype, 0, interpreter);
+
+        // `Array.prototype[Symbol.iterator]` is the same function object as `values`.
+        let symbol_iterator = interpreter.well_known_symbols().iterator_symbol();
+        let values = prototype.get_field("values");
+        prototype.set_field(symbol_iterator, values);
 
         let array = make_constructor_fn(
             Self::NAME,
@@ -1049,3 +1049,31 @@ fn call_array_constructor_with_one_argument() {
     let result = forward(&mut engine, "one.length");
     assert_eq!(result, "1");
 }
+
+#[test]
+fn array_iterators() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var values = ["a", "b"].values();
+        var keys = ["a", "b"].keys();
+        var entries = ["a", "b"].entries();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "values.next().value"), "\"a\"");
+    assert_eq!(forward(&mut engine, "values.next().value"), "\"b\"");
+    assert_eq!(forward(&mut engine, "values.next().done"), "true");
+    assert_eq!(forward(&mut engine, "keys.next().value"), "0");
+    assert_eq!(
+        forward(&mut engine, "entries.next().value.join()"),
+        "\"0,a\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[][Symbol.iterator] === [].values"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "values[Symbol.iterator]() === values"),
+        "true"
+    );
+}
@@ -0,0 +1,388 @@
+//! This module implements the global `ArrayBuffer` object.
+//!
+//! The `ArrayBuffer` object is used to represent a generic, fixed-length raw binary data buffer.
+//! Its contents can't be manipulated directly, but through typed arrays or `DataView` objects
+//! that view the buffer in a specific format.
+//!
+//! The bytes of a buffer are stored in a `Vec<u8>`, which Rust code can access without copying
+//! with `Object::as_array_buffer`, or hand over to JavaScript with
+//! `Interpreter::create_array_buffer`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
+
+use super::function::{make_builtin_fn, make_builtin_getter, make_constructor_fn};
+use crate::{
+    builtins::{
+        object::{ObjectData, PROTOTYPE},
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal state of an `ArrayBuffer` object.
+///
+/// Resizable buffers are created with a maximum byte length, up to which they can be resized
+/// with `ArrayBuffer.prototype.resize()`. The bytes are only allocated when they are in the
+/// buffer.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ArrayBufferData {
+    /// The bytes of the buffer.
+    data: Vec<u8>,
+    /// The maximum byte length of the buffer, if it is resizable.
+    max_byte_length: Option<usize>,
+}
+
+impl ArrayBufferData {
+    /// Creates the data of a fixed-length buffer, which owns the given bytes.
+    pub fn new(data: Vec<u8>) -> Self {
+        Self {
+            data,
+            max_byte_length: None,
+        }
+    }
+
+    /// Gets the bytes of the buffer.
+    #[inline]
+    pub fn bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Gets the bytes of the buffer, to modify them.
+    #[inline]
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// Gets the number of bytes in the buffer.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Gets the number of bytes the buffer can be resized to, which is its byte length if it
+    /// isn't resizable.
+    #[inline]
+    pub fn max_byte_length(&self) -> usize {
+        self.max_byte_length.unwrap_or_else(|| self.data.len())
+    }
+
+    /// Checks if the buffer can be resized.
+    #[inline]
+    pub fn is_resizable(&self) -> bool {
+        self.max_byte_length.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ArrayBuffer;
+
+impl ArrayBuffer {
+    pub(crate) const NAME: &'static str = "ArrayBuffer";
+
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Helper function to access the data of `this`, throwing a `TypeError` if it isn't an
+    /// `ArrayBuffer`.
+    fn with_buffer<R, F>(this: &Value, interpreter: &mut Interpreter, f: F) -> Result<R, Value>
+    where
+        F: FnOnce(&mut ArrayBufferData) -> R,
+    {
+        if let Some(mut object) = this.as_object_mut() {
+            if let Some(buffer) = object.as_array_buffer_mut() {
+                return Ok(f(buffer));
+            }
+        }
+        Err(interpreter.construct_type_error("'this' is not an ArrayBuffer"))
+    }
+
+    /// Allocates `byte_length` zeroed bytes, throwing a `RangeError` if they can't be allocated.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createbytedatablock
+    pub(crate) fn allocate(
+        byte_length: usize,
+        interpreter: &mut Interpreter,
+    ) -> Result<Vec<u8>, Value> {
+        let mut data = Vec::new();
+        if data.try_reserve_exact(byte_length).is_err() {
+            return Err(interpreter.construct_range_error(format!(
+                "cannot allocate a buffer of {} bytes",
+                byte_length
+            )));
+        }
+        data.resize(byte_length, 0);
+        Ok(data)
+    }
+
+    /// Creates a new `ArrayBuffer` object with the given data.
+    pub(crate) fn create(data: ArrayBufferData, interpreter: &Interpreter) -> Value {
+        let prototype = interpreter
+            .global()
+            .get_field(Self::NAME)
+            .get_field(PROTOTYPE);
+        Value::new_object_from_prototype(prototype, ObjectData::ArrayBuffer(data))
+    }
+
+    /// Create a new array buffer
+    ///
+    /// The buffer is resizable if the `maxByteLength` option is given.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer-length
+    pub(crate) fn make_array_buffer(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let byte_length = ctx.to_index(&args.get(0).cloned().unwrap_or_default())?;
+
+        let options = args.get(1).cloned().unwrap_or_default();
+        let max_byte_length = if options.is_object() {
+            let max_byte_length = ctx.get_field(&options, "maxByteLength")?;
+            if max_byte_length.is_undefined() {
+                None
+            } else {
+                Some(ctx.to_index(&max_byte_length)?)
+            }
+        } else {
+            None
+        };
+        if max_byte_length.map_or(false, |max_byte_length| byte_length > max_byte_length) {
+            return ctx.throw_range_error("ArrayBuffer: byteLength exceeds maxByteLength");
+        }
+
+        let data = Self::allocate(byte_length, ctx)?;
+        this.set_data(ObjectData::ArrayBuffer(ArrayBufferData {
+            data,
+            max_byte_length,
+        }));
+        Ok(this.clone())
+    }
+
+    /// `ArrayBuffer.isView( arg )`
+    ///
+    /// This function checks if the argument is one of the views of array buffers, a typed array
+    /// or a `DataView` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.isview
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView
+    pub(crate) fn is_view(_: &Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
+        let is_view = args.get(0).map_or(false, |arg| {
+            arg.as_object().map_or(false, |object| {
+                object.is_typed_array() || object.is_data_view()
+            })
+        });
+        Ok(is_view.into())
+    }
+
+    /// `get ArrayBuffer.prototype.byteLength`
+    ///
+    /// This accessor returns the number of bytes in the buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength
+    pub(crate) fn byte_length(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Ok(Self::with_buffer(this, ctx, |buffer| buffer.byte_length())?.into())
+    }
+
+    /// `get ArrayBuffer.prototype.maxByteLength`
+    ///
+    /// This accessor returns the number of bytes the buffer can be resized to, which is its byte
+    /// length if it isn't resizable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.maxbytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/maxByteLength
+    pub(crate) fn max_byte_length(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Ok(Self::with_buffer(this, ctx, |buffer| buffer.max_byte_length())?.into())
+    }
+
+    /// `get ArrayBuffer.prototype.resizable`
+    ///
+    /// This accessor checks if the buffer can be resized.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.resizable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resizable
+    pub(crate) fn resizable(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Ok(Self::with_buffer(this, ctx, |buffer| buffer.is_resizable())?.into())
+    }
+
+    /// `ArrayBuffer.prototype.resize( newLength )`
+    ///
+    /// This method changes the byte length of a resizable buffer. New bytes are zeroed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.resize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resize
+    pub(crate) fn resize(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let max_byte_length = Self::with_buffer(this, ctx, |buffer| buffer.max_byte_length)?;
+        let max_byte_length = match max_byte_length {
+            Some(max_byte_length) => max_byte_length,
+            None => {
+                return ctx
+                    .throw_type_error("ArrayBuffer.prototype.resize: the buffer is not resizable")
+            }
+        };
+
+        let new_length = ctx.to_index(&args.get(0).cloned().unwrap_or_default())?;
+        if new_length > max_byte_length {
+            return ctx.throw_range_error(
+                "ArrayBuffer.prototype.resize: the new length exceeds maxByteLength",
+            );
+        }
+
+        let resized = Self::with_buffer(this, ctx, |buffer| {
+            let additional = new_length.saturating_sub(buffer.data.len());
+            if buffer.data.try_reserve_exact(additional).is_err() {
+                return false;
+            }
+            buffer.data.resize(new_length, 0);
+            true
+        })?;
+        if !resized {
+            return ctx
+                .throw_range_error(format!("cannot allocate a buffer of {} bytes", new_length));
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `ArrayBuffer.prototype.slice( start, end )`
+    ///
+    /// This method returns a new fixed-length buffer with a copy of the bytes of the buffer from
+    /// `start` to `end`, which can be negative to count from the end of the buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice
+    pub(crate) fn slice(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let length = Self::with_buffer(this, ctx, |buffer| buffer.byte_length())?;
+        let first = relative_index(args.get(0), length, 0, ctx)?;
+        let last = relative_index(args.get(1), length, length, ctx)?;
+        let new_length = last.saturating_sub(first);
+
+        let mut data = Self::allocate(new_length, ctx)?;
+
+        // The conversions of the arguments can resize the buffer, in which case only the bytes
+        // still in it are copied.
+        Self::with_buffer(this, ctx, |buffer| {
+            let bytes = buffer.bytes();
+            if first < bytes.len() {
+                let count = new_length.min(bytes.len() - first);
+                data[..count].copy_from_slice(&bytes[first..first + count]);
+            }
+        })?;
+
+        Ok(Self::create(ArrayBufferData::new(data), ctx))
+    }
+
+    /// Initialise the `ArrayBuffer` object on the global object.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let prototype = Value::new_object(Some(global));
+
+        make_builtin_getter(Self::byte_length, "byteLength", &prototype, interpreter);
+        make_builtin_getter(
+            Self::max_byte_length,
+            "maxByteLength",
+            &prototype,
+            interpreter,
+        );
+        make_builtin_getter(Self::resizable, "resizable", &prototype, interpreter);
+        make_builtin_fn(Self::resize, "resize", &prototype, 1, interpreter);
+        make_builtin_fn(Self::slice, "slice", &prototype, 2, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from(Self::NAME),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        let array_buffer = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_array_buffer,
+            global,
+            prototype,
+            true,
+            false,
+        );
+
+        make_builtin_fn(Self::is_view, "isView", &array_buffer, 1, interpreter);
+
+        (Self::NAME, array_buffer)
+    }
+}
+
+/// Converts the argument to an index in a sequence of `length` elements, counting from the end of
+/// the sequence if it is negative, and clamping it to the bounds of the sequence.
+///
+/// `default` is used if the argument is missing or `undefined`. This is the conversion of the
+/// `start` and `end` arguments of the `slice()` methods.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
+pub(crate) fn relative_index(
+    argument: Option<&Value>,
+    length: usize,
+    default: usize,
+    interpreter: &mut Interpreter,
+) -> Result<usize, Value> {
+    let argument = match argument {
+        Some(argument) if !argument.is_undefined() => argument,
+        _ => return Ok(default),
+    };
+    let relative = interpreter.to_integer(argument)?;
+    let index = if relative < 0.0 {
+        (length as f64 + relative).max(0.0)
+    } else {
+        relative.min(length as f64)
+    };
+    Ok(index as usize)
+}
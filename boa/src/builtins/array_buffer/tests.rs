@@ -0,0 +1,129 @@
+use crate::{exec::Interpreter, forward, forward_val, realm::Realm};
+
+#[test]
+fn construct() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var empty = new ArrayBuffer();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "buffer.byteLength"), "8");
+    assert_eq!(forward(&mut engine, "buffer.maxByteLength"), "8");
+    assert_eq!(forward(&mut engine, "buffer.resizable"), "false");
+    assert_eq!(forward(&mut engine, "empty.byteLength"), "0");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new ArrayBuffer(-1) } catch (e) { e.toString() }"
+        ),
+        "\"RangeError: Integer index must be >= 0\""
+    );
+}
+
+#[test]
+fn slice() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var bytes = new Uint8Array([1, 2, 3, 4, 5]);
+        var middle = new Uint8Array(bytes.buffer.slice(1, -1));
+        var tail = new Uint8Array(bytes.buffer.slice(-2));
+        var empty = bytes.buffer.slice(4, 1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "middle.join()"), "\"2,3,4\"");
+    assert_eq!(forward(&mut engine, "tail.join()"), "\"4,5\"");
+    assert_eq!(forward(&mut engine, "empty.byteLength"), "0");
+
+    // The slice is a copy.
+    forward(&mut engine, "middle[0] = 9");
+    assert_eq!(forward(&mut engine, "bytes[1]"), "2");
+}
+
+#[test]
+fn resize() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var buffer = new ArrayBuffer(2, { maxByteLength: 8 });
+        var tracking = new Uint8Array(buffer);
+        var fixed = new Uint8Array(buffer, 0, 2);
+        tracking[1] = 7;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "buffer.resizable"), "true");
+    assert_eq!(forward(&mut engine, "buffer.maxByteLength"), "8");
+
+    forward(&mut engine, "buffer.resize(6)");
+    assert_eq!(forward(&mut engine, "buffer.byteLength"), "6");
+    assert_eq!(forward(&mut engine, "tracking.length"), "6");
+    assert_eq!(forward(&mut engine, "tracking.join()"), "\"0,7,0,0,0,0\"");
+
+    forward(&mut engine, "buffer.resize(1)");
+    assert_eq!(forward(&mut engine, "tracking.length"), "1");
+    assert_eq!(forward(&mut engine, "fixed.length"), "0");
+    assert_eq!(forward(&mut engine, "fixed[0]"), "undefined");
+
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { buffer.resize(9) } catch (e) { e.toString() }"
+        ),
+        "\"RangeError: ArrayBuffer.prototype.resize: the new length exceeds maxByteLength\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new ArrayBuffer(1).resize(0) } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: ArrayBuffer.prototype.resize: the buffer is not resizable\""
+    );
+}
+
+#[test]
+fn is_view() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var buffer = new ArrayBuffer(4);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "ArrayBuffer.isView(buffer)"), "false");
+    assert_eq!(
+        forward(&mut engine, "ArrayBuffer.isView(new Int8Array(buffer))"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "ArrayBuffer.isView(new DataView(buffer))"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "ArrayBuffer.isView([])"), "false");
+}
+
+#[test]
+fn zero_copy_access() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let buffer = engine.create_array_buffer(vec![1, 2, 3, 4]);
+    engine.global().set_field("pixels", buffer.clone());
+
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array(pixels).join()"),
+        "\"1,2,3,4\""
+    );
+    forward(&mut engine, "new Uint8Array(pixels, 2)[1] = 40");
+
+    // The script writes to the bytes Rust handed over, and Rust reads them back in place.
+    {
+        let object = buffer.as_object().unwrap();
+        let data = object.as_array_buffer().unwrap();
+        assert_eq!(data.bytes(), &[1, 2, 3, 40]);
+    }
+
+    let array = forward_val(&mut engine, "new Uint16Array(pixels, 2, 1)").unwrap();
+    let array = array.as_object().unwrap();
+    let array = array.as_typed_array().unwrap();
+    assert_eq!(array.with_bytes(|bytes| bytes.to_vec()), vec![3, 40]);
+}
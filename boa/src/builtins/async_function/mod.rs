@@ -0,0 +1,156 @@
+//! This module implements the execution of async functions (`async function`), async arrow
+//! functions and async methods.
+//!
+//! The body of an async function is suspended by `await` expressions until the awaited promise is
+//! settled. It runs like the body of a generator: every `await` suspends the body, and the
+//! reactions added to the awaited promise resume it from the job queue.
+//!
+//! Calling an async function returns a promise, which is settled with the completion of the body.
+//! A rejection of that promise without any handler is reported to the promise rejection tracker
+//! of the host.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-function-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::{
+        function::make_closure,
+        generator::{Generator, ResumeKind},
+        value::{ResultValue, Value},
+        Promise,
+    },
+    environment::lexical_environment::LexicalEnvironment,
+    exec::Interpreter,
+    syntax::ast::node::StatementList,
+    BoaProfiler,
+};
+use gc::{Finalize, Gc, Trace};
+use std::rc::Rc;
+
+/// The state of a running async function, shared by the reactions that resume its body.
+#[derive(Debug, Trace, Finalize)]
+struct AsyncFunctionState {
+    /// The generator running the body.
+    generator: Value,
+    /// The promise returned by the call of the async function.
+    promise: Value,
+}
+
+/// The execution of the body of async functions.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncFunction;
+
+impl AsyncFunction {
+    /// Starts running the body of an async function in the given environment, where its
+    /// parameters have already been bound, until it completes or awaits.
+    ///
+    /// The promise is settled with the completion of the body.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-async-functions-abstract-operations-async-function-start
+    pub(crate) fn start(
+        promise: &Value,
+        environment: LexicalEnvironment,
+        body: Rc<StatementList>,
+        interpreter: &mut Interpreter,
+    ) {
+        let _timer = BoaProfiler::global().start_event("AsyncFunction::start", "exec");
+        // The body is run by a generator that is never exposed, each `await` is a `yield` of
+        // the awaited value.
+        let generator = Generator::create(Value::null(), environment, body);
+
+        let state = Gc::new(AsyncFunctionState {
+            generator,
+            promise: promise.clone(),
+        });
+
+        Self::resume(&state, ResumeKind::Next(Value::undefined()), interpreter);
+    }
+
+    /// Resumes the body of the async function, and runs it until it completes or awaits.
+    ///
+    /// When the body awaits a value, reactions that resume it again are added to the promise
+    /// resolved with that value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await
+    fn resume(state: &Gc<AsyncFunctionState>, kind: ResumeKind, interpreter: &mut Interpreter) {
+        let mut kind = kind;
+        loop {
+            let awaited = match Generator::resume_body(&state.generator, kind, interpreter) {
+                Ok((value, true)) => {
+                    return Promise::resolve_promise(&state.promise, value, interpreter)
+                }
+                Err(error) => return Promise::reject_promise(&state.promise, error, interpreter),
+                Ok((value, false)) => value,
+            };
+
+            let constructor = interpreter.global().get_field(Promise::NAME);
+            match Promise::promise_resolve(&constructor, awaited, interpreter) {
+                Ok(awaited) => {
+                    let on_fulfilled =
+                        make_closure(Self::await_fulfilled, state.clone(), 1, interpreter);
+                    let on_rejected =
+                        make_closure(Self::await_rejected, state.clone(), 1, interpreter);
+                    Promise::perform_promise_then(
+                        &awaited,
+                        on_fulfilled,
+                        on_rejected,
+                        None,
+                        interpreter,
+                    );
+                    return;
+                }
+                // The error is thrown by the `await` expression.
+                Err(error) => kind = ResumeKind::Throw(error),
+            }
+        }
+    }
+
+    /// The fulfillment handler of an awaited promise, the `await` expression evaluates to the
+    /// value of the promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await-fulfilled
+    fn await_fulfilled(
+        _: &Value,
+        args: &[Value],
+        state: &Gc<AsyncFunctionState>,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(state, ResumeKind::Next(value), ctx);
+        Ok(Value::undefined())
+    }
+
+    /// The rejection handler of an awaited promise, the `await` expression throws the reason of
+    /// the promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await-rejected
+    fn await_rejected(
+        _: &Value,
+        args: &[Value],
+        state: &Gc<AsyncFunctionState>,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        Self::resume(state, ResumeKind::Throw(reason), ctx);
+        Ok(Value::undefined())
+    }
+}
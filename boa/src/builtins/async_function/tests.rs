@@ -0,0 +1,283 @@
+use crate::{
+    builtins::promise::PromiseRejectionOperation, exec::Interpreter, forward, realm::Realm,
+};
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn returns_promise() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        async function f() {
+            return 1;
+        }
+        var result;
+        var promise = f();
+        promise.then(value => { result = value; });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "promise instanceof Promise"), "true");
+    assert_eq!(forward(&mut engine, "result"), "undefined");
+
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "1");
+}
+
+#[test]
+fn await_suspends() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var order = [];
+        async function f() {
+            order.push("start");
+            let value = await Promise.resolve("awaited");
+            order.push(value);
+            order.push(await "plain");
+        }
+        f();
+        order.push("sync");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "order.join()"), "\"start,sync\"");
+
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "order.join()"),
+        "\"start,sync,awaited,plain\""
+    );
+}
+
+#[test]
+fn await_thenable() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var result;
+        async function f() {
+            result = await { then(resolve) { resolve(42); } };
+        }
+        f();
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "42");
+}
+
+#[test]
+fn await_rejection() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var caught;
+        var result;
+        async function f() {
+            try {
+                await Promise.reject("failed");
+            } catch (e) {
+                caught = e;
+            }
+            return "recovered";
+        }
+        f().then(value => { result = value; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "caught"), "\"failed\"");
+    assert_eq!(forward(&mut engine, "result"), "\"recovered\"");
+}
+
+#[test]
+fn throw_rejects() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var reasons = [];
+        async function before() {
+            throw "before";
+        }
+        async function after() {
+            await null;
+            throw "after";
+        }
+        async function parameters({ a }) {}
+        before().catch(reason => reasons.push(reason));
+        after().catch(reason => reasons.push(reason));
+        parameters().catch(reason => reasons.push(reason instanceof TypeError));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "reasons.join()"),
+        "\"before,true,after\""
+    );
+}
+
+#[test]
+fn loops() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var result;
+        async function sum(values) {
+            let total = 0;
+            for (let value of values) {
+                total += await value;
+            }
+            return total;
+        }
+        sum([1, Promise.resolve(2), 3]).then(value => { result = value; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "6");
+}
+
+#[test]
+fn nested_calls() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var result;
+        async function inner(value) {
+            await null;
+            return value * 2;
+        }
+        async function outer() {
+            let a = await inner(1);
+            let b = await inner(a);
+            return [a, b];
+        }
+        outer().then(value => { result = value; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "[ 2, 4 ]");
+}
+
+#[test]
+fn arrow_functions() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var results = [];
+        var double = async x => (await x) * 2;
+        var add = async (a, b) => {
+            return await a + await b;
+        };
+        var obj = {
+            value: "this",
+            method() {
+                return async () => this.value;
+            }
+        };
+        double(Promise.resolve(2)).then(value => results.push(value));
+        add(1, 2).then(value => results.push(value));
+        obj.method()().then(value => results.push(value));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "results.join()"), "\"this,4,3\"");
+}
+
+#[test]
+fn methods() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var results = [];
+        var obj = {
+            async load() {
+                return await "object";
+            }
+        };
+        class Loader {
+            constructor(value) {
+                this.value = value;
+            }
+            async load() {
+                return await this.value;
+            }
+            static async create() {
+                return new Loader("class");
+            }
+        }
+        obj.load().then(value => results.push(value));
+        Loader.create().then(loader => loader.load()).then(value => results.push(value));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "results.join()"), "\"object,class\"");
+}
+
+#[test]
+fn expressions() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var result;
+        var f = async function named() {
+            return await "expression";
+        };
+        f().then(value => { result = value; });
+        var async = 1;
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"expression\"");
+    assert_eq!(forward(&mut engine, "f.name"), "\"named\"");
+    assert_eq!(forward(&mut engine, "async + 1"), "2");
+}
+
+#[test]
+fn not_constructable() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        async function f() {}
+        var error;
+        try {
+            new f();
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "error instanceof TypeError"), "true");
+    assert_eq!(forward(&mut engine, "f.prototype"), "undefined");
+}
+
+#[test]
+fn uncaught_rejection() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+
+    let operations = Rc::new(RefCell::new(Vec::new()));
+    let tracked = operations.clone();
+    engine.set_promise_rejection_tracker(move |_, reason, operation, _| {
+        tracked.borrow_mut().push((reason.to_string(), operation));
+    });
+
+    let init = r#"
+        async function fail() {
+            await null;
+            throw "uncaught";
+        }
+        async function handled() {
+            try {
+                await fail();
+            } catch (e) {}
+        }
+        fail();
+        handled();
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        *operations.borrow(),
+        vec![(
+            "\"uncaught\"".to_string(),
+            PromiseRejectionOperation::Reject
+        )]
+    );
+}
@@ -48,6 +48,41 @@ impl BigInt {
         self.0.to_f64().unwrap_or(std::f64::INFINITY)
     }
 
+    /// Converts the BigInt to an i128 type.
+    ///
+    /// Returns `None` if the BigInt doesn't fit.
+    #[inline]
+    pub fn to_i128(&self) -> Option<i128> {
+        self.0.to_i128()
+    }
+
+    /// Converts the BigInt to an u128 type.
+    ///
+    /// Returns `None` if the BigInt doesn't fit.
+    #[inline]
+    pub fn to_u128(&self) -> Option<u128> {
+        self.0.to_u128()
+    }
+
+    /// Converts the BigInt to an u64 type, wrapping it modulo `2^64` like
+    /// `BigInt.asUintN(64, bigint)` does.
+    ///
+    /// The result is the two's complement representation of the BigInt, so it is also the
+    /// wrapped value of `BigInt.asIntN(64, bigint)` when cast to an i64.
+    #[inline]
+    pub fn to_u64_wrapping(&self) -> u64 {
+        let fill = if self.0.sign() == num_bigint::Sign::Minus {
+            0xff
+        } else {
+            0
+        };
+        let mut bytes = [fill; 8];
+        for (byte, value) in bytes.iter_mut().zip(self.0.to_signed_bytes_le()) {
+            *byte = value;
+        }
+        u64::from_le_bytes(bytes)
+    }
+
     #[inline]
     pub(crate) fn from_str(string: &str) -> Option<Self> {
         match num_bigint::BigInt::from_str(string) {
@@ -69,6 +104,18 @@ impl From<i32> for BigInt {
     }
 }
 
+impl From<i128> for BigInt {
+    fn from(n: i128) -> BigInt {
+        BigInt(num_bigint::BigInt::from(n))
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(n: u128) -> BigInt {
+        BigInt(num_bigint::BigInt::from(n))
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TryFromF64Error;
 
@@ -0,0 +1,443 @@
+//! This module implements the global `DataView` object.
+//!
+//! A `DataView` reads and writes numbers of any of the element types of typed arrays at any byte
+//! offset of an `ArrayBuffer`, in little-endian or big-endian order, which is big-endian by
+//! default.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-dataview-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
+
+use super::function::{
+    make_builtin_fn, make_builtin_getter, make_constructor_fn, NativeFunctionData,
+};
+use crate::{
+    builtins::{
+        array_buffer::ArrayBufferData,
+        object::ObjectData,
+        property::{Attribute, Property},
+        typed_array::TypedArrayKind,
+        value::{ResultValue, Value},
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal state of a `DataView` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DataViewData {
+    /// The `ArrayBuffer` object the view reads and writes.
+    buffer: Value,
+    /// The offset of the view in the buffer, in bytes.
+    byte_offset: usize,
+    /// The number of bytes of the view, or `None` if the view tracks the length of a resizable
+    /// buffer.
+    byte_length: Option<usize>,
+}
+
+impl DataViewData {
+    /// Gets the `ArrayBuffer` object the view reads and writes.
+    #[inline]
+    pub fn buffer(&self) -> &Value {
+        &self.buffer
+    }
+
+    /// Gets the offset of the view in the buffer, in bytes.
+    #[inline]
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// Gets the current byte length of the buffer.
+    fn buffer_byte_length(&self) -> usize {
+        self.buffer
+            .as_object()
+            .and_then(|buffer| buffer.as_array_buffer().map(ArrayBufferData::byte_length))
+            .unwrap_or(0)
+    }
+
+    /// Checks if the view is out of the bounds of its buffer, because the buffer was shrunk.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isviewoutofbounds
+    pub fn is_out_of_bounds(&self) -> bool {
+        let end = self.byte_offset + self.byte_length.unwrap_or(0);
+        end > self.buffer_byte_length()
+    }
+
+    /// Gets the number of bytes of the view, which is 0 if the view is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewbytelength
+    pub fn byte_length(&self) -> usize {
+        if self.is_out_of_bounds() {
+            return 0;
+        }
+        match self.byte_length {
+            Some(byte_length) => byte_length,
+            None => self.buffer_byte_length() - self.byte_offset,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DataView;
+
+impl DataView {
+    pub(crate) const NAME: &'static str = "DataView";
+
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Helper function to get the data of `this`, throwing a `TypeError` if it isn't a
+    /// `DataView`.
+    fn this_data_view(this: &Value, interpreter: &mut Interpreter) -> Result<DataViewData, Value> {
+        let view = this
+            .as_object()
+            .and_then(|object| object.as_data_view().cloned());
+        view.ok_or_else(|| interpreter.construct_type_error("'this' is not a DataView"))
+    }
+
+    /// Create a new data view
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview-buffer-byteoffset-bytelength
+    pub(crate) fn make_data_view(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let buffer = args.get(0).cloned().unwrap_or_default();
+        if !buffer
+            .as_object()
+            .map_or(false, |object| object.is_array_buffer())
+        {
+            return ctx.throw_type_error("DataView: buffer must be an ArrayBuffer");
+        }
+
+        let byte_offset = ctx.to_index(&args.get(1).cloned().unwrap_or_default())?;
+        let (buffer_byte_length, resizable) = buffer
+            .as_object()
+            .and_then(|object| {
+                object
+                    .as_array_buffer()
+                    .map(|buffer| (buffer.byte_length(), buffer.is_resizable()))
+            })
+            .expect("the buffer is an ArrayBuffer");
+        if byte_offset > buffer_byte_length {
+            return ctx
+                .throw_range_error("DataView: start offset is outside the bounds of the buffer");
+        }
+
+        let byte_length = match args.get(2) {
+            Some(byte_length) if !byte_length.is_undefined() => {
+                let byte_length = ctx.to_index(byte_length)?;
+                if byte_offset + byte_length > buffer_byte_length {
+                    return ctx.throw_range_error("DataView: invalid data view length");
+                }
+                Some(byte_length)
+            }
+            _ if resizable => None,
+            _ => Some(buffer_byte_length - byte_offset),
+        };
+
+        this.set_data(ObjectData::DataView(DataViewData {
+            buffer,
+            byte_offset,
+            byte_length,
+        }));
+        Ok(this.clone())
+    }
+
+    /// `get DataView.prototype.buffer`
+    ///
+    /// This accessor returns the `ArrayBuffer` the view reads and writes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.buffer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer
+    pub(crate) fn buffer(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Ok(Self::this_data_view(this, ctx)?.buffer.clone())
+    }
+
+    /// `get DataView.prototype.byteLength`
+    ///
+    /// This accessor returns the number of bytes of the view.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength
+    pub(crate) fn byte_length(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let view = Self::this_data_view(this, ctx)?;
+        if view.is_out_of_bounds() {
+            return ctx.throw_type_error("the DataView is out of bounds");
+        }
+        Ok(view.byte_length().into())
+    }
+
+    /// `get DataView.prototype.byteOffset`
+    ///
+    /// This accessor returns the offset of the view in the buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.byteoffset
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteOffset
+    pub(crate) fn byte_offset(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let view = Self::this_data_view(this, ctx)?;
+        if view.is_out_of_bounds() {
+            return ctx.throw_type_error("the DataView is out of bounds");
+        }
+        Ok(view.byte_offset.into())
+    }
+
+    /// Helper function to get the range of bytes of a value of type `kind` at the byte offset
+    /// `request_index` of the view, throwing if it isn't within the view.
+    fn view_range(
+        view: &DataViewData,
+        request_index: &Value,
+        kind: TypedArrayKind,
+        ctx: &mut Interpreter,
+    ) -> Result<std::ops::Range<usize>, Value> {
+        let index = ctx.to_index(request_index)?;
+        if view.is_out_of_bounds() {
+            return Err(ctx.construct_type_error("the DataView is out of bounds"));
+        }
+        let size = kind.element_size();
+        if index
+            .checked_add(size)
+            .map_or(true, |end| end > view.byte_length())
+        {
+            return Err(ctx.construct_range_error("offset is outside the bounds of the DataView"));
+        }
+        let start = view.byte_offset + index;
+        Ok(start..start + size)
+    }
+
+    /// Reads a value of type `kind` from the view, for the `get` methods.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewvalue
+    fn get_view_value(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+        kind: TypedArrayKind,
+    ) -> ResultValue {
+        let view = Self::this_data_view(this, ctx)?;
+        let request_index = args.get(0).cloned().unwrap_or_default();
+        let little_endian = args.get(1).map_or(false, Value::to_boolean);
+        let range = Self::view_range(&view, &request_index, kind, ctx)?;
+
+        let buffer = view
+            .buffer
+            .as_object()
+            .expect("the buffer of a DataView is an object");
+        let buffer = buffer
+            .as_array_buffer()
+            .expect("the buffer of a DataView is an ArrayBuffer");
+        Ok(kind.get_value(&buffer.bytes()[range], little_endian))
+    }
+
+    /// Writes a value of type `kind` to the view, for the `set` methods.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setviewvalue
+    fn set_view_value(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+        kind: TypedArrayKind,
+    ) -> ResultValue {
+        let view = Self::this_data_view(this, ctx)?;
+        let request_index = ctx.to_index(&args.get(0).cloned().unwrap_or_default())?;
+        let value = kind.to_element_value(&args.get(1).cloned().unwrap_or_default(), ctx)?;
+        let little_endian = args.get(2).map_or(false, Value::to_boolean);
+        let range = Self::view_range(&view, &Value::from(request_index), kind, ctx)?;
+
+        let mut buffer = view
+            .buffer
+            .as_object_mut()
+            .expect("the buffer of a DataView is an object");
+        let buffer = buffer
+            .as_array_buffer_mut()
+            .expect("the buffer of a DataView is an ArrayBuffer");
+        kind.set_value(&mut buffer.bytes_mut()[range], &value, little_endian);
+        Ok(Value::undefined())
+    }
+
+    /// The `get` and `set` methods of `DataView.prototype`, with their names and lengths.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-the-dataview-prototype-object
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView#instance_methods
+    const METHODS: [(&'static str, NativeFunctionData, usize); 20] = [
+        (
+            "getInt8",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::Int8),
+            1,
+        ),
+        (
+            "getUint8",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::Uint8),
+            1,
+        ),
+        (
+            "getInt16",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::Int16),
+            1,
+        ),
+        (
+            "getUint16",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::Uint16),
+            1,
+        ),
+        (
+            "getInt32",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::Int32),
+            1,
+        ),
+        (
+            "getUint32",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::Uint32),
+            1,
+        ),
+        (
+            "getBigInt64",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::BigInt64),
+            1,
+        ),
+        (
+            "getBigUint64",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::BigUint64),
+            1,
+        ),
+        (
+            "getFloat32",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::Float32),
+            1,
+        ),
+        (
+            "getFloat64",
+            |this, args, ctx| Self::get_view_value(this, args, ctx, TypedArrayKind::Float64),
+            1,
+        ),
+        (
+            "setInt8",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::Int8),
+            2,
+        ),
+        (
+            "setUint8",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::Uint8),
+            2,
+        ),
+        (
+            "setInt16",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::Int16),
+            2,
+        ),
+        (
+            "setUint16",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::Uint16),
+            2,
+        ),
+        (
+            "setInt32",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::Int32),
+            2,
+        ),
+        (
+            "setUint32",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::Uint32),
+            2,
+        ),
+        (
+            "setBigInt64",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::BigInt64),
+            2,
+        ),
+        (
+            "setBigUint64",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::BigUint64),
+            2,
+        ),
+        (
+            "setFloat32",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::Float32),
+            2,
+        ),
+        (
+            "setFloat64",
+            |this, args, ctx| Self::set_view_value(this, args, ctx, TypedArrayKind::Float64),
+            2,
+        ),
+    ];
+
+    /// Initialise the `DataView` object on the global object.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let prototype = Value::new_object(Some(global));
+
+        make_builtin_getter(Self::buffer, "buffer", &prototype, interpreter);
+        make_builtin_getter(Self::byte_length, "byteLength", &prototype, interpreter);
+        make_builtin_getter(Self::byte_offset, "byteOffset", &prototype, interpreter);
+        for &(name, function, length) in Self::METHODS.iter() {
+            make_builtin_fn(function, name, &prototype, length, interpreter);
+        }
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from(Self::NAME),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        let data_view = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_data_view,
+            global,
+            prototype,
+            true,
+            false,
+        );
+
+        (Self::NAME, data_view)
+    }
+}
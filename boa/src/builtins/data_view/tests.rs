@@ -0,0 +1,113 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn construct() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var view = new DataView(buffer, 2);
+        var short = new DataView(buffer, 1, 3);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "view.buffer === buffer"), "true");
+    assert_eq!(forward(&mut engine, "view.byteOffset"), "2");
+    assert_eq!(forward(&mut engine, "view.byteLength"), "6");
+    assert_eq!(forward(&mut engine, "short.byteLength"), "3");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new DataView({}) } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: DataView: buffer must be an ArrayBuffer\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new DataView(buffer, 4, 5) } catch (e) { e.toString() }"
+        ),
+        "\"RangeError: DataView: invalid data view length\""
+    );
+}
+
+#[test]
+fn endianness() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(8));
+        var bytes = new Uint8Array(view.buffer);
+        view.setUint16(0, 0x1234);
+        view.setUint16(2, 0x1234, true);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "bytes.join()"),
+        "\"18,52,52,18,0,0,0,0\""
+    );
+    assert_eq!(forward(&mut engine, "view.getUint16(0)"), "4660");
+    assert_eq!(forward(&mut engine, "view.getUint16(0, true)"), "13330");
+    assert_eq!(forward(&mut engine, "view.getInt8(0)"), "18");
+
+    forward(&mut engine, "view.setFloat64(0, -1.5, true)");
+    assert_eq!(forward(&mut engine, "view.getFloat64(0, true)"), "-1.5");
+    assert_eq!(forward(&mut engine, "bytes[7]"), "191");
+}
+
+#[test]
+fn bigint_values() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(8));
+        view.setBigInt64(0, -1n);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "view.getBigInt64(0)"), "-1n");
+    assert_eq!(
+        forward(&mut engine, "view.getBigUint64(0)"),
+        "18446744073709551615n"
+    );
+    assert_eq!(forward(&mut engine, "view.getInt32(4)"), "-1");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { view.setBigInt64(0, 1) } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: cannot convert the number 1 to a BigInt\""
+    );
+    assert_eq!(forward(&mut engine, "view.getBigInt64(0)"), "-1n");
+}
+
+#[test]
+fn out_of_bounds() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+        var view = new DataView(buffer, 1, 2);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { view.getUint16(1) } catch (e) { e.toString() }"
+        ),
+        "\"RangeError: offset is outside the bounds of the DataView\""
+    );
+    forward(&mut engine, "buffer.resize(2)");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { view.byteLength } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: the DataView is out of bounds\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { view.getUint8(0) } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: the DataView is out of bounds\""
+    );
+}
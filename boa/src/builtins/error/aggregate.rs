@@ -0,0 +1,104 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! Represents several errors wrapped in a single error, like the rejection reasons of all the
+//! promises passed to `Promise.any()`.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        function::make_constructor_fn,
+        iterable::iterable_to_list,
+        object::ObjectData,
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+        Array,
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+
+/// JavaScript `AggregateError` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl AggregateError {
+    /// The name of the object.
+    pub(crate) const NAME: &'static str = "AggregateError";
+
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// Create a new error object.
+    pub(crate) fn make_error(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        if let Some(message) = args.get(1) {
+            if !message.is_undefined() {
+                this.set_field("message", ctx.to_string(message)?);
+            }
+        }
+
+        let errors = iterable_to_list(ctx, &args.get(0).cloned().unwrap_or_default())?;
+        let errors_array = Array::new_array(ctx)?;
+        Array::construct_array(&errors_array, &errors)?;
+        this.set_property(
+            "errors",
+            Property::data_descriptor(
+                errors_array,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+
+        // This value is used by console.log and other routines to match Object type
+        // to its Javascript Identifier (global constructor method name)
+        this.set_data(ObjectData::Error);
+        Err(this.clone())
+    }
+
+    /// `Error.prototype.toString()`
+    ///
+    /// The toString() method returns a string representing the specified Error object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-error.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/toString
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_string(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let name = ctx.to_string(&this.get_field("name"))?;
+        let message = ctx.to_string(&this.get_field("message"))?;
+        Ok(Value::from(format!("{}: {}", name, message)))
+    }
+
+    /// Initialise the global object with the `AggregateError` object.
+    #[inline]
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let prototype = Value::new_object(Some(global));
+        prototype.set_field("name", Self::NAME);
+        prototype.set_field("message", "");
+
+        make_builtin_fn(Self::to_string, "toString", &prototype, 0, interpreter);
+
+        let aggregate_error_object = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_error,
+            global,
+            prototype,
+            true,
+            true,
+        );
+
+        (Self::NAME, aggregate_error_object)
+    }
+}
@@ -20,6 +20,7 @@ use crate::{
     profiler::BoaProfiler,
 };
 
+pub(crate) mod aggregate;
 pub(crate) mod range;
 pub(crate) mod reference;
 pub(crate) mod syntax;
@@ -27,6 +28,7 @@ pub(crate) mod r#type;
 // pub(crate) mod eval;
 // pub(crate) mod uri;
 
+pub(crate) use self::aggregate::AggregateError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
 pub(crate) use self::reference::ReferenceError;
@@ -0,0 +1,157 @@
+//! This module implements the global `eval` function.
+//!
+//! The `eval()` function evaluates JavaScript code represented as a string. When it is called
+//! directly, as `eval(...)`, the code runs in the environment of the caller. Otherwise it runs in
+//! the global environment.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-eval-x
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+
+use crate::{
+    builtins::{
+        function::Function,
+        object::Object,
+        value::{ResultValue, Value},
+    },
+    environment::{
+        function_environment_record::BindingStatus,
+        lexical_environment::{
+            new_declarative_environment, new_function_environment, LexicalEnvironment,
+        },
+    },
+    exec::{Executable, Interpreter, InterpreterState},
+    syntax::{ast::node::StatementList, lexer::Lexer, parser::Parser},
+    BoaProfiler,
+};
+use std::mem;
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `eval` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Eval;
+
+impl Eval {
+    /// The binding name of the function.
+    pub(crate) const NAME: &'static str = "eval";
+
+    /// `eval( x )`
+    ///
+    /// Calling the `eval` function any other way than `eval(...)` is an indirect eval, so the code
+    /// runs in the global environment.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-eval-x
+    pub(crate) fn eval(_: &Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        Self::perform(args.get(0), false, interpreter)
+    }
+
+    /// Evaluates the source code `x`, which is returned unchanged if it is not a string.
+    ///
+    /// A direct eval runs in the environment of the caller and inherits its strictness, an
+    /// indirect eval runs in the global environment. Strict mode code gets its own variable
+    /// environment, so that its `var` declarations don't leak out of it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn perform(
+        x: Option<&Value>,
+        direct: bool,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let source = match x {
+            Some(Value::String(ref source)) => source.clone(),
+            Some(x) => return Ok(x.clone()),
+            None => return Ok(Value::undefined()),
+        };
+
+        let inherited_strict = direct && interpreter.is_strict();
+        let body = Self::parse(&source, inherited_strict)
+            .map_err(|message| interpreter.construct_syntax_error(message))?;
+        let strict = inherited_strict || body.is_strict();
+
+        let caller_env = if direct {
+            None
+        } else {
+            let global_env = interpreter
+                .realm
+                .environment
+                .environments()
+                .last()
+                .expect("there is always a global environment")
+                .clone();
+            Some(mem::replace(
+                &mut interpreter.realm.environment,
+                LexicalEnvironment::from_scope(global_env),
+            ))
+        };
+        let eval_env = if strict {
+            // A function environment without a `this` binding holds the `var` declarations.
+            new_function_environment(
+                Value::undefined(),
+                None,
+                None,
+                BindingStatus::Lexical,
+                Value::undefined(),
+                Value::undefined(),
+            )
+        } else {
+            new_declarative_environment(None)
+        };
+        interpreter.realm.environment.push(eval_env);
+        let caller_strict = interpreter.is_strict();
+        interpreter.set_strict(strict);
+
+        let result = if body.statements().is_empty() {
+            Ok(Value::undefined())
+        } else {
+            body.run(interpreter)
+        };
+
+        interpreter.set_strict(caller_strict);
+        match caller_env {
+            Some(caller_env) => interpreter.realm.environment = caller_env,
+            None => {
+                interpreter.realm.environment.pop();
+            }
+        }
+        interpreter.set_current_state(InterpreterState::Executing);
+
+        result
+    }
+
+    /// Parses the source code of an eval, as a script.
+    fn parse(source: &str, strict: bool) -> Result<StatementList, String> {
+        let mut lexer = Lexer::new(source);
+        lexer.lex().map_err(|err| err.to_string())?;
+        let mut parser = Parser::new(&lexer.tokens);
+        parser.set_strict_mode(strict);
+        parser.parse_all().map_err(|err| err.to_string())
+    }
+
+    /// Initialise the `eval` function on the global object.
+    #[inline]
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let mut function = Object::function(
+            Function::builtin(Vec::new(), Self::eval),
+            interpreter
+                .global()
+                .get_field("Function")
+                .get_field("prototype"),
+        );
+        function.insert_field("length", Value::from(1));
+
+        (Self::NAME, Value::from(function))
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn direct_eval_sees_the_caller_scope() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let value = "global";
+        function local() {
+            let value = "local";
+            return eval("value");
+        }
+        function indirect() {
+            let value = "local";
+            let e = eval;
+            return e("value");
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "local()"), "\"local\"");
+    assert_eq!(forward(&mut engine, "indirect()"), "\"global\"");
+    assert_eq!(forward(&mut engine, "eval('1; 2')"), "2");
+    assert_eq!(forward(&mut engine, "eval('')"), "undefined");
+}
+
+#[test]
+fn var_declarations() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function sloppy() {
+            eval("var leaked = 1");
+            return leaked;
+        }
+        function strict() {
+            "use strict";
+            eval("var inner = 1");
+            try {
+                return inner;
+            } catch (e) {
+                return e.toString();
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "sloppy()"), "1");
+    assert_eq!(
+        forward(&mut engine, "strict()"),
+        "\"ReferenceError: inner is not defined\""
+    );
+}
+
+#[test]
+fn this_binding() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let obj = {
+            direct() {
+                return eval("this") === obj;
+            }
+        };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "obj.direct()"), "true");
+}
+
+#[test]
+fn non_string_argument() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    assert_eq!(forward(&mut engine, "eval(42)"), "42");
+    assert_eq!(forward(&mut engine, "eval()"), "undefined");
+    assert_eq!(forward(&mut engine, "let o = {}; eval(o) === o"), "true");
+}
+
+#[test]
+fn syntax_error() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { eval('1 +') } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+}
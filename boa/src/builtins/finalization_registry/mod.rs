@@ -0,0 +1,244 @@
+//! This module implements the global `FinalizationRegistry` object.
+//!
+//! A `FinalizationRegistry` calls a cleanup callback with a held value after an object registered
+//! with it is collected. The cleanups are enqueued as jobs, when the host runs jobs after a garbage
+//! collection.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+
+use super::function::{make_builtin_fn, make_constructor_fn};
+use crate::{
+    builtins::{
+        object::{GcObject, ObjectData, WeakGcObject},
+        property::{Attribute, Property},
+        value::{same_value, ResultValue, Value},
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// An object registered with a `FinalizationRegistry`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct RegistryCell {
+    /// The registered object.
+    target: WeakGcObject,
+    /// The value passed to the cleanup callback once the object is collected.
+    held_value: Value,
+    /// The object that can be used to unregister the object.
+    unregister_token: Option<WeakGcObject>,
+}
+
+/// The internal state of a `FinalizationRegistry` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    cleanup_callback: Value,
+    cells: Vec<RegistryCell>,
+}
+
+impl FinalizationRegistry {
+    pub(crate) const NAME: &'static str = "FinalizationRegistry";
+
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Helper function to access the state of `this`, throwing a `TypeError` if it isn't a
+    /// finalization registry.
+    fn with_registry<R, F>(this: &Value, interpreter: &mut Interpreter, f: F) -> Result<R, Value>
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        if let Some(mut object) = this.as_object_mut() {
+            if let ObjectData::FinalizationRegistry(ref mut registry) = object.data {
+                return Ok(f(registry));
+            }
+        }
+        Err(interpreter.construct_type_error("'this' is not a FinalizationRegistry"))
+    }
+
+    /// Checks if objects registered with the registry have been collected.
+    pub(crate) fn needs_cleanup(registry: &GcObject) -> bool {
+        match registry.borrow().data {
+            ObjectData::FinalizationRegistry(ref registry) => {
+                registry.cells.iter().any(|cell| !cell.target.is_alive())
+            }
+            _ => false,
+        }
+    }
+
+    /// Calls the cleanup callback of the registry with the held value of each registered object
+    /// that has been collected.
+    ///
+    /// If the callback throws, the error is returned, and the remaining collected objects are left
+    /// for the next cleanup.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    pub(crate) fn cleanup(registry: &Value, interpreter: &mut Interpreter) -> ResultValue {
+        loop {
+            let next = Self::with_registry(registry, interpreter, |registry| {
+                let index = registry
+                    .cells
+                    .iter()
+                    .position(|cell| !cell.target.is_alive())?;
+                let cell = registry.cells.remove(index);
+                Some((registry.cleanup_callback.clone(), cell.held_value.clone()))
+            })?;
+            match next {
+                Some((callback, held_value)) => {
+                    interpreter.call(&callback, &Value::undefined(), &[held_value])?;
+                }
+                None => return Ok(Value::undefined()),
+            }
+        }
+    }
+
+    /// `FinalizationRegistry.prototype.register( target, heldValue [ , unregisterToken ] )`
+    ///
+    /// This method registers the target object, so that the cleanup callback is called with the
+    /// held value once it is collected. The registration can be removed with the unregister token,
+    /// which is not kept alive by the registry either.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+    pub(crate) fn register(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::with_registry(this, ctx, |_| ())?;
+
+        let target = match args.get(0) {
+            Some(Value::Object(target)) => target,
+            _ => return ctx.throw_type_error("FinalizationRegistry: target must be an object"),
+        };
+        let held_value = args.get(1).cloned().unwrap_or_default();
+        if same_value(&args[0], &held_value) {
+            return ctx.throw_type_error(
+                "FinalizationRegistry: target and held value must not be the same",
+            );
+        }
+        let unregister_token = match args.get(2) {
+            Some(Value::Object(token)) => Some(GcObject::downgrade(token)),
+            None | Some(Value::Undefined) => None,
+            Some(_) => {
+                return ctx
+                    .throw_type_error("FinalizationRegistry: unregister token must be an object")
+            }
+        };
+
+        let cell = RegistryCell {
+            target: GcObject::downgrade(target),
+            held_value,
+            unregister_token,
+        };
+        Self::with_registry(this, ctx, |registry| registry.cells.push(cell))?;
+        Ok(Value::undefined())
+    }
+
+    /// `FinalizationRegistry.prototype.unregister( unregisterToken )`
+    ///
+    /// This method removes the registrations made with the unregister token. Returns true if there
+    /// were some, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+    pub(crate) fn unregister(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::with_registry(this, ctx, |_| ())?;
+
+        let address = match args.get(0) {
+            Some(Value::Object(token)) => token.address(),
+            _ => {
+                return ctx
+                    .throw_type_error("FinalizationRegistry: unregister token must be an object")
+            }
+        };
+
+        let removed = Self::with_registry(this, ctx, |registry| {
+            let len = registry.cells.len();
+            registry.cells.retain(|cell| {
+                !cell.unregister_token.as_ref().map_or(false, |token| {
+                    token.is_alive() && token.address() == address
+                })
+            });
+            registry.cells.len() != len
+        })?;
+        Ok(removed.into())
+    }
+
+    /// Create a new finalization registry
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    pub(crate) fn make_finalization_registry(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let cleanup_callback = args.get(0).cloned().unwrap_or_default();
+        if !cleanup_callback.is_function() {
+            return ctx.throw_type_error("FinalizationRegistry: cleanup must be callable");
+        }
+
+        this.set_data(ObjectData::FinalizationRegistry(Self {
+            cleanup_callback,
+            cells: Vec::new(),
+        }));
+        if let Value::Object(ref registry) = this {
+            ctx.add_finalization_registry(GcObject::downgrade(registry));
+        }
+
+        Ok(this.clone())
+    }
+
+    /// Initialise the `FinalizationRegistry` object on the global object.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let prototype = Value::new_object(Some(global));
+
+        make_builtin_fn(Self::register, "register", &prototype, 2, interpreter);
+        make_builtin_fn(Self::unregister, "unregister", &prototype, 1, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from(Self::NAME),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        let finalization_registry_object = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_finalization_registry,
+            global,
+            prototype,
+            true,
+            false,
+        );
+
+        (Self::NAME, finalization_registry_object)
+    }
+}
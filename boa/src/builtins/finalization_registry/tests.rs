@@ -0,0 +1,87 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn cleanup_after_collection() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let cleaned = [];
+        let registry = new FinalizationRegistry(function(heldValue) {
+            cleaned.push(heldValue);
+        });
+        let kept = {};
+        let token = {};
+        registry.register({}, "collected");
+        registry.register({}, "unregistered", token);
+        registry.register(kept, "kept");
+        let unregistered = registry.unregister(token);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "unregistered"), "true");
+    assert_eq!(forward(&mut engine, "registry.unregister(token)"), "false");
+
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "cleaned.length"), "0");
+
+    gc::force_collect();
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "cleaned.join()"), "\"collected\"");
+
+    forward(&mut engine, "kept = undefined");
+    gc::force_collect();
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "cleaned.join()"), "\"collected,kept\"");
+}
+
+#[test]
+fn invalid_arguments() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function message(code) {
+            try {
+                code();
+            } catch (e) {
+                return e.toString();
+            }
+        }
+        let registry = new FinalizationRegistry(function() {});
+        let target = {};
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(function() { new FinalizationRegistry(1); })"
+        ),
+        "\"TypeError: FinalizationRegistry: cleanup must be callable\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(function() { registry.register(1, 2); })"
+        ),
+        "\"TypeError: FinalizationRegistry: target must be an object\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(function() { registry.register(target, target); })"
+        ),
+        "\"TypeError: FinalizationRegistry: target and held value must not be the same\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(function() { registry.register(target, 1, 2); })"
+        ),
+        "\"TypeError: FinalizationRegistry: unregister token must be an object\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(function() { registry.unregister(1); })"
+        ),
+        "\"TypeError: FinalizationRegistry: unregister token must be an object\""
+    );
+}
@@ -13,31 +13,89 @@
 
 use crate::{
     builtins::{
-        object::{Object, ObjectData, PROTOTYPE},
+        async_function::AsyncFunction,
+        generator::Generator,
+        object::{Object, PROTOTYPE},
         property::{Attribute, Property, PropertyKey},
         value::{RcString, ResultValue, Value},
-        Array,
+        Array, Promise,
     },
     environment::function_environment_record::BindingStatus,
-    environment::lexical_environment::{new_function_environment, Environment},
-    exec::{Executable, Interpreter},
-    syntax::ast::node::{FormalParameter, StatementList},
+    environment::lexical_environment::{new_function_environment, Environment, LexicalEnvironment},
+    exec::{BindingKind, Executable, Interpreter, InterpreterState},
+    syntax::{
+        ast::node::{FormalParameter, StatementList},
+        lexer::Lexer,
+        parser::Parser,
+    },
     BoaProfiler,
 };
 use bitflags::bitflags;
-use gc::{unsafe_empty_trace, Finalize, Trace};
-use std::fmt::{self, Debug};
+use gc::{custom_trace, unsafe_empty_trace, Finalize, Gc, Trace};
+use std::{
+    fmt::{self, Debug},
+    mem,
+    rc::Rc,
+};
 
 /// _fn(this, arguments, ctx) -> ResultValue_ - The signature of a built-in function
 pub type NativeFunctionData = fn(&Value, &[Value], &mut Interpreter) -> ResultValue;
 
+/// _fn(this, arguments, captures, ctx) -> ResultValue_ - The signature of a built-in function that
+/// captures a value when it is created
+pub type NativeCapturingFunctionData =
+    fn(&Value, &[Value], &Value, &mut Interpreter) -> ResultValue;
+
+/// A built-in function backed by a Rust closure, along with the data it captured.
+///
+/// It is implemented for the closures given to `Function::closure`.
+pub trait ClosureFunction: Trace {
+    /// Calls the closure with its captured data.
+    fn call(&self, this: &Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue;
+}
+
+/// A closure and the data it captured, which is traced by the garbage collector.
+struct Closure<F, C> {
+    function: F,
+    captures: C,
+}
+
+impl<F, C: Trace> Finalize for Closure<F, C> {}
+
+/// `Trace` implementation for `Closure`.
+///
+/// Only the captured data is traced, the closure itself must not hold any GC'd objects, which is
+/// why they are passed to it as captured data instead.
+unsafe impl<F, C: Trace> Trace for Closure<F, C> {
+    custom_trace!(this, {
+        mark(&this.captures);
+    });
+}
+
+impl<F, C> ClosureFunction for Closure<F, C>
+where
+    F: Fn(&Value, &[Value], &C, &mut Interpreter) -> ResultValue,
+    C: Trace,
+{
+    fn call(&self, this: &Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        (self.function)(this, args, &self.captures, interpreter)
+    }
+}
+
 /// Sets the ConstructorKind
-#[derive(Debug, Copy, Clone)]
+///
+/// Constructors of derived classes (`class A extends B`) don't create their own `this` value,
+/// they receive it from the `super(...)` call instead.
+#[derive(Debug, Copy, Finalize, Clone, PartialEq)]
 pub enum ConstructorKind {
     Base,
     Derived,
 }
 
+unsafe impl Trace for ConstructorKind {
+    unsafe_empty_trace!();
+}
+
 /// Defines how this references are interpreted within the formal parameters and code body of the function.
 ///
 /// Arrow functions don't define a `this` and thus are lexical, `function`s do define a this and thus are NonLexical
@@ -53,16 +111,26 @@ unsafe impl Trace for ThisMode {
 }
 
 /// FunctionBody is specific to this interpreter, it will either be Rust code or JavaScript code (AST Node)
+///
+/// The statements of an ordinary function are reference counted, so that a function can be
+/// cheaply copied out of its object before it is called.
 #[derive(Clone, Finalize)]
 pub enum FunctionBody {
     BuiltIn(NativeFunctionData),
-    Ordinary(StatementList),
+    /// A built-in function created at run time, like the resolving functions of a promise, along
+    /// with the value it captured.
+    BuiltInCapturing(NativeCapturingFunctionData, Value),
+    /// A built-in function backed by a Rust closure, created by the host.
+    Closure(Gc<Box<dyn ClosureFunction>>),
+    Ordinary(Rc<StatementList>),
 }
 
 impl Debug for FunctionBody {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::BuiltIn(_) => write!(f, "[native]"),
+            Self::BuiltIn(_) | Self::BuiltInCapturing(..) | Self::Closure(_) => {
+                write!(f, "[native]")
+            }
             Self::Ordinary(statements) => write!(f, "{:?}", statements),
         }
     }
@@ -72,6 +140,10 @@ impl PartialEq for FunctionBody {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::BuiltIn(a), Self::BuiltIn(b)) => std::ptr::eq(a, b),
+            (Self::BuiltInCapturing(a, a_captures), Self::BuiltInCapturing(b, b_captures)) => {
+                std::ptr::eq(a, b) && a_captures.strict_equals(b_captures)
+            }
+            (Self::Closure(a), Self::Closure(b)) => Gc::ptr_eq(a, b),
             (Self::Ordinary(a), Self::Ordinary(b)) => a == b,
             (_, _) => false,
         }
@@ -82,13 +154,20 @@ impl Eq for FunctionBody {}
 
 /// `Trace` implementation for `FunctionBody`.
 ///
-/// This is indeed safe, but we need to mark this as an empty trace because neither
-// `NativeFunctionData` nor Node hold any GC'd objects, but Gc doesn't know that. So we need to
-/// signal it manually. `rust-gc` does not have a `Trace` implementation for `fn(_, _, _)`.
+/// This is indeed safe, but we need to implement it manually because neither
+/// `NativeFunctionData` nor Node hold any GC'd objects, only the captured data of capturing
+/// built-in functions and closures do, but Gc doesn't know that. `rust-gc` does not have a
+/// `Trace` implementation for `fn(_, _, _)`.
 ///
 /// <https://github.com/Manishearth/rust-gc/blob/master/gc/src/trace.rs>
 unsafe impl Trace for FunctionBody {
-    unsafe_empty_trace!();
+    custom_trace!(this, {
+        match this {
+            Self::BuiltInCapturing(_, captures) => mark(captures),
+            Self::Closure(closure) => mark(closure),
+            Self::BuiltIn(_) | Self::Ordinary(_) => {}
+        }
+    });
 }
 
 bitflags! {
@@ -96,6 +175,9 @@ bitflags! {
     struct FunctionFlags: u8 {
         const CALLABLE = 0b0000_0001;
         const CONSTRUCTABLE = 0b0000_0010;
+        const GENERATOR = 0b0000_0100;
+        const ASYNC = 0b0000_1000;
+        const STRICT = 0b0001_0000;
     }
 }
 
@@ -122,6 +204,21 @@ impl FunctionFlags {
     fn is_constructable(&self) -> bool {
         self.contains(Self::CONSTRUCTABLE)
     }
+
+    #[inline]
+    fn is_generator(&self) -> bool {
+        self.contains(Self::GENERATOR)
+    }
+
+    #[inline]
+    fn is_async(&self) -> bool {
+        self.contains(Self::ASYNC)
+    }
+
+    #[inline]
+    fn is_strict(&self) -> bool {
+        self.contains(Self::STRICT)
+    }
 }
 
 unsafe impl Trace for FunctionFlags {
@@ -141,6 +238,10 @@ pub struct Function {
     pub this_mode: ThisMode,
     // Environment, built-in functions don't need Environments
     pub environment: Option<Environment>,
+    /// Whether this is the constructor of a base or a derived class
+    pub constructor_kind: ConstructorKind,
+    /// The object this function is a method of, `super` property lookups start from its prototype
+    pub home_object: Value,
     /// Is it constructable or
     flags: FunctionFlags,
 }
@@ -157,12 +258,21 @@ impl Function {
     where
         P: Into<Box<[FormalParameter]>>,
     {
+        let mut flags = FunctionFlags::from_parameters(callable, constructable);
+        if let FunctionBody::Ordinary(ref body) = body {
+            if body.is_strict() {
+                flags |= FunctionFlags::STRICT;
+            }
+        }
+
         Self {
             body,
             environment: scope,
             params: parameter_list.into(),
             this_mode,
-            flags: FunctionFlags::from_parameters(callable, constructable),
+            constructor_kind: ConstructorKind::Base,
+            home_object: Value::undefined(),
+            flags,
         }
     }
 
@@ -181,13 +291,58 @@ impl Function {
         Self::new(
             parameter_list.into(),
             Some(scope),
-            FunctionBody::Ordinary(body),
+            FunctionBody::Ordinary(Rc::new(body)),
             this_mode,
             true,
             true,
         )
     }
 
+    /// This will create a generator function object, whose calls return a generator object
+    /// instead of running the body.
+    ///
+    /// <https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-instantiatefunctionobject>
+    pub fn generator<P>(parameter_list: P, scope: Environment, body: StatementList) -> Self
+    where
+        P: Into<Box<[FormalParameter]>>,
+    {
+        let mut function = Self::new(
+            parameter_list.into(),
+            Some(scope),
+            FunctionBody::Ordinary(Rc::new(body)),
+            ThisMode::NonLexical,
+            false,
+            true,
+        );
+        function.flags |= FunctionFlags::GENERATOR;
+        function
+    }
+
+    /// This will create an async function object, whose calls return a promise settled with the
+    /// completion of the body.
+    ///
+    /// <https://tc39.es/ecma262/#sec-async-function-definitions-InstantiateFunctionObject>
+    pub fn async_function<P>(
+        parameter_list: P,
+        scope: Environment,
+        body: StatementList,
+        this_mode: ThisMode,
+    ) -> Self
+    where
+        P: Into<Box<[FormalParameter]>>,
+    {
+        let mut function = Self::new(
+            parameter_list.into(),
+            Some(scope),
+            FunctionBody::Ordinary(Rc::new(body)),
+            this_mode,
+            false,
+            true,
+        );
+        function.flags |= FunctionFlags::ASYNC;
+        function
+    }
+
     /// This will create a built-in function object
     ///
     /// <https://tc39.es/ecma262/#sec-createbuiltinfunction>
@@ -206,6 +361,43 @@ impl Function {
         )
     }
 
+    /// This will create a built-in function object that captures a value, which is passed to the
+    /// body every time the function is called.
+    pub fn builtin_capturing(body: NativeCapturingFunctionData, captures: Value) -> Self {
+        let _timer = BoaProfiler::global().start_event("function::builtin_capturing", "function");
+        Self::new(
+            Vec::new(),
+            None,
+            FunctionBody::BuiltInCapturing(body, captures),
+            ThisMode::NonLexical,
+            false,
+            true,
+        )
+    }
+
+    /// This will create a built-in function object backed by a closure, which is passed
+    /// `captures` every time the function is called.
+    ///
+    /// The captured data is traced by the garbage collector, so it can hold values like objects,
+    /// which the closure itself must not capture. Data that doesn't hold any GC'd objects can be
+    /// captured by the closure directly, with `()` as `captures`.
+    pub fn closure<F, C>(function: F, captures: C) -> Self
+    where
+        F: Fn(&Value, &[Value], &C, &mut Interpreter) -> ResultValue + 'static,
+        C: Trace + 'static,
+    {
+        let _timer = BoaProfiler::global().start_event("function::closure", "function");
+        let closure: Box<dyn ClosureFunction> = Box::new(Closure { function, captures });
+        Self::new(
+            Vec::new(),
+            None,
+            FunctionBody::Closure(Gc::new(closure)),
+            ThisMode::NonLexical,
+            false,
+            true,
+        )
+    }
+
     /// This will handle calls for both ordinary and built-in functions
     ///
     /// <https://tc39.es/ecma262/#sec-prepareforordinarycall>
@@ -218,61 +410,61 @@ impl Function {
         interpreter: &mut Interpreter,
     ) -> ResultValue {
         let _timer = BoaProfiler::global().start_event("function::call", "function");
-        if self.flags.is_callable() {
-            match self.body {
-                FunctionBody::BuiltIn(func) => func(this, args_list, interpreter),
-                FunctionBody::Ordinary(ref body) => {
-                    // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
-                    // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
-                    let local_env = new_function_environment(
-                        function,
-                        if let ThisMode::Lexical = self.this_mode {
-                            None
-                        } else {
-                            Some(this.clone())
-                        },
-                        self.environment.as_ref().cloned(),
-                        // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
-                        if let ThisMode::Lexical = self.this_mode {
-                            BindingStatus::Lexical
-                        } else {
-                            BindingStatus::Uninitialized
-                        },
-                    );
-
-                    // Add argument bindings to the function environment
-                    for (i, param) in self.params.iter().enumerate() {
-                        // Rest Parameters
-                        if param.is_rest_param() {
-                            self.add_rest_param(param, i, args_list, interpreter, &local_env);
-                            break;
-                        }
-
-                        let value = args_list.get(i).cloned().unwrap_or_else(Value::undefined);
-                        self.add_arguments_to_environment(param, value, &local_env);
-                    }
-
-                    // Add arguments object
-                    let arguments_obj = create_unmapped_arguments_object(args_list);
-                    local_env
-                        .borrow_mut()
-                        .create_mutable_binding("arguments".to_string(), false);
-                    local_env
-                        .borrow_mut()
-                        .initialize_binding("arguments", arguments_obj);
-
-                    interpreter.realm.environment.push(local_env);
-
-                    // Call body should be set before reaching here
-                    let result = body.run(interpreter);
+        if !self.flags.is_callable() {
+            // The only ordinary functions that can't be called are class constructors.
+            if let FunctionBody::Ordinary(_) = self.body {
+                return interpreter
+                    .throw_type_error("class constructors must be invoked with 'new'");
+            }
+            let name = interpreter.to_string(&function.get_field("name"))?;
+            return interpreter.throw_type_error(format!("{} constructor requires 'new'", name));
+        }
 
-                    // local_env gets dropped here, its no longer needed
-                    interpreter.realm.environment.pop();
-                    result
+        match self.body {
+            // Built-in functions called as plain functions, like `Date()`, still see the global
+            // object as their `this` value.
+            FunctionBody::BuiltIn(func) if this.is_undefined() => {
+                func(&interpreter.global().clone(), args_list, interpreter)
+            }
+            FunctionBody::BuiltIn(func) => func(this, args_list, interpreter),
+            FunctionBody::BuiltInCapturing(func, ref captures) => {
+                func(this, args_list, captures, interpreter)
+            }
+            FunctionBody::Closure(ref closure) => closure.call(this, args_list, interpreter),
+            FunctionBody::Ordinary(ref body) => {
+                // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
+                let (this, binding_status) = if let ThisMode::Lexical = self.this_mode {
+                    (None, BindingStatus::Lexical)
+                } else if self.flags.is_strict() {
+                    (Some(this.clone()), BindingStatus::Uninitialized)
+                } else {
+                    // Sloppy mode functions get the global object instead of `undefined` or
+                    // `null`, and a wrapper object instead of a primitive.
+                    // <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
+                    let this = if this.is_null_or_undefined() {
+                        interpreter.global().clone()
+                    } else {
+                        interpreter.to_object(this)?
+                    };
+                    (Some(this), BindingStatus::Uninitialized)
+                };
+
+                let local_env = self.create_environment(
+                    function.clone(),
+                    this,
+                    binding_status,
+                    Value::undefined(),
+                    args_list,
+                );
+
+                if self.flags.is_generator() {
+                    self.create_generator(function, body, local_env, args_list, interpreter)
+                } else if self.flags.is_async() {
+                    Ok(self.start_async(body, local_env, args_list, interpreter))
+                } else {
+                    self.run_body(body, local_env, args_list, interpreter)
                 }
             }
-        } else {
-            panic!("TypeError: class constructors must be invoked with 'new'");
         }
     }
 
@@ -280,108 +472,266 @@ impl Function {
     pub fn construct(
         &self,
         function: Value, // represents a pointer to this function object wrapped in a GC (not a `this` JS object)
-        this: &Value,
+        new_target: &Value,
         args_list: &[Value],
         interpreter: &mut Interpreter,
     ) -> ResultValue {
-        if self.flags.is_constructable() {
-            match self.body {
-                FunctionBody::BuiltIn(func) => {
-                    func(this, args_list, interpreter)?;
-                    Ok(this.clone())
+        if !self.flags.is_constructable() {
+            let name = function.get_field("name").to_string();
+            return interpreter.throw_type_error(format!("{} is not a constructor", name));
+        }
+
+        match self.body {
+            FunctionBody::BuiltIn(func) => {
+                let this = ordinary_create_from_constructor(new_target, interpreter);
+                let result = func(&this, args_list, interpreter)?;
+
+                // Built-in constructors initialize `this`, except the `Function` constructor,
+                // which returns the function it creates.
+                let this = if result.is_function() { result } else { this };
+
+                // Built-in constructors set up the prototype of their own instances, so it has to
+                // be restored when they are called from a subclass.
+                if !new_target.strict_equals(&function) {
+                    let prototype = new_target.get_field(PROTOTYPE);
+                    if prototype.is_object() {
+                        if let Some(mut object) = this.as_object_mut() {
+                            object.set_prototype(prototype);
+                        }
+                    }
+                }
+                Ok(this)
+            }
+            FunctionBody::BuiltInCapturing(..) | FunctionBody::Closure(_) => {
+                unreachable!("capturing built-in functions are not constructable")
+            }
+            FunctionBody::Ordinary(ref body) => {
+                // Derived constructors get their `this` value from the `super(...)` call.
+                let this = match self.constructor_kind {
+                    ConstructorKind::Base => {
+                        Some(ordinary_create_from_constructor(new_target, interpreter))
+                    }
+                    ConstructorKind::Derived => None,
+                };
+
+                let local_env = self.create_environment(
+                    function,
+                    this.clone(),
+                    BindingStatus::Uninitialized,
+                    new_target.clone(),
+                    args_list,
+                );
+
+                let result = self.run_body(body, local_env.clone(), args_list, interpreter)?;
+                if result.is_object() {
+                    return Ok(result);
                 }
-                FunctionBody::Ordinary(ref body) => {
-                    // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
-                    // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
-                    let local_env = new_function_environment(
-                        function,
-                        Some(this.clone()),
-                        self.environment.as_ref().cloned(),
-                        // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
-                        if let ThisMode::Lexical = self.this_mode {
-                            BindingStatus::Lexical
-                        } else {
-                            BindingStatus::Uninitialized
-                        },
+                if let Some(this) = this {
+                    return Ok(this);
+                }
+                if !result.is_undefined() {
+                    return interpreter.throw_type_error(
+                        "derived constructors may only return an object or undefined",
                     );
+                }
 
-                    // Add argument bindings to the function environment
-                    for (i, param) in self.params.iter().enumerate() {
-                        // Rest Parameters
-                        if param.is_rest_param() {
-                            self.add_rest_param(param, i, args_list, interpreter, &local_env);
-                            break;
-                        }
+                let local_env = local_env.borrow();
+                let record = local_env
+                    .as_function_environment_record()
+                    .expect("constructors always have a function environment");
+                if record.is_this_initialized() {
+                    Ok(record.this_value.clone())
+                } else {
+                    interpreter.throw_reference_error(
+                        "must call super constructor before returning from derived constructor",
+                    )
+                }
+            }
+        }
+    }
 
-                        let value = args_list.get(i).cloned().unwrap_or_else(Value::undefined);
-                        self.add_arguments_to_environment(param, value, &local_env);
-                    }
+    /// Creates the function environment of an ordinary function.
+    ///
+    /// The parameters are bound by `run_body`, as their default values and patterns are evaluated
+    /// inside of the environment.
+    ///
+    /// <https://tc39.es/ecma262/#sec-prepareforordinarycall>
+    /// <https://tc39.es/ecma262/#sec-functiondeclarationinstantiation>
+    fn create_environment(
+        &self,
+        function: Value,
+        this: Option<Value>,
+        binding_status: BindingStatus,
+        new_target: Value,
+        args_list: &[Value],
+    ) -> Environment {
+        // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
+        let local_env = new_function_environment(
+            function,
+            this,
+            self.environment.as_ref().cloned(),
+            binding_status,
+            self.home_object.clone(),
+            new_target,
+        );
 
-                    // Add arguments object
-                    let arguments_obj = create_unmapped_arguments_object(args_list);
-                    local_env
-                        .borrow_mut()
-                        .create_mutable_binding("arguments".to_string(), false);
-                    local_env
-                        .borrow_mut()
-                        .initialize_binding("arguments", arguments_obj);
+        // Add arguments object
+        let arguments_obj = create_unmapped_arguments_object(args_list);
+        local_env
+            .borrow_mut()
+            .create_mutable_binding("arguments".to_string(), false);
+        local_env
+            .borrow_mut()
+            .initialize_binding("arguments", arguments_obj);
 
-                    interpreter.realm.environment.push(local_env);
+        local_env
+    }
 
-                    // Call body should be set before reaching here
-                    let _ = body.run(interpreter);
+    /// Binds the parameters and runs the body of an ordinary function inside of its function
+    /// environment.
+    ///
+    /// The body runs on top of the scope the function was defined in, not the scope of the caller.
+    /// Returns the value of the `return` statement that ended the body, or `undefined`.
+    fn run_body(
+        &self,
+        body: &StatementList,
+        local_env: Environment,
+        args_list: &[Value],
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let scope = self
+            .environment
+            .clone()
+            .expect("ordinary functions always have a scope");
+        let caller_env = mem::replace(
+            &mut interpreter.realm.environment,
+            LexicalEnvironment::from_scope(scope),
+        );
+        interpreter.realm.environment.push(local_env);
+        let caller_strict = interpreter.is_strict();
+        interpreter.set_strict(self.is_strict());
 
-                    // local_env gets dropped here, its no longer needed
-                    let binding = interpreter.realm.environment.get_this_binding();
-                    Ok(binding)
-                }
-            }
+        let result = self
+            .bind_parameters(args_list, interpreter)
+            .and_then(|_| body.run(interpreter));
+
+        // local_env gets dropped here, its no longer needed
+        interpreter.realm.environment = caller_env;
+        interpreter.set_strict(caller_strict);
+
+        let returned = *interpreter.get_current_state() == InterpreterState::Return;
+        interpreter.set_current_state(InterpreterState::Executing);
+
+        let value = result?;
+        if returned {
+            Ok(value)
         } else {
-            let name = this.get_field("name").to_string();
-            panic!("TypeError: {} is not a constructor", name);
+            Ok(Value::undefined())
         }
     }
 
-    // Adds the final rest parameters to the Environment as an array
-    fn add_rest_param(
+    /// Binds the parameters of a generator function, and creates the generator object that runs
+    /// its body inside of the function environment.
+    ///
+    /// <https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluatebody>
+    fn create_generator(
         &self,
-        param: &FormalParameter,
-        index: usize,
+        function: Value,
+        body: &Rc<StatementList>,
+        local_env: Environment,
         args_list: &[Value],
         interpreter: &mut Interpreter,
-        local_env: &Environment,
-    ) {
-        // Create array of values
-        let array = Array::new_array(interpreter).unwrap();
-        Array::add_to_array_object(&array, &args_list[index..]).unwrap();
+    ) -> ResultValue {
+        let generator_env = self.suspended_environment(local_env, args_list, interpreter)?;
 
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
+        let prototype = function.get_field(PROTOTYPE);
+        let prototype = if prototype.is_object() {
+            prototype
+        } else {
+            interpreter.iterator_prototypes().generator().clone()
+        };
+        Ok(Generator::create(prototype, generator_env, body.clone()))
+    }
 
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), array);
+    /// Binds the parameters of an async function, and starts running its body inside of the
+    /// function environment.
+    ///
+    /// Returns the promise settled with the completion of the body, which is rejected if the
+    /// parameters can't be bound.
+    ///
+    /// <https://tc39.es/ecma262/#sec-async-function-definitions-EvaluateBody>
+    fn start_async(
+        &self,
+        body: &Rc<StatementList>,
+        local_env: Environment,
+        args_list: &[Value],
+        interpreter: &mut Interpreter,
+    ) -> Value {
+        let promise = Promise::create(interpreter);
+        match self.suspended_environment(local_env, args_list, interpreter) {
+            Ok(environment) => {
+                AsyncFunction::start(&promise, environment, body.clone(), interpreter)
+            }
+            Err(error) => Promise::reject_promise(&promise, error, interpreter),
+        }
+        promise
     }
 
-    // Adds an argument to the environment
-    fn add_arguments_to_environment(
+    /// Binds the parameters of a function whose body doesn't run right away, and returns the
+    /// environment its body runs in later.
+    fn suspended_environment(
         &self,
-        param: &FormalParameter,
-        value: Value,
-        local_env: &Environment,
-    ) {
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
+        local_env: Environment,
+        args_list: &[Value],
+        interpreter: &mut Interpreter,
+    ) -> Result<LexicalEnvironment, Value> {
+        let scope = self
+            .environment
+            .clone()
+            .expect("ordinary functions always have a scope");
+        let caller_env = mem::replace(
+            &mut interpreter.realm.environment,
+            LexicalEnvironment::from_scope(scope),
+        );
+        interpreter.realm.environment.push(local_env);
 
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), value);
+        let result = self.bind_parameters(args_list, interpreter);
+
+        let environment = mem::replace(&mut interpreter.realm.environment, caller_env);
+        result.map(|_| environment)
+    }
+
+    /// Binds the arguments of a call to the parameters of the function, in the current
+    /// environment.
+    ///
+    /// Parameters get their default value if their argument is missing or `undefined`, and the
+    /// rest parameter gets an array of the remaining arguments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
+    fn bind_parameters(
+        &self,
+        args_list: &[Value],
+        interpreter: &mut Interpreter,
+    ) -> Result<(), Value> {
+        for (i, param) in self.params.iter().enumerate() {
+            let value = if param.is_rest_param() {
+                let array = Array::new_array(interpreter)?;
+                Array::add_to_array_object(&array, args_list.get(i..).unwrap_or(&[]))?
+            } else {
+                match (args_list.get(i), param.init()) {
+                    (Some(value), _) if !value.is_undefined() => value.clone(),
+                    (_, Some(init)) => init.run(interpreter)?,
+                    (_, None) => Value::undefined(),
+                }
+            };
+            param
+                .target()
+                .bind(value, BindingKind::Parameter, interpreter)?;
+        }
+        Ok(())
     }
 
     /// Returns true if the function object is callable.
@@ -393,6 +743,21 @@ impl Function {
     pub fn is_constructable(&self) -> bool {
         self.flags.is_constructable()
     }
+
+    /// Returns true if the function object is a generator function.
+    pub fn is_generator(&self) -> bool {
+        self.flags.is_generator()
+    }
+
+    /// Returns true if the function object is an async function.
+    pub fn is_async(&self) -> bool {
+        self.flags.is_async()
+    }
+
+    /// Returns true if the code of the function is strict mode code.
+    pub fn is_strict(&self) -> bool {
+        self.flags.is_strict()
+    }
 }
 
 impl Debug for Function {
@@ -432,15 +797,99 @@ pub fn create_unmapped_arguments_object(arguments_list: &[Value]) -> Value {
     Value::from(obj)
 }
 
-/// Create new function `[[Construct]]`
+/// Creates the `this` object of a constructor call, inheriting from `new_target.prototype`.
+///
+/// If `new_target.prototype` is not an object, `Object.prototype` is used instead.
+///
+/// <https://tc39.es/ecma262/#sec-ordinarycreatefromconstructor>
+fn ordinary_create_from_constructor(new_target: &Value, interpreter: &Interpreter) -> Value {
+    let this = Value::new_object(Some(interpreter.global()));
+    let prototype = new_target.get_field(PROTOTYPE);
+    if prototype.is_object() {
+        this.as_object_mut()
+            .expect("this was not an object")
+            .set_prototype(prototype);
+    }
+    this
+}
+
+/// `Function( p1, p2, … , pn, body )`
+///
+/// Creates a new function whose parameters and body are parsed from the given strings. The new
+/// function always closes over the global environment, not the environment of the caller.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createdynamicfunction
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/Function
+pub fn make_function(_: &Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+    let (params, body) = match args.split_last() {
+        Some((body, params)) => {
+            let mut params_text = Vec::with_capacity(params.len());
+            for param in params {
+                params_text.push(interpreter.to_string(param)?.to_string());
+            }
+            (
+                params_text.join(","),
+                interpreter.to_string(body)?.to_string(),
+            )
+        }
+        None => (String::new(), String::new()),
+    };
+
+    let (params, body) = parse_dynamic_function(&params, &body)
+        .map_err(|message| interpreter.construct_syntax_error(message))?;
+    let params_len = params.len();
+
+    let global_env = interpreter
+        .realm
+        .environment
+        .environments()
+        .last()
+        .expect("there is always a global environment")
+        .clone();
+    let func = Function::new(
+        params,
+        Some(global_env),
+        FunctionBody::Ordinary(Rc::new(body)),
+        ThisMode::NonLexical,
+        true,
+        true,
+    );
+
+    // A new function object is created even when `Function` is called as a method, so that the
+    // receiver isn't turned into the function.
+    let function_prototype = interpreter
+        .global()
+        .get_field("Function")
+        .get_field(PROTOTYPE);
+    let function = Value::from(Object::function(func, function_prototype));
+    function.set_field(PROTOTYPE, Value::new_object(Some(interpreter.global())));
+    function.set_field("length", Value::from(params_len));
+    function.set_field("name", Value::from("anonymous"));
+
+    Ok(function)
+}
+
+/// Parses the parameters and the body of a dynamic function.
 ///
-// This gets called when a new Function() is created.
-pub fn make_function(this: &Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
-    this.set_data(ObjectData::Function(Function::builtin(
-        Vec::new(),
-        |_, _, _| Ok(Value::undefined()),
-    )));
-    Ok(this.clone())
+/// Both parts are lexed on their own first, so that a comment or a string literal cannot start
+/// in one of them and end in the other.
+fn parse_dynamic_function(
+    params: &str,
+    body: &str,
+) -> Result<(Box<[FormalParameter]>, StatementList), String> {
+    Lexer::new(params).lex().map_err(|err| err.to_string())?;
+    Lexer::new(body).lex().map_err(|err| err.to_string())?;
+
+    let source = format!("({}\n) {{\n{}\n}}", params, body);
+    let mut lexer = Lexer::new(&source);
+    lexer.lex().map_err(|err| err.to_string())?;
+    Parser::new(&lexer.tokens)
+        .parse_function()
+        .map_err(|err| err.to_string())
 }
 
 /// Creates a new constructor function
@@ -482,10 +931,13 @@ pub fn make_constructor_fn(
 
     let constructor = Value::from(constructor);
 
-    prototype
-        .as_object_mut()
-        .unwrap()
-        .insert_field("constructor", constructor.clone());
+    prototype.as_object_mut().unwrap().insert_property(
+        "constructor",
+        Property::data_descriptor(
+            constructor.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
 
     constructor
         .as_object_mut()
@@ -520,7 +972,7 @@ pub fn make_builtin_fn<N>(
     length: usize,
     interpreter: &Interpreter,
 ) where
-    N: Into<String>,
+    N: Into<PropertyKey>,
 {
     let name = name.into();
     let _timer = BoaProfiler::global().start_event(&format!("make_builtin_fn: {}", &name), "init");
@@ -535,10 +987,149 @@ pub fn make_builtin_fn<N>(
 
     function.insert_field("length", Value::from(length));
 
+    // Built-in methods are not enumerable.
+    parent.as_object_mut().unwrap().insert_property(
+        name,
+        Property::data_descriptor(
+            Value::from(function),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+}
+
+/// Creates a new read-only accessor property of a `Object` or `prototype`, like
+/// `ArrayBuffer.prototype.byteLength`.
+///
+/// The getter is called with the object the property was read from as `this`, and the property
+/// is not enumerable, like the other properties of the built-in objects.
+pub fn make_builtin_getter<N>(
+    function: NativeFunctionData,
+    name: N,
+    parent: &Value,
+    interpreter: &Interpreter,
+) where
+    N: Into<PropertyKey>,
+{
+    let name = name.into();
+    let _timer =
+        BoaProfiler::global().start_event(&format!("make_builtin_getter: {}", &name), "init");
+
+    let mut function = Object::function(
+        Function::builtin(Vec::new(), function),
+        interpreter
+            .global()
+            .get_field("Function")
+            .get_field("prototype"),
+    );
+
+    function.insert_field("length", Value::from(0));
+
+    let mut property = Property::default().get(Value::from(function));
+    property.attribute = Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
     parent
         .as_object_mut()
         .unwrap()
-        .insert_field(name, Value::from(function));
+        .insert_property(name, property);
+}
+
+/// Creates a new method of an object or prototype, backed by a closure.
+///
+/// This is the equivalent of `make_builtin_fn` for functions that capture data, see
+/// `Function::closure`. The function is called with `captures` every time, and its `length`
+/// property is set to `length`.
+pub fn make_closure_fn<N, F, C>(
+    function: F,
+    captures: C,
+    name: N,
+    parent: &Value,
+    length: usize,
+    interpreter: &Interpreter,
+) where
+    N: Into<PropertyKey>,
+    F: Fn(&Value, &[Value], &C, &mut Interpreter) -> ResultValue + 'static,
+    C: Trace + 'static,
+{
+    let name = name.into();
+    let _timer = BoaProfiler::global().start_event(&format!("make_closure_fn: {}", &name), "init");
+
+    let mut function = Object::function(
+        Function::closure(function, captures),
+        interpreter
+            .global()
+            .get_field("Function")
+            .get_field(PROTOTYPE),
+    );
+    function.insert_field("length", Value::from(length));
+
+    parent.as_object_mut().unwrap().insert_property(
+        name,
+        Property::data_descriptor(
+            Value::from(function),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+}
+
+/// Creates a new built-in function object that captures a value, like the resolving functions of
+/// a promise.
+///
+/// The function isn't a property of any object, it is returned instead. Its `length` property is
+/// set to `length`.
+pub fn make_capturing_fn(
+    function: NativeCapturingFunctionData,
+    length: usize,
+    captures: Value,
+    interpreter: &Interpreter,
+) -> Value {
+    let mut function = Object::function(
+        Function::builtin_capturing(function, captures),
+        interpreter
+            .global()
+            .get_field("Function")
+            .get_field(PROTOTYPE),
+    );
+    function.insert_property(
+        "length",
+        Property::data_descriptor(
+            Value::from(length),
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+
+    Value::from(function)
+}
+
+/// Creates a new built-in function object backed by a closure, which is passed `captures` every
+/// time it's called.
+///
+/// This is the equivalent of `make_capturing_fn` for data that isn't a JavaScript value, see
+/// `Function::closure`. Its `length` property is set to `length`.
+pub fn make_closure<F, C>(
+    function: F,
+    captures: C,
+    length: usize,
+    interpreter: &Interpreter,
+) -> Value
+where
+    F: Fn(&Value, &[Value], &C, &mut Interpreter) -> ResultValue + 'static,
+    C: Trace + 'static,
+{
+    let mut function = Object::function(
+        Function::closure(function, captures),
+        interpreter
+            .global()
+            .get_field("Function")
+            .get_field(PROTOTYPE),
+    );
+    function.insert_property(
+        "length",
+        Property::data_descriptor(
+            Value::from(length),
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+
+    Value::from(function)
 }
 
 /// Initialise the `Function` object on the global object.
@@ -0,0 +1,127 @@
+//! The suspension and resumption of generator bodies.
+//!
+//! A `yield` expression can't pause the interpreter in the middle of the body, so suspending the
+//! body unwinds it instead: the `yield` expression returns an error, and every node it is nested
+//! in saves a `Frame` with its progress before passing the error on. Resuming the body runs it
+//! again from the top, and each node that saved a frame skips the parts it had already run,
+//! down to the `yield` expression, which then evaluates to the value the generator was resumed
+//! with.
+//!
+//! The frames are taken in the same order they were saved in, since the nodes are entered again
+//! from the outermost one. A node either always saves a frame when the body is suspended inside
+//! of it, or never does, in which case it must not do anything before running the part that was
+//! suspended.
+
+use super::ResumeKind;
+use crate::{
+    builtins::value::Value,
+    exec::{Interpreter, InterpreterState},
+};
+use gc::{custom_trace, Finalize, Trace};
+
+/// The progress of a node of a suspended generator body.
+#[derive(Debug, Clone)]
+pub(crate) struct Frame {
+    /// The part of the node that was running when the body was suspended, numbered by the node.
+    pub(crate) step: usize,
+    /// The values computed by the node before the body was suspended.
+    pub(crate) values: Vec<Value>,
+    /// The state of the interpreter saved by the node, like the pending `return` of a `finally`
+    /// block.
+    pub(crate) state: InterpreterState,
+}
+
+impl Finalize for Frame {}
+
+// Frames are destructured by the nodes resuming from them, so they can't implement `Drop`.
+unsafe impl Trace for Frame {
+    custom_trace!(this, mark(&this.values));
+}
+
+impl Frame {
+    /// Creates the frame of a node suspended while running the given step.
+    pub(crate) fn new(step: usize, values: Vec<Value>) -> Self {
+        Self {
+            step,
+            values,
+            state: InterpreterState::Executing,
+        }
+    }
+
+    /// Creates the frame of a node suspended while running the given step, with a saved state.
+    pub(crate) fn with_state(step: usize, values: Vec<Value>, state: InterpreterState) -> Self {
+        Self {
+            step,
+            values,
+            state,
+        }
+    }
+}
+
+/// The state of the generator body the interpreter is running.
+#[derive(Debug, Default)]
+pub(crate) struct GeneratorContext {
+    /// The frames of the nodes the body is suspended in, the outermost one last.
+    pub(super) frames: Vec<Frame>,
+    /// How the body was resumed, taken by the `yield` expression it was suspended at.
+    pub(super) resumed: Option<ResumeKind>,
+    /// The value yielded by the body, while it is being suspended.
+    pub(super) yielded: Option<Value>,
+}
+
+impl GeneratorContext {
+    /// Creates the context of a body resumed from the given frames.
+    pub(super) fn new(frames: Vec<Frame>, resumed: ResumeKind) -> Self {
+        Self {
+            frames,
+            resumed: Some(resumed),
+            yielded: None,
+        }
+    }
+
+    /// Takes the frame of the next node to resume, if the body hasn't got back to the point it
+    /// was suspended at yet.
+    pub(crate) fn resume_frame(&mut self) -> Option<Frame> {
+        self.frames.pop()
+    }
+
+    /// Checks if the body is being suspended.
+    pub(crate) fn is_suspending(&self) -> bool {
+        self.yielded.is_some()
+    }
+
+    /// Saves the frame of a node the body is being suspended in.
+    pub(crate) fn save_frame(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+}
+
+/// Starts suspending the generator body that is currently running, the value is returned to the
+/// caller that resumed it.
+///
+/// The `yield` or `await` expression saves its frame and returns the error unwinding the body.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-generatoryield
+pub(crate) fn suspend(interpreter: &mut Interpreter, value: Value, frame: Frame) -> Value {
+    match interpreter.generator_context {
+        Some(ref mut context) => {
+            context.yielded = Some(value);
+            context.save_frame(frame);
+            Value::undefined()
+        }
+        None => interpreter.construct_syntax_error("yield is only valid in generators"),
+    }
+}
+
+/// Takes how the generator body was resumed, for the `yield` or `await` expression it was
+/// suspended at.
+pub(crate) fn resumed(interpreter: &mut Interpreter) -> ResumeKind {
+    interpreter
+        .generator_context
+        .as_mut()
+        .and_then(|context| context.resumed.take())
+        .expect("a resumed generator body has a resumption")
+}
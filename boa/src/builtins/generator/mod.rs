@@ -0,0 +1,316 @@
+//! This module implements generator objects, returned by calling a generator function
+//! (`function*`), and the `%GeneratorPrototype%` object.
+//!
+//! The body of a generator has to be suspended in the middle of its execution by `yield`
+//! expressions. The progress of the suspended body is saved in the generator object, along with
+//! its environment, and the body picks up from there when the generator is resumed.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
+
+mod context;
+#[cfg(test)]
+mod tests;
+
+pub(crate) use self::context::{resumed, suspend, Frame, GeneratorContext};
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::create_iter_result_object,
+        object::ObjectData,
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+    },
+    environment::lexical_environment::LexicalEnvironment,
+    exec::{Executable, Interpreter, InterpreterState},
+    syntax::ast::node::StatementList,
+    BoaProfiler,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{
+    fmt::{self, Debug},
+    mem,
+    rc::Rc,
+};
+
+/// The execution state of a generator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-instances
+#[derive(Debug, Clone, Copy, PartialEq, Finalize)]
+pub enum GeneratorState {
+    /// The body hasn't started running yet.
+    SuspendedStart,
+    /// The body is suspended at a `yield` expression.
+    SuspendedYield,
+    /// The body is running.
+    Executing,
+    /// The body has returned or thrown, the generator can't be resumed anymore.
+    Completed,
+}
+
+unsafe impl Trace for GeneratorState {
+    unsafe_empty_trace!();
+}
+
+/// How a suspended generator is resumed, by `next()`, `return()` or `throw()`.
+#[derive(Debug, Clone)]
+pub(crate) enum ResumeKind {
+    /// The `yield` expression evaluates to the value.
+    Next(Value),
+    /// The generator returns the value from the `yield` expression, running `finally` blocks.
+    Return(Value),
+    /// The `yield` expression throws the value.
+    Throw(Value),
+}
+
+/// The internal state of a generator object.
+#[derive(Clone, Trace, Finalize)]
+pub struct Generator {
+    state: GeneratorState,
+    /// The body of the generator function.
+    #[unsafe_ignore_trace]
+    body: Rc<StatementList>,
+    /// The environment of the body, dropped once the generator is completed.
+    environment: Option<LexicalEnvironment>,
+    /// The frames of the nodes the body is suspended in, the outermost one last.
+    frames: Vec<Frame>,
+}
+
+impl Generator {
+    /// Creates a new generator object, which runs the given body in the given environment, where
+    /// the parameters of the generator function have already been bound.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluatebody
+    pub(crate) fn create(
+        prototype: Value,
+        environment: LexicalEnvironment,
+        body: Rc<StatementList>,
+    ) -> Value {
+        Value::new_object_from_prototype(
+            prototype,
+            ObjectData::Generator(Self {
+                state: GeneratorState::SuspendedStart,
+                body,
+                environment: Some(environment),
+                frames: Vec::new(),
+            }),
+        )
+    }
+
+    /// Gets the execution state of the generator.
+    #[inline]
+    pub fn state(&self) -> GeneratorState {
+        self.state
+    }
+
+    /// `Generator.prototype.next( value )`
+    ///
+    /// Resumes the generator, the current `yield` expression evaluates to `value`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next
+    pub(crate) fn next(this: &Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeKind::Next(value), interpreter)
+    }
+
+    /// `Generator.prototype.return( value )`
+    ///
+    /// Resumes the generator as if the current `yield` expression was a `return` statement.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return
+    pub(crate) fn r#return(
+        this: &Value,
+        args: &[Value],
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeKind::Return(value), interpreter)
+    }
+
+    /// `Generator.prototype.throw( exception )`
+    ///
+    /// Resumes the generator as if the current `yield` expression was a `throw` statement.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw
+    pub(crate) fn throw(
+        this: &Value,
+        args: &[Value],
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, ResumeKind::Throw(value), interpreter)
+    }
+
+    /// Resumes the generator, and returns an iterator result with the value it yields or returns.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorresume
+    fn resume(this: &Value, kind: ResumeKind, interpreter: &mut Interpreter) -> ResultValue {
+        let (value, done) = Self::resume_body(this, kind, interpreter)?;
+        Ok(create_iter_result_object(interpreter, value, done))
+    }
+
+    /// Resumes the body of the generator, and returns the value it yields or returns, along with
+    /// whether it completed.
+    pub(crate) fn resume_body(
+        this: &Value,
+        kind: ResumeKind,
+        interpreter: &mut Interpreter,
+    ) -> Result<(Value, bool), Value> {
+        // The state of the body is taken out of the object, so the object isn't borrowed while
+        // the body runs.
+        let suspended = match this.as_object_mut() {
+            Some(mut object) => match object.data {
+                ObjectData::Generator(ref mut generator) => match generator.state {
+                    GeneratorState::Executing => {
+                        return Err(interpreter.construct_type_error("generator is already running"))
+                    }
+                    GeneratorState::SuspendedStart if !matches!(kind, ResumeKind::Next(_)) => {
+                        generator.complete();
+                        None
+                    }
+                    GeneratorState::Completed => None,
+                    _ => {
+                        generator.state = GeneratorState::Executing;
+                        generator.environment.take().map(|environment| {
+                            (
+                                generator.body.clone(),
+                                environment,
+                                mem::take(&mut generator.frames),
+                            )
+                        })
+                    }
+                },
+                _ => return Err(interpreter.construct_type_error("'this' is not a Generator")),
+            },
+            None => return Err(interpreter.construct_type_error("'this' is not a Generator")),
+        };
+
+        let (body, environment, frames) = match suspended {
+            Some(suspended) => suspended,
+            None => {
+                return match kind {
+                    ResumeKind::Next(_) => Ok((Value::undefined(), true)),
+                    ResumeKind::Return(value) => Ok((value, true)),
+                    ResumeKind::Throw(value) => Err(value),
+                }
+            }
+        };
+
+        let caller_environment = mem::replace(&mut interpreter.realm.environment, environment);
+        let caller_context = interpreter
+            .generator_context
+            .replace(GeneratorContext::new(frames, kind));
+        let caller_state = interpreter.get_current_state().clone();
+        let caller_strict = interpreter.is_strict();
+        interpreter.set_current_state(InterpreterState::Executing);
+        interpreter.set_strict(body.is_strict());
+
+        let result = body.run(interpreter);
+        let returned = *interpreter.get_current_state() == InterpreterState::Return;
+
+        let context = mem::replace(&mut interpreter.generator_context, caller_context)
+            .expect("the generator context was replaced while the body was running");
+        interpreter.set_current_state(caller_state);
+        interpreter.set_strict(caller_strict);
+        let environment = mem::replace(&mut interpreter.realm.environment, caller_environment);
+
+        let suspended = context.yielded.is_some();
+        if let Some(mut object) = this.as_object_mut() {
+            if let ObjectData::Generator(ref mut generator) = object.data {
+                if suspended {
+                    generator.state = GeneratorState::SuspendedYield;
+                    generator.environment = Some(environment);
+                    generator.frames = context.frames;
+                } else {
+                    generator.complete();
+                }
+            }
+        }
+
+        match context.yielded {
+            Some(value) => Ok((value, false)),
+            None if returned => result.map(|value| (value, true)),
+            None => result.map(|_| (Value::undefined(), true)),
+        }
+    }
+
+    /// Marks the generator as completed, dropping the environment of its body.
+    fn complete(&mut self) {
+        self.state = GeneratorState::Completed;
+        self.environment = None;
+        self.frames.clear();
+    }
+
+    /// Creates the `%GeneratorPrototype%` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-prototype
+    pub(crate) fn create_prototype(
+        interpreter: &mut Interpreter,
+        iterator_prototype: Value,
+    ) -> Value {
+        let _timer = BoaProfiler::global().start_event("Generator", "init");
+        let global = interpreter.global();
+        let prototype = Value::new_object(Some(global));
+        if let Some(mut object) = prototype.as_object_mut() {
+            object.set_prototype(iterator_prototype);
+        }
+
+        make_builtin_fn(Self::next, "next", &prototype, 1, interpreter);
+        make_builtin_fn(Self::r#return, "return", &prototype, 1, interpreter);
+        make_builtin_fn(Self::throw, "throw", &prototype, 1, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from("Generator"),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        prototype
+    }
+}
+
+impl Debug for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Generator")
+            .field("state", &self.state)
+            .finish()
+    }
+}
@@ -0,0 +1,477 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn next() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function* gen() {
+            yield 1;
+            yield 2;
+            return 3;
+        }
+        var it = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next().value"), "1");
+    assert_eq!(forward(&mut engine, "it.next().value"), "2");
+    let result = forward(&mut engine, "var last = it.next(); [last.value, last.done]");
+    assert_eq!(result, "[ 3, true ]");
+    let result = forward(
+        &mut engine,
+        "var after = it.next(); [after.value, after.done]",
+    );
+    assert_eq!(result, "[ undefined, true ]");
+}
+
+#[test]
+fn next_sends_value() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function* gen(a) {
+            let b = yield a;
+            let c = yield a + b;
+            return a + b + c;
+        }
+        var it = gen(1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next(100).value"), "1");
+    assert_eq!(forward(&mut engine, "it.next(2).value"), "3");
+    assert_eq!(forward(&mut engine, "it.next(3).value"), "6");
+}
+
+#[test]
+fn lazy_start() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var started = false;
+        function* gen() {
+            started = true;
+            yield 1;
+        }
+        var it = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "started"), "false");
+    forward(&mut engine, "it.next()");
+    assert_eq!(forward(&mut engine, "started"), "true");
+}
+
+#[test]
+fn r#return() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var cleaned = false;
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                cleaned = true;
+            }
+        }
+        var it = gen();
+        it.next();
+        var result = it.return(7);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "[result.value, result.done]"),
+        "[ 7, true ]"
+    );
+    assert_eq!(forward(&mut engine, "cleaned"), "true");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn throw() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function* gen() {
+            try {
+                yield 1;
+            } catch (e) {
+                yield "caught " + e;
+            }
+        }
+        var it = gen();
+        it.next();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.throw('x').value"), "\"caught x\"");
+
+    let result = forward(
+        &mut engine,
+        r#"
+        var message;
+        try {
+            it.throw(new Error("boom"));
+        } catch (e) {
+            message = e.message;
+        }
+        message
+        "#,
+    );
+    assert_eq!(result, "\"boom\"");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn throw_before_start() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var started = false;
+        function* gen() {
+            started = true;
+            yield 1;
+        }
+        var it = gen();
+        try {
+            it.throw("error");
+        } catch (e) {}
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "started"), "false");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn already_running() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function* gen() {
+            yield it.next();
+        }
+        var it = gen();
+        var message;
+        try {
+            it.next();
+        } catch (e) {
+            message = e.message;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "message"),
+        "\"generator is already running\""
+    );
+}
+
+#[test]
+fn delegate() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function* inner() {
+            let a = yield 1;
+            yield a;
+            return "inner done";
+        }
+        function* outer() {
+            let result = yield* inner();
+            yield result;
+            yield* [4, 5];
+        }
+        var it = outer();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "it.next().value"), "1");
+    assert_eq!(forward(&mut engine, "it.next('sent').value"), "\"sent\"");
+    assert_eq!(forward(&mut engine, "it.next().value"), "\"inner done\"");
+    assert_eq!(forward(&mut engine, "it.next().value"), "4");
+    assert_eq!(forward(&mut engine, "it.next().value"), "5");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+}
+
+#[test]
+fn for_of() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var closed = false;
+        function* naturals() {
+            try {
+                let n = 0;
+                while (true) {
+                    yield n++;
+                }
+            } finally {
+                closed = true;
+            }
+        }
+        var sum = 0;
+        for (let n of naturals()) {
+            if (n > 4) {
+                break;
+            }
+            sum += n;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "sum"), "10");
+    assert_eq!(forward(&mut engine, "closed"), "true");
+}
+
+#[test]
+fn methods() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var obj = {
+            *values() {
+                yield 1;
+                yield 2;
+            }
+        };
+        class Range {
+            constructor(end) {
+                this.end = end;
+            }
+            *values() {
+                for (let i = 0; i < this.end; i++) {
+                    yield i;
+                }
+            }
+            static *empty() {}
+        }
+        "#;
+    forward(&mut engine, init);
+    let result = forward(
+        &mut engine,
+        r#"
+        var values = [];
+        for (let value of obj.values()) {
+            values.push(value);
+        }
+        for (let value of new Range(3).values()) {
+            values.push(value);
+        }
+        values
+        "#,
+    );
+    assert_eq!(result, "[ 1, 2, 0, 1, 2 ]");
+    assert_eq!(forward(&mut engine, "Range.empty().next().done"), "true");
+}
+
+#[test]
+fn prototype() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function* gen() {}
+        var it = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Object.getPrototypeOf(it) === gen.prototype"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "it[Symbol.iterator]() === it"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(gen.prototype).next === it.next"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn not_constructable() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function* gen() {}
+        var error;
+        try {
+            new gen();
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "error instanceof TypeError"), "true");
+}
+
+/// Runs a generator to completion, resuming each `yield` with ten times the yielded value, and
+/// returns the yielded values followed by the returned one.
+const DRIVE: &str = r#"
+    function drive(gen) {
+        let it = gen();
+        let result = it.next();
+        let log = [];
+        while (!result.done) {
+            log.push(result.value);
+            result = it.next(result.value * 10);
+        }
+        log.push(result.value);
+        return log.join(",");
+    }
+    "#;
+
+#[test]
+fn yield_in_expressions() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    forward(&mut engine, DRIVE);
+
+    let cases = [
+        (
+            "function* () { return (yield 1) + (yield 2) * (yield 3); }",
+            "\"1,2,3,610\"",
+        ),
+        (
+            "function* () { function f(a, b, c) { return a + b + c; } return f(yield 1, 5, yield 2); }",
+            "\"1,2,35\"",
+        ),
+        (
+            "function* () { return [yield 1, ...[yield 2, 7], yield 3].join('-'); }",
+            "\"1,2,3,10-20-7-30\"",
+        ),
+        (
+            "function* () { let o = { a: yield 1, [yield 2]: yield 3 }; return o.a + o[20]; }",
+            "\"1,2,3,40\"",
+        ),
+        ("function* () { return `x${yield 1}y${yield 2}z`; }", "\"1,2,x10y20z\""),
+        (
+            "function* () { let o = { v: 1 }; o.v += yield 2; o['v'] += yield 3; return o.v; }",
+            "\"2,3,51\"",
+        ),
+        (
+            "function* () { let o = { k: 5 }; return o[yield 1] === undefined && (yield 2); }",
+            "\"1,2,20\"",
+        ),
+        (
+            "function* () { class C { constructor(v) { this.v = v; } } return new C(yield 4).v; }",
+            "\"4,40\"",
+        ),
+        (
+            "function* () { let x = 0; x = yield 1, x += 1; return x; }",
+            "\"1,11\"",
+        ),
+    ];
+    for (gen, expected) in cases.iter() {
+        assert_eq!(forward(&mut engine, &format!("drive({})", gen)), *expected);
+    }
+}
+
+#[test]
+fn yield_in_statements() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    forward(&mut engine, DRIVE);
+
+    let cases = [
+        (
+            "function* () { let s = 0; for (let i = 1; i < (yield 3); i += yield i) { s += i; } return s; }",
+            "\"3,1,3,11,3,12\"",
+        ),
+        (
+            "function* () { let n = 0; while ((yield n) < 20) { n++; } return n; }",
+            "\"0,1,2,2\"",
+        ),
+        (
+            "function* () { let n = 1; do { n += yield n; } while (n < 50); return n; }",
+            "\"1,11,121\"",
+        ),
+        (
+            "function* () { let s = ''; for (let k in { a: 1, b: 2 }) { s += k + (yield 1); } return s; }",
+            "\"1,1,a10b10\"",
+        ),
+        (
+            "function* () { let s = 0; for (const v of [1, 2, 3]) { s += yield v; } return s; }",
+            "\"1,2,3,60\"",
+        ),
+        (
+            "function* () { let s = ''; for (var k in (yield 1, { a: 1, b: 2 })) { s += k; } return s; }",
+            "\"1,ab\"",
+        ),
+        (
+            "function* () { let s = 0; for (const x of [yield 1, yield 2]) { s += x + (yield x); } return s; }",
+            "\"1,2,10,20,330\"",
+        ),
+        (
+            "function* () { switch (yield 1) { case 5: return 'no'; case yield 2: return 'no'; case 10: yield 3; case 11: return 'fell'; } }",
+            "\"1,2,3,fell\"",
+        ),
+        (
+            "function* () { try { yield 1; throw yield 2; } catch (e) { return e + (yield 3); } }",
+            "\"1,2,3,50\"",
+        ),
+        (
+            "function* () { let r = ''; try { r += yield 1; } finally { r += yield 2; } return r; }",
+            "\"1,2,1020\"",
+        ),
+        (
+            "function* () { if ((yield 1) > 5) { return yield 2; } else { return 0; } }",
+            "\"1,2,20\"",
+        ),
+        (
+            "function* () { outer: for (let i = 0; i < 2; i++) { for (let j = 0; j < 3; j++) { if ((yield j) == 10) continue outer; } } return 'done'; }",
+            "\"0,1,0,1,done\"",
+        ),
+        (
+            "function* () { let [a = yield 1, b] = [undefined, 2]; let { c = yield 3, d } = { d: 4 }; return a + b + c + d; }",
+            "\"1,3,46\"",
+        ),
+        (
+            "function* () { let r = yield* (function* () { return (yield 1) + 1; })(); return r + (yield 9); }",
+            "\"1,9,101\"",
+        ),
+    ];
+    for (gen, expected) in cases.iter() {
+        assert_eq!(forward(&mut engine, &format!("drive({})", gen)), *expected);
+    }
+}
+
+#[test]
+fn return_during_finally() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                yield 3;
+            }
+        }
+        var it = gen();
+        it.next();
+        "#;
+    forward(&mut engine, init);
+    let result = forward(&mut engine, "var r = it.return(5); [r.value, r.done]");
+    assert_eq!(result, "[ 3, false ]");
+    let result = forward(&mut engine, "var r = it.next(); [r.value, r.done]");
+    assert_eq!(result, "[ 5, true ]");
+}
+
+#[test]
+fn suspended_generator_is_collected() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var weak;
+        (function () {
+            function* gen() {
+                let self = it;
+                yield 1;
+            }
+            let it = gen();
+            it.next();
+            weak = new WeakRef(it);
+        })();
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "weak.deref()"), "undefined");
+}
@@ -0,0 +1,286 @@
+//! This module implements the iteration protocols, and the `%IteratorPrototype%` object that all
+//! the builtin iterators inherit from.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-iteration
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols
+
+use crate::{
+    builtins::{
+        array::array_iterator::ArrayIterator, function::make_builtin_fn, generator::Generator,
+        map::map_iterator::MapIterator, set::set_iterator::SetIterator,
+        string::string_iterator::StringIterator, value::Value,
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+
+/// The prototypes of the builtin iterator objects, which are shared by all their instances.
+#[derive(Debug, Default)]
+pub struct IteratorPrototypes {
+    iterator_prototype: Value,
+    array_iterator: Value,
+    string_iterator: Value,
+    map_iterator: Value,
+    set_iterator: Value,
+    generator: Value,
+}
+
+impl IteratorPrototypes {
+    /// Creates the iterator prototypes.
+    ///
+    /// This has to be done after the builtin objects are initialized.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> Self {
+        let _timer = BoaProfiler::global().start_event("IteratorPrototypes", "init");
+        let iterator_prototype = create_iterator_prototype(interpreter);
+
+        Self {
+            array_iterator: ArrayIterator::create_prototype(
+                interpreter,
+                iterator_prototype.clone(),
+            ),
+            string_iterator: StringIterator::create_prototype(
+                interpreter,
+                iterator_prototype.clone(),
+            ),
+            map_iterator: MapIterator::create_prototype(interpreter, iterator_prototype.clone()),
+            set_iterator: SetIterator::create_prototype(interpreter, iterator_prototype.clone()),
+            generator: Generator::create_prototype(interpreter, iterator_prototype.clone()),
+            iterator_prototype,
+        }
+    }
+
+    /// The `%IteratorPrototype%` object.
+    #[inline]
+    pub fn iterator_prototype(&self) -> &Value {
+        &self.iterator_prototype
+    }
+
+    /// The `%ArrayIteratorPrototype%` object.
+    #[inline]
+    pub fn array_iterator(&self) -> &Value {
+        &self.array_iterator
+    }
+
+    /// The `%StringIteratorPrototype%` object.
+    #[inline]
+    pub fn string_iterator(&self) -> &Value {
+        &self.string_iterator
+    }
+
+    /// The `%MapIteratorPrototype%` object.
+    #[inline]
+    pub fn map_iterator(&self) -> &Value {
+        &self.map_iterator
+    }
+
+    /// The `%SetIteratorPrototype%` object.
+    #[inline]
+    pub fn set_iterator(&self) -> &Value {
+        &self.set_iterator
+    }
+
+    /// The `%GeneratorPrototype%` object.
+    #[inline]
+    pub fn generator(&self) -> &Value {
+        &self.generator
+    }
+}
+
+/// Creates the `%IteratorPrototype%` object, whose `[Symbol.iterator]()` method returns the
+/// iterator itself, which makes iterators iterable.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
+fn create_iterator_prototype(interpreter: &mut Interpreter) -> Value {
+    let prototype = Value::new_object(Some(interpreter.global()));
+    let symbol_iterator = interpreter.well_known_symbols().iterator_symbol();
+    make_builtin_fn(
+        |this, _, _| Ok(this.clone()),
+        symbol_iterator,
+        &prototype,
+        0,
+        interpreter,
+    );
+    prototype
+}
+
+/// Creates the object returned by the `next()` method of iterators.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createiterresultobject
+pub fn create_iter_result_object(interpreter: &Interpreter, value: Value, done: bool) -> Value {
+    let object = Value::new_object(Some(interpreter.global()));
+    object.set_field("value", value);
+    object.set_field("done", done);
+    object
+}
+
+/// Gets an iterator over the values of an iterable object, using its `[Symbol.iterator]()`
+/// method.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiterator
+pub fn get_iterator(
+    interpreter: &mut Interpreter,
+    iterable: &Value,
+) -> Result<IteratorRecord, Value> {
+    let symbol_iterator = interpreter.well_known_symbols().iterator_symbol();
+    let method = if iterable.is_null_or_undefined() {
+        Value::undefined()
+    } else {
+        let object = interpreter.to_object(iterable)?;
+        interpreter.get_field_with_receiver(&object, symbol_iterator, iterable)?
+    };
+    if !method.is_function() {
+        let message = if iterable.is_object() {
+            "object is not iterable".to_string()
+        } else {
+            format!("{} is not iterable", iterable)
+        };
+        return Err(interpreter.construct_type_error(message));
+    }
+
+    let iterator_object = interpreter.call(&method, iterable, &[])?;
+    if !iterator_object.is_object() {
+        return Err(interpreter.construct_type_error("iterator is not an object"));
+    }
+
+    let next_function = interpreter.get_field(&iterator_object, "next")?;
+    Ok(IteratorRecord::new(iterator_object, next_function))
+}
+
+/// Collects the values of an iterable object, using its `[Symbol.iterator]()` method.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterabletolist
+pub fn iterable_to_list(
+    interpreter: &mut Interpreter,
+    iterable: &Value,
+) -> Result<Vec<Value>, Value> {
+    let iterator = get_iterator(interpreter, iterable)?;
+    let mut values = Vec::new();
+    loop {
+        let next = iterator.next(interpreter)?;
+        if next.is_done() {
+            return Ok(values);
+        }
+        values.push(next.into_value());
+    }
+}
+
+/// An iterator, along with the `next()` method used to step through it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-records
+#[derive(Debug)]
+pub struct IteratorRecord {
+    iterator_object: Value,
+    next_function: Value,
+}
+
+impl IteratorRecord {
+    /// Creates a new iterator record.
+    pub fn new(iterator_object: Value, next_function: Value) -> Self {
+        Self {
+            iterator_object,
+            next_function,
+        }
+    }
+
+    /// Gets the iterator object.
+    #[inline]
+    pub fn iterator_object(&self) -> &Value {
+        &self.iterator_object
+    }
+
+    /// Gets the `next()` method of the iterator.
+    #[inline]
+    pub fn next_function(&self) -> &Value {
+        &self.next_function
+    }
+
+    /// Steps through the iterator, getting its next result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iteratornext
+    pub fn next(&self, interpreter: &mut Interpreter) -> Result<IteratorResult, Value> {
+        let result = interpreter.call(&self.next_function, &self.iterator_object, &[])?;
+        if !result.is_object() {
+            return Err(interpreter.construct_type_error("iterator result is not an object"));
+        }
+
+        let done = interpreter.get_field(&result, "done")?.to_boolean();
+        let value = interpreter.get_field(&result, "value")?;
+        Ok(IteratorResult { value, done })
+    }
+
+    /// Closes the iterator before it's done, calling its `return()` method if it has one.
+    ///
+    /// The `completion` is the result of the code that stopped iterating. An error thrown by the
+    /// `return()` method only replaces it if it isn't an error itself.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iteratorclose
+    pub fn close<T>(
+        &self,
+        completion: Result<T, Value>,
+        interpreter: &mut Interpreter,
+    ) -> Result<T, Value> {
+        let inner_result = match interpreter.get_field(&self.iterator_object, "return") {
+            Ok(return_method) if return_method.is_null_or_undefined() => return completion,
+            Ok(return_method) => interpreter.call(&return_method, &self.iterator_object, &[]),
+            Err(error) => Err(error),
+        };
+        let completion = completion?;
+        if !inner_result?.is_object() {
+            return Err(interpreter.construct_type_error("iterator result is not an object"));
+        }
+
+        Ok(completion)
+    }
+}
+
+/// The result of a step of an iterator.
+#[derive(Debug)]
+pub struct IteratorResult {
+    value: Value,
+    done: bool,
+}
+
+impl IteratorResult {
+    /// Gets the value produced by the iterator.
+    #[inline]
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Consumes the result, returning the value produced by the iterator.
+    #[inline]
+    pub fn into_value(self) -> Value {
+        self.value
+    }
+
+    /// Checks if the iterator is done, in which case the value is its return value.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
@@ -230,7 +230,7 @@ fn json_parse_array_with_reviver() {
             if (typeof v == 'number') {
                 return v * 2;
             } else {
-                v
+                return v;
         }})"#,
     )
     .unwrap();
@@ -0,0 +1,158 @@
+//! This module implements the `MapIterator` object, returned by `Map.prototype.entries()`,
+//! `keys()`, `values()` and `[Symbol.iterator]()`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-map-iterator-objects
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::create_iter_result_object,
+        object::ObjectData,
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+        Array,
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+/// What a `MapIterator` produces for each entry of the map.
+#[derive(Debug, Clone, Copy, Finalize, PartialEq)]
+pub enum MapIterationKind {
+    Key,
+    Value,
+    KeyAndValue,
+}
+
+unsafe impl Trace for MapIterationKind {
+    unsafe_empty_trace!();
+}
+
+/// The internal state of a `MapIterator` object.
+///
+/// The iterated map is set to `undefined` once the iterator is done.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct MapIterator {
+    map: Value,
+    /// The position in the insertion order of the map of the next entry to visit.
+    cursor: u64,
+    kind: MapIterationKind,
+}
+
+impl MapIterator {
+    /// Creates a new iterator over the entries of the given map.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createmapiterator
+    pub(crate) fn create_map_iterator(
+        interpreter: &Interpreter,
+        map: Value,
+        kind: MapIterationKind,
+    ) -> Value {
+        Value::new_object_from_prototype(
+            interpreter.iterator_prototypes().map_iterator().clone(),
+            ObjectData::MapIterator(Self {
+                map,
+                cursor: 0,
+                kind,
+            }),
+        )
+    }
+
+    /// `%MapIteratorPrototype%.next()`
+    ///
+    /// Gets the next result of the iterator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%mapiteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        let (map, cursor, kind) = match this.as_object() {
+            Some(object) => match object.data {
+                ObjectData::MapIterator(ref iterator) => {
+                    (iterator.map.clone(), iterator.cursor, iterator.kind)
+                }
+                _ => return interpreter.throw_type_error("'this' is not a MapIterator"),
+            },
+            None => return interpreter.throw_type_error("'this' is not a MapIterator"),
+        };
+
+        let entry = map.as_object().and_then(|object| {
+            object
+                .as_map_ref()
+                .and_then(|map| map.get_from(cursor))
+                .map(|(key, value, next)| (key.clone(), value.clone(), next))
+        });
+
+        let (key, value) = if let Some((key, value, next)) = entry {
+            if let Some(mut object) = this.as_object_mut() {
+                if let ObjectData::MapIterator(ref mut iterator) = object.data {
+                    iterator.cursor = next;
+                }
+            }
+            (key, value)
+        } else {
+            if let Some(mut object) = this.as_object_mut() {
+                if let ObjectData::MapIterator(ref mut iterator) = object.data {
+                    iterator.map = Value::undefined();
+                }
+            }
+            return Ok(create_iter_result_object(
+                interpreter,
+                Value::undefined(),
+                true,
+            ));
+        };
+
+        let value = match kind {
+            MapIterationKind::Key => key,
+            MapIterationKind::Value => value,
+            MapIterationKind::KeyAndValue => {
+                let entry = Array::new_array(interpreter)?;
+                Array::add_to_array_object(&entry, &[key, value])?
+            }
+        };
+        Ok(create_iter_result_object(interpreter, value, false))
+    }
+
+    /// Creates the `%MapIteratorPrototype%` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%mapiteratorprototype%-object
+    pub(crate) fn create_prototype(
+        interpreter: &mut Interpreter,
+        iterator_prototype: Value,
+    ) -> Value {
+        let _timer = BoaProfiler::global().start_event("MapIterator", "init");
+        let global = interpreter.global();
+        let prototype = Value::new_object(Some(global));
+        if let Some(mut object) = prototype.as_object_mut() {
+            object.set_prototype(iterator_prototype);
+        }
+
+        make_builtin_fn(Self::next, "next", &prototype, 0, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from("Map Iterator"),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        prototype
+    }
+}
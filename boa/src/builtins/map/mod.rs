@@ -10,8 +10,10 @@ use crate::{
     exec::Interpreter,
     BoaProfiler,
 };
+use map_iterator::{MapIterationKind, MapIterator};
 use ordered_map::OrderedMap;
 
+pub mod map_iterator;
 pub mod ordered_map;
 #[cfg(test)]
 mod tests;
@@ -140,8 +142,17 @@ impl Map {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.clear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/clear
-    pub(crate) fn clear(this: &Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
-        this.set_data(ObjectData::Map(OrderedMap::new()));
+    pub(crate) fn clear(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(map) = object.as_map_mut() {
+                map.clear();
+            } else {
+                return Err(ctx.construct_type_error("'this' is not a Map"));
+            }
+        } else {
+            return Err(ctx.construct_type_error("'this' is not a Map"));
+        }
 
         Self::set_size(this, 0);
 
@@ -197,20 +208,92 @@ impl Map {
         let callback_arg = &args[0];
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        if let Value::Object(ref object) = this {
-            let object = object.borrow();
-            if let Some(map) = object.as_map_ref().cloned() {
-                for (key, value) in map {
-                    let arguments = [value, key, this.clone()];
+        let mut cursor = 0;
+        while let Some((key, value)) = Self::next_entry(this, &mut cursor) {
+            let arguments = [value, key, this.clone()];
 
-                    interpreter.call(callback_arg, &this_arg, &arguments)?;
-                }
-            }
+            interpreter.call(callback_arg, &this_arg, &arguments)?;
         }
 
         Ok(Value::Undefined)
     }
 
+    /// Helper function to get the next entry of the map from `cursor`, as the map is now, and move
+    /// the cursor past it.
+    ///
+    /// Iterating with this, instead of over a copy of the entries, visits the entries that are
+    /// added by callbacks while iterating and skips the ones they delete, like the specification
+    /// requires.
+    fn next_entry(map: &Value, cursor: &mut u64) -> Option<(Value, Value)> {
+        let (key, value, next) = map.as_object().and_then(|object| {
+            object
+                .as_map_ref()
+                .and_then(|map| map.get_from(*cursor))
+                .map(|(key, value, next)| (key.clone(), value.clone(), next))
+        })?;
+        *cursor = next;
+        Some((key, value))
+    }
+
+    /// Helper function to create an iterator over the entries of a map.
+    fn create_iterator(
+        this: &Value,
+        kind: MapIterationKind,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        if !this.as_object().map_or(false, |object| object.is_map()) {
+            return interpreter.throw_type_error("'this' is not a Map");
+        }
+        Ok(MapIterator::create_map_iterator(
+            interpreter,
+            this.clone(),
+            kind,
+        ))
+    }
+
+    /// `Map.prototype.entries( )`
+    ///
+    /// The entries method returns an iterator over the `[key, value]` pairs of the map, in
+    /// insertion order. It is also the `[Symbol.iterator]()` method of maps.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/entries
+    pub(crate) fn entries(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        Self::create_iterator(this, MapIterationKind::KeyAndValue, interpreter)
+    }
+
+    /// `Map.prototype.keys( )`
+    ///
+    /// The keys method returns an iterator over the keys of the map, in insertion order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/keys
+    pub(crate) fn keys(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        Self::create_iterator(this, MapIterationKind::Key, interpreter)
+    }
+
+    /// `Map.prototype.values( )`
+    ///
+    /// The values method returns an iterator over the values of the map, in insertion order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/values
+    pub(crate) fn values(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        Self::create_iterator(this, MapIterationKind::Value, interpreter)
+    }
+
     /// Helper function to get a key-value pair from an array.
     fn get_key_value(value: &Value) -> Option<(Value, Value)> {
         if let Value::Object(object) = value {
@@ -297,6 +380,14 @@ impl Map {
         make_builtin_fn(Self::clear, "clear", &prototype, 0, interpreter);
         make_builtin_fn(Self::has, "has", &prototype, 1, interpreter);
         make_builtin_fn(Self::for_each, "forEach", &prototype, 1, interpreter);
+        make_builtin_fn(Self::entries, "entries", &prototype, 0, interpreter);
+        make_builtin_fn(Self::keys, "keys", &prototype, 0, interpreter);
+        make_builtin_fn(Self::values, "values", &prototype, 0, interpreter);
+
+        // `Map.prototype[Symbol.iterator]` is the same function object as `entries`.
+        let symbol_iterator = interpreter.well_known_symbols().iterator_symbol();
+        let entries = prototype.get_field("entries");
+        prototype.set_field(symbol_iterator, entries);
 
         let map_object = make_constructor_fn(
             Self::NAME,
@@ -1,34 +1,52 @@
 use gc::{custom_trace, Finalize, Trace};
-use indexmap::{map::IntoIter, map::Iter, map::IterMut, IndexMap};
+use indexmap::{
+    map::{IntoIter, Iter, IterMut, Keys, ValuesMut},
+    Equivalent, IndexMap,
+};
 use std::collections::hash_map::RandomState;
 use std::fmt::Debug;
 use std::hash::{BuildHasher, Hash};
 
-/// A newtype wrapping indexmap::IndexMap
+/// A wrapper of indexmap::IndexMap, which also numbers its entries in the order they were
+/// inserted in.
+///
+/// The number of an entry, unlike its index, doesn't change when the entries before it are
+/// removed, so it can be used as a cursor to iterate over a map that is modified while iterating.
 #[derive(Clone)]
-pub struct OrderedMap<K, V, S = RandomState>(IndexMap<K, V, S>)
+pub struct OrderedMap<K, V, S = RandomState>
 where
-    K: Hash + Eq;
+    K: Hash + Eq,
+{
+    map: IndexMap<K, V, S>,
+    /// The insertion numbers of the entries, by index.
+    sequences: Vec<u64>,
+    /// The insertion number of the next entry.
+    next_sequence: u64,
+}
 
 impl<K: Eq + Hash + Trace, V: Trace, S: BuildHasher> Finalize for OrderedMap<K, V, S> {}
 unsafe impl<K: Eq + Hash + Trace, V: Trace, S: BuildHasher> Trace for OrderedMap<K, V, S> {
     custom_trace!(this, {
-        for (k, v) in this.0.iter() {
+        for (k, v) in this.map.iter() {
             mark(k);
             mark(v);
         }
     });
 }
 
-impl<K: Hash + Eq + Debug, V: Debug> Debug for OrderedMap<K, V> {
+impl<K: Hash + Eq + Debug, V: Debug, S: BuildHasher> Debug for OrderedMap<K, V, S> {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        self.0.fmt(formatter)
+        self.map.fmt(formatter)
     }
 }
 
-impl<K: Hash + Eq, V> Default for OrderedMap<K, V> {
+impl<K: Hash + Eq, V, S: BuildHasher + Default> Default for OrderedMap<K, V, S> {
     fn default() -> Self {
-        Self::new()
+        OrderedMap {
+            map: IndexMap::default(),
+            sequences: Vec::new(),
+            next_sequence: 0,
+        }
     }
 }
 
@@ -37,25 +55,35 @@ where
     K: Hash + Eq,
 {
     pub fn new() -> Self {
-        OrderedMap(IndexMap::new())
+        Self::default()
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        OrderedMap(IndexMap::with_capacity(capacity))
+        OrderedMap {
+            map: IndexMap::with_capacity(capacity),
+            sequences: Vec::with_capacity(capacity),
+            next_sequence: 0,
+        }
     }
+}
 
+impl<K, V, S> OrderedMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
     /// Return the number of key-value pairs in the map.
     ///
     /// Computes in **O(1)** time.
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.map.len()
     }
 
     /// Returns true if the map contains no elements.
     ///
     /// Computes in **O(1)** time.
     pub fn is_empty(&self) -> bool {
-        self.0.len() == 0
+        self.map.len() == 0
     }
 
     /// Insert a key-value pair in the map.
@@ -69,7 +97,12 @@ where
     ///
     /// Computes in **O(1)** time (amortized average).
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
-        self.0.insert(key, value)
+        let (_, old_value) = self.map.insert_full(key, value);
+        if old_value.is_none() {
+            self.sequences.push(self.next_sequence);
+            self.next_sequence += 1;
+        }
+        old_value
     }
 
     /// Remove the key-value pair equivalent to `key` and return
@@ -82,28 +115,95 @@ where
     /// Return `None` if `key` is not in map.
     ///
     /// Computes in **O(n)** time (average).
-    pub fn remove(&mut self, key: &K) -> Option<V> {
-        self.0.shift_remove(key)
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        let (index, _, value) = self.map.shift_remove_full(key)?;
+        self.sequences.remove(index);
+        Some(value)
+    }
+
+    /// Remove all the key-value pairs of the map.
+    ///
+    /// The entries inserted afterwards are still numbered after the removed ones.
+    ///
+    /// Computes in **O(n)** time.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.sequences.clear();
     }
 
     /// Return a reference to the value stored for `key`, if it is present,
     /// else `None`.
     ///
     /// Computes in **O(1)** time (average).
-    pub fn get(&self, key: &K) -> Option<&V> {
-        self.0.get(key)
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.map.get(key)
+    }
+
+    /// Return a mutable reference to the value stored for `key`, if it is
+    /// present, else `None`.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.map.get_mut(key)
+    }
+
+    /// Get a key-value pair by index
+    ///
+    /// Valid indices are *0 <= index < self.len()*
+    ///
+    /// Computes in **O(1)** time.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.map.get_index(index)
+    }
+
+    /// Get the first key-value pair inserted at or after the position `cursor` of the insertion
+    /// order, along with the position after it.
+    ///
+    /// Iterating with a cursor that starts at 0 visits each entry once, even if entries are
+    /// inserted or removed while iterating.
+    ///
+    /// Computes in **O(log(n))** time.
+    pub fn get_from(&self, cursor: u64) -> Option<(&K, &V, u64)> {
+        let index = self
+            .sequences
+            .partition_point(|&sequence| sequence < cursor);
+        let (key, value) = self.map.get_index(index)?;
+        Some((key, value, self.sequences[index] + 1))
     }
 
     /// Return an iterator over the key-value pairs of the map, in their order
     pub fn iter(&self) -> Iter<'_, K, V> {
-        self.0.iter()
+        self.map.iter()
+    }
+
+    /// Return an iterator over the keys of the map, in their order
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.map.keys()
+    }
+
+    /// Return an iterator over mutable references to the values of the map,
+    /// in their order
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        self.map.values_mut()
     }
 
     /// Return `true` if an equivalent to `key` exists in the map.
     ///
     /// Computes in **O(1)** time (average).
-    pub fn contains_key(&self, key: &K) -> bool {
-        self.0.contains_key(key)
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Equivalent<K>,
+    {
+        self.map.contains_key(key)
     }
 }
 
@@ -115,7 +215,7 @@ where
     type Item = (&'a K, &'a V);
     type IntoIter = Iter<'a, K, V>;
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+        self.map.iter()
     }
 }
 
@@ -127,7 +227,7 @@ where
     type Item = (&'a K, &'a mut V);
     type IntoIter = IterMut<'a, K, V>;
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter_mut()
+        self.map.iter_mut()
     }
 }
 
@@ -139,6 +239,6 @@ where
     type Item = (K, V);
     type IntoIter = IntoIter<K, V>;
     fn into_iter(self) -> IntoIter<K, V> {
-        self.0.into_iter()
+        self.map.into_iter()
     }
 }
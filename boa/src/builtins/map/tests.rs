@@ -228,10 +228,89 @@ fn recursive_display() {
 }
 
 #[test]
-#[should_panic]
 fn not_a_function() {
     let realm = Realm::create();
     let mut engine = Interpreter::new(realm);
-    let init = "let map = Map()";
+    let init = r#"
+        try {
+            let map = Map()
+        } catch(e) {
+            e.toString()
+        }
+    "#;
+    assert_eq!(
+        forward(&mut engine, init),
+        "\"TypeError: Map constructor requires 'new'\""
+    );
+}
+
+#[test]
+fn iterators() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let map = new Map([["a", 1], ["b", 2]]);
+        let entries = [];
+        for (let [key, value] of map) {
+            entries.push(key + value);
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "entries.join()"), "\"a1,b2\"");
+    assert_eq!(forward(&mut engine, "[...map.keys()].join()"), "\"a,b\"");
+    assert_eq!(forward(&mut engine, "[...map.values()].join()"), "\"1,2\"");
+    assert_eq!(
+        forward(&mut engine, "[...map.entries()].join(\";\")"),
+        "\"a,1;b,2\""
+    );
+    assert_eq!(
+        forward(&mut engine, "map[Symbol.iterator] === map.entries"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "map.keys()[Symbol.toStringTag]"),
+        "\"Map Iterator\""
+    );
+}
+
+#[test]
+fn delete_while_iterating() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let visited = [];
+        let map = new Map([[1, "a"], [2, "b"], [3, "c"]]);
+        for (const [key] of map) {
+            visited.push(key);
+            map.delete(key);
+        }
+
+        let each = [];
+        let other = new Map([[1, "a"], [2, "b"], [3, "c"]]);
+        other.forEach((value, key) => {
+            each.push(value);
+            other.delete(key);
+            if (key === 3) {
+                other.set(4, "d");
+            }
+        });
+
+        let cleared = new Map([[1, "a"], [2, "b"]]);
+        let it = cleared.keys();
+        it.next();
+        cleared.clear();
+        cleared.set(3, "c");
+
+        let shifted = new Map([[1, "a"], [2, "b"], [3, "c"]]);
+        let iter = shifted.values();
+        iter.next();
+        shifted.delete(1);
+        "#;
     forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "visited.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "map.size"), "0");
+    assert_eq!(forward(&mut engine, "each.join()"), "\"a,b,c,d\"");
+    assert_eq!(forward(&mut engine, "it.next().value"), "3");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+    assert_eq!(forward(&mut engine, "iter.next().value"), "\"b\"");
 }
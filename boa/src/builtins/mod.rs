@@ -1,34 +1,53 @@
 //! Builtins live here, such as Object, String, Math, etc.
 
 pub mod array;
+pub mod array_buffer;
+pub mod async_function;
 pub mod bigint;
 pub mod boolean;
 pub mod console;
+pub mod data_view;
 pub mod date;
 pub mod error;
+pub mod eval;
+pub mod finalization_registry;
 pub mod function;
+pub mod generator;
 pub mod global_this;
 pub mod infinity;
+pub mod iterable;
 pub mod json;
 pub mod map;
 pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
 pub mod property;
+pub mod proxy;
+pub mod reflect;
 pub mod regexp;
+pub mod set;
 pub mod string;
 pub mod symbol;
+pub mod typed_array;
 pub mod undefined;
 pub mod value;
+pub mod weak_map;
+pub mod weak_ref;
+pub mod weak_set;
 
 pub(crate) use self::{
     array::Array,
+    array_buffer::ArrayBuffer,
     bigint::BigInt,
     boolean::Boolean,
     console::Console,
+    data_view::DataView,
     date::Date,
-    error::{Error, RangeError, ReferenceError, SyntaxError, TypeError},
+    error::{AggregateError, Error, RangeError, ReferenceError, SyntaxError, TypeError},
+    eval::Eval,
+    finalization_registry::FinalizationRegistry,
     global_this::GlobalThis,
     infinity::Infinity,
     json::Json,
@@ -36,11 +55,19 @@ pub(crate) use self::{
     math::Math,
     nan::NaN,
     number::Number,
+    promise::Promise,
+    proxy::Proxy,
+    reflect::Reflect,
     regexp::RegExp,
+    set::Set,
     string::String,
     symbol::Symbol,
+    typed_array::TypedArray,
     undefined::Undefined,
     value::{ResultValue, Value},
+    weak_map::WeakMap,
+    weak_ref::WeakRef,
+    weak_set::WeakSet,
 };
 use crate::Interpreter;
 
@@ -52,16 +79,27 @@ pub fn init(interpreter: &mut Interpreter) {
         function::init,
         object::init,
         Array::init,
+        ArrayBuffer::init,
         BigInt::init,
         Boolean::init,
+        DataView::init,
         Date::init,
+        Eval::init,
+        FinalizationRegistry::init,
         Json::init,
         Map::init,
         Math::init,
         Number::init,
+        Promise::init,
+        Proxy::init,
+        Reflect::init,
         RegExp::init,
+        Set::init,
         String::init,
         Symbol::init,
+        WeakMap::init,
+        WeakRef::init,
+        WeakSet::init,
         Console::init,
         // Global error types.
         Error::init,
@@ -69,6 +107,7 @@ pub fn init(interpreter: &mut Interpreter) {
         ReferenceError::init,
         TypeError::init,
         SyntaxError::init,
+        AggregateError::init,
         // Global properties.
         NaN::init,
         Infinity::init,
@@ -86,4 +125,15 @@ pub fn init(interpreter: &mut Interpreter) {
             _ => unreachable!("expect global object"),
         }
     }
+
+    // The typed array constructors share the `%TypedArray%` intrinsic, so they are initialized
+    // together, once `Array.prototype.toString` exists.
+    for (name, value) in TypedArray::init(interpreter) {
+        match interpreter.global() {
+            Value::Object(ref global_object) => {
+                global_object.borrow_mut().insert_field(name, value);
+            }
+            _ => unreachable!("expect global object"),
+        }
+    }
 }
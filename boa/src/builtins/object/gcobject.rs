@@ -1,10 +1,14 @@
 //! This module implements the `GcObject` structure.
 //!
-//! The `GcObject` is a garbage collected Object.
+//! The `GcObject` is a garbage collected Object, and the `WeakGcObject` a weak reference to one.
 
 use super::Object;
-use gc::{Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace};
-use std::fmt::{self, Display};
+use gc::{unsafe_empty_trace, Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::{self, Debug, Display},
+    rc::{self, Rc},
+};
 
 /// Garbage collected `Object`.
 #[derive(Debug, Trace, Finalize, Clone)]
@@ -41,6 +45,19 @@ impl GcObject {
     pub fn equals(lhs: &Self, rhs: &Self) -> bool {
         std::ptr::eq(lhs.as_ref(), rhs.as_ref())
     }
+
+    /// Creates a weak reference to the object.
+    #[inline]
+    pub fn downgrade(&self) -> WeakGcObject {
+        WeakGcObject::new(self)
+    }
+
+    /// Gets the address of the garbage collected memory, which identifies the object while it is
+    /// alive.
+    #[inline]
+    pub(crate) fn address(&self) -> usize {
+        self.as_ref() as *const GcCell<Object> as usize
+    }
 }
 
 impl AsRef<GcCell<Object>> for GcObject {
@@ -71,3 +88,135 @@ impl Display for BorrowMutError {
         Display::fmt("Object already borrowed", f)
     }
 }
+
+thread_local! {
+    /// The number of objects with weak references to them that have been collected on this thread.
+    static COLLECTED_OBJECTS: Cell<usize> = Cell::new(0);
+}
+
+/// Gets the number of objects with weak references to them that have been collected on this
+/// thread, which changes after a garbage collection collects one of them.
+pub(crate) fn collected_objects() -> usize {
+    COLLECTED_OBJECTS.with(Cell::get)
+}
+
+/// The liveness of an object with weak references to it, dropped along with the object.
+#[derive(Debug)]
+struct Liveness;
+
+impl Drop for Liveness {
+    fn drop(&mut self) {
+        COLLECTED_OBJECTS.with(|collected| collected.set(collected.get().wrapping_add(1)));
+    }
+}
+
+/// The token that the weak references to an object check to know if it is still alive.
+///
+/// It is created along with the first weak reference to the object, and lives as long as the
+/// object does. A clone of the object is a different object, with its own token.
+#[derive(Debug, Default, Finalize)]
+pub(crate) struct WeakToken(RefCell<Option<Rc<Liveness>>>);
+
+impl WeakToken {
+    /// Gets a weak pointer to the liveness of the object, which can't be upgraded once the object
+    /// is collected.
+    fn downgrade(&self) -> rc::Weak<Liveness> {
+        let mut liveness = self.0.borrow_mut();
+        Rc::downgrade(liveness.get_or_insert_with(|| Rc::new(Liveness)))
+    }
+}
+
+impl Clone for WeakToken {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+unsafe impl Trace for WeakToken {
+    unsafe_empty_trace!();
+}
+
+/// A weak reference to a garbage collected `Object`.
+///
+/// Unlike a `GcObject`, it doesn't keep the object alive: once the object is collected, `upgrade`
+/// returns `None`.
+pub struct WeakGcObject {
+    /// An unrooted pointer to the object, which is never traced, and only dereferenced while the
+    /// object is alive.
+    object: Option<Gc<GcCell<Object>>>,
+    /// The liveness of the object, which can be upgraded for as long as the object is alive.
+    liveness: rc::Weak<Liveness>,
+    /// The address of the object.
+    address: usize,
+}
+
+impl WeakGcObject {
+    /// Creates a weak reference to the given object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is mutably borrowed.
+    pub fn new(object: &GcObject) -> Self {
+        let liveness = object.borrow().weak_token.downgrade();
+        Self {
+            object: Some(Self::unrooted(object)),
+            liveness,
+            address: object.address(),
+        }
+    }
+
+    /// Creates a pointer to the object that doesn't keep it alive.
+    fn unrooted(object: &GcObject) -> Gc<GcCell<Object>> {
+        let gc = object.0.clone();
+        // SAFETY: the pointer was rooted when it was cloned, and it is never rooted or traced
+        // again, so the collector doesn't see it and dropping it doesn't touch the object.
+        unsafe { gc.unroot() };
+        gc
+    }
+
+    /// Gets the object, if it hasn't been collected.
+    pub fn upgrade(&self) -> Option<GcObject> {
+        // The liveness is dropped with the object, so the object is still allocated if it can be
+        // upgraded.
+        self.liveness.upgrade()?;
+        self.object.as_ref().map(|object| GcObject(object.clone()))
+    }
+
+    /// Checks if the object is still alive.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.liveness.strong_count() > 0
+    }
+
+    /// Gets the address of the object, which identifies it while it is alive.
+    #[inline]
+    pub(crate) fn address(&self) -> usize {
+        self.address
+    }
+}
+
+impl Clone for WeakGcObject {
+    fn clone(&self) -> Self {
+        Self {
+            object: self.upgrade().as_ref().map(Self::unrooted),
+            liveness: self.liveness.clone(),
+            address: self.address,
+        }
+    }
+}
+
+impl Debug for WeakGcObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakGcObject")
+            .field("address", &self.address)
+            .field("alive", &self.is_alive())
+            .finish()
+    }
+}
+
+impl Finalize for WeakGcObject {}
+
+// The object is not traced, so that the weak reference doesn't keep it alive.
+unsafe impl Trace for WeakGcObject {
+    unsafe_empty_trace!();
+}
@@ -8,7 +8,8 @@
 use crate::builtins::{
     object::Object,
     property::{Attribute, Property, PropertyKey},
-    value::{same_value, RcString, Value},
+    typed_array::{canonical_numeric_index, TypedArrayData},
+    value::{same_value, Value},
 };
 use crate::BoaProfiler;
 
@@ -20,6 +21,10 @@ impl Object {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-hasproperty-p
     pub fn has_property(&self, property_key: &PropertyKey) -> bool {
+        if let Some((array, index)) = self.typed_array_index(property_key) {
+            return array.is_valid_index(index);
+        }
+
         let prop = self.get_own_property(property_key);
         if prop.value.is_none() {
             let parent: Value = self.get_prototype_of();
@@ -61,64 +66,44 @@ impl Object {
     }
 
     /// Delete property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-delete-p
     pub fn delete(&mut self, property_key: &PropertyKey) -> bool {
+        if let Some((array, index)) = self.typed_array_index(property_key) {
+            return !array.is_valid_index(index);
+        }
+
         let desc = self.get_own_property(property_key);
-        if desc
-            .value
-            .clone()
-            .expect("unable to get value")
-            .is_undefined()
-        {
+        if desc.is_none() {
             return true;
         }
         if desc.configurable_or(false) {
-            self.remove_property(&property_key.to_string());
+            match property_key {
+                PropertyKey::String(ref string) => self.remove_property(string),
+                PropertyKey::Symbol(ref symbol) => {
+                    self.symbol_properties.remove(symbol);
+                }
+            }
             return true;
         }
 
         false
     }
 
-    /// [[Get]]
-    /// https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver
-    pub fn get(&self, property_key: &PropertyKey) -> Value {
-        let desc = self.get_own_property(property_key);
-        if desc.value.clone().is_none()
-            || desc
-                .value
-                .clone()
-                .expect("Failed to get object")
-                .is_undefined()
-        {
-            // parent will either be null or an Object
-            let parent = self.get_prototype_of();
-            if parent.is_null() {
-                return Value::undefined();
-            }
-
-            let parent_obj = Object::from(&parent).expect("Failed to get object");
-
-            return parent_obj.get(property_key);
-        }
-
-        if desc.is_data_descriptor() {
-            return desc.value.clone().expect("failed to extract value");
-        }
-
-        let getter = desc.get.clone();
-        if getter.is_none() || getter.expect("Failed to get object").is_undefined() {
-            return Value::undefined();
-        }
-
-        // TODO: Call getter from here!
-        Value::undefined()
-    }
-
     /// [[Set]]
     /// <https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-set-p-v-receiver>
     pub fn set(&mut self, property_key: &PropertyKey, val: Value) -> bool {
         let _timer = BoaProfiler::global().start_event("Object::set", "object");
 
+        // The value should already be converted by `Interpreter::set_field`.
+        if let Some((array, index)) = self.typed_array_index(property_key) {
+            array.set_element(index, &val);
+            return true;
+        }
+
         // Fetch property key
         let mut own_desc = self.get_own_property(property_key);
         // [2]
@@ -143,13 +128,9 @@ impl Object {
             return self.define_own_property(property_key, own_desc);
         }
         // [4]
+        // Setters need the interpreter to run, they are called by `Interpreter::set_field`.
         debug_assert!(own_desc.is_accessor_descriptor());
-        match own_desc.set {
-            None => false,
-            Some(_) => {
-                unimplemented!();
-            }
-        }
+        false
     }
 
     /// Define an own property.
@@ -161,96 +142,29 @@ impl Object {
     pub fn define_own_property(&mut self, property_key: &PropertyKey, desc: Property) -> bool {
         let _timer = BoaProfiler::global().start_event("Object::define_own_property", "object");
 
-        let mut current = self.get_own_property(property_key);
-        let extensible = self.is_extensible();
-
-        // https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
-        // There currently isn't a property, lets create a new one
-        if current.value.is_none() || current.value.as_ref().expect("failed").is_undefined() {
-            if !extensible {
-                return false;
-            }
-
-            self.insert_property(property_key, desc);
-            return true;
-        }
-        // If every field is absent we don't need to set anything
-        if desc.is_none() {
-            return true;
-        }
-
-        // 4
-        if !current.configurable_or(false) {
-            if desc.configurable_or(false) {
+        if let Some((array, index)) = self.typed_array_index(property_key) {
+            if !array.is_valid_index(index)
+                || !desc.configurable_or(true)
+                || !desc.enumerable_or(true)
+                || desc.is_accessor_descriptor()
+                || !desc.writable_or(true)
+            {
                 return false;
             }
-
-            if desc.enumerable_or(false) != current.enumerable_or(false) {
-                return false;
+            if let Some(ref value) = desc.value {
+                array.set_element(index, value);
             }
+            return true;
         }
 
-        // 5
-        if desc.is_generic_descriptor() {
-            // 6
-        } else if current.is_data_descriptor() != desc.is_data_descriptor() {
-            // a
-            if !current.configurable() {
-                return false;
-            }
-            // b
-            if current.is_data_descriptor() {
-                // Convert to accessor
-                current.value = None;
-                current.attribute.remove(Attribute::WRITABLE);
-            } else {
-                // c
-                // convert to data
-                current.get = None;
-                current.set = None;
+        let current = self.get_own_property(property_key);
+        match validate_and_apply_property_descriptor(self.is_extensible(), current, desc) {
+            Some(property) => {
+                self.insert_property(property_key, property);
+                true
             }
-
-            self.insert_property(property_key, current);
-        // 7
-        } else if current.is_data_descriptor() && desc.is_data_descriptor() {
-            // a
-            if !current.configurable() && !current.writable() {
-                if desc.writable_or(false) {
-                    return false;
-                }
-
-                if desc.value.is_some()
-                    && !same_value(
-                        &desc.value.clone().unwrap(),
-                        &current.value.clone().unwrap(),
-                    )
-                {
-                    return false;
-                }
-
-                return true;
-            }
-        // 8
-        } else {
-            if !current.configurable() {
-                if desc.set.is_some()
-                    && !same_value(&desc.set.clone().unwrap(), &current.set.clone().unwrap())
-                {
-                    return false;
-                }
-
-                if desc.get.is_some()
-                    && !same_value(&desc.get.clone().unwrap(), &current.get.clone().unwrap())
-                {
-                    return false;
-                }
-            }
-
-            return true;
+            None => false,
         }
-        // 9
-        self.insert_property(property_key, desc);
-        true
     }
 
     /// The specification returns a Property Descriptor or Undefined.
@@ -264,6 +178,24 @@ impl Object {
     pub fn get_own_property(&self, property_key: &PropertyKey) -> Property {
         let _timer = BoaProfiler::global().start_event("Object::get_own_property", "object");
 
+        if let Some((array, index)) = self.typed_array_index(property_key) {
+            return array
+                .get_element(index)
+                .map_or_else(Property::empty, |value| {
+                    Property::data_descriptor(
+                        value,
+                        Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                    )
+                });
+        }
+
+        if let Some(character) = self.string_index(property_key) {
+            return Property::data_descriptor(
+                character.to_string().into(),
+                Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+            );
+        }
+
         // Prop could either be a String or Symbol
         match property_key {
             PropertyKey::String(ref st) => {
@@ -280,24 +212,62 @@ impl Object {
                     d
                 })
             }
-            PropertyKey::Symbol(ref symbol) => self
-                .symbol_properties()
-                .get(&symbol.hash())
-                .map_or_else(Property::empty, |v| {
-                    let mut d = Property::empty();
-                    if v.is_data_descriptor() {
-                        d.value = v.value.clone();
-                    } else {
-                        debug_assert!(v.is_accessor_descriptor());
-                        d.get = v.get.clone();
-                        d.set = v.set.clone();
-                    }
-                    d.attribute = v.attribute;
-                    d
-                }),
+            PropertyKey::Symbol(ref symbol) => {
+                self.symbol_properties()
+                    .get(symbol)
+                    .map_or_else(Property::empty, |v| {
+                        let mut d = Property::empty();
+                        if v.is_data_descriptor() {
+                            d.value = v.value.clone();
+                        } else {
+                            debug_assert!(v.is_accessor_descriptor());
+                            d.get = v.get.clone();
+                            d.set = v.set.clone();
+                        }
+                        d.attribute = v.attribute;
+                        d
+                    })
+            }
         }
     }
 
+    /// Gets the data of the object, if it is a typed array, and the numeric index the property key
+    /// is the canonical string of, if it is one.
+    ///
+    /// The integer-indexed properties of typed arrays are their elements rather than ordinary
+    /// properties, even if the index is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects
+    pub(crate) fn typed_array_index(
+        &self,
+        property_key: &PropertyKey,
+    ) -> Option<(&TypedArrayData, f64)> {
+        let array = self.as_typed_array()?;
+        let index = canonical_numeric_index(property_key)?;
+        Some((array, index))
+    }
+
+    /// Gets the character of the string of the object at the index the property key is, if the
+    /// object is a `String` object.
+    ///
+    /// The characters of `String` objects are read-only, enumerable own properties.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-stringgetownproperty
+    pub(crate) fn string_index(&self, property_key: &PropertyKey) -> Option<char> {
+        let string = self.as_string()?;
+        let index = canonical_numeric_index(property_key)?;
+        if index.fract() != 0.0 || index.is_sign_negative() {
+            return None;
+        }
+        string.chars().nth(index as usize)
+    }
+
     /// `Object.setPropertyOf(obj, prototype)`
     ///
     /// This method sets the prototype (i.e., the internal `[[Prototype]]` property)
@@ -352,11 +322,18 @@ impl Object {
 
     /// Helper function for property insertion.
     #[inline]
-    pub(crate) fn insert_property<N>(&mut self, name: N, p: Property)
+    pub(crate) fn insert_property<K>(&mut self, key: K, p: Property)
     where
-        N: Into<RcString>,
+        K: Into<PropertyKey>,
     {
-        self.properties.insert(name.into(), p);
+        match key.into() {
+            PropertyKey::String(ref string) => {
+                self.properties.insert(string.clone(), p);
+            }
+            PropertyKey::Symbol(ref symbol) => {
+                self.symbol_properties.insert(symbol.clone(), p);
+            }
+        }
     }
 
     /// Helper function for property removal.
@@ -370,17 +347,20 @@ impl Object {
     /// If a field was already in the object with the same name that a `Some` is returned
     /// with that field, otherwise None is retuned.
     #[inline]
-    pub(crate) fn insert_field<N>(&mut self, name: N, value: Value) -> Option<Property>
+    pub(crate) fn insert_field<K>(&mut self, key: K, value: Value) -> Option<Property>
     where
-        N: Into<RcString>,
+        K: Into<PropertyKey>,
     {
-        self.properties.insert(
-            name.into(),
-            Property::data_descriptor(
-                value,
-                Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
-            ),
-        )
+        let property = Property::data_descriptor(
+            value,
+            Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        match key.into() {
+            PropertyKey::String(ref string) => self.properties.insert(string.clone(), property),
+            PropertyKey::Symbol(ref symbol) => {
+                self.symbol_properties.insert(symbol.clone(), property)
+            }
+        }
     }
 
     /// This function returns an Optional reference value to the objects field.
@@ -392,3 +372,142 @@ impl Object {
         self.properties.get(name).and_then(|x| x.value.as_ref())
     }
 }
+
+/// Validates that the property descriptor `desc` can be applied to the property `current` of an
+/// object, and returns the resulting property, or `None` if it can't be applied.
+///
+/// `current` is an empty property if the object doesn't have the property yet. This is also used
+/// to check the invariants of proxy traps, which is the `IsCompatiblePropertyDescriptor`
+/// operation of the specification.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
+pub(crate) fn validate_and_apply_property_descriptor(
+    extensible: bool,
+    mut current: Property,
+    desc: Property,
+) -> Option<Property> {
+    // There currently isn't a property, lets create a new one
+    if current.is_none() {
+        if !extensible {
+            return None;
+        }
+
+        // The absent attributes of the new property are false.
+        let mut property = desc;
+        if !property.attribute.has_enumerable() {
+            property.attribute.set_enumerable(false);
+        }
+        if !property.attribute.has_configurable() {
+            property.attribute.set_configurable(false);
+        }
+        if !property.is_accessor_descriptor() {
+            if !property.attribute.has_writable() {
+                property.attribute.set_writable(false);
+            }
+            if property.value.is_none() {
+                property.value = Some(Value::undefined());
+            }
+        }
+
+        return Some(property);
+    }
+    // If every field is absent we don't need to set anything
+    if desc.is_none() {
+        return Some(current);
+    }
+
+    // 4
+    if !current.configurable_or(false) {
+        if desc.configurable_or(false) {
+            return None;
+        }
+
+        if desc.attribute.has_enumerable()
+            && desc.enumerable_or(false) != current.enumerable_or(false)
+        {
+            return None;
+        }
+    }
+
+    // 5
+    if desc.is_generic_descriptor() {
+        // 6
+    } else if current.is_data_descriptor() != desc.is_data_descriptor() {
+        // a
+        if !current.configurable_or(false) {
+            return None;
+        }
+        // b
+        if current.is_data_descriptor() {
+            // Convert to accessor
+            current.value = None;
+            current.attribute.remove(Attribute::WRITABLE);
+        } else {
+            // c
+            // convert to data
+            current.get = None;
+            current.set = None;
+        }
+    // 7
+    } else if current.is_data_descriptor() && desc.is_data_descriptor() {
+        // a
+        if !current.configurable_or(false) && !current.writable_or(false) {
+            if desc.writable_or(false) {
+                return None;
+            }
+
+            if let (Some(ref value), Some(ref current_value)) = (&desc.value, &current.value) {
+                if !same_value(value, current_value) {
+                    return None;
+                }
+            }
+
+            return Some(current);
+        }
+    // 8
+    } else if !current.configurable_or(false) {
+        let undefined = Value::undefined();
+        if let Some(ref set) = desc.set {
+            if !same_value(set, current.set.as_ref().unwrap_or(&undefined)) {
+                return None;
+            }
+        }
+
+        if let Some(ref get) = desc.get {
+            if !same_value(get, current.get.as_ref().unwrap_or(&undefined)) {
+                return None;
+            }
+        }
+
+        return Some(current);
+    }
+
+    // 9
+    // The fields present in the descriptor replace the ones of the current property.
+    if desc.value.is_some() {
+        current.value = desc.value.clone();
+    }
+    if desc.get.is_some() {
+        current.get = desc.get.clone();
+    }
+    if desc.set.is_some() {
+        current.set = desc.set.clone();
+    }
+    if desc.attribute.has_writable() {
+        current.attribute.set_writable(desc.attribute.writable());
+    }
+    if desc.attribute.has_enumerable() {
+        current
+            .attribute
+            .set_enumerable(desc.attribute.enumerable());
+    }
+    if desc.attribute.has_configurable() {
+        current
+            .attribute
+            .set_configurable(desc.attribute.configurable());
+    }
+    Some(current)
+}
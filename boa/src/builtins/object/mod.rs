@@ -15,18 +15,34 @@
 
 use crate::{
     builtins::{
+        array::array_iterator::ArrayIterator,
+        array_buffer::ArrayBufferData,
+        data_view::DataViewData,
+        finalization_registry::FinalizationRegistry,
         function::Function,
+        generator::Generator,
+        map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
-        property::Property,
+        promise::Promise,
+        property::{Property, PropertyKey},
+        proxy::ProxyData,
+        set::{ordered_set::OrderedSet, set_iterator::SetIterator},
+        string::string_iterator::StringIterator,
+        typed_array::TypedArrayData,
         value::{RcBigInt, RcString, RcSymbol, ResultValue, Value},
+        weak_map::weak_object_map::{Ephemerons, WeakObjectMap, WeakObjectSet},
         BigInt, Date, RegExp,
     },
-    exec::Interpreter,
+    class::NativeData,
+    exec::{Interpreter, ModuleNamespace},
     BoaProfiler,
 };
 use gc::{Finalize, Trace};
-use rustc_hash::FxHashMap;
-use std::fmt::{Debug, Display, Error, Formatter};
+use rustc_hash::FxHasher;
+use std::{
+    fmt::{Debug, Display, Error, Formatter},
+    hash::BuildHasherDefault,
+};
 
 use super::function::{make_builtin_fn, make_constructor_fn};
 use crate::builtins::value::same_value;
@@ -36,11 +52,15 @@ pub mod gcobject;
 pub mod internal_methods;
 mod internal_state;
 
-pub use gcobject::GcObject;
+use gcobject::WeakToken;
+pub use gcobject::{GcObject, WeakGcObject};
 
 #[cfg(test)]
 mod tests;
 
+/// The hasher of the property maps of objects.
+type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
 /// Static `prototype`, usually set on constructors as a key to point to their respective prototype object.
 pub static PROTOTYPE: &str = "prototype";
 
@@ -52,34 +72,55 @@ pub static PROTOTYPE: &str = "prototype";
 pub struct Object {
     /// The type of the object.
     pub data: ObjectData,
-    /// Properties
-    properties: FxHashMap<RcString, Property>,
-    /// Symbol Properties
-    symbol_properties: FxHashMap<u32, Property>,
+    /// Properties, in the order they were created in.
+    properties: OrderedMap<RcString, Property, FxBuildHasher>,
+    /// Symbol properties, in the order they were created in.
+    symbol_properties: OrderedMap<RcSymbol, Property, FxBuildHasher>,
     /// Instance prototype `__proto__`.
     prototype: Value,
     /// Some rust object that stores internal state
     state: Option<InternalStateCell>,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// The token checked by the weak references to the object.
+    weak_token: WeakToken,
+    /// The values that weak maps associate with the object.
+    ephemerons: Ephemerons,
 }
 
 /// Defines the different types of objects.
 #[derive(Debug, Trace, Finalize, Clone)]
 pub enum ObjectData {
     Array,
+    ArrayIterator(ArrayIterator),
+    ArrayBuffer(ArrayBufferData),
+    TypedArray(TypedArrayData),
+    DataView(DataViewData),
     Map(OrderedMap<Value, Value>),
+    MapIterator(MapIterator),
     RegExp(RegExp),
+    Set(OrderedSet<Value>),
+    SetIterator(SetIterator),
+    WeakMap(WeakObjectMap),
+    WeakSet(WeakObjectSet),
+    WeakRef(WeakGcObject),
+    FinalizationRegistry(FinalizationRegistry),
+    Proxy(ProxyData),
     BigInt(RcBigInt),
     Boolean(bool),
     Function(Function),
+    Generator(Generator),
+    Promise(Promise),
     String(RcString),
+    StringIterator(StringIterator),
     Number(f64),
     Symbol(RcSymbol),
     Error,
     Ordinary,
     Date(Date),
     Global,
+    ModuleNamespace(ModuleNamespace),
+    NativeObject(NativeData),
 }
 
 impl Display for ObjectData {
@@ -89,10 +130,25 @@ impl Display for ObjectData {
             "{}",
             match self {
                 Self::Array => "Array",
+                Self::ArrayIterator(_) => "ArrayIterator",
+                Self::ArrayBuffer(_) => "ArrayBuffer",
+                Self::TypedArray(_) => "TypedArray",
+                Self::DataView(_) => "DataView",
                 Self::Function(_) => "Function",
+                Self::Generator(_) => "Generator",
+                Self::Promise(_) => "Promise",
                 Self::RegExp(_) => "RegExp",
                 Self::Map(_) => "Map",
+                Self::MapIterator(_) => "MapIterator",
+                Self::Set(_) => "Set",
+                Self::SetIterator(_) => "SetIterator",
+                Self::WeakMap(_) => "WeakMap",
+                Self::WeakSet(_) => "WeakSet",
+                Self::WeakRef(_) => "WeakRef",
+                Self::FinalizationRegistry(_) => "FinalizationRegistry",
+                Self::Proxy(_) => "Proxy",
                 Self::String(_) => "String",
+                Self::StringIterator(_) => "StringIterator",
                 Self::Symbol(_) => "Symbol",
                 Self::Error => "Error",
                 Self::Ordinary => "Ordinary",
@@ -101,6 +157,8 @@ impl Display for ObjectData {
                 Self::BigInt(_) => "BigInt",
                 Self::Date(_) => "Date",
                 Self::Global => "Global",
+                Self::ModuleNamespace(_) => "ModuleNamespace",
+                Self::NativeObject(_) => "NativeObject",
             }
         )
     }
@@ -112,11 +170,13 @@ impl Default for Object {
     fn default() -> Self {
         Self {
             data: ObjectData::Ordinary,
-            properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             state: None,
             extensible: true,
+            weak_token: WeakToken::default(),
+            ephemerons: Ephemerons::default(),
         }
     }
 }
@@ -133,11 +193,13 @@ impl Object {
 
         Self {
             data: ObjectData::Function(function),
-            properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype,
             state: None,
             extensible: true,
+            weak_token: WeakToken::default(),
+            ephemerons: Ephemerons::default(),
         }
     }
 
@@ -158,11 +220,13 @@ impl Object {
     pub fn boolean(value: bool) -> Self {
         Self {
             data: ObjectData::Boolean(value),
-            properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             state: None,
             extensible: true,
+            weak_token: WeakToken::default(),
+            ephemerons: Ephemerons::default(),
         }
     }
 
@@ -170,11 +234,13 @@ impl Object {
     pub fn number(value: f64) -> Self {
         Self {
             data: ObjectData::Number(value),
-            properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             state: None,
             extensible: true,
+            weak_token: WeakToken::default(),
+            ephemerons: Ephemerons::default(),
         }
     }
 
@@ -185,11 +251,13 @@ impl Object {
     {
         Self {
             data: ObjectData::String(value.into()),
-            properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             state: None,
             extensible: true,
+            weak_token: WeakToken::default(),
+            ephemerons: Ephemerons::default(),
         }
     }
 
@@ -197,11 +265,13 @@ impl Object {
     pub fn bigint(value: RcBigInt) -> Self {
         Self {
             data: ObjectData::BigInt(value),
-            properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             state: None,
             extensible: true,
+            weak_token: WeakToken::default(),
+            ephemerons: Ephemerons::default(),
         }
     }
 
@@ -231,7 +301,11 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
     #[inline]
     pub fn is_callable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_callable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_callable(),
+            ObjectData::Proxy(ref proxy) => proxy.is_callable(),
+            _ => false,
+        }
     }
 
     /// It determines if Object is a function object with a [[Construct]] internal method.
@@ -242,7 +316,11 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
     #[inline]
     pub fn is_constructable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_constructable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_constructable(),
+            ObjectData::Proxy(ref proxy) => proxy.is_constructor(),
+            _ => false,
+        }
     }
 
     /// Checks if it an `Array` object.
@@ -281,6 +359,116 @@ impl Object {
         }
     }
 
+    /// Checks if it is a `Set` object.
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        matches!(self.data, ObjectData::Set(_))
+    }
+
+    #[inline]
+    pub fn as_set_ref(&self) -> Option<&OrderedSet<Value>> {
+        match self.data {
+            ObjectData::Set(ref set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_set_mut(&mut self) -> Option<&mut OrderedSet<Value>> {
+        match &mut self.data {
+            ObjectData::Set(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map_mut(&mut self) -> Option<&mut WeakObjectMap> {
+        match &mut self.data {
+            ObjectData::WeakMap(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_set_mut(&mut self) -> Option<&mut WeakObjectSet> {
+        match &mut self.data {
+            ObjectData::WeakSet(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is an `ArrayBuffer` object.
+    #[inline]
+    pub fn is_array_buffer(&self) -> bool {
+        matches!(self.data, ObjectData::ArrayBuffer(_))
+    }
+
+    #[inline]
+    pub fn as_array_buffer(&self) -> Option<&ArrayBufferData> {
+        match self.data {
+            ObjectData::ArrayBuffer(ref buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_array_buffer_mut(&mut self) -> Option<&mut ArrayBufferData> {
+        match self.data {
+            ObjectData::ArrayBuffer(ref mut buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a typed array object, like an `Uint8Array`.
+    #[inline]
+    pub fn is_typed_array(&self) -> bool {
+        matches!(self.data, ObjectData::TypedArray(_))
+    }
+
+    #[inline]
+    pub fn as_typed_array(&self) -> Option<&TypedArrayData> {
+        match self.data {
+            ObjectData::TypedArray(ref array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `DataView` object.
+    #[inline]
+    pub fn is_data_view(&self) -> bool {
+        matches!(self.data, ObjectData::DataView(_))
+    }
+
+    #[inline]
+    pub fn as_data_view(&self) -> Option<&DataViewData> {
+        match self.data {
+            ObjectData::DataView(ref view) => Some(view),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `Proxy` object.
+    #[inline]
+    pub fn is_proxy(&self) -> bool {
+        matches!(self.data, ObjectData::Proxy(_))
+    }
+
+    #[inline]
+    pub fn as_proxy(&self) -> Option<&ProxyData> {
+        match self.data {
+            ObjectData::Proxy(ref proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_proxy_mut(&mut self) -> Option<&mut ProxyData> {
+        match self.data {
+            ObjectData::Proxy(ref mut proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
     /// Checks if it a `String` object.
     #[inline]
     pub fn is_string(&self) -> bool {
@@ -295,10 +483,14 @@ impl Object {
         }
     }
 
-    /// Checks if it a `Function` object.
+    /// Checks if it a `Function` object, or a `Proxy` of a function, which is callable as well.
     #[inline]
     pub fn is_function(&self) -> bool {
-        matches!(self.data, ObjectData::Function(_))
+        match self.data {
+            ObjectData::Function(_) => true,
+            ObjectData::Proxy(ref proxy) => proxy.is_callable(),
+            _ => false,
+        }
     }
 
     #[inline]
@@ -309,6 +501,14 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_function_mut(&mut self) -> Option<&mut Function> {
+        match self.data {
+            ObjectData::Function(ref mut function) => Some(function),
+            _ => None,
+        }
+    }
+
     /// Checks if it a Symbol object.
     #[inline]
     pub fn is_symbol(&self) -> bool {
@@ -400,25 +600,94 @@ impl Object {
     }
 
     #[inline]
-    pub fn properties(&self) -> &FxHashMap<RcString, Property> {
+    pub fn properties(&self) -> &OrderedMap<RcString, Property, FxBuildHasher> {
         &self.properties
     }
 
     #[inline]
-    pub fn properties_mut(&mut self) -> &mut FxHashMap<RcString, Property> {
+    pub fn properties_mut(&mut self) -> &mut OrderedMap<RcString, Property, FxBuildHasher> {
         &mut self.properties
     }
 
-    #[inline]
-    pub fn symbol_properties(&self) -> &FxHashMap<u32, Property> {
+    /// Gets the string keys of the own properties of the object, in the order of
+    /// `[[OwnPropertyKeys]]`.
+    ///
+    /// Array indices come first, in ascending numeric order, followed by the other keys in the
+    /// order they were created in. The indices of the elements of a typed array, or of the
+    /// characters of a `String` object, come before all of them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+    pub(crate) fn string_property_keys(&self) -> Vec<RcString> {
+        let elements = match self.data {
+            ObjectData::String(ref string) => string.chars().count(),
+            _ => self.as_typed_array().map_or(0, TypedArrayData::length),
+        };
+        let mut indices = Vec::new();
+        let mut keys = Vec::with_capacity(self.properties.len());
+        for key in self.properties.keys() {
+            match key.parse::<u32>() {
+                Ok(index) if index != u32::MAX && index.to_string() == key.as_str() => {
+                    indices.push((index, key.clone()))
+                }
+                _ => keys.push(key.clone()),
+            }
+        }
+
+        indices.sort_unstable_by_key(|&(index, _)| index);
+        (0..elements)
+            .map(|index| RcString::from(index.to_string()))
+            .chain(indices.into_iter().map(|(_, key)| key))
+            .chain(keys)
+            .collect()
+    }
+
+    /// Gets the keys of the own properties of the object, in the order of `[[OwnPropertyKeys]]`.
+    ///
+    /// The string keys come first, in the order of `string_property_keys`, followed by the
+    /// symbol keys in the order they were created in.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+    pub fn own_property_keys(&self) -> Vec<PropertyKey> {
+        self.string_property_keys()
+            .into_iter()
+            .map(PropertyKey::from)
+            .chain(
+                self.symbol_properties
+                    .keys()
+                    .cloned()
+                    .map(PropertyKey::from),
+            )
+            .collect()
+    }
+
+    #[inline]
+    pub fn symbol_properties(&self) -> &OrderedMap<RcSymbol, Property, FxBuildHasher> {
         &self.symbol_properties
     }
 
     #[inline]
-    pub fn symbol_properties_mut(&mut self) -> &mut FxHashMap<u32, Property> {
+    pub fn symbol_properties_mut(&mut self) -> &mut OrderedMap<RcSymbol, Property, FxBuildHasher> {
         &mut self.symbol_properties
     }
 
+    /// Gets the values that weak maps associate with the object.
+    #[inline]
+    pub fn ephemerons(&self) -> &Ephemerons {
+        &self.ephemerons
+    }
+
+    /// Gets the values that weak maps associate with the object, mutably.
+    #[inline]
+    pub fn ephemerons_mut(&mut self) -> &mut Ephemerons {
+        &mut self.ephemerons
+    }
+
     #[inline]
     pub fn state(&self) -> &Option<InternalStateCell> {
         &self.state
@@ -491,29 +760,71 @@ pub fn is(_: &Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
     Ok(same_value(&x, &y).into())
 }
 
-/// Get the `prototype` of an object.
-pub fn get_prototype_of(_: &Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let obj = args.get(0).expect("Cannot get object");
-    Ok(obj
-        .as_object()
-        .map_or_else(Value::undefined, |object| object.prototype.clone()))
+/// `Object.getPrototypeOf( object )`
+///
+/// This method returns the prototype of the object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.getprototypeof
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getPrototypeOf
+pub fn get_prototype_of(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = ctx.to_object(&args.get(0).cloned().unwrap_or_default())?;
+    ctx.get_prototype_of(&obj)
 }
 
-/// Set the `prototype` of an object.
-pub fn set_prototype_of(_: &Value, args: &[Value], _: &mut Interpreter) -> ResultValue {
-    let obj = args.get(0).expect("Cannot get object").clone();
-    let proto = args.get(1).expect("Cannot get object").clone();
-    obj.as_object_mut().unwrap().prototype = proto;
+/// `Object.setPrototypeOf( object, prototype )`
+///
+/// This method sets the prototype of the object, and returns the object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.setprototypeof
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/setPrototypeOf
+pub fn set_prototype_of(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+    let obj = args.get(0).cloned().unwrap_or_default();
+    ctx.require_object_coercible(&obj)?;
+    let proto = args.get(1).cloned().unwrap_or_default();
+    if !proto.is_object() && !proto.is_null() {
+        return ctx.throw_type_error("Object prototype may only be an Object or null");
+    }
+    if !obj.is_object() {
+        return Ok(obj);
+    }
+    if !ctx.set_prototype_of(&obj, proto)? {
+        return ctx.throw_type_error("Object.setPrototypeOf: cannot set the prototype");
+    }
     Ok(obj)
 }
 
-/// Define a property in an object
+/// `Object.defineProperty( object, property, descriptor )`
+///
+/// This method defines a new property directly on an object, or modifies an existing property on
+/// an object, and returns the object. The property can be a data property or an accessor property
+/// with a getter and a setter.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object.defineproperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty
 pub fn define_property(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
-    let obj = args.get(0).expect("Cannot get object");
-    let prop = ctx.to_string(args.get(1).expect("Cannot get object"))?;
-    let desc = Property::from(args.get(2).expect("Cannot get object"));
-    obj.set_property(prop, desc);
-    Ok(Value::undefined())
+    let obj = args.get(0).cloned().unwrap_or_default();
+    if !obj.is_object() {
+        return ctx.throw_type_error("Object.defineProperty called on non-object");
+    }
+    let key = ctx.to_property_key(&args.get(1).cloned().unwrap_or_default())?;
+    let desc = ctx.to_property_descriptor(&args.get(2).cloned().unwrap_or_default())?;
+
+    if !ctx.define_property(&obj, key.clone(), desc)? {
+        return ctx.throw_type_error(format!("Cannot redefine property: {}", key));
+    }
+    Ok(obj)
 }
 
 /// `Object.prototype.toString()`
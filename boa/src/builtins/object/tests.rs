@@ -142,3 +142,58 @@ fn object_property_is_enumerable() {
     );
     assert_eq!(forward(&mut engine, r#"x.propertyIsEnumerable()"#), "false",)
 }
+
+#[test]
+fn object_define_property_accessors() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var log = [];
+        var obj = {};
+        Object.defineProperty(obj, "value", {
+            get() { return "got"; },
+            configurable: true
+        });
+        Object.defineProperty(obj, "value", {
+            set(v) { log.push(v); }
+        });
+        obj.value = "set";
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "obj.value"), "\"got\"");
+    assert_eq!(forward(&mut engine, "log.join()"), "\"set\"");
+    assert_eq!(
+        forward(&mut engine, "obj.propertyIsEnumerable('value')"),
+        "false"
+    );
+}
+
+#[test]
+fn object_define_property_errors() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var errors = [];
+        var obj = {};
+        Object.defineProperty(obj, "fixed", { value: 1 });
+        var attempts = [
+            () => Object.defineProperty(1, "value", {}),
+            () => Object.defineProperty(obj, "value", { get: 1 }),
+            () => Object.defineProperty(obj, "value", { get() {}, value: 1 }),
+            () => Object.defineProperty(obj, "fixed", { value: 2 }),
+        ];
+        for (let attempt of attempts) {
+            try {
+                attempt();
+            } catch (e) {
+                errors.push(e instanceof TypeError);
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "errors.join()"),
+        "\"true,true,true,true\""
+    );
+    assert_eq!(forward(&mut engine, "obj.fixed"), "1");
+}
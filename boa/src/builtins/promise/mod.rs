@@ -0,0 +1,1130 @@
+//! This module implements the global `Promise` object.
+//!
+//! A promise represents the eventual completion (or failure) of an asynchronous operation, and
+//! its resulting value. The reactions registered with `then()` are never run synchronously, they
+//! are run by jobs in the job queue of the interpreter, which the host has to run.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+use crate::{
+    builtins::{
+        function::{make_builtin_fn, make_capturing_fn, make_constructor_fn},
+        iterable::{get_iterator, IteratorRecord},
+        object::{ObjectData, PROTOTYPE},
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+        Array,
+    },
+    exec::{Interpreter, Job},
+    BoaProfiler,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{
+    fmt::{self, Debug},
+    mem,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-promise-instances
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum PromiseState {
+    /// The promise hasn't been settled yet.
+    Pending,
+    /// The promise has been fulfilled with the value.
+    Fulfilled(Value),
+    /// The promise has been rejected with the reason.
+    Rejected(Value),
+}
+
+/// The operation reported to the promise rejection tracker of the host.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromiseRejectionOperation {
+    /// A promise was rejected without any handler.
+    Reject,
+    /// A handler was added to a rejected promise that didn't have one.
+    Handle,
+}
+
+/// The signature of the promise rejection tracker, called with the promise, its rejection reason
+/// and the operation.
+type PromiseRejectionTrackerFn =
+    dyn FnMut(&Value, &Value, PromiseRejectionOperation, &mut Interpreter);
+
+/// The function called with a promise and its rejection reason, when it is rejected without any
+/// handler, or when a handler is later added to it.
+pub(crate) struct PromiseRejectionTracker(pub(crate) Box<PromiseRejectionTrackerFn>);
+
+impl Debug for PromiseRejectionTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PromiseRejectionTracker")
+    }
+}
+
+/// Whether a reaction handles the fulfillment or the rejection of a promise.
+#[derive(Debug, Clone, Copy, PartialEq, Finalize)]
+enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+unsafe impl Trace for ReactionType {
+    unsafe_empty_trace!();
+}
+
+/// A promise, along with the functions that resolve or reject it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct PromiseCapability {
+    promise: Value,
+    resolve: Value,
+    reject: Value,
+}
+
+/// A function to call when a promise is settled, along with the capability that is resolved with
+/// its result.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct PromiseReaction {
+    /// The capability resolved with the result of the handler, if any.
+    capability: Option<PromiseCapability>,
+    kind: ReactionType,
+    /// The handler function, or `undefined` to pass the value or reason through.
+    handler: Value,
+}
+
+impl PromiseReaction {
+    /// Enqueues a job that runs the reaction with the value or reason of the settled promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn enqueue_job(self, argument: Value, interpreter: &mut Interpreter) {
+        interpreter.enqueue_job(Job::new(move |interpreter| {
+            let result = if self.handler.is_undefined() {
+                match self.kind {
+                    ReactionType::Fulfill => Ok(argument),
+                    ReactionType::Reject => Err(argument),
+                }
+            } else {
+                interpreter.call(&self.handler, &Value::undefined(), &[argument])
+            };
+
+            match self.capability {
+                None => result,
+                Some(ref capability) => match result {
+                    Ok(value) => {
+                        interpreter.call(&capability.resolve, &Value::undefined(), &[value])
+                    }
+                    Err(error) => {
+                        interpreter.call(&capability.reject, &Value::undefined(), &[error])
+                    }
+                },
+            }
+        }));
+    }
+}
+
+/// The promise combinators, which combine the promises of an iterable into a single promise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Combinator {
+    All,
+    AllSettled,
+    Any,
+    Race,
+}
+
+/// The internal state of a promise object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    fulfill_reactions: Vec<PromiseReaction>,
+    reject_reactions: Vec<PromiseReaction>,
+    /// Whether a handler has ever been added to the promise.
+    is_handled: bool,
+}
+
+impl Promise {
+    /// The name of the object.
+    pub(crate) const NAME: &'static str = "Promise";
+
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates the state of a pending promise.
+    fn new() -> Self {
+        Self {
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+            is_handled: false,
+        }
+    }
+
+    /// Creates a new pending promise object, like the built-in `Promise` constructor does.
+    ///
+    /// The promise has no resolving functions, it is settled with `resolve_promise` or
+    /// `reject_promise`.
+    pub(crate) fn create(interpreter: &Interpreter) -> Value {
+        let prototype = interpreter
+            .global()
+            .get_field(Self::NAME)
+            .get_field(PROTOTYPE);
+        Value::new_object_from_prototype(prototype, ObjectData::Promise(Self::new()))
+    }
+
+    /// Gets the state of the promise.
+    #[inline]
+    pub fn state(&self) -> &PromiseState {
+        &self.state
+    }
+
+    /// Checks if the value is a promise object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ispromise
+    pub(crate) fn is_promise(value: &Value) -> bool {
+        value.as_object().map_or(false, |object| {
+            matches!(object.data, ObjectData::Promise(_))
+        })
+    }
+
+    /// Create a new promise object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn make_promise(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let is_new_object = this
+            .as_object()
+            .map_or(false, |object| matches!(object.data, ObjectData::Ordinary));
+        if !is_new_object {
+            return ctx.throw_type_error("Promise constructor cannot be invoked without 'new'");
+        }
+
+        let executor = args.get(0).cloned().unwrap_or_default();
+        if !executor.is_function() {
+            return ctx.throw_type_error("Promise executor is not a function");
+        }
+
+        this.set_data(ObjectData::Promise(Self::new()));
+
+        let (resolve, reject) = Self::create_resolving_functions(this, ctx);
+        if let Err(error) = ctx.call(&executor, &Value::undefined(), &[resolve, reject.clone()]) {
+            ctx.call(&reject, &Value::undefined(), &[error])?;
+        }
+
+        Ok(this.clone())
+    }
+
+    /// Creates the `resolve` and `reject` functions passed to the executor of a promise.
+    ///
+    /// Once one of them has been called, calling any of them again has no effect.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    fn create_resolving_functions(promise: &Value, interpreter: &Interpreter) -> (Value, Value) {
+        let record = Value::new_object(None);
+        record.set_field("promise", promise.clone());
+        record.set_field("alreadyResolved", false);
+
+        let resolve = make_capturing_fn(Self::resolve_function, 1, record.clone(), interpreter);
+        let reject = make_capturing_fn(Self::reject_function, 1, record, interpreter);
+        (resolve, reject)
+    }
+
+    /// The `resolve` function created by `create_resolving_functions`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    fn resolve_function(
+        _: &Value,
+        args: &[Value],
+        record: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        if record.get_field("alreadyResolved").to_boolean() {
+            return Ok(Value::undefined());
+        }
+        record.set_field("alreadyResolved", true);
+
+        let resolution = args.get(0).cloned().unwrap_or_default();
+        Self::resolve_promise(&record.get_field("promise"), resolution, ctx);
+        Ok(Value::undefined())
+    }
+
+    /// The `reject` function created by `create_resolving_functions`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-reject-functions
+    fn reject_function(
+        _: &Value,
+        args: &[Value],
+        record: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        if record.get_field("alreadyResolved").to_boolean() {
+            return Ok(Value::undefined());
+        }
+        record.set_field("alreadyResolved", true);
+
+        let reason = args.get(0).cloned().unwrap_or_default();
+        Self::reject_promise(&record.get_field("promise"), reason, ctx);
+        Ok(Value::undefined())
+    }
+
+    /// Resolves a pending promise with a value, which can be a thenable object whose state the
+    /// promise will then follow.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    pub(crate) fn resolve_promise(
+        promise: &Value,
+        resolution: Value,
+        interpreter: &mut Interpreter,
+    ) {
+        if resolution.strict_equals(promise) {
+            let error = interpreter.construct_type_error("cannot resolve a promise with itself");
+            return Self::reject_promise(promise, error, interpreter);
+        }
+
+        if !resolution.is_object() {
+            return Self::fulfill_promise(promise, resolution, interpreter);
+        }
+        let then = match interpreter.get_field(&resolution, "then") {
+            Ok(then) => then,
+            Err(error) => return Self::reject_promise(promise, error, interpreter),
+        };
+        if !then.is_function() {
+            return Self::fulfill_promise(promise, resolution, interpreter);
+        }
+
+        // The thenable is followed in a job, so that its `then()` method isn't called
+        // synchronously.
+        // https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+        let promise = promise.clone();
+        interpreter.enqueue_job(Job::new(move |interpreter| {
+            let (resolve, reject) = Self::create_resolving_functions(&promise, interpreter);
+            match interpreter.call(&then, &resolution, &[resolve, reject.clone()]) {
+                Err(error) => interpreter.call(&reject, &Value::undefined(), &[error]),
+                result => result,
+            }
+        }));
+    }
+
+    /// Fulfills a pending promise with a value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn fulfill_promise(promise: &Value, value: Value, interpreter: &mut Interpreter) {
+        let reactions = match promise.as_object_mut() {
+            Some(mut object) => match object.data {
+                ObjectData::Promise(ref mut promise) => {
+                    promise.state = PromiseState::Fulfilled(value.clone());
+                    promise.reject_reactions.clear();
+                    mem::take(&mut promise.fulfill_reactions)
+                }
+                _ => unreachable!("only promises can be fulfilled"),
+            },
+            None => unreachable!("only promises can be fulfilled"),
+        };
+
+        for reaction in reactions {
+            reaction.enqueue_job(value.clone(), interpreter);
+        }
+    }
+
+    /// Rejects a pending promise with a reason, reporting it to the host if the promise doesn't
+    /// have a handler.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    pub(crate) fn reject_promise(promise: &Value, reason: Value, interpreter: &mut Interpreter) {
+        let (reactions, is_handled) = match promise.as_object_mut() {
+            Some(mut object) => match object.data {
+                ObjectData::Promise(ref mut promise) => {
+                    promise.state = PromiseState::Rejected(reason.clone());
+                    promise.fulfill_reactions.clear();
+                    (mem::take(&mut promise.reject_reactions), promise.is_handled)
+                }
+                _ => unreachable!("only promises can be rejected"),
+            },
+            None => unreachable!("only promises can be rejected"),
+        };
+
+        if !is_handled {
+            interpreter.track_promise_rejection(
+                promise,
+                &reason,
+                PromiseRejectionOperation::Reject,
+            );
+        }
+
+        for reaction in reactions {
+            reaction.enqueue_job(reason.clone(), interpreter);
+        }
+    }
+
+    /// Creates a new promise with the given constructor, along with the functions that resolve
+    /// or reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub(crate) fn new_promise_capability(
+        constructor: &Value,
+        interpreter: &mut Interpreter,
+    ) -> Result<PromiseCapability, Value> {
+        let is_constructor = constructor
+            .as_object()
+            .map_or(false, |object| object.is_constructable());
+        if !is_constructor {
+            return Err(
+                interpreter.construct_type_error("promise constructor is not a constructor")
+            );
+        }
+
+        let record = Value::new_object(None);
+        let executor = make_capturing_fn(
+            Self::get_capabilities_executor,
+            2,
+            record.clone(),
+            interpreter,
+        );
+        let promise = interpreter.construct(constructor, &[executor], constructor)?;
+
+        let resolve = record.get_field("resolve");
+        let reject = record.get_field("reject");
+        if !resolve.is_function() || !reject.is_function() {
+            return Err(
+                interpreter.construct_type_error("promise resolve or reject is not callable")
+            );
+        }
+
+        Ok(PromiseCapability {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// The executor passed to the constructor by `new_promise_capability`, which gets the
+    /// resolving functions of the new promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getcapabilitiesexecutor-functions
+    fn get_capabilities_executor(
+        _: &Value,
+        args: &[Value],
+        record: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        if !record.get_field("resolve").is_undefined() || !record.get_field("reject").is_undefined()
+        {
+            return ctx.throw_type_error("promise executor was already called");
+        }
+
+        record.set_field("resolve", args.get(0).cloned().unwrap_or_default());
+        record.set_field("reject", args.get(1).cloned().unwrap_or_default());
+        Ok(Value::undefined())
+    }
+
+    /// Gets the constructor used to create the promises derived from a promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-speciesconstructor
+    fn species_constructor(promise: &Value, interpreter: &mut Interpreter) -> ResultValue {
+        let default_constructor = interpreter.global().get_field(Self::NAME);
+        let constructor = promise.get_field("constructor");
+        if constructor.is_undefined() {
+            return Ok(default_constructor);
+        }
+        if !constructor.is_object() {
+            return interpreter.throw_type_error("promise constructor is not an object");
+        }
+
+        // TODO: `Promise[Symbol.species]` is an accessor returning `this`, which can't be defined
+        // until accessor properties are supported.
+        let species = constructor.get_field(interpreter.well_known_symbols().species_symbol());
+        if species.is_null_or_undefined() {
+            return Ok(default_constructor);
+        }
+        if species
+            .as_object()
+            .map_or(false, |object| object.is_constructable())
+        {
+            Ok(species)
+        } else {
+            interpreter.throw_type_error("promise species is not a constructor")
+        }
+    }
+
+    /// Adds the reactions to a promise, which resolve the capability with the result of the
+    /// handlers, if there is one.
+    ///
+    /// Returns the promise of the capability, or `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    pub(crate) fn perform_promise_then(
+        promise: &Value,
+        on_fulfilled: Value,
+        on_rejected: Value,
+        capability: Option<PromiseCapability>,
+        interpreter: &mut Interpreter,
+    ) -> Value {
+        let result = capability
+            .as_ref()
+            .map(|capability| capability.promise.clone())
+            .unwrap_or_default();
+
+        let fulfill_reaction = PromiseReaction {
+            capability: capability.clone(),
+            kind: ReactionType::Fulfill,
+            handler: if on_fulfilled.is_function() {
+                on_fulfilled
+            } else {
+                Value::undefined()
+            },
+        };
+        let reject_reaction = PromiseReaction {
+            capability,
+            kind: ReactionType::Reject,
+            handler: if on_rejected.is_function() {
+                on_rejected
+            } else {
+                Value::undefined()
+            },
+        };
+
+        let (state, was_handled) = match promise.as_object_mut() {
+            Some(mut object) => match object.data {
+                ObjectData::Promise(ref mut promise) => {
+                    if let PromiseState::Pending = promise.state {
+                        promise.fulfill_reactions.push(fulfill_reaction.clone());
+                        promise.reject_reactions.push(reject_reaction.clone());
+                    }
+                    let was_handled = mem::replace(&mut promise.is_handled, true);
+                    (promise.state.clone(), was_handled)
+                }
+                _ => unreachable!("only promises have reactions"),
+            },
+            None => unreachable!("only promises have reactions"),
+        };
+
+        match state {
+            PromiseState::Pending => {}
+            PromiseState::Fulfilled(ref value) => {
+                fulfill_reaction.enqueue_job(value.clone(), interpreter)
+            }
+            PromiseState::Rejected(ref reason) => {
+                if !was_handled {
+                    interpreter.track_promise_rejection(
+                        promise,
+                        reason,
+                        PromiseRejectionOperation::Handle,
+                    );
+                }
+                reject_reaction.enqueue_job(reason.clone(), interpreter);
+            }
+        }
+
+        result
+    }
+
+    /// Gets a promise resolved with the value, which is the value itself if it is already a
+    /// promise created by the constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    pub(crate) fn promise_resolve(
+        constructor: &Value,
+        value: Value,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        if Self::is_promise(&value) && value.get_field("constructor").strict_equals(constructor) {
+            return Ok(value);
+        }
+
+        let capability = Self::new_promise_capability(constructor, interpreter)?;
+        interpreter.call(&capability.resolve, &Value::undefined(), &[value])?;
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.prototype.then( onFulfilled, onRejected )`
+    ///
+    /// Adds the handlers to the promise, and returns a new promise resolved with the result of
+    /// the handler that is called.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        if !Self::is_promise(this) {
+            return ctx.throw_type_error("'this' is not a Promise");
+        }
+
+        let constructor = Self::species_constructor(this, ctx)?;
+        let capability = Self::new_promise_capability(&constructor, ctx)?;
+
+        let on_fulfilled = args.get(0).cloned().unwrap_or_default();
+        let on_rejected = args.get(1).cloned().unwrap_or_default();
+        Ok(Self::perform_promise_then(
+            this,
+            on_fulfilled,
+            on_rejected,
+            Some(capability),
+            ctx,
+        ))
+    }
+
+    /// `Promise.prototype.catch( onRejected )`
+    ///
+    /// Adds a rejection handler to the promise, it is the same as `then(undefined, onRejected)`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let on_rejected = args.get(0).cloned().unwrap_or_default();
+        let then = ctx.get_field(this, "then")?;
+        ctx.call(&then, this, &[Value::undefined(), on_rejected])
+    }
+
+    /// `Promise.prototype.finally( onFinally )`
+    ///
+    /// Adds a handler called when the promise is settled, the returned promise is settled like
+    /// the original one, unless the handler throws.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        if !this.is_object() {
+            return ctx.throw_type_error("'this' is not an object");
+        }
+        let constructor = Self::species_constructor(this, ctx)?;
+
+        let on_finally = args.get(0).cloned().unwrap_or_default();
+        let (then_finally, catch_finally) = if on_finally.is_function() {
+            let record = Value::new_object(None);
+            record.set_field("onFinally", on_finally);
+            record.set_field("constructor", constructor);
+            (
+                make_capturing_fn(Self::then_finally, 1, record.clone(), ctx),
+                make_capturing_fn(Self::catch_finally, 1, record, ctx),
+            )
+        } else {
+            (on_finally.clone(), on_finally)
+        };
+
+        let then = ctx.get_field(this, "then")?;
+        ctx.call(&then, this, &[then_finally, catch_finally])
+    }
+
+    /// Calls the `onFinally` handler of `finally()`, and returns a promise that waits for its
+    /// result before settling like the original promise.
+    fn call_finally(record: &Value, thunk: Value, ctx: &mut Interpreter) -> ResultValue {
+        let result = ctx.call(&record.get_field("onFinally"), &Value::undefined(), &[])?;
+        let promise = Self::promise_resolve(&record.get_field("constructor"), result, ctx)?;
+        let then = ctx.get_field(&promise, "then")?;
+        ctx.call(&then, &promise, &[thunk])
+    }
+
+    /// The fulfillment handler added by `finally()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-thenfinallyfunctions
+    fn then_finally(
+        _: &Value,
+        args: &[Value],
+        record: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let value = args.get(0).cloned().unwrap_or_default();
+        let value_thunk = make_capturing_fn(|_, _, value, _| Ok(value.clone()), 0, value, ctx);
+        Self::call_finally(record, value_thunk, ctx)
+    }
+
+    /// The rejection handler added by `finally()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-catchfinallyfunctions
+    fn catch_finally(
+        _: &Value,
+        args: &[Value],
+        record: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        let thrower = make_capturing_fn(|_, _, reason, _| Err(reason.clone()), 0, reason, ctx);
+        Self::call_finally(record, thrower, ctx)
+    }
+
+    /// `Promise.resolve( value )`
+    ///
+    /// Returns a promise resolved with the value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        if !this.is_object() {
+            return ctx.throw_type_error("'this' is not an object");
+        }
+
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::promise_resolve(this, value, ctx)
+    }
+
+    /// `Promise.reject( reason )`
+    ///
+    /// Returns a promise rejected with the reason.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let capability = Self::new_promise_capability(this, ctx)?;
+        let reason = args.get(0).cloned().unwrap_or_default();
+        ctx.call(&capability.reject, &Value::undefined(), &[reason])?;
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.all( iterable )`
+    ///
+    /// Returns a promise fulfilled with the values of all the promises of the iterable, or
+    /// rejected with the reason of the first one that is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::combine(this, args, Combinator::All, ctx)
+    }
+
+    /// `Promise.allSettled( iterable )`
+    ///
+    /// Returns a promise fulfilled with the outcomes of all the promises of the iterable, once
+    /// they are all settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::combine(this, args, Combinator::AllSettled, ctx)
+    }
+
+    /// `Promise.any( iterable )`
+    ///
+    /// Returns a promise fulfilled with the value of the first promise of the iterable that is
+    /// fulfilled, or rejected with an `AggregateError` if they are all rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any
+    pub(crate) fn any(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::combine(this, args, Combinator::Any, ctx)
+    }
+
+    /// `Promise.race( iterable )`
+    ///
+    /// Returns a promise settled like the first promise of the iterable that is settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::combine(this, args, Combinator::Race, ctx)
+    }
+
+    /// Runs a promise combinator over an iterable, returning the combined promise.
+    ///
+    /// Errors thrown while iterating reject the combined promise instead of being thrown.
+    fn combine(
+        this: &Value,
+        args: &[Value],
+        combinator: Combinator,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let capability = Self::new_promise_capability(this, ctx)?;
+
+        let result = match ctx.get_field(this, "resolve") {
+            Ok(promise_resolve) if promise_resolve.is_function() => {
+                let iterable = args.get(0).cloned().unwrap_or_default();
+                get_iterator(ctx, &iterable).and_then(|iterator| {
+                    let mut done = false;
+                    let result = Self::combine_iterator(
+                        &iterator,
+                        &mut done,
+                        this,
+                        &capability,
+                        &promise_resolve,
+                        combinator,
+                        ctx,
+                    );
+                    if done {
+                        result
+                    } else {
+                        iterator.close(result, ctx)
+                    }
+                })
+            }
+            Ok(_) => Err(ctx.construct_type_error("Promise.resolve is not a function")),
+            Err(error) => Err(error),
+        };
+
+        if let Err(error) = result {
+            ctx.call(&capability.reject, &Value::undefined(), &[error])?;
+        }
+        Ok(capability.promise.clone())
+    }
+
+    /// Adds the reactions of the combinator to the promises of the iterator.
+    ///
+    /// `done` is set once the iterator is done or has thrown, in which case it must not be
+    /// closed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseall
+    fn combine_iterator(
+        iterator: &IteratorRecord,
+        done: &mut bool,
+        constructor: &Value,
+        capability: &PromiseCapability,
+        promise_resolve: &Value,
+        combinator: Combinator,
+        ctx: &mut Interpreter,
+    ) -> Result<(), Value> {
+        // The values of `all()` and `allSettled()`, or the errors of `any()`.
+        let values = Array::new_array(ctx)?;
+        // The number of promises that aren't settled yet, plus one until the iterator is done.
+        let remaining = Value::new_object(None);
+        remaining.set_field("count", 1);
+        let mut index = 0;
+
+        loop {
+            let next = iterator.next(ctx).map_err(|error| {
+                *done = true;
+                error
+            })?;
+            if next.is_done() {
+                *done = true;
+                if combinator != Combinator::Race && Self::decrement_remaining(&remaining) {
+                    Self::settle_combined(&values, &capability.resolve, combinator, ctx)?;
+                }
+                return Ok(());
+            }
+
+            let next_promise = ctx.call(promise_resolve, constructor, &[next.into_value()])?;
+
+            let (on_fulfilled, on_rejected) = if combinator == Combinator::Race {
+                (capability.resolve.clone(), capability.reject.clone())
+            } else {
+                Array::add_to_array_object(&values, &[Value::undefined()])?;
+                remaining.set_field("count", i32::from(&remaining.get_field("count")) + 1);
+
+                let record = Value::new_object(None);
+                record.set_field("alreadyCalled", false);
+                record.set_field("index", index);
+                record.set_field("values", values.clone());
+                record.set_field("remaining", remaining.clone());
+
+                match combinator {
+                    Combinator::All => {
+                        record.set_field("capability", capability.resolve.clone());
+                        (
+                            make_capturing_fn(Self::all_resolve_element, 1, record, ctx),
+                            capability.reject.clone(),
+                        )
+                    }
+                    Combinator::AllSettled => {
+                        record.set_field("capability", capability.resolve.clone());
+                        (
+                            make_capturing_fn(
+                                Self::all_settled_resolve_element,
+                                1,
+                                record.clone(),
+                                ctx,
+                            ),
+                            make_capturing_fn(Self::all_settled_reject_element, 1, record, ctx),
+                        )
+                    }
+                    Combinator::Any => {
+                        record.set_field("capability", capability.reject.clone());
+                        (
+                            capability.resolve.clone(),
+                            make_capturing_fn(Self::any_reject_element, 1, record, ctx),
+                        )
+                    }
+                    Combinator::Race => unreachable!(),
+                }
+            };
+
+            let then = ctx.get_field(&next_promise, "then")?;
+            ctx.call(&then, &next_promise, &[on_fulfilled, on_rejected])?;
+            index += 1;
+        }
+    }
+
+    /// Decrements the number of remaining promises of a combinator, returning `true` once there
+    /// are none left.
+    fn decrement_remaining(remaining: &Value) -> bool {
+        let count = i32::from(&remaining.get_field("count")) - 1;
+        remaining.set_field("count", count);
+        count == 0
+    }
+
+    /// Settles the promise of a combinator once all of the promises are settled, with the values
+    /// or the errors that were collected.
+    fn settle_combined(
+        values: &Value,
+        settle: &Value,
+        combinator: Combinator,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let result = if combinator == Combinator::Any {
+            let aggregate_error = ctx.global().get_field("AggregateError");
+            let message = Value::from("all promises were rejected");
+            ctx.construct(
+                &aggregate_error,
+                &[values.clone(), message],
+                &aggregate_error,
+            )
+            .expect_err("AggregateError should always throw")
+        } else {
+            values.clone()
+        };
+        ctx.call(settle, &Value::undefined(), &[result])
+    }
+
+    /// Stores the value of a settled promise in the values of a combinator, and settles the
+    /// combined promise if it was the last one.
+    fn store_element(
+        record: &Value,
+        value: Value,
+        combinator: Combinator,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        if record.get_field("alreadyCalled").to_boolean() {
+            return Ok(Value::undefined());
+        }
+        record.set_field("alreadyCalled", true);
+
+        let values = record.get_field("values");
+        let index = i32::from(&record.get_field("index"));
+        values.set_field(index.to_string(), value);
+        if Self::decrement_remaining(&record.get_field("remaining")) {
+            Self::settle_combined(&values, &record.get_field("capability"), combinator, ctx)?;
+        }
+        Ok(Value::undefined())
+    }
+
+    /// Creates the outcome object of a promise, stored by `allSettled()`.
+    fn settled_outcome(status: &str, key: &str, value: Value, ctx: &Interpreter) -> Value {
+        let outcome = Value::new_object(Some(ctx.global()));
+        outcome.set_field("status", status);
+        outcome.set_field(key, value);
+        outcome
+    }
+
+    /// The fulfillment handler of the promises passed to `Promise.all()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all-resolve-element-functions
+    fn all_resolve_element(
+        _: &Value,
+        args: &[Value],
+        record: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::store_element(record, value, Combinator::All, ctx)
+    }
+
+    /// The fulfillment handler of the promises passed to `Promise.allSettled()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled-resolve-element-functions
+    fn all_settled_resolve_element(
+        _: &Value,
+        args: &[Value],
+        record: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let value = args.get(0).cloned().unwrap_or_default();
+        let outcome = Self::settled_outcome("fulfilled", "value", value, ctx);
+        Self::store_element(record, outcome, Combinator::AllSettled, ctx)
+    }
+
+    /// The rejection handler of the promises passed to `Promise.allSettled()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled-reject-element-functions
+    fn all_settled_reject_element(
+        _: &Value,
+        args: &[Value],
+        record: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        let outcome = Self::settled_outcome("rejected", "reason", reason, ctx);
+        Self::store_element(record, outcome, Combinator::AllSettled, ctx)
+    }
+
+    /// The rejection handler of the promises passed to `Promise.any()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any-reject-element-functions
+    fn any_reject_element(
+        _: &Value,
+        args: &[Value],
+        record: &Value,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        Self::store_element(record, reason, Combinator::Any, ctx)
+    }
+
+    /// Initialise the `Promise` object on the global object.
+    #[inline]
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let prototype = Value::new_object(Some(global));
+
+        make_builtin_fn(Self::then, "then", &prototype, 2, interpreter);
+        make_builtin_fn(Self::catch, "catch", &prototype, 1, interpreter);
+        make_builtin_fn(Self::finally, "finally", &prototype, 1, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from(Self::NAME),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        let promise_object = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_promise,
+            global,
+            prototype,
+            true,
+            false,
+        );
+
+        make_builtin_fn(Self::resolve, "resolve", &promise_object, 1, interpreter);
+        make_builtin_fn(Self::reject, "reject", &promise_object, 1, interpreter);
+        make_builtin_fn(Self::all, "all", &promise_object, 1, interpreter);
+        make_builtin_fn(
+            Self::all_settled,
+            "allSettled",
+            &promise_object,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(Self::any, "any", &promise_object, 1, interpreter);
+        make_builtin_fn(Self::race, "race", &promise_object, 1, interpreter);
+
+        (Self::NAME, promise_object)
+    }
+}
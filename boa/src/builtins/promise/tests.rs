@@ -0,0 +1,312 @@
+use super::PromiseRejectionOperation;
+use crate::{exec::Interpreter, forward, realm::Realm};
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn reactions_run_in_jobs() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var result = "pending";
+        Promise.resolve(1).then(value => { result = value; });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result"), "\"pending\"");
+    assert!(engine.has_pending_jobs());
+
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "1");
+    assert!(!engine.has_pending_jobs());
+}
+
+#[test]
+fn run_job() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var order = [];
+        Promise.resolve().then(() => order.push("a")).then(() => order.push("c"));
+        Promise.resolve().then(() => order.push("b"));
+        "#;
+    forward(&mut engine, init);
+
+    assert!(engine.run_job().unwrap().is_ok());
+    assert_eq!(forward(&mut engine, "order.join()"), "\"a\"");
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "order.join()"), "\"a,b,c\"");
+    assert!(engine.run_job().is_none());
+}
+
+#[test]
+fn executor() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var results = [];
+        new Promise((resolve, reject) => {
+            resolve("first");
+            reject("ignored");
+            resolve("ignored");
+        }).then(value => results.push(value));
+        new Promise((resolve, reject) => reject("rejected"))
+            .then(undefined, reason => results.push(reason));
+        new Promise(() => { throw "thrown"; })
+            .catch(reason => results.push(reason));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "results.join()"),
+        "\"first,rejected,thrown\""
+    );
+}
+
+#[test]
+fn constructor_errors() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var errors = [];
+        try {
+            Promise(() => {});
+        } catch (e) {
+            errors.push(e instanceof TypeError);
+        }
+        try {
+            new Promise(1);
+        } catch (e) {
+            errors.push(e instanceof TypeError);
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "errors.join()"), "\"true,true\"");
+}
+
+#[test]
+fn chaining() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var result;
+        Promise.resolve(1)
+            .then(value => value + 1)
+            .then(value => Promise.resolve(value * 10))
+            .then(value => ({ then(resolve) { resolve(value + 5); } }))
+            .then(value => { throw value; })
+            .then(() => "skipped")
+            .catch(reason => { result = reason; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "25");
+}
+
+#[test]
+fn resolve_with_itself() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var result;
+        var resolvePromise;
+        var promise = new Promise(resolve => { resolvePromise = resolve; });
+        resolvePromise(promise);
+        promise.catch(reason => { result = reason instanceof TypeError; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "true");
+}
+
+#[test]
+fn finally() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var results = [];
+        Promise.resolve("value")
+            .finally(() => { results.push("finally"); return "ignored"; })
+            .then(value => results.push(value));
+        Promise.reject("reason")
+            .finally(() => results.push("finally"))
+            .catch(reason => results.push(reason));
+        Promise.resolve("value")
+            .finally(() => { throw "replaced"; })
+            .catch(reason => results.push(reason));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "results.join()"),
+        "\"finally,finally,replaced,value,reason\""
+    );
+}
+
+#[test]
+fn all() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var values;
+        var reason;
+        var resolveLater;
+        var later = new Promise(resolve => { resolveLater = resolve; });
+        Promise.all([later, 2, Promise.resolve(3)]).then(result => { values = result; });
+        Promise.all([1, Promise.reject("failed")]).catch(result => { reason = result; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "values"), "undefined");
+    assert_eq!(forward(&mut engine, "reason"), "\"failed\"");
+
+    forward(&mut engine, "resolveLater(1)");
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "values"), "[ 1, 2, 3 ]");
+
+    forward(
+        &mut engine,
+        "Promise.all([]).then(result => { values = result; })",
+    );
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "values.length"), "0");
+}
+
+#[test]
+fn all_settled() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var outcomes;
+        Promise.allSettled([Promise.resolve(1), Promise.reject(2)])
+            .then(result => { outcomes = result; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "outcomes.length"), "2");
+    assert_eq!(forward(&mut engine, "outcomes[0].status"), "\"fulfilled\"");
+    assert_eq!(forward(&mut engine, "outcomes[0].value"), "1");
+    assert_eq!(forward(&mut engine, "outcomes[1].status"), "\"rejected\"");
+    assert_eq!(forward(&mut engine, "outcomes[1].reason"), "2");
+}
+
+#[test]
+fn any() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var value;
+        var error;
+        Promise.any([Promise.reject(1), Promise.resolve(2), Promise.resolve(3)])
+            .then(result => { value = result; });
+        Promise.any([Promise.reject(1), Promise.reject(2)])
+            .catch(result => { error = result; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "value"), "2");
+    assert_eq!(forward(&mut engine, "error.name"), "\"AggregateError\"");
+    assert_eq!(forward(&mut engine, "error.errors"), "[ 1, 2 ]");
+}
+
+#[test]
+fn race() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var results = [];
+        var never = new Promise(() => {});
+        Promise.race([never, Promise.resolve("first"), Promise.resolve("second")])
+            .then(value => results.push(value));
+        Promise.race([never, Promise.reject("rejected")])
+            .catch(reason => results.push(reason));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "results.join()"), "\"first,rejected\"");
+}
+
+#[test]
+fn combinator_not_iterable() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var result;
+        Promise.all(1).catch(reason => { result = reason instanceof TypeError; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "true");
+}
+
+#[test]
+fn rejection_tracker() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+
+    let operations = Rc::new(RefCell::new(Vec::new()));
+    let tracked = operations.clone();
+    engine.set_promise_rejection_tracker(move |_, reason, operation, _| {
+        tracked.borrow_mut().push((reason.to_string(), operation));
+    });
+
+    forward(&mut engine, "var rejected = Promise.reject('unhandled');");
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        *operations.borrow(),
+        vec![(
+            "\"unhandled\"".to_string(),
+            PromiseRejectionOperation::Reject
+        )]
+    );
+
+    forward(&mut engine, "rejected.catch(() => {});");
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        operations.borrow()[1],
+        (
+            "\"unhandled\"".to_string(),
+            PromiseRejectionOperation::Handle
+        )
+    );
+
+    // Rejections of promises that already have a handler aren't reported.
+    forward(
+        &mut engine,
+        "var reject; new Promise((_, r) => { reject = r; }).catch(() => {}); reject(2);",
+    );
+    engine.run_jobs().unwrap();
+    assert_eq!(operations.borrow().len(), 2);
+}
+
+#[test]
+fn to_string_tag() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    assert_eq!(
+        forward(&mut engine, "Promise.prototype[Symbol.toStringTag]"),
+        "\"Promise\""
+    );
+}
+
+#[test]
+fn thenable_accessor() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var results = [];
+        var thenable = {
+            get then() {
+                return resolve => resolve("getter");
+            }
+        };
+        var throwing = {
+            get then() {
+                throw "thrown";
+            }
+        };
+        Promise.resolve(thenable).then(value => results.push(value));
+        Promise.resolve(throwing).catch(reason => results.push(reason));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "results.join()"), "\"thrown,getter\"");
+}
@@ -77,7 +77,7 @@ impl Attribute {
         if value {
             *self |= Self::WRITABLE;
         } else {
-            *self |= *self & !Self::WRITABLE;
+            *self = (*self & !Self::WRITABLE) | Self::READONLY;
         }
     }
 
@@ -100,7 +100,7 @@ impl Attribute {
         if value {
             *self |= Self::ENUMERABLE;
         } else {
-            *self |= *self & !Self::ENUMERABLE;
+            *self = (*self & !Self::ENUMERABLE) | Self::NON_ENUMERABLE;
         }
     }
 
@@ -123,7 +123,7 @@ impl Attribute {
         if value {
             *self |= Self::CONFIGURABLE;
         } else {
-            *self |= *self & !Self::CONFIGURABLE;
+            *self = (*self & !Self::CONFIGURABLE) | Self::PERMANENT;
         }
     }
 
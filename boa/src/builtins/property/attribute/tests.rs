@@ -165,3 +165,19 @@ fn set_configurable_to_false() {
     assert!(attribute.has_configurable());
     assert!(!attribute.configurable());
 }
+
+#[test]
+fn unset_all_flags() {
+    let mut attribute = Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE;
+
+    attribute.set_writable(false);
+    attribute.set_enumerable(false);
+    attribute.set_configurable(false);
+
+    assert!(attribute.has_writable());
+    assert!(!attribute.writable());
+    assert!(attribute.has_enumerable());
+    assert!(!attribute.enumerable());
+    assert!(attribute.has_configurable());
+    assert!(!attribute.configurable());
+}
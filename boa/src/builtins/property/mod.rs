@@ -270,11 +270,19 @@ impl<'a> From<&'a Value> for Property {
             attribute.set_configurable(bool::from(&configurable));
         }
 
+        let field = |name| {
+            if value.has_field(name) {
+                Some(value.get_field(name))
+            } else {
+                None
+            }
+        };
+
         Self {
             attribute,
-            value: Some(value.get_field("value")),
-            get: Some(value.get_field("get")),
-            set: Some(value.get_field("set")),
+            value: field("value"),
+            get: field("get"),
+            set: field("set"),
         }
     }
 }
@@ -286,7 +294,7 @@ impl<'a> From<&'a Value> for Property {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-ispropertykey
-#[derive(Trace, Finalize, Debug, Clone)]
+#[derive(Trace, Finalize, Debug, Clone, PartialEq, Eq)]
 pub enum PropertyKey {
     String(RcString),
     Symbol(RcSymbol),
@@ -327,6 +335,13 @@ impl From<RcSymbol> for PropertyKey {
     }
 }
 
+impl From<&PropertyKey> for PropertyKey {
+    #[inline]
+    fn from(property_key: &PropertyKey) -> PropertyKey {
+        property_key.clone()
+    }
+}
+
 impl fmt::Display for PropertyKey {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
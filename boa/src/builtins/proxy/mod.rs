@@ -0,0 +1,875 @@
+//! This module implements the global `Proxy` object.
+//!
+//! A `Proxy` wraps a target object, and the operations done on the proxy are forwarded to the
+//! functions of a handler object, called traps, or to the target if the handler doesn't have
+//! the trap. The results of the traps are checked against the target, so that a proxy can't
+//! report something that contradicts the non-configurable properties or the non-extensibility
+//! of its target.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
+
+use super::function::{make_builtin_fn, make_capturing_fn, make_constructor_fn};
+use crate::{
+    builtins::{
+        object::{internal_methods::validate_and_apply_property_descriptor, ObjectData},
+        property::{Property, PropertyKey},
+        value::{same_value, ResultValue, Value},
+        Array,
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal state of a `Proxy` object.
+///
+/// The target and the handler are `null` once the proxy has been revoked.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ProxyData {
+    target: Value,
+    handler: Value,
+    /// Whether the target was callable when the proxy was created.
+    callable: bool,
+    /// Whether the target was a constructor when the proxy was created.
+    constructor: bool,
+}
+
+impl ProxyData {
+    /// Checks if the proxy has a `[[Call]]` internal method.
+    #[inline]
+    pub fn is_callable(&self) -> bool {
+        self.callable
+    }
+
+    /// Checks if the proxy has a `[[Construct]]` internal method.
+    #[inline]
+    pub fn is_constructor(&self) -> bool {
+        self.constructor
+    }
+
+    /// Checks if the proxy has been revoked.
+    #[inline]
+    pub fn is_revoked(&self) -> bool {
+        self.handler.is_null()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Proxy;
+
+impl Proxy {
+    pub(crate) const NAME: &'static str = "Proxy";
+
+    pub(crate) const LENGTH: usize = 2;
+
+    /// Gets the target of the proxy and the trap of its handler with the given name, throwing a
+    /// `TypeError` if the proxy has been revoked or the trap is not a function.
+    ///
+    /// The trap is `None` if the handler doesn't have it, in which case the operation is done on
+    /// the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmethod
+    fn trap(
+        proxy: &ProxyData,
+        name: &str,
+        interpreter: &mut Interpreter,
+    ) -> Result<(Value, Option<(Value, Value)>), Value> {
+        if proxy.is_revoked() {
+            return Err(interpreter.construct_type_error(format!(
+                "cannot perform '{}' on a proxy that has been revoked",
+                name
+            )));
+        }
+
+        let trap = interpreter.get_field(&proxy.handler, name)?;
+        if trap.is_null_or_undefined() {
+            return Ok((proxy.target.clone(), None));
+        }
+        if !trap.is_function() {
+            return Err(interpreter
+                .construct_type_error(format!("proxy trap '{}' is not a function", name)));
+        }
+        Ok((proxy.target.clone(), Some((trap, proxy.handler.clone()))))
+    }
+
+    /// Throws the `TypeError` of a trap that broke an invariant of the proxy.
+    fn invariant_error(name: &str, message: &str, interpreter: &mut Interpreter) -> Value {
+        interpreter.construct_type_error(format!("proxy trap '{}' {}", name, message))
+    }
+
+    /// `[[GetPrototypeOf]] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getprototypeof
+    pub(crate) fn get_prototype_of(
+        proxy: &ProxyData,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let (target, (trap, handler)) = match Self::trap(proxy, "getPrototypeOf", interpreter)? {
+            (target, None) => return interpreter.get_prototype_of(&target),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let prototype = interpreter.call(&trap, &handler, &[target.clone()])?;
+        if !prototype.is_object() && !prototype.is_null() {
+            return Err(Self::invariant_error(
+                "getPrototypeOf",
+                "returned neither an object nor null",
+                interpreter,
+            ));
+        }
+        if interpreter.is_extensible(&target)? {
+            return Ok(prototype);
+        }
+        let target_prototype = interpreter.get_prototype_of(&target)?;
+        if !same_value(&prototype, &target_prototype) {
+            return Err(Self::invariant_error(
+                "getPrototypeOf",
+                "did not return the prototype of the non-extensible target",
+                interpreter,
+            ));
+        }
+        Ok(prototype)
+    }
+
+    /// `[[SetPrototypeOf]] ( V )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-setprototypeof-v
+    pub(crate) fn set_prototype_of(
+        proxy: &ProxyData,
+        prototype: Value,
+        interpreter: &mut Interpreter,
+    ) -> Result<bool, Value> {
+        let (target, (trap, handler)) = match Self::trap(proxy, "setPrototypeOf", interpreter)? {
+            (target, None) => return interpreter.set_prototype_of(&target, prototype),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let result = interpreter.call(&trap, &handler, &[target.clone(), prototype.clone()])?;
+        if !result.to_boolean() {
+            return Ok(false);
+        }
+        if interpreter.is_extensible(&target)? {
+            return Ok(true);
+        }
+        let target_prototype = interpreter.get_prototype_of(&target)?;
+        if !same_value(&prototype, &target_prototype) {
+            return Err(Self::invariant_error(
+                "setPrototypeOf",
+                "returned true for a non-extensible target with a different prototype",
+                interpreter,
+            ));
+        }
+        Ok(true)
+    }
+
+    /// Checks if the target of the proxy is an array, throwing a `TypeError` if the proxy has
+    /// been revoked.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isarray
+    pub(crate) fn is_array(
+        proxy: &ProxyData,
+        interpreter: &mut Interpreter,
+    ) -> Result<bool, Value> {
+        if proxy.is_revoked() {
+            return Err(interpreter.construct_type_error(
+                "cannot perform 'IsArray' on a proxy that has been revoked",
+            ));
+        }
+        interpreter.is_array(&proxy.target)
+    }
+
+    /// `[[IsExtensible]] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-isextensible
+    pub(crate) fn is_extensible(
+        proxy: &ProxyData,
+        interpreter: &mut Interpreter,
+    ) -> Result<bool, Value> {
+        let (target, (trap, handler)) = match Self::trap(proxy, "isExtensible", interpreter)? {
+            (target, None) => return interpreter.is_extensible(&target),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let result = interpreter
+            .call(&trap, &handler, &[target.clone()])?
+            .to_boolean();
+        if result != interpreter.is_extensible(&target)? {
+            return Err(Self::invariant_error(
+                "isExtensible",
+                "did not return the extensibility of the target",
+                interpreter,
+            ));
+        }
+        Ok(result)
+    }
+
+    /// `[[PreventExtensions]] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-preventextensions
+    pub(crate) fn prevent_extensions(
+        proxy: &ProxyData,
+        interpreter: &mut Interpreter,
+    ) -> Result<bool, Value> {
+        let (target, (trap, handler)) = match Self::trap(proxy, "preventExtensions", interpreter)? {
+            (target, None) => return interpreter.prevent_extensions(&target),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let result = interpreter
+            .call(&trap, &handler, &[target.clone()])?
+            .to_boolean();
+        if result && interpreter.is_extensible(&target)? {
+            return Err(Self::invariant_error(
+                "preventExtensions",
+                "returned true for an extensible target",
+                interpreter,
+            ));
+        }
+        Ok(result)
+    }
+
+    /// `[[GetOwnProperty]] ( P )`
+    ///
+    /// An empty property is returned if the proxy doesn't have the property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getownproperty-p
+    pub(crate) fn get_own_property(
+        proxy: &ProxyData,
+        key: &PropertyKey,
+        interpreter: &mut Interpreter,
+    ) -> Result<Property, Value> {
+        const NAME: &str = "getOwnPropertyDescriptor";
+        let (target, (trap, handler)) = match Self::trap(proxy, NAME, interpreter)? {
+            (target, None) => return interpreter.get_own_property(&target, key),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let result = interpreter.call(&trap, &handler, &[target.clone(), key.into()])?;
+        if !result.is_object() && !result.is_undefined() {
+            return Err(Self::invariant_error(
+                NAME,
+                "returned neither an object nor undefined",
+                interpreter,
+            ));
+        }
+        let target_property = interpreter.get_own_property(&target, key)?;
+        if result.is_undefined() {
+            if target_property.is_none() {
+                return Ok(Property::empty());
+            }
+            if !target_property.configurable_or(false) {
+                return Err(Self::invariant_error(
+                    NAME,
+                    "reported a non-configurable property as non-existent",
+                    interpreter,
+                ));
+            }
+            if !interpreter.is_extensible(&target)? {
+                return Err(Self::invariant_error(
+                    NAME,
+                    "reported an existing property of a non-extensible target as non-existent",
+                    interpreter,
+                ));
+            }
+            return Ok(Property::empty());
+        }
+
+        let extensible = interpreter.is_extensible(&target)?;
+        let property = interpreter.to_property_descriptor(&result)?;
+        let property = validate_and_apply_property_descriptor(true, Property::empty(), property)
+            .expect("a property can always be added to an extensible object");
+        if validate_and_apply_property_descriptor(
+            extensible,
+            target_property.clone(),
+            property.clone(),
+        )
+        .is_none()
+        {
+            return Err(Self::invariant_error(
+                NAME,
+                "reported a property incompatible with the property of the target",
+                interpreter,
+            ));
+        }
+        if !property.configurable_or(false) {
+            if target_property.is_none() || target_property.configurable_or(false) {
+                return Err(Self::invariant_error(
+                    NAME,
+                    "reported a configurable or non-existent property as non-configurable",
+                    interpreter,
+                ));
+            }
+            if property.attribute.has_writable()
+                && !property.writable_or(false)
+                && target_property.writable_or(false)
+            {
+                return Err(Self::invariant_error(
+                    NAME,
+                    "reported a writable property as non-configurable and non-writable",
+                    interpreter,
+                ));
+            }
+        }
+        Ok(property)
+    }
+
+    /// `[[DefineOwnProperty]] ( P, Desc )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-defineownproperty-p-desc
+    pub(crate) fn define_own_property(
+        proxy: &ProxyData,
+        key: &PropertyKey,
+        property: Property,
+        interpreter: &mut Interpreter,
+    ) -> Result<bool, Value> {
+        const NAME: &str = "defineProperty";
+        let (target, (trap, handler)) = match Self::trap(proxy, NAME, interpreter)? {
+            (target, None) => return interpreter.define_property(&target, key, property),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let descriptor = interpreter.from_property_descriptor(&property);
+        let result =
+            interpreter.call(&trap, &handler, &[target.clone(), key.into(), descriptor])?;
+        if !result.to_boolean() {
+            return Ok(false);
+        }
+
+        let target_property = interpreter.get_own_property(&target, key)?;
+        let extensible = interpreter.is_extensible(&target)?;
+        let setting_non_configurable =
+            property.attribute.has_configurable() && !property.configurable_or(false);
+        if target_property.is_none() {
+            if !extensible {
+                return Err(Self::invariant_error(
+                    NAME,
+                    "added a property to a non-extensible target",
+                    interpreter,
+                ));
+            }
+            if setting_non_configurable {
+                return Err(Self::invariant_error(
+                    NAME,
+                    "defined a non-configurable property that doesn't exist on the target",
+                    interpreter,
+                ));
+            }
+            return Ok(true);
+        }
+
+        if validate_and_apply_property_descriptor(
+            extensible,
+            target_property.clone(),
+            property.clone(),
+        )
+        .is_none()
+        {
+            return Err(Self::invariant_error(
+                NAME,
+                "defined a property incompatible with the property of the target",
+                interpreter,
+            ));
+        }
+        if setting_non_configurable && target_property.configurable_or(false) {
+            return Err(Self::invariant_error(
+                NAME,
+                "defined a non-configurable property that is configurable on the target",
+                interpreter,
+            ));
+        }
+        if target_property.is_data_descriptor()
+            && !target_property.configurable_or(false)
+            && target_property.writable_or(false)
+            && property.attribute.has_writable()
+            && !property.writable_or(false)
+        {
+            return Err(Self::invariant_error(
+                NAME,
+                "made a non-configurable property of the target non-writable",
+                interpreter,
+            ));
+        }
+        Ok(true)
+    }
+
+    /// `[[HasProperty]] ( P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p
+    pub(crate) fn has_property(
+        proxy: &ProxyData,
+        key: &PropertyKey,
+        interpreter: &mut Interpreter,
+    ) -> Result<bool, Value> {
+        let (target, (trap, handler)) = match Self::trap(proxy, "has", interpreter)? {
+            (target, None) => return interpreter.has_property(&target, key),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let result = interpreter
+            .call(&trap, &handler, &[target.clone(), key.into()])?
+            .to_boolean();
+        if !result {
+            let target_property = interpreter.get_own_property(&target, key)?;
+            if !target_property.is_none() {
+                if !target_property.configurable_or(false) {
+                    return Err(Self::invariant_error(
+                        "has",
+                        "reported a non-configurable property as non-existent",
+                        interpreter,
+                    ));
+                }
+                if !interpreter.is_extensible(&target)? {
+                    return Err(Self::invariant_error(
+                        "has",
+                        "reported an existing property of a non-extensible target as non-existent",
+                        interpreter,
+                    ));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// `[[Get]] ( P, Receiver )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-get-p-receiver
+    pub(crate) fn get(
+        proxy: &ProxyData,
+        key: &PropertyKey,
+        receiver: &Value,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let (target, (trap, handler)) = match Self::trap(proxy, "get", interpreter)? {
+            (target, None) => {
+                return interpreter.get_field_with_receiver(&target, key, receiver);
+            }
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let value = interpreter.call(
+            &trap,
+            &handler,
+            &[target.clone(), key.into(), receiver.clone()],
+        )?;
+        let target_property = interpreter.get_own_property(&target, key)?;
+        if !target_property.is_none() && !target_property.configurable_or(false) {
+            if target_property.is_data_descriptor()
+                && !target_property.writable_or(false)
+                && !same_value(&value, &target_property.value.clone().unwrap_or_default())
+            {
+                return Err(Self::invariant_error(
+                    "get",
+                    "reported a different value for a non-writable, non-configurable property",
+                    interpreter,
+                ));
+            }
+            if target_property.is_accessor_descriptor()
+                && target_property
+                    .get
+                    .as_ref()
+                    .map_or(true, |get| get.is_undefined())
+                && !value.is_undefined()
+            {
+                return Err(Self::invariant_error(
+                    "get",
+                    "reported a value for a non-configurable accessor property without a getter",
+                    interpreter,
+                ));
+            }
+        }
+        Ok(value)
+    }
+
+    /// `[[Set]] ( P, V, Receiver )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-set-p-v-receiver
+    pub(crate) fn set(
+        proxy: &ProxyData,
+        key: &PropertyKey,
+        value: Value,
+        receiver: &Value,
+        interpreter: &mut Interpreter,
+    ) -> Result<bool, Value> {
+        let (target, (trap, handler)) = match Self::trap(proxy, "set", interpreter)? {
+            (target, None) => {
+                return interpreter.set_field_with_receiver(&target, key, value, receiver);
+            }
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let result = interpreter.call(
+            &trap,
+            &handler,
+            &[target.clone(), key.into(), value.clone(), receiver.clone()],
+        )?;
+        if !result.to_boolean() {
+            return Ok(false);
+        }
+        let target_property = interpreter.get_own_property(&target, key)?;
+        if !target_property.is_none() && !target_property.configurable_or(false) {
+            if target_property.is_data_descriptor()
+                && !target_property.writable_or(false)
+                && !same_value(&value, &target_property.value.clone().unwrap_or_default())
+            {
+                return Err(Self::invariant_error(
+                    "set",
+                    "changed the value of a non-writable, non-configurable property",
+                    interpreter,
+                ));
+            }
+            if target_property.is_accessor_descriptor()
+                && target_property
+                    .set
+                    .as_ref()
+                    .map_or(true, |set| set.is_undefined())
+            {
+                return Err(Self::invariant_error(
+                    "set",
+                    "set a non-configurable accessor property without a setter",
+                    interpreter,
+                ));
+            }
+        }
+        Ok(true)
+    }
+
+    /// `[[Delete]] ( P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-delete-p
+    pub(crate) fn delete(
+        proxy: &ProxyData,
+        key: &PropertyKey,
+        interpreter: &mut Interpreter,
+    ) -> Result<bool, Value> {
+        let (target, (trap, handler)) = match Self::trap(proxy, "deleteProperty", interpreter)? {
+            (target, None) => return interpreter.delete_property(&target, key),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let result = interpreter.call(&trap, &handler, &[target.clone(), key.into()])?;
+        if !result.to_boolean() {
+            return Ok(false);
+        }
+        let target_property = interpreter.get_own_property(&target, key)?;
+        if target_property.is_none() {
+            return Ok(true);
+        }
+        if !target_property.configurable_or(false) {
+            return Err(Self::invariant_error(
+                "deleteProperty",
+                "deleted a non-configurable property",
+                interpreter,
+            ));
+        }
+        if !interpreter.is_extensible(&target)? {
+            return Err(Self::invariant_error(
+                "deleteProperty",
+                "deleted a property of a non-extensible target",
+                interpreter,
+            ));
+        }
+        Ok(true)
+    }
+
+    /// `[[OwnPropertyKeys]] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-ownpropertykeys
+    pub(crate) fn own_property_keys(
+        proxy: &ProxyData,
+        interpreter: &mut Interpreter,
+    ) -> Result<Vec<PropertyKey>, Value> {
+        let (target, (trap, handler)) = match Self::trap(proxy, "ownKeys", interpreter)? {
+            (target, None) => return interpreter.own_property_keys(&target),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let result = interpreter.call(&trap, &handler, &[target.clone()])?;
+        let mut keys: Vec<PropertyKey> = Vec::new();
+        for value in interpreter.create_list_from_array_like(&result)? {
+            let key = match value {
+                Value::String(ref string) => PropertyKey::from(string.clone()),
+                Value::Symbol(ref symbol) => PropertyKey::from(symbol.clone()),
+                _ => {
+                    return Err(Self::invariant_error(
+                        "ownKeys",
+                        "returned a value that is neither a string nor a symbol",
+                        interpreter,
+                    ))
+                }
+            };
+            if keys.contains(&key) {
+                return Err(Self::invariant_error(
+                    "ownKeys",
+                    "returned duplicate keys",
+                    interpreter,
+                ));
+            }
+            keys.push(key);
+        }
+
+        let extensible = interpreter.is_extensible(&target)?;
+        let mut configurable_keys = Vec::new();
+        let mut non_configurable_keys = Vec::new();
+        for key in interpreter.own_property_keys(&target)? {
+            let property = interpreter.get_own_property(&target, &key)?;
+            if !property.is_none() && !property.configurable_or(false) {
+                non_configurable_keys.push(key);
+            } else {
+                configurable_keys.push(key);
+            }
+        }
+        if extensible && non_configurable_keys.is_empty() {
+            return Ok(keys);
+        }
+
+        let mut unchecked_keys = keys.clone();
+        for key in non_configurable_keys {
+            match unchecked_keys
+                .iter()
+                .position(|unchecked| *unchecked == key)
+            {
+                Some(index) => {
+                    unchecked_keys.remove(index);
+                }
+                None => {
+                    return Err(Self::invariant_error(
+                        "ownKeys",
+                        "did not return all the non-configurable keys of the target",
+                        interpreter,
+                    ))
+                }
+            }
+        }
+        if extensible {
+            return Ok(keys);
+        }
+        for key in configurable_keys {
+            match unchecked_keys
+                .iter()
+                .position(|unchecked| *unchecked == key)
+            {
+                Some(index) => {
+                    unchecked_keys.remove(index);
+                }
+                None => {
+                    return Err(Self::invariant_error(
+                        "ownKeys",
+                        "did not return all the keys of the non-extensible target",
+                        interpreter,
+                    ))
+                }
+            }
+        }
+        if !unchecked_keys.is_empty() {
+            return Err(Self::invariant_error(
+                "ownKeys",
+                "returned keys that the non-extensible target doesn't have",
+                interpreter,
+            ));
+        }
+        Ok(keys)
+    }
+
+    /// `[[Call]] ( thisArgument, argumentsList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-call-thisargument-argumentslist
+    pub(crate) fn call(
+        proxy: &ProxyData,
+        this: &Value,
+        arguments_list: &[Value],
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let (target, (trap, handler)) = match Self::trap(proxy, "apply", interpreter)? {
+            (target, None) => return interpreter.call(&target, this, arguments_list),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let arguments = Array::new_array(interpreter)?;
+        Array::construct_array(&arguments, arguments_list)?;
+        interpreter.call(&trap, &handler, &[target, this.clone(), arguments])
+    }
+
+    /// `[[Construct]] ( argumentsList, newTarget )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-construct-argumentslist-newtarget
+    pub(crate) fn construct(
+        proxy: &ProxyData,
+        arguments_list: &[Value],
+        new_target: &Value,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let (target, (trap, handler)) = match Self::trap(proxy, "construct", interpreter)? {
+            (target, None) => return interpreter.construct(&target, arguments_list, new_target),
+            (target, Some(trap)) => (target, trap),
+        };
+
+        let arguments = Array::new_array(interpreter)?;
+        Array::construct_array(&arguments, arguments_list)?;
+        let object = interpreter.call(&trap, &handler, &[target, arguments, new_target.clone()])?;
+        if !object.is_object() {
+            return Err(Self::invariant_error(
+                "construct",
+                "did not return an object",
+                interpreter,
+            ));
+        }
+        Ok(object)
+    }
+
+    /// Creates the internal state of a proxy, throwing a `TypeError` if the target or the handler
+    /// is not an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxycreate
+    fn create(target: &Value, handler: &Value, ctx: &mut Interpreter) -> Result<ProxyData, Value> {
+        let (callable, constructor) = match target.as_object() {
+            Some(target) => (target.is_callable(), target.is_constructable()),
+            None => return Err(ctx.construct_type_error("Proxy: target must be an object")),
+        };
+        if !handler.is_object() {
+            return Err(ctx.construct_type_error("Proxy: handler must be an object"));
+        }
+
+        Ok(ProxyData {
+            target: target.clone(),
+            handler: handler.clone(),
+            callable,
+            constructor,
+        })
+    }
+
+    /// Create a new proxy
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-target-handler
+    pub(crate) fn make_proxy(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = args.get(0).cloned().unwrap_or_default();
+        let handler = args.get(1).cloned().unwrap_or_default();
+        let proxy = Self::create(&target, &handler, ctx)?;
+
+        this.set_data(ObjectData::Proxy(proxy));
+        if let Some(mut object) = this.as_object_mut() {
+            object.set_prototype(Value::null());
+        }
+        Ok(this.clone())
+    }
+
+    /// The function returned by `Proxy.revocable()`, which revokes the proxy it captured.
+    fn revoke(_: &Value, _: &[Value], proxy: &Value, _: &mut Interpreter) -> ResultValue {
+        if let Some(mut object) = proxy.as_object_mut() {
+            if let Some(proxy) = object.as_proxy_mut() {
+                proxy.target = Value::null();
+                proxy.handler = Value::null();
+            }
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `Proxy.revocable( target, handler )`
+    ///
+    /// This method creates a proxy along with a function that revokes it. Every operation on a
+    /// revoked proxy throws a `TypeError`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy.revocable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/revocable
+    pub(crate) fn revocable(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = args.get(0).cloned().unwrap_or_default();
+        let handler = args.get(1).cloned().unwrap_or_default();
+        let data = Self::create(&target, &handler, ctx)?;
+
+        let proxy = Value::new_object(None);
+        proxy.set_data(ObjectData::Proxy(data));
+        let revoke = make_capturing_fn(Self::revoke, 0, proxy.clone(), ctx);
+
+        let result = Value::new_object(Some(ctx.global()));
+        result.set_field("proxy", proxy);
+        result.set_field("revoke", revoke);
+        Ok(result)
+    }
+
+    /// Initialise the `Proxy` object on the global object.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let proxy_object = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_proxy,
+            global,
+            Value::new_object(None),
+            true,
+            false,
+        );
+        // `Proxy` has no `prototype` property, proxies don't inherit from anything.
+        proxy_object
+            .as_object_mut()
+            .expect("constructor is an object")
+            .remove_property("prototype");
+
+        make_builtin_fn(Self::revocable, "revocable", &proxy_object, 2, interpreter);
+
+        (Self::NAME, proxy_object)
+    }
+}
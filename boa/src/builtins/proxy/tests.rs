@@ -0,0 +1,452 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn forwards_to_target() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let target = { a: 1 };
+        let proxy = new Proxy(target, {});
+        proxy.b = 2;
+        delete proxy.a;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "target.b"), "2");
+    assert_eq!(forward(&mut engine, "'a' in target"), "false");
+    assert_eq!(forward(&mut engine, "proxy.b"), "2");
+    assert_eq!(forward(&mut engine, "'b' in proxy"), "true");
+    assert_eq!(forward(&mut engine, "typeof proxy"), "\"object\"");
+    assert_eq!(forward(&mut engine, "Proxy.prototype"), "undefined");
+}
+
+#[test]
+fn property_traps() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let log = [];
+        let target = { a: 1 };
+        let proxy = new Proxy(target, {
+            get(target, key, receiver) {
+                log.push("get " + key);
+                if (receiver !== proxy) {
+                    return 0;
+                }
+                return target[key] * 10;
+            },
+            set(target, key, value) {
+                log.push("set " + key);
+                target[key] = value + 1;
+                return true;
+            },
+            has(target, key) {
+                log.push("has " + key);
+                return key === "hidden";
+            },
+            deleteProperty(target, key) {
+                log.push("delete " + key);
+                return false;
+            },
+        });
+        let a = proxy.a;
+        proxy.b = 2;
+        let has = "hidden" in proxy;
+        let deleted = delete proxy.a;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "a"), "10");
+    assert_eq!(forward(&mut engine, "target.b"), "3");
+    assert_eq!(forward(&mut engine, "has"), "true");
+    assert_eq!(forward(&mut engine, "deleted"), "false");
+    assert_eq!(forward(&mut engine, "target.a"), "1");
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"get a,set b,has hidden,delete a\""
+    );
+}
+
+#[test]
+fn descriptor_and_keys_traps() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let defined;
+        let proxy = new Proxy({ x: 0, y: 0 }, {
+            ownKeys(target) {
+                return ["x", "y"];
+            },
+            getOwnPropertyDescriptor(target, key) {
+                return { value: key + "!", enumerable: key === "x", configurable: true };
+            },
+            defineProperty(target, key, descriptor) {
+                defined = key + ":" + descriptor.value + ":" + descriptor.writable;
+                return true;
+            },
+        });
+        let keys = [];
+        for (let key in proxy) {
+            keys.push(key);
+        }
+        Object.defineProperty(proxy, "z", { value: 1, writable: true });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "keys.join()"), "\"x\"");
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(proxy).join()"),
+        "\"x,y\""
+    );
+    assert_eq!(forward(&mut engine, "defined"), "\"z:1:true\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.getOwnPropertyDescriptor(proxy, 'y').value"
+        ),
+        "\"y!\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.getOwnPropertyDescriptor(proxy, 'y').writable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn prototype_and_extensibility_traps() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let prototype = { inherited: true };
+        let log = [];
+        let proxy = new Proxy({}, {
+            getPrototypeOf(target) {
+                log.push("getPrototypeOf");
+                return prototype;
+            },
+            setPrototypeOf(target, proto) {
+                log.push("setPrototypeOf");
+                return false;
+            },
+            isExtensible(target) {
+                log.push("isExtensible");
+                return Reflect.isExtensible(target);
+            },
+            preventExtensions(target) {
+                log.push("preventExtensions");
+                return Reflect.preventExtensions(target);
+            },
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Object.getPrototypeOf(proxy) === prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(proxy, null)"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(proxy)"), "true");
+    assert_eq!(
+        forward(&mut engine, "Reflect.preventExtensions(proxy)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(proxy)"), "false");
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"getPrototypeOf,setPrototypeOf,isExtensible,preventExtensions,isExtensible\""
+    );
+}
+
+#[test]
+fn function_traps() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function target(a, b) {
+            return a + b;
+        }
+        let proxy = new Proxy(target, {
+            apply(target, thisArgument, args) {
+                return target(args[0], args[1]) * 2;
+            },
+            construct(target, args, newTarget) {
+                return { args: args.length, sameTarget: newTarget === proxy };
+            },
+        });
+        let constructed = new proxy(1, 2, 3);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "typeof proxy"), "\"function\"");
+    assert_eq!(forward(&mut engine, "proxy(1, 2)"), "6");
+    assert_eq!(forward(&mut engine, "constructed.args"), "3");
+    assert_eq!(forward(&mut engine, "constructed.sameTarget"), "true");
+    assert_eq!(forward(&mut engine, "new Proxy(target, {})(2, 3)"), "5");
+}
+
+#[test]
+fn inherited_from_proxy() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let proxy = new Proxy({}, {
+            get(target, key, receiver) {
+                if (key === "self") {
+                    return receiver;
+                }
+                return "missing " + key;
+            },
+        });
+        let object = Object.create(proxy);
+        object.own = 1;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "object.own"), "1");
+    assert_eq!(forward(&mut engine, "object.other"), "\"missing other\"");
+    assert_eq!(forward(&mut engine, "object.self === object"), "true");
+}
+
+#[test]
+fn invariants() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function message(f) {
+            try {
+                f();
+            } catch (e) {
+                return e.toString();
+            }
+        }
+        let target = {};
+        Object.defineProperty(target, "fixed", { value: 1 });
+        let proxy = new Proxy(target, {
+            get() { return 2; },
+            has() { return false; },
+            ownKeys() { return []; },
+            deleteProperty() { return true; },
+            getOwnPropertyDescriptor() { return undefined; },
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "message(() => proxy.fixed)"),
+        "\"TypeError: proxy trap 'get' reported a different value for a non-writable, non-configurable property\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => 'fixed' in proxy)"),
+        "\"TypeError: proxy trap 'has' reported a non-configurable property as non-existent\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => Reflect.ownKeys(proxy))"),
+        "\"TypeError: proxy trap 'ownKeys' did not return all the non-configurable keys of the target\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => delete proxy.fixed)"),
+        "\"TypeError: proxy trap 'deleteProperty' deleted a non-configurable property\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(() => Reflect.getOwnPropertyDescriptor(proxy, 'fixed'))"
+        ),
+        "\"TypeError: proxy trap 'getOwnPropertyDescriptor' reported a non-configurable property as non-existent\""
+    );
+    assert_eq!(forward(&mut engine, "proxy.other"), "2");
+
+    let init = r#"
+        Reflect.preventExtensions(target);
+        let frozen = new Proxy(target, {
+            getPrototypeOf() { return Array.prototype; },
+            isExtensible() { return true; },
+            defineProperty() { return true; },
+            ownKeys() { return ["fixed", "extra"]; },
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "message(() => Object.getPrototypeOf(frozen))"),
+        "\"TypeError: proxy trap 'getPrototypeOf' did not return the prototype of the non-extensible target\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => Reflect.isExtensible(frozen))"),
+        "\"TypeError: proxy trap 'isExtensible' did not return the extensibility of the target\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(() => Reflect.defineProperty(frozen, 'new', { value: 1 }))"
+        ),
+        "\"TypeError: proxy trap 'defineProperty' added a property to a non-extensible target\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => Reflect.ownKeys(frozen))"),
+        "\"TypeError: proxy trap 'ownKeys' returned keys that the non-extensible target doesn't have\""
+    );
+}
+
+#[test]
+fn invalid_arguments() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function message(f) {
+            try {
+                f();
+            } catch (e) {
+                return e.toString();
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "message(() => new Proxy(1, {}))"),
+        "\"TypeError: Proxy: target must be an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => new Proxy({}, null))"),
+        "\"TypeError: Proxy: handler must be an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => Proxy({}, {}))"),
+        "\"TypeError: Proxy constructor requires 'new'\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => new Proxy({}, { get: 1 }).a)"),
+        "\"TypeError: proxy trap 'get' is not a function\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => new Proxy({}, {})())"),
+        "\"TypeError: not a function\""
+    );
+}
+
+#[test]
+fn revocable() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let { proxy, revoke } = Proxy.revocable({ a: 1 }, {});
+        let before = proxy.a;
+        revoke();
+        revoke();
+        let message;
+        try {
+            proxy.a;
+        } catch (e) {
+            message = e.toString();
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "before"), "1");
+    assert_eq!(
+        forward(&mut engine, "message"),
+        "\"TypeError: cannot perform 'get' on a proxy that has been revoked\""
+    );
+    assert_eq!(forward(&mut engine, "revoke.length"), "0");
+}
+
+#[test]
+fn instanceof_and_is_array() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        class Foo {}
+        let log = [];
+        let instance = new Proxy({}, {
+            getPrototypeOf(target) {
+                log.push("getPrototypeOf");
+                return Foo.prototype;
+            }
+        });
+        let constructor = new Proxy(function() {}, {
+            get(target, key) {
+                log.push("get " + key);
+                if (key === "prototype") {
+                    return Foo.prototype;
+                }
+                return target[key];
+            }
+        });
+        let { proxy, revoke } = Proxy.revocable([], {});
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "instance instanceof Foo"), "true");
+    assert_eq!(
+        forward(&mut engine, "new Foo() instanceof constructor"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"getPrototypeOf,get prototype\""
+    );
+    assert_eq!(forward(&mut engine, "Array.isArray(proxy)"), "true");
+    assert_eq!(
+        forward(&mut engine, "Array.isArray(new Proxy(proxy, {}))"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Array.isArray(instance)"), "false");
+    forward(&mut engine, "revoke()");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Array.isArray(proxy) } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: cannot perform 'IsArray' on a proxy that has been revoked\""
+    );
+}
+
+#[test]
+fn iteration() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let log = [];
+        let logged = new Proxy([4, 5], {
+            get(target, key, receiver) {
+                if (typeof key !== "symbol") {
+                    log.push(key);
+                }
+                return Reflect.get(target, key, receiver);
+            }
+        });
+        let values = [];
+        for (let x of new Proxy([], {})) {
+            values.push(x);
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "[...new Proxy([1, 2, 3], {})].join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(forward(&mut engine, "values.length"), "0");
+    assert_eq!(forward(&mut engine, "[...logged].join()"), "\"4,5\"");
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"length,0,length,1,length\""
+    );
+}
+
+#[test]
+fn thenable() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let results = [];
+        let forwarded = new Proxy({ then(resolve) { resolve("target"); } }, {});
+        let trapped = new Proxy({}, {
+            get(target, key) {
+                if (key === "then") {
+                    return resolve => resolve("trap");
+                }
+            }
+        });
+        Promise.resolve(forwarded).then(value => results.push(value));
+        new Promise(resolve => resolve(trapped)).then(value => results.push(value));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "results.join()"), "\"target,trap\"");
+}
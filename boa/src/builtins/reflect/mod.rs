@@ -0,0 +1,401 @@
+//! This module implements the global `Reflect` object.
+//!
+//! `Reflect` is a built-in object that provides a function for each internal method of objects,
+//! with the same behaviour as the default operations done on a `Proxy` without a trap. It's not a
+//! function object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-reflect-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+        Array,
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Javascript `Reflect` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Reflect;
+
+impl Reflect {
+    /// The name of the object.
+    pub(crate) const NAME: &'static str = "Reflect";
+
+    /// Helper function to get the target argument of a `Reflect` function, throwing a `TypeError`
+    /// if it isn't an object.
+    fn target(name: &str, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        match args.get(0) {
+            Some(target) if target.is_object() => Ok(target.clone()),
+            _ => {
+                interpreter.throw_type_error(format!("Reflect.{}: target must be an object", name))
+            }
+        }
+    }
+
+    /// `Reflect.apply( target, thisArgument, argumentsList )`
+    ///
+    /// Calls the target function with the given `this` value and the elements of the array-like
+    /// `argumentsList` as arguments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.apply
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/apply
+    pub(crate) fn apply(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = args.get(0).cloned().unwrap_or_default();
+        if !target.is_function() {
+            return ctx.throw_type_error("Reflect.apply: target must be a function");
+        }
+        let this = args.get(1).cloned().unwrap_or_default();
+        let arguments =
+            ctx.create_list_from_array_like(&args.get(2).cloned().unwrap_or_default())?;
+        ctx.call(&target, &this, &arguments)
+    }
+
+    /// `Reflect.construct( target, argumentsList [ , newTarget ] )`
+    ///
+    /// Calls the target constructor like the `new` operator, with the elements of the array-like
+    /// `argumentsList` as arguments. The prototype of the new object is taken from `newTarget`,
+    /// which is the target by default.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.construct
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct
+    pub(crate) fn construct(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = args.get(0).cloned().unwrap_or_default();
+        if !target
+            .as_object()
+            .map_or(false, |target| target.is_constructable())
+        {
+            return ctx.throw_type_error("Reflect.construct: target must be a constructor");
+        }
+        let new_target = match args.get(2) {
+            Some(new_target) => new_target.clone(),
+            None => target.clone(),
+        };
+        if !new_target
+            .as_object()
+            .map_or(false, |new_target| new_target.is_constructable())
+        {
+            return ctx.throw_type_error("Reflect.construct: newTarget must be a constructor");
+        }
+        let arguments =
+            ctx.create_list_from_array_like(&args.get(1).cloned().unwrap_or_default())?;
+        ctx.construct(&target, &arguments, &new_target)
+    }
+
+    /// `Reflect.defineProperty( target, propertyKey, attributes )`
+    ///
+    /// Defines a property of the target like `Object.defineProperty`, but returns whether it
+    /// could be defined instead of throwing.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.defineproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/defineProperty
+    pub(crate) fn define_property(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = Self::target("defineProperty", args, ctx)?;
+        let key = ctx.to_property_key(&args.get(1).cloned().unwrap_or_default())?;
+        let property = ctx.to_property_descriptor(&args.get(2).cloned().unwrap_or_default())?;
+        Ok(ctx.define_property(&target, key, property)?.into())
+    }
+
+    /// `Reflect.deleteProperty( target, propertyKey )`
+    ///
+    /// Deletes a property of the target like the `delete` operator, and returns whether it could
+    /// be deleted.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.deleteproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/deleteProperty
+    pub(crate) fn delete_property(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = Self::target("deleteProperty", args, ctx)?;
+        let key = ctx.to_property_key(&args.get(1).cloned().unwrap_or_default())?;
+        Ok(ctx.delete_property(&target, key)?.into())
+    }
+
+    /// `Reflect.get( target, propertyKey [ , receiver ] )`
+    ///
+    /// Gets the value of a property of the target, calling its getter with the receiver as
+    /// `this`, which is the target by default.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/get
+    pub(crate) fn get(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = Self::target("get", args, ctx)?;
+        let key = ctx.to_property_key(&args.get(1).cloned().unwrap_or_default())?;
+        let receiver = args.get(2).cloned().unwrap_or_else(|| target.clone());
+        ctx.get_field_with_receiver(&target, key, &receiver)
+    }
+
+    /// `Reflect.getOwnPropertyDescriptor( target, propertyKey )`
+    ///
+    /// Returns the descriptor of an own property of the target, or undefined if it doesn't have
+    /// the property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.getownpropertydescriptor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getOwnPropertyDescriptor
+    pub(crate) fn get_own_property_descriptor(
+        _: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let target = Self::target("getOwnPropertyDescriptor", args, ctx)?;
+        let key = ctx.to_property_key(&args.get(1).cloned().unwrap_or_default())?;
+        let property = ctx.get_own_property(&target, &key)?;
+        if property.is_none() {
+            return Ok(Value::undefined());
+        }
+        Ok(ctx.from_property_descriptor(&property))
+    }
+
+    /// `Reflect.getPrototypeOf( target )`
+    ///
+    /// Returns the prototype of the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.getprototypeof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getPrototypeOf
+    pub(crate) fn get_prototype_of(
+        _: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let target = Self::target("getPrototypeOf", args, ctx)?;
+        ctx.get_prototype_of(&target)
+    }
+
+    /// `Reflect.has( target, propertyKey )`
+    ///
+    /// Checks if the target or one of its prototypes has the property, like the `in` operator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/has
+    pub(crate) fn has(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = Self::target("has", args, ctx)?;
+        let key = ctx.to_property_key(&args.get(1).cloned().unwrap_or_default())?;
+        Ok(ctx.has_property(&target, &key)?.into())
+    }
+
+    /// `Reflect.isExtensible( target )`
+    ///
+    /// Checks if properties can be added to the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.isextensible
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/isExtensible
+    pub(crate) fn is_extensible(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = Self::target("isExtensible", args, ctx)?;
+        Ok(ctx.is_extensible(&target)?.into())
+    }
+
+    /// `Reflect.ownKeys( target )`
+    ///
+    /// Returns an array of the keys of the own properties of the target, strings and symbols,
+    /// enumerable or not.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.ownkeys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/ownKeys
+    pub(crate) fn own_keys(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = Self::target("ownKeys", args, ctx)?;
+        let keys: Vec<Value> = ctx
+            .own_property_keys(&target)?
+            .iter()
+            .map(Value::from)
+            .collect();
+        let array = Array::new_array(ctx)?;
+        Array::construct_array(&array, &keys)
+    }
+
+    /// `Reflect.preventExtensions( target )`
+    ///
+    /// Prevents properties from being added to the target, and returns whether it succeeded.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.preventextensions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/preventExtensions
+    pub(crate) fn prevent_extensions(
+        _: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let target = Self::target("preventExtensions", args, ctx)?;
+        Ok(ctx.prevent_extensions(&target)?.into())
+    }
+
+    /// `Reflect.set( target, propertyKey, V [ , receiver ] )`
+    ///
+    /// Sets the value of a property of the target, calling its setter with the receiver as
+    /// `this`, which is the target by default. Returns whether the property could be set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set
+    pub(crate) fn set(_: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = Self::target("set", args, ctx)?;
+        let key = ctx.to_property_key(&args.get(1).cloned().unwrap_or_default())?;
+        let value = args.get(2).cloned().unwrap_or_default();
+        let receiver = args.get(3).cloned().unwrap_or_else(|| target.clone());
+        Ok(ctx
+            .set_field_with_receiver(&target, key, value, &receiver)?
+            .into())
+    }
+
+    /// `Reflect.setPrototypeOf( target, proto )`
+    ///
+    /// Sets the prototype of the target, and returns whether it could be changed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.setprototypeof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/setPrototypeOf
+    pub(crate) fn set_prototype_of(
+        _: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let target = Self::target("setPrototypeOf", args, ctx)?;
+        let prototype = args.get(1).cloned().unwrap_or_default();
+        if !prototype.is_object() && !prototype.is_null() {
+            return ctx.throw_type_error("Reflect.setPrototypeOf: proto must be an object or null");
+        }
+        Ok(ctx.set_prototype_of(&target, prototype)?.into())
+    }
+
+    /// Create a new `Reflect` object
+    pub(crate) fn create(interpreter: &mut Interpreter) -> Value {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event("reflect:create", "init");
+        let reflect = Value::new_object(Some(global));
+
+        make_builtin_fn(Self::apply, "apply", &reflect, 3, interpreter);
+        make_builtin_fn(Self::construct, "construct", &reflect, 2, interpreter);
+        make_builtin_fn(
+            Self::define_property,
+            "defineProperty",
+            &reflect,
+            3,
+            interpreter,
+        );
+        make_builtin_fn(
+            Self::delete_property,
+            "deleteProperty",
+            &reflect,
+            2,
+            interpreter,
+        );
+        make_builtin_fn(Self::get, "get", &reflect, 2, interpreter);
+        make_builtin_fn(
+            Self::get_own_property_descriptor,
+            "getOwnPropertyDescriptor",
+            &reflect,
+            2,
+            interpreter,
+        );
+        make_builtin_fn(
+            Self::get_prototype_of,
+            "getPrototypeOf",
+            &reflect,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(Self::has, "has", &reflect, 2, interpreter);
+        make_builtin_fn(
+            Self::is_extensible,
+            "isExtensible",
+            &reflect,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(Self::own_keys, "ownKeys", &reflect, 1, interpreter);
+        make_builtin_fn(
+            Self::prevent_extensions,
+            "preventExtensions",
+            &reflect,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(Self::set, "set", &reflect, 3, interpreter);
+        make_builtin_fn(
+            Self::set_prototype_of,
+            "setPrototypeOf",
+            &reflect,
+            2,
+            interpreter,
+        );
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        reflect
+            .as_object_mut()
+            .expect("Reflect is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from(Self::NAME),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        reflect
+    }
+
+    /// Initialise the `Reflect` object on the global object.
+    #[inline]
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        (Self::NAME, Self::create(interpreter))
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn apply_and_construct() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function sum(a, b) {
+            return this.base + a + b;
+        }
+        class Point {
+            constructor(x, y) {
+                this.x = x;
+                this.y = y;
+            }
+        }
+        class Other {}
+        let point = Reflect.construct(Point, [1, 2]);
+        let other = Reflect.construct(Point, [3], Other);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Reflect.apply(sum, { base: 10 }, [1, 2])"),
+        "13"
+    );
+    assert_eq!(forward(&mut engine, "point.x + point.y"), "3");
+    assert_eq!(forward(&mut engine, "point instanceof Point"), "true");
+    assert_eq!(forward(&mut engine, "other.x"), "3");
+    assert_eq!(forward(&mut engine, "other instanceof Other"), "true");
+}
+
+#[test]
+fn properties() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let object = {
+            a: 1,
+            get double() { return this.a * 2; },
+            set double(value) { this.a = value / 2; },
+        };
+        let receiver = { a: 10 };
+        let symbol = Symbol("s");
+        object[symbol] = 2;
+        Reflect.defineProperty(object, "fixed", { value: 3 });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "Reflect.get(object, 'double')"), "2");
+    assert_eq!(
+        forward(&mut engine, "Reflect.get(object, 'double', receiver)"),
+        "20"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.set(object, 'double', 8, receiver)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "receiver.a"), "4");
+    assert_eq!(
+        forward(&mut engine, "Reflect.set(object, 'fixed', 4)"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "object.fixed"), "3");
+    assert_eq!(
+        forward(&mut engine, "Reflect.has(object, 'toString')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.deleteProperty(object, 'fixed')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.deleteProperty(object, 'a')"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.has(object, 'a')"), "false");
+    assert_eq!(forward(&mut engine, "Reflect.ownKeys(object).length"), "3");
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(object)[2] === symbol"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(object, 'fixed', { value: 4 })"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn get_own_property_descriptor() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let object = { a: 1, get b() { return 2; } };
+        let a = Reflect.getOwnPropertyDescriptor(object, "a");
+        let b = Reflect.getOwnPropertyDescriptor(object, "b");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "a.value"), "1");
+    assert_eq!(forward(&mut engine, "a.writable"), "true");
+    assert_eq!(forward(&mut engine, "a.enumerable"), "true");
+    assert_eq!(forward(&mut engine, "a.configurable"), "true");
+    assert_eq!(forward(&mut engine, "typeof b.get"), "\"function\"");
+    assert_eq!(forward(&mut engine, "'writable' in b"), "false");
+    assert_eq!(
+        forward(&mut engine, "Reflect.getOwnPropertyDescriptor(object, 'c')"),
+        "undefined"
+    );
+}
+
+#[test]
+fn prototype_and_extensibility() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let prototype = {};
+        let object = {};
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.getPrototypeOf(object) === Object.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(object, prototype)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.getPrototypeOf(object) === prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(prototype, object)"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(object)"), "true");
+    assert_eq!(
+        forward(&mut engine, "Reflect.preventExtensions(object)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.isExtensible(object)"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(object, null)"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(object, 'a', { value: 1 })"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn invalid_arguments() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function message(f) {
+            try {
+                f();
+            } catch (e) {
+                return e.toString();
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "message(() => Reflect.get(1, 'a'))"),
+        "\"TypeError: Reflect.get: target must be an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => Reflect.apply({}, null, []))"),
+        "\"TypeError: Reflect.apply: target must be a function\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(() => Reflect.apply(() => 1, null, 1))"
+        ),
+        "\"TypeError: cannot create a list from a non-object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => Reflect.construct(() => 1, []))"),
+        "\"TypeError: Reflect.construct: target must be a constructor\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(() => Reflect.setPrototypeOf({}, 1))"),
+        "\"TypeError: Reflect.setPrototypeOf: proto must be an object or null\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect[Symbol.toStringTag]"),
+        "\"Reflect\""
+    );
+}
@@ -0,0 +1,699 @@
+//! This module implements the global `Set` object.
+//!
+//! The `Set` object lets you store unique values of any type, whether primitive values or object
+//! references. Values are compared with the `SameValueZero` algorithm, and iterated in insertion
+//! order.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-set-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set
+
+#![allow(clippy::mutable_key_type)]
+
+use super::function::{make_builtin_fn, make_constructor_fn, Function};
+use crate::{
+    builtins::{
+        iterable::{get_iterator, IteratorRecord},
+        object::{Object, ObjectData, PROTOTYPE},
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use ordered_set::OrderedSet;
+use set_iterator::{SetIterationKind, SetIterator};
+
+pub mod ordered_set;
+pub mod set_iterator;
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Set;
+
+impl Set {
+    pub(crate) const NAME: &'static str = "Set";
+
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Helper function to access the values of `this`, throwing a `TypeError` if it isn't a set.
+    fn with_set<R, F>(this: &Value, interpreter: &mut Interpreter, f: F) -> Result<R, Value>
+    where
+        F: FnOnce(&mut OrderedSet<Value>) -> R,
+    {
+        if let Some(mut object) = this.as_object_mut() {
+            if let Some(set) = object.as_set_mut() {
+                return Ok(f(set));
+            }
+        }
+        Err(interpreter.construct_type_error("'this' is not a Set"))
+    }
+
+    /// Helper function to get the next value of the set from `cursor`, as the set is now, and move
+    /// the cursor past it.
+    ///
+    /// Iterating with this, instead of over a copy of the values, visits the values that are added
+    /// by callbacks while iterating and skips the ones they delete, like the specification
+    /// requires.
+    fn next_value(set: &Value, cursor: &mut u64) -> Option<Value> {
+        let (value, next) = set.as_object().and_then(|object| {
+            object
+                .as_set_ref()
+                .and_then(|set| set.get_from(*cursor))
+                .map(|(value, next)| (value.clone(), next))
+        })?;
+        *cursor = next;
+        Some(value)
+    }
+
+    /// Helper function to check if the set contains `value`.
+    fn contains(set: &Value, value: &Value) -> bool {
+        set.as_object().map_or(false, |object| {
+            object.as_set_ref().map_or(false, |set| set.contains(value))
+        })
+    }
+
+    /// Helper function to canonicalize a value before adding it to a set, so that `-0` is stored
+    /// as `+0`.
+    fn canonicalize(value: Value) -> Value {
+        match value {
+            Value::Rational(number) if number == 0.0 => Value::integer(0),
+            value => value,
+        }
+    }
+
+    /// Helper function to create a new set with the given values.
+    fn create(values: OrderedSet<Value>, interpreter: &Interpreter) -> Value {
+        let prototype = interpreter
+            .global()
+            .get_field(Self::NAME)
+            .get_field(PROTOTYPE);
+        Value::new_object_from_prototype(prototype, ObjectData::Set(values))
+    }
+
+    /// `Set.prototype.add( value )`
+    ///
+    /// This method appends the value to the set, if it isn't already in it. Returns the set
+    /// object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add
+    pub(crate) fn add(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let value = Self::canonicalize(args.get(0).cloned().unwrap_or_default());
+        Self::with_set(this, ctx, |set| set.insert(value))?;
+        Ok(this.clone())
+    }
+
+    /// `Set.prototype.clear( )`
+    ///
+    /// This method removes all the values from the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.clear
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/clear
+    pub(crate) fn clear(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::with_set(this, ctx, OrderedSet::clear)?;
+        Ok(Value::undefined())
+    }
+
+    /// `Set.prototype.delete( value )`
+    ///
+    /// This method removes the value from the set. Returns true if it was in the set, false
+    /// otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Ok(Self::with_set(this, ctx, |set| set.remove(&value))?.into())
+    }
+
+    /// `Set.prototype.has( value )`
+    ///
+    /// This method checks if the value is in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/has
+    pub(crate) fn has(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Ok(Self::with_set(this, ctx, |set| set.contains(&value))?.into())
+    }
+
+    /// `get Set.prototype.size`
+    ///
+    /// This accessor returns the number of values in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-set.prototype.size
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/size
+    pub(crate) fn size(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Ok(Self::with_set(this, ctx, |set| set.len())?.into())
+    }
+
+    /// `Set.prototype.forEach( callbackFn [ , thisArg ] )`
+    ///
+    /// This method executes the provided callback function for each value in the set, in
+    /// insertion order. Values added by the callback are visited too.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/forEach
+    pub(crate) fn for_each(
+        this: &Value,
+        args: &[Value],
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        Self::with_set(this, interpreter, |_| ())?;
+
+        let callback = args.get(0).cloned().unwrap_or_default();
+        if !callback.is_function() {
+            return interpreter
+                .throw_type_error("Set.prototype.forEach: callback is not a function");
+        }
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        let mut cursor = 0;
+        while let Some(value) = Self::next_value(this, &mut cursor) {
+            interpreter.call(&callback, &this_arg, &[value.clone(), value, this.clone()])?;
+        }
+
+        Ok(Value::undefined())
+    }
+
+    /// Helper function to create an iterator over the values of a set.
+    fn create_iterator(
+        this: &Value,
+        kind: SetIterationKind,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        if !this.as_object().map_or(false, |object| object.is_set()) {
+            return interpreter.throw_type_error("'this' is not a Set");
+        }
+        Ok(SetIterator::create_set_iterator(
+            interpreter,
+            this.clone(),
+            kind,
+        ))
+    }
+
+    /// `Set.prototype.entries( )`
+    ///
+    /// The entries method returns an iterator over `[value, value]` pairs for each value of the
+    /// set, in insertion order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/entries
+    pub(crate) fn entries(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        Self::create_iterator(this, SetIterationKind::KeyAndValue, interpreter)
+    }
+
+    /// `Set.prototype.values( )`
+    ///
+    /// The values method returns an iterator over the values of the set, in insertion order. It is
+    /// also the `keys()` and `[Symbol.iterator]()` methods of sets.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values
+    pub(crate) fn values(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        Self::create_iterator(this, SetIterationKind::Value, interpreter)
+    }
+
+    /// `Set.prototype.union( other )`
+    ///
+    /// This method returns a new set with the values of the set followed by the values of the
+    /// set-like object `other` that aren't in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.union
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/union
+    pub(crate) fn union(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let mut result = Self::with_set(this, ctx, |set| set.clone())?;
+        let other = SetRecord::new(&args.get(0).cloned().unwrap_or_default(), ctx)?;
+
+        let keys = other.keys(ctx)?;
+        loop {
+            let next = keys.next(ctx)?;
+            if next.is_done() {
+                break;
+            }
+            result.insert(Self::canonicalize(next.into_value()));
+        }
+
+        Ok(Self::create(result, ctx))
+    }
+
+    /// `Set.prototype.intersection( other )`
+    ///
+    /// This method returns a new set with the values of the set that are also in the set-like
+    /// object `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.intersection
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/intersection
+    pub(crate) fn intersection(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let size = Self::with_set(this, ctx, |set| set.len())?;
+        let other = SetRecord::new(&args.get(0).cloned().unwrap_or_default(), ctx)?;
+
+        let mut result = OrderedSet::new();
+        if size as f64 <= other.size {
+            let mut cursor = 0;
+            while let Some(value) = Self::next_value(this, &mut cursor) {
+                if other.has(value.clone(), ctx)? {
+                    result.insert(value);
+                }
+            }
+        } else {
+            let keys = other.keys(ctx)?;
+            loop {
+                let next = keys.next(ctx)?;
+                if next.is_done() {
+                    break;
+                }
+                let value = Self::canonicalize(next.into_value());
+                if Self::contains(this, &value) {
+                    result.insert(value);
+                }
+            }
+        }
+
+        Ok(Self::create(result, ctx))
+    }
+
+    /// `Set.prototype.difference( other )`
+    ///
+    /// This method returns a new set with the values of the set that aren't in the set-like object
+    /// `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.difference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/difference
+    pub(crate) fn difference(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let mut result = Self::with_set(this, ctx, |set| set.clone())?;
+        let other = SetRecord::new(&args.get(0).cloned().unwrap_or_default(), ctx)?;
+
+        if result.len() as f64 <= other.size {
+            let mut cursor = 0;
+            while let Some(value) = Self::next_value(this, &mut cursor) {
+                if other.has(value.clone(), ctx)? {
+                    result.remove(&value);
+                }
+            }
+        } else {
+            let keys = other.keys(ctx)?;
+            loop {
+                let next = keys.next(ctx)?;
+                if next.is_done() {
+                    break;
+                }
+                result.remove(&Self::canonicalize(next.into_value()));
+            }
+        }
+
+        Ok(Self::create(result, ctx))
+    }
+
+    /// `Set.prototype.symmetricDifference( other )`
+    ///
+    /// This method returns a new set with the values that are either in the set or in the
+    /// set-like object `other`, but not in both.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.symmetricdifference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/symmetricDifference
+    pub(crate) fn symmetric_difference(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let mut result = Self::with_set(this, ctx, |set| set.clone())?;
+        let other = SetRecord::new(&args.get(0).cloned().unwrap_or_default(), ctx)?;
+
+        let keys = other.keys(ctx)?;
+        loop {
+            let next = keys.next(ctx)?;
+            if next.is_done() {
+                break;
+            }
+            let value = Self::canonicalize(next.into_value());
+            let in_result = result.contains(&value);
+            if Self::contains(this, &value) {
+                if in_result {
+                    result.remove(&value);
+                }
+            } else if !in_result {
+                result.insert(value);
+            }
+        }
+
+        Ok(Self::create(result, ctx))
+    }
+
+    /// `Set.prototype.isSubsetOf( other )`
+    ///
+    /// This method checks if all the values of the set are in the set-like object `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.issubsetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSubsetOf
+    pub(crate) fn is_subset_of(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let size = Self::with_set(this, ctx, |set| set.len())?;
+        let other = SetRecord::new(&args.get(0).cloned().unwrap_or_default(), ctx)?;
+
+        if size as f64 > other.size {
+            return Ok(false.into());
+        }
+
+        let mut cursor = 0;
+        while let Some(value) = Self::next_value(this, &mut cursor) {
+            if !other.has(value, ctx)? {
+                return Ok(false.into());
+            }
+        }
+
+        Ok(true.into())
+    }
+
+    /// `Set.prototype.isSupersetOf( other )`
+    ///
+    /// This method checks if all the values of the set-like object `other` are in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.issupersetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSupersetOf
+    pub(crate) fn is_superset_of(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let size = Self::with_set(this, ctx, |set| set.len())?;
+        let other = SetRecord::new(&args.get(0).cloned().unwrap_or_default(), ctx)?;
+
+        if (size as f64) < other.size {
+            return Ok(false.into());
+        }
+
+        let keys = other.keys(ctx)?;
+        loop {
+            let next = keys.next(ctx)?;
+            if next.is_done() {
+                return Ok(true.into());
+            }
+            if !Self::contains(this, next.value()) {
+                return keys.close(Ok(false.into()), ctx);
+            }
+        }
+    }
+
+    /// `Set.prototype.isDisjointFrom( other )`
+    ///
+    /// This method checks if the set and the set-like object `other` have no values in common.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.isdisjointfrom
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isDisjointFrom
+    pub(crate) fn is_disjoint_from(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let size = Self::with_set(this, ctx, |set| set.len())?;
+        let other = SetRecord::new(&args.get(0).cloned().unwrap_or_default(), ctx)?;
+
+        if size as f64 <= other.size {
+            let mut cursor = 0;
+            while let Some(value) = Self::next_value(this, &mut cursor) {
+                if other.has(value, ctx)? {
+                    return Ok(false.into());
+                }
+            }
+        } else {
+            let keys = other.keys(ctx)?;
+            loop {
+                let next = keys.next(ctx)?;
+                if next.is_done() {
+                    break;
+                }
+                if Self::contains(this, next.value()) {
+                    return keys.close(Ok(false.into()), ctx);
+                }
+            }
+        }
+
+        Ok(true.into())
+    }
+
+    /// Create a new set
+    ///
+    /// The values of the iterable argument are added with the `add()` method of the new set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterable
+    pub(crate) fn make_set(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        this.set_data(ObjectData::Set(OrderedSet::new()));
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(this.clone());
+        }
+
+        let adder = ctx.get_field(this, "add")?;
+        if !adder.is_function() {
+            return ctx.throw_type_error("'add' of the new Set is not a function");
+        }
+
+        let iterator = get_iterator(ctx, &iterable)?;
+        loop {
+            let next = iterator.next(ctx)?;
+            if next.is_done() {
+                return Ok(this.clone());
+            }
+            if let Err(error) = ctx.call(&adder, this, &[next.into_value()]) {
+                return iterator.close(Err(error), ctx);
+            }
+        }
+    }
+
+    /// Initialise the `Set` object on the global object.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let prototype = Value::new_object(Some(global));
+
+        make_builtin_fn(Self::add, "add", &prototype, 1, interpreter);
+        make_builtin_fn(Self::clear, "clear", &prototype, 0, interpreter);
+        make_builtin_fn(Self::delete, "delete", &prototype, 1, interpreter);
+        make_builtin_fn(Self::has, "has", &prototype, 1, interpreter);
+        make_builtin_fn(Self::for_each, "forEach", &prototype, 1, interpreter);
+        make_builtin_fn(Self::entries, "entries", &prototype, 0, interpreter);
+        make_builtin_fn(Self::values, "values", &prototype, 0, interpreter);
+        make_builtin_fn(Self::union, "union", &prototype, 1, interpreter);
+        make_builtin_fn(
+            Self::intersection,
+            "intersection",
+            &prototype,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(Self::difference, "difference", &prototype, 1, interpreter);
+        make_builtin_fn(
+            Self::symmetric_difference,
+            "symmetricDifference",
+            &prototype,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(Self::is_subset_of, "isSubsetOf", &prototype, 1, interpreter);
+        make_builtin_fn(
+            Self::is_superset_of,
+            "isSupersetOf",
+            &prototype,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(
+            Self::is_disjoint_from,
+            "isDisjointFrom",
+            &prototype,
+            1,
+            interpreter,
+        );
+
+        // `Set.prototype.keys` and `Set.prototype[Symbol.iterator]` are the same function object
+        // as `values`.
+        let symbol_iterator = interpreter.well_known_symbols().iterator_symbol();
+        let values = prototype.get_field("values");
+        prototype.set_field("keys", values.clone());
+        prototype.set_field(symbol_iterator, values);
+
+        let function_prototype = global.get_field("Function").get_field(PROTOTYPE);
+        let mut size = Object::function(
+            Function::builtin(Vec::new(), Self::size),
+            function_prototype,
+        );
+        size.insert_field("length", Value::from(0));
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        {
+            let mut prototype = prototype.as_object_mut().expect("prototype is an object");
+            let mut size = Property::default().get(Value::from(size));
+            size.attribute = Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+            prototype.insert_property("size", size);
+            prototype.insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from(Self::NAME),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+        }
+
+        let set_object = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_set,
+            global,
+            prototype,
+            true,
+            false,
+        );
+
+        (Self::NAME, set_object)
+    }
+}
+
+/// A set-like object, with the size and the `has()` and `keys()` methods that the set methods
+/// taking another set use.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set-records
+#[derive(Debug)]
+struct SetRecord {
+    set: Value,
+    size: f64,
+    has: Value,
+    keys: Value,
+}
+
+impl SetRecord {
+    /// Gets the size and the methods of the set-like object `value`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getsetrecord
+    fn new(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        if !value.is_object() {
+            return Err(interpreter.construct_type_error("the argument is not a set-like object"));
+        }
+
+        let size = interpreter.get_field(value, "size")?;
+        let size = interpreter.to_number(&size)?;
+        if size.is_nan() {
+            return Err(interpreter.construct_type_error("the size of the set-like object is NaN"));
+        }
+        let size = size.trunc();
+        if size < 0.0 {
+            return Err(
+                interpreter.construct_range_error("the size of the set-like object is negative")
+            );
+        }
+
+        let has = interpreter.get_field(value, "has")?;
+        if !has.is_function() {
+            return Err(
+                interpreter.construct_type_error("'has' of the set-like object is not a function")
+            );
+        }
+        let keys = interpreter.get_field(value, "keys")?;
+        if !keys.is_function() {
+            return Err(
+                interpreter.construct_type_error("'keys' of the set-like object is not a function")
+            );
+        }
+
+        Ok(Self {
+            set: value.clone(),
+            size,
+            has,
+            keys,
+        })
+    }
+
+    /// Checks if `value` is in the set-like object, with its `has()` method.
+    fn has(&self, value: Value, interpreter: &mut Interpreter) -> Result<bool, Value> {
+        Ok(interpreter
+            .call(&self.has, &self.set, &[value])?
+            .to_boolean())
+    }
+
+    /// Gets an iterator over the values of the set-like object, with its `keys()` method.
+    fn keys(&self, interpreter: &mut Interpreter) -> Result<IteratorRecord, Value> {
+        let iterator = interpreter.call(&self.keys, &self.set, &[])?;
+        if !iterator.is_object() {
+            return Err(interpreter
+                .construct_type_error("'keys' of the set-like object didn't return an object"));
+        }
+        let next = interpreter.get_field(&iterator, "next")?;
+        Ok(IteratorRecord::new(iterator, next))
+    }
+}
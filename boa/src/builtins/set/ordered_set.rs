@@ -0,0 +1,199 @@
+use gc::{custom_trace, Finalize, Trace};
+use indexmap::{
+    set::{IntoIter, Iter},
+    Equivalent, IndexSet,
+};
+use std::collections::hash_map::RandomState;
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+
+/// A wrapper of indexmap::IndexSet, which also numbers its values in the order they were
+/// inserted in.
+///
+/// The number of a value, unlike its index, doesn't change when the values before it are
+/// removed, so it can be used as a cursor to iterate over a set that is modified while iterating.
+#[derive(Clone)]
+pub struct OrderedSet<V, S = RandomState>
+where
+    V: Hash + Eq,
+{
+    set: IndexSet<V, S>,
+    /// The insertion numbers of the values, by index.
+    sequences: Vec<u64>,
+    /// The insertion number of the next value.
+    next_sequence: u64,
+}
+
+impl<V: Eq + Hash + Trace, S: BuildHasher> Finalize for OrderedSet<V, S> {}
+unsafe impl<V: Eq + Hash + Trace, S: BuildHasher> Trace for OrderedSet<V, S> {
+    custom_trace!(this, {
+        for v in this.set.iter() {
+            mark(v);
+        }
+    });
+}
+
+impl<V: Hash + Eq + Debug, S: BuildHasher> Debug for OrderedSet<V, S> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        self.set.fmt(formatter)
+    }
+}
+
+impl<V: Hash + Eq, S: BuildHasher + Default> Default for OrderedSet<V, S> {
+    fn default() -> Self {
+        OrderedSet {
+            set: IndexSet::default(),
+            sequences: Vec::new(),
+            next_sequence: 0,
+        }
+    }
+}
+
+impl<V> OrderedSet<V>
+where
+    V: Hash + Eq,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        OrderedSet {
+            set: IndexSet::with_capacity(capacity),
+            sequences: Vec::with_capacity(capacity),
+            next_sequence: 0,
+        }
+    }
+}
+
+impl<V, S> OrderedSet<V, S>
+where
+    V: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Return the number of elements in the set.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    /// Returns true if the set contains no elements.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn is_empty(&self) -> bool {
+        self.set.len() == 0
+    }
+
+    /// Insert a value in the set.
+    ///
+    /// If an equivalent value already exists in the set: the value remains
+    /// in its place in the order, and `false` is returned.
+    ///
+    /// If no equivalent value existed in the set: the new value is inserted,
+    /// last in order, and `true` is returned.
+    ///
+    /// Computes in **O(1)** time (amortized average).
+    pub fn insert(&mut self, value: V) -> bool {
+        let inserted = self.set.insert(value);
+        if inserted {
+            self.sequences.push(self.next_sequence);
+            self.next_sequence += 1;
+        }
+        inserted
+    }
+
+    /// Remove the value equivalent to `value`, and return `true` if it was
+    /// present.
+    ///
+    /// Like `Vec::remove`, the value is removed by shifting all of the
+    /// elements that follow it, preserving their relative order.
+    /// **This perturbs the index of all of those elements!**
+    ///
+    /// Computes in **O(n)** time (average).
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Equivalent<V>,
+    {
+        match self.set.shift_remove_full(value) {
+            Some((index, _)) => {
+                self.sequences.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove all the values of the set.
+    ///
+    /// The values inserted afterwards are still numbered after the removed ones.
+    ///
+    /// Computes in **O(n)** time.
+    pub fn clear(&mut self) {
+        self.set.clear();
+        self.sequences.clear();
+    }
+
+    /// Get a value by index
+    ///
+    /// Valid indices are *0 <= index < self.len()*
+    ///
+    /// Computes in **O(1)** time.
+    pub fn get_index(&self, index: usize) -> Option<&V> {
+        self.set.get_index(index)
+    }
+
+    /// Get the first value inserted at or after the position `cursor` of the insertion order,
+    /// along with the position after it.
+    ///
+    /// Iterating with a cursor that starts at 0 visits each value once, even if values are
+    /// inserted or removed while iterating.
+    ///
+    /// Computes in **O(log(n))** time.
+    pub fn get_from(&self, cursor: u64) -> Option<(&V, u64)> {
+        let index = self
+            .sequences
+            .partition_point(|&sequence| sequence < cursor);
+        let value = self.set.get_index(index)?;
+        Some((value, self.sequences[index] + 1))
+    }
+
+    /// Return an iterator over the values of the set, in their order
+    pub fn iter(&self) -> Iter<'_, V> {
+        self.set.iter()
+    }
+
+    /// Return `true` if an equivalent to `value` exists in the set.
+    ///
+    /// Computes in **O(1)** time (average).
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Equivalent<V>,
+    {
+        self.set.contains(value)
+    }
+}
+
+impl<'a, V, S> IntoIterator for &'a OrderedSet<V, S>
+where
+    V: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = &'a V;
+    type IntoIter = Iter<'a, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.set.iter()
+    }
+}
+
+impl<V, S> IntoIterator for OrderedSet<V, S>
+where
+    V: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = V;
+    type IntoIter = IntoIter<V>;
+    fn into_iter(self) -> IntoIter<V> {
+        self.set.into_iter()
+    }
+}
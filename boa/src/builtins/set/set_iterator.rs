@@ -0,0 +1,156 @@
+//! This module implements the `SetIterator` object, returned by `Set.prototype.values()`,
+//! `keys()`, `entries()` and `[Symbol.iterator]()`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-set-iterator-objects
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::create_iter_result_object,
+        object::ObjectData,
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+        Array,
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+/// What a `SetIterator` produces for each value of the set.
+#[derive(Debug, Clone, Copy, Finalize, PartialEq)]
+pub enum SetIterationKind {
+    Value,
+    KeyAndValue,
+}
+
+unsafe impl Trace for SetIterationKind {
+    unsafe_empty_trace!();
+}
+
+/// The internal state of a `SetIterator` object.
+///
+/// The iterated set is set to `undefined` once the iterator is done.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct SetIterator {
+    set: Value,
+    /// The position in the insertion order of the set of the next value to visit.
+    cursor: u64,
+    kind: SetIterationKind,
+}
+
+impl SetIterator {
+    /// Creates a new iterator over the values of the given set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createsetiterator
+    pub(crate) fn create_set_iterator(
+        interpreter: &Interpreter,
+        set: Value,
+        kind: SetIterationKind,
+    ) -> Value {
+        Value::new_object_from_prototype(
+            interpreter.iterator_prototypes().set_iterator().clone(),
+            ObjectData::SetIterator(Self {
+                set,
+                cursor: 0,
+                kind,
+            }),
+        )
+    }
+
+    /// `%SetIteratorPrototype%.next()`
+    ///
+    /// Gets the next result of the iterator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%setiteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        let (set, cursor, kind) = match this.as_object() {
+            Some(object) => match object.data {
+                ObjectData::SetIterator(ref iterator) => {
+                    (iterator.set.clone(), iterator.cursor, iterator.kind)
+                }
+                _ => return interpreter.throw_type_error("'this' is not a SetIterator"),
+            },
+            None => return interpreter.throw_type_error("'this' is not a SetIterator"),
+        };
+
+        let entry = set.as_object().and_then(|object| {
+            object
+                .as_set_ref()
+                .and_then(|set| set.get_from(cursor))
+                .map(|(value, next)| (value.clone(), next))
+        });
+
+        let value = if let Some((value, next)) = entry {
+            if let Some(mut object) = this.as_object_mut() {
+                if let ObjectData::SetIterator(ref mut iterator) = object.data {
+                    iterator.cursor = next;
+                }
+            }
+            value
+        } else {
+            if let Some(mut object) = this.as_object_mut() {
+                if let ObjectData::SetIterator(ref mut iterator) = object.data {
+                    iterator.set = Value::undefined();
+                }
+            }
+            return Ok(create_iter_result_object(
+                interpreter,
+                Value::undefined(),
+                true,
+            ));
+        };
+
+        let value = match kind {
+            SetIterationKind::Value => value,
+            SetIterationKind::KeyAndValue => {
+                let entry = Array::new_array(interpreter)?;
+                Array::add_to_array_object(&entry, &[value.clone(), value])?
+            }
+        };
+        Ok(create_iter_result_object(interpreter, value, false))
+    }
+
+    /// Creates the `%SetIteratorPrototype%` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%setiteratorprototype%-object
+    pub(crate) fn create_prototype(
+        interpreter: &mut Interpreter,
+        iterator_prototype: Value,
+    ) -> Value {
+        let _timer = BoaProfiler::global().start_event("SetIterator", "init");
+        let global = interpreter.global();
+        let prototype = Value::new_object(Some(global));
+        if let Some(mut object) = prototype.as_object_mut() {
+            object.set_prototype(iterator_prototype);
+        }
+
+        make_builtin_fn(Self::next, "next", &prototype, 0, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from("Set Iterator"),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        prototype
+    }
+}
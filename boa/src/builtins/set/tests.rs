@@ -0,0 +1,379 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn construct_empty() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var empty = new Set();
+        var fromUndefined = new Set(undefined);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "empty.size"), "0");
+    assert_eq!(forward(&mut engine, "fromUndefined.size"), "0");
+}
+
+#[test]
+fn construct_from_iterable() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let set = new Set([1, 2, 2, 3, 1]);
+        let letters = new Set("hello");
+        let clone = new Set(set);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "set.size"), "3");
+    assert_eq!(forward(&mut engine, "[...set].join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "[...letters].join('')"), "\"helo\"");
+    forward(&mut engine, "set.add(4)");
+    assert_eq!(forward(&mut engine, "clone.size"), "3");
+}
+
+#[test]
+fn add_has_delete_clear() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let obj = {};
+        let set = new Set();
+        let returned = set.add("a").add(obj).add("a");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "returned === set"), "true");
+    assert_eq!(forward(&mut engine, "set.size"), "2");
+    assert_eq!(forward(&mut engine, "set.has(obj)"), "true");
+    assert_eq!(forward(&mut engine, "set.has({})"), "false");
+    assert_eq!(forward(&mut engine, "set.delete(obj)"), "true");
+    assert_eq!(forward(&mut engine, "set.delete(obj)"), "false");
+    assert_eq!(forward(&mut engine, "set.size"), "1");
+    assert_eq!(forward(&mut engine, "set.clear()"), "undefined");
+    assert_eq!(forward(&mut engine, "set.size"), "0");
+}
+
+#[test]
+fn same_value_zero() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let set = new Set([NaN, 0 / 0, -0, 0, 1, 1.0, "1"]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "set.size"), "4");
+    assert_eq!(forward(&mut engine, "set.has(NaN)"), "true");
+    assert_eq!(forward(&mut engine, "set.has(0)"), "true");
+    assert_eq!(forward(&mut engine, "set.has(-0)"), "true");
+    assert_eq!(forward(&mut engine, "1 / [...set][1]"), "Infinity");
+}
+
+#[test]
+fn size_accessor() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let message;
+        try {
+            Set.prototype.size;
+        } catch (e) {
+            message = e.toString();
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "message"),
+        "\"TypeError: 'this' is not a Set\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Set([1]).hasOwnProperty('size')"),
+        "false"
+    );
+}
+
+#[test]
+fn for_each() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let set = new Set([1, 2, 3]);
+        let visited = [];
+        let sameSet = true;
+        set.forEach(function(value, key, s) {
+            visited.push(value + key);
+            if (s !== set) {
+                sameSet = false;
+            }
+            if (value === 1) {
+                set.add(4);
+            }
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "visited.join()"), "\"2,4,6,8\"");
+    assert_eq!(forward(&mut engine, "sameSet"), "true");
+}
+
+#[test]
+fn iterators() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let set = new Set(["a", "b"]);
+        let values = [];
+        for (let value of set) {
+            values.push(value);
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "values.join()"), "\"a,b\"");
+    assert_eq!(forward(&mut engine, "[...set.keys()].join()"), "\"a,b\"");
+    assert_eq!(
+        forward(&mut engine, "[...set.entries()].join(\";\")"),
+        "\"a,a;b,b\""
+    );
+    assert_eq!(
+        forward(&mut engine, "set[Symbol.iterator] === set.values"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "set.keys === set.values"), "true");
+    assert_eq!(
+        forward(&mut engine, "set.values()[Symbol.toStringTag]"),
+        "\"Set Iterator\""
+    );
+    assert_eq!(forward(&mut engine, "set[Symbol.toStringTag]"), "\"Set\"");
+}
+
+#[test]
+fn subclass() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        class TaggedSet extends Set {
+            add(value) {
+                return super.add("tag:" + value);
+            }
+        }
+        let set = new TaggedSet([1, 2]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "[...set].join()"), "\"tag:1,tag:2\"");
+    assert_eq!(forward(&mut engine, "set instanceof TaggedSet"), "true");
+}
+
+#[test]
+fn not_a_function() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        try {
+            let set = Set()
+        } catch(e) {
+            e.toString()
+        }
+    "#;
+    assert_eq!(
+        forward(&mut engine, init),
+        "\"TypeError: Set constructor requires 'new'\""
+    );
+}
+
+#[test]
+fn set_algebra() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let odds = new Set([1, 3, 5, 7]);
+        let primes = new Set([2, 3, 5, 7]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "[...odds.union(primes)].join()"),
+        "\"1,3,5,7,2\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[...odds.intersection(primes)].join()"),
+        "\"3,5,7\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[...odds.difference(primes)].join()"),
+        "\"1\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[...odds.symmetricDifference(primes)].join()"),
+        "\"1,2\""
+    );
+    assert_eq!(forward(&mut engine, "odds.isSubsetOf(primes)"), "false");
+    assert_eq!(
+        forward(&mut engine, "new Set([3, 5]).isSubsetOf(primes)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "primes.isSupersetOf(new Set([2, 7]))"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "odds.isDisjointFrom(primes)"), "false");
+    assert_eq!(
+        forward(&mut engine, "odds.isDisjointFrom(new Set([2, 4]))"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "odds.size"), "4");
+    assert_eq!(
+        forward(&mut engine, "odds.union(primes) instanceof Set"),
+        "true"
+    );
+}
+
+#[test]
+fn set_like_objects() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let map = new Map([[1, "one"], [4, "four"]]);
+        let evens = {
+            size: Infinity,
+            has(value) {
+                return value % 2 === 0;
+            },
+            keys() {
+                throw new Error("keys() should not be called");
+            },
+        };
+        let set = new Set([1, 2, 3, 4]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "[...set.intersection(map)].join()"),
+        "\"1,4\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[...set.intersection(evens)].join()"),
+        "\"2,4\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[...set.difference(evens)].join()"),
+        "\"1,3\""
+    );
+    assert_eq!(forward(&mut engine, "set.isSubsetOf(evens)"), "false");
+}
+
+#[test]
+fn set_like_errors() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function message(other) {
+            try {
+                new Set([1]).union(other);
+            } catch (e) {
+                return e.toString();
+            }
+        }
+        let keys = function() { return [].values(); };
+        let has = function() { return false; };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "message([1, 2])"),
+        "\"TypeError: the size of the set-like object is NaN\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message(1)"),
+        "\"TypeError: the argument is not a set-like object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message({ size: -1, has: has, keys: keys })"),
+        "\"RangeError: the size of the set-like object is negative\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message({ size: 1, has: 1, keys: keys })"),
+        "\"TypeError: 'has' of the set-like object is not a function\""
+    );
+    assert_eq!(
+        forward(&mut engine, "message({ size: 1, has: has })"),
+        "\"TypeError: 'keys' of the set-like object is not a function\""
+    );
+}
+
+#[test]
+fn closes_iterators() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let closed = false;
+        let other = {
+            size: 1,
+            has(value) {
+                return false;
+            },
+            keys() {
+                let values = [1, 2, 3].values();
+                return {
+                    next() {
+                        return values.next();
+                    },
+                    return() {
+                        closed = true;
+                        return {};
+                    },
+                };
+            },
+        };
+        let result = new Set([1, 2]).isSupersetOf(other);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result"), "false");
+    assert_eq!(forward(&mut engine, "closed"), "true");
+}
+
+#[test]
+fn delete_while_iterating() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let visited = [];
+        let set = new Set([1, 2, 3]);
+        for (const value of set) {
+            visited.push(value);
+            set.delete(value);
+        }
+
+        let each = [];
+        let other = new Set([1, 2, 3]);
+        other.forEach(value => {
+            each.push(value);
+            other.delete(value);
+        });
+
+        let readded = [];
+        let again = new Set([1, 2, 3]);
+        for (const value of again) {
+            readded.push(value);
+            if (readded.length === 1) {
+                again.delete(1);
+                again.delete(2);
+                again.add(1);
+            }
+        }
+
+        let cleared = new Set([1, 2]);
+        let it = cleared.values();
+        it.next();
+        cleared.clear();
+        cleared.add(3);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "visited.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "set.size"), "0");
+    assert_eq!(forward(&mut engine, "each.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "readded.join()"), "\"1,3,1\"");
+    assert_eq!(forward(&mut engine, "it.next().value"), "3");
+    assert_eq!(forward(&mut engine, "it.next().done"), "true");
+
+    let init = r#"
+        let s = new Set([1, 2, 3]);
+        let iter = s.values();
+        iter.next();
+        s.delete(1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "iter.next().value"), "2");
+}
@@ -9,14 +9,16 @@
 //! [spec]: https://tc39.es/ecma262/#sec-string-object
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String
 
+pub mod string_iterator;
 #[cfg(test)]
 mod tests;
 
+use self::string_iterator::StringIterator;
 use super::function::{make_builtin_fn, make_constructor_fn};
 use crate::{
     builtins::{
         object::{Object, ObjectData},
-        property::Property,
+        property::{Attribute, Property},
         value::{RcString, ResultValue, Value},
         RegExp,
     },
@@ -77,7 +79,11 @@ impl String {
 
         let length = string.chars().count();
 
-        this.set_field("length", Value::from(length as i32));
+        let length = Property::data_descriptor(
+            Value::from(length as i32),
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        this.set_property("length".to_string(), length);
 
         this.set_data(ObjectData::String(string.clone()));
 
@@ -1000,6 +1006,26 @@ impl String {
         RegExp::match_all(&re, ctx.to_string(this)?.to_string())
     }
 
+    /// `String.prototype[Symbol.iterator]( )`
+    ///
+    /// The `[Symbol.iterator]()` method returns an iterator that iterates over the code points of
+    /// the string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype-@@iterator
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/@@iterator
+    pub(crate) fn iterator(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        if this.is_null_or_undefined() {
+            return ctx
+                .throw_type_error("String.prototype[Symbol.iterator] called on null or undefined");
+        }
+        let string = ctx.to_string(this)?;
+        Ok(StringIterator::create_string_iterator(ctx, string))
+    }
+
     /// Initialise the `String` object on the global object.
     #[inline]
     pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
@@ -1056,6 +1082,9 @@ impl String {
         make_builtin_fn(Self::match_all, "matchAll", &prototype, 1, interpreter);
         make_builtin_fn(Self::replace, "replace", &prototype, 2, interpreter);
 
+        let symbol_iterator = interpreter.well_known_symbols().iterator_symbol();
+        make_builtin_fn(Self::iterator, symbol_iterator, &prototype, 0, interpreter);
+
         let string_object = make_constructor_fn(
             Self::NAME,
             Self::LENGTH,
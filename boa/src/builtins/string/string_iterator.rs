@@ -0,0 +1,115 @@
+//! This module implements the `StringIterator` object, returned by
+//! `String.prototype[Symbol.iterator]()`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-string-iterator-objects
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::create_iter_result_object,
+        object::ObjectData,
+        property::{Attribute, Property},
+        value::{RcString, ResultValue, Value},
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{Finalize, Trace};
+
+/// The internal state of a `StringIterator` object.
+///
+/// The iterated string is set to `None` once the iterator is done.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct StringIterator {
+    string: Option<RcString>,
+    /// The byte offset of the next code point in the string.
+    next_index: usize,
+}
+
+impl StringIterator {
+    /// Creates a new iterator over the code points of the given string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createstringiterator
+    pub(crate) fn create_string_iterator(interpreter: &Interpreter, string: RcString) -> Value {
+        Value::new_object_from_prototype(
+            interpreter.iterator_prototypes().string_iterator().clone(),
+            ObjectData::StringIterator(Self {
+                string: Some(string),
+                next_index: 0,
+            }),
+        )
+    }
+
+    /// `%StringIteratorPrototype%.next()`
+    ///
+    /// Gets the next code point of the string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%stringiteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        let next = match this.as_object_mut() {
+            Some(mut object) => match object.data {
+                ObjectData::StringIterator(ref mut iterator) => {
+                    let next = iterator
+                        .string
+                        .as_ref()
+                        .and_then(|string| string[iterator.next_index..].chars().next());
+                    match next {
+                        Some(ch) => iterator.next_index += ch.len_utf8(),
+                        None => iterator.string = None,
+                    }
+                    next
+                }
+                _ => return interpreter.throw_type_error("'this' is not a StringIterator"),
+            },
+            None => return interpreter.throw_type_error("'this' is not a StringIterator"),
+        };
+
+        Ok(match next {
+            Some(ch) => create_iter_result_object(interpreter, Value::from(ch.to_string()), false),
+            None => create_iter_result_object(interpreter, Value::undefined(), true),
+        })
+    }
+
+    /// Creates the `%StringIteratorPrototype%` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%stringiteratorprototype%-object
+    pub(crate) fn create_prototype(
+        interpreter: &mut Interpreter,
+        iterator_prototype: Value,
+    ) -> Value {
+        let _timer = BoaProfiler::global().start_event("StringIterator", "init");
+        let global = interpreter.global();
+        let prototype = Value::new_object(Some(global));
+        if let Some(mut object) = prototype.as_object_mut() {
+            object.set_prototype(iterator_prototype);
+        }
+
+        make_builtin_fn(Self::next, "next", &prototype, 0, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from("String Iterator"),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        prototype
+    }
+}
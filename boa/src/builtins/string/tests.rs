@@ -648,3 +648,13 @@ fn last_index_non_integer_position_argument() {
     );
     assert_eq!(forward(&mut engine, "'abcx'.lastIndexOf('x', null)"), "3");
 }
+
+#[test]
+fn string_iterator() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    forward(&mut engine, "var iterator = 'aé'[Symbol.iterator]();");
+    assert_eq!(forward(&mut engine, "iterator.next().value"), "\"a\"");
+    assert_eq!(forward(&mut engine, "iterator.next().value"), "\"é\"");
+    assert_eq!(forward(&mut engine, "iterator.next().done"), "true");
+}
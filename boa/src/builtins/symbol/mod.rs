@@ -101,39 +101,7 @@ impl Symbol {
     /// Initialise the `Symbol` object on the global object.
     #[inline]
     pub fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
-        // Define the Well-Known Symbols
-        // https://tc39.es/ecma262/#sec-well-known-symbols
-        let symbol_async_iterator = Symbol(
-            Some("Symbol.asyncIterator".into()),
-            interpreter.generate_hash(),
-        );
-        let symbol_has_instance = Symbol(
-            Some("Symbol.hasInstance".into()),
-            interpreter.generate_hash(),
-        );
-        let symbol_is_concat_spreadable = Symbol(
-            Some("Symbol.isConcatSpreadable".into()),
-            interpreter.generate_hash(),
-        );
-        let symbol_iterator = Symbol(Some("Symbol.iterator".into()), interpreter.generate_hash());
-        let symbol_match = Symbol(Some("Symbol.match".into()), interpreter.generate_hash());
-        let symbol_match_all = Symbol(Some("Symbol.matchAll".into()), interpreter.generate_hash());
-        let symbol_replace = Symbol(Some("Symbol.replace".into()), interpreter.generate_hash());
-        let symbol_search = Symbol(Some("Symbol.search".into()), interpreter.generate_hash());
-        let symbol_species = Symbol(Some("Symbol.species".into()), interpreter.generate_hash());
-        let symbol_split = Symbol(Some("Symbol.split".into()), interpreter.generate_hash());
-        let symbol_to_primitive = Symbol(
-            Some("Symbol.toPrimitive".into()),
-            interpreter.generate_hash(),
-        );
-        let symbol_to_string_tag = Symbol(
-            Some("Symbol.toStringTag".into()),
-            interpreter.generate_hash(),
-        );
-        let symbol_unscopables = Symbol(
-            Some("Symbol.unscopables".into()),
-            interpreter.generate_hash(),
-        );
+        let symbols = interpreter.well_known_symbols().clone();
 
         let global = interpreter.global();
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
@@ -153,23 +121,155 @@ impl Symbol {
             true,
         );
 
-        symbol_object.set_field("asyncIterator", Value::symbol(symbol_async_iterator));
-        symbol_object.set_field("hasInstance", Value::symbol(symbol_has_instance));
-        symbol_object.set_field(
-            "isConcatSpreadable",
-            Value::symbol(symbol_is_concat_spreadable),
-        );
-        symbol_object.set_field("iterator", Value::symbol(symbol_iterator));
-        symbol_object.set_field("match", Value::symbol(symbol_match));
-        symbol_object.set_field("matchAll", Value::symbol(symbol_match_all));
-        symbol_object.set_field("replace", Value::symbol(symbol_replace));
-        symbol_object.set_field("search", Value::symbol(symbol_search));
-        symbol_object.set_field("species", Value::symbol(symbol_species));
-        symbol_object.set_field("split", Value::symbol(symbol_split));
-        symbol_object.set_field("toPrimitive", Value::symbol(symbol_to_primitive));
-        symbol_object.set_field("toStringTag", Value::symbol(symbol_to_string_tag));
-        symbol_object.set_field("unscopables", Value::symbol(symbol_unscopables));
+        symbol_object.set_field("asyncIterator", symbols.async_iterator_symbol());
+        symbol_object.set_field("hasInstance", symbols.has_instance_symbol());
+        symbol_object.set_field("isConcatSpreadable", symbols.is_concat_spreadable_symbol());
+        symbol_object.set_field("iterator", symbols.iterator_symbol());
+        symbol_object.set_field("match", symbols.match_symbol());
+        symbol_object.set_field("matchAll", symbols.match_all_symbol());
+        symbol_object.set_field("replace", symbols.replace_symbol());
+        symbol_object.set_field("search", symbols.search_symbol());
+        symbol_object.set_field("species", symbols.species_symbol());
+        symbol_object.set_field("split", symbols.split_symbol());
+        symbol_object.set_field("toPrimitive", symbols.to_primitive_symbol());
+        symbol_object.set_field("toStringTag", symbols.to_string_tag_symbol());
+        symbol_object.set_field("unscopables", symbols.unscopables_symbol());
 
         (Self::NAME, symbol_object)
     }
 }
+
+/// The well-known symbols, which are used by the specification to extend the behaviour of
+/// objects, and are shared by all the code running in an interpreter.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-well-known-symbols
+#[derive(Debug, Clone)]
+pub struct WellKnownSymbols {
+    async_iterator: RcSymbol,
+    has_instance: RcSymbol,
+    is_concat_spreadable: RcSymbol,
+    iterator: RcSymbol,
+    match_: RcSymbol,
+    match_all: RcSymbol,
+    replace: RcSymbol,
+    search: RcSymbol,
+    species: RcSymbol,
+    split: RcSymbol,
+    to_primitive: RcSymbol,
+    to_string_tag: RcSymbol,
+    unscopables: RcSymbol,
+}
+
+impl WellKnownSymbols {
+    /// Creates the well-known symbols.
+    ///
+    /// Returns the symbols along with the amount of hashes used by them, new symbols must be
+    /// created with hashes from this number on.
+    pub(crate) fn new() -> (Self, u32) {
+        let mut count = 0;
+        let mut symbol = |description: &str| {
+            let symbol = RcSymbol::from(Symbol(Some(description.into()), count));
+            count += 1;
+            symbol
+        };
+
+        let symbols = Self {
+            async_iterator: symbol("Symbol.asyncIterator"),
+            has_instance: symbol("Symbol.hasInstance"),
+            is_concat_spreadable: symbol("Symbol.isConcatSpreadable"),
+            iterator: symbol("Symbol.iterator"),
+            match_: symbol("Symbol.match"),
+            match_all: symbol("Symbol.matchAll"),
+            replace: symbol("Symbol.replace"),
+            search: symbol("Symbol.search"),
+            species: symbol("Symbol.species"),
+            split: symbol("Symbol.split"),
+            to_primitive: symbol("Symbol.toPrimitive"),
+            to_string_tag: symbol("Symbol.toStringTag"),
+            unscopables: symbol("Symbol.unscopables"),
+        };
+
+        (symbols, count)
+    }
+
+    /// The `Symbol.asyncIterator` well-known symbol.
+    #[inline]
+    pub fn async_iterator_symbol(&self) -> RcSymbol {
+        self.async_iterator.clone()
+    }
+
+    /// The `Symbol.hasInstance` well-known symbol.
+    #[inline]
+    pub fn has_instance_symbol(&self) -> RcSymbol {
+        self.has_instance.clone()
+    }
+
+    /// The `Symbol.isConcatSpreadable` well-known symbol.
+    #[inline]
+    pub fn is_concat_spreadable_symbol(&self) -> RcSymbol {
+        self.is_concat_spreadable.clone()
+    }
+
+    /// The `Symbol.iterator` well-known symbol.
+    #[inline]
+    pub fn iterator_symbol(&self) -> RcSymbol {
+        self.iterator.clone()
+    }
+
+    /// The `Symbol.match` well-known symbol.
+    #[inline]
+    pub fn match_symbol(&self) -> RcSymbol {
+        self.match_.clone()
+    }
+
+    /// The `Symbol.matchAll` well-known symbol.
+    #[inline]
+    pub fn match_all_symbol(&self) -> RcSymbol {
+        self.match_all.clone()
+    }
+
+    /// The `Symbol.replace` well-known symbol.
+    #[inline]
+    pub fn replace_symbol(&self) -> RcSymbol {
+        self.replace.clone()
+    }
+
+    /// The `Symbol.search` well-known symbol.
+    #[inline]
+    pub fn search_symbol(&self) -> RcSymbol {
+        self.search.clone()
+    }
+
+    /// The `Symbol.species` well-known symbol.
+    #[inline]
+    pub fn species_symbol(&self) -> RcSymbol {
+        self.species.clone()
+    }
+
+    /// The `Symbol.split` well-known symbol.
+    #[inline]
+    pub fn split_symbol(&self) -> RcSymbol {
+        self.split.clone()
+    }
+
+    /// The `Symbol.toPrimitive` well-known symbol.
+    #[inline]
+    pub fn to_primitive_symbol(&self) -> RcSymbol {
+        self.to_primitive.clone()
+    }
+
+    /// The `Symbol.toStringTag` well-known symbol.
+    #[inline]
+    pub fn to_string_tag_symbol(&self) -> RcSymbol {
+        self.to_string_tag.clone()
+    }
+
+    /// The `Symbol.unscopables` well-known symbol.
+    #[inline]
+    pub fn unscopables_symbol(&self) -> RcSymbol {
+        self.unscopables.clone()
+    }
+}
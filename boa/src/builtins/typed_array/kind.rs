@@ -0,0 +1,234 @@
+//! This module implements the element types of typed arrays, and the conversions between
+//! JavaScript values and the bytes of their elements.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#table-the-typedarray-constructors
+
+use crate::{
+    builtins::{
+        value::{ResultValue, Value},
+        BigInt,
+    },
+    exec::Interpreter,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+/// Reads a number of type `$ty` from the bytes `$bytes`, in little-endian order if
+/// `$little_endian` is true.
+macro_rules! from_bytes {
+    ($ty:ty, $bytes:expr, $little_endian:expr) => {{
+        let mut array = [0; std::mem::size_of::<$ty>()];
+        array.copy_from_slice($bytes);
+        if $little_endian {
+            <$ty>::from_le_bytes(array)
+        } else {
+            <$ty>::from_be_bytes(array)
+        }
+    }};
+}
+
+/// Writes the number `$value` to the bytes `$bytes`, in little-endian order if `$little_endian`
+/// is true.
+macro_rules! to_bytes {
+    ($value:expr, $bytes:expr, $little_endian:expr) => {{
+        let value = $value;
+        if $little_endian {
+            $bytes.copy_from_slice(&value.to_le_bytes())
+        } else {
+            $bytes.copy_from_slice(&value.to_be_bytes())
+        }
+    }};
+}
+
+/// The element type of a typed array, or of a value read or written by a `DataView`.
+#[derive(Debug, Clone, Copy, Finalize, PartialEq, Eq)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    BigInt64,
+    BigUint64,
+    Float32,
+    Float64,
+}
+
+unsafe impl Trace for TypedArrayKind {
+    unsafe_empty_trace!();
+}
+
+impl TypedArrayKind {
+    /// All the element types, in the order of the specification.
+    pub const ALL: [Self; 11] = [
+        Self::Int8,
+        Self::Uint8,
+        Self::Uint8Clamped,
+        Self::Int16,
+        Self::Uint16,
+        Self::Int32,
+        Self::Uint32,
+        Self::BigInt64,
+        Self::BigUint64,
+        Self::Float32,
+        Self::Float64,
+    ];
+
+    /// Gets the name of the typed array constructor for this element type.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Int8 => "Int8Array",
+            Self::Uint8 => "Uint8Array",
+            Self::Uint8Clamped => "Uint8ClampedArray",
+            Self::Int16 => "Int16Array",
+            Self::Uint16 => "Uint16Array",
+            Self::Int32 => "Int32Array",
+            Self::Uint32 => "Uint32Array",
+            Self::BigInt64 => "BigInt64Array",
+            Self::BigUint64 => "BigUint64Array",
+            Self::Float32 => "Float32Array",
+            Self::Float64 => "Float64Array",
+        }
+    }
+
+    /// Gets the number of bytes of an element of this type.
+    pub fn element_size(self) -> usize {
+        match self {
+            Self::Int8 | Self::Uint8 | Self::Uint8Clamped => 1,
+            Self::Int16 | Self::Uint16 => 2,
+            Self::Int32 | Self::Uint32 | Self::Float32 => 4,
+            Self::BigInt64 | Self::BigUint64 | Self::Float64 => 8,
+        }
+    }
+
+    /// Checks if the elements of this type are BigInts, rather than Numbers.
+    pub fn is_bigint(self) -> bool {
+        matches!(self, Self::BigInt64 | Self::BigUint64)
+    }
+
+    /// Converts a value to a value that can be stored in an element of this type, which is a
+    /// BigInt for the BigInt types and a Number for the other types.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraysetelement
+    pub(crate) fn to_element_value(
+        self,
+        value: &Value,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        if self.is_bigint() {
+            Ok(Value::from(interpreter.to_bigint_strict(value)?))
+        } else {
+            Ok(Value::from(interpreter.to_number(value)?))
+        }
+    }
+
+    /// Reads an element of this type from `bytes`, which must be `element_size()` long.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rawbytestonumeric
+    pub(crate) fn get_value(self, bytes: &[u8], little_endian: bool) -> Value {
+        match self {
+            Self::Int8 => Value::from(i32::from(bytes[0] as i8)),
+            Self::Uint8 | Self::Uint8Clamped => Value::from(i32::from(bytes[0])),
+            Self::Int16 => Value::from(i32::from(from_bytes!(i16, bytes, little_endian))),
+            Self::Uint16 => Value::from(i32::from(from_bytes!(u16, bytes, little_endian))),
+            Self::Int32 => Value::from(from_bytes!(i32, bytes, little_endian)),
+            Self::Uint32 => Value::from(from_bytes!(u32, bytes, little_endian)),
+            Self::BigInt64 => Value::from(BigInt::from(from_bytes!(i64, bytes, little_endian))),
+            Self::BigUint64 => Value::from(BigInt::from(u128::from(from_bytes!(
+                u64,
+                bytes,
+                little_endian
+            )))),
+            Self::Float32 => Value::from(f64::from(from_bytes!(f32, bytes, little_endian))),
+            Self::Float64 => Value::from(from_bytes!(f64, bytes, little_endian)),
+        }
+    }
+
+    /// Writes `value` as an element of this type to `bytes`, which must be `element_size()`
+    /// long.
+    ///
+    /// The value should already be converted with `to_element_value`. Returns `false`, without
+    /// writing anything, if a BigInt is written to an element that is a Number or the other way
+    /// around.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
+    pub(crate) fn set_value(self, bytes: &mut [u8], value: &Value, little_endian: bool) -> bool {
+        if self.is_bigint() {
+            let bigint = match value {
+                Value::BigInt(ref bigint) => bigint.to_u64_wrapping(),
+                _ => return false,
+            };
+            to_bytes!(bigint, bytes, little_endian);
+            return true;
+        }
+
+        if value.is_bigint() {
+            return false;
+        }
+        let number = f64::from(value);
+        match self {
+            Self::Int8 | Self::Uint8 => bytes[0] = modulo(number, 8) as u8,
+            Self::Uint8Clamped => bytes[0] = clamp(number),
+            Self::Int16 | Self::Uint16 => {
+                to_bytes!(modulo(number, 16) as u16, bytes, little_endian)
+            }
+            Self::Int32 | Self::Uint32 => {
+                to_bytes!(modulo(number, 32) as u32, bytes, little_endian)
+            }
+            Self::Float32 => to_bytes!(number as f32, bytes, little_endian),
+            Self::Float64 => to_bytes!(number, bytes, little_endian),
+            Self::BigInt64 | Self::BigUint64 => unreachable!("BigInt elements are written above"),
+        }
+        true
+    }
+}
+
+/// Converts a number to an integer modulo `2^bits`, which is the conversion of the integer
+/// element types, like `ToInt16` and `ToUint16`, before the integer is cast to the element type.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-touint16
+fn modulo(number: f64, bits: i32) -> u64 {
+    if !number.is_finite() {
+        return 0;
+    }
+    number.trunc().rem_euclid(2f64.powi(bits)) as u64
+}
+
+/// Converts a number to an element of an `Uint8ClampedArray`, rounding it to the nearest integer,
+/// or the nearest even integer if it is halfway between two integers.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-touint8clamp
+fn clamp(number: f64) -> u8 {
+    if number.is_nan() || number <= 0.0 {
+        return 0;
+    }
+    if number >= 255.0 {
+        return 255;
+    }
+    let floor = number.floor();
+    let half = floor + 0.5;
+    if number < half || (number == half && floor % 2.0 == 0.0) {
+        floor as u8
+    } else {
+        floor as u8 + 1
+    }
+}
@@ -0,0 +1,1940 @@
+//! This module implements the typed array objects, like `Uint8Array` and `Float64Array`, and the
+//! `%TypedArray%` intrinsic object they inherit their methods from.
+//!
+//! A typed array is a view of an `ArrayBuffer` as an array of elements of a specific numeric
+//! type. Its integer-indexed properties are read from and written to the bytes of the buffer, in
+//! the native byte order. A typed array on a resizable buffer, without an explicit length, tracks
+//! the length of the buffer.
+//!
+//! The methods that create new typed arrays create them with the constructor of the same element
+//! type, as `Symbol.species` is not supported.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-typedarray-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray
+
+use super::function::{
+    make_builtin_fn, make_builtin_getter, make_constructor_fn, NativeFunctionData,
+};
+use crate::{
+    builtins::{
+        array::array_iterator::{ArrayIterationKind, ArrayIterator},
+        array_buffer::{relative_index, ArrayBuffer, ArrayBufferData},
+        iterable::iterable_to_list,
+        object::{ObjectData, PROTOTYPE},
+        property::{Attribute, Property, PropertyKey},
+        value::{same_value_zero, ResultValue, Value},
+        Number,
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{Finalize, Trace};
+use std::{cmp::Ordering, ops::Range};
+
+pub mod kind;
+#[cfg(test)]
+mod tests;
+
+pub use kind::TypedArrayKind;
+
+/// The internal state of a typed array object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct TypedArrayData {
+    /// The type of the elements.
+    kind: TypedArrayKind,
+    /// The `ArrayBuffer` object the elements are stored in.
+    buffer: Value,
+    /// The offset of the first element in the buffer, in bytes.
+    byte_offset: usize,
+    /// The number of elements, or `None` if the array tracks the length of a resizable buffer.
+    array_length: Option<usize>,
+}
+
+impl TypedArrayData {
+    /// Creates the data of a typed array viewing `buffer`, which must be an `ArrayBuffer` object.
+    pub(crate) fn new(
+        kind: TypedArrayKind,
+        buffer: Value,
+        byte_offset: usize,
+        array_length: Option<usize>,
+    ) -> Self {
+        Self {
+            kind,
+            buffer,
+            byte_offset,
+            array_length,
+        }
+    }
+
+    /// Gets the type of the elements.
+    #[inline]
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    /// Gets the `ArrayBuffer` object the elements are stored in.
+    #[inline]
+    pub fn buffer(&self) -> &Value {
+        &self.buffer
+    }
+
+    /// Gets the offset of the first element in the buffer, in bytes.
+    #[inline]
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// Checks if the array tracks the length of a resizable buffer.
+    #[inline]
+    pub fn is_length_tracking(&self) -> bool {
+        self.array_length.is_none()
+    }
+
+    /// Gets the current byte length of the buffer.
+    fn buffer_byte_length(&self) -> usize {
+        self.buffer
+            .as_object()
+            .and_then(|buffer| buffer.as_array_buffer().map(ArrayBufferData::byte_length))
+            .unwrap_or(0)
+    }
+
+    /// Checks if the array is out of the bounds of its buffer, because the buffer was shrunk.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-istypedarrayoutofbounds
+    pub fn is_out_of_bounds(&self) -> bool {
+        let buffer_byte_length = self.buffer_byte_length();
+        match self.array_length {
+            Some(length) => {
+                self.byte_offset + length * self.kind.element_size() > buffer_byte_length
+            }
+            None => self.byte_offset > buffer_byte_length,
+        }
+    }
+
+    /// Gets the number of elements, which is 0 if the array is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraylength
+    pub fn length(&self) -> usize {
+        if self.is_out_of_bounds() {
+            return 0;
+        }
+        match self.array_length {
+            Some(length) => length,
+            None => (self.buffer_byte_length() - self.byte_offset) / self.kind.element_size(),
+        }
+    }
+
+    /// Gets the number of bytes of the elements, which is 0 if the array is out of bounds.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.length() * self.kind.element_size()
+    }
+
+    /// Gets the range of the bytes of the elements in the buffer.
+    fn byte_range(&self) -> Range<usize> {
+        if self.is_out_of_bounds() {
+            return 0..0;
+        }
+        self.byte_offset..self.byte_offset + self.byte_length()
+    }
+
+    /// Calls `f` with the bytes of the elements, without copying them.
+    pub fn with_bytes<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        let range = self.byte_range();
+        let buffer = self
+            .buffer
+            .as_object()
+            .expect("the buffer of a typed array is an object");
+        let buffer = buffer
+            .as_array_buffer()
+            .expect("the buffer of a typed array is an ArrayBuffer");
+        f(&buffer.bytes()[range])
+    }
+
+    /// Calls `f` with the bytes of the elements, to modify them without copying them.
+    pub fn with_bytes_mut<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut [u8]) -> R,
+    {
+        let range = self.byte_range();
+        let mut buffer = self
+            .buffer
+            .as_object_mut()
+            .expect("the buffer of a typed array is an object");
+        let buffer = buffer
+            .as_array_buffer_mut()
+            .expect("the buffer of a typed array is an ArrayBuffer");
+        f(&mut buffer.bytes_mut()[range])
+    }
+
+    /// Checks if `index` is the index of an element of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isvalidintegerindex
+    pub(crate) fn is_valid_index(&self, index: f64) -> bool {
+        index.fract() == 0.0
+            && !(index == 0.0 && index.is_sign_negative())
+            && index >= 0.0
+            && index < self.length() as f64
+    }
+
+    /// Gets the element at `index`, or `None` if it isn't the index of an element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraygetelement
+    pub(crate) fn get_element(&self, index: f64) -> Option<Value> {
+        if !self.is_valid_index(index) {
+            return None;
+        }
+        let size = self.kind.element_size();
+        let start = index as usize * size;
+        Some(self.with_bytes(|bytes| {
+            self.kind
+                .get_value(&bytes[start..start + size], cfg!(target_endian = "little"))
+        }))
+    }
+
+    /// Sets the element at `index`, if it is the index of an element.
+    ///
+    /// The value should already be converted with `TypedArrayKind::to_element_value`. Returns
+    /// `false` if nothing was written.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraysetelement
+    pub(crate) fn set_element(&self, index: f64, value: &Value) -> bool {
+        if !self.is_valid_index(index) {
+            return false;
+        }
+        let size = self.kind.element_size();
+        let start = index as usize * size;
+        self.with_bytes_mut(|bytes| {
+            self.kind.set_value(
+                &mut bytes[start..start + size],
+                value,
+                cfg!(target_endian = "little"),
+            )
+        })
+    }
+}
+
+/// Converts a property key to the number it is the canonical string of, like `"1"` or `"-0"`.
+///
+/// The integer-indexed properties of typed arrays are the keys that are canonical numeric
+/// strings, even the ones that aren't valid indices, like `"1.5"`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalnumericindexstring
+pub(crate) fn canonical_numeric_index(key: &PropertyKey) -> Option<f64> {
+    let string = match key {
+        PropertyKey::String(ref string) => string,
+        PropertyKey::Symbol(_) => return None,
+    };
+    if string.as_str() == "-0" {
+        return Some(-0.0);
+    }
+    let number = string.parse::<f64>().ok()?;
+    if Number::to_native_string(number) == string.as_str() {
+        Some(number)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TypedArray;
+
+impl TypedArray {
+    pub(crate) const NAME: &'static str = "TypedArray";
+
+    pub(crate) const LENGTH: usize = 0;
+
+    /// The `length` of the typed array constructors.
+    const CONSTRUCTOR_LENGTH: usize = 3;
+
+    /// Helper function to get the data of `this`, throwing a `TypeError` if it isn't a typed
+    /// array.
+    fn typed_array_of(
+        this: &Value,
+        interpreter: &mut Interpreter,
+    ) -> Result<TypedArrayData, Value> {
+        let array = this
+            .as_object()
+            .and_then(|object| object.as_typed_array().cloned());
+        array.ok_or_else(|| interpreter.construct_type_error("'this' is not a typed array"))
+    }
+
+    /// Helper function to get the data of `this`, throwing a `TypeError` if it isn't a typed
+    /// array or if it is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validatetypedarray
+    fn this_typed_array(
+        this: &Value,
+        interpreter: &mut Interpreter,
+    ) -> Result<TypedArrayData, Value> {
+        let array = Self::typed_array_of(this, interpreter)?;
+        if array.is_out_of_bounds() {
+            return Err(interpreter.construct_type_error("the typed array is out of bounds"));
+        }
+        Ok(array)
+    }
+
+    /// Helper function to get the callback argument of the methods that take one.
+    fn callback(args: &[Value], method: &str, interpreter: &mut Interpreter) -> ResultValue {
+        match args.get(0) {
+            Some(callback) if callback.is_function() => Ok(callback.clone()),
+            _ => interpreter.throw_type_error(format!(
+                "TypedArray.prototype.{}: callback is not a function",
+                method
+            )),
+        }
+    }
+
+    /// Helper function to get the element at `index`, or `undefined` if the array was shrunk.
+    fn get(array: &TypedArrayData, index: usize) -> Value {
+        array.get_element(index as f64).unwrap_or_default()
+    }
+
+    /// Helper function to convert `value` and set it as the element at `index`, if the array
+    /// still has it after the conversion.
+    fn set(
+        array: &TypedArrayData,
+        index: usize,
+        value: &Value,
+        interpreter: &mut Interpreter,
+    ) -> Result<(), Value> {
+        let value = array.kind.to_element_value(value, interpreter)?;
+        array.set_element(index as f64, &value);
+        Ok(())
+    }
+
+    /// Allocates a new buffer for a typed array of `length` elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatetypedarraybuffer
+    fn allocate(
+        kind: TypedArrayKind,
+        length: usize,
+        interpreter: &mut Interpreter,
+    ) -> Result<TypedArrayData, Value> {
+        let byte_length = match length.checked_mul(kind.element_size()) {
+            Some(byte_length) => byte_length,
+            None => {
+                return Err(interpreter
+                    .construct_range_error(format!("{}: invalid typed array length", kind.name())))
+            }
+        };
+        let data = ArrayBuffer::allocate(byte_length, interpreter)?;
+        let buffer = ArrayBuffer::create(ArrayBufferData::new(data), interpreter);
+        Ok(TypedArrayData::new(kind, buffer, 0, Some(length)))
+    }
+
+    /// Creates a new typed array object with the given data, inheriting from the prototype of the
+    /// constructor of its element type.
+    fn create_object(array: TypedArrayData, interpreter: &Interpreter) -> Value {
+        let prototype = interpreter
+            .global()
+            .get_field(array.kind.name())
+            .get_field(PROTOTYPE);
+        Value::new_object_from_prototype(prototype, ObjectData::TypedArray(array))
+    }
+
+    /// Creates a new typed array of `length` elements, with the constructor `constructor`.
+    ///
+    /// A `TypeError` is thrown if the constructor doesn't create a typed array with at least
+    /// `length` elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#typedarray-create
+    fn create_from_constructor(
+        constructor: &Value,
+        length: usize,
+        interpreter: &mut Interpreter,
+    ) -> Result<(Value, TypedArrayData), Value> {
+        let object = interpreter.construct(constructor, &[Value::from(length)], constructor)?;
+        let array = object
+            .as_object()
+            .and_then(|object| object.as_typed_array().cloned());
+        match array {
+            Some(ref array) if array.is_out_of_bounds() => {
+                Err(interpreter.construct_type_error("the typed array is out of bounds"))
+            }
+            Some(ref array) if array.length() < length => Err(interpreter
+                .construct_type_error("the constructor created a typed array that is too short")),
+            Some(array) => Ok((object, array)),
+            None => {
+                Err(interpreter.construct_type_error("the constructor didn't create a typed array"))
+            }
+        }
+    }
+
+    /// Initializes the data of a typed array with a copy of the elements of another typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromtypedarray
+    fn from_typed_array(
+        kind: TypedArrayKind,
+        source: &TypedArrayData,
+        interpreter: &mut Interpreter,
+    ) -> Result<TypedArrayData, Value> {
+        if source.is_out_of_bounds() {
+            return Err(interpreter.construct_type_error("the source typed array is out of bounds"));
+        }
+        if kind.is_bigint() != source.kind.is_bigint() {
+            return Err(interpreter.construct_type_error(
+                "cannot mix BigInt and other types, use explicit conversions",
+            ));
+        }
+
+        let length = source.length();
+        let array = Self::allocate(kind, length, interpreter)?;
+        if source.kind == kind {
+            source.with_bytes(|source| array.with_bytes_mut(|bytes| bytes.copy_from_slice(source)));
+        } else {
+            for index in 0..length {
+                array.set_element(index as f64, &Self::get(source, index));
+            }
+        }
+        Ok(array)
+    }
+
+    /// Initializes the data of a typed array viewing an `ArrayBuffer`, from `byte_offset` and for
+    /// `length` elements, or up to the end of the buffer if `length` is `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromarraybuffer
+    fn from_array_buffer(
+        kind: TypedArrayKind,
+        buffer: &Value,
+        byte_offset: &Value,
+        length: &Value,
+        interpreter: &mut Interpreter,
+    ) -> Result<TypedArrayData, Value> {
+        let name = kind.name();
+        let size = kind.element_size();
+        let byte_offset = interpreter.to_index(byte_offset)?;
+        if byte_offset % size != 0 {
+            return Err(interpreter.construct_range_error(format!(
+                "{}: start offset should be a multiple of {}",
+                name, size
+            )));
+        }
+        let length = if length.is_undefined() {
+            None
+        } else {
+            Some(interpreter.to_index(length)?)
+        };
+
+        let (buffer_byte_length, resizable) = buffer
+            .as_object()
+            .and_then(|object| {
+                object
+                    .as_array_buffer()
+                    .map(|buffer| (buffer.byte_length(), buffer.is_resizable()))
+            })
+            .expect("the buffer is an ArrayBuffer");
+        let array_length = match length {
+            None if resizable => None,
+            None => {
+                if buffer_byte_length % size != 0 {
+                    return Err(interpreter.construct_range_error(format!(
+                        "{}: byte length of the buffer should be a multiple of {}",
+                        name, size
+                    )));
+                }
+                if byte_offset > buffer_byte_length {
+                    return Err(interpreter.construct_range_error(format!(
+                        "{}: start offset is outside the bounds of the buffer",
+                        name
+                    )));
+                }
+                Some((buffer_byte_length - byte_offset) / size)
+            }
+            Some(length) => Some(length),
+        };
+
+        let end = match array_length {
+            Some(length) => length
+                .checked_mul(size)
+                .and_then(|byte_length| byte_length.checked_add(byte_offset)),
+            None => Some(byte_offset),
+        };
+        if end.map_or(true, |end| end > buffer_byte_length) {
+            return Err(
+                interpreter.construct_range_error(format!("{}: invalid typed array length", name))
+            );
+        }
+
+        Ok(TypedArrayData::new(
+            kind,
+            buffer.clone(),
+            byte_offset,
+            array_length,
+        ))
+    }
+
+    /// Create a new typed array
+    ///
+    /// The argument can be a length, another typed array to copy, an `ArrayBuffer` to view, or an
+    /// iterable or array-like object whose values are converted to the elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray
+    fn make_typed_array(
+        kind: TypedArrayKind,
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let first = args.get(0).cloned().unwrap_or_default();
+        let source = first
+            .as_object()
+            .and_then(|object| object.as_typed_array().cloned());
+        let is_buffer = first
+            .as_object()
+            .map_or(false, |object| object.as_array_buffer().is_some());
+
+        let array = if !first.is_object() {
+            let length = ctx.to_index(&first)?;
+            Self::allocate(kind, length, ctx)?
+        } else if let Some(source) = source {
+            Self::from_typed_array(kind, &source, ctx)?
+        } else if is_buffer {
+            let byte_offset = args.get(1).cloned().unwrap_or_default();
+            let length = args.get(2).cloned().unwrap_or_default();
+            Self::from_array_buffer(kind, &first, &byte_offset, &length, ctx)?
+        } else {
+            let symbol_iterator = ctx.well_known_symbols().iterator_symbol();
+            let iterator = ctx.get_field(&first, symbol_iterator)?;
+            if iterator.is_null_or_undefined() {
+                let length = ctx.get_field(&first, "length")?;
+                let length = ctx.to_length(&length)?;
+                let array = Self::allocate(kind, length, ctx)?;
+                for index in 0..length {
+                    let value = ctx.get_field(&first, index.to_string())?;
+                    Self::set(&array, index, &value, ctx)?;
+                }
+                array
+            } else {
+                if !iterator.is_function() {
+                    return ctx.throw_type_error(format!(
+                        "{}: [Symbol.iterator] is not a function",
+                        kind.name()
+                    ));
+                }
+                let values = iterable_to_list(ctx, &first)?;
+                let array = Self::allocate(kind, values.len(), ctx)?;
+                for (index, value) in values.iter().enumerate() {
+                    Self::set(&array, index, value, ctx)?;
+                }
+                array
+            }
+        };
+
+        this.set_data(ObjectData::TypedArray(array));
+        Ok(this.clone())
+    }
+
+    /// Gets the native function of the constructor of the typed arrays of the given kind.
+    fn constructor(kind: TypedArrayKind) -> NativeFunctionData {
+        match kind {
+            TypedArrayKind::Int8 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::Int8, this, args, ctx)
+            }
+            TypedArrayKind::Uint8 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::Uint8, this, args, ctx)
+            }
+            TypedArrayKind::Uint8Clamped => |this, args, ctx| {
+                Self::make_typed_array(TypedArrayKind::Uint8Clamped, this, args, ctx)
+            },
+            TypedArrayKind::Int16 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::Int16, this, args, ctx)
+            }
+            TypedArrayKind::Uint16 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::Uint16, this, args, ctx)
+            }
+            TypedArrayKind::Int32 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::Int32, this, args, ctx)
+            }
+            TypedArrayKind::Uint32 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::Uint32, this, args, ctx)
+            }
+            TypedArrayKind::BigInt64 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::BigInt64, this, args, ctx)
+            }
+            TypedArrayKind::BigUint64 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::BigUint64, this, args, ctx)
+            }
+            TypedArrayKind::Float32 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::Float32, this, args, ctx)
+            }
+            TypedArrayKind::Float64 => {
+                |this, args, ctx| Self::make_typed_array(TypedArrayKind::Float64, this, args, ctx)
+            }
+        }
+    }
+
+    /// The `%TypedArray%` constructor, which throws a `TypeError` as it is abstract.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%
+    fn make_abstract(_: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        ctx.throw_type_error("the TypedArray constructor can't be called directly")
+    }
+
+    /// `%TypedArray%.from( source [ , mapFn [ , thisArg ] ] )`
+    ///
+    /// This function creates a new typed array, with the constructor it is called on, from the
+    /// values of an iterable or array-like object, optionally mapped with `mapFn`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/from
+    pub(crate) fn from(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        if !this
+            .as_object()
+            .map_or(false, |object| object.is_constructable())
+        {
+            return ctx.throw_type_error("TypedArray.from: 'this' is not a constructor");
+        }
+        let source = args.get(0).cloned().unwrap_or_default();
+        let map_fn = args.get(1).cloned().unwrap_or_default();
+        let this_arg = args.get(2).cloned().unwrap_or_default();
+        if !map_fn.is_undefined() && !map_fn.is_function() {
+            return ctx.throw_type_error("TypedArray.from: the map function is not a function");
+        }
+
+        let map = |value: Value, index: usize, ctx: &mut Interpreter| {
+            if map_fn.is_undefined() {
+                Ok(value)
+            } else {
+                ctx.call(&map_fn, &this_arg, &[value, Value::from(index)])
+            }
+        };
+
+        let symbol_iterator = ctx.well_known_symbols().iterator_symbol();
+        let iterator = if source.is_null_or_undefined() {
+            Value::undefined()
+        } else {
+            let object = ctx.to_object(&source)?;
+            ctx.get_field(&object, symbol_iterator)?
+        };
+
+        if !iterator.is_null_or_undefined() {
+            if !iterator.is_function() {
+                return ctx
+                    .throw_type_error("TypedArray.from: [Symbol.iterator] is not a function");
+            }
+            let values = iterable_to_list(ctx, &source)?;
+            let (target, array) = Self::create_from_constructor(this, values.len(), ctx)?;
+            for (index, value) in values.into_iter().enumerate() {
+                let value = map(value, index, ctx)?;
+                Self::set(&array, index, &value, ctx)?;
+            }
+            return Ok(target);
+        }
+
+        let array_like = ctx.to_object(&source)?;
+        let length = ctx.get_field(&array_like, "length")?;
+        let length = ctx.to_length(&length)?;
+        let (target, array) = Self::create_from_constructor(this, length, ctx)?;
+        for index in 0..length {
+            let value = ctx.get_field(&array_like, index.to_string())?;
+            let value = map(value, index, ctx)?;
+            Self::set(&array, index, &value, ctx)?;
+        }
+        Ok(target)
+    }
+
+    /// `%TypedArray%.of( ...items )`
+    ///
+    /// This function creates a new typed array, with the constructor it is called on, from its
+    /// arguments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/of
+    pub(crate) fn of(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        if !this
+            .as_object()
+            .map_or(false, |object| object.is_constructable())
+        {
+            return ctx.throw_type_error("TypedArray.of: 'this' is not a constructor");
+        }
+        let (target, array) = Self::create_from_constructor(this, args.len(), ctx)?;
+        for (index, value) in args.iter().enumerate() {
+            Self::set(&array, index, value, ctx)?;
+        }
+        Ok(target)
+    }
+
+    /// `get %TypedArray%.prototype.buffer`
+    ///
+    /// This accessor returns the `ArrayBuffer` the elements are stored in.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.buffer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/buffer
+    pub(crate) fn buffer(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Ok(Self::typed_array_of(this, ctx)?.buffer.clone())
+    }
+
+    /// `get %TypedArray%.prototype.byteLength`
+    ///
+    /// This accessor returns the number of bytes of the elements, which is 0 if the array is out
+    /// of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteLength
+    pub(crate) fn byte_length(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Ok(Self::typed_array_of(this, ctx)?.byte_length().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteOffset`
+    ///
+    /// This accessor returns the offset of the first element in the buffer, which is 0 if the
+    /// array is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.byteoffset
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteOffset
+    pub(crate) fn byte_offset(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::typed_array_of(this, ctx)?;
+        if array.is_out_of_bounds() {
+            return Ok(Value::from(0));
+        }
+        Ok(array.byte_offset.into())
+    }
+
+    /// `get %TypedArray%.prototype.length`
+    ///
+    /// This accessor returns the number of elements, which is 0 if the array is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.length
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/length
+    pub(crate) fn length(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Ok(Self::typed_array_of(this, ctx)?.length().into())
+    }
+
+    /// `get %TypedArray%.prototype[ @@toStringTag ]`
+    ///
+    /// This accessor returns the name of the constructor of the typed array, or `undefined` if
+    /// `this` isn't a typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-@@tostringtag
+    pub(crate) fn to_string_tag(this: &Value, _: &[Value], _: &mut Interpreter) -> ResultValue {
+        let kind = this
+            .as_object()
+            .and_then(|object| object.as_typed_array().map(TypedArrayData::kind));
+        Ok(kind.map_or_else(Value::undefined, |kind| Value::from(kind.name())))
+    }
+
+    /// `%TypedArray%.prototype.at( index )`
+    ///
+    /// This method returns the element at the index, which can be negative to count from the end
+    /// of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/at
+    pub(crate) fn at(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length() as f64;
+        let relative = ctx.to_integer(&args.get(0).cloned().unwrap_or_default())?;
+        let index = if relative >= 0.0 {
+            relative
+        } else {
+            length + relative
+        };
+        if index < 0.0 || index >= length {
+            return Ok(Value::undefined());
+        }
+        Ok(Self::get(&array, index as usize))
+    }
+
+    /// `%TypedArray%.prototype.copyWithin( target, start [ , end ] )`
+    ///
+    /// This method copies the elements from `start` to `end` to the elements from `target`,
+    /// which can all be negative to count from the end of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.copywithin
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/copyWithin
+    pub(crate) fn copy_within(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let to = relative_index(args.get(0), length, 0, ctx)?;
+        let from = relative_index(args.get(1), length, 0, ctx)?;
+        let end = relative_index(args.get(2), length, length, ctx)?;
+        let count = end.saturating_sub(from).min(length - to);
+
+        if count > 0 {
+            if array.is_out_of_bounds() {
+                return ctx.throw_type_error("the typed array is out of bounds");
+            }
+            // The conversions of the arguments can shrink the array, in which case only the
+            // elements still in it are copied.
+            let size = array.kind.element_size();
+            let limit = array.byte_length();
+            let (to, from) = (to * size, from * size);
+            let count = (count * size)
+                .min(limit.saturating_sub(from))
+                .min(limit.saturating_sub(to));
+            if count > 0 {
+                array.with_bytes_mut(|bytes| bytes.copy_within(from..from + count, to));
+            }
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.entries( )`
+    ///
+    /// This method returns an iterator over `[index, element]` pairs for each element of the
+    /// array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/entries
+    pub(crate) fn entries(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::this_typed_array(this, ctx)?;
+        Ok(ArrayIterator::create_array_iterator(
+            ctx,
+            this.clone(),
+            ArrayIterationKind::KeyAndValue,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.every( callbackFn [ , thisArg ] )`
+    ///
+    /// This method checks if the callback returns a truthy value for every element of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.every
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/every
+    pub(crate) fn every(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let callback = Self::callback(args, "every", ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..array.length() {
+            let arguments = [Self::get(&array, index), Value::from(index), this.clone()];
+            if !ctx.call(&callback, &this_arg, &arguments)?.to_boolean() {
+                return Ok(false.into());
+            }
+        }
+        Ok(true.into())
+    }
+
+    /// `%TypedArray%.prototype.fill( value [ , start [ , end ] ] )`
+    ///
+    /// This method sets the elements from `start` to `end`, which can be negative to count from
+    /// the end of the array, to the value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.fill
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/fill
+    pub(crate) fn fill(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let value = array
+            .kind
+            .to_element_value(&args.get(0).cloned().unwrap_or_default(), ctx)?;
+        let start = relative_index(args.get(1), length, 0, ctx)?;
+        let end = relative_index(args.get(2), length, length, ctx)?;
+
+        if array.is_out_of_bounds() {
+            return ctx.throw_type_error("the typed array is out of bounds");
+        }
+        for index in start..end.min(array.length()) {
+            array.set_element(index as f64, &value);
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.filter( callbackFn [ , thisArg ] )`
+    ///
+    /// This method returns a new typed array with the elements of the array for which the
+    /// callback returns a truthy value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.filter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/filter
+    pub(crate) fn filter(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let callback = Self::callback(args, "filter", ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        let mut kept = Vec::new();
+        for index in 0..array.length() {
+            let value = Self::get(&array, index);
+            let arguments = [value.clone(), Value::from(index), this.clone()];
+            if ctx.call(&callback, &this_arg, &arguments)?.to_boolean() {
+                kept.push(value);
+            }
+        }
+
+        let result = Self::allocate(array.kind, kept.len(), ctx)?;
+        for (index, value) in kept.iter().enumerate() {
+            result.set_element(index as f64, value);
+        }
+        Ok(Self::create_object(result, ctx))
+    }
+
+    /// Helper function for the `find()`, `findIndex()`, `findLast()` and `findLastIndex()`
+    /// methods, which returns the first element, in the given order of indices, for which the
+    /// callback returns a truthy value, along with its index.
+    fn find_element<I>(
+        this: &Value,
+        args: &[Value],
+        method: &str,
+        indices: I,
+        ctx: &mut Interpreter,
+    ) -> Result<Option<(usize, Value)>, Value>
+    where
+        I: Fn(usize) -> Box<dyn Iterator<Item = usize>>,
+    {
+        let array = Self::this_typed_array(this, ctx)?;
+        let callback = Self::callback(args, method, ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in indices(array.length()) {
+            let value = Self::get(&array, index);
+            let arguments = [value.clone(), Value::from(index), this.clone()];
+            if ctx.call(&callback, &this_arg, &arguments)?.to_boolean() {
+                return Ok(Some((index, value)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// `%TypedArray%.prototype.find( predicate [ , thisArg ] )`
+    ///
+    /// This method returns the first element for which the predicate returns a truthy value, or
+    /// `undefined` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.find
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/find
+    pub(crate) fn find(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let found = Self::find_element(this, args, "find", |length| Box::new(0..length), ctx)?;
+        Ok(found.map_or_else(Value::undefined, |(_, value)| value))
+    }
+
+    /// `%TypedArray%.prototype.findIndex( predicate [ , thisArg ] )`
+    ///
+    /// This method returns the index of the first element for which the predicate returns a
+    /// truthy value, or -1 if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/findIndex
+    pub(crate) fn find_index(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let found = Self::find_element(this, args, "findIndex", |length| Box::new(0..length), ctx)?;
+        Ok(found.map_or(Value::from(-1), |(index, _)| Value::from(index)))
+    }
+
+    /// `%TypedArray%.prototype.findLast( predicate [ , thisArg ] )`
+    ///
+    /// This method returns the last element for which the predicate returns a truthy value, or
+    /// `undefined` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlast
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/findLast
+    pub(crate) fn find_last(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let found = Self::find_element(
+            this,
+            args,
+            "findLast",
+            |length| Box::new((0..length).rev()),
+            ctx,
+        )?;
+        Ok(found.map_or_else(Value::undefined, |(_, value)| value))
+    }
+
+    /// `%TypedArray%.prototype.findLastIndex( predicate [ , thisArg ] )`
+    ///
+    /// This method returns the index of the last element for which the predicate returns a
+    /// truthy value, or -1 if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlastindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/findLastIndex
+    pub(crate) fn find_last_index(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let found = Self::find_element(
+            this,
+            args,
+            "findLastIndex",
+            |length| Box::new((0..length).rev()),
+            ctx,
+        )?;
+        Ok(found.map_or(Value::from(-1), |(index, _)| Value::from(index)))
+    }
+
+    /// `%TypedArray%.prototype.forEach( callbackFn [ , thisArg ] )`
+    ///
+    /// This method calls the callback for each element of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/forEach
+    pub(crate) fn for_each(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let callback = Self::callback(args, "forEach", ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..array.length() {
+            let arguments = [Self::get(&array, index), Value::from(index), this.clone()];
+            ctx.call(&callback, &this_arg, &arguments)?;
+        }
+        Ok(Value::undefined())
+    }
+
+    /// Helper function to convert the `fromIndex` argument of `includes()` and `indexOf()`, which
+    /// returns `None` if no element should be searched.
+    fn from_index(
+        argument: Option<&Value>,
+        length: usize,
+        ctx: &mut Interpreter,
+    ) -> Result<Option<usize>, Value> {
+        if length == 0 {
+            return Ok(None);
+        }
+        let from = ctx.to_integer(&argument.cloned().unwrap_or_default())?;
+        if from >= length as f64 {
+            return Ok(None);
+        }
+        if from >= 0.0 {
+            Ok(Some(from as usize))
+        } else {
+            Ok(Some((length as f64 + from).max(0.0) as usize))
+        }
+    }
+
+    /// `%TypedArray%.prototype.includes( searchElement [ , fromIndex ] )`
+    ///
+    /// This method checks if the array contains the element, compared with `SameValueZero`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.includes
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/includes
+    pub(crate) fn includes(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let search = args.get(0).cloned().unwrap_or_default();
+        let from = match Self::from_index(args.get(1), length, ctx)? {
+            Some(from) => from,
+            None => return Ok(false.into()),
+        };
+        let found = (from..length).any(|index| same_value_zero(&Self::get(&array, index), &search));
+        Ok(found.into())
+    }
+
+    /// `%TypedArray%.prototype.indexOf( searchElement [ , fromIndex ] )`
+    ///
+    /// This method returns the first index of the element, compared with strict equality, or -1
+    /// if the array doesn't contain it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.indexof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/indexOf
+    pub(crate) fn index_of(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let search = args.get(0).cloned().unwrap_or_default();
+        let from = match Self::from_index(args.get(1), length, ctx)? {
+            Some(from) => from,
+            None => return Ok(Value::from(-1)),
+        };
+        // The elements that are no longer in the array, if it was shrunk, are skipped.
+        let found = (from..length).find(|&index| {
+            array
+                .get_element(index as f64)
+                .map_or(false, |value| value.strict_equals(&search))
+        });
+        Ok(found.map_or(Value::from(-1), Value::from))
+    }
+
+    /// `%TypedArray%.prototype.join( separator )`
+    ///
+    /// This method returns the string representations of the elements, separated by the
+    /// separator, which is a comma by default.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.join
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/join
+    pub(crate) fn join(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let separator = match args.get(0) {
+            Some(separator) if !separator.is_undefined() => ctx.to_string(separator)?.to_string(),
+            _ => String::from(","),
+        };
+
+        let mut result = String::new();
+        for index in 0..length {
+            if index > 0 {
+                result.push_str(&separator);
+            }
+            let value = Self::get(&array, index);
+            if !value.is_undefined() {
+                result.push_str(&ctx.to_string(&value)?);
+            }
+        }
+        Ok(Value::from(result))
+    }
+
+    /// `%TypedArray%.prototype.keys( )`
+    ///
+    /// This method returns an iterator over the indices of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/keys
+    pub(crate) fn keys(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::this_typed_array(this, ctx)?;
+        Ok(ArrayIterator::create_array_iterator(
+            ctx,
+            this.clone(),
+            ArrayIterationKind::Key,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.lastIndexOf( searchElement [ , fromIndex ] )`
+    ///
+    /// This method returns the last index of the element, compared with strict equality, or -1
+    /// if the array doesn't contain it. The search goes backwards from `fromIndex`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.lastindexof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/lastIndexOf
+    pub(crate) fn last_index_of(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        if length == 0 {
+            return Ok(Value::from(-1));
+        }
+        let search = args.get(0).cloned().unwrap_or_default();
+        let from = match args.get(1) {
+            Some(from) => ctx.to_integer(from)?,
+            None => length as f64 - 1.0,
+        };
+        let from = if from >= 0.0 {
+            from.min(length as f64 - 1.0)
+        } else {
+            length as f64 + from
+        };
+        if from < 0.0 {
+            return Ok(Value::from(-1));
+        }
+
+        let found = (0..=from as usize).rev().find(|&index| {
+            array
+                .get_element(index as f64)
+                .map_or(false, |value| value.strict_equals(&search))
+        });
+        Ok(found.map_or(Value::from(-1), Value::from))
+    }
+
+    /// `%TypedArray%.prototype.map( callbackFn [ , thisArg ] )`
+    ///
+    /// This method returns a new typed array of the same type, with the values returned by the
+    /// callback for each element of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.map
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/map
+    pub(crate) fn map(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let callback = Self::callback(args, "map", ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        let result = Self::allocate(array.kind, length, ctx)?;
+        for index in 0..length {
+            let arguments = [Self::get(&array, index), Value::from(index), this.clone()];
+            let value = ctx.call(&callback, &this_arg, &arguments)?;
+            Self::set(&result, index, &value, ctx)?;
+        }
+        Ok(Self::create_object(result, ctx))
+    }
+
+    /// Helper function for the `reduce()` and `reduceRight()` methods, which reduces the elements
+    /// in the given order of indices.
+    fn reduce_elements(
+        this: &Value,
+        args: &[Value],
+        method: &str,
+        reverse: bool,
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let callback = Self::callback(args, method, ctx)?;
+        let length = array.length();
+        let mut indices: Box<dyn Iterator<Item = usize>> = if reverse {
+            Box::new((0..length).rev())
+        } else {
+            Box::new(0..length)
+        };
+
+        let mut accumulator = match args.get(1) {
+            Some(initial_value) => initial_value.clone(),
+            None => match indices.next() {
+                Some(index) => Self::get(&array, index),
+                None => {
+                    return ctx.throw_type_error(format!(
+                        "TypedArray.prototype.{}: empty array with no initial value",
+                        method
+                    ))
+                }
+            },
+        };
+        for index in indices {
+            let arguments = [
+                accumulator,
+                Self::get(&array, index),
+                Value::from(index),
+                this.clone(),
+            ];
+            accumulator = ctx.call(&callback, &Value::undefined(), &arguments)?;
+        }
+        Ok(accumulator)
+    }
+
+    /// `%TypedArray%.prototype.reduce( callbackFn [ , initialValue ] )`
+    ///
+    /// This method accumulates the elements of the array, from the first to the last, with the
+    /// callback.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduce
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reduce
+    pub(crate) fn reduce(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::reduce_elements(this, args, "reduce", false, ctx)
+    }
+
+    /// `%TypedArray%.prototype.reduceRight( callbackFn [ , initialValue ] )`
+    ///
+    /// This method accumulates the elements of the array, from the last to the first, with the
+    /// callback.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduceright
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reduceRight
+    pub(crate) fn reduce_right(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::reduce_elements(this, args, "reduceRight", true, ctx)
+    }
+
+    /// `%TypedArray%.prototype.reverse( )`
+    ///
+    /// This method reverses the order of the elements of the array, in place.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reverse
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reverse
+    pub(crate) fn reverse(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let size = array.kind.element_size();
+        array.with_bytes_mut(|bytes| {
+            for lower in 0..length / 2 {
+                let upper = length - 1 - lower;
+                for byte in 0..size {
+                    bytes.swap(lower * size + byte, upper * size + byte);
+                }
+            }
+        });
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.set( source [ , offset ] )`
+    ///
+    /// This method copies the values of a typed array or an array-like object to the elements of
+    /// the array, starting at `offset`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/set
+    pub(crate) fn set_values(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = Self::typed_array_of(this, ctx)?;
+        let offset = ctx.to_integer(&args.get(1).cloned().unwrap_or_default())?;
+        if offset < 0.0 {
+            return ctx.throw_range_error("TypedArray.prototype.set: offset must be positive");
+        }
+
+        let source = args.get(0).cloned().unwrap_or_default();
+        let source_array = source
+            .as_object()
+            .and_then(|object| object.as_typed_array().cloned());
+        if target.is_out_of_bounds() {
+            return ctx.throw_type_error("the typed array is out of bounds");
+        }
+        let target_length = target.length();
+
+        if let Some(source) = source_array {
+            if source.is_out_of_bounds() {
+                return ctx.throw_type_error("the source typed array is out of bounds");
+            }
+            if target.kind.is_bigint() != source.kind.is_bigint() {
+                return ctx.throw_type_error(
+                    "cannot mix BigInt and other types, use explicit conversions",
+                );
+            }
+            let source_length = source.length();
+            if source_length as f64 + offset > target_length as f64 {
+                return ctx.throw_range_error("TypedArray.prototype.set: source is too large");
+            }
+            let offset = offset as usize;
+
+            // The values are read before they are written, as the arrays can share a buffer.
+            if source.kind == target.kind {
+                let size = target.kind.element_size();
+                let values = source.with_bytes(<[u8]>::to_vec);
+                target.with_bytes_mut(|bytes| {
+                    bytes[offset * size..offset * size + values.len()].copy_from_slice(&values)
+                });
+            } else {
+                let values: Vec<Value> = (0..source_length)
+                    .map(|index| Self::get(&source, index))
+                    .collect();
+                for (index, value) in values.iter().enumerate() {
+                    target.set_element((offset + index) as f64, value);
+                }
+            }
+            return Ok(Value::undefined());
+        }
+
+        let source = ctx.to_object(&source)?;
+        let source_length = ctx.get_field(&source, "length")?;
+        let source_length = ctx.to_length(&source_length)?;
+        if source_length as f64 + offset > target_length as f64 {
+            return ctx.throw_range_error("TypedArray.prototype.set: source is too large");
+        }
+        let offset = offset as usize;
+        for index in 0..source_length {
+            let value = ctx.get_field(&source, index.to_string())?;
+            Self::set(&target, offset + index, &value, ctx)?;
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `%TypedArray%.prototype.slice( start, end )`
+    ///
+    /// This method returns a new typed array of the same type, with a copy of the elements from
+    /// `start` to `end`, which can be negative to count from the end of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/slice
+    pub(crate) fn slice(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let start = relative_index(args.get(0), length, 0, ctx)?;
+        let end = relative_index(args.get(1), length, length, ctx)?;
+        let count = end.saturating_sub(start);
+
+        let result = Self::allocate(array.kind, count, ctx)?;
+        if count > 0 {
+            if array.is_out_of_bounds() {
+                return ctx.throw_type_error("the typed array is out of bounds");
+            }
+            // The conversions of the arguments can shrink the array, in which case only the
+            // elements still in it are copied.
+            let end = end.min(array.length());
+            for (index, source_index) in (start..end).enumerate() {
+                result.set_element(index as f64, &Self::get(&array, source_index));
+            }
+        }
+        Ok(Self::create_object(result, ctx))
+    }
+
+    /// `%TypedArray%.prototype.some( callbackFn [ , thisArg ] )`
+    ///
+    /// This method checks if the callback returns a truthy value for at least one element of the
+    /// array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.some
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/some
+    pub(crate) fn some(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let callback = Self::callback(args, "some", ctx)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..array.length() {
+            let arguments = [Self::get(&array, index), Value::from(index), this.clone()];
+            if ctx.call(&callback, &this_arg, &arguments)?.to_boolean() {
+                return Ok(true.into());
+            }
+        }
+        Ok(false.into())
+    }
+
+    /// Helper function to get the sorted elements of the array, for `sort()` and `toSorted()`.
+    fn sorted_elements(
+        array: &TypedArrayData,
+        compare: &Value,
+        method: &str,
+        ctx: &mut Interpreter,
+    ) -> Result<Vec<Value>, Value> {
+        if !compare.is_undefined() && !compare.is_function() {
+            return Err(ctx.construct_type_error(format!(
+                "TypedArray.prototype.{}: the comparison function must be either a function or undefined",
+                method
+            )));
+        }
+        let values = (0..array.length())
+            .map(|index| Self::get(array, index))
+            .collect();
+        sort_values(values, &mut |x, y| compare_elements(x, y, compare, ctx))
+    }
+
+    /// `%TypedArray%.prototype.sort( comparefn )`
+    ///
+    /// This method sorts the elements of the array in place, in numeric order or with the
+    /// comparison function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/sort
+    pub(crate) fn sort(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let compare = args.get(0).cloned().unwrap_or_default();
+        if !compare.is_undefined() && !compare.is_function() {
+            return ctx.throw_type_error(
+                "TypedArray.prototype.sort: the comparison function must be either a function or undefined",
+            );
+        }
+        let array = Self::this_typed_array(this, ctx)?;
+        let values = Self::sorted_elements(&array, &compare, "sort", ctx)?;
+        for (index, value) in values.iter().enumerate() {
+            array.set_element(index as f64, value);
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.subarray( begin, end )`
+    ///
+    /// This method returns a new typed array of the same type viewing the same buffer, from the
+    /// element at `begin` to the element at `end`, which can be negative to count from the end of
+    /// the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.subarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/subarray
+    pub(crate) fn subarray(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::typed_array_of(this, ctx)?;
+        let length = array.length();
+        let begin = relative_index(args.get(0), length, 0, ctx)?;
+        let end = args.get(1).cloned().unwrap_or_default();
+
+        // A subarray of a length-tracking array, without an end, tracks the length too.
+        let new_length = if array.is_length_tracking() && end.is_undefined() {
+            Value::undefined()
+        } else {
+            let end = relative_index(Some(&end), length, length, ctx)?;
+            Value::from(end.saturating_sub(begin))
+        };
+        let byte_offset = array.byte_offset + begin * array.kind.element_size();
+        let result = Self::from_array_buffer(
+            array.kind,
+            &array.buffer,
+            &Value::from(byte_offset),
+            &new_length,
+            ctx,
+        )?;
+        Ok(Self::create_object(result, ctx))
+    }
+
+    /// `%TypedArray%.prototype.toLocaleString( )`
+    ///
+    /// This method returns the locale-specific string representations of the elements, separated
+    /// by commas.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/toLocaleString
+    pub(crate) fn to_locale_string(
+        this: &Value,
+        _: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let mut result = String::new();
+        for index in 0..array.length() {
+            if index > 0 {
+                result.push(',');
+            }
+            let value = Self::get(&array, index);
+            if !value.is_undefined() {
+                let object = ctx.to_object(&value)?;
+                let method = ctx.get_field(&object, "toLocaleString")?;
+                let string = ctx.call(&method, &value, &[])?;
+                result.push_str(&ctx.to_string(&string)?);
+            }
+        }
+        Ok(Value::from(result))
+    }
+
+    /// `%TypedArray%.prototype.toReversed( )`
+    ///
+    /// This method returns a new typed array of the same type, with the elements of the array in
+    /// reverse order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.toreversed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/toReversed
+    pub(crate) fn to_reversed(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let result = Self::allocate(array.kind, length, ctx)?;
+        for index in 0..length {
+            result.set_element(index as f64, &Self::get(&array, length - 1 - index));
+        }
+        Ok(Self::create_object(result, ctx))
+    }
+
+    /// `%TypedArray%.prototype.toSorted( comparefn )`
+    ///
+    /// This method returns a new typed array of the same type, with the elements of the array
+    /// sorted in numeric order or with the comparison function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.tosorted
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/toSorted
+    pub(crate) fn to_sorted(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let compare = args.get(0).cloned().unwrap_or_default();
+        if !compare.is_undefined() && !compare.is_function() {
+            return ctx.throw_type_error(
+                "TypedArray.prototype.toSorted: the comparison function must be either a function or undefined",
+            );
+        }
+        let array = Self::this_typed_array(this, ctx)?;
+        let result = Self::allocate(array.kind, array.length(), ctx)?;
+        let values = Self::sorted_elements(&array, &compare, "toSorted", ctx)?;
+        for (index, value) in values.iter().enumerate() {
+            result.set_element(index as f64, value);
+        }
+        Ok(Self::create_object(result, ctx))
+    }
+
+    /// `%TypedArray%.prototype.values( )`
+    ///
+    /// This method returns an iterator over the elements of the array. It is also the
+    /// `[Symbol.iterator]()` method of typed arrays.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/values
+    pub(crate) fn values(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::this_typed_array(this, ctx)?;
+        Ok(ArrayIterator::create_array_iterator(
+            ctx,
+            this.clone(),
+            ArrayIterationKind::Value,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.with( index, value )`
+    ///
+    /// This method returns a new typed array of the same type, with a copy of the elements of the
+    /// array, except the element at the index, which can be negative to count from the end of the
+    /// array, which is replaced with the value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/with
+    pub(crate) fn with(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let array = Self::this_typed_array(this, ctx)?;
+        let length = array.length();
+        let relative = ctx.to_integer(&args.get(0).cloned().unwrap_or_default())?;
+        let index = if relative >= 0.0 {
+            relative
+        } else {
+            length as f64 + relative
+        };
+        let value = array
+            .kind
+            .to_element_value(&args.get(1).cloned().unwrap_or_default(), ctx)?;
+        if !array.is_valid_index(index) {
+            return ctx.throw_range_error("TypedArray.prototype.with: index out of bounds");
+        }
+
+        let result = Self::allocate(array.kind, length, ctx)?;
+        for current in 0..length {
+            let element = if current as f64 == index {
+                value.clone()
+            } else {
+                Self::get(&array, current)
+            };
+            result.set_element(current as f64, &element);
+        }
+        Ok(Self::create_object(result, ctx))
+    }
+
+    /// Initialise the `%TypedArray%` intrinsic object and the typed array constructors, which are
+    /// returned with their names to be added to the global object.
+    ///
+    /// `%TypedArray%` itself is not a global.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> Vec<(&'static str, Value)> {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let prototype = Value::new_object(Some(global));
+
+        make_builtin_getter(Self::buffer, "buffer", &prototype, interpreter);
+        make_builtin_getter(Self::byte_length, "byteLength", &prototype, interpreter);
+        make_builtin_getter(Self::byte_offset, "byteOffset", &prototype, interpreter);
+        make_builtin_getter(Self::length, "length", &prototype, interpreter);
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        make_builtin_getter(Self::to_string_tag, to_string_tag, &prototype, interpreter);
+
+        make_builtin_fn(Self::at, "at", &prototype, 1, interpreter);
+        make_builtin_fn(Self::copy_within, "copyWithin", &prototype, 2, interpreter);
+        make_builtin_fn(Self::entries, "entries", &prototype, 0, interpreter);
+        make_builtin_fn(Self::every, "every", &prototype, 1, interpreter);
+        make_builtin_fn(Self::fill, "fill", &prototype, 1, interpreter);
+        make_builtin_fn(Self::filter, "filter", &prototype, 1, interpreter);
+        make_builtin_fn(Self::find, "find", &prototype, 1, interpreter);
+        make_builtin_fn(Self::find_index, "findIndex", &prototype, 1, interpreter);
+        make_builtin_fn(Self::find_last, "findLast", &prototype, 1, interpreter);
+        make_builtin_fn(
+            Self::find_last_index,
+            "findLastIndex",
+            &prototype,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(Self::for_each, "forEach", &prototype, 1, interpreter);
+        make_builtin_fn(Self::includes, "includes", &prototype, 1, interpreter);
+        make_builtin_fn(Self::index_of, "indexOf", &prototype, 1, interpreter);
+        make_builtin_fn(Self::join, "join", &prototype, 1, interpreter);
+        make_builtin_fn(Self::keys, "keys", &prototype, 0, interpreter);
+        make_builtin_fn(
+            Self::last_index_of,
+            "lastIndexOf",
+            &prototype,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(Self::map, "map", &prototype, 1, interpreter);
+        make_builtin_fn(Self::reduce, "reduce", &prototype, 1, interpreter);
+        make_builtin_fn(
+            Self::reduce_right,
+            "reduceRight",
+            &prototype,
+            1,
+            interpreter,
+        );
+        make_builtin_fn(Self::reverse, "reverse", &prototype, 0, interpreter);
+        make_builtin_fn(Self::set_values, "set", &prototype, 1, interpreter);
+        make_builtin_fn(Self::slice, "slice", &prototype, 2, interpreter);
+        make_builtin_fn(Self::some, "some", &prototype, 1, interpreter);
+        make_builtin_fn(Self::sort, "sort", &prototype, 1, interpreter);
+        make_builtin_fn(Self::subarray, "subarray", &prototype, 2, interpreter);
+        make_builtin_fn(
+            Self::to_locale_string,
+            "toLocaleString",
+            &prototype,
+            0,
+            interpreter,
+        );
+        make_builtin_fn(Self::to_reversed, "toReversed", &prototype, 0, interpreter);
+        make_builtin_fn(Self::to_sorted, "toSorted", &prototype, 1, interpreter);
+        make_builtin_fn(Self::values, "values", &prototype, 0, interpreter);
+        make_builtin_fn(Self::with, "with", &prototype, 2, interpreter);
+
+        // `%TypedArray%.prototype[Symbol.iterator]` is the same function object as `values`, and
+        // `%TypedArray%.prototype.toString` is the same function object as
+        // `Array.prototype.toString`.
+        let symbol_iterator = interpreter.well_known_symbols().iterator_symbol();
+        let values = prototype.get_field("values");
+        prototype.set_field(symbol_iterator, values);
+        let to_string = global
+            .get_field("Array")
+            .get_field(PROTOTYPE)
+            .get_field("toString");
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                "toString",
+                Property::data_descriptor(
+                    to_string,
+                    Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        let typed_array = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_abstract,
+            global,
+            prototype.clone(),
+            true,
+            false,
+        );
+
+        make_builtin_fn(Self::from, "from", &typed_array, 1, interpreter);
+        make_builtin_fn(Self::of, "of", &typed_array, 0, interpreter);
+
+        let mut constructors = Vec::with_capacity(TypedArrayKind::ALL.len());
+        for &kind in TypedArrayKind::ALL.iter() {
+            let bytes_per_element = Property::data_descriptor(
+                Value::from(kind.element_size()),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            );
+
+            let kind_prototype = Value::new_object(Some(global));
+            {
+                let mut kind_prototype = kind_prototype
+                    .as_object_mut()
+                    .expect("prototype is an object");
+                kind_prototype.set_prototype(prototype.clone());
+                kind_prototype.insert_property("BYTES_PER_ELEMENT", bytes_per_element.clone());
+            }
+
+            let constructor = make_constructor_fn(
+                kind.name(),
+                Self::CONSTRUCTOR_LENGTH,
+                Self::constructor(kind),
+                global,
+                kind_prototype,
+                true,
+                false,
+            );
+            {
+                let mut constructor = constructor
+                    .as_object_mut()
+                    .expect("constructor is an object");
+                constructor.set_prototype(typed_array.clone());
+                constructor.insert_property("BYTES_PER_ELEMENT", bytes_per_element);
+            }
+
+            constructors.push((kind.name(), constructor));
+        }
+
+        constructors
+    }
+}
+
+/// Compares two elements of a typed array, with the comparison function if it isn't `undefined`,
+/// or in numeric order, where `-0` comes before `+0` and `NaN` comes last.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-comparetypedarrayelements
+fn compare_elements(
+    x: &Value,
+    y: &Value,
+    compare: &Value,
+    interpreter: &mut Interpreter,
+) -> Result<Ordering, Value> {
+    if !compare.is_undefined() {
+        let result = interpreter.call(compare, &Value::undefined(), &[x.clone(), y.clone()])?;
+        let result = interpreter.to_number(&result)?;
+        return Ok(if result < 0.0 {
+            Ordering::Less
+        } else if result > 0.0 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        });
+    }
+
+    if let (Value::BigInt(ref x), Value::BigInt(ref y)) = (x, y) {
+        return Ok(Ord::cmp(&**x, &**y));
+    }
+    let (x, y) = (f64::from(x), f64::from(y));
+    Ok(match (x.is_nan(), y.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) if x == 0.0 && y == 0.0 => y.is_sign_negative().cmp(&x.is_sign_negative()),
+        (false, false) => x.partial_cmp(&y).expect("the numbers are not NaN"),
+    })
+}
+
+/// Sorts values with a comparison function that can throw, keeping the order of the values that
+/// compare equal.
+///
+/// This is a merge sort, so the comparison function is only called with values from the list,
+/// even if it is inconsistent.
+fn sort_values<F>(mut values: Vec<Value>, compare: &mut F) -> Result<Vec<Value>, Value>
+where
+    F: FnMut(&Value, &Value) -> Result<Ordering, Value>,
+{
+    if values.len() <= 1 {
+        return Ok(values);
+    }
+    let right = values.split_off(values.len() / 2);
+    let left = sort_values(values, compare)?;
+    let right = sort_values(right, compare)?;
+
+    let mut sorted = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(x), Some(y)) = (left.peek(), right.peek()) {
+        if compare(y, x)? == Ordering::Less {
+            sorted.extend(right.next());
+        } else {
+            sorted.extend(left.next());
+        }
+    }
+    sorted.extend(left);
+    sorted.extend(right);
+    Ok(sorted)
+}
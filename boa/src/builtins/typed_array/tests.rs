@@ -0,0 +1,327 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn construct() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var zeroed = new Float64Array(3);
+        var fromArray = new Int8Array([1, -2, 300]);
+        var fromTypedArray = new Uint16Array(fromArray);
+        var fromIterable = new Uint8Array(new Set([4, 5]));
+        var fromArrayLike = new Int32Array({ length: 2, 0: 7 });
+        var buffer = new ArrayBuffer(8);
+        var view = new Int16Array(buffer, 2, 2);
+        var rest = new Uint8Array(buffer, 4);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "zeroed.join()"), "\"0,0,0\"");
+    assert_eq!(forward(&mut engine, "fromArray.join()"), "\"1,-2,44\"");
+    assert_eq!(
+        forward(&mut engine, "fromTypedArray.join()"),
+        "\"1,65534,44\""
+    );
+    assert_eq!(forward(&mut engine, "fromIterable.join()"), "\"4,5\"");
+    assert_eq!(forward(&mut engine, "fromArrayLike.join()"), "\"7,0\"");
+    assert_eq!(forward(&mut engine, "view.byteOffset"), "2");
+    assert_eq!(forward(&mut engine, "view.byteLength"), "4");
+    assert_eq!(forward(&mut engine, "view.buffer === buffer"), "true");
+    assert_eq!(forward(&mut engine, "rest.length"), "4");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Int16Array(buffer, 1) } catch (e) { e.toString() }"
+        ),
+        "\"RangeError: Int16Array: start offset should be a multiple of 2\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Int32Array(buffer, 4, 2) } catch (e) { e.toString() }"
+        ),
+        "\"RangeError: Int32Array: invalid typed array length\""
+    );
+}
+
+#[test]
+fn element_conversions() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var uint8 = new Uint8Array([256, -1, 1.9]);
+        var clamped = new Uint8ClampedArray([300, -5, 1.5, 2.5]);
+        var float32 = new Float32Array([1.1]);
+        var int32 = new Int32Array([2147483648]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "uint8.join()"), "\"0,255,1\"");
+    assert_eq!(forward(&mut engine, "clamped.join()"), "\"255,0,2,2\"");
+    assert_eq!(forward(&mut engine, "float32[0] === 1.1"), "false");
+    assert_eq!(forward(&mut engine, "int32[0]"), "-2147483648");
+}
+
+#[test]
+fn bigint_elements() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var signed = new BigInt64Array([1n, -2n]);
+        var unsigned = new BigUint64Array(signed.buffer);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "signed[1]"), "-2n");
+    assert_eq!(forward(&mut engine, "unsigned[1]"), "18446744073709551614n");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { signed[0] = 1.5 } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: cannot convert the number 1.5 to a BigInt\""
+    );
+    forward(&mut engine, "signed[0] = '7'");
+    assert_eq!(forward(&mut engine, "signed[0]"), "7n");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new Int8Array(signed) } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: cannot mix BigInt and other types, use explicit conversions\""
+    );
+}
+
+#[test]
+fn bigint_elements_reject_numbers() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var array = new BigInt64Array(1);
+        function error(f) {
+            try { f(); } catch (e) { return e.name; }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "error(() => { array[0] = 1; })"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "error(() => array.fill(1))"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "error(() => new BigInt64Array([1]))"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "error(() => new BigUint64Array([undefined]))"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "error(() => array.fill(null))"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut engine, "error(() => { array[0] = Symbol(); })"),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut engine, "array[0]"), "0n");
+    forward(&mut engine, "array.fill(true)");
+    assert_eq!(forward(&mut engine, "array[0]"), "1n");
+}
+
+#[test]
+fn integer_indexed_properties() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var array = new Uint8Array([5, 6]);
+        array[2] = 1;
+        array["-0"] = 1;
+        array.extra = 1;
+        Object.getPrototypeOf(Uint8Array.prototype)[1] = "inherited";
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array[2]"), "undefined");
+    assert_eq!(forward(&mut engine, "array['-0']"), "undefined");
+    assert_eq!(forward(&mut engine, "array.extra"), "1");
+    assert_eq!(forward(&mut engine, "array[1]"), "6");
+    assert_eq!(forward(&mut engine, "1 in array"), "true");
+    assert_eq!(forward(&mut engine, "2 in array"), "false");
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(array).join()"),
+        "\"0,1,extra\""
+    );
+    assert_eq!(forward(&mut engine, "delete array[0]"), "false");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(array, '0', { value: 9 })"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(array, '1', { value: 9, writable: false })"
+        ),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "array.join()"), "\"9,6\"");
+}
+
+#[test]
+fn iteration() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var array = new Int16Array([3, 4]);
+        var sum = 0;
+        for (let value of array) {
+            sum += value;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "sum"), "7");
+    assert_eq!(forward(&mut engine, "[...array.keys()].join()"), "\"0,1\"");
+    assert_eq!(
+        forward(&mut engine, "[...array.entries()].join(';')"),
+        "\"0,3;1,4\""
+    );
+    assert_eq!(
+        forward(&mut engine, "array[Symbol.iterator] === array.values"),
+        "true"
+    );
+}
+
+#[test]
+fn prototype_methods() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var array = new Int8Array([3, -1, 2, 5]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array.at(-1)"), "5");
+    assert_eq!(
+        forward(&mut engine, "array.map(x => x * 2).join()"),
+        "\"6,-2,4,10\""
+    );
+    assert_eq!(
+        forward(&mut engine, "array.filter(x => x > 0).join()"),
+        "\"3,2,5\""
+    );
+    assert_eq!(
+        forward(&mut engine, "array.reduce((acc, x) => acc + x)"),
+        "9"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "array.reduceRight((acc, x) => acc + ',' + x, '')"
+        ),
+        "\",5,2,-1,3\""
+    );
+    assert_eq!(forward(&mut engine, "array.find(x => x < 0)"), "-1");
+    assert_eq!(forward(&mut engine, "array.findLastIndex(x => x > 2)"), "3");
+    assert_eq!(forward(&mut engine, "array.indexOf(2)"), "2");
+    assert_eq!(forward(&mut engine, "array.lastIndexOf(9)"), "-1");
+    assert_eq!(forward(&mut engine, "array.includes(5, -1)"), "true");
+    assert_eq!(forward(&mut engine, "array.every(x => x > -2)"), "true");
+    assert_eq!(forward(&mut engine, "array.some(x => x > 5)"), "false");
+    assert_eq!(forward(&mut engine, "array.slice(1, 3).join()"), "\"-1,2\"");
+    assert_eq!(
+        forward(&mut engine, "array.toSorted().join()"),
+        "\"-1,2,3,5\""
+    );
+    assert_eq!(
+        forward(&mut engine, "array.toReversed().join()"),
+        "\"5,2,-1,3\""
+    );
+    assert_eq!(
+        forward(&mut engine, "array.with(0, 7).join()"),
+        "\"7,-1,2,5\""
+    );
+    assert_eq!(forward(&mut engine, "array.join()"), "\"3,-1,2,5\"");
+    assert_eq!(forward(&mut engine, "array.toString()"), "\"3,-1,2,5\"");
+
+    forward(&mut engine, "array.sort((a, b) => b - a)");
+    assert_eq!(forward(&mut engine, "array.join()"), "\"5,3,2,-1\"");
+    forward(&mut engine, "array.reverse()");
+    assert_eq!(forward(&mut engine, "array.join()"), "\"-1,2,3,5\"");
+    forward(&mut engine, "array.copyWithin(0, 2)");
+    assert_eq!(forward(&mut engine, "array.join()"), "\"3,5,3,5\"");
+    forward(&mut engine, "array.fill(0, -2)");
+    assert_eq!(forward(&mut engine, "array.join()"), "\"3,5,0,0\"");
+}
+
+#[test]
+fn set_and_subarray() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var array = new Uint8Array(6);
+        var middle = array.subarray(2, -1);
+        array.set([1, 2], 1);
+        array.set(new Float64Array([7.5, 8]), 4);
+        middle.set(middle.subarray(0, 2), 1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "middle.length"), "3");
+    assert_eq!(forward(&mut engine, "middle.byteOffset"), "2");
+    assert_eq!(forward(&mut engine, "array.join()"), "\"0,1,2,2,0,8\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { array.set([1, 2], 5) } catch (e) { e.toString() }"
+        ),
+        "\"RangeError: TypedArray.prototype.set: source is too large\""
+    );
+}
+
+#[test]
+fn constructors() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var TypedArray = Object.getPrototypeOf(Int8Array);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(Float32Array) === TypedArray"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Float64Array.BYTES_PER_ELEMENT"), "8");
+    assert_eq!(
+        forward(&mut engine, "BigUint64Array.prototype.BYTES_PER_ELEMENT"),
+        "8"
+    );
+    assert_eq!(
+        forward(&mut engine, "Uint8Array.of(1, 2).join()"),
+        "\"1,2\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Int16Array.from([1, 2], x => x * 3).join()"),
+        "\"3,6\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array(2)[Symbol.toStringTag]"),
+        "\"Uint8Array\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { new TypedArray() } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: the TypedArray constructor can't be called directly\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Int8Array(1) } catch (e) { e.toString() }"
+        ),
+        "\"TypeError: Int8Array constructor requires 'new'\""
+    );
+}
@@ -42,17 +42,18 @@ macro_rules! print_obj_value {
     };
     (props of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr, $print_internals:expr) => {
         print_obj_value!(impl properties, $obj, |(key, val)| {
-            let v = &val
-                .value
-                .as_ref()
-                .expect("Could not get the property's value");
+            let v = match accessor_display(val) {
+                Some(accessor) => accessor.to_string(),
+                None => {
+                    let v = &val
+                        .value
+                        .as_ref()
+                        .expect("Could not get the property's value");
+                    $display_fn(v, $encounters, $indent.wrapping_add(4), $print_internals)
+                }
+            };
 
-            format!(
-                "{:>width$}: {}",
-                key,
-                $display_fn(v, $encounters, $indent.wrapping_add(4), $print_internals),
-                width = $indent,
-            )
+            format!("{:>width$}: {}", key, v, width = $indent)
         })
     };
 
@@ -68,6 +69,20 @@ macro_rules! print_obj_value {
     };
 }
 
+/// Returns the placeholder that is printed for an accessor property, which has no value of its own.
+///
+/// The getter isn't called, because printing a value must not run any JavaScript code.
+fn accessor_display(property: &Property) -> Option<&'static str> {
+    let is_function = |f: &Option<Value>| f.as_ref().map_or(false, |f| !f.is_undefined());
+    match (is_function(&property.get), is_function(&property.set)) {
+        _ if !property.is_accessor_descriptor() => None,
+        (true, true) => Some("[Getter/Setter]"),
+        (true, false) => Some("[Getter]"),
+        (false, true) => Some("[Setter]"),
+        (false, false) => Some("undefined"),
+    }
+}
+
 pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children: bool) -> String {
     match x {
         // We don't want to print private (compiler) or prototype properties
@@ -103,16 +118,20 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
 
                         let arr = (0..len)
                             .map(|i| {
+                                let element = v
+                                    .borrow()
+                                    .properties()
+                                    .get(i.to_string().as_str())
+                                    .unwrap()
+                                    .clone();
+                                if let Some(accessor) = accessor_display(&element) {
+                                    return accessor.to_string();
+                                }
+
                                 // Introduce recursive call to stringify any objects
                                 // which are part of the Array
                                 log_string_from(
-                                    &v.borrow()
-                                        .properties()
-                                        .get(i.to_string().as_str())
-                                        .unwrap()
-                                        .value
-                                        .clone()
-                                        .expect("Could not borrow value"),
+                                    element.value.as_ref().expect("Could not borrow value"),
                                     print_internals,
                                     false,
                                 )
@@ -154,6 +173,39 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
                         format!("Map({})", size)
                     }
                 }
+                ObjectData::Set(ref set) => {
+                    if set.is_empty() {
+                        return String::from("Set(0)");
+                    }
+
+                    if print_children {
+                        let entries = set
+                            .iter()
+                            .map(|value| log_string_from(value, print_internals, false))
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        format!("Set {{ {} }}", entries)
+                    } else {
+                        format!("Set({})", set.len())
+                    }
+                }
+                ObjectData::ArrayBuffer(ref buffer) => {
+                    format!("ArrayBuffer({})", buffer.byte_length())
+                }
+                ObjectData::TypedArray(ref array) => {
+                    let len = array.length();
+                    if print_children && len > 0 {
+                        let elements = (0..len)
+                            .filter_map(|i| array.get_element(i as f64))
+                            .map(|value| log_string_from(&value, print_internals, false))
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        format!("{}({}) [ {} ]", array.kind().name(), len, elements)
+                    } else {
+                        format!("{}({})", array.kind().name(), len)
+                    }
+                }
+                ObjectData::DataView(ref view) => format!("DataView({})", view.byte_length()),
                 _ => display_obj(&x, print_internals),
             }
         }
@@ -32,7 +32,15 @@ impl Eq for RationalHashable {}
 impl Hash for RationalHashable {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_bits().hash(state);
+        // Values equal with SameValueZero must have the same hash, so `+0` and `-0`, and all the
+        // `NaN`s, are hashed the same way.
+        if self.0 == 0.0 {
+            0.0_f64.to_bits().hash(state);
+        } else if self.0.is_nan() {
+            f64::NAN.to_bits().hash(state);
+        } else {
+            self.0.to_bits().hash(state);
+        }
     }
 }
 
@@ -43,7 +51,7 @@ impl Hash for Value {
             Self::Null => NullHashable.hash(state),
             Self::String(ref string) => string.hash(state),
             Self::Boolean(boolean) => boolean.hash(state),
-            Self::Integer(integer) => integer.hash(state),
+            Self::Integer(integer) => RationalHashable(f64::from(*integer)).hash(state),
             Self::BigInt(ref bigint) => bigint.hash(state),
             Self::Rational(rational) => RationalHashable(*rational).hash(state),
             Self::Symbol(ref symbol) => Hash::hash(symbol, state),
@@ -11,8 +11,8 @@ use crate::builtins::{
     property::{Attribute, Property, PropertyKey},
     BigInt, Symbol,
 };
-use crate::exec::Interpreter;
 use crate::BoaProfiler;
+use crate::{class::NativeData, exec::Interpreter};
 use gc::{Finalize, GcCellRef, GcCellRefMut, Trace};
 use serde_json::{map::Map, Number as JSONNumber, Value as JSONValue};
 use std::{
@@ -531,8 +531,10 @@ impl Value {
     }
 
     /// Resolve the property in the object and get its value, or undefined if this is not an object or the field doesn't exist
-    /// get_field recieves a Property from get_prop(). It should then return the [[Get]] result value if that's set, otherwise fall back to [[Value]]
-    /// TODO: this function should use the get Value if its set
+    ///
+    /// This only reads the [[Value]] of data properties: getters aren't called and proxy traps
+    /// aren't run, so accessor properties read as undefined. Use `Interpreter::get_field` to get
+    /// properties the way JavaScript code does.
     pub fn get_field<F>(&self, field: F) -> Self
     where
         F: Into<Value>,
@@ -540,34 +542,32 @@ impl Value {
         let _timer = BoaProfiler::global().start_event("Value::get_field", "value");
         match field.into() {
             // Our field will either be a String or a Symbol
-            Self::String(ref s) => {
-                match self.get_property(s) {
-                    Some(prop) => {
-                        // If the Property has [[Get]] set to a function, we should run that and return the Value
-                        let prop_getter = match prop.get {
-                            Some(_) => None,
-                            None => None,
-                        };
-
-                        // If the getter is populated, use that. If not use [[Value]] instead
-                        if let Some(val) = prop_getter {
-                            val
-                        } else {
-                            let val = prop
-                                .value
-                                .as_ref()
-                                .expect("Could not get property as reference");
-                            val.clone()
-                        }
+            Self::String(ref s) => match self.get_property(s) {
+                Some(prop) => prop.value.clone().unwrap_or_else(Value::undefined),
+                None => Value::undefined(),
+            },
+            Self::Symbol(ref symbol) => match self {
+                Self::Object(ref object) => {
+                    let object = object.borrow();
+                    match object.symbol_properties().get(symbol) {
+                        Some(prop) => prop.value.clone().unwrap_or_else(Value::undefined),
+                        None => object.prototype().get_field(symbol.clone()),
                     }
-                    None => Value::undefined(),
                 }
-            }
-            Self::Symbol(_) => unimplemented!(),
+                _ => Value::undefined(),
+            },
             _ => Value::undefined(),
         }
     }
 
+    /// Gets the native data of an instance of a class defined in Rust, see `boa::class`.
+    pub fn native_data(&self) -> Option<NativeData> {
+        self.as_object().and_then(|object| match object.data {
+            ObjectData::NativeObject(ref data) => Some(data.clone()),
+            _ => None,
+        })
+    }
+
     /// Check whether an object has an internal state set.
     #[inline]
     pub fn has_internal_state(&self) -> bool {
@@ -448,6 +448,29 @@ fn display_negative_zero_object() {
     assert_eq!(value.to_string(), "Number { -0 }")
 }
 
+#[test]
+fn display_accessor_properties() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let d_obj = r#"
+        let o = { get a() { return 1; }, set b(v) {}, get c() { return 1; }, set c(v) {} };
+        Object.defineProperty(o, 'd', { get() {} });
+        o
+    "#;
+    let value = forward_val(&mut engine, d_obj).unwrap().to_string();
+    assert!(value.contains("a: [Getter]"));
+    assert!(value.contains("b: [Setter]"));
+    assert!(value.contains("c: [Getter/Setter]"));
+    assert!(value.contains("d: [Getter]"));
+
+    let value = forward_val(
+        &mut engine,
+        "let arr = [1]; Object.defineProperty(arr, '0', { get() { return 2; } }); arr",
+    )
+    .unwrap();
+    assert_eq!(value.to_string(), "[ [Getter] ]");
+}
+
 #[test]
 #[ignore] // TODO: Once objects are printed in a simpler way this test can be simplified and used
 fn display_object() {
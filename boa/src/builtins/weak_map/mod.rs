@@ -0,0 +1,240 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! A `WeakMap` associates values with objects without keeping the objects alive: once a key is
+//! collected, its entry is removed from the map. A value is kept alive by the map only while its
+//! key is reachable, so a value that refers to its own key doesn't keep the entry alive.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+use super::function::{make_builtin_fn, make_constructor_fn};
+use crate::{
+    builtins::{
+        iterable::get_iterator,
+        object::{GcObject, ObjectData},
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use weak_object_map::WeakObjectMap;
+
+#[cfg(test)]
+mod tests;
+pub mod weak_object_map;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl WeakMap {
+    pub(crate) const NAME: &'static str = "WeakMap";
+
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Helper function to access the entries of `this`, throwing a `TypeError` if it isn't a weak
+    /// map.
+    fn with_map<R, F>(this: &Value, interpreter: &mut Interpreter, f: F) -> Result<R, Value>
+    where
+        F: FnOnce(&mut WeakObjectMap) -> R,
+    {
+        if let Some(mut object) = this.as_object_mut() {
+            if let Some(map) = object.as_weak_map_mut() {
+                return Ok(f(map));
+            }
+        }
+        Err(interpreter.construct_type_error("'this' is not a WeakMap"))
+    }
+
+    /// `WeakMap.prototype.delete( key )`
+    ///
+    /// This method removes the entry of the key, if there is one. Returns true if there was an
+    /// entry, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let deleted = match args.get(0) {
+            Some(Value::Object(key)) => {
+                let id = Self::with_map(this, ctx, |map| {
+                    map.keys_mut().remove(key);
+                    map.id()
+                })?;
+                key.borrow_mut().ephemerons_mut().remove(id)
+            }
+            _ => Self::with_map(this, ctx, |_| false)?,
+        };
+        Ok(deleted.into())
+    }
+
+    /// `WeakMap.prototype.get( key )`
+    ///
+    /// This method returns the value associated with the key, or undefined if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        match args.get(0) {
+            Some(Value::Object(key)) => {
+                let id = Self::with_map(this, ctx, |map| map.id())?;
+                Ok(key
+                    .borrow()
+                    .ephemerons()
+                    .get(id)
+                    .cloned()
+                    .unwrap_or_default())
+            }
+            _ => Self::with_map(this, ctx, |_| Value::undefined()),
+        }
+    }
+
+    /// `WeakMap.prototype.has( key )`
+    ///
+    /// This method checks if there is a value associated with the key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let has = match args.get(0) {
+            Some(Value::Object(key)) => {
+                let id = Self::with_map(this, ctx, |map| map.id())?;
+                key.borrow().ephemerons().contains(id)
+            }
+            _ => Self::with_map(this, ctx, |_| false)?,
+        };
+        Ok(has.into())
+    }
+
+    /// `WeakMap.prototype.set( key, value )`
+    ///
+    /// This method associates the value with the key, which must be an object. Returns the weak
+    /// map object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::with_map(this, ctx, |_| ())?;
+        let key = match args.get(0) {
+            Some(Value::Object(key)) => key,
+            _ => return ctx.throw_type_error("invalid value used as weak map key"),
+        };
+        let value = args.get(1).cloned().unwrap_or_default();
+
+        // The value is stored on the key, so that it is only reachable through it. The key may be
+        // the weak map itself, so the map is not borrowed meanwhile.
+        let weak_key = GcObject::downgrade(key);
+        let id = Self::with_map(this, ctx, |map| {
+            map.keys_mut().insert(weak_key);
+            map.id()
+        })?;
+        key.borrow_mut().ephemerons_mut().insert(id, value);
+        Ok(this.clone())
+    }
+
+    /// Helper function to add an entry of the iterable passed to the constructor, with the
+    /// `set()` method of the new weak map.
+    fn add_entry(this: &Value, adder: &Value, entry: &Value, ctx: &mut Interpreter) -> ResultValue {
+        if !entry.is_object() {
+            return ctx.throw_type_error("iterator value is not an entry object");
+        }
+        let key = ctx.get_field(entry, "0")?;
+        let value = ctx.get_field(entry, "1")?;
+        ctx.call(adder, this, &[key, value])
+    }
+
+    /// Create a new weak map
+    ///
+    /// The entries of the iterable argument are added with the `set()` method of the new weak
+    /// map.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    pub(crate) fn make_weak_map(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        this.set_data(ObjectData::WeakMap(WeakObjectMap::new()));
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(this.clone());
+        }
+
+        let adder = ctx.get_field(this, "set")?;
+        if !adder.is_function() {
+            return ctx.throw_type_error("'set' of the new WeakMap is not a function");
+        }
+
+        let iterator = get_iterator(ctx, &iterable)?;
+        loop {
+            let next = iterator.next(ctx)?;
+            if next.is_done() {
+                return Ok(this.clone());
+            }
+            if let Err(error) = Self::add_entry(this, &adder, next.value(), ctx) {
+                return iterator.close(Err(error), ctx);
+            }
+        }
+    }
+
+    /// Initialise the `WeakMap` object on the global object.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let prototype = Value::new_object(Some(global));
+
+        make_builtin_fn(Self::delete, "delete", &prototype, 1, interpreter);
+        make_builtin_fn(Self::get, "get", &prototype, 1, interpreter);
+        make_builtin_fn(Self::has, "has", &prototype, 1, interpreter);
+        make_builtin_fn(Self::set, "set", &prototype, 2, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from(Self::NAME),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        let weak_map_object = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_weak_map,
+            global,
+            prototype,
+            true,
+            false,
+        );
+
+        (Self::NAME, weak_map_object)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn set_get_has_delete() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let key = {};
+        let other = {};
+        let map = new WeakMap();
+        let returned = map.set(key, "value");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "returned === map"), "true");
+    assert_eq!(forward(&mut engine, "map.get(key)"), "\"value\"");
+    assert_eq!(forward(&mut engine, "map.get(other)"), "undefined");
+    assert_eq!(forward(&mut engine, "map.get(1)"), "undefined");
+    assert_eq!(forward(&mut engine, "map.has(key)"), "true");
+    assert_eq!(forward(&mut engine, "map.has(other)"), "false");
+    forward(&mut engine, "map.set(key, 'replaced')");
+    assert_eq!(forward(&mut engine, "map.get(key)"), "\"replaced\"");
+    assert_eq!(forward(&mut engine, "map.delete(key)"), "true");
+    assert_eq!(forward(&mut engine, "map.delete(key)"), "false");
+    assert_eq!(forward(&mut engine, "map.has(key)"), "false");
+    forward(&mut engine, "map.set(map, 'itself')");
+    assert_eq!(forward(&mut engine, "map.get(map)"), "\"itself\"");
+}
+
+#[test]
+fn construct_from_iterable() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let a = {};
+        let b = {};
+        let map = new WeakMap([[a, 1], [b, 2]]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "map.get(a) + map.get(b)"), "3");
+    assert_eq!(
+        forward(&mut engine, "map[Symbol.toStringTag]"),
+        "\"WeakMap\""
+    );
+}
+
+#[test]
+fn invalid_keys() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        function message(code) {
+            try {
+                code();
+            } catch (e) {
+                return e.toString();
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(function() { new WeakMap().set('key', 1); })"
+        ),
+        "\"TypeError: invalid value used as weak map key\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(function() { new WeakMap([[1, 2]]); })"
+        ),
+        "\"TypeError: invalid value used as weak map key\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "message(function() { ({ get: WeakMap.prototype.get }).get({}); })"
+        ),
+        "\"TypeError: 'this' is not a WeakMap\""
+    );
+}
+
+#[test]
+fn releases_collected_entries() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let map = new WeakMap();
+        let key = {};
+        let kept = {};
+        map.set(key, { metadata: true });
+        map.set(kept, "kept");
+        let value = new WeakRef(map.get(key));
+        key = undefined;
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    gc::force_collect();
+
+    assert_eq!(forward(&mut engine, "value.deref()"), "undefined");
+    assert_eq!(forward(&mut engine, "map.get(kept)"), "\"kept\"");
+}
+
+#[test]
+fn values_referring_to_their_keys() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let map = new WeakMap();
+        let key = {};
+        map.set(key, { key: key });
+        let collected = new WeakRef(key);
+        key = undefined;
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    gc::force_collect();
+
+    // A value is only reachable through its key, so it doesn't keep the key alive.
+    assert_eq!(forward(&mut engine, "collected.deref()"), "undefined");
+}
+
+#[test]
+fn releases_values_of_collected_maps() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let map = new WeakMap();
+        let key = {};
+        map.set(key, { metadata: true });
+        let value = new WeakRef(map.get(key));
+        map = undefined;
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    gc::force_collect();
+    gc::force_collect();
+
+    assert_eq!(forward(&mut engine, "value.deref()"), "undefined");
+    assert_eq!(forward(&mut engine, "typeof key"), "\"object\"");
+}
@@ -0,0 +1,186 @@
+use crate::builtins::{
+    object::{gcobject::collected_objects, GcObject, WeakGcObject},
+    value::Value,
+};
+use gc::{Finalize, Trace};
+use rustc_hash::FxHashMap;
+use std::cell::Cell;
+
+thread_local! {
+    /// The identifier of the next weak map created on this thread.
+    static NEXT_MAP_ID: Cell<usize> = Cell::new(0);
+}
+
+/// A set of objects, which doesn't keep them alive.
+///
+/// Objects are compared by identity. The objects that have been collected are removed the next
+/// time the set is used after a garbage collection.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct WeakObjectSet {
+    /// The objects, by their address.
+    objects: FxHashMap<usize, WeakGcObject>,
+    /// The number of collected objects the last time the set removed the collected objects.
+    collected_objects: usize,
+}
+
+impl WeakObjectSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self {
+            objects: FxHashMap::default(),
+            collected_objects: collected_objects(),
+        }
+    }
+
+    /// Removes the objects that have been collected, if a garbage collection collected objects
+    /// with weak references to them since the last time.
+    fn remove_collected(&mut self) {
+        let collected = collected_objects();
+        if collected != self.collected_objects {
+            self.objects.retain(|_, object| object.is_alive());
+            self.collected_objects = collected;
+        }
+    }
+
+    /// Checks if the object is in the set.
+    pub fn contains(&mut self, object: &GcObject) -> bool {
+        self.remove_collected();
+        self.objects.contains_key(&object.address())
+    }
+
+    /// Adds the object to the set.
+    pub fn insert(&mut self, object: WeakGcObject) {
+        self.remove_collected();
+        self.objects.insert(object.address(), object);
+    }
+
+    /// Removes the object from the set, and returns whether it was in the set.
+    pub fn remove(&mut self, object: &GcObject) -> bool {
+        self.remove_collected();
+        self.objects.remove(&object.address()).is_some()
+    }
+
+    /// Returns the number of objects in the set that are alive.
+    pub fn len(&mut self) -> usize {
+        self.remove_collected();
+        self.objects.len()
+    }
+
+    /// Returns true if there are no objects in the set that are alive.
+    pub fn is_empty(&mut self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for WeakObjectSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A map from objects to values, which doesn't keep its keys alive, and keeps its values alive
+/// only while their keys are.
+///
+/// The map only holds its keys, weakly: the values are stored on the keys themselves, in their
+/// [`Ephemerons`](struct.Ephemerons.html), under the identifier of the map. So a value is traced
+/// only through its key, and a value that refers to its own key doesn't keep it alive.
+///
+/// When the map is finalized, it removes its values from the keys that are still alive, unless
+/// they are borrowed at the time, in which case the values are released along with the keys.
+///
+/// A clone of the map is a different, empty map.
+#[derive(Debug, Trace)]
+pub struct WeakObjectMap {
+    /// The identifier of the map in the ephemerons of its keys.
+    id: usize,
+    /// The keys of the map.
+    keys: WeakObjectSet,
+}
+
+impl WeakObjectMap {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        let id = NEXT_MAP_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            id
+        });
+        Self {
+            id,
+            keys: WeakObjectSet::new(),
+        }
+    }
+
+    /// Gets the identifier of the map, under which its values are stored on their keys.
+    #[inline]
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Gets the keys of the map.
+    #[inline]
+    pub fn keys_mut(&mut self) -> &mut WeakObjectSet {
+        &mut self.keys
+    }
+}
+
+impl Default for WeakObjectMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for WeakObjectMap {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl Finalize for WeakObjectMap {
+    fn finalize(&self) {
+        for key in self.keys.objects.values() {
+            if let Some(key) = key.upgrade() {
+                if let Ok(mut key) = key.try_borrow_mut() {
+                    key.ephemerons_mut().remove(self.id);
+                }
+            }
+        }
+    }
+}
+
+/// The values that weak maps associate with an object, by the identifier of the map.
+///
+/// They are traced along with the object, so they are alive for as long as the object is. A clone
+/// of the object is a different key, without values.
+#[derive(Debug, Default, Trace, Finalize)]
+pub struct Ephemerons(FxHashMap<usize, Value>);
+
+impl Ephemerons {
+    /// Gets the value that the map associates with the object, if there is one.
+    pub fn get(&self, map: usize) -> Option<&Value> {
+        self.0.get(&map)
+    }
+
+    /// Checks if the map associates a value with the object.
+    pub fn contains(&self, map: usize) -> bool {
+        self.0.contains_key(&map)
+    }
+
+    /// Associates the value with the object in the map, replacing the previous value if there
+    /// was one.
+    pub fn insert(&mut self, map: usize, value: Value) {
+        self.0.insert(map, value);
+    }
+
+    /// Removes the value that the map associates with the object, and returns whether there was
+    /// one.
+    pub fn remove(&mut self, map: usize) -> bool {
+        self.0.remove(&map).is_some()
+    }
+}
+
+impl Clone for Ephemerons {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
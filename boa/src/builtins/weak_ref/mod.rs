@@ -0,0 +1,120 @@
+//! This module implements the global `WeakRef` object.
+//!
+//! A `WeakRef` holds a reference to an object that doesn't keep it alive. The object stays alive
+//! at least until the end of the job that created the `WeakRef` or last dereferenced it.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weak-ref-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef
+
+use super::function::{make_builtin_fn, make_constructor_fn};
+use crate::{
+    builtins::{
+        object::{GcObject, ObjectData},
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakRef;
+
+impl WeakRef {
+    pub(crate) const NAME: &'static str = "WeakRef";
+
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `WeakRef.prototype.deref( )`
+    ///
+    /// This method returns the target of the weak reference, or undefined if it has been
+    /// collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref.prototype.deref
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/deref
+    pub(crate) fn deref(this: &Value, _: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let target = match this.as_object() {
+            Some(object) => match object.data {
+                ObjectData::WeakRef(ref target) => target.upgrade(),
+                _ => return ctx.throw_type_error("'this' is not a WeakRef"),
+            },
+            None => return ctx.throw_type_error("'this' is not a WeakRef"),
+        };
+
+        Ok(match target {
+            Some(target) => {
+                ctx.add_to_kept_objects(target.clone());
+                Value::Object(target)
+            }
+            None => Value::undefined(),
+        })
+    }
+
+    /// Create a new weak reference
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref-target
+    pub(crate) fn make_weak_ref(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        let target = match args.get(0) {
+            Some(Value::Object(target)) => target.clone(),
+            _ => return ctx.throw_type_error("WeakRef: target must be an object"),
+        };
+
+        this.set_data(ObjectData::WeakRef(GcObject::downgrade(&target)));
+        ctx.add_to_kept_objects(target);
+
+        Ok(this.clone())
+    }
+
+    /// Initialise the `WeakRef` object on the global object.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let prototype = Value::new_object(Some(global));
+
+        make_builtin_fn(Self::deref, "deref", &prototype, 0, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from(Self::NAME),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        let weak_ref_object = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_weak_ref,
+            global,
+            prototype,
+            true,
+            false,
+        );
+
+        (Self::NAME, weak_ref_object)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn deref() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let target = {};
+        let weak = new WeakRef(target);
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "weak.deref() === target"), "true");
+    assert_eq!(
+        forward(&mut engine, "weak[Symbol.toStringTag]"),
+        "\"WeakRef\""
+    );
+
+    forward(&mut engine, "target = undefined");
+    engine.run_jobs().unwrap();
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "weak.deref()"), "undefined");
+}
+
+#[test]
+fn kept_alive_until_jobs_run() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    forward(&mut engine, "let weak = new WeakRef({ answer: 42 })");
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "weak.deref().answer"), "42");
+
+    engine.run_jobs().unwrap();
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "weak.deref()"), "undefined");
+}
+
+#[test]
+fn invalid_target() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        try {
+            new WeakRef(1);
+        } catch (e) {
+            e.toString();
+        }
+        "#;
+    assert_eq!(
+        forward(&mut engine, init),
+        "\"TypeError: WeakRef: target must be an object\""
+    );
+}
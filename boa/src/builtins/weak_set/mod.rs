@@ -0,0 +1,182 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! A `WeakSet` is a collection of objects that doesn't keep them alive: once an object is
+//! collected, it is removed from the set.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+
+use super::function::{make_builtin_fn, make_constructor_fn};
+use crate::{
+    builtins::{
+        iterable::get_iterator,
+        object::{GcObject, ObjectData},
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+        weak_map::weak_object_map::WeakObjectSet,
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl WeakSet {
+    pub(crate) const NAME: &'static str = "WeakSet";
+
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Helper function to access the objects of `this`, throwing a `TypeError` if it isn't a weak
+    /// set.
+    fn with_set<R, F>(this: &Value, interpreter: &mut Interpreter, f: F) -> Result<R, Value>
+    where
+        F: FnOnce(&mut WeakObjectSet) -> R,
+    {
+        if let Some(mut object) = this.as_object_mut() {
+            if let Some(set) = object.as_weak_set_mut() {
+                return Ok(f(set));
+            }
+        }
+        Err(interpreter.construct_type_error("'this' is not a WeakSet"))
+    }
+
+    /// `WeakSet.prototype.add( value )`
+    ///
+    /// This method adds the value, which must be an object, to the weak set. Returns the weak set
+    /// object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        Self::with_set(this, ctx, |_| ())?;
+        let value = match args.get(0) {
+            Some(Value::Object(value)) => GcObject::downgrade(value),
+            _ => return ctx.throw_type_error("invalid value used in weak set"),
+        };
+        Self::with_set(this, ctx, |set| set.insert(value))?;
+        Ok(this.clone())
+    }
+
+    /// `WeakSet.prototype.delete( value )`
+    ///
+    /// This method removes the value from the weak set. Returns true if it was in the weak set,
+    /// false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let deleted = match args.get(0) {
+            Some(Value::Object(value)) => Self::with_set(this, ctx, |set| set.remove(value))?,
+            _ => Self::with_set(this, ctx, |_| false)?,
+        };
+        Ok(deleted.into())
+    }
+
+    /// `WeakSet.prototype.has( value )`
+    ///
+    /// This method checks if the value is in the weak set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(this: &Value, args: &[Value], ctx: &mut Interpreter) -> ResultValue {
+        let has = match args.get(0) {
+            Some(Value::Object(value)) => Self::with_set(this, ctx, |set| set.contains(value))?,
+            _ => Self::with_set(this, ctx, |_| false)?,
+        };
+        Ok(has.into())
+    }
+
+    /// Create a new weak set
+    ///
+    /// The values of the iterable argument are added with the `add()` method of the new weak set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset-iterable
+    pub(crate) fn make_weak_set(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Interpreter,
+    ) -> ResultValue {
+        this.set_data(ObjectData::WeakSet(WeakObjectSet::new()));
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(this.clone());
+        }
+
+        let adder = ctx.get_field(this, "add")?;
+        if !adder.is_function() {
+            return ctx.throw_type_error("'add' of the new WeakSet is not a function");
+        }
+
+        let iterator = get_iterator(ctx, &iterable)?;
+        loop {
+            let next = iterator.next(ctx)?;
+            if next.is_done() {
+                return Ok(this.clone());
+            }
+            if let Err(error) = ctx.call(&adder, this, &[next.into_value()]) {
+                return iterator.close(Err(error), ctx);
+            }
+        }
+    }
+
+    /// Initialise the `WeakSet` object on the global object.
+    pub(crate) fn init(interpreter: &mut Interpreter) -> (&'static str, Value) {
+        let global = interpreter.global();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let prototype = Value::new_object(Some(global));
+
+        make_builtin_fn(Self::add, "add", &prototype, 1, interpreter);
+        make_builtin_fn(Self::delete, "delete", &prototype, 1, interpreter);
+        make_builtin_fn(Self::has, "has", &prototype, 1, interpreter);
+
+        let to_string_tag = interpreter.well_known_symbols().to_string_tag_symbol();
+        prototype
+            .as_object_mut()
+            .expect("prototype is an object")
+            .insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from(Self::NAME),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+        let weak_set_object = make_constructor_fn(
+            Self::NAME,
+            Self::LENGTH,
+            Self::make_weak_set,
+            global,
+            prototype,
+            true,
+            false,
+        );
+
+        (Self::NAME, weak_set_object)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn add_has_delete() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let a = {};
+        let b = {};
+        let set = new WeakSet([a]);
+        let returned = set.add(b);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "returned === set"), "true");
+    assert_eq!(forward(&mut engine, "set.has(a)"), "true");
+    assert_eq!(forward(&mut engine, "set.has(b)"), "true");
+    assert_eq!(forward(&mut engine, "set.has({})"), "false");
+    assert_eq!(forward(&mut engine, "set.has(1)"), "false");
+    assert_eq!(forward(&mut engine, "set.delete(a)"), "true");
+    assert_eq!(forward(&mut engine, "set.delete(a)"), "false");
+    assert_eq!(forward(&mut engine, "set.has(a)"), "false");
+    assert_eq!(
+        forward(&mut engine, "set[Symbol.toStringTag]"),
+        "\"WeakSet\""
+    );
+}
+
+#[test]
+fn invalid_values() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        try {
+            new WeakSet().add("value");
+        } catch (e) {
+            e.toString();
+        }
+        "#;
+    assert_eq!(
+        forward(&mut engine, init),
+        "\"TypeError: invalid value used in weak set\""
+    );
+}
+
+#[test]
+fn does_not_keep_values_alive() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let value = {};
+        let set = new WeakSet([value]);
+        let weak = new WeakRef(value);
+        value = undefined;
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "weak.deref()"), "undefined");
+}
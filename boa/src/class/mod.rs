@@ -0,0 +1,384 @@
+//! Classes defined in Rust, whose instances hold native data.
+//!
+//! A Rust type implementing the [`Class`][Class] trait can be registered as a global class with
+//! [`Interpreter::register_class`][Interpreter::register_class]. Every instance of the class
+//! holds a value of this type, which is traced by the garbage collector, so it can hold
+//! JavaScript values. The methods and accessors of the class get typed access to the data of
+//! their `this` value, and throw a `TypeError` if it isn't an instance of the class.
+//!
+//! ```
+//! # use boa::{builtins::value::Value, class::{Class, ClassBuilder}, exec::Interpreter};
+//! # use gc::{Finalize, Trace};
+//! #[derive(Debug, Trace, Finalize)]
+//! struct Counter {
+//!     count: i32,
+//! }
+//!
+//! impl Class for Counter {
+//!     const NAME: &'static str = "Counter";
+//!
+//!     fn constructor(_: &[Value], _: &mut Interpreter) -> Result<Self, Value> {
+//!         Ok(Self { count: 0 })
+//!     }
+//!
+//!     fn init(class: &mut ClassBuilder<'_, Self>) {
+//!         class.method("increment", 0, |counter, _, _| {
+//!             counter.count += 1;
+//!             Ok(Value::from(counter.count))
+//!         });
+//!     }
+//! }
+//! ```
+
+use crate::{
+    builtins::{
+        function::{make_closure_fn, make_constructor_fn, Function},
+        object::{Object, ObjectData, PROTOTYPE},
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+    },
+    exec::Interpreter,
+    BoaProfiler,
+};
+use gc::{custom_trace, Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace};
+use std::{
+    any::{Any, TypeId},
+    fmt::Debug,
+    marker::PhantomData,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Data of a native type, that can be held by an object.
+///
+/// It is implemented for every type that implements `Trace` and `Debug`.
+pub trait NativeObject: Any + Debug + Trace {
+    /// Converts the data to `Any`, so it can be downcast to its type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Converts the data to `Any`, so it can be downcast to its type.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any + Debug + Trace> NativeObject for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// A Rust type that can be used as a JavaScript class.
+pub trait Class: NativeObject + Sized {
+    /// The name of the class, which is the name of its constructor.
+    const NAME: &'static str;
+
+    /// The number of arguments expected by the constructor.
+    const LENGTH: usize = 0;
+
+    /// Creates the data of a new instance of the class, from the arguments given to its
+    /// constructor.
+    fn constructor(args: &[Value], interpreter: &mut Interpreter) -> Result<Self, Value>;
+
+    /// Defines the methods, accessors and static methods of the class.
+    fn init(class: &mut ClassBuilder<'_, Self>);
+}
+
+/// The native data held by an instance of a class defined in Rust.
+///
+/// The data is shared by the clones of this handle, and is borrowed while a method of the class
+/// is running.
+#[derive(Debug, Clone, Finalize)]
+pub struct NativeData {
+    type_id: TypeId,
+    data: Gc<GcCell<Box<dyn NativeObject>>>,
+}
+
+/// `Trace` implementation for `NativeData`.
+///
+/// Only the data is traced, since the `TypeId` doesn't hold any GC'd objects.
+unsafe impl Trace for NativeData {
+    custom_trace!(this, {
+        mark(&this.data);
+    });
+}
+
+impl NativeData {
+    /// Creates a handle to the given data.
+    pub fn new<T: NativeObject>(data: T) -> Self {
+        let data: Box<dyn NativeObject> = Box::new(data);
+        Self {
+            type_id: TypeId::of::<T>(),
+            data: Gc::new(GcCell::new(data)),
+        }
+    }
+
+    /// Checks if the data is of type `T`.
+    pub fn is<T: NativeObject>(&self) -> bool {
+        self.type_id == TypeId::of::<T>()
+    }
+
+    /// Borrows the data as a `T`.
+    ///
+    /// Returns `None` if the data is not of type `T`, or if it is already mutably borrowed.
+    pub fn downcast_ref<T: NativeObject>(&self) -> Option<GcCellRef<'_, T>> {
+        if !self.is::<T>() {
+            return None;
+        }
+        let data = self.data.try_borrow().ok()?;
+        Some(GcCellRef::map(data, |data| {
+            (**data).as_any().downcast_ref().expect("checked to be a T")
+        }))
+    }
+
+    /// Mutably borrows the data as a `T`.
+    ///
+    /// Returns `None` if the data is not of type `T`, or if it is already borrowed.
+    pub fn downcast_mut<T: NativeObject>(&self) -> Option<GcCellRefMut<'_, T>> {
+        if !self.is::<T>() {
+            return None;
+        }
+        let data = self.data.try_borrow_mut().ok()?;
+        Some(GcCellRefMut::map(data, |data| {
+            (**data)
+                .as_any_mut()
+                .downcast_mut()
+                .expect("checked to be a T")
+        }))
+    }
+}
+
+/// Defines the members of a class, see `Class::init`.
+#[derive(Debug)]
+pub struct ClassBuilder<'i, T> {
+    interpreter: &'i mut Interpreter,
+    constructor: Value,
+    prototype: Value,
+    class: PhantomData<T>,
+}
+
+impl<T: Class> ClassBuilder<'_, T> {
+    /// Adds a method to the prototype of the class, which gets the data of `this`.
+    pub fn method<F>(&mut self, name: &str, length: usize, method: F) -> &mut Self
+    where
+        F: Fn(&mut T, &[Value], &mut Interpreter) -> ResultValue + 'static,
+    {
+        let qualified_name = format!("{}.prototype.{}", T::NAME, name);
+        make_closure_fn(
+            move |this, args, _: &(), interpreter| {
+                let data = this_data::<T>(this, &qualified_name, interpreter)?;
+                let mut data = borrow_mut(&data, &qualified_name, interpreter)?;
+                method(&mut data, args, interpreter)
+            },
+            (),
+            name,
+            &self.prototype,
+            length,
+            self.interpreter,
+        );
+        self
+    }
+
+    /// Adds a method to the constructor of the class.
+    pub fn static_method<F>(&mut self, name: &str, length: usize, method: F) -> &mut Self
+    where
+        F: Fn(&Value, &[Value], &mut Interpreter) -> ResultValue + 'static,
+    {
+        make_closure_fn(
+            move |this, args, _: &(), interpreter| method(this, args, interpreter),
+            (),
+            name,
+            &self.constructor,
+            length,
+            self.interpreter,
+        );
+        self
+    }
+
+    /// Adds a property to the constructor of the class.
+    pub fn static_property<V>(&mut self, name: &str, value: V, attribute: Attribute) -> &mut Self
+    where
+        V: Into<Value>,
+    {
+        self.constructor
+            .as_object_mut()
+            .expect("constructors are objects")
+            .insert_property(name, Property::data_descriptor(value.into(), attribute));
+        self
+    }
+
+    /// Adds a read-only accessor property to the prototype of the class, whose getter gets the
+    /// data of `this`.
+    pub fn getter<G>(&mut self, name: &str, getter: G) -> &mut Self
+    where
+        G: Fn(&T, &mut Interpreter) -> ResultValue + 'static,
+    {
+        let getter = self.getter_function(name, getter);
+        self.accessor_property(name, Property::default().get(getter))
+    }
+
+    /// Adds an accessor property to the prototype of the class, whose getter and setter get the
+    /// data of `this`.
+    pub fn accessor<G, S>(&mut self, name: &str, getter: G, setter: S) -> &mut Self
+    where
+        G: Fn(&T, &mut Interpreter) -> ResultValue + 'static,
+        S: Fn(&mut T, Value, &mut Interpreter) -> Result<(), Value> + 'static,
+    {
+        let getter = self.getter_function(name, getter);
+        let qualified_name = format!("set {}.prototype.{}", T::NAME, name);
+        let setter = self.function(
+            move |this, args, _: &(), interpreter| {
+                let data = this_data::<T>(this, &qualified_name, interpreter)?;
+                let mut data = borrow_mut(&data, &qualified_name, interpreter)?;
+                let value = args.first().cloned().unwrap_or_default();
+                setter(&mut data, value, interpreter)?;
+                Ok(Value::undefined())
+            },
+            1,
+        );
+        self.accessor_property(name, Property::default().get(getter).set(setter))
+    }
+
+    /// Gets the interpreter the class is defined in.
+    pub fn interpreter(&mut self) -> &mut Interpreter {
+        self.interpreter
+    }
+
+    /// Creates the getter function of an accessor property.
+    fn getter_function<G>(&mut self, name: &str, getter: G) -> Value
+    where
+        G: Fn(&T, &mut Interpreter) -> ResultValue + 'static,
+    {
+        let qualified_name = format!("get {}.prototype.{}", T::NAME, name);
+        self.function(
+            move |this, _, _: &(), interpreter| {
+                let data = this_data::<T>(this, &qualified_name, interpreter)?;
+                let data = data.downcast_ref::<T>().ok_or_else(|| {
+                    interpreter.construct_type_error(format!(
+                        "{} called while the object is in use",
+                        qualified_name
+                    ))
+                })?;
+                getter(&data, interpreter)
+            },
+            0,
+        )
+    }
+
+    /// Creates a function object that isn't the property of any object.
+    fn function<F>(&mut self, function: F, length: usize) -> Value
+    where
+        F: Fn(&Value, &[Value], &(), &mut Interpreter) -> ResultValue + 'static,
+    {
+        let function_prototype = self
+            .interpreter
+            .global()
+            .get_field("Function")
+            .get_field(PROTOTYPE);
+        let mut function = Object::function(Function::closure(function, ()), function_prototype);
+        function.insert_field("length", Value::from(length));
+        Value::from(function)
+    }
+
+    /// Adds an accessor property to the prototype, which is not enumerable like the accessors of
+    /// built-in classes.
+    fn accessor_property(&mut self, name: &str, mut property: Property) -> &mut Self {
+        property.attribute = Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        self.prototype
+            .as_object_mut()
+            .expect("prototypes are objects")
+            .insert_property(name, property);
+        self
+    }
+}
+
+/// Gets the native data of `this`, throwing a `TypeError` if it isn't an instance of the class
+/// `T`.
+fn this_data<T: Class>(
+    this: &Value,
+    method: &str,
+    interpreter: &mut Interpreter,
+) -> Result<NativeData, Value> {
+    this.native_data()
+        .filter(NativeData::is::<T>)
+        .ok_or_else(|| {
+            interpreter.construct_type_error(format!(
+                "{} called on an object that is not a {}",
+                method,
+                T::NAME
+            ))
+        })
+}
+
+/// Mutably borrows the native data of `this`, throwing a `TypeError` if a method is already
+/// using it.
+fn borrow_mut<'a, T: Class>(
+    data: &'a NativeData,
+    method: &str,
+    interpreter: &mut Interpreter,
+) -> Result<GcCellRefMut<'a, T>, Value> {
+    data.downcast_mut().ok_or_else(|| {
+        interpreter.construct_type_error(format!("{} called while the object is in use", method))
+    })
+}
+
+/// The body of the constructor of a class defined in Rust.
+fn constructor<T: Class>(
+    this: &Value,
+    args: &[Value],
+    interpreter: &mut Interpreter,
+) -> ResultValue {
+    let data = T::constructor(args, interpreter)?;
+    this.set_data(ObjectData::NativeObject(NativeData::new(data)));
+    Ok(this.clone())
+}
+
+impl Interpreter {
+    /// Defines the class `T` as a global, and returns its constructor.
+    pub fn register_class<T: Class>(&mut self) -> Value {
+        let _timer = BoaProfiler::global().start_event(T::NAME, "class");
+        let global = self.global().clone();
+        let prototype = Value::new_object(Some(&global));
+        let constructor = make_constructor_fn(
+            T::NAME,
+            T::LENGTH,
+            constructor::<T>,
+            &global,
+            prototype.clone(),
+            true,
+            false,
+        );
+
+        let mut class = ClassBuilder {
+            interpreter: self,
+            constructor: constructor.clone(),
+            prototype: prototype.clone(),
+            class: PhantomData,
+        };
+        T::init(&mut class);
+
+        self.class_prototypes.insert(TypeId::of::<T>(), prototype);
+        global
+            .as_object_mut()
+            .expect("the global is an object")
+            .insert_field(T::NAME, constructor.clone());
+        constructor
+    }
+
+    /// Creates an instance of the class `T` holding the given data, without calling its
+    /// constructor.
+    ///
+    /// A `TypeError` is thrown if the class wasn't registered with `register_class`.
+    pub fn create_instance<T: Class>(&mut self, data: T) -> ResultValue {
+        match self.class_prototypes.get(&TypeId::of::<T>()) {
+            Some(prototype) => Ok(Value::new_object_from_prototype(
+                prototype.clone(),
+                ObjectData::NativeObject(NativeData::new(data)),
+            )),
+            None => self.throw_type_error(format!("the class {} is not registered", T::NAME)),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use super::{Class, ClassBuilder};
+use crate::{
+    builtins::{property::Attribute, value::Value},
+    exec::Interpreter,
+    forward,
+    realm::Realm,
+};
+use gc::{Finalize, Trace};
+
+/// A file, that keeps the lines written to it.
+#[derive(Debug, Trace, Finalize)]
+struct File {
+    name: String,
+    lines: Vec<Value>,
+}
+
+impl Class for File {
+    const NAME: &'static str = "File";
+    const LENGTH: usize = 1;
+
+    fn constructor(args: &[Value], interpreter: &mut Interpreter) -> Result<Self, Value> {
+        let name = interpreter.to_string(&args.first().cloned().unwrap_or_default())?;
+        Ok(Self {
+            name: name.to_string(),
+            lines: Vec::new(),
+        })
+    }
+
+    fn init(class: &mut ClassBuilder<'_, Self>) {
+        class
+            .method("write", 1, |file, args, _| {
+                file.lines.extend(args.iter().cloned());
+                Ok(Value::from(file.lines.len()))
+            })
+            .method("line", 1, |file, args, interpreter| {
+                let index = interpreter.to_index(&args.first().cloned().unwrap_or_default())?;
+                Ok(file.lines.get(index).cloned().unwrap_or_default())
+            })
+            .method("each", 1, |file, args, interpreter| {
+                let callback = args.first().cloned().unwrap_or_default();
+                for line in &file.lines {
+                    interpreter.call(&callback, &Value::undefined(), std::slice::from_ref(line))?;
+                }
+                Ok(Value::undefined())
+            })
+            .getter("length", |file, _| Ok(Value::from(file.lines.len())))
+            .accessor(
+                "name",
+                |file, _| Ok(Value::from(file.name.as_str())),
+                |file, name, interpreter| {
+                    file.name = interpreter.to_string(&name)?.to_string();
+                    Ok(())
+                },
+            )
+            .static_method("open", 1, |_, args, interpreter| {
+                let name = interpreter.to_string(&args.first().cloned().unwrap_or_default())?;
+                interpreter.create_instance(File {
+                    name: format!("/tmp/{}", name),
+                    lines: Vec::new(),
+                })
+            })
+            .static_property("separator", "/", Attribute::READONLY);
+    }
+}
+
+fn interpreter() -> Interpreter {
+    let mut engine = Interpreter::new(Realm::create());
+    engine.register_class::<File>();
+    engine
+}
+
+#[test]
+fn class_members() {
+    let mut engine = interpreter();
+    forward(
+        &mut engine,
+        r#"
+        var file = new File("a.txt");
+        file.write("first", "second");
+        file.name = "b.txt";
+        "#,
+    );
+
+    assert_eq!(forward(&mut engine, "file.line(1)"), "\"second\"");
+    assert_eq!(forward(&mut engine, "file.length"), "2");
+    assert_eq!(forward(&mut engine, "file.name"), "\"b.txt\"");
+    assert_eq!(
+        forward(&mut engine, "File.open('c.txt').name"),
+        "\"/tmp/c.txt\""
+    );
+    assert_eq!(forward(&mut engine, "File.separator"), "\"/\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "[file instanceof File, File.open('c') instanceof File, File.length, File.name].join()"
+        ),
+        "\"true,true,1,File\""
+    );
+}
+
+#[test]
+fn class_data_is_traced() {
+    let mut engine = interpreter();
+    forward(
+        &mut engine,
+        r#"
+        var file = new File("a.txt");
+        (function () { file.write({ text: "kept" }); })();
+        "#,
+    );
+    gc::force_collect();
+
+    assert_eq!(forward(&mut engine, "file.line(0).text"), "\"kept\"");
+}
+
+#[test]
+fn class_subclasses() {
+    let mut engine = interpreter();
+    let scenario = r#"
+        class Log extends File {
+            constructor() { super("log.txt"); }
+            log(message) { return this.write("log: " + message); }
+        }
+        var log = new Log();
+        log.log("started");
+        [log instanceof File, log.name, log.line(0)].join();
+    "#;
+
+    assert_eq!(
+        forward(&mut engine, scenario),
+        "\"true,log.txt,log: started\""
+    );
+}
+
+#[test]
+fn class_wrong_receivers() {
+    let mut engine = interpreter();
+    let scenario = r#"
+        var file = new File("a.txt");
+        file.write("line");
+        var errors = [];
+        function check(f) {
+            try { f(); } catch (e) { errors.push(e instanceof TypeError); }
+        }
+        check(() => File("a.txt"));
+        check(() => ({ write: file.write }).write("line"));
+        check(() => Object.getOwnPropertyDescriptor(File.prototype, "length").get());
+        check(() => file.each(() => file.write("again")));
+        errors.join();
+    "#;
+
+    assert_eq!(forward(&mut engine, scenario), "\"true,true,true,true\"");
+}
+
+#[test]
+fn class_instances_from_rust() {
+    let mut engine = interpreter();
+    let file = engine
+        .create_instance(File {
+            name: "rust.txt".to_owned(),
+            lines: vec![Value::from("from rust")],
+        })
+        .unwrap();
+    let data = file.native_data().unwrap();
+    data.downcast_mut::<File>()
+        .unwrap()
+        .lines
+        .push(Value::from(2));
+    engine.global().set_field("file", file);
+
+    assert_eq!(forward(&mut engine, "file.name"), "\"rust.txt\"");
+    assert_eq!(forward(&mut engine, "file.line(1)"), "2");
+    assert!(data.downcast_ref::<String>().is_none());
+
+    let err = Interpreter::new(Realm::create())
+        .create_instance(File {
+            name: "unregistered".to_owned(),
+            lines: Vec::new(),
+        })
+        .unwrap_err();
+    assert_eq!(err.get_field("name").to_string(), "\"TypeError\"");
+}
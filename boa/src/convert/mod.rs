@@ -0,0 +1,566 @@
+//! Typed conversions between Rust values and JavaScript values.
+//!
+//! The [`TryFromJs`][TryFromJs] trait converts a JavaScript value to a Rust type, throwing a
+//! `TypeError` if the value doesn't have the expected type, or a `RangeError` if a number doesn't
+//! fit in an integer type. The [`IntoJs`][IntoJs] trait converts a Rust value to a JavaScript
+//! value. Both traits can be derived for plain structs and enums: structs with named fields are
+//! converted from and to objects, tuple structs from and to arrays, unit variants of enums from
+//! and to their name, and the other variants to an object with a single property named after the
+//! variant.
+//!
+//! The arguments of a native function can be decoded all at once as a tuple, with
+//! [`Interpreter::decode_args`][Interpreter::decode_args]. Missing arguments are `undefined`, so
+//! optional arguments can be decoded as an `Option`.
+//!
+//! ```
+//! # use boa::{builtins::value::{ResultValue, Value}, convert::{IntoJs, TryFromJs}, exec::Interpreter};
+//! #[derive(TryFromJs, IntoJs)]
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! fn scale(_: &Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+//!     let (point, factor): (Point, Option<f64>) = interpreter.decode_args(args)?;
+//!     let factor = factor.unwrap_or(2.0);
+//!     let point = Point { x: point.x * factor, y: point.y * factor };
+//!     Ok(point.into_js(interpreter))
+//! }
+//! ```
+
+use crate::{
+    builtins::{
+        property::PropertyKey,
+        value::{RcString, Value},
+    },
+    exec::Interpreter,
+};
+use std::{collections::HashMap, convert::TryFrom, hash::BuildHasher};
+
+pub use boa_derive::{IntoJs, TryFromJs};
+
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(test)]
+mod tests;
+
+/// A Rust type that can be converted from a JavaScript value.
+pub trait TryFromJs: Sized {
+    /// Converts the JavaScript value, or throws an error if it doesn't have the expected type.
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value>;
+}
+
+/// A Rust type that can be converted to a JavaScript value.
+pub trait IntoJs {
+    /// Converts the value to a JavaScript value.
+    fn into_js(self, interpreter: &mut Interpreter) -> Value;
+}
+
+/// A tuple of Rust types that the arguments of a function can be converted to.
+///
+/// Missing arguments are converted from `undefined`, and extra arguments are ignored.
+pub trait TryFromJsArgs: Sized {
+    /// Converts the arguments, or throws an error if one of them doesn't have the expected type.
+    fn try_from_js_args(args: &[Value], interpreter: &mut Interpreter) -> Result<Self, Value>;
+}
+
+impl Interpreter {
+    /// Converts a JavaScript value to the Rust type `T`.
+    pub fn decode<T>(&mut self, value: &Value) -> Result<T, Value>
+    where
+        T: TryFromJs,
+    {
+        T::try_from_js(value, self)
+    }
+
+    /// Converts the arguments of a function to the tuple of Rust types `T`.
+    pub fn decode_args<T>(&mut self, args: &[Value]) -> Result<T, Value>
+    where
+        T: TryFromJsArgs,
+    {
+        T::try_from_js_args(args, self)
+    }
+}
+
+impl TryFromJs for Value {
+    fn try_from_js(value: &Value, _: &mut Interpreter) -> Result<Self, Value> {
+        Ok(value.clone())
+    }
+}
+
+impl IntoJs for Value {
+    fn into_js(self, _: &mut Interpreter) -> Value {
+        self
+    }
+}
+
+impl IntoJs for () {
+    fn into_js(self, _: &mut Interpreter) -> Value {
+        Value::undefined()
+    }
+}
+
+impl TryFromJs for bool {
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        match *value {
+            Value::Boolean(boolean) => Ok(boolean),
+            _ => Err(__private::unexpected(value, "a boolean", interpreter)),
+        }
+    }
+}
+
+impl IntoJs for bool {
+    fn into_js(self, _: &mut Interpreter) -> Value {
+        Value::boolean(self)
+    }
+}
+
+impl TryFromJs for String {
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        match *value {
+            Value::String(ref string) => Ok(string.to_string()),
+            _ => Err(__private::unexpected(value, "a string", interpreter)),
+        }
+    }
+}
+
+impl IntoJs for String {
+    fn into_js(self, _: &mut Interpreter) -> Value {
+        Value::string(self)
+    }
+}
+
+impl IntoJs for &str {
+    fn into_js(self, _: &mut Interpreter) -> Value {
+        Value::string(self)
+    }
+}
+
+impl TryFromJs for RcString {
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        match *value {
+            Value::String(ref string) => Ok(string.clone()),
+            _ => Err(__private::unexpected(value, "a string", interpreter)),
+        }
+    }
+}
+
+impl IntoJs for RcString {
+    fn into_js(self, _: &mut Interpreter) -> Value {
+        Value::String(self)
+    }
+}
+
+impl TryFromJs for char {
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        if let Value::String(ref string) = *value {
+            let mut chars = string.chars();
+            if let (Some(c), None) = (chars.next(), chars.next()) {
+                return Ok(c);
+            }
+        }
+        Err(__private::unexpected(
+            value,
+            "a string of one character",
+            interpreter,
+        ))
+    }
+}
+
+impl IntoJs for char {
+    fn into_js(self, _: &mut Interpreter) -> Value {
+        Value::from(self)
+    }
+}
+
+/// Gets the number of a JavaScript value, that must be a number.
+fn number(value: &Value, interpreter: &mut Interpreter) -> Result<f64, Value> {
+    match *value {
+        Value::Integer(integer) => Ok(f64::from(integer)),
+        Value::Rational(rational) => Ok(rational),
+        _ => Err(__private::unexpected(value, "a number", interpreter)),
+    }
+}
+
+impl TryFromJs for f64 {
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        number(value, interpreter)
+    }
+}
+
+impl IntoJs for f64 {
+    fn into_js(self, _: &mut Interpreter) -> Value {
+        Value::rational(self)
+    }
+}
+
+impl TryFromJs for f32 {
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        number(value, interpreter).map(|number| number as f32)
+    }
+}
+
+impl IntoJs for f32 {
+    fn into_js(self, _: &mut Interpreter) -> Value {
+        Value::rational(f64::from(self))
+    }
+}
+
+/// Implements the conversions of integer types, which only accept numbers that are integers in
+/// the range of the type.
+macro_rules! impl_try_from_js_for_integers {
+    ($($ty:ident),*) => {
+        $(
+            impl TryFromJs for $ty {
+                fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+                    let number = number(value, interpreter)?;
+                    // `$ty::MAX as f64` rounds up to a power of two for the 64-bit types, so the
+                    // upper bound is checked exclusively against that power of two instead.
+                    let upper = 2f64.powi($ty::MAX.count_ones() as i32);
+                    if number.fract() == 0.0 && number >= $ty::MIN as f64 && number < upper {
+                        Ok(number as $ty)
+                    } else {
+                        Err(interpreter.construct_range_error(format!(
+                            "expected an integer between {} and {}, got {}",
+                            $ty::MIN,
+                            $ty::MAX,
+                            value
+                        )))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_js_for_integers!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Implements the conversions of the integer types that always fit in an `i32`.
+macro_rules! impl_into_js_for_small_integers {
+    ($($ty:ident),*) => {
+        $(
+            impl IntoJs for $ty {
+                fn into_js(self, _: &mut Interpreter) -> Value {
+                    Value::integer(i32::from(self))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_js_for_small_integers!(i8, i16, i32, u8, u16);
+
+/// Implements the conversions of the integer types that can be too large for an `i32`, which are
+/// converted to a rational number when they are.
+macro_rules! impl_into_js_for_large_integers {
+    ($($ty:ident),*) => {
+        $(
+            impl IntoJs for $ty {
+                fn into_js(self, _: &mut Interpreter) -> Value {
+                    i32::try_from(self)
+                        .map(Value::integer)
+                        .unwrap_or_else(|_| Value::rational(self as f64))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_js_for_large_integers!(i64, isize, u32, u64, usize);
+
+impl<T> TryFromJs for Option<T>
+where
+    T: TryFromJs,
+{
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        if value.is_null_or_undefined() {
+            Ok(None)
+        } else {
+            T::try_from_js(value, interpreter).map(Some)
+        }
+    }
+}
+
+impl<T> IntoJs for Option<T>
+where
+    T: IntoJs,
+{
+    fn into_js(self, interpreter: &mut Interpreter) -> Value {
+        match self {
+            Some(value) => value.into_js(interpreter),
+            None => Value::null(),
+        }
+    }
+}
+
+impl<T> TryFromJs for Vec<T>
+where
+    T: TryFromJs,
+{
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        let len = __private::array_length(value, "an array", interpreter)?;
+        (0..len)
+            .map(|index| __private::element(value, index, interpreter))
+            .collect()
+    }
+}
+
+impl<T> IntoJs for Vec<T>
+where
+    T: IntoJs,
+{
+    fn into_js(self, interpreter: &mut Interpreter) -> Value {
+        let elements: Vec<Value> = self
+            .into_iter()
+            .map(|element| element.into_js(interpreter))
+            .collect();
+        __private::array(&elements, interpreter)
+    }
+}
+
+impl<V, S> TryFromJs for HashMap<String, V, S>
+where
+    V: TryFromJs,
+    S: BuildHasher + Default,
+{
+    fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+        __private::expect_object(value, "an object", interpreter)?;
+        let mut map = HashMap::default();
+        for key in interpreter.own_property_keys(value)? {
+            let enumerable = interpreter
+                .get_own_property(value, &key)?
+                .enumerable_or(false);
+            if let (PropertyKey::String(ref name), true) = (&key, enumerable) {
+                let property = interpreter.get_field(value, key.clone())?;
+                map.insert(name.to_string(), V::try_from_js(&property, interpreter)?);
+            }
+        }
+        Ok(map)
+    }
+}
+
+impl<V, S> IntoJs for HashMap<String, V, S>
+where
+    V: IntoJs,
+    S: BuildHasher,
+{
+    fn into_js(self, interpreter: &mut Interpreter) -> Value {
+        let object = __private::object(interpreter);
+        for (key, value) in self {
+            object.set_field(key, value.into_js(interpreter));
+        }
+        object
+    }
+}
+
+/// Implements the conversions of tuples, which are converted from and to arrays, and the
+/// conversion of the arguments of a function to a tuple.
+macro_rules! impl_tuples {
+    ($(($len:expr => $($name:ident $index:tt),*)),*) => {
+        $(
+            impl<$($name),*> TryFromJs for ($($name,)*)
+            where
+                $($name: TryFromJs,)*
+            {
+                fn try_from_js(value: &Value, interpreter: &mut Interpreter) -> Result<Self, Value> {
+                    let description = concat!("an array of length ", $len);
+                    __private::expect_array(value, $len, description, interpreter)?;
+                    Ok(($(__private::element::<$name>(value, $index, interpreter)?,)*))
+                }
+            }
+
+            impl<$($name),*> IntoJs for ($($name,)*)
+            where
+                $($name: IntoJs,)*
+            {
+                fn into_js(self, interpreter: &mut Interpreter) -> Value {
+                    let elements = [$(self.$index.into_js(interpreter)),*];
+                    __private::array(&elements, interpreter)
+                }
+            }
+
+            impl<$($name),*> TryFromJsArgs for ($($name,)*)
+            where
+                $($name: TryFromJs,)*
+            {
+                fn try_from_js_args(
+                    args: &[Value],
+                    interpreter: &mut Interpreter,
+                ) -> Result<Self, Value> {
+                    Ok(($(
+                        $name::try_from_js(
+                            &args.get($index).cloned().unwrap_or_default(),
+                            interpreter,
+                        )?,
+                    )*))
+                }
+            }
+        )*
+    };
+}
+
+impl_tuples!(
+    (1 => A 0),
+    (2 => A 0, B 1),
+    (3 => A 0, B 1, C 2),
+    (4 => A 0, B 1, C 2, D 3),
+    (5 => A 0, B 1, C 2, D 3, E 4),
+    (6 => A 0, B 1, C 2, D 3, E 4, F 5),
+    (7 => A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (8 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+);
+
+impl TryFromJsArgs for () {
+    fn try_from_js_args(_: &[Value], _: &mut Interpreter) -> Result<Self, Value> {
+        Ok(())
+    }
+}
+
+/// Helpers used by the code generated by the derive macros.
+#[doc(hidden)]
+pub mod __private {
+    use super::TryFromJs;
+    use crate::{
+        builtins::{value::Value, Array},
+        exec::Interpreter,
+    };
+
+    /// Creates the error thrown when `value` isn't `expected`.
+    pub fn unexpected(value: &Value, expected: &str, interpreter: &mut Interpreter) -> Value {
+        let found = if value.is_null() {
+            "null"
+        } else {
+            value.get_type().as_str()
+        };
+        interpreter.construct_type_error(format!("expected {}, got {}", expected, found))
+    }
+
+    /// Checks that `value` is an object.
+    pub fn expect_object(
+        value: &Value,
+        expected: &str,
+        interpreter: &mut Interpreter,
+    ) -> Result<(), Value> {
+        if value.is_object() {
+            Ok(())
+        } else {
+            Err(unexpected(value, expected, interpreter))
+        }
+    }
+
+    /// Gets the length of `value`, which must be an array.
+    pub fn array_length(
+        value: &Value,
+        expected: &str,
+        interpreter: &mut Interpreter,
+    ) -> Result<usize, Value> {
+        let is_array = value
+            .as_object()
+            .map(|object| object.is_array())
+            .unwrap_or_default();
+        if !is_array {
+            return Err(unexpected(value, expected, interpreter));
+        }
+        let length = interpreter.get_field(value, "length")?;
+        interpreter.to_length(&length)
+    }
+
+    /// Checks that `value` is an array of length `len`.
+    pub fn expect_array(
+        value: &Value,
+        len: usize,
+        expected: &str,
+        interpreter: &mut Interpreter,
+    ) -> Result<(), Value> {
+        let length = array_length(value, expected, interpreter)?;
+        if length == len {
+            Ok(())
+        } else {
+            Err(interpreter.construct_type_error(format!(
+                "expected {}, got an array of length {}",
+                expected, length
+            )))
+        }
+    }
+
+    /// Converts the element `index` of the array `value`.
+    pub fn element<T>(
+        value: &Value,
+        index: usize,
+        interpreter: &mut Interpreter,
+    ) -> Result<T, Value>
+    where
+        T: TryFromJs,
+    {
+        let element = interpreter.get_field(value, index.to_string())?;
+        T::try_from_js(&element, interpreter)
+    }
+
+    /// Converts the property `name` of the object `value`, which is a `type_name`.
+    pub fn field<T>(
+        value: &Value,
+        name: &str,
+        type_name: &str,
+        interpreter: &mut Interpreter,
+    ) -> Result<T, Value>
+    where
+        T: TryFromJs,
+    {
+        let field = interpreter.get_field(value, name)?;
+        match T::try_from_js(&field, interpreter) {
+            Ok(field) => Ok(field),
+            Err(err) if interpreter.has_property(value, &name.into())? => Err(err),
+            Err(_) => Err(interpreter
+                .construct_type_error(format!("missing field '{}' of {}", name, type_name))),
+        }
+    }
+
+    /// Finds the variant of the enum `type_name` that `value` is, and returns its index in
+    /// `variants` with the value of its fields.
+    ///
+    /// `variants` holds the name of each variant, and whether it has fields. Variants without
+    /// fields are strings, and the other variants are objects with a property named after the
+    /// variant.
+    pub fn variant(
+        value: &Value,
+        variants: &[(&str, bool)],
+        type_name: &str,
+        interpreter: &mut Interpreter,
+    ) -> Result<(usize, Value), Value> {
+        match *value {
+            Value::String(ref name) => {
+                let index = variants
+                    .iter()
+                    .position(|&(variant, has_fields)| !has_fields && variant == name.as_str());
+                if let Some(index) = index {
+                    return Ok((index, Value::undefined()));
+                }
+                return Err(interpreter
+                    .construct_type_error(format!("unknown variant '{}' of {}", name, type_name)));
+            }
+            Value::Object(_) => {
+                for (index, &(variant, has_fields)) in variants.iter().enumerate() {
+                    if has_fields && interpreter.has_property(value, &variant.into())? {
+                        let fields = interpreter.get_field(value, variant)?;
+                        return Ok((index, fields));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Err(unexpected(value, &format!("a {}", type_name), interpreter))
+    }
+
+    /// Creates an empty object.
+    pub fn object(interpreter: &Interpreter) -> Value {
+        Value::new_object(Some(interpreter.global()))
+    }
+
+    /// Creates an array of `elements`.
+    pub fn array(elements: &[Value], interpreter: &Interpreter) -> Value {
+        Array::new_array(interpreter)
+            .and_then(|array| Array::construct_array(&array, elements))
+            .expect("creating an array never throws")
+    }
+}
@@ -0,0 +1,462 @@
+use super::{Error, MAX_SAFE_INTEGER};
+use crate::{
+    builtins::{
+        object::GcObject,
+        property::PropertyKey,
+        value::{RcString, Value},
+    },
+    exec::Interpreter,
+};
+use serde::{
+    de::{self, value::StringDeserializer, DeserializeSeed, IntoDeserializer, Visitor},
+    forward_to_deserialize_any,
+};
+use std::{convert::TryFrom, vec};
+
+/// A deserializer that converts JavaScript values to Rust values.
+#[derive(Debug)]
+pub struct Deserializer<'a> {
+    value: Value,
+    interpreter: &'a mut Interpreter,
+    ancestors: Option<&'a Ancestor<'a>>,
+}
+
+/// An object that is being deserialized, with the objects that contain it.
+#[derive(Debug)]
+struct Ancestor<'a> {
+    object: GcObject,
+    parent: Option<&'a Ancestor<'a>>,
+}
+
+impl<'a> Deserializer<'a> {
+    /// Creates a deserializer of `value`, which calls its getters with `interpreter`.
+    pub fn new(value: Value, interpreter: &'a mut Interpreter) -> Self {
+        Self {
+            value,
+            interpreter,
+            ancestors: None,
+        }
+    }
+
+    /// Checks that the object being deserialized doesn't contain itself, and returns it as an
+    /// ancestor of its properties.
+    fn enter(&self, object: &GcObject) -> Result<Ancestor<'a>, Error> {
+        let mut ancestor = self.ancestors;
+        while let Some(current) = ancestor {
+            if GcObject::equals(&current.object, object) {
+                return Err(Error::Message(
+                    "cannot deserialize an object that contains itself".to_owned(),
+                ));
+            }
+            ancestor = current.parent;
+        }
+
+        Ok(Ancestor {
+            object: object.clone(),
+            parent: self.ancestors,
+        })
+    }
+
+    /// Deserializes an object as a map of its enumerable own properties.
+    fn deserialize_object<'de, V>(self, object: &GcObject, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let ancestor = self.enter(object)?;
+        let keys: Vec<RcString> = object
+            .borrow()
+            .own_property_keys()
+            .into_iter()
+            .filter_map(|key| match key {
+                PropertyKey::String(ref name)
+                    if object.borrow().get_own_property(&key).enumerable_or(false) =>
+                {
+                    Some(name.clone())
+                }
+                _ => None,
+            })
+            .collect();
+
+        visitor.visit_map(ObjectAccess {
+            object: self.value,
+            keys: keys.into_iter(),
+            value: None,
+            interpreter: self.interpreter,
+            ancestor,
+        })
+    }
+
+    /// Deserializes an array as a sequence of its elements.
+    fn deserialize_array<'de, V>(self, object: &GcObject, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let ancestor = self.enter(object)?;
+        let length = self
+            .interpreter
+            .get_field(&self.value, "length")
+            .and_then(|length| self.interpreter.to_length(&length))
+            .map_err(Error::Thrown)?;
+
+        visitor.visit_seq(ArrayAccess {
+            array: self.value,
+            index: 0,
+            length,
+            interpreter: self.interpreter,
+            ancestor,
+        })
+    }
+}
+
+impl<'de, 'a> de::Deserializer<'de> for Deserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Undefined => visitor.visit_none(),
+            Value::Null => visitor.visit_unit(),
+            Value::Boolean(boolean) => visitor.visit_bool(boolean),
+            Value::String(ref string) => visitor.visit_str(string),
+            Value::Integer(integer) => visitor.visit_i32(integer),
+            Value::Rational(rational) => {
+                let is_integer = rational.fract() == 0.0
+                    && rational.abs() <= MAX_SAFE_INTEGER
+                    && !(rational == 0.0 && rational.is_sign_negative());
+                if is_integer {
+                    visitor.visit_i64(rational as i64)
+                } else {
+                    visitor.visit_f64(rational)
+                }
+            }
+            Value::BigInt(ref bigint) => {
+                if let Some(bigint) = bigint.to_i128() {
+                    match i64::try_from(bigint) {
+                        Ok(bigint) => visitor.visit_i64(bigint),
+                        Err(_) => match u64::try_from(bigint) {
+                            Ok(bigint) => visitor.visit_u64(bigint),
+                            Err(_) => visitor.visit_i128(bigint),
+                        },
+                    }
+                } else if let Some(bigint) = bigint.to_u128() {
+                    visitor.visit_u128(bigint)
+                } else {
+                    Err(Error::Message(format!("BigInt {} is too large", bigint)))
+                }
+            }
+            Value::Symbol(_) => Err(Error::Message("cannot deserialize a symbol".to_owned())),
+            Value::Object(ref object) => {
+                let object = object.clone();
+                let (is_function, is_array) = {
+                    let object = object.borrow();
+                    (object.is_function(), object.is_array())
+                };
+                if is_function {
+                    Err(Error::Message("cannot deserialize a function".to_owned()))
+                } else if is_array {
+                    self.deserialize_array(&object, visitor)
+                } else {
+                    self.deserialize_object(&object, visitor)
+                }
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.value.is_null_or_undefined() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.value.is_null_or_undefined() {
+            visitor.visit_unit()
+        } else {
+            self.deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_unit_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let object = match self.value {
+            Value::String(ref variant) => {
+                return visitor.visit_enum(variant.to_string().into_deserializer());
+            }
+            Value::Object(ref object) => object.clone(),
+            _ => {
+                return Err(Error::Message(format!(
+                    "expected a string or an object for the enum {}, got {}",
+                    name,
+                    self.value.get_type().as_str()
+                )));
+            }
+        };
+
+        let ancestor = self.enter(&object)?;
+        let mut keys = object.borrow().own_property_keys().into_iter();
+        let variant = match (keys.next(), keys.next()) {
+            (Some(PropertyKey::String(ref variant)), None) => variant.clone(),
+            _ => {
+                return Err(Error::Message(format!(
+                    "expected an object with a single property for the enum {}",
+                    name
+                )));
+            }
+        };
+        let fields = self
+            .interpreter
+            .get_field(&self.value, variant.clone())
+            .map_err(Error::Thrown)?;
+
+        visitor.visit_enum(VariantAccess {
+            variant,
+            fields: Deserializer {
+                value: fields,
+                interpreter: self.interpreter,
+                ancestors: Some(&ancestor),
+            },
+        })
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf seq
+        tuple tuple_struct map struct identifier
+    }
+}
+
+/// Gives the elements of an array to a visitor.
+struct ArrayAccess<'a> {
+    array: Value,
+    index: usize,
+    length: usize,
+    interpreter: &'a mut Interpreter,
+    ancestor: Ancestor<'a>,
+}
+
+impl<'de> de::SeqAccess<'de> for ArrayAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.index >= self.length {
+            return Ok(None);
+        }
+
+        let element = self
+            .interpreter
+            .get_field(&self.array, self.index.to_string())
+            .map_err(Error::Thrown)?;
+        self.index += 1;
+        seed.deserialize(Deserializer {
+            value: element,
+            interpreter: self.interpreter,
+            ancestors: Some(&self.ancestor),
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length - self.index)
+    }
+}
+
+/// Gives the enumerable own properties of an object to a visitor, skipping the properties that
+/// are `undefined`.
+struct ObjectAccess<'a> {
+    object: Value,
+    keys: vec::IntoIter<RcString>,
+    value: Option<Value>,
+    interpreter: &'a mut Interpreter,
+    ancestor: Ancestor<'a>,
+}
+
+impl<'de> de::MapAccess<'de> for ObjectAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        for key in &mut self.keys {
+            let value = self
+                .interpreter
+                .get_field(&self.object, key.clone())
+                .map_err(Error::Thrown)?;
+            if !value.is_undefined() {
+                self.value = Some(value);
+                return seed.deserialize(KeyDeserializer(key)).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let value = self
+            .value
+            .take()
+            .expect("next_value_seed is called after next_key_seed");
+        seed.deserialize(Deserializer {
+            value,
+            interpreter: self.interpreter,
+            ancestors: Some(&self.ancestor),
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+/// Gives the variant of an enum, and its fields, to a visitor.
+struct VariantAccess<'a> {
+    variant: RcString,
+    fields: Deserializer<'a>,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for VariantAccess<'a> {
+    type Error = Error;
+    type Variant = Deserializer<'a>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Deserializer<'a>), Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(KeyDeserializer(self.variant))?;
+        Ok((variant, self.fields))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer<'_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V>(self, _: &'static [&'static str], visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
+
+/// Deserializes the key of a property, which can also be deserialized as a number, so maps with
+/// integer keys can be deserialized.
+struct KeyDeserializer(RcString);
+
+impl KeyDeserializer {
+    fn string(self) -> StringDeserializer<Error> {
+        self.0.to_string().into_deserializer()
+    }
+}
+
+/// Implements the deserialization of keys as numbers, by parsing them.
+macro_rules! deserialize_number_keys {
+    ($($method:ident => $visit:ident),*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Error>
+            where
+                V: Visitor<'de>,
+            {
+                match self.0.parse() {
+                    Ok(number) => visitor.$visit(number),
+                    Err(_) => de::Deserializer::deserialize_any(self.string(), visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_any(self.string(), visitor)
+    }
+
+    deserialize_number_keys! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64
+    }
+
+    fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i128 u128 char str string bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
@@ -0,0 +1,108 @@
+//! Conversions between Rust values and JavaScript values with serde.
+//!
+//! [`to_value`][to_value] converts any type implementing `Serialize` to a JavaScript value, and
+//! [`from_value`][from_value] converts a JavaScript value to any type implementing `Deserialize`,
+//! without going through a JSON string.
+//!
+//! Structs and maps are converted from and to objects, sequences and tuples from and to arrays,
+//! and enums like `serde_json` does. `None` and `()` are converted to `null`. Integers are
+//! converted to numbers if they are safe integers, and to BigInts otherwise, and BigInts can be
+//! converted back to any integer type they fit in. When converting an object, properties whose
+//! value is `undefined` are skipped, like `JSON.stringify` does, so they are treated as missing.
+//! Converting an object that contains itself is an error.
+//!
+//! ```
+//! # use boa::{convert::serde::{from_value, to_value}, exec::Interpreter, realm::Realm};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     name: String,
+//!     retries: Option<u32>,
+//! }
+//!
+//! let mut engine = Interpreter::new(Realm::create());
+//! let config = Config { name: "boa".to_owned(), retries: Some(3) };
+//! let value = to_value(&config, &mut engine).unwrap();
+//! assert_eq!(value.get_field("retries").to_number(), 3.0);
+//! assert_eq!(from_value::<Config>(&value, &mut engine).unwrap(), config);
+//! ```
+
+mod de;
+mod ser;
+#[cfg(test)]
+mod tests;
+
+pub use self::{de::Deserializer, ser::Serializer};
+
+use crate::{builtins::value::Value, exec::Interpreter};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::{self, Display};
+
+/// The largest integer that a number can represent exactly, `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Converts a Rust value to a JavaScript value.
+///
+/// Errors are thrown as `TypeError`s.
+pub fn to_value<T>(value: &T, interpreter: &mut Interpreter) -> Result<Value, Value>
+where
+    T: Serialize + ?Sized,
+{
+    value
+        .serialize(&mut Serializer::new(interpreter))
+        .map_err(|err| err.into_value(interpreter))
+}
+
+/// Converts a JavaScript value to a Rust value.
+///
+/// Errors are thrown as `TypeError`s, and the exceptions thrown by getters are rethrown.
+pub fn from_value<T>(value: &Value, interpreter: &mut Interpreter) -> Result<T, Value>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(Deserializer::new(value.clone(), interpreter))
+        .map_err(|err| err.into_value(interpreter))
+}
+
+/// An error of a conversion between Rust values and JavaScript values.
+#[derive(Debug)]
+pub enum Error {
+    /// An exception thrown by JavaScript code, like a getter.
+    Thrown(Value),
+    /// The value can't be converted.
+    Message(String),
+}
+
+impl Error {
+    /// Converts the error to a value that can be thrown, which is a `TypeError` if the value can't
+    /// be converted.
+    pub fn into_value(self, interpreter: &mut Interpreter) -> Value {
+        match self {
+            Self::Thrown(value) => value,
+            Self::Message(message) => interpreter.construct_type_error(message),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Thrown(ref value) => write!(f, "uncaught exception: {}", value),
+            Self::Message(ref message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
@@ -0,0 +1,423 @@
+use super::{Error, MAX_SAFE_INTEGER};
+use crate::{
+    builtins::{bigint::BigInt, value::Value},
+    convert::__private::{array, object},
+    exec::Interpreter,
+};
+use serde::ser::{self, Serialize};
+use std::convert::TryFrom;
+
+/// A serializer that converts Rust values to JavaScript values.
+#[derive(Debug)]
+pub struct Serializer<'a> {
+    interpreter: &'a mut Interpreter,
+}
+
+impl<'a> Serializer<'a> {
+    /// Creates a serializer creating its values with `interpreter`.
+    pub fn new(interpreter: &'a mut Interpreter) -> Self {
+        Self { interpreter }
+    }
+}
+
+/// Converts an integer to a number if it's a safe integer, or to a BigInt otherwise.
+fn integer(value: i128) -> Value {
+    if let Ok(integer) = i32::try_from(value) {
+        Value::integer(integer)
+    } else if (value as f64).abs() <= MAX_SAFE_INTEGER {
+        Value::rational(value as f64)
+    } else {
+        Value::bigint(BigInt::from(value))
+    }
+}
+
+impl<'s, 'a> ser::Serializer for &'s mut Serializer<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SerializeArray<'s, 'a>;
+    type SerializeTuple = SerializeArray<'s, 'a>;
+    type SerializeTupleStruct = SerializeArray<'s, 'a>;
+    type SerializeTupleVariant = SerializeArray<'s, 'a>;
+    type SerializeMap = SerializeObject<'s, 'a>;
+    type SerializeStruct = SerializeObject<'s, 'a>;
+    type SerializeStructVariant = SerializeObject<'s, 'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Ok(Value::integer(i32::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Ok(Value::integer(i32::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Ok(Value::integer(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(integer(i128::from(v)))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value, Error> {
+        Ok(integer(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Ok(Value::integer(i32::from(v)))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Ok(Value::integer(i32::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Ok(integer(i128::from(v)))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Ok(integer(i128::from(v)))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value, Error> {
+        Ok(match i128::try_from(v) {
+            Ok(v) => integer(v),
+            Err(_) => Value::bigint(BigInt::from(v)),
+        })
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        Ok(Value::rational(f64::from(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(Value::rational(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::string(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        let elements: Vec<Value> = v.iter().map(|&byte| Value::integer(byte)).collect();
+        Ok(array(&elements, self.interpreter))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::null())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Value, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::null())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value, Error> {
+        Ok(Value::null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Ok(Value::from(variant))
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<Value, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(&mut *self)?;
+        let wrapper = object(self.interpreter);
+        wrapper.set_field(variant, value);
+        Ok(wrapper)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Ok(SerializeArray::new(self, len.unwrap_or_default(), None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        Ok(SerializeArray::new(self, len, None))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Ok(SerializeArray::new(self, len, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Ok(SerializeArray::new(self, len, Some(variant)))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(SerializeObject::new(self, None))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, Error> {
+        Ok(SerializeObject::new(self, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Ok(SerializeObject::new(self, Some(variant)))
+    }
+}
+
+/// Wraps the fields of the enum variant `variant` in an object, if there is a variant.
+fn wrap(serializer: &mut Serializer<'_>, variant: Option<&str>, fields: Value) -> Value {
+    match variant {
+        Some(variant) => {
+            let wrapper = object(serializer.interpreter);
+            wrapper.set_field(variant, fields);
+            wrapper
+        }
+        None => fields,
+    }
+}
+
+/// Serializes sequences, tuples, and the fields of tuple structs and tuple variants, to an array.
+#[derive(Debug)]
+pub struct SerializeArray<'s, 'a> {
+    serializer: &'s mut Serializer<'a>,
+    elements: Vec<Value>,
+    variant: Option<&'static str>,
+}
+
+impl<'s, 'a> SerializeArray<'s, 'a> {
+    fn new(serializer: &'s mut Serializer<'a>, len: usize, variant: Option<&'static str>) -> Self {
+        Self {
+            serializer,
+            elements: Vec::with_capacity(len),
+            variant,
+        }
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let element = value.serialize(&mut *self.serializer)?;
+        self.elements.push(element);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, Error> {
+        let fields = array(&self.elements, self.serializer.interpreter);
+        Ok(wrap(self.serializer, self.variant, fields))
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray<'_, '_> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray<'_, '_> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray<'_, '_> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeArray<'_, '_> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+/// Serializes maps, and the fields of structs and struct variants, to an object.
+#[derive(Debug)]
+pub struct SerializeObject<'s, 'a> {
+    serializer: &'s mut Serializer<'a>,
+    object: Value,
+    key: Option<String>,
+    variant: Option<&'static str>,
+}
+
+impl<'s, 'a> SerializeObject<'s, 'a> {
+    fn new(serializer: &'s mut Serializer<'a>, variant: Option<&'static str>) -> Self {
+        let object = object(serializer.interpreter);
+        Self {
+            serializer,
+            object,
+            key: None,
+            variant,
+        }
+    }
+
+    fn insert<T>(&mut self, key: &str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(&mut *self.serializer)?;
+        self.object.set_field(key, value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, Error> {
+        Ok(wrap(self.serializer, self.variant, self.object))
+    }
+}
+
+impl ser::SerializeMap for SerializeObject<'_, '_> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = match key.serialize(&mut *self.serializer)? {
+            Value::String(ref string) => string.to_string(),
+            key @ Value::Boolean(_) | key @ Value::Integer(_) | key @ Value::Rational(_) => {
+                key.to_string()
+            }
+            Value::BigInt(ref bigint) => bigint.to_string(),
+            key => {
+                return Err(Error::Message(format!(
+                    "object keys must be strings or numbers, got {}",
+                    key.get_type().as_str()
+                )));
+            }
+        };
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_value is called after serialize_key");
+        self.insert(&key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeObject<'_, '_> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeObject<'_, '_> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
@@ -0,0 +1,199 @@
+use super::{from_value, to_value};
+use crate::{builtins::value::Value, exec::Interpreter, forward, forward_val, realm::Realm};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    port: u16,
+    debug: Option<bool>,
+    tags: Vec<String>,
+    limits: BTreeMap<String, f64>,
+    mode: Mode,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Mode {
+    Off,
+    Fixed(u32),
+    Range { min: u32, max: u32 },
+}
+
+fn config() -> Config {
+    let mut limits = BTreeMap::new();
+    limits.insert("cpu".to_owned(), 1.5);
+    Config {
+        name: "server".to_owned(),
+        port: 8080,
+        debug: None,
+        tags: vec!["a".to_owned(), "b".to_owned()],
+        limits,
+        mode: Mode::Range { min: 1, max: 4 },
+    }
+}
+
+/// Gets the message of an error thrown by a conversion, which must be a `TypeError`.
+fn type_error(err: Value) -> String {
+    assert_eq!(err.get_field("name").to_string(), "\"TypeError\"");
+    err.get_field("message").as_string().unwrap().to_string()
+}
+
+#[test]
+fn serialize() {
+    let mut engine = Interpreter::new(Realm::create());
+    let value = to_value(&config(), &mut engine).unwrap();
+    engine.global().set_field("config", value);
+
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify(config)"),
+        r#""{"debug":null,"limits":{"cpu":1.5},"mode":{"Range":{"max":4,"min":1}},"name":"server","port":8080,"tags":["a","b"]}""#
+    );
+    assert_eq!(forward(&mut engine, "Array.isArray(config.tags)"), "true");
+    assert_eq!(forward(&mut engine, "config instanceof Object"), "true");
+
+    let modes = to_value(&[Mode::Off, Mode::Fixed(2)], &mut engine).unwrap();
+    engine.global().set_field("modes", modes);
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify(modes)"),
+        r#""["Off",{"Fixed":2}]""#
+    );
+}
+
+#[test]
+fn deserialize() {
+    let mut engine = Interpreter::new(Realm::create());
+    let value = forward_val(
+        &mut engine,
+        r#"({
+            name: "server",
+            port: 8080,
+            debug: undefined,
+            tags: ["a", "b"],
+            limits: { cpu: 1.5 },
+            mode: { Range: { min: 1, max: 4 } },
+            get ignored() { return "extra"; },
+        })"#,
+    )
+    .unwrap();
+
+    assert_eq!(from_value::<Config>(&value, &mut engine).unwrap(), config());
+
+    let value = forward_val(&mut engine, "({ 1: 'one', 20: 'twenty' })").unwrap();
+    let map: HashMap<u32, String> = from_value(&value, &mut engine).unwrap();
+    assert_eq!(map[&20], "twenty");
+
+    let value = forward_val(&mut engine, "['Off', { Fixed: 3 }]").unwrap();
+    assert_eq!(
+        from_value::<Vec<Mode>>(&value, &mut engine).unwrap(),
+        vec![Mode::Off, Mode::Fixed(3)]
+    );
+
+    let value = forward_val(&mut engine, "({ port: 'http' })").unwrap();
+    let err = from_value::<Config>(&value, &mut engine).unwrap_err();
+    assert_eq!(
+        type_error(err),
+        "invalid type: string \"http\", expected u16"
+    );
+}
+
+#[test]
+fn null_and_undefined() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    let value = forward_val(&mut engine, "[null, undefined, 1]").unwrap();
+    assert_eq!(
+        from_value::<Vec<Option<i32>>>(&value, &mut engine).unwrap(),
+        vec![None, None, Some(1)]
+    );
+    assert_eq!(
+        from_value::<serde_json::Value>(&value, &mut engine).unwrap(),
+        serde_json::json!([null, null, 1])
+    );
+
+    let value = forward_val(&mut engine, "({ a: null, b: undefined })").unwrap();
+    assert_eq!(
+        from_value::<serde_json::Value>(&value, &mut engine).unwrap(),
+        serde_json::json!({ "a": null })
+    );
+
+    let value = to_value(&(None::<i32>, ()), &mut engine).unwrap();
+    engine.global().set_field("value", value);
+    assert_eq!(
+        forward(&mut engine, "value[0] === null && value[1] === null"),
+        "true"
+    );
+}
+
+#[test]
+fn big_integers() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    let value = to_value(
+        &[9_007_199_254_740_991_u64, 9_007_199_254_740_992],
+        &mut engine,
+    )
+    .unwrap();
+    engine.global().set_field("value", value);
+    assert_eq!(
+        forward(&mut engine, "[typeof value[0], typeof value[1]].join()"),
+        "\"number,bigint\""
+    );
+    assert_eq!(
+        forward(&mut engine, "value[1] === 9007199254740992n"),
+        "true"
+    );
+
+    let value = forward_val(
+        &mut engine,
+        "[18446744073709551615n, -170141183460469231731687303715884105728n, 4294967296]",
+    )
+    .unwrap();
+    assert_eq!(
+        from_value::<(u64, i128, i64)>(&value, &mut engine).unwrap(),
+        (u64::MAX, i128::MIN, 4_294_967_296)
+    );
+
+    let value = forward_val(&mut engine, "2n ** 200n").unwrap();
+    let err = from_value::<serde_json::Value>(&value, &mut engine).unwrap_err();
+    assert!(type_error(err).ends_with("is too large"));
+
+    let value = forward_val(&mut engine, "300n").unwrap();
+    let err = from_value::<u8>(&value, &mut engine).unwrap_err();
+    assert_eq!(type_error(err), "invalid value: integer `300`, expected u8");
+}
+
+#[test]
+fn cycles() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    let value = forward_val(&mut engine, "var a = { b: {} }; a.b.a = a; a").unwrap();
+    let err = from_value::<serde_json::Value>(&value, &mut engine).unwrap_err();
+    assert_eq!(
+        type_error(err),
+        "cannot deserialize an object that contains itself"
+    );
+
+    let value = forward_val(&mut engine, "var list = [1]; list.push(list); list").unwrap();
+    assert!(from_value::<serde_json::Value>(&value, &mut engine).is_err());
+
+    let value = forward_val(&mut engine, "var shared = { x: 1 }; [shared, shared]").unwrap();
+    assert_eq!(
+        from_value::<serde_json::Value>(&value, &mut engine).unwrap(),
+        serde_json::json!([{ "x": 1 }, { "x": 1 }])
+    );
+}
+
+#[test]
+fn thrown_exceptions() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    let value = forward_val(
+        &mut engine,
+        "({ get name() { throw new RangeError('no name'); } })",
+    )
+    .unwrap();
+    let err = from_value::<HashMap<String, String>>(&value, &mut engine).unwrap_err();
+    assert_eq!(err.get_field("name").to_string(), "\"RangeError\"");
+    assert_eq!(err.get_field("message").to_string(), "\"no name\"");
+}
@@ -0,0 +1,317 @@
+use super::{IntoJs, TryFromJs};
+use crate::{
+    builtins::value::{ResultValue, Value},
+    exec::Interpreter,
+    forward, forward_val,
+    realm::Realm,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+struct Pair(String, f64);
+
+#[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+enum Shape {
+    Empty,
+    Circle { center: Point, radius: f64 },
+    Polygon(Vec<Point>),
+    Line(Point, Point),
+}
+
+#[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+struct Labeled<T> {
+    label: Option<String>,
+    value: T,
+}
+
+#[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+struct Token {
+    r#type: String,
+    value: i32,
+}
+
+#[derive(Debug, PartialEq, TryFromJs, IntoJs)]
+enum Event {
+    Input { r#type: String, r#ref: i32 },
+}
+
+fn decode<T: TryFromJs>(engine: &mut Interpreter, src: &str) -> Result<T, Value> {
+    let value = forward_val(engine, src).unwrap();
+    engine.decode(&value)
+}
+
+/// Gets the `"name: message"` of an error thrown by a conversion.
+fn error<T: TryFromJs + std::fmt::Debug>(engine: &mut Interpreter, src: &str) -> String {
+    let err = decode::<T>(engine, src).unwrap_err();
+    format!(
+        "{}: {}",
+        err.get_field("name").as_string().unwrap(),
+        err.get_field("message").as_string().unwrap()
+    )
+}
+
+#[test]
+fn primitives() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    assert_eq!(decode::<bool>(&mut engine, "true"), Ok(true));
+    assert_eq!(decode::<String>(&mut engine, "'boa'"), Ok("boa".to_owned()));
+    assert_eq!(decode::<char>(&mut engine, "'b'"), Ok('b'));
+    assert_eq!(decode::<f64>(&mut engine, "1.5"), Ok(1.5));
+    assert_eq!(decode::<u8>(&mut engine, "255"), Ok(255));
+    assert_eq!(
+        decode::<i64>(&mut engine, "-4294967296"),
+        Ok(-4_294_967_296)
+    );
+    assert_eq!(decode::<Option<u8>>(&mut engine, "null"), Ok(None));
+    assert_eq!(decode::<Option<u8>>(&mut engine, "7"), Ok(Some(7)));
+
+    assert_eq!(
+        error::<bool>(&mut engine, "1"),
+        "TypeError: expected a boolean, got number"
+    );
+    assert_eq!(
+        error::<String>(&mut engine, "null"),
+        "TypeError: expected a string, got null"
+    );
+    assert_eq!(
+        error::<u8>(&mut engine, "256"),
+        "RangeError: expected an integer between 0 and 255, got 256"
+    );
+    assert_eq!(
+        error::<i32>(&mut engine, "1.5"),
+        "RangeError: expected an integer between -2147483648 and 2147483647, got 1.5"
+    );
+}
+
+/// Checks the bounds of the 64-bit integers, whose maximum values aren't representable as `f64`.
+#[test]
+fn integer_bounds() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    assert_eq!(decode::<i64>(&mut engine, "-(2 ** 63)"), Ok(i64::MIN));
+    assert_eq!(
+        decode::<i64>(&mut engine, "2 ** 63 - 1024"),
+        Ok(9_223_372_036_854_774_784)
+    );
+    assert_eq!(
+        decode::<u64>(&mut engine, "2 ** 64 - 2048"),
+        Ok(18_446_744_073_709_549_568)
+    );
+    assert_eq!(decode::<u32>(&mut engine, "2 ** 32 - 1"), Ok(u32::MAX));
+
+    assert_eq!(
+        error::<i64>(&mut engine, "2 ** 63"),
+        "RangeError: expected an integer between -9223372036854775808 and 9223372036854775807, got 9223372036854776000"
+    );
+    assert_eq!(
+        error::<u64>(&mut engine, "2 ** 64"),
+        "RangeError: expected an integer between 0 and 18446744073709551615, got 18446744073709552000"
+    );
+    assert_eq!(
+        error::<u32>(&mut engine, "2 ** 32"),
+        "RangeError: expected an integer between 0 and 4294967295, got 4294967296"
+    );
+}
+
+#[test]
+fn collections() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    assert_eq!(
+        decode::<Vec<u32>>(&mut engine, "[1, 2, 3]"),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(
+        decode::<(String, bool)>(&mut engine, "['a', false]"),
+        Ok(("a".to_owned(), false))
+    );
+    let map = decode::<HashMap<String, i32>>(&mut engine, "({ a: 1, b: 2 })").unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["b"], 2);
+
+    assert_eq!(
+        error::<Vec<u32>>(&mut engine, "({ length: 1 })"),
+        "TypeError: expected an array, got object"
+    );
+    assert_eq!(
+        error::<Vec<u32>>(&mut engine, "[1, 'two']"),
+        "TypeError: expected a number, got string"
+    );
+    assert_eq!(
+        error::<(i32, i32)>(&mut engine, "[1, 2, 3]"),
+        "TypeError: expected an array of length 2, got an array of length 3"
+    );
+
+    let value = (vec![1, 2], Some("three")).into_js(&mut engine);
+    engine.global().set_field("value", value);
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify(value)"),
+        r#""[[1,2],"three"]""#
+    );
+    assert_eq!(forward(&mut engine, "Array.isArray(value)"), "true");
+}
+
+#[test]
+fn derived_structs() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    assert_eq!(
+        decode::<Point>(&mut engine, "({ x: 1, y: 2, z: 3 })"),
+        Ok(Point { x: 1, y: 2 })
+    );
+    assert_eq!(
+        decode::<Pair>(&mut engine, "['one', 1]"),
+        Ok(Pair("one".to_owned(), 1.0))
+    );
+    assert_eq!(
+        decode::<Labeled<bool>>(&mut engine, "({ value: true })"),
+        Ok(Labeled {
+            label: None,
+            value: true
+        })
+    );
+
+    assert_eq!(
+        error::<Point>(&mut engine, "({ x: 1 })"),
+        "TypeError: missing field 'y' of Point"
+    );
+    assert_eq!(
+        error::<Point>(&mut engine, "({ x: 1, y: '2' })"),
+        "TypeError: expected a number, got string"
+    );
+    assert_eq!(
+        error::<Point>(&mut engine, "'1,2'"),
+        "TypeError: expected Point, got string"
+    );
+
+    let point = Point { x: 3, y: 4 }.into_js(&mut engine);
+    engine.global().set_field("point", point);
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify(point)"),
+        r#""{"x":3,"y":4}""#
+    );
+}
+
+#[test]
+fn derived_enums() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    assert_eq!(decode::<Shape>(&mut engine, "'Empty'"), Ok(Shape::Empty));
+    assert_eq!(
+        decode::<Shape>(
+            &mut engine,
+            "({ Circle: { center: { x: 0, y: 1 }, radius: 2 } })"
+        ),
+        Ok(Shape::Circle {
+            center: Point { x: 0, y: 1 },
+            radius: 2.0
+        })
+    );
+    assert_eq!(
+        decode::<Shape>(&mut engine, "({ Polygon: [{ x: 0, y: 0 }] })"),
+        Ok(Shape::Polygon(vec![Point { x: 0, y: 0 }]))
+    );
+    assert_eq!(
+        decode::<Shape>(&mut engine, "({ Line: [{ x: 0, y: 0 }, { x: 1, y: 1 }] })"),
+        Ok(Shape::Line(Point { x: 0, y: 0 }, Point { x: 1, y: 1 }))
+    );
+
+    assert_eq!(
+        error::<Shape>(&mut engine, "'Square'"),
+        "TypeError: unknown variant 'Square' of Shape"
+    );
+    assert_eq!(
+        error::<Shape>(&mut engine, "({ Square: 1 })"),
+        "TypeError: expected a Shape, got object"
+    );
+
+    let shapes = vec![
+        Shape::Empty,
+        Shape::Line(Point { x: 0, y: 0 }, Point { x: 1, y: 1 }),
+    ]
+    .into_js(&mut engine);
+    engine.global().set_field("shapes", shapes);
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify(shapes)"),
+        r#""["Empty",{"Line":[{"x":0,"y":0},{"x":1,"y":1}]}]""#
+    );
+}
+
+#[test]
+fn derived_raw_identifiers() {
+    let mut engine = Interpreter::new(Realm::create());
+
+    assert_eq!(
+        decode::<Token>(&mut engine, "({ type: 'number', value: 1 })"),
+        Ok(Token {
+            r#type: "number".to_owned(),
+            value: 1
+        })
+    );
+    assert_eq!(
+        decode::<Event>(&mut engine, "({ Input: { type: 'text', ref: 2 } })"),
+        Ok(Event::Input {
+            r#type: "text".to_owned(),
+            r#ref: 2
+        })
+    );
+    assert_eq!(
+        error::<Token>(&mut engine, "({ value: 1 })"),
+        "TypeError: missing field 'type' of Token"
+    );
+
+    let token = Token {
+        r#type: "number".to_owned(),
+        value: 1,
+    }
+    .into_js(&mut engine);
+    let event = Event::Input {
+        r#type: "text".to_owned(),
+        r#ref: 2,
+    }
+    .into_js(&mut engine);
+    engine.global().set_field("token", token);
+    engine.global().set_field("event", event);
+    assert_eq!(forward(&mut engine, "token.type"), "\"number\"");
+    assert_eq!(forward(&mut engine, "event.Input.type"), "\"text\"");
+    assert_eq!(forward(&mut engine, "event.Input.ref"), "2");
+}
+
+#[test]
+fn native_function_arguments() {
+    fn distance(_: &Value, args: &[Value], interpreter: &mut Interpreter) -> ResultValue {
+        let (from, to, scale): (Point, Point, Option<f64>) = interpreter.decode_args(args)?;
+        let (dx, dy) = (f64::from(to.x - from.x), f64::from(to.y - from.y));
+        Ok((dx.hypot(dy) * scale.unwrap_or(1.0)).into_js(interpreter))
+    }
+
+    let realm = Realm::create().register_global_func("distance", distance);
+    let mut engine = Interpreter::new(realm);
+
+    assert_eq!(
+        forward(&mut engine, "distance({ x: 0, y: 0 }, { x: 3, y: 4 })"),
+        "5"
+    );
+    assert_eq!(
+        forward(&mut engine, "distance({ x: 0, y: 0 }, { x: 3, y: 4 }, 2)"),
+        "10"
+    );
+    let scenario = r#"
+        try {
+            distance({ x: 0, y: 0 });
+        } catch (e) {
+            e instanceof TypeError && e.message;
+        }
+    "#;
+    assert_eq!(
+        forward(&mut engine, scenario),
+        "\"expected Point, got undefined\""
+    );
+}
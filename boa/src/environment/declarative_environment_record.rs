@@ -118,6 +118,14 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         }
     }
 
+    fn is_immutable_binding(&self, name: &str) -> bool {
+        matches!(self.env_rec.get(name), Some(binding) if !binding.mutable)
+    }
+
+    fn is_initialized_binding(&self, name: &str) -> bool {
+        matches!(self.env_rec.get(name), Some(binding) if binding.value.is_some())
+    }
+
     fn get_binding_value(&self, name: &str, _strict: bool) -> Value {
         if let Some(binding) = self.env_rec.get(name) {
             binding
@@ -10,7 +10,11 @@
 //!
 use crate::{
     builtins::value::Value,
-    environment::lexical_environment::{Environment, EnvironmentType},
+    environment::{
+        function_environment_record::FunctionEnvironmentRecord,
+        lexical_environment::{Environment, EnvironmentType},
+        module_environment_record::ModuleEnvironmentRecord,
+    },
 };
 use gc::{Finalize, Trace};
 use std::fmt::Debug;
@@ -82,4 +86,43 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
 
     /// Fetch global variable
     fn get_global_object(&self) -> Option<Value>;
+
+    /// Returns this record as a function Environment Record, if it is one.
+    ///
+    /// This gives access to the state only function environments have, like the
+    /// `[[HomeObject]]` and `[[NewTarget]]` used by `super`.
+    fn as_function_environment_record(&self) -> Option<&FunctionEnvironmentRecord> {
+        None
+    }
+
+    /// Returns this record as a mutable function Environment Record, if it is one.
+    fn as_function_environment_record_mut(&mut self) -> Option<&mut FunctionEnvironmentRecord> {
+        None
+    }
+
+    /// Checks if the binding `name` is an import binding, which can never be assigned to.
+    ///
+    /// Only module Environment Records have import bindings.
+    fn is_import_binding(&self, _name: &str) -> bool {
+        false
+    }
+
+    /// Checks if the binding `name` is immutable, like the bindings of `const` declarations and
+    /// the inner bindings of class names, which can never be assigned to.
+    fn is_immutable_binding(&self, _name: &str) -> bool {
+        false
+    }
+
+    /// Checks if the binding `name` has been initialized, or if it is still in its temporal dead
+    /// zone, like the inner binding of a class name while its heritage is evaluated.
+    fn is_initialized_binding(&self, _name: &str) -> bool {
+        true
+    }
+
+    /// Returns this record as a mutable module Environment Record, if it is one.
+    ///
+    /// This gives access to the import bindings only module environments have.
+    fn as_module_environment_record_mut(&mut self) -> Option<&mut ModuleEnvironmentRecord> {
+        None
+    }
 }
@@ -78,11 +78,29 @@ impl FunctionEnvironmentRecord {
             }
         }
     }
+
+    /// Returns `true` if the `this` value of this environment has already been bound.
+    ///
+    /// This is `false` inside derived class constructors until `super(...)` has been called.
+    pub fn is_this_initialized(&self) -> bool {
+        !matches!(self.this_binding_status, BindingStatus::Uninitialized)
+    }
+
+    /// Returns the object that `super` property lookups start from.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getsuperbase
+    pub fn get_super_base(&self) -> Value {
+        match self.home_object {
+            Value::Object(ref home) => home.borrow().get_prototype_of(),
+            _ => Value::undefined(),
+        }
+    }
 }
 
 impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
-    // TODO: get_super_base can't implement until GetPrototypeof is implemented on object
-
     fn has_binding(&self, name: &str) -> bool {
         self.env_rec.contains_key(name)
     }
@@ -181,6 +199,14 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         }
     }
 
+    fn is_immutable_binding(&self, name: &str) -> bool {
+        matches!(self.env_rec.get(name), Some(binding) if !binding.mutable)
+    }
+
+    fn is_initialized_binding(&self, name: &str) -> bool {
+        matches!(self.env_rec.get(name), Some(binding) if binding.value.is_some())
+    }
+
     fn get_binding_value(&self, name: &str, _strict: bool) -> Value {
         if let Some(binding) = self.env_rec.get(name) {
             binding
@@ -248,4 +274,12 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
             None => None,
         }
     }
+
+    fn as_function_environment_record(&self) -> Option<&FunctionEnvironmentRecord> {
+        Some(self)
+    }
+
+    fn as_function_environment_record_mut(&mut self) -> Option<&mut FunctionEnvironmentRecord> {
+        Some(self)
+    }
 }
@@ -140,6 +140,15 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         self.object_record.set_mutable_binding(name, value, strict)
     }
 
+    fn is_immutable_binding(&self, name: &str) -> bool {
+        self.declarative_record.is_immutable_binding(name)
+    }
+
+    fn is_initialized_binding(&self, name: &str) -> bool {
+        !self.declarative_record.has_binding(name)
+            || self.declarative_record.is_initialized_binding(name)
+    }
+
     fn get_binding_value(&self, name: &str, strict: bool) -> Value {
         if self.declarative_record.has_binding(&name) {
             return self.declarative_record.get_binding_value(name, strict);
@@ -12,11 +12,12 @@ use crate::{
         environment_record_trait::EnvironmentRecordTrait,
         function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
         global_environment_record::GlobalEnvironmentRecord,
+        module_environment_record::ModuleEnvironmentRecord,
         object_environment_record::ObjectEnvironmentRecord,
     },
     BoaProfiler,
 };
-use gc::{Gc, GcCell};
+use gc::{Finalize, Gc, GcCell, Trace};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::{collections::VecDeque, error, fmt};
 
@@ -30,6 +31,7 @@ pub enum EnvironmentType {
     Declarative,
     Function,
     Global,
+    Module,
     Object,
 }
 
@@ -42,7 +44,7 @@ pub enum VariableScope {
     Function,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Trace, Finalize)]
 pub struct LexicalEnvironment {
     environment_stack: VecDeque<Environment>,
 }
@@ -91,6 +93,21 @@ impl LexicalEnvironment {
         lexical_env
     }
 
+    /// Creates a lexical environment whose stack is the scope chain ending in `env`.
+    ///
+    /// Function bodies run in such an environment, so they see the bindings of the
+    /// scope they were defined in instead of the bindings of their caller.
+    pub fn from_scope(env: Environment) -> Self {
+        let mut environment_stack = VecDeque::new();
+        let mut current = Some(env);
+        while let Some(env) = current {
+            current = env.borrow().get_outer_environment();
+            environment_stack.push_front(env);
+        }
+
+        Self { environment_stack }
+    }
+
     pub fn push(&mut self, env: Environment) {
         let current_env: Environment = self.get_current_environment().clone();
         env.borrow_mut().set_outer_environment(current_env);
@@ -120,6 +137,18 @@ impl LexicalEnvironment {
             .unwrap_or_else(Value::undefined)
     }
 
+    /// Finds the closest environment that provides a `this` binding.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getthisenvironment
+    pub fn get_this_environment(&self) -> &Environment {
+        self.environments()
+            .find(|env| env.borrow().has_this_binding())
+            .expect("the global environment always has a this binding")
+    }
+
     pub fn create_mutable_binding(&mut self, name: String, deletion: bool, scope: VariableScope) {
         match scope {
             VariableScope::Block => self
@@ -127,14 +156,16 @@ impl LexicalEnvironment {
                 .borrow_mut()
                 .create_mutable_binding(name, deletion),
             VariableScope::Function => {
-                // Find the first function or global environment (from the top of the stack)
+                // Find the first function, module or global environment (from the top of the stack)
                 let env = self
                     .environments()
                     .find(|env| match env.borrow().get_environment_type() {
-                        EnvironmentType::Function | EnvironmentType::Global => true,
+                        EnvironmentType::Function
+                        | EnvironmentType::Module
+                        | EnvironmentType::Global => true,
                         _ => false,
                     })
-                    .expect("No function or global environment");
+                    .expect("No function, module or global environment");
 
                 env.borrow_mut().create_mutable_binding(name, deletion);
             }
@@ -153,14 +184,16 @@ impl LexicalEnvironment {
                 .borrow_mut()
                 .create_immutable_binding(name, deletion),
             VariableScope::Function => {
-                // Find the first function or global environment (from the top of the stack)
+                // Find the first function, module or global environment (from the top of the stack)
                 let env = self
                     .environments()
                     .find(|env| match env.borrow().get_environment_type() {
-                        EnvironmentType::Function | EnvironmentType::Global => true,
+                        EnvironmentType::Function
+                        | EnvironmentType::Module
+                        | EnvironmentType::Global => true,
                         _ => false,
                     })
-                    .expect("No function or global environment");
+                    .expect("No function, module or global environment");
 
                 #[allow(clippy::let_and_return)]
                 // FIXME need to assign result to a variable to avoid borrow checker error
@@ -212,9 +245,26 @@ impl LexicalEnvironment {
             .any(|env| env.borrow().has_binding(name))
     }
 
+    /// Checks if the first binding named `name` is an import binding of a module.
+    pub fn is_import_binding(&self, name: &str) -> bool {
+        self.environments()
+            .find(|env| env.borrow().has_binding(name))
+            .map_or(false, |env| env.borrow().is_import_binding(name))
+    }
+
+    /// Checks if the first binding named `name` is immutable.
+    pub fn is_immutable_binding(&self, name: &str) -> bool {
+        self.environments()
+            .find(|env| env.borrow().has_binding(name))
+            .map_or(false, |env| env.borrow().is_immutable_binding(name))
+    }
+
+    /// Gets the value of the first binding named `name`, if there is one and it has been
+    /// initialized.
     pub fn get_binding_value(&self, name: &str) -> Option<Value> {
         self.environments()
             .find(|env| env.borrow().has_binding(name))
+            .filter(|env| env.borrow().is_initialized_binding(name))
             .map(|env| env.borrow().get_binding_value(name, false))
     }
 }
@@ -229,18 +279,32 @@ pub fn new_declarative_environment(env: Option<Environment>) -> Environment {
     Gc::new(GcCell::new(boxed_env))
 }
 
+/// Creates the environment of a module, whose outer environment is the global environment.
+pub fn new_module_environment(global_env: Environment) -> Environment {
+    let _timer = BoaProfiler::global().start_event("new_module_environment", "env");
+    Gc::new(GcCell::new(Box::new(ModuleEnvironmentRecord {
+        declarative_record: DeclarativeEnvironmentRecord {
+            env_rec: FxHashMap::default(),
+            outer_env: Some(global_env),
+        },
+        import_bindings: FxHashMap::default(),
+    })))
+}
+
 pub fn new_function_environment(
     f: Value,
     this: Option<Value>,
     outer: Option<Environment>,
     binding_status: BindingStatus,
+    home_object: Value,
+    new_target: Value,
 ) -> Environment {
     let mut func_env = FunctionEnvironmentRecord {
         env_rec: FxHashMap::default(),
         function: f,
         this_binding_status: binding_status,
-        home_object: Value::undefined(),
-        new_target: Value::undefined(),
+        home_object,
+        new_target,
         outer_env: outer, // this will come from Environment set as a private property of F - https://tc39.es/ecma262/#sec-ecmascript-function-objects
         this_value: Value::undefined(),
     };
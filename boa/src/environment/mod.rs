@@ -1,8 +1,9 @@
-//! Environment handling, lexical, object, function and declaritive records
+//! Environment handling, lexical, object, function, module and declaritive records
 
 pub mod declarative_environment_record;
 pub mod environment_record_trait;
 pub mod function_environment_record;
 pub mod global_environment_record;
 pub mod lexical_environment;
+pub mod module_environment_record;
 pub mod object_environment_record;
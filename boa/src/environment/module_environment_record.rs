@@ -0,0 +1,157 @@
+//! # Module Environment Records
+//!
+//! A module Environment Record is a declarative Environment Record that is used to represent the
+//! outer scope of an ECMAScript Module. In additional to normal mutable and immutable bindings,
+//! module Environment Records also provide immutable import bindings which are bindings that
+//! provide indirect access to a target binding that exists in another Environment Record.
+//! More info: <https://tc39.es/ecma262/#sec-module-environment-records>
+
+use crate::{
+    builtins::value::Value,
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait,
+        lexical_environment::{Environment, EnvironmentType},
+    },
+};
+use gc::{Finalize, Trace};
+use rustc_hash::FxHashMap;
+
+/// An import binding is an indirect binding to a binding of the environment of another module.
+#[derive(Trace, Finalize, Debug, Clone)]
+pub struct ImportBinding {
+    pub module: Environment,
+    pub name: String,
+}
+
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ModuleEnvironmentRecord {
+    pub declarative_record: DeclarativeEnvironmentRecord,
+    pub import_bindings: FxHashMap<String, ImportBinding>,
+}
+
+impl ModuleEnvironmentRecord {
+    /// Creates an immutable import binding named `name`, which refers to the binding named
+    /// `binding_name` of the environment `module`.
+    ///
+    /// Returns an error message if the environment already has a binding named `name`. The
+    /// parser rejects modules that declare a name more than once, so this only happens for
+    /// modules that weren't parsed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createimportbinding
+    pub fn create_import_binding(
+        &mut self,
+        name: String,
+        module: Environment,
+        binding_name: String,
+    ) -> Result<(), String> {
+        if self.import_bindings.contains_key(&name) || self.declarative_record.has_binding(&name) {
+            return Err(format!("Identifier {} has already been declared", name));
+        }
+
+        self.import_bindings.insert(
+            name,
+            ImportBinding {
+                module,
+                name: binding_name,
+            },
+        );
+        Ok(())
+    }
+}
+
+impl EnvironmentRecordTrait for ModuleEnvironmentRecord {
+    fn has_binding(&self, name: &str) -> bool {
+        // An import binding only exists once the binding it refers to is created, by the
+        // evaluation of the module that exports it.
+        match self.import_bindings.get(name) {
+            Some(import) => import.module.borrow().has_binding(&import.name),
+            None => self.declarative_record.has_binding(name),
+        }
+    }
+
+    fn create_mutable_binding(&mut self, name: String, deletion: bool) {
+        self.declarative_record
+            .create_mutable_binding(name, deletion)
+    }
+
+    fn create_immutable_binding(&mut self, name: String, strict: bool) -> bool {
+        self.declarative_record
+            .create_immutable_binding(name, strict)
+    }
+
+    fn initialize_binding(&mut self, name: &str, value: Value) {
+        self.declarative_record.initialize_binding(name, value)
+    }
+
+    fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool) {
+        // Assignments to import bindings throw a `TypeError` before getting here.
+        debug_assert!(!self.import_bindings.contains_key(name));
+        self.declarative_record
+            .set_mutable_binding(name, value, strict)
+    }
+
+    fn get_binding_value(&self, name: &str, strict: bool) -> Value {
+        match self.import_bindings.get(name) {
+            Some(import) => import.module.borrow().get_binding_value(&import.name, true),
+            None => self.declarative_record.get_binding_value(name, strict),
+        }
+    }
+
+    fn delete_binding(&mut self, name: &str) -> bool {
+        // Module code is strict mode code, so its bindings can't be deleted.
+        !self.import_bindings.contains_key(name) && !self.declarative_record.has_binding(name)
+    }
+
+    fn is_import_binding(&self, name: &str) -> bool {
+        self.import_bindings.contains_key(name)
+    }
+
+    fn is_immutable_binding(&self, name: &str) -> bool {
+        self.declarative_record.is_immutable_binding(name)
+    }
+
+    fn is_initialized_binding(&self, name: &str) -> bool {
+        self.import_bindings.contains_key(name)
+            || self.declarative_record.is_initialized_binding(name)
+    }
+
+    fn has_this_binding(&self) -> bool {
+        true
+    }
+
+    fn get_this_binding(&self) -> Value {
+        Value::undefined()
+    }
+
+    fn has_super_binding(&self) -> bool {
+        false
+    }
+
+    fn with_base_object(&self) -> Value {
+        Value::undefined()
+    }
+
+    fn get_outer_environment(&self) -> Option<Environment> {
+        self.declarative_record.get_outer_environment()
+    }
+
+    fn set_outer_environment(&mut self, env: Environment) {
+        self.declarative_record.set_outer_environment(env)
+    }
+
+    fn get_environment_type(&self) -> EnvironmentType {
+        EnvironmentType::Module
+    }
+
+    fn get_global_object(&self) -> Option<Value> {
+        self.declarative_record.get_global_object()
+    }
+
+    fn as_module_environment_record_mut(&mut self) -> Option<&mut ModuleEnvironmentRecord> {
+        Some(self)
+    }
+}
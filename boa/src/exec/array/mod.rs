@@ -2,7 +2,7 @@
 
 use super::{Executable, Interpreter};
 use crate::{
-    builtins::{Array, ResultValue},
+    builtins::{generator::Frame, iterable::iterable_to_list, Array, ResultValue},
     syntax::ast::node::{ArrayDecl, Node},
     BoaProfiler,
 };
@@ -10,17 +10,27 @@ use crate::{
 impl Executable for ArrayDecl {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         let _timer = BoaProfiler::global().start_event("ArrayDecl", "exec");
-        let array = Array::new_array(interpreter)?;
-        let mut elements = Vec::new();
-        for elem in self.as_ref() {
-            if let Node::Spread(ref x) = elem {
-                let val = x.run(interpreter)?;
-                let mut vals = interpreter.extract_array_properties(&val).unwrap();
-                elements.append(&mut vals);
-                continue; // Don't push array after spread
+        // In a generator body, the step is the index of the element, and the frame has the values
+        // of the previous elements.
+        let (start, mut elements) = match interpreter.resume_frame() {
+            Some(frame) => (frame.step, frame.values),
+            None => (0, Vec::new()),
+        };
+        for (i, elem) in self.as_ref().iter().enumerate().skip(start) {
+            let node = match elem {
+                Node::Spread(ref x) => x.val(),
+                elem => elem,
+            };
+            let val = node
+                .run(interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(i, elements.clone())))?;
+            if let Node::Spread(_) = elem {
+                elements.append(&mut iterable_to_list(interpreter, &val)?);
+            } else {
+                elements.push(val);
             }
-            elements.push(elem.run(interpreter)?);
         }
+        let array = Array::new_array(interpreter)?;
         Array::add_to_array_object(&array, &elements)?;
 
         Ok(array)
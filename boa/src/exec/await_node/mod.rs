@@ -0,0 +1,35 @@
+//! Await expression execution.
+
+use super::{Executable, Interpreter};
+use crate::{
+    builtins::{
+        generator::{resumed, suspend, Frame, ResumeKind},
+        value::ResultValue,
+    },
+    syntax::ast::node::Await,
+    BoaProfiler,
+};
+
+impl Executable for Await {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("Await", "exec");
+        // Step 0 is the suspension at this expression, step 1 the evaluation of the operand.
+        if let Some(Frame { step: 0, .. }) = interpreter.resume_frame() {
+            return match resumed(interpreter) {
+                ResumeKind::Next(value) => Ok(value),
+                ResumeKind::Throw(reason) => Err(reason),
+                ResumeKind::Return(_) => {
+                    unreachable!("async functions are never resumed by a return")
+                }
+            };
+        }
+
+        let value = self
+            .expr()
+            .run(interpreter)
+            .map_err(|err| interpreter.save_frame(err, || Frame::new(1, Vec::new())))?;
+
+        // The body of the async function is suspended until the awaited promise is settled.
+        Err(suspend(interpreter, value, Frame::new(0, Vec::new())))
+    }
+}
@@ -2,7 +2,10 @@
 
 use super::{Executable, Interpreter, InterpreterState};
 use crate::{
-    builtins::value::{ResultValue, Value},
+    builtins::{
+        generator::Frame,
+        value::{ResultValue, Value},
+    },
     environment::lexical_environment::new_declarative_environment,
     syntax::ast::node::Block,
     BoaProfiler,
@@ -11,37 +14,53 @@ use crate::{
 impl Executable for Block {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         let _timer = BoaProfiler::global().start_event("Block", "exec");
-        {
-            let env = &mut interpreter.realm_mut().environment;
-            env.push(new_declarative_environment(Some(
-                env.get_current_environment_ref().clone(),
-            )));
-        }
-
-        let mut obj = Value::null();
-        for statement in self.statements() {
-            obj = statement.run(interpreter)?;
-
-            match interpreter.get_current_state() {
-                InterpreterState::Return => {
-                    // Early return.
-                    break;
-                }
-                InterpreterState::Break(_label) => {
-                    // TODO, break to a label.
-
-                    // Early break.
-                    break;
-                }
-                _ => {
-                    // Continue execution
-                }
+        // A block resumed in a generator body already has its environment, and the step is the
+        // index of the statement it was suspended in.
+        let start = match interpreter.resume_frame() {
+            Some(frame) => frame.step,
+            None => {
+                let env = &mut interpreter.realm_mut().environment;
+                env.push(new_declarative_environment(Some(
+                    env.get_current_environment_ref().clone(),
+                )));
+                0
             }
+        };
+
+        let result = run_statements(self, start, interpreter);
+
+        // pop the block env, even if a statement threw, unless the environment is kept by a
+        // suspended generator body
+        if !interpreter.is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
         }
 
-        // pop the block env
-        let _ = interpreter.realm_mut().environment.pop();
+        result
+    }
+}
+
+/// Runs the statements of a block from the given one, until one of them ends the block early.
+fn run_statements(block: &Block, start: usize, interpreter: &mut Interpreter) -> ResultValue {
+    let mut obj = Value::null();
+    for (i, statement) in block.statements().iter().enumerate().skip(start) {
+        obj = statement
+            .run(interpreter)
+            .map_err(|err| interpreter.save_frame(err, || Frame::new(i, Vec::new())))?;
 
-        Ok(obj)
+        match interpreter.get_current_state() {
+            InterpreterState::Return => {
+                // Early return.
+                break;
+            }
+            InterpreterState::Break(_) | InterpreterState::Continue(_) => {
+                // Early break, the enclosing statements handle the label.
+                break;
+            }
+            _ => {
+                // Continue execution
+            }
+        }
     }
+
+    Ok(obj)
 }
@@ -1,6 +1,14 @@
-use super::{Executable, Interpreter, InterpreterState};
+use super::{
+    field::evaluate_object_and_field, optional::evaluate_chain, Executable, Interpreter,
+    InterpreterState,
+};
 use crate::{
-    builtins::value::{ResultValue, Type},
+    builtins::{
+        generator::Frame,
+        iterable::iterable_to_list,
+        value::{same_value, ResultValue, Type, Value},
+        Eval,
+    },
     syntax::ast::node::{Call, Node},
     BoaProfiler,
 };
@@ -8,39 +16,30 @@ use crate::{
 impl Executable for Call {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         let _timer = BoaProfiler::global().start_event("Call", "exec");
-        let (this, func) = match self.expr() {
-            Node::GetConstField(ref get_const_field) => {
-                let mut obj = get_const_field.obj().run(interpreter)?;
-                if obj.get_type() != Type::Object || obj.get_type() != Type::Symbol {
-                    obj = interpreter
-                        .to_object(&obj)
-                        .expect("failed to convert to object");
-                }
-                (obj.clone(), obj.get_field(get_const_field.field()))
-            }
-            Node::GetField(ref get_field) => {
-                let obj = get_field.obj().run(interpreter)?;
-                let field = get_field.field().run(interpreter)?;
-                (obj.clone(), obj.get_field(field.to_string()))
+        // In a generator body, step 0 is the callee and step 1 the arguments.
+        let (this, func) = match interpreter.resume_frame() {
+            Some(frame) if frame.step == 1 => {
+                let mut values = frame.values.into_iter();
+                let this = values.next().expect("the this value is saved");
+                (this, values.next().expect("the function is saved"))
             }
-            _ => (
-                interpreter.realm().global_obj.clone(),
-                self.expr().run(interpreter)?,
-            ), // 'this' binding should come from the function's self-contained environment
+            _ => evaluate_callee(self.expr(), interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?,
         };
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            if let Node::Spread(ref x) = arg {
-                let val = x.run(interpreter)?;
-                let mut vals = interpreter.extract_array_properties(&val).unwrap();
-                v_args.append(&mut vals);
-                break; // after spread we don't accept any new arguments
-            }
-            v_args.push(arg.run(interpreter)?);
-        }
+        let v_args = evaluate_arguments(self.args(), interpreter).map_err(|err| {
+            interpreter.save_frame(err, || Frame::new(1, vec![this.clone(), func.clone()]))
+        })?;
+
+        // `eval(...)` calling the `%eval%` intrinsic is a direct eval, running in this environment.
+        let is_direct_eval = matches!(self.expr(), Node::Identifier(ref name) if name.as_ref() == "eval")
+            && same_value(&func, interpreter.eval_function());
 
         // execute the function call itself
-        let fnct_result = interpreter.call(&func, &this, &v_args);
+        let fnct_result = if is_direct_eval {
+            Eval::perform(v_args.get(0), true, interpreter)
+        } else {
+            interpreter.call(&func, &this, &v_args)
+        };
 
         // unset the early return flag
         interpreter.set_current_state(InterpreterState::Executing);
@@ -48,3 +47,73 @@ impl Executable for Call {
         fnct_result
     }
 }
+
+/// Evaluates the arguments of a call expression.
+///
+/// The values of spread arguments, like `...args`, are expanded by iterating over them.
+///
+/// In a generator body, the step is the index of the argument, and the frame has the values of the
+/// previous arguments.
+pub(super) fn evaluate_arguments(
+    args: &[Node],
+    interpreter: &mut Interpreter,
+) -> Result<Vec<Value>, Value> {
+    let (start, mut v_args) = match interpreter.resume_frame() {
+        Some(frame) => (frame.step, frame.values),
+        None => (0, Vec::with_capacity(args.len())),
+    };
+    for (i, arg) in args.iter().enumerate().skip(start) {
+        let node = match arg {
+            Node::Spread(ref x) => x.val(),
+            arg => arg,
+        };
+        let val = node
+            .run(interpreter)
+            .map_err(|err| interpreter.save_frame(err, || Frame::new(i, v_args.clone())))?;
+        if let Node::Spread(_) = arg {
+            v_args.append(&mut iterable_to_list(interpreter, &val)?);
+        } else {
+            v_args.push(val);
+        }
+    }
+    Ok(v_args)
+}
+
+/// Evaluates the function of a call expression, along with the `this` value it is called with.
+///
+/// Functions accessed as properties are called with their object as `this`, even through a
+/// parenthesized optional chain like `(o?.f)()`, and other functions with `undefined`.
+pub(super) fn evaluate_callee(
+    expr: &Node,
+    interpreter: &mut Interpreter,
+) -> Result<(Value, Value), Value> {
+    match expr {
+        Node::GetConstField(ref get_const_field) => {
+            let mut obj = get_const_field.obj().run(interpreter)?;
+            if obj.get_type() != Type::Object || obj.get_type() != Type::Symbol {
+                obj = interpreter
+                    .to_object(&obj)
+                    .expect("failed to convert to object");
+            }
+            let func = interpreter.get_field(&obj, get_const_field.field())?;
+            Ok((obj, func))
+        }
+        Node::GetField(ref get_field) => {
+            let (mut obj, field) = evaluate_object_and_field(get_field, interpreter)?;
+            if obj.get_type() != Type::Object || obj.get_type() != Type::Symbol {
+                obj = interpreter.to_object(&obj)?;
+            }
+            let key = interpreter.to_property_key(&field)?;
+            let func = interpreter.get_field(&obj, key)?;
+            Ok((obj, func))
+        }
+        Node::Optional(ref optional) => evaluate_chain(optional, interpreter),
+        Node::GetSuperField(ref get_super_field) => Ok((
+            Node::This.run(interpreter)?,
+            get_super_field.run(interpreter)?,
+        )),
+        // Plain calls pass `undefined` as `this`, sloppy mode functions replace it with the global
+        // object when they are called.
+        _ => Ok((Value::undefined(), expr.run(interpreter)?)),
+    }
+}
@@ -0,0 +1,366 @@
+//! Class execution.
+
+use super::{call::evaluate_arguments, Executable, Interpreter};
+use crate::{
+    builtins::{
+        function::{ConstructorKind, ThisMode},
+        generator::Frame,
+        object::{ObjectData, PROTOTYPE},
+        property::{Attribute, Property, PropertyKey},
+        value::{ResultValue, Value},
+    },
+    environment::lexical_environment::{new_declarative_environment, VariableScope},
+    syntax::ast::node::{
+        ClassDecl, ClassExpr, ClassTail, GetSuperField, MethodDefinitionKind, Node, SuperCall,
+    },
+    BoaProfiler,
+};
+
+#[cfg(test)]
+mod tests;
+
+impl Executable for ClassDecl {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("ClassDecl", "exec");
+        let class = class_definition_evaluation(Some(self.name()), self.tail(), interpreter)?;
+
+        let environment = &mut interpreter.realm_mut().environment;
+        environment.create_mutable_binding(self.name().to_owned(), false, VariableScope::Block);
+        environment.initialize_binding(self.name(), class);
+
+        Ok(Value::undefined())
+    }
+}
+
+impl Executable for ClassExpr {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("ClassExpr", "exec");
+        class_definition_evaluation(self.name(), self.tail(), interpreter)
+    }
+}
+
+/// Creates the constructor function of a class, along with its prototype and methods.
+///
+/// If the class has a name, it is bound in a new scope which only the class body can see.
+///
+/// In a generator body, step 0 is the heritage and step `i + 1` the name of the element `i`, whose
+/// frame has the class and its prototype.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+fn class_definition_evaluation(
+    name: Option<&str>,
+    tail: &ClassTail,
+    interpreter: &mut Interpreter,
+) -> ResultValue {
+    // The class scope of a resumed generator body is already there.
+    let frame = interpreter.resume_frame();
+    if let (Some(name), None) = (name, &frame) {
+        let env = &mut interpreter.realm_mut().environment;
+        env.push(new_declarative_environment(Some(
+            env.get_current_environment_ref().clone(),
+        )));
+        env.create_immutable_binding(name.to_owned(), true, VariableScope::Block);
+    }
+
+    let class = create_class(name, tail, frame, interpreter);
+
+    if name.is_some() && !interpreter.is_suspending() {
+        // pop the class scope
+        let _ = interpreter.realm_mut().environment.pop();
+    }
+
+    class
+}
+
+/// Evaluates the heritage and body of a class, inside the class scope.
+fn create_class(
+    name: Option<&str>,
+    tail: &ClassTail,
+    frame: Option<Frame>,
+    interpreter: &mut Interpreter,
+) -> ResultValue {
+    let (start, class, proto) = match frame {
+        Some(frame) if frame.step > 0 => {
+            let mut values = frame.values.into_iter();
+            let class = values.next().expect("the class is saved");
+            let proto = values.next().expect("the prototype is saved");
+            (frame.step - 1, class, proto)
+        }
+        _ => {
+            let (class, proto) = create_constructor(name, tail, interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+            (0, class, proto)
+        }
+    };
+
+    for (i, element) in tail.elements().iter().enumerate().skip(start) {
+        let target = if element.is_static() { &class } else { &proto };
+        let key = element.name().to_property_key(interpreter).map_err(|err| {
+            interpreter.save_frame(err, || {
+                Frame::new(i + 1, vec![class.clone(), proto.clone()])
+            })
+        })?;
+        let function = element.function();
+        let method = match element.kind() {
+            MethodDefinitionKind::Generator => interpreter
+                .create_generator_function(function.parameters().to_vec(), function.body().clone()),
+            MethodDefinitionKind::Async => interpreter.create_async_function(
+                function.parameters().to_vec(),
+                function.body().clone(),
+                ThisMode::NonLexical,
+            ),
+            _ => interpreter.create_function(
+                function.parameters().to_vec(),
+                function.body().clone(),
+                ThisMode::NonLexical,
+                false,
+                true,
+            ),
+        };
+        if let Some(mut object) = method.as_object_mut() {
+            object
+                .as_function_mut()
+                .expect("class method must be a function")
+                .home_object = target.clone();
+        }
+        let name = match key {
+            PropertyKey::String(ref name) => name.to_string(),
+            PropertyKey::Symbol(ref symbol) => {
+                format!("[{}]", symbol.description().unwrap_or(""))
+            }
+        };
+        method.set_field("name", name);
+
+        let property = match element.kind() {
+            MethodDefinitionKind::Ordinary
+            | MethodDefinitionKind::Generator
+            | MethodDefinitionKind::Async => Property::data_descriptor(
+                method,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+            kind => {
+                // A getter and a setter with the same name are merged into a single property.
+                let mut property = target
+                    .as_object()
+                    .map(|object| object.get_own_property(&key))
+                    .filter(Property::is_accessor_descriptor)
+                    .unwrap_or_else(Property::empty);
+                property.attribute = Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+                if let MethodDefinitionKind::Get = kind {
+                    property.get(method)
+                } else {
+                    property.set(method)
+                }
+            }
+        };
+        if let Some(mut object) = target.as_object_mut() {
+            object.insert_property(key, property);
+        }
+    }
+
+    if let Some(name) = name {
+        interpreter
+            .realm_mut()
+            .environment
+            .initialize_binding(name, class.clone());
+    }
+
+    Ok(class)
+}
+
+/// Creates the constructor function of a class and its prototype, evaluating the heritage.
+fn create_constructor(
+    name: Option<&str>,
+    tail: &ClassTail,
+    interpreter: &mut Interpreter,
+) -> Result<(Value, Value), Value> {
+    let global = interpreter
+        .realm()
+        .environment
+        .get_global_object()
+        .expect("Could not get the global object");
+
+    let (proto_parent, constructor_parent) = match tail.heritage() {
+        None => (
+            global.get_field("Object").get_field(PROTOTYPE),
+            global.get_field("Function").get_field(PROTOTYPE),
+        ),
+        Some(heritage) => {
+            let superclass = heritage.run(interpreter)?;
+            if superclass.is_null() {
+                (
+                    Value::null(),
+                    global.get_field("Function").get_field(PROTOTYPE),
+                )
+            } else if !superclass
+                .as_object()
+                .map(|object| object.is_constructable())
+                .unwrap_or(false)
+            {
+                return Err(interpreter.construct_type_error(format!(
+                    "class heritage {} is not a constructor",
+                    heritage
+                )));
+            } else {
+                let proto_parent = superclass.get_field(PROTOTYPE);
+                if !proto_parent.is_object() && !proto_parent.is_null() {
+                    return Err(interpreter.construct_type_error(
+                        "class heritage prototype must be an object or null",
+                    ));
+                }
+                (proto_parent, superclass)
+            }
+        }
+    };
+
+    let proto = Value::new_object_from_prototype(proto_parent, ObjectData::Ordinary);
+
+    let constructor = tail.constructor();
+    let class = interpreter.create_function(
+        constructor.parameters().to_vec(),
+        constructor.body().clone(),
+        ThisMode::NonLexical,
+        true,
+        false,
+    );
+    if let Some(mut object) = class.as_object_mut() {
+        object.set_prototype(constructor_parent);
+        let function = object
+            .as_function_mut()
+            .expect("class constructor must be a function");
+        if tail.heritage().is_some() {
+            function.constructor_kind = ConstructorKind::Derived;
+        }
+        function.home_object = proto.clone();
+    }
+
+    class.set_property(
+        PROTOTYPE,
+        Property::data_descriptor(
+            proto.clone(),
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        ),
+    );
+    class.set_field("name", name.unwrap_or(""));
+    proto.set_property(
+        "constructor",
+        Property::data_descriptor(
+            class.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+
+    Ok((class, proto))
+}
+
+impl Executable for SuperCall {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("SuperCall", "exec");
+        let env = interpreter
+            .realm()
+            .environment
+            .get_this_environment()
+            .clone();
+        let (new_target, active_function) = match env.borrow().as_function_environment_record() {
+            Some(record) if !record.new_target.is_undefined() => {
+                (record.new_target.clone(), record.function.clone())
+            }
+            _ => return interpreter.throw_syntax_error("'super' keyword unexpected here"),
+        };
+
+        let super_constructor = active_function
+            .as_object()
+            .map(|object| object.get_prototype_of())
+            .unwrap_or_else(Value::null);
+
+        let v_args = evaluate_arguments(self.args(), interpreter)?;
+
+        if !super_constructor
+            .as_object()
+            .map(|object| object.is_constructable())
+            .unwrap_or(false)
+        {
+            return interpreter.throw_type_error("super constructor is not a constructor");
+        }
+
+        let this = interpreter.construct(&super_constructor, &v_args, &new_target)?;
+
+        let mut env = env.borrow_mut();
+        let record = env
+            .as_function_environment_record_mut()
+            .expect("super call must be inside a function");
+        if record.is_this_initialized() {
+            drop(env);
+            return interpreter.throw_reference_error("super constructor may only be called once");
+        }
+        record.bind_this_value(this.clone());
+
+        Ok(this)
+    }
+}
+
+impl Executable for GetSuperField {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("GetSuperField", "exec");
+        let field = self.field().run(interpreter)?;
+        let key = interpreter.to_property_key(&field)?;
+        get_super_field(key, interpreter)
+    }
+}
+
+/// Gets the object the properties of `super` are looked up on, which is the prototype of the
+/// home object of the running method.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getsuperbase
+fn super_base(interpreter: &mut Interpreter) -> ResultValue {
+    let env = interpreter
+        .realm()
+        .environment
+        .get_this_environment()
+        .clone();
+    let base = match env.borrow().as_function_environment_record() {
+        Some(record) if !record.home_object.is_undefined() => record.get_super_base(),
+        _ => return interpreter.throw_syntax_error("'super' keyword unexpected here"),
+    };
+    interpreter.to_object(&base)
+}
+
+/// Gets the value of the property `key` of `super`.
+///
+/// Getters of the parent are called with the `this` value of the method.
+pub(crate) fn get_super_field(key: PropertyKey, interpreter: &mut Interpreter) -> ResultValue {
+    let base = super_base(interpreter)?;
+    let this = Node::This.run(interpreter)?;
+    interpreter.get_field_with_receiver(&base, key, &this)
+}
+
+/// Assigns a value to the property `key` of `super`.
+///
+/// The property is looked up on the parent, so a setter it has is called with the `this` value
+/// of the method, and otherwise the property is set on the `this` value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-putvalue
+pub(crate) fn put_super_field(
+    key: PropertyKey,
+    value: Value,
+    interpreter: &mut Interpreter,
+) -> Result<(), Value> {
+    let base = super_base(interpreter)?;
+    let this = Node::This.run(interpreter)?;
+    if !interpreter.set_field_with_receiver(&base, key.clone(), value, &this)?
+        && interpreter.is_strict()
+    {
+        return Err(interpreter
+            .construct_type_error(format!("cannot assign to read-only property '{}'", key)));
+    }
+    Ok(())
+}
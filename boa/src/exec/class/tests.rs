@@ -0,0 +1,358 @@
+use crate::exec;
+
+#[test]
+fn class_declaration() {
+    let scenario = r#"
+        class Point {
+            constructor(x, y) {
+                this.x = x;
+                this.y = y;
+            }
+
+            sum() {
+                return this.x + this.y;
+            }
+
+            static origin() {
+                return new Point(0, 0);
+            }
+        }
+
+        let p = new Point(1, 2);
+        p.sum() + Point.origin().sum()
+        "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn class_expression() {
+    let scenario = r#"
+        const Foo = class Bar {
+            name() {
+                return Bar.name;
+            }
+        };
+        new Foo().name()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"Bar\"");
+}
+
+#[test]
+fn class_prototype() {
+    let scenario = r#"
+        class Foo {
+            method() {}
+        }
+        let foo = new Foo();
+        [
+            Object.getPrototypeOf(foo) === Foo.prototype,
+            Foo.prototype.constructor === Foo,
+            Foo.prototype.propertyIsEnumerable("method"),
+            foo instanceof Foo,
+        ].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,false,true\"");
+}
+
+#[test]
+fn class_constructor_requires_new() {
+    let scenario = r#"
+        class Foo {}
+        try {
+            Foo();
+        } catch (err) {
+            err.toString()
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"TypeError: class constructors must be invoked with 'new'\""
+    );
+}
+
+#[test]
+fn class_extends() {
+    let scenario = r#"
+        class Animal {
+            constructor(name) {
+                this.name = name;
+            }
+
+            speak() {
+                return this.name + " makes a sound";
+            }
+
+            static create(name) {
+                return new this(name);
+            }
+        }
+
+        class Dog extends Animal {
+            constructor(name) {
+                super(name + " the dog");
+                this.barks = true;
+            }
+
+            speak() {
+                return super.speak() + ": woof";
+            }
+
+            static create(name) {
+                return super.create(name);
+            }
+        }
+
+        let dog = Dog.create("Rex");
+        [dog.speak(), dog.barks, dog instanceof Dog, dog instanceof Animal].join()
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"Rex the dog makes a sound: woof,true,true,true\""
+    );
+}
+
+#[test]
+fn class_default_derived_constructor() {
+    let scenario = r#"
+        class Base {
+            constructor(a, b) {
+                this.sum = a + b;
+            }
+        }
+        class Derived extends Base {}
+        new Derived(1, 2).sum
+        "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn class_extends_null() {
+    let scenario = r#"
+        class Foo extends null {}
+        Object.getPrototypeOf(Foo.prototype)
+        "#;
+
+    assert_eq!(&exec(scenario), "null");
+}
+
+#[test]
+fn class_extends_builtin() {
+    let scenario = r#"
+        class List extends Array {
+            first() {
+                return this[0];
+            }
+        }
+        let list = new List(3, 4);
+        [list.first(), list.length, list instanceof List, list instanceof Array].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"3,2,true,true\"");
+}
+
+#[test]
+fn class_extends_non_constructor() {
+    let scenario = r#"
+        try {
+            class Foo extends 5 {}
+        } catch (err) {
+            err.toString()
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"TypeError: class heritage 5 is not a constructor\""
+    );
+}
+
+#[test]
+fn class_this_before_super() {
+    let scenario = r#"
+        class Base {}
+        class Derived extends Base {
+            constructor() {
+                this.a = 1;
+                super();
+            }
+        }
+        try {
+            new Derived();
+        } catch (err) {
+            err.toString()
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError: must call super constructor before accessing 'this'\""
+    );
+}
+
+#[test]
+fn class_missing_super_call() {
+    let scenario = r#"
+        class Base {}
+        class Derived extends Base {
+            constructor() {}
+        }
+        try {
+            new Derived();
+        } catch (err) {
+            err.toString()
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError: must call super constructor before returning from derived constructor\""
+    );
+}
+
+#[test]
+fn class_super_called_twice() {
+    let scenario = r#"
+        class Base {}
+        class Derived extends Base {
+            constructor() {
+                super();
+                super();
+            }
+        }
+        try {
+            new Derived();
+        } catch (err) {
+            err.toString()
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError: super constructor may only be called once\""
+    );
+}
+
+#[test]
+fn class_super_property_assignment() {
+    let scenario = r#"
+        class Base {}
+        class Derived extends Base {
+            set() {
+                super.value = 5;
+                return this.value;
+            }
+        }
+        new Derived().set()
+        "#;
+
+    assert_eq!(&exec(scenario), "5");
+}
+
+#[test]
+fn class_constructor_returns_object() {
+    let scenario = r#"
+        class Foo {
+            constructor() {
+                return { replaced: true };
+            }
+        }
+        new Foo().replaced
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn class_computed_method_names() {
+    let scenario = r#"
+        var key = "method";
+        class Foo {
+            [key]() { return 1; }
+            static ["static" + "Method"]() { return 2; }
+            *[Symbol.iterator]() { yield 3; }
+        }
+        [new Foo().method(), Foo.staticMethod(), ...new Foo(), Foo.prototype[Symbol.iterator].name].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,3,[Symbol.iterator]\"");
+}
+
+#[test]
+fn class_string_constructor_name() {
+    let scenario = r#"
+        class Foo {
+            "constructor"() { this.value = 1; }
+        }
+        new Foo().value
+        "#;
+
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn class_super_property_parent_setter() {
+    let scenario = r#"
+        let log = [];
+        class Base {
+            set value(value) {
+                log.push(this instanceof Derived);
+                this.stored = value;
+            }
+            get value() {
+                return this.stored;
+            }
+        }
+
+        class Derived extends Base {
+            assign() {
+                super.value = 1;
+                super["value"] += 2;
+                super.other = 4;
+                return [this.stored, this.other, this.hasOwnProperty("value")];
+            }
+        }
+
+        let derived = new Derived();
+        [...derived.assign(), ...log, "other" in Base.prototype].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"3,4,false,true,true,false\"");
+}
+
+#[test]
+fn class_name_inner_binding() {
+    let scenario = r#"
+        const C = class Named { who() { return Named.name; } };
+        class D { static self() { return D; } }
+        const E = D;
+        D = null;
+        let errors = [];
+        try {
+            new (class Inner { assign() { Inner = 1; } })().assign();
+        } catch (e) {
+            errors.push(e.name);
+        }
+        try {
+            class A extends A {}
+        } catch (e) {
+            errors.push(e.name);
+        }
+        try {
+            Named;
+        } catch (e) {
+            errors.push(e.name);
+        }
+        [new C().who(), E.self() === E, ...errors].join()
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"Named,true,TypeError,ReferenceError,ReferenceError\""
+    );
+}
@@ -1,18 +1,36 @@
 use super::{Executable, Interpreter};
 use crate::{
-    builtins::{ResultValue, Value},
+    builtins::{generator::Frame, ResultValue, Value},
     syntax::ast::node::If,
 };
-use std::borrow::Borrow;
 
 impl Executable for If {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        Ok(if self.cond().run(interpreter)?.borrow().to_boolean() {
-            self.body().run(interpreter)?
+        // Step 0 is the condition, step 1 the body and step 2 the `else` branch.
+        let step = match interpreter.resume_frame() {
+            Some(frame) if frame.step > 0 => frame.step,
+            _ => {
+                let cond = self
+                    .cond()
+                    .run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+                if cond.to_boolean() {
+                    1
+                } else {
+                    2
+                }
+            }
+        };
+
+        let branch = if step == 1 {
+            self.body()
         } else if let Some(ref else_e) = self.else_node() {
-            else_e.run(interpreter)?
+            else_e
         } else {
-            Value::undefined()
-        })
+            return Ok(Value::undefined());
+        };
+        branch
+            .run(interpreter)
+            .map_err(|err| interpreter.save_frame(err, || Frame::new(step, Vec::new())))
     }
 }
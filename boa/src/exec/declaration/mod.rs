@@ -1,14 +1,17 @@
 //! Declaration execution.
 
-use super::{Executable, Interpreter};
+use super::{BindingKind, Executable, Interpreter};
 use crate::{
     builtins::{
         function::ThisMode,
+        generator::Frame,
         value::{ResultValue, Value},
     },
     environment::lexical_environment::VariableScope,
     syntax::ast::node::{
-        ArrowFunctionDecl, ConstDeclList, FunctionDecl, FunctionExpr, LetDeclList, VarDeclList,
+        ArrowFunctionDecl, AsyncArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr,
+        BindingTarget, ConstDeclList, FunctionDecl, FunctionExpr, GeneratorDecl, GeneratorExpr,
+        LetDecl, LetDeclList, Node, VarDecl, VarDeclList,
     },
     BoaProfiler,
 };
@@ -18,7 +21,7 @@ impl Executable for FunctionDecl {
         let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
         let val = interpreter.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body().clone(),
             ThisMode::NonLexical,
             true,
             true,
@@ -45,7 +48,7 @@ impl Executable for FunctionExpr {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         let val = interpreter.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body().clone(),
             ThisMode::NonLexical,
             true,
             true,
@@ -59,69 +62,170 @@ impl Executable for FunctionExpr {
     }
 }
 
-impl Executable for VarDeclList {
+impl Executable for GeneratorDecl {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        for var in self.as_ref() {
-            let val = match var.init() {
-                Some(v) => v.run(interpreter)?,
-                None => Value::undefined(),
-            };
-            let environment = &mut interpreter.realm_mut().environment;
-
-            if environment.has_binding(var.name()) {
-                if var.init().is_some() {
-                    environment.set_mutable_binding(var.name(), val, true);
-                }
-            } else {
-                environment.create_mutable_binding(
-                    var.name().to_owned(),
-                    false,
-                    VariableScope::Function,
-                );
-                environment.initialize_binding(var.name(), val);
-            }
-        }
+        let _timer = BoaProfiler::global().start_event("GeneratorDecl", "exec");
+        let val =
+            interpreter.create_generator_function(self.parameters().to_vec(), self.body().clone());
+
+        // Set the name and assign it in the current environment
+        val.set_field("name", self.name());
+        interpreter.realm_mut().environment.create_mutable_binding(
+            self.name().to_owned(),
+            false,
+            VariableScope::Function,
+        );
+
+        interpreter
+            .realm_mut()
+            .environment
+            .initialize_binding(self.name(), val);
+
         Ok(Value::undefined())
     }
 }
 
-impl Executable for ConstDeclList {
+impl Executable for GeneratorExpr {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        for decl in self.as_ref() {
-            let val = decl.init().run(interpreter)?;
-
-            interpreter
-                .realm_mut()
-                .environment
-                .create_immutable_binding(decl.name().to_owned(), false, VariableScope::Block);
-
-            interpreter
-                .realm_mut()
-                .environment
-                .initialize_binding(decl.name(), val);
+        let val =
+            interpreter.create_generator_function(self.parameters().to_vec(), self.body().clone());
+
+        if let Some(name) = self.name() {
+            val.set_field("name", Value::from(name));
         }
+
+        Ok(val)
+    }
+}
+
+impl Executable for AsyncFunctionDecl {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("AsyncFunctionDecl", "exec");
+        let val = interpreter.create_async_function(
+            self.parameters().to_vec(),
+            self.body().clone(),
+            ThisMode::NonLexical,
+        );
+
+        // Set the name and assign it in the current environment
+        val.set_field("name", self.name());
+        interpreter.realm_mut().environment.create_mutable_binding(
+            self.name().to_owned(),
+            false,
+            VariableScope::Function,
+        );
+
+        interpreter
+            .realm_mut()
+            .environment
+            .initialize_binding(self.name(), val);
+
         Ok(Value::undefined())
     }
 }
 
-impl Executable for LetDeclList {
+impl Executable for AsyncFunctionExpr {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        for var in self.as_ref() {
-            let val = match var.init() {
-                Some(v) => v.run(interpreter)?,
-                None => Value::undefined(),
-            };
-            interpreter.realm_mut().environment.create_mutable_binding(
-                var.name().to_owned(),
-                false,
-                VariableScope::Block,
-            );
-            interpreter
-                .realm_mut()
-                .environment
-                .initialize_binding(var.name(), val);
+        let val = interpreter.create_async_function(
+            self.parameters().to_vec(),
+            self.body().clone(),
+            ThisMode::NonLexical,
+        );
+
+        if let Some(name) = self.name() {
+            val.set_field("name", Value::from(name));
         }
-        Ok(Value::undefined())
+
+        Ok(val)
+    }
+}
+
+/// Runs the declarations of a `var`, `let` or `const` declaration list, calling `declare` with
+/// the declaration and the value of its initializer, if it has one.
+///
+/// In a generator body, step `2 * i` is the initializer of the declaration `i` and step `2 * i + 1`
+/// its binding, whose frame has the value.
+fn run_declarations<T, I, D>(
+    declarations: &[T],
+    init: I,
+    mut declare: D,
+    interpreter: &mut Interpreter,
+) -> ResultValue
+where
+    I: Fn(&T) -> Option<&Node>,
+    D: FnMut(&T, Option<Value>, &mut Interpreter) -> Result<(), Value>,
+{
+    let (start, mut resumed_value) = match interpreter.resume_frame() {
+        Some(mut frame) => (frame.step, frame.values.pop()),
+        None => (0, None),
+    };
+
+    for (i, declaration) in declarations.iter().enumerate().skip(start / 2) {
+        let value = match (resumed_value.take(), init(declaration)) {
+            (Some(value), _) => Some(value),
+            (None, Some(init)) => Some(
+                init.run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(2 * i, Vec::new())))?,
+            ),
+            (None, None) => None,
+        };
+        declare(declaration, value.clone(), interpreter).map_err(|err| {
+            interpreter.save_frame(err, || Frame::new(2 * i + 1, value.into_iter().collect()))
+        })?;
+    }
+    Ok(Value::undefined())
+}
+
+impl Executable for VarDeclList {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        run_declarations(
+            self.as_ref(),
+            VarDecl::init,
+            |var, value, interpreter| {
+                let value = match value {
+                    Some(value) => value,
+                    None => {
+                        // A redeclaration without an initializer keeps the current value.
+                        if let BindingTarget::Identifier(ref name) = var.target() {
+                            if interpreter.realm().environment.has_binding(name.as_ref()) {
+                                return Ok(());
+                            }
+                        }
+                        Value::undefined()
+                    }
+                };
+                var.target().bind(value, BindingKind::Var, interpreter)
+            },
+            interpreter,
+        )
+    }
+}
+
+impl Executable for ConstDeclList {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        run_declarations(
+            self.as_ref(),
+            |decl| Some(decl.init()),
+            |decl, value, interpreter| {
+                let value = value.unwrap_or_default();
+                decl.target().bind(value, BindingKind::Const, interpreter)
+            },
+            interpreter,
+        )
+    }
+}
+
+impl Executable for LetDeclList {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        run_declarations(
+            self.as_ref(),
+            LetDecl::init,
+            |var, value, interpreter| {
+                let value = value.unwrap_or_default();
+                var.target().bind(value, BindingKind::Let, interpreter)
+            },
+            interpreter,
+        )
     }
 }
 
@@ -129,10 +233,20 @@ impl Executable for ArrowFunctionDecl {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         Ok(interpreter.create_function(
             self.params().to_vec(),
-            self.body().to_vec(),
+            self.body().clone(),
             ThisMode::Lexical,
             false,
             true,
         ))
     }
 }
+
+impl Executable for AsyncArrowFunctionDecl {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        Ok(interpreter.create_async_function(
+            self.params().to_vec(),
+            self.body().clone(),
+            ThisMode::Lexical,
+        ))
+    }
+}
@@ -8,6 +8,27 @@ use crate::{
 };
 
 impl Interpreter {
+    /// Constructs an `Error` with the specified message.
+    pub fn construct_error<M>(&mut self, message: M) -> Value
+    where
+        M: Into<String>,
+    {
+        New::from(Call::new(
+            Identifier::from("Error"),
+            vec![Const::from(message.into()).into()],
+        ))
+        .run(self)
+        .expect_err("Error should always throw")
+    }
+
+    /// Throws an `Error` with the specified message.
+    pub fn throw_error<M>(&mut self, message: M) -> ResultValue
+    where
+        M: Into<String>,
+    {
+        Err(self.construct_error(message))
+    }
+
     /// Constructs a `RangeError` with the specified message.
     pub fn construct_range_error<M>(&mut self, message: M) -> Value
     where
@@ -59,7 +80,7 @@ impl Interpreter {
     {
         New::from(Call::new(
             Identifier::from("ReferenceError"),
-            vec![Const::from(message.into() + " is not defined").into()],
+            vec![Const::from(message.into()).into()],
         ))
         .run(self)
         .expect_err("ReferenceError should always throw")
@@ -1,6 +1,9 @@
 use super::{Executable, Interpreter};
 use crate::{
-    builtins::value::{ResultValue, Type},
+    builtins::{
+        generator::Frame,
+        value::{ResultValue, Type, Value},
+    },
     syntax::ast::node::{GetConstField, GetField},
 };
 
@@ -11,18 +14,39 @@ impl Executable for GetConstField {
             obj = interpreter.to_object(&obj)?;
         }
 
-        Ok(obj.get_field(self.field()))
+        interpreter.get_field(&obj, self.field())
     }
 }
 
 impl Executable for GetField {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        let mut obj = self.obj().run(interpreter)?;
+        let (mut obj, field) = evaluate_object_and_field(self, interpreter)?;
         if obj.get_type() != Type::Object || obj.get_type() != Type::Symbol {
             obj = interpreter.to_object(&obj)?;
         }
-        let field = self.field().run(interpreter)?;
+        let key = interpreter.to_property_key(&field)?;
 
-        Ok(obj.get_field(interpreter.to_string(&field)?))
+        interpreter.get_field(&obj, key)
     }
 }
+
+/// Evaluates the object and the field of a computed member expression, like `obj[field]`.
+///
+/// In a generator body, step 0 is the object and step 1 the field, whose frame has the object.
+pub(super) fn evaluate_object_and_field(
+    get_field: &GetField,
+    interpreter: &mut Interpreter,
+) -> Result<(Value, Value), Value> {
+    let obj = match interpreter.resume_frame() {
+        Some(mut frame) if frame.step == 1 => frame.values.pop().expect("the object is saved"),
+        _ => get_field
+            .obj()
+            .run(interpreter)
+            .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?,
+    };
+    let field = get_field
+        .field()
+        .run(interpreter)
+        .map_err(|err| interpreter.save_frame(err, || Frame::new(1, vec![obj.clone()])))?;
+    Ok((obj, field))
+}
@@ -7,6 +7,8 @@ impl Executable for Identifier {
             .realm()
             .environment
             .get_binding_value(self.as_ref())
-            .ok_or_else(|| interpreter.construct_reference_error(self.as_ref()))
+            .ok_or_else(|| {
+                interpreter.construct_reference_error(format!("{} is not defined", self))
+            })
     }
 }
@@ -1,88 +1,211 @@
 //! Iteration node execution.
 
-use super::{Executable, Interpreter, InterpreterState};
+use super::{operator::assign_to, BindingKind, Executable, Interpreter, InterpreterState};
 use crate::{
-    builtins::value::{ResultValue, Value},
+    builtins::{
+        generator::Frame,
+        iterable::{get_iterator, IteratorRecord},
+        property::PropertyKey,
+        value::{RcString, ResultValue, Value},
+    },
     environment::lexical_environment::new_declarative_environment,
-    syntax::ast::node::{DoWhileLoop, ForLoop, WhileLoop},
+    syntax::ast::node::{
+        Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, IterableLoopInitializer, Node,
+        WhileLoop,
+    },
     BoaProfiler,
 };
+use rustc_hash::FxHashSet;
 use std::borrow::Borrow;
 
 #[cfg(test)]
 mod tests;
 
-impl Executable for ForLoop {
-    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        // Create the block environment.
+/// Checks the state of the interpreter after running the body of a loop, and returns whether the
+/// loop should keep iterating.
+///
+/// Loops 'consume' unlabelled `break` and `continue` statements, and the labelled ones targeting
+/// one of the labels of the loop. Any other state is left for the enclosing statements.
+fn loop_continues(interpreter: &mut Interpreter, labels: &[&str]) -> bool {
+    let targets_loop = |label: &Option<String>| match label {
+        Some(label) => labels.contains(&label.as_str()),
+        None => true,
+    };
+
+    let (consumed, continues) = match interpreter.get_current_state() {
+        InterpreterState::Executing => return true,
+        InterpreterState::Continue(ref label) if targets_loop(label) => (true, true),
+        InterpreterState::Break(ref label) if targets_loop(label) => (true, false),
+        _ => (false, false),
+    };
+    if consumed {
+        interpreter.set_current_state(InterpreterState::Executing);
+    }
+    continues
+}
+
+impl ForLoop {
+    /// Runs the loop, with the given labels as the targets of `break` and `continue` statements.
+    pub(super) fn run_with_labels(
+        &self,
+        interpreter: &mut Interpreter,
+        labels: &[&str],
+    ) -> ResultValue {
         let _timer = BoaProfiler::global().start_event("ForLoop", "exec");
-        {
-            let env = &mut interpreter.realm_mut().environment;
-            env.push(new_declarative_environment(Some(
-                env.get_current_environment_ref().clone(),
-            )));
-        }
+        // Step 0 is the initialization, step 1 the condition, step 2 the body and step 3 the
+        // final expression. A loop resumed in a generator body already has its environment.
+        let step = match interpreter.resume_frame() {
+            Some(frame) => frame.step,
+            None => {
+                // Create the block environment.
+                let env = &mut interpreter.realm_mut().environment;
+                env.push(new_declarative_environment(Some(
+                    env.get_current_environment_ref().clone(),
+                )));
+                0
+            }
+        };
+
+        let result = self.run_steps(step, interpreter, labels);
 
-        if let Some(init) = self.init() {
-            init.run(interpreter)?;
+        // pop the block env, unless it is kept by a suspended generator body
+        if !interpreter.is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
         }
 
-        while self
-            .condition()
-            .map(|cond| cond.run(interpreter).map(|v| v.to_boolean()))
-            .transpose()?
-            .unwrap_or(true)
-        {
-            let result = self.body().run(interpreter)?;
+        result
+    }
 
-            match interpreter.get_current_state() {
-                InterpreterState::Break(_label) => {
-                    // TODO break to label.
+    /// Runs the loop from the given step.
+    fn run_steps(
+        &self,
+        mut step: usize,
+        interpreter: &mut Interpreter,
+        labels: &[&str],
+    ) -> ResultValue {
+        if step == 0 {
+            if let Some(init) = self.init() {
+                init.run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+            }
+            step = 1;
+        }
 
-                    // Loops 'consume' breaks.
-                    interpreter.set_current_state(InterpreterState::Executing);
-                    break;
+        loop {
+            if step == 1 {
+                if let Some(cond) = self.condition() {
+                    let cond = cond
+                        .run(interpreter)
+                        .map_err(|err| interpreter.save_frame(err, || Frame::new(1, Vec::new())))?;
+                    if !cond.to_boolean() {
+                        break;
+                    }
                 }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                _ => {
-                    // Continue execution.
+                step = 2;
+            }
+
+            if step == 2 {
+                let result = self
+                    .body()
+                    .run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(2, Vec::new())))?;
+                if !loop_continues(interpreter, labels) {
+                    if let InterpreterState::Return = interpreter.get_current_state() {
+                        return Ok(result);
+                    }
+                    break;
                 }
             }
 
             if let Some(final_expr) = self.final_expr() {
-                final_expr.run(interpreter)?;
+                final_expr
+                    .run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(3, Vec::new())))?;
             }
+            step = 1;
         }
 
-        // pop the block env
-        let _ = interpreter.realm_mut().environment.pop();
-
         Ok(Value::undefined())
     }
 }
 
-impl Executable for WhileLoop {
+impl Executable for ForLoop {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        let mut result = Value::undefined();
-        while self.cond().run(interpreter)?.borrow().to_boolean() {
-            result = self.expr().run(interpreter)?;
-            match interpreter.get_current_state() {
-                InterpreterState::Break(_label) => {
-                    // TODO break to label.
-
-                    // Loops 'consume' breaks.
-                    interpreter.set_current_state(InterpreterState::Executing);
+        self.run_with_labels(interpreter, &[])
+    }
+}
+
+impl WhileLoop {
+    /// Runs the loop, with the given labels as the targets of `break` and `continue` statements.
+    pub(super) fn run_with_labels(
+        &self,
+        interpreter: &mut Interpreter,
+        labels: &[&str],
+    ) -> ResultValue {
+        // Step 0 is the condition and step 1 the body, the frame has the result of the loop.
+        let (mut step, mut result) = match interpreter.resume_frame() {
+            Some(mut frame) => (frame.step, frame.values.remove(0)),
+            None => (0, Value::undefined()),
+        };
+
+        loop {
+            if step == 0 {
+                let cond = self.cond().run(interpreter).map_err(|err| {
+                    interpreter.save_frame(err, || Frame::new(0, vec![result.clone()]))
+                })?;
+                if !cond.to_boolean() {
                     break;
                 }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                _ => {
-                    // Continue execution.
+            }
+            step = 0;
+
+            result = self.expr().run(interpreter).map_err(|err| {
+                interpreter.save_frame(err, || Frame::new(1, vec![result.clone()]))
+            })?;
+            if !loop_continues(interpreter, labels) {
+                break;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Executable for WhileLoop {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        self.run_with_labels(interpreter, &[])
+    }
+}
+
+impl DoWhileLoop {
+    /// Runs the loop, with the given labels as the targets of `break` and `continue` statements.
+    pub(super) fn run_with_labels(
+        &self,
+        interpreter: &mut Interpreter,
+        labels: &[&str],
+    ) -> ResultValue {
+        // Step 0 is the body and step 1 the condition, the frame has the result of the loop.
+        let (mut step, mut result) = match interpreter.resume_frame() {
+            Some(mut frame) => (frame.step, frame.values.remove(0)),
+            None => (0, Value::undefined()),
+        };
+
+        loop {
+            if step == 0 {
+                result = self.body().run(interpreter).map_err(|err| {
+                    interpreter.save_frame(err, || Frame::new(0, vec![result.clone()]))
+                })?;
+                if !loop_continues(interpreter, labels) {
+                    break;
                 }
             }
+            step = 0;
+
+            let cond = self.cond().run(interpreter).map_err(|err| {
+                interpreter.save_frame(err, || Frame::new(1, vec![result.clone()]))
+            })?;
+            if !cond.to_boolean() {
+                break;
+            }
         }
         Ok(result)
     }
@@ -90,41 +213,251 @@ impl Executable for WhileLoop {
 
 impl Executable for DoWhileLoop {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        let mut result = self.body().run(interpreter)?;
-        match interpreter.get_current_state() {
-            InterpreterState::Break(_label) => {
-                // TODO break to label.
+        self.run_with_labels(interpreter, &[])
+    }
+}
+
+impl IterableLoopInitializer {
+    /// Runs an iteration of a `for...in` or `for...of` loop, binding the value of the iteration
+    /// to the head of the loop.
+    ///
+    /// `let` and `const` declarations get a new binding for each iteration, so closures created
+    /// in the body see the value of their own iteration.
+    fn run_iteration(
+        &self,
+        value: Value,
+        body: &Node,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        // Step 0 is the binding of the value and step 1 the body. An iteration resumed in a
+        // generator body already has its environment.
+        let (step, resumed) = match interpreter.resume_frame() {
+            Some(frame) => (frame.step, true),
+            None => (0, false),
+        };
+        let scoped = matches!(self, Self::Let(_) | Self::Const(_));
 
-                // Loops 'consume' breaks.
-                interpreter.set_current_state(InterpreterState::Executing);
-                return Ok(result);
+        if scoped && !resumed {
+            let env = &mut interpreter.realm_mut().environment;
+            env.push(new_declarative_environment(Some(
+                env.get_current_environment_ref().clone(),
+            )));
+        }
+
+        let result = if step == 0 {
+            self.bind(value, interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))
+        } else {
+            Ok(())
+        }
+        .and_then(|_| {
+            body.run(interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(1, Vec::new())))
+        });
+
+        // pop the iteration env, unless it is kept by a suspended generator body
+        if scoped && !interpreter.is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+
+        result
+    }
+
+    /// Binds the value of an iteration to the head of the loop.
+    fn bind(&self, value: Value, interpreter: &mut Interpreter) -> Result<(), Value> {
+        match self {
+            Self::Var(ref target) => target.bind(value, BindingKind::Var, interpreter),
+            Self::Let(ref target) => target.bind(value, BindingKind::Let, interpreter),
+            Self::Const(ref target) => target.bind(value, BindingKind::Const, interpreter),
+            Self::Expression(ref node) => assign_to(node, value, interpreter),
+        }
+    }
+}
+
+/// Gets the keys visited by a `for...in` loop over the given object.
+///
+/// The enumerable string keys of the object come first, followed by the ones of its prototypes
+/// that are not shadowed by a key seen before, enumerable or not.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-enumerate-object-properties
+fn enumerate_object_properties(
+    object: &Value,
+    interpreter: &mut Interpreter,
+) -> Result<Vec<RcString>, Value> {
+    let mut visited = FxHashSet::default();
+    let mut keys = Vec::new();
+
+    let mut current = object.clone();
+    while current.is_object() {
+        for key in interpreter.own_property_keys(&current)? {
+            if let PropertyKey::String(ref name) = key {
+                let enumerable = interpreter
+                    .get_own_property(&current, &key)?
+                    .enumerable_or(false);
+                if visited.insert(name.clone()) && enumerable {
+                    keys.push(name.clone());
+                }
             }
-            InterpreterState::Return => {
-                return Ok(result);
+        }
+        current = interpreter.get_prototype_of(&current)?;
+    }
+
+    Ok(keys)
+}
+
+impl ForInLoop {
+    /// Runs the loop, with the given labels as the targets of `break` and `continue` statements.
+    pub(super) fn run_with_labels(
+        &self,
+        interpreter: &mut Interpreter,
+        labels: &[&str],
+    ) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("ForInLoop", "exec");
+        // Step 0 is the object, and step `i + 1` the iteration of the key `i`. The frame has the
+        // object, the result of the loop and the keys.
+        let (start, object, mut result, keys) = match interpreter.resume_frame() {
+            Some(frame) if frame.step > 0 => {
+                let mut values = frame.values.into_iter();
+                let object = values.next().expect("the frame has the object");
+                let result = values.next().expect("the frame has the result");
+                let keys: Vec<RcString> = values
+                    .map(|key| key.as_string().expect("keys are strings").clone())
+                    .collect();
+                (Some(frame.step - 1), object, result, keys)
             }
             _ => {
-                // Continue execution.
+                let object = self
+                    .expr()
+                    .run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+                if object.is_null_or_undefined() {
+                    return Ok(Value::undefined());
+                }
+                let object = interpreter.to_object(&object)?;
+                let keys = enumerate_object_properties(&object, interpreter)?;
+                (None, object, Value::undefined(), keys)
+            }
+        };
+
+        for (i, key) in keys.iter().enumerate().skip(start.unwrap_or(0)) {
+            // Properties deleted before being visited are skipped.
+            let resumed = start == Some(i);
+            if !resumed && !interpreter.has_property(&object, &key.clone().into())? {
+                continue;
+            }
+
+            result = self
+                .init()
+                .run_iteration(key.clone().into(), self.body(), interpreter)
+                .map_err(|err| {
+                    interpreter.save_frame(err, || {
+                        let mut values = vec![object.clone(), result.clone()];
+                        values.extend(keys.iter().cloned().map(Value::from));
+                        Frame::new(i + 1, values)
+                    })
+                })?;
+            if !loop_continues(interpreter, labels) {
+                break;
             }
         }
 
-        while self.cond().run(interpreter)?.borrow().to_boolean() {
-            result = self.body().run(interpreter)?;
-            match interpreter.get_current_state() {
-                InterpreterState::Break(_label) => {
-                    // TODO break to label.
+        Ok(result)
+    }
+}
 
-                    // Loops 'consume' breaks.
-                    interpreter.set_current_state(InterpreterState::Executing);
-                    break;
-                }
-                InterpreterState::Return => {
-                    return Ok(result);
+impl Executable for ForInLoop {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        self.run_with_labels(interpreter, &[])
+    }
+}
+
+impl ForOfLoop {
+    /// Runs the loop, with the given labels as the targets of `break` and `continue` statements.
+    ///
+    /// The iterator is closed if the loop is left before the iterator is done.
+    pub(super) fn run_with_labels(
+        &self,
+        interpreter: &mut Interpreter,
+        labels: &[&str],
+    ) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("ForOfLoop", "exec");
+        // Step 0 is the iterable and step 1 an iteration. The frame has the iterator, its next
+        // method, the result of the loop and the value of the iteration.
+        let (iterator, mut result, mut resumed_value) = match interpreter.resume_frame() {
+            Some(frame) if frame.step > 0 => {
+                let mut values = frame.values.into_iter();
+                let mut next = || values.next().expect("the frame has the iterator state");
+                let iterator = IteratorRecord::new(next(), next());
+                (iterator, next(), Some(next()))
+            }
+            _ => {
+                let iterable = self
+                    .iterable()
+                    .run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+                let iterator = get_iterator(interpreter, &iterable)?;
+                (iterator, Value::undefined(), None)
+            }
+        };
+
+        loop {
+            let value = match resumed_value.take() {
+                Some(value) => value,
+                None => {
+                    let next = iterator.next(interpreter)?;
+                    if next.is_done() {
+                        break;
+                    }
+                    next.into_value()
                 }
-                _ => {
-                    // Continue execution.
+            };
+
+            result = match self
+                .init()
+                .run_iteration(value.clone(), self.body(), interpreter)
+            {
+                Ok(result) => result,
+                // A suspended generator body keeps iterating when it is resumed.
+                Err(err) if interpreter.is_suspending() => {
+                    return Err(interpreter.save_frame(err, || {
+                        let values = vec![
+                            iterator.iterator_object().clone(),
+                            iterator.next_function().clone(),
+                            result.clone(),
+                            value,
+                        ];
+                        Frame::new(1, values)
+                    }))
                 }
+                Err(err) => return iterator.close(Err(err), interpreter),
+            };
+
+            if !loop_continues(interpreter, labels) {
+                // Calling the `return` method of the iterator resets the state.
+                let state = interpreter.get_current_state().clone();
+                iterator.close(Ok(()), interpreter)?;
+                interpreter.set_current_state(state);
+                break;
             }
         }
+
         Ok(result)
     }
 }
+
+impl Executable for ForOfLoop {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        self.run_with_labels(interpreter, &[])
+    }
+}
+
+impl Executable for Continue {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        interpreter.set_current_state(InterpreterState::Continue(self.label().map(String::from)));
+
+        Ok(Value::undefined())
+    }
+}
@@ -99,3 +99,202 @@ fn do_loop_early_break() {
 
     assert_eq!(&exec(scenario), "3");
 }
+
+#[test]
+fn for_in_loop_key_order() {
+    let scenario = r#"
+        let obj = { b: 1, a: 2, 2: 3, 1: 4 };
+        let keys = "";
+        for (let key in obj) {
+            keys += key;
+        }
+        keys;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"12ba\"");
+}
+
+#[test]
+fn for_in_loop_prototype_chain() {
+    let scenario = r#"
+        let proto = { a: 1, b: 2 };
+        let obj = Object.create(proto);
+        obj.c = 3;
+        obj.a = 4;
+        Object.defineProperty(obj, "b", { value: 5, enumerable: false });
+        let keys = "";
+        for (var key in obj) {
+            keys += key + obj[key];
+        }
+        keys;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"c3a4\"");
+}
+
+#[test]
+fn for_in_loop_deleted_keys() {
+    let scenario = r#"
+        let obj = { a: 1, b: 2, c: 3 };
+        let keys = "";
+        for (let key in obj) {
+            delete obj.b;
+            keys += key;
+        }
+        keys;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"ac\"");
+}
+
+#[test]
+fn for_in_loop_array() {
+    let scenario = r#"
+        let array = [5, 6];
+        array.push(7);
+        array.foo = 8;
+        let keys = "";
+        for (let key in array) {
+            keys += key;
+        }
+        keys;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"012foo\"");
+}
+
+#[test]
+fn for_in_loop_string() {
+    let scenario = r#"
+        let keys = "";
+        for (let key in new String("ab")) {
+            keys += key;
+        }
+        for (let key in "cd") {
+            keys += key;
+        }
+        keys;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"0101\"");
+}
+
+#[test]
+fn for_in_loop_null() {
+    let scenario = r#"
+        let count = 0;
+        for (let key in null) {
+            count++;
+        }
+        for (let key in undefined) {
+            count++;
+        }
+        count;
+    "#;
+
+    assert_eq!(&exec(scenario), "0");
+}
+
+#[test]
+fn for_of_loop() {
+    let scenario = r#"
+        let sum = 0;
+        for (const x of [1, 2, 3]) {
+            sum += x;
+        }
+        let chars = "";
+        for (let ch of "abc") {
+            chars = ch + chars;
+        }
+        sum + chars;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"6cba\"");
+}
+
+#[test]
+fn for_of_loop_per_iteration_bindings() {
+    let scenario = r#"
+        let fns = [];
+        for (let x of [1, 2, 3]) {
+            fns.push(() => x);
+        }
+        fns[0]() + fns[1]() + fns[2]();
+    "#;
+
+    assert_eq!(&exec(scenario), "6");
+}
+
+#[test]
+fn for_of_loop_destructuring() {
+    let scenario = r#"
+        let result = "";
+        for (let [key, { value }] of [["a", { value: 1 }], ["b", { value: 2 }]]) {
+            result += key + value;
+        }
+        let obj = {};
+        for ([obj.first] of [[3]]) {}
+        result + obj.first;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"a1b23\"");
+}
+
+#[test]
+fn for_of_loop_iterator_close() {
+    let scenario = r#"
+        let closed = 0;
+        let iterable = {};
+        iterable[Symbol.iterator] = function () {
+            let i = 0;
+            return {
+                next() {
+                    i++;
+                    return { value: i, done: i > 5 };
+                },
+                return() {
+                    closed++;
+                    return {};
+                }
+            };
+        };
+
+        for (let x of iterable) {
+            if (x == 2) {
+                break;
+            }
+        }
+        for (let x of iterable) {}
+        try {
+            for (let x of iterable) {
+                throw "error";
+            }
+        } catch (e) {}
+        let f = function () {
+            for (let x of iterable) {
+                return x;
+            }
+        };
+        closed + f() * 10 + closed * 100;
+    "#;
+
+    assert_eq!(&exec(scenario), "312");
+}
+
+#[test]
+fn for_of_loop_labelled_continue() {
+    let scenario = r#"
+        let count = 0;
+        outer: for (let x of [1, 2, 3]) {
+            for (let y of [1, 2, 3]) {
+                if (y == 2) {
+                    continue outer;
+                }
+                count++;
+            }
+        }
+        count;
+    "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
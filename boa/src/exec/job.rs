@@ -0,0 +1,156 @@
+//! The job queue of the interpreter.
+//!
+//! Jobs are run after the code that enqueued them has completed, like the reactions of a promise.
+//! The interpreter never runs them on its own, the host decides when they run, by calling
+//! `Interpreter::run_jobs` or `Interpreter::run_job`.
+//!
+//! Running jobs is also when the objects kept alive by `WeakRef`s are released, and when the
+//! cleanups of the `FinalizationRegistry` objects whose registered objects have been collected are
+//! enqueued.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-jobs
+
+use super::Interpreter;
+use crate::builtins::{
+    finalization_registry::FinalizationRegistry,
+    object::{gcobject::collected_objects, GcObject, WeakGcObject},
+    value::{ResultValue, Value},
+};
+use std::fmt::{self, Debug};
+
+/// A job, waiting in the job queue of the interpreter until it is run.
+///
+/// The values captured by the job are kept alive until it runs.
+pub struct Job(Box<dyn FnOnce(&mut Interpreter) -> ResultValue>);
+
+impl Job {
+    /// Creates a new job, that runs the given function.
+    pub fn new<F>(job: F) -> Self
+    where
+        F: FnOnce(&mut Interpreter) -> ResultValue + 'static,
+    {
+        Self(Box::new(job))
+    }
+
+    /// Runs the job.
+    fn run(self, interpreter: &mut Interpreter) -> ResultValue {
+        (self.0)(interpreter)
+    }
+}
+
+impl Debug for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Job")
+    }
+}
+
+impl Interpreter {
+    /// Adds a job at the end of the job queue.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    pub fn enqueue_job(&mut self, job: Job) {
+        self.job_queue.push_back(job);
+    }
+
+    /// Checks if there are jobs waiting in the job queue.
+    #[inline]
+    pub fn has_pending_jobs(&self) -> bool {
+        !self.job_queue.is_empty()
+    }
+
+    /// Runs the job at the front of the job queue.
+    ///
+    /// The code that ran before has completed, so the objects kept alive by the `WeakRef`s it
+    /// created or dereferenced are released first, and the cleanups of the finalization registries
+    /// are enqueued if objects have been collected since the last job.
+    ///
+    /// Returns `None` if there are no pending jobs, or the completion of the job otherwise.
+    pub fn run_job(&mut self) -> Option<ResultValue> {
+        self.clear_kept_objects();
+        self.enqueue_finalization_cleanups();
+
+        let job = self.job_queue.pop_front()?;
+        let result = job.run(self);
+        self.clear_kept_objects();
+        Some(result)
+    }
+
+    /// Runs the pending jobs until the job queue is empty, including the jobs that are enqueued
+    /// while they run.
+    ///
+    /// If a job throws, the error is returned and the remaining jobs stay in the queue.
+    pub fn run_jobs(&mut self) -> Result<(), Value> {
+        while let Some(result) = self.run_job() {
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Keeps the object alive until the code that is running has completed, like the targets of
+    /// the `WeakRef`s it creates or dereferences.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-addtokeptobjects
+    pub(crate) fn add_to_kept_objects(&mut self, object: GcObject) {
+        self.kept_objects.insert(object.address(), object);
+    }
+
+    /// Releases the objects kept alive by the `WeakRef`s created or dereferenced by the code that
+    /// has run.
+    ///
+    /// This is done each time a job is run. Hosts that run code without running jobs afterwards
+    /// can call it once the code has completed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-clear-kept-objects
+    pub fn clear_kept_objects(&mut self) {
+        self.kept_objects.clear();
+    }
+
+    /// Adds a `FinalizationRegistry` object to the registries whose registered objects are checked
+    /// after garbage collections.
+    pub(crate) fn add_finalization_registry(&mut self, registry: WeakGcObject) {
+        self.finalization_registries.push(registry);
+    }
+
+    /// Enqueues a cleanup job for each finalization registry with registered objects that have
+    /// been collected, if objects with weak references to them have been collected since the last
+    /// check.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    fn enqueue_finalization_cleanups(&mut self) {
+        let collected = collected_objects();
+        if collected == self.checked_collections {
+            return;
+        }
+        self.checked_collections = collected;
+
+        self.finalization_registries.retain(WeakGcObject::is_alive);
+        let registries: Vec<GcObject> = self
+            .finalization_registries
+            .iter()
+            .filter_map(WeakGcObject::upgrade)
+            .collect();
+        for registry in registries {
+            if FinalizationRegistry::needs_cleanup(&registry) {
+                let registry = Value::Object(registry);
+                self.enqueue_job(Job::new(move |interpreter| {
+                    FinalizationRegistry::cleanup(&registry, interpreter)
+                }));
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Labelled statement execution.
+
+use super::{Executable, Interpreter, InterpreterState};
+use crate::{
+    builtins::value::ResultValue,
+    syntax::ast::node::{Labelled, Node},
+    BoaProfiler,
+};
+
+#[cfg(test)]
+mod tests;
+
+impl Executable for Labelled {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("Labelled", "exec");
+
+        // A statement can have several labels, like `a: b: while (true) {}`.
+        let mut labels = vec![self.label()];
+        let mut item = self.item();
+        while let Node::Labelled(ref labelled) = *item {
+            labels.push(labelled.label());
+            item = labelled.item();
+        }
+
+        // Loops are the targets of the `continue` statements using their labels.
+        let result = match *item {
+            Node::ForLoop(ref for_loop) => for_loop.run_with_labels(interpreter, &labels),
+            Node::ForInLoop(ref for_in) => for_in.run_with_labels(interpreter, &labels),
+            Node::ForOfLoop(ref for_of) => for_of.run_with_labels(interpreter, &labels),
+            Node::WhileLoop(ref while_loop) => while_loop.run_with_labels(interpreter, &labels),
+            Node::DoWhileLoop(ref do_while) => do_while.run_with_labels(interpreter, &labels),
+            _ => item.run(interpreter),
+        }?;
+
+        // Any statement is the target of the `break` statements using its labels.
+        let consumed = match interpreter.get_current_state() {
+            InterpreterState::Break(Some(ref label)) => labels.contains(&label.as_str()),
+            _ => false,
+        };
+        if consumed {
+            interpreter.set_current_state(InterpreterState::Executing);
+        }
+
+        Ok(result)
+    }
+}
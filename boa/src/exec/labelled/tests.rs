@@ -0,0 +1,166 @@
+use crate::exec;
+
+#[test]
+fn labelled_break_from_nested_loops() {
+    let scenario = r#"
+        let count = 0;
+        outer: for (let i = 0; i < 5; i++) {
+            for (let j = 0; j < 5; j++) {
+                if (j == 2) {
+                    break outer;
+                }
+                count++;
+            }
+        }
+        count;
+    "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn labelled_continue_from_nested_loops() {
+    let scenario = r#"
+        let count = 0;
+        outer: for (let i = 0; i < 3; i++) {
+            let j = 0;
+            while (true) {
+                j++;
+                if (j == 2) {
+                    continue outer;
+                }
+                count++;
+            }
+        }
+        count;
+    "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn labelled_continue_do_while() {
+    let scenario = r#"
+        let i = 0;
+        let count = 0;
+        outer: do {
+            i++;
+            do {
+                continue outer;
+            } while (false);
+            count++;
+        } while (i < 3);
+        i + count;
+    "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn unlabelled_continue() {
+    let scenario = r#"
+        let sum = 0;
+        for (let i = 0; i < 5; i++) {
+            if (i % 2 == 0) {
+                continue;
+            }
+            sum += i;
+        }
+        sum;
+    "#;
+
+    assert_eq!(&exec(scenario), "4");
+}
+
+#[test]
+fn labelled_block() {
+    let scenario = r#"
+        let a = 1;
+        block: {
+            a = 2;
+            break block;
+            a = 3;
+        }
+        a;
+    "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn multiple_labels() {
+    let scenario = r#"
+        let count = 0;
+        a: b: for (let i = 0; i < 3; i++) {
+            for (let j = 0; j < 3; j++) {
+                count++;
+                if (i == 0) {
+                    continue a;
+                }
+                break b;
+            }
+        }
+        count;
+    "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn continue_in_switch() {
+    let scenario = r#"
+        let result = "";
+        for (let i = 0; i < 3; i++) {
+            switch (i) {
+                case 1:
+                    continue;
+                default:
+                    result += i;
+            }
+            result += "-";
+        }
+        result;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"0-2-\"");
+}
+
+#[test]
+fn labelled_break_in_switch() {
+    let scenario = r#"
+        let count = 0;
+        outer: while (true) {
+            count++;
+            switch (count) {
+                case 3:
+                    break outer;
+                default:
+                    break;
+            }
+        }
+        count;
+    "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn continue_in_finally() {
+    let scenario = r#"
+        let result = "";
+        for (let i = 0; i < 3; i++) {
+            try {
+                if (i == 1) {
+                    continue;
+                }
+                result += i;
+            } finally {
+                result += "f";
+                result += "!";
+            }
+        }
+        result;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"0f!f!2f!\"");
+}
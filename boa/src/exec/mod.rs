@@ -1,35 +1,56 @@
 //! Execution of the AST, this is where the interpreter actually runs
 
 mod array;
+mod await_node;
 mod block;
 mod break_node;
 mod call;
+mod class;
 mod conditional;
 mod declaration;
 mod exception;
 mod field;
 mod identifier;
 mod iteration;
+mod job;
+mod labelled;
+mod module;
 mod new;
 mod object;
 mod operator;
+mod optional;
+mod pattern;
 mod return_smt;
 mod spread;
 mod statement_list;
 mod switch;
+mod template;
 #[cfg(test)]
 mod tests;
 mod throw;
 mod try_node;
+mod yield_node;
+
+pub use job::Job;
+pub use module::{ModuleLoader, ModuleNamespace};
+pub(crate) use pattern::BindingKind;
 
 use crate::{
     builtins,
     builtins::{
+        array_buffer::{ArrayBuffer, ArrayBufferData},
         function::{Function as FunctionObject, FunctionBody, ThisMode},
+        generator::{Frame, GeneratorContext},
+        iterable::IteratorPrototypes,
         number::{f64_to_int32, f64_to_uint32},
-        object::{Object, ObjectData, PROTOTYPE},
-        property::PropertyKey,
-        value::{RcBigInt, RcString, ResultValue, Type, Value},
+        object::{
+            gcobject::collected_objects, GcObject, Object, ObjectData, WeakGcObject, PROTOTYPE,
+        },
+        promise::{PromiseRejectionOperation, PromiseRejectionTracker},
+        property::{Attribute, Property, PropertyKey},
+        proxy::{Proxy, ProxyData},
+        symbol::WellKnownSymbols,
+        value::{same_value, RcBigInt, RcString, ResultValue, Type, Value},
         BigInt, Console, Number,
     },
     realm::Realm,
@@ -39,21 +60,37 @@ use crate::{
     },
     BoaProfiler,
 };
-use std::borrow::Borrow;
+use class::put_super_field;
+use gc::GcCell;
+use module::ModuleRecord;
+use rustc_hash::FxHashMap;
+use std::any::TypeId;
+use std::collections::VecDeque;
 use std::convert::TryFrom;
-use std::ops::Deref;
+use std::rc::Rc;
 
 pub trait Executable {
     /// Runs this executable in the given executor.
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue;
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum InterpreterState {
     Executing,
     Return,
     Break(Option<String>),
+    Continue(Option<String>),
+}
+/// Returns the internal state of `value` if it is a `Proxy` object.
+///
+/// The internal methods of proxies are dispatched to their handlers by the methods of the
+/// interpreter, like `Interpreter::get_field`.
+fn as_proxy(value: &Value) -> Option<ProxyData> {
+    value
+        .as_object()
+        .and_then(|object| object.as_proxy().cloned())
 }
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum PreferredType {
     String,
@@ -75,21 +112,76 @@ pub struct Interpreter {
 
     /// console object state.
     console: Console,
+
+    /// The well-known symbols, shared by all the code of the interpreter.
+    well_known_symbols: WellKnownSymbols,
+
+    /// The prototypes of the builtin iterator objects.
+    iterator_prototypes: IteratorPrototypes,
+
+    /// The `%eval%` intrinsic, which performs a direct eval when it is called as `eval(...)`.
+    eval_function: Value,
+
+    /// The state of the generator body being run, if any.
+    pub(crate) generator_context: Option<GeneratorContext>,
+
+    /// The jobs waiting to be run by the host, like the reactions of promises.
+    job_queue: VecDeque<Job>,
+
+    /// The objects kept alive until the running code has completed, by their address.
+    kept_objects: FxHashMap<usize, GcObject>,
+
+    /// The `FinalizationRegistry` objects that have been created.
+    finalization_registries: Vec<WeakGcObject>,
+
+    /// The number of collected objects when the finalization registries were last checked.
+    checked_collections: usize,
+
+    /// The function of the host tracking the promises rejected without any handler.
+    promise_rejection_tracker: Option<PromiseRejectionTracker>,
+
+    /// Whether the code being run is strict mode code.
+    strict: bool,
+
+    /// The loader of the host finding the modules imported by the code.
+    module_loader: Option<Box<dyn ModuleLoader>>,
+
+    /// The modules loaded by the interpreter, by key.
+    modules: FxHashMap<String, ModuleRecord>,
+
+    /// The prototypes of the classes defined in Rust, by the type of their native data.
+    pub(crate) class_prototypes: FxHashMap<TypeId, Value>,
 }
 
 impl Interpreter {
     /// Creates a new interpreter.
     pub fn new(realm: Realm) -> Self {
+        let (well_known_symbols, symbol_count) = WellKnownSymbols::new();
         let mut interpreter = Self {
             state: InterpreterState::Executing,
             realm,
-            symbol_count: 0,
+            symbol_count,
             console: Console::default(),
+            well_known_symbols,
+            iterator_prototypes: IteratorPrototypes::default(),
+            eval_function: Value::undefined(),
+            generator_context: None,
+            job_queue: VecDeque::new(),
+            kept_objects: FxHashMap::default(),
+            finalization_registries: Vec::new(),
+            checked_collections: collected_objects(),
+            promise_rejection_tracker: None,
+            strict: false,
+            module_loader: None,
+            modules: FxHashMap::default(),
+            class_prototypes: FxHashMap::default(),
         };
 
         // Add new builtIns to Interpreter Realm
         // At a later date this can be removed from here and called explicitly, but for now we almost always want these default builtins
         interpreter.create_intrinsics();
+        interpreter.iterator_prototypes = IteratorPrototypes::init(&mut interpreter);
+        interpreter.eval_function = interpreter.global().get_field("eval");
 
         interpreter
     }
@@ -115,10 +207,149 @@ impl Interpreter {
 
     /// Retrieves the global object of the `Realm` of this executor.
     #[inline]
-    pub(crate) fn global(&self) -> &Value {
+    pub fn global(&self) -> &Value {
         &self.realm.global_obj
     }
 
+    /// Gets the value of the global binding `name`, like a global variable, a global `let` or
+    /// `const` declaration, or a property of the global object.
+    ///
+    /// A `ReferenceError` is thrown if there is no such binding.
+    pub fn get_global(&mut self, name: &str) -> ResultValue {
+        let global_env = self
+            .realm
+            .environment
+            .environments()
+            .last()
+            .expect("there is always a global environment")
+            .clone();
+        let global_env = global_env.borrow();
+        if global_env.has_binding(name) {
+            Ok(global_env.get_binding_value(name, false))
+        } else {
+            self.throw_reference_error(format!("{} is not defined", name))
+        }
+    }
+
+    /// Checks if the code being run is strict mode code.
+    ///
+    /// <https://tc39.es/ecma262/#sec-strict-mode-code>
+    #[inline]
+    pub(crate) fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets the value of the existing binding `name`.
+    ///
+    /// Import bindings and other immutable bindings, like constants, can't be assigned to, so
+    /// assigning to one throws a `TypeError`.
+    ///
+    /// <https://tc39.es/ecma262/#sec-declarative-environment-records-setmutablebinding-n-v-s>
+    pub(crate) fn set_mutable_binding(&mut self, name: &str, value: Value) -> Result<(), Value> {
+        if self.realm.environment.is_import_binding(name) {
+            self.throw_type_error(format!("cannot assign to the import binding {}", name))?;
+        }
+        if self.realm.environment.is_immutable_binding(name) {
+            self.throw_type_error(format!("cannot assign to the constant {}", name))?;
+        }
+        self.realm
+            .environment
+            .set_mutable_binding(name, value, true);
+        Ok(())
+    }
+
+    /// Sets whether the code being run is strict mode code.
+    #[inline]
+    pub(crate) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Takes the frame of the next node of the generator body being resumed, until the body gets
+    /// back to the point it was suspended at.
+    ///
+    /// Nodes that save a frame when the body is suspended inside of them must take it as soon as
+    /// they are run.
+    #[inline]
+    pub(crate) fn resume_frame(&mut self) -> Option<Frame> {
+        self.generator_context
+            .as_mut()
+            .and_then(GeneratorContext::resume_frame)
+    }
+
+    /// Checks if the error returned by a node is the suspension of the generator body being run,
+    /// which must not be caught.
+    #[inline]
+    pub(crate) fn is_suspending(&self) -> bool {
+        self.generator_context
+            .as_ref()
+            .map_or(false, GeneratorContext::is_suspending)
+    }
+
+    /// Saves the frame of a node if the generator body being run is suspended inside of it, and
+    /// passes on the error.
+    pub(crate) fn save_frame<F>(&mut self, error: Value, frame: F) -> Value
+    where
+        F: FnOnce() -> Frame,
+    {
+        if let Some(ref mut context) = self.generator_context {
+            if context.is_suspending() {
+                context.save_frame(frame());
+            }
+        }
+        error
+    }
+
+    /// Retrieves the well-known symbols of this executor.
+    #[inline]
+    pub fn well_known_symbols(&self) -> &WellKnownSymbols {
+        &self.well_known_symbols
+    }
+
+    /// Retrieves the prototypes of the builtin iterator objects.
+    #[inline]
+    pub(crate) fn iterator_prototypes(&self) -> &IteratorPrototypes {
+        &self.iterator_prototypes
+    }
+
+    /// Retrieves the `%eval%` intrinsic.
+    #[inline]
+    pub(crate) fn eval_function(&self) -> &Value {
+        &self.eval_function
+    }
+
+    /// Sets the function called when a promise is rejected without any handler, and when a
+    /// handler is later added to such a promise.
+    ///
+    /// The function is called with the promise, its rejection reason and the operation. A
+    /// rejection that is never handled is reported with `PromiseRejectionOperation::Reject` only.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    pub fn set_promise_rejection_tracker<F>(&mut self, tracker: F)
+    where
+        F: FnMut(&Value, &Value, PromiseRejectionOperation, &mut Interpreter) + 'static,
+    {
+        self.promise_rejection_tracker = Some(PromiseRejectionTracker(Box::new(tracker)));
+    }
+
+    /// Reports a rejected promise to the promise rejection tracker of the host, if it has one.
+    pub(crate) fn track_promise_rejection(
+        &mut self,
+        promise: &Value,
+        reason: &Value,
+        operation: PromiseRejectionOperation,
+    ) {
+        // The tracker is taken out of the interpreter while it runs, so that it can use it.
+        if let Some(mut tracker) = self.promise_rejection_tracker.take() {
+            (tracker.0)(promise, reason, operation, self);
+            if self.promise_rejection_tracker.is_none() {
+                self.promise_rejection_tracker = Some(tracker);
+            }
+        }
+    }
+
     /// Generates a new `Symbol` internal hash.
     ///
     /// This currently is an incremented value.
@@ -163,7 +394,7 @@ impl Interpreter {
         let func = FunctionObject::new(
             params,
             Some(self.realm.environment.get_current_environment().clone()),
-            FunctionBody::Ordinary(body.into()),
+            FunctionBody::Ordinary(Rc::new(body.into())),
             this_mode,
             constructable,
             callable,
@@ -178,22 +409,128 @@ impl Interpreter {
         val
     }
 
+    /// Utility to create a function Value for Generator Declarations, Generator Expressions and
+    /// generator methods.
+    ///
+    /// The `prototype` property of a generator function is the prototype of the generator
+    /// objects it returns.
+    pub(crate) fn create_generator_function<P, B>(&mut self, params: P, body: B) -> Value
+    where
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        let function_prototype = self
+            .realm
+            .environment
+            .get_global_object()
+            .expect("Could not get the global object")
+            .get_field("Function")
+            .get_field(PROTOTYPE);
+
+        let proto = Value::new_object_from_prototype(
+            self.iterator_prototypes.generator().clone(),
+            ObjectData::Ordinary,
+        );
+
+        let params = params.into();
+        let params_len = params.len();
+        let func = FunctionObject::generator(
+            params,
+            self.realm.environment.get_current_environment().clone(),
+            body.into(),
+        );
+
+        let val = Value::from(Object::function(func, function_prototype));
+        val.set_field(PROTOTYPE, proto);
+        val.set_field("length", Value::from(params_len));
+
+        val
+    }
+
+    /// Utility to create a function Value for Async Function Declarations, Async Function
+    /// Expressions, Async Arrow Functions and async methods.
+    ///
+    /// Async functions are not constructors, so they don't have a `prototype` property.
+    pub(crate) fn create_async_function<P, B>(
+        &mut self,
+        params: P,
+        body: B,
+        this_mode: ThisMode,
+    ) -> Value
+    where
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        let function_prototype = self
+            .realm
+            .environment
+            .get_global_object()
+            .expect("Could not get the global object")
+            .get_field("Function")
+            .get_field(PROTOTYPE);
+
+        let params = params.into();
+        let params_len = params.len();
+        let func = FunctionObject::async_function(
+            params,
+            self.realm.environment.get_current_environment().clone(),
+            body.into(),
+            this_mode,
+        );
+
+        let val = Value::from(Object::function(func, function_prototype));
+        val.set_field("length", Value::from(params_len));
+
+        val
+    }
+
+    /// Creates an `ArrayBuffer` object that owns `data`, without copying it.
+    ///
+    /// The bytes can be accessed again from Rust with `Object::as_array_buffer`, or through the
+    /// typed arrays viewing the buffer with `TypedArrayData::with_bytes`.
+    pub fn create_array_buffer(&self, data: Vec<u8>) -> Value {
+        ArrayBuffer::create(ArrayBufferData::new(data), self)
+    }
+
+    /// Calls the function `f` with the given `this` value and arguments.
+    ///
+    /// A `TypeError` is thrown if `f` is not a function.
+    ///
     /// <https://tc39.es/ecma262/#sec-call>
-    pub(crate) fn call(
+    pub fn call(&mut self, f: &Value, this: &Value, arguments_list: &[Value]) -> ResultValue {
+        // The function is copied out of its object, so the object isn't borrowed while its body
+        // runs, and the body is free to modify it.
+        if let Some(proxy) = as_proxy(f) {
+            if proxy.is_callable() {
+                return Proxy::call(&proxy, this, arguments_list, self);
+            }
+        }
+        match f.as_object().and_then(|obj| obj.as_function().cloned()) {
+            Some(func) => func.call(f.clone(), this, arguments_list, self),
+            None => self.throw_type_error("not a function"),
+        }
+    }
+
+    /// Calls `f` as a constructor, creating an object that inherits from `new_target.prototype`.
+    ///
+    /// `new f(...arguments_list)` is a call with `f` as the `new_target`. A `TypeError` is thrown
+    /// if `f` is not a constructor.
+    ///
+    /// <https://tc39.es/ecma262/#sec-construct>
+    pub fn construct(
         &mut self,
         f: &Value,
-        this: &Value,
         arguments_list: &[Value],
+        new_target: &Value,
     ) -> ResultValue {
-        match *f {
-            Value::Object(ref obj) => {
-                let obj = obj.borrow();
-                if let ObjectData::Function(ref func) = obj.data {
-                    return func.call(f.clone(), this, arguments_list, self);
-                }
-                self.throw_type_error("not a function")
+        if let Some(proxy) = as_proxy(f) {
+            if proxy.is_constructor() {
+                return Proxy::construct(&proxy, arguments_list, new_target, self);
             }
-            _ => self.throw_type_error("not a function"),
+        }
+        match f.as_object().and_then(|obj| obj.as_function().cloned()) {
+            Some(func) => func.construct(f.clone(), new_target, arguments_list, self),
+            None => self.throw_type_error("not a constructor"),
         }
     }
 
@@ -246,6 +583,27 @@ impl Interpreter {
         }
     }
 
+    /// Converts a value to a BigInt, like the `ToBigInt` abstract operation does.
+    ///
+    /// Unlike `to_bigint`, which converts values like the `BigInt()` constructor does, Numbers
+    /// aren't converted and throw a `TypeError`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-tobigint
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_bigint_strict(&mut self, value: &Value) -> Result<RcBigInt, Value> {
+        let primitive = self.to_primitive(value, PreferredType::Number)?;
+        match primitive {
+            Value::Integer(_) | Value::Rational(_) => Err(self.construct_type_error(format!(
+                "cannot convert the number {} to a BigInt",
+                primitive
+            ))),
+            _ => self.to_bigint(&primitive),
+        }
+    }
+
     /// Converts a value to a non-negative integer if it is a valid integer index value.
     ///
     /// See: https://tc39.es/ecma262/#sec-toindex
@@ -384,55 +742,47 @@ impl Interpreter {
         Ok(self.to_number(&primitive)?)
     }
 
-    /// Converts an array object into a rust vector of values.
-    ///
-    /// This is useful for the spread operator, for any other object an `Err` is returned
-    pub(crate) fn extract_array_properties(&mut self, value: &Value) -> Result<Vec<Value>, ()> {
-        if let Value::Object(ref x) = value {
-            // Check if object is array
-            if let ObjectData::Array = x.deref().borrow().data {
-                let length = i32::from(&value.get_field("length"));
-                let values = (0..length)
-                    .map(|idx| value.get_field(idx.to_string()))
-                    .collect();
-                return Ok(values);
-            }
-            // Check if object is a Map
-            else if let ObjectData::Map(ref map) = x.deref().borrow().data {
-                let values = map
-                    .borrow()
-                    .iter()
-                    .map(|(key, value)| {
-                        // Construct a new array containing the key-value pair
-                        let array = Value::new_object(Some(
-                            &self
-                                .realm()
-                                .environment
-                                .get_global_object()
-                                .expect("Could not get global object"),
-                        ));
-                        array.set_data(ObjectData::Array);
-                        array.as_object_mut().expect("object").set_prototype(
-                            self.realm()
-                                .environment
-                                .get_binding_value("Array")
-                                .expect("Array was not initialized")
-                                .borrow()
-                                .get_field(PROTOTYPE),
-                        );
-                        array.set_field("0", key);
-                        array.set_field("1", value);
-                        array.set_field("length", Value::from(2));
-                        array
-                    })
-                    .collect();
-                return Ok(values);
-            }
-
-            return Err(());
-        }
-
-        Err(())
+    /// Copies the own enumerable properties of `source` to `target`, except for the `excluded`
+    /// ones, like the spread properties of object literals do.
+    ///
+    /// The keys and properties of `source` are read through its internal methods, so the proxy
+    /// traps are run and the indices of strings are copied. The getters of `source` are called,
+    /// and its values are defined as data properties of `target`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-copydataproperties
+    pub(crate) fn copy_data_properties(
+        &mut self,
+        target: &Value,
+        source: &Value,
+        excluded: &[PropertyKey],
+    ) -> Result<(), Value> {
+        if source.is_null_or_undefined() {
+            return Ok(());
+        }
+        let source_object = self.to_object(source)?;
+
+        let attribute = Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE;
+        for key in self.own_property_keys(&source_object)? {
+            if excluded.contains(&key) {
+                continue;
+            }
+            if !self
+                .get_own_property(&source_object, &key)?
+                .enumerable_or(false)
+            {
+                continue;
+            }
+
+            let value = self.get_field(&source_object, key.clone())?;
+            if let Some(mut object) = target.as_object_mut() {
+                object.insert_property(&key, Property::data_descriptor(value, attribute));
+            }
+        }
+
+        Ok(())
     }
 
     /// Converts an object to a primitive.
@@ -509,7 +859,7 @@ impl Interpreter {
     ///
     /// https://tc39.es/ecma262/#sec-topropertykey
     #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn to_property_key(&mut self, value: &Value) -> Result<PropertyKey, Value> {
+    pub fn to_property_key(&mut self, value: &Value) -> Result<PropertyKey, Value> {
         let key = self.to_primitive(value, PreferredType::String)?;
         if let Value::Symbol(ref symbol) = key {
             Ok(PropertyKey::from(symbol.clone()))
@@ -519,13 +869,563 @@ impl Interpreter {
         }
     }
 
-    /// https://tc39.es/ecma262/#sec-hasproperty
-    pub(crate) fn has_property(&self, obj: &Value, key: &PropertyKey) -> bool {
-        if let Some(obj) = obj.as_object() {
-            obj.has_property(key)
-        } else {
-            false
+    /// Converts an object, like the descriptor given to `Object.defineProperty`, into a property
+    /// descriptor.
+    ///
+    /// The getters of the object are called, and a `TypeError` is thrown if it describes both a
+    /// data property and an accessor property, or if its accessors are not functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-topropertydescriptor
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_property_descriptor(&mut self, value: &Value) -> Result<Property, Value> {
+        if !value.is_object() {
+            return Err(self.construct_type_error(format!(
+                "Property description must be an object: {}",
+                value
+            )));
         }
+
+        let mut property = Property::empty();
+        if self.has_property(value, &"enumerable".into())? {
+            let enumerable = self.get_field(value, "enumerable")?;
+            property.attribute.set_enumerable(enumerable.to_boolean());
+        }
+        if self.has_property(value, &"configurable".into())? {
+            let configurable = self.get_field(value, "configurable")?;
+            property.set_configurable(configurable.to_boolean());
+        }
+        if self.has_property(value, &"value".into())? {
+            property.value = Some(self.get_field(value, "value")?);
+        }
+        if self.has_property(value, &"writable".into())? {
+            let writable = self.get_field(value, "writable")?;
+            property.attribute.set_writable(writable.to_boolean());
+        }
+        if self.has_property(value, &"get".into())? {
+            let getter = self.get_field(value, "get")?;
+            if !getter.is_function() && !getter.is_undefined() {
+                return Err(
+                    self.construct_type_error(format!("Getter must be a function: {}", getter))
+                );
+            }
+            property.get = Some(getter);
+        }
+        if self.has_property(value, &"set".into())? {
+            let setter = self.get_field(value, "set")?;
+            if !setter.is_function() && !setter.is_undefined() {
+                return Err(
+                    self.construct_type_error(format!("Setter must be a function: {}", setter))
+                );
+            }
+            property.set = Some(setter);
+        }
+
+        if property.is_accessor_descriptor() && property.is_data_descriptor() {
+            return Err(self.construct_type_error(
+                "Invalid property descriptor. Cannot both specify accessors and a value or writable attribute",
+            ));
+        }
+        Ok(property)
+    }
+
+    /// Converts a property descriptor into an object, like the one returned by
+    /// `Object.getOwnPropertyDescriptor`.
+    ///
+    /// Only the fields present in the descriptor are properties of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-frompropertydescriptor
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn from_property_descriptor(&self, property: &Property) -> Value {
+        let object = Value::new_object(Some(self.global()));
+        if let Some(ref value) = property.value {
+            object.set_field("value", value.clone());
+        }
+        if property.attribute.has_writable() {
+            object.set_field("writable", property.attribute.writable());
+        }
+        if let Some(ref get) = property.get {
+            object.set_field("get", get.clone());
+        }
+        if let Some(ref set) = property.set {
+            object.set_field("set", set.clone());
+        }
+        if property.attribute.has_enumerable() {
+            object.set_field("enumerable", property.attribute.enumerable());
+        }
+        if property.attribute.has_configurable() {
+            object.set_field("configurable", property.attribute.configurable());
+        }
+        object
+    }
+
+    /// Gets the elements of an array-like object, which has a `length` and indexed properties,
+    /// like the arguments passed to `Reflect.apply`.
+    ///
+    /// A `TypeError` is thrown if `value` is not an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createlistfromarraylike
+    pub(crate) fn create_list_from_array_like(
+        &mut self,
+        value: &Value,
+    ) -> Result<Vec<Value>, Value> {
+        if !value.is_object() {
+            return Err(self.construct_type_error("cannot create a list from a non-object"));
+        }
+        let length = self.get_field(value, "length")?;
+        let length = self.to_length(&length)?;
+        let mut list = Vec::with_capacity(length);
+        for index in 0..length {
+            list.push(self.get_field(value, index.to_string())?);
+        }
+        Ok(list)
+    }
+
+    /// Checks if the object `obj` or one of its prototypes has the property `key`, like the `in`
+    /// operator does.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hasproperty
+    pub fn has_property(&mut self, obj: &Value, key: &PropertyKey) -> Result<bool, Value> {
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::has_property(&proxy, key, self);
+        }
+        let prototype = match obj.as_object() {
+            Some(object) if object.typed_array_index(key).is_some() => {
+                return Ok(object.has_property(key))
+            }
+            Some(object) if !object.get_own_property(key).is_none() => return Ok(true),
+            Some(object) => object.get_prototype_of(),
+            None => return Ok(false),
+        };
+        self.has_property(&prototype, key)
+    }
+
+    /// Gets the own property `key` of the object `obj`, or an empty property if it doesn't have
+    /// it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getownproperty-p
+    pub fn get_own_property(&mut self, obj: &Value, key: &PropertyKey) -> Result<Property, Value> {
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::get_own_property(&proxy, key, self);
+        }
+        Ok(obj
+            .as_object()
+            .map_or_else(Property::empty, |object| object.get_own_property(key)))
+    }
+
+    /// Gets the prototype of the object `obj`, like `Reflect.getPrototypeOf` does.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getprototypeof
+    pub fn get_prototype_of(&mut self, obj: &Value) -> ResultValue {
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::get_prototype_of(&proxy, self);
+        }
+        match obj.as_object() {
+            Some(object) => Ok(object.get_prototype_of()),
+            None => self.throw_type_error("cannot get the prototype of a non-object"),
+        }
+    }
+
+    /// Sets the prototype of the object `obj`, like `Reflect.setPrototypeOf` does.
+    ///
+    /// Returns `false` if the prototype can't be changed, because the object isn't extensible or
+    /// the prototype chain would contain a cycle.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-setprototypeof-v
+    pub fn set_prototype_of(&mut self, obj: &Value, prototype: Value) -> Result<bool, Value> {
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::set_prototype_of(&proxy, prototype, self);
+        }
+        let object = match obj {
+            Value::Object(ref object) => object,
+            _ => return Err(self.construct_type_error("cannot set the prototype of a non-object")),
+        };
+        let current = object.borrow().get_prototype_of();
+        if same_value(&current, &prototype) {
+            return Ok(true);
+        }
+        if !object.borrow().is_extensible() {
+            return Ok(false);
+        }
+
+        // The prototype chain can't contain a cycle, which is only checked up to the first
+        // proxy, whose prototype is not known without calling its handler.
+        let mut parent = prototype.clone();
+        while let Value::Object(ref current) = parent {
+            if GcObject::equals(current, object) {
+                return Ok(false);
+            }
+            if current.borrow().is_proxy() {
+                break;
+            }
+            let next = current.borrow().get_prototype_of();
+            parent = next;
+        }
+        object.borrow_mut().set_prototype(prototype);
+        Ok(true)
+    }
+
+    /// Checks if the value is an array, or a proxy of one, like `Array.isArray` does.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isarray
+    pub fn is_array(&mut self, value: &Value) -> Result<bool, Value> {
+        if let Some(proxy) = as_proxy(value) {
+            return Proxy::is_array(&proxy, self);
+        }
+        Ok(value.as_object().map_or(false, |object| object.is_array()))
+    }
+
+    /// Checks if properties can be added to the object `obj`, like `Reflect.isExtensible` does.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-isextensible
+    pub fn is_extensible(&mut self, obj: &Value) -> Result<bool, Value> {
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::is_extensible(&proxy, self);
+        }
+        match obj.as_object() {
+            Some(object) => Ok(object.is_extensible()),
+            None => Err(self.construct_type_error("cannot check if a non-object is extensible")),
+        }
+    }
+
+    /// Prevents properties from being added to the object `obj`, like
+    /// `Reflect.preventExtensions` does.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-preventextensions
+    pub fn prevent_extensions(&mut self, obj: &Value) -> Result<bool, Value> {
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::prevent_extensions(&proxy, self);
+        }
+        match obj.as_object_mut() {
+            Some(mut object) => Ok(object.prevent_extensions()),
+            None => Err(self.construct_type_error("cannot prevent extensions of a non-object")),
+        }
+    }
+
+    /// Gets the value of a property of an object, calling its getter with the object as `this`
+    /// if it is an accessor property, including getters inherited from its prototypes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-o-p
+    pub fn get_field<K>(&mut self, obj: &Value, key: K) -> ResultValue
+    where
+        K: Into<PropertyKey>,
+    {
+        self.get_field_with_receiver(obj, key, obj)
+    }
+
+    /// Gets the value of a property of an object, calling its getter with `receiver` as `this`
+    /// if it is an accessor property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryget
+    pub(crate) fn get_field_with_receiver<K>(
+        &mut self,
+        obj: &Value,
+        key: K,
+        receiver: &Value,
+    ) -> ResultValue
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        // The exports of a module namespace object are read from the bindings of the module.
+        let namespace = obj.as_object().and_then(|object| match object.data {
+            ObjectData::ModuleNamespace(ref namespace) => Some(namespace.clone()),
+            _ => None,
+        });
+        if let Some(value) =
+            namespace.and_then(|namespace| self.get_module_namespace_field(&namespace, &key))
+        {
+            return value;
+        }
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::get(&proxy, &key, receiver, self);
+        }
+
+        let (property, prototype) = match obj.as_object() {
+            // The elements of typed arrays are never looked up on their prototypes.
+            Some(object) if object.typed_array_index(&key).is_some() => {
+                let element = object
+                    .typed_array_index(&key)
+                    .and_then(|(array, index)| array.get_element(index));
+                return Ok(element.unwrap_or_default());
+            }
+            Some(object) => (object.get_own_property(&key), object.get_prototype_of()),
+            // Primitives have properties that aren't stored in objects, like the `length` of
+            // strings.
+            None => return Ok(obj.get_field(key)),
+        };
+        if property.is_none() {
+            if prototype.is_object() {
+                return self.get_field_with_receiver(&prototype, key, receiver);
+            }
+            return Ok(Value::undefined());
+        }
+        if property.is_accessor_descriptor() {
+            return match property.get {
+                Some(ref getter) if !getter.is_undefined() => self.call(getter, receiver, &[]),
+                _ => Ok(Value::undefined()),
+            };
+        }
+        Ok(property.value.clone().unwrap_or_default())
+    }
+
+    /// Sets the value of a property of an object, calling its setter with the object as `this`
+    /// if it is an accessor property, including setters inherited from its prototypes.
+    ///
+    /// Returns `false` if the property can't be set, because it is read-only or an accessor
+    /// without a setter.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryset
+    pub fn set_field<K, V>(&mut self, obj: &Value, key: K, value: V) -> Result<bool, Value>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        self.set_field_with_receiver(obj, key, value.into(), obj)
+    }
+
+    /// Sets the value of a property of an object, calling its setter with `receiver` as `this`
+    /// if it is an accessor property, or setting the property of `receiver` if it is a data
+    /// property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinarysetwithowndescriptor
+    pub(crate) fn set_field_with_receiver<K>(
+        &mut self,
+        obj: &Value,
+        key: K,
+        value: Value,
+        receiver: &Value,
+    ) -> Result<bool, Value>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::set(&proxy, &key, value, receiver, self);
+        }
+
+        // The elements of typed arrays are set after converting the value to their type, and
+        // setting an element that is out of bounds is ignored.
+        let element = obj.as_object().and_then(|object| {
+            object
+                .typed_array_index(&key)
+                .map(|(array, index)| (array.kind(), index))
+        });
+        if let Some((kind, index)) = element {
+            if same_value(obj, receiver) {
+                let value = kind.to_element_value(&value, self)?;
+                if let Some(object) = obj.as_object() {
+                    if let Some((array, _)) = object.typed_array_index(&key) {
+                        array.set_element(index, &value);
+                    }
+                }
+                return Ok(true);
+            }
+            let valid = obj
+                .as_object()
+                .map_or(false, |object| object.has_property(&key));
+            if !valid {
+                return Ok(true);
+            }
+        }
+
+        let (property, prototype) = match obj.as_object() {
+            Some(object) => (object.get_own_property(&key), object.get_prototype_of()),
+            None => (Property::empty(), Value::null()),
+        };
+        if property.is_none() && prototype.is_object() {
+            return self.set_field_with_receiver(&prototype, key, value, receiver);
+        }
+        if property.is_accessor_descriptor() {
+            return match property.set {
+                Some(ref setter) if !setter.is_undefined() => {
+                    self.call(setter, receiver, &[value])?;
+                    Ok(true)
+                }
+                _ => Ok(false),
+            };
+        }
+        if !property.is_none() && !property.writable() {
+            return Ok(false);
+        }
+
+        // The property is set on the receiver, which isn't the object the property was found on
+        // if it was inherited, or if the receiver was given to `Reflect.set`.
+        if receiver
+            .as_object()
+            .map_or(false, |object| object.is_proxy())
+        {
+            let existing = self.get_own_property(receiver, &key)?;
+            if existing.is_none() {
+                let property = Property::data_descriptor(
+                    value,
+                    Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                );
+                return self.define_property(receiver, key, property);
+            }
+            if existing.is_accessor_descriptor() || !existing.writable() {
+                return Ok(false);
+            }
+            return self.define_property(receiver, key, Property::empty().value(value));
+        }
+        if let Some(existing) = receiver
+            .as_object()
+            .map(|object| object.get_own_property(&key))
+        {
+            if existing.is_accessor_descriptor() || (!existing.is_none() && !existing.writable()) {
+                return Ok(false);
+            }
+        }
+        receiver.set_field(key, value);
+        Ok(true)
+    }
+
+    /// Defines the own property `key` of the object `obj`, like `Reflect.defineProperty` does.
+    ///
+    /// Returns `false` if the property can't be defined, because the object isn't extensible or
+    /// the property already exists and can't be redefined. A `TypeError` is thrown if `obj` is
+    /// not an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-definepropertyorthrow
+    pub fn define_property<K>(
+        &mut self,
+        obj: &Value,
+        key: K,
+        property: Property,
+    ) -> Result<bool, Value>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::define_own_property(&proxy, &key, property, self);
+        }
+        // The value of an element of a typed array is converted to its type first.
+        let kind = obj.as_object().and_then(|object| {
+            object
+                .typed_array_index(&key)
+                .map(|(array, _)| array.kind())
+        });
+        let property = match (kind, property.value.clone()) {
+            (Some(kind), Some(value)) => {
+                let value = kind.to_element_value(&value, self)?;
+                property.value(value)
+            }
+            _ => property,
+        };
+        match obj.as_object_mut() {
+            Some(mut object) => Ok(object.define_own_property(&key, property)),
+            None => Err(self.construct_type_error("cannot define a property of a non-object")),
+        }
+    }
+
+    /// Deletes the own property `key` of the object `obj`, like `Reflect.deleteProperty` does.
+    ///
+    /// Returns `false` if the property can't be deleted, because it isn't configurable. A
+    /// `TypeError` is thrown if `obj` is not an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-delete-p
+    pub fn delete_property<K>(&mut self, obj: &Value, key: K) -> Result<bool, Value>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        if let Some(proxy) = as_proxy(obj) {
+            return Proxy::delete(&proxy, &key, self);
+        }
+        match obj.as_object_mut() {
+            Some(mut object) => Ok(object.delete(&key)),
+            None => Err(self.construct_type_error("cannot delete a property of a non-object")),
+        }
+    }
+
+    /// Gets the keys of the own properties of `obj`, converted to an object, in the order of
+    /// `[[OwnPropertyKeys]]`.
+    ///
+    /// A `TypeError` is thrown if `obj` is `null` or `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+    pub fn own_property_keys(&mut self, obj: &Value) -> Result<Vec<PropertyKey>, Value> {
+        let object = self.to_object(obj)?;
+        if let Some(proxy) = as_proxy(&object) {
+            return Proxy::own_property_keys(&proxy, self);
+        }
+        let keys = object
+            .as_object()
+            .map(|object| object.own_property_keys())
+            .unwrap_or_default();
+        Ok(keys)
+    }
+
+    /// Sets the value of a property like an assignment does, which throws a `TypeError` in strict
+    /// mode code if the property can't be set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-putvalue
+    pub(crate) fn put_field<K, V>(&mut self, obj: &Value, key: K, value: V) -> Result<(), Value>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        let key = key.into();
+        if !self.set_field(obj, key.clone(), value)? && self.strict {
+            return Err(
+                self.construct_type_error(format!("cannot assign to read-only property '{}'", key))
+            );
+        }
+        Ok(())
     }
 
     /// The abstract operation ToObject converts argument to a value of type Object
@@ -618,21 +1518,19 @@ impl Interpreter {
     fn set_value(&mut self, node: &Node, value: Value) -> ResultValue {
         match node {
             Node::Identifier(ref name) => {
-                self.realm
-                    .environment
-                    .set_mutable_binding(name.as_ref(), value.clone(), true);
+                if self.strict && !self.realm.environment.has_binding(name.as_ref()) {
+                    return self.throw_reference_error(format!("{} is not defined", name));
+                }
+                self.set_mutable_binding(name.as_ref(), value.clone())?;
                 Ok(value)
             }
-            Node::GetConstField(ref get_const_field_node) => Ok(get_const_field_node
-                .obj()
-                .run(self)?
-                .set_field(get_const_field_node.field(), value)),
-            Node::GetField(ref get_field) => {
-                let field = get_field.field().run(self)?;
+            Node::GetSuperField(ref get_super_field) => {
+                let field = get_super_field.field().run(self)?;
                 let key = self.to_property_key(&field)?;
-                Ok(get_field.obj().run(self)?.set_field(key, value))
+                put_super_field(key, value.clone(), self)?;
+                Ok(value)
             }
-            _ => panic!("TypeError: invalid assignment to {}", node),
+            _ => self.throw_reference_error(format!("invalid assignment to {}", node)),
         }
     }
 
@@ -695,18 +1593,32 @@ impl Executable for Node {
             Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(interpreter),
             Node::GetField(ref get_field) => get_field.run(interpreter),
             Node::Call(ref call) => call.run(interpreter),
+            Node::ClassDecl(ref decl) => decl.run(interpreter),
+            Node::ClassExpr(ref expr) => expr.run(interpreter),
+            Node::SuperCall(ref call) => call.run(interpreter),
+            Node::GetSuperField(ref get_super_field) => get_super_field.run(interpreter),
+            Node::TemplateLit(ref template) => template.run(interpreter),
+            Node::TaggedTemplate(ref template) => template.run(interpreter),
             Node::WhileLoop(ref while_loop) => while_loop.run(interpreter),
             Node::DoWhileLoop(ref do_while) => do_while.run(interpreter),
             Node::ForLoop(ref for_loop) => for_loop.run(interpreter),
+            Node::ForInLoop(ref for_in) => for_in.run(interpreter),
+            Node::ForOfLoop(ref for_of) => for_of.run(interpreter),
             Node::If(ref if_smt) => if_smt.run(interpreter),
             Node::Switch(ref switch) => switch.run(interpreter),
             Node::Object(ref obj) => obj.run(interpreter),
+            Node::Optional(ref optional) => optional.run(interpreter),
             Node::ArrayDecl(ref arr) => arr.run(interpreter),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionDecl(ref decl) => decl.run(interpreter),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionExpr(ref function_expr) => function_expr.run(interpreter),
             Node::ArrowFunctionDecl(ref decl) => decl.run(interpreter),
+            Node::GeneratorDecl(ref decl) => decl.run(interpreter),
+            Node::GeneratorExpr(ref expr) => expr.run(interpreter),
+            Node::AsyncFunctionDecl(ref decl) => decl.run(interpreter),
+            Node::AsyncFunctionExpr(ref expr) => expr.run(interpreter),
+            Node::AsyncArrowFunctionDecl(ref decl) => decl.run(interpreter),
             Node::BinOp(ref op) => op.run(interpreter),
             Node::UnaryOp(ref op) => op.run(interpreter),
             Node::New(ref call) => call.run(interpreter),
@@ -717,12 +1629,28 @@ impl Executable for Node {
             Node::LetDeclList(ref decl) => decl.run(interpreter),
             Node::ConstDeclList(ref decl) => decl.run(interpreter),
             Node::Spread(ref spread) => spread.run(interpreter),
+            Node::Empty => Ok(Value::undefined()),
             Node::This => {
-                // Will either return `this` binding or undefined
-                Ok(interpreter.realm().environment.get_this_binding())
+                let env = interpreter.realm().environment.get_this_environment();
+                let env = GcCell::borrow(env);
+                match env.as_function_environment_record() {
+                    Some(record) if !record.is_this_initialized() => {
+                        drop(env);
+                        interpreter.throw_reference_error(
+                            "must call super constructor before accessing 'this'",
+                        )
+                    }
+                    _ => Ok(env.get_this_binding()),
+                }
             }
             Node::Try(ref try_node) => try_node.run(interpreter),
             Node::Break(ref break_node) => break_node.run(interpreter),
+            Node::Continue(ref continue_node) => continue_node.run(interpreter),
+            Node::Labelled(ref labelled) => labelled.run(interpreter),
+            Node::Yield(ref yield_expr) => yield_expr.run(interpreter),
+            Node::Await(ref await_expr) => await_expr.run(interpreter),
+            Node::ImportDecl(ref import) => import.run(interpreter),
+            Node::ExportDecl(ref export) => export.run(interpreter),
             ref i => unimplemented!("{:?}", i),
         }
     }
@@ -0,0 +1,705 @@
+//! Loading, linking and evaluation of modules.
+//!
+//! The interpreter doesn't know where modules come from, the host gives it a `ModuleLoader` that
+//! resolves the specifiers of `import` declarations and fetches the source code of the modules.
+//! Each module is only loaded and evaluated once, and is identified by the key its specifier
+//! resolves to.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
+
+#[cfg(test)]
+mod tests;
+
+use super::{Executable, Interpreter, InterpreterState};
+use crate::{
+    builtins::{
+        object::ObjectData,
+        property::{Attribute, Property, PropertyKey},
+        value::{ResultValue, Value},
+    },
+    environment::{
+        lexical_environment::{
+            new_module_environment, Environment, LexicalEnvironment, VariableScope,
+        },
+        module_environment_record::ImportBinding,
+    },
+    syntax::{
+        ast::node::{ExportDecl, ImportDecl, ImportSpecifier, Module, Node, DEFAULT_BINDING},
+        lexer::Lexer,
+        parser::Parser,
+    },
+    BoaProfiler,
+};
+use gc::{Finalize, Trace};
+use rustc_hash::FxHashMap;
+use std::{fmt::Debug, mem, rc::Rc};
+
+/// The host hook used by the interpreter to find the modules imported by the code it runs.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+pub trait ModuleLoader: Debug {
+    /// Resolves the specifier of an imported module to the key identifying the module, like its
+    /// absolute path.
+    ///
+    /// The `referrer` is the key of the module that contains the `import` declaration, or `None`
+    /// if the module is imported by the host.
+    fn resolve(&self, specifier: &str, referrer: Option<&str>) -> Result<String, String>;
+
+    /// Fetches the source code of the module identified by `key`.
+    fn fetch(&self, key: &str) -> Result<String, String>;
+}
+
+/// The progress of a module through linking and evaluation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModuleStatus {
+    Unlinked,
+    Linking,
+    Linked,
+    Evaluating,
+    Evaluated,
+}
+
+/// A loaded module.
+#[derive(Debug)]
+pub(crate) struct ModuleRecord {
+    /// The parsed code of the module.
+    module: Rc<Module>,
+    /// The environment holding the bindings of the module.
+    environment: Environment,
+    /// The specifiers of the modules requested by the module, with their keys, in the order
+    /// the modules are evaluated.
+    requested_modules: Vec<(String, String)>,
+    status: ModuleStatus,
+    /// The error thrown by the evaluation of the module, thrown again by later imports.
+    error: Option<Value>,
+    /// The namespace object of the module, created the first time it is needed.
+    namespace: Option<Value>,
+}
+
+impl ModuleRecord {
+    /// Gets the key of a module requested by this module.
+    fn requested_module(&self, specifier: &str) -> &str {
+        self.requested_modules
+            .iter()
+            .find(|(requested, _)| requested == specifier)
+            .map(|(_, key)| key.as_str())
+            .expect("the requested modules are resolved when the module is loaded")
+    }
+}
+
+/// The binding an exported name refers to.
+#[derive(Debug, Clone, PartialEq)]
+enum ResolvedBinding {
+    /// A binding of the environment of a module.
+    Binding { module: String, name: String },
+    /// The namespace object of a module, exported by `export * as ns from "module";`.
+    Namespace(String),
+}
+
+/// The result of looking up an exported name.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+#[derive(Debug, Clone, PartialEq)]
+enum ExportResolution {
+    NotFound,
+    /// The name is exported by more than one `export * from "module";` declaration.
+    Ambiguous,
+    Resolved(ResolvedBinding),
+}
+
+/// The internal state of a module namespace object, which gives access to the exports of a
+/// module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ModuleNamespace {
+    exports: FxHashMap<String, NamespaceExport>,
+}
+
+/// An export of a module namespace object.
+#[derive(Debug, Trace, Finalize, Clone)]
+enum NamespaceExport {
+    Binding(ImportBinding),
+    /// The namespace object of the module with the given key.
+    Namespace(String),
+}
+
+impl Interpreter {
+    /// Sets the loader used to find the modules imported by the code run by the interpreter.
+    pub fn set_module_loader<L>(&mut self, loader: L)
+    where
+        L: ModuleLoader + 'static,
+    {
+        self.module_loader = Some(Box::new(loader));
+    }
+
+    /// Imports a module, loading, linking and evaluating it and the modules it imports, and
+    /// returns its namespace object.
+    ///
+    /// A module that was already imported isn't evaluated again.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
+    pub fn import_module(&mut self, specifier: &str) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("import_module", "exec");
+        let key = self.resolve_module(specifier, None)?;
+        self.load_module(&key)?;
+        self.link_module(&key)?;
+        self.evaluate_module(&key)?;
+        Ok(self.module_namespace(&key))
+    }
+
+    /// Resolves the specifier of a module with the module loader.
+    fn resolve_module(&mut self, specifier: &str, referrer: Option<&str>) -> Result<String, Value> {
+        let resolved = match self.module_loader {
+            Some(ref loader) => loader.resolve(specifier, referrer),
+            None => Err("no module loader".to_owned()),
+        };
+        resolved.map_err(|err| {
+            self.construct_type_error(format!("cannot resolve module '{}': {}", specifier, err))
+        })
+    }
+
+    /// Fetches and parses the module identified by `key` and the modules it requests, if they
+    /// aren't already loaded.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-LoadRequestedModules
+    fn load_module(&mut self, key: &str) -> Result<(), Value> {
+        if self.modules.contains_key(key) {
+            return Ok(());
+        }
+
+        let fetched = match self.module_loader {
+            Some(ref loader) => loader.fetch(key),
+            None => Err("no module loader".to_owned()),
+        };
+        let source = fetched.map_err(|err| {
+            self.construct_type_error(format!("cannot load module '{}': {}", key, err))
+        })?;
+        let module = parse_module(&source)
+            .map_err(|err| self.construct_syntax_error(format!("in module '{}': {}", key, err)))?;
+
+        let mut requested_modules = Vec::new();
+        for specifier in module.requested_modules() {
+            let requested = self.resolve_module(specifier, Some(key))?;
+            requested_modules.push((specifier.to_owned(), requested));
+        }
+
+        let global_env = self
+            .realm
+            .environment
+            .environments()
+            .last()
+            .expect("there is always a global environment")
+            .clone();
+        self.modules.insert(
+            key.to_owned(),
+            ModuleRecord {
+                module: Rc::new(module),
+                environment: new_module_environment(global_env),
+                requested_modules: requested_modules.clone(),
+                status: ModuleStatus::Unlinked,
+                error: None,
+                namespace: None,
+            },
+        );
+
+        for (_, requested) in requested_modules {
+            self.load_module(&requested)?;
+        }
+        Ok(())
+    }
+
+    /// Links a loaded module and the modules it requests, by creating the import bindings of
+    /// their environments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduledeclarationlinking
+    fn link_module(&mut self, key: &str) -> Result<(), Value> {
+        let result = self.link_module_inner(key);
+        if result.is_err() {
+            // Modules that failed to link can be linked again by a later import.
+            for record in self.modules.values_mut() {
+                if record.status == ModuleStatus::Linking {
+                    record.status = ModuleStatus::Unlinked;
+                }
+            }
+        }
+        result
+    }
+
+    fn link_module_inner(&mut self, key: &str) -> Result<(), Value> {
+        let record = self.modules.get_mut(key).expect("the module is loaded");
+        if record.status != ModuleStatus::Unlinked {
+            return Ok(());
+        }
+        record.status = ModuleStatus::Linking;
+
+        let requested = record.requested_modules.clone();
+        for (_, requested) in requested {
+            self.link_module_inner(&requested)?;
+        }
+
+        self.initialize_module_environment(key)?;
+        self.modules
+            .get_mut(key)
+            .expect("the module is loaded")
+            .status = ModuleStatus::Linked;
+        Ok(())
+    }
+
+    /// Checks that the names re-exported by a module exist, and creates the import bindings of
+    /// its environment.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment
+    fn initialize_module_environment(&mut self, key: &str) -> Result<(), Value> {
+        let record = &self.modules[key];
+        let module = record.module.clone();
+        let environment = record.environment.clone();
+
+        for node in module.body().statements() {
+            match node {
+                Node::ExportDecl(ExportDecl::Named(ref specifiers, Some(ref specifier))) => {
+                    let requested = self.requested_module(key, specifier);
+                    for export in specifiers.iter() {
+                        self.resolve_import(&requested, specifier, export.local())?;
+                    }
+                }
+                Node::ImportDecl(ref import) => {
+                    let requested = self.requested_module(key, import.module());
+                    for import_specifier in import.specifiers() {
+                        let local = import_specifier.local().to_owned();
+                        let binding = match import_specifier {
+                            ImportSpecifier::Namespace(_) => {
+                                ResolvedBinding::Namespace(requested.clone())
+                            }
+                            ImportSpecifier::Default(_) => {
+                                self.resolve_import(&requested, import.module(), "default")?
+                            }
+                            ImportSpecifier::Named { ref imported, .. } => {
+                                self.resolve_import(&requested, import.module(), imported)?
+                            }
+                        };
+
+                        match binding {
+                            ResolvedBinding::Binding { module, name } => {
+                                let module = self.modules[&module].environment.clone();
+                                let created = environment
+                                    .borrow_mut()
+                                    .as_module_environment_record_mut()
+                                    .expect("modules have a module environment")
+                                    .create_import_binding(local, module, name);
+                                if let Err(message) = created {
+                                    return Err(self.construct_syntax_error(message));
+                                }
+                            }
+                            ResolvedBinding::Namespace(module) => {
+                                let namespace = self.module_namespace(&module);
+                                let mut environment = environment.borrow_mut();
+                                environment.create_immutable_binding(local.clone(), true);
+                                environment.initialize_binding(&local, namespace);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the key of a module requested by the module identified by `key`.
+    fn requested_module(&self, key: &str, specifier: &str) -> String {
+        self.modules[key].requested_module(specifier).to_owned()
+    }
+
+    /// Resolves a name imported from a module, throwing a `SyntaxError` if the module doesn't
+    /// export it.
+    fn resolve_import(
+        &mut self,
+        module: &str,
+        specifier: &str,
+        name: &str,
+    ) -> Result<ResolvedBinding, Value> {
+        match self.resolve_export(module, name, &mut Vec::new()) {
+            ExportResolution::Resolved(binding) => Ok(binding),
+            ExportResolution::NotFound => Err(self.construct_syntax_error(format!(
+                "the module '{}' doesn't export '{}'",
+                specifier, name
+            ))),
+            ExportResolution::Ambiguous => Err(self.construct_syntax_error(format!(
+                "the module '{}' exports '{}' more than once",
+                specifier, name
+            ))),
+        }
+    }
+
+    /// Finds the binding a name exported by a module refers to, following re-exports.
+    ///
+    /// The `resolve_set` holds the exports already being resolved, to stop on circular
+    /// re-exports.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+    fn resolve_export(
+        &self,
+        key: &str,
+        name: &str,
+        resolve_set: &mut Vec<(String, String)>,
+    ) -> ExportResolution {
+        if resolve_set
+            .iter()
+            .any(|(module, export)| module == key && export == name)
+        {
+            return ExportResolution::NotFound;
+        }
+        resolve_set.push((key.to_owned(), name.to_owned()));
+
+        let record = &self.modules[key];
+        let exports = record.module.body().statements().iter().filter_map(|node| {
+            if let Node::ExportDecl(ref export) = node {
+                Some(export)
+            } else {
+                None
+            }
+        });
+
+        let mut star_exports = Vec::new();
+        for export in exports {
+            match export {
+                ExportDecl::Declaration(_) | ExportDecl::Default(_) => {
+                    if export.exported_names().contains(&name) {
+                        let local = match export {
+                            ExportDecl::Default(_) => export.bound_names()[0],
+                            _ => name,
+                        };
+                        return ExportResolution::Resolved(ResolvedBinding::Binding {
+                            module: key.to_owned(),
+                            name: local.to_owned(),
+                        });
+                    }
+                }
+                ExportDecl::Named(ref specifiers, ref specifier) => {
+                    let export = specifiers.iter().find(|export| export.exported() == name);
+                    match (export, specifier) {
+                        (Some(export), None) => {
+                            return ExportResolution::Resolved(ResolvedBinding::Binding {
+                                module: key.to_owned(),
+                                name: export.local().to_owned(),
+                            })
+                        }
+                        (Some(export), Some(specifier)) => {
+                            let requested = record.requested_module(specifier);
+                            return self.resolve_export(requested, export.local(), resolve_set);
+                        }
+                        (None, _) => {}
+                    }
+                }
+                ExportDecl::All(Some(ref namespace), ref specifier) => {
+                    if namespace.as_ref() == name {
+                        let requested = record.requested_module(specifier);
+                        return ExportResolution::Resolved(ResolvedBinding::Namespace(
+                            requested.to_owned(),
+                        ));
+                    }
+                }
+                ExportDecl::All(None, ref specifier) => {
+                    star_exports.push(record.requested_module(specifier));
+                }
+            }
+        }
+
+        // The default export is never re-exported by `export * from "module";`.
+        if name == "default" {
+            return ExportResolution::NotFound;
+        }
+
+        let mut star_resolution = ExportResolution::NotFound;
+        for requested in star_exports {
+            match self.resolve_export(requested, name, resolve_set) {
+                ExportResolution::Ambiguous => return ExportResolution::Ambiguous,
+                ExportResolution::NotFound => {}
+                resolution => {
+                    if star_resolution == ExportResolution::NotFound {
+                        star_resolution = resolution;
+                    } else if star_resolution != resolution {
+                        return ExportResolution::Ambiguous;
+                    }
+                }
+            }
+        }
+        star_resolution
+    }
+
+    /// Gets the names exported by a module, including the names re-exported by its
+    /// `export * from "module";` declarations.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getexportednames
+    fn exported_names(&self, key: &str, export_star_set: &mut Vec<String>) -> Vec<String> {
+        if export_star_set.iter().any(|module| module == key) {
+            return Vec::new();
+        }
+        export_star_set.push(key.to_owned());
+
+        let record = &self.modules[key];
+        let mut names = Vec::new();
+        for node in record.module.body().statements() {
+            if let Node::ExportDecl(ref export) = node {
+                if let ExportDecl::All(None, ref specifier) = export {
+                    let requested = record.requested_module(specifier);
+                    for name in self.exported_names(requested, export_star_set) {
+                        if name != "default" && !names.contains(&name) {
+                            names.push(name);
+                        }
+                    }
+                } else {
+                    names.extend(export.exported_names().into_iter().map(str::to_owned));
+                }
+            }
+        }
+        names
+    }
+
+    /// Gets the namespace object of a linked module, creating it the first time.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    fn module_namespace(&mut self, key: &str) -> Value {
+        if let Some(ref namespace) = self.modules[key].namespace {
+            return namespace.clone();
+        }
+
+        let mut names = self.exported_names(key, &mut Vec::new());
+        names.sort();
+
+        let mut exports = FxHashMap::default();
+        for name in names {
+            // Ambiguous names are left out of the namespace.
+            let export = match self.resolve_export(key, &name, &mut Vec::new()) {
+                ExportResolution::Resolved(ResolvedBinding::Binding { module, name }) => {
+                    NamespaceExport::Binding(ImportBinding {
+                        module: self.modules[&module].environment.clone(),
+                        name,
+                    })
+                }
+                ExportResolution::Resolved(ResolvedBinding::Namespace(module)) => {
+                    NamespaceExport::Namespace(module)
+                }
+                ExportResolution::NotFound | ExportResolution::Ambiguous => continue,
+            };
+            exports.insert(name, export);
+        }
+
+        let namespace = Value::new_object_from_prototype(Value::null(), ObjectData::Ordinary);
+        {
+            let mut object = namespace.as_object_mut().expect("namespace is an object");
+            let mut names: Vec<_> = exports.keys().cloned().collect();
+            names.sort();
+            for name in names {
+                // The exports are read-only, since a namespace object can't change the bindings
+                // of a module.
+                object.insert_property(
+                    name,
+                    Property::data_descriptor(
+                        Value::undefined(),
+                        Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+                    ),
+                );
+            }
+            let to_string_tag = self.well_known_symbols().to_string_tag_symbol();
+            object.insert_property(
+                to_string_tag,
+                Property::data_descriptor(
+                    Value::from("Module"),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+                ),
+            );
+            object.data = ObjectData::ModuleNamespace(ModuleNamespace { exports });
+            object.prevent_extensions();
+        }
+
+        self.modules
+            .get_mut(key)
+            .expect("the module is loaded")
+            .namespace = Some(namespace.clone());
+        self.update_module_namespace(&namespace);
+        namespace
+    }
+
+    /// Copies the current values of the exports of a module to the properties of its namespace
+    /// object, for the operations that read the properties of an object without the interpreter.
+    fn update_module_namespace(&mut self, namespace: &Value) {
+        let exports = match namespace.as_object().map(|object| object.data.clone()) {
+            Some(ObjectData::ModuleNamespace(ref namespace)) => namespace.exports.clone(),
+            _ => return,
+        };
+        for (name, export) in exports {
+            if let Some(Ok(value)) = self.module_namespace_export(&export) {
+                namespace.set_property(
+                    name,
+                    Property::data_descriptor(
+                        value,
+                        Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Gets the current value of an export of a module namespace object.
+    ///
+    /// Returns `None` if the property isn't an export of the namespace.
+    pub(crate) fn get_module_namespace_field(
+        &mut self,
+        namespace: &ModuleNamespace,
+        key: &PropertyKey,
+    ) -> Option<ResultValue> {
+        let export = match key {
+            PropertyKey::String(ref name) => namespace.exports.get(name.as_str())?,
+            PropertyKey::Symbol(_) => return None,
+        };
+        let export = export.clone();
+        Some(
+            self.module_namespace_export(&export)
+                .unwrap_or_else(|| self.throw_reference_error(format!("{} is not defined", key))),
+        )
+    }
+
+    /// Gets the value of an export, or `None` if its binding doesn't exist yet, because the
+    /// module that declares it hasn't been evaluated.
+    fn module_namespace_export(&mut self, export: &NamespaceExport) -> Option<ResultValue> {
+        match export {
+            NamespaceExport::Binding(ref binding) => {
+                let module = binding.module.borrow();
+                if module.has_binding(&binding.name) {
+                    Some(Ok(module.get_binding_value(&binding.name, true)))
+                } else {
+                    None
+                }
+            }
+            NamespaceExport::Namespace(ref module) => Some(Ok(self.module_namespace(module))),
+        }
+    }
+
+    /// Evaluates a linked module, after the modules it requests.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-innermoduleevaluation
+    fn evaluate_module(&mut self, key: &str) -> Result<(), Value> {
+        let record = self.modules.get_mut(key).expect("the module is loaded");
+        if let Some(ref error) = record.error {
+            return Err(error.clone());
+        }
+        if record.status != ModuleStatus::Linked {
+            // The module is already evaluated, or is being evaluated by a circular import.
+            return Ok(());
+        }
+        record.status = ModuleStatus::Evaluating;
+
+        let module = record.module.clone();
+        let environment = record.environment.clone();
+        let requested = record.requested_modules.clone();
+
+        let result = requested
+            .iter()
+            .try_for_each(|(_, requested)| self.evaluate_module(requested))
+            .and_then(|_| self.run_module_body(&module, environment));
+
+        let record = self.modules.get_mut(key).expect("the module is loaded");
+        record.status = ModuleStatus::Evaluated;
+        if let Err(ref error) = result {
+            record.error = Some(error.clone());
+        }
+        if let Some(namespace) = record.namespace.clone() {
+            self.update_module_namespace(&namespace);
+        }
+        result
+    }
+
+    /// Runs the body of a module in its environment.
+    fn run_module_body(&mut self, module: &Module, environment: Environment) -> Result<(), Value> {
+        let _timer = BoaProfiler::global().start_event("Module", "exec");
+        let caller_env = mem::replace(
+            &mut self.realm.environment,
+            LexicalEnvironment::from_scope(environment),
+        );
+        let caller_strict = self.is_strict();
+        self.set_strict(true);
+
+        let result = module.body().run(self);
+
+        self.realm.environment = caller_env;
+        self.set_strict(caller_strict);
+        self.set_current_state(InterpreterState::Executing);
+
+        result.map(|_| ())
+    }
+}
+
+/// Parses the source code of a module.
+fn parse_module(source: &str) -> Result<Module, String> {
+    let mut lexer = Lexer::new(source);
+    lexer.lex().map_err(|err| err.to_string())?;
+    Parser::new(&lexer.tokens)
+        .parse_module()
+        .map_err(|err| err.to_string())
+}
+
+impl Executable for ImportDecl {
+    fn run(&self, _: &mut Interpreter) -> ResultValue {
+        // The bindings of the imports are created when the module is linked.
+        Ok(Value::undefined())
+    }
+}
+
+impl Executable for ExportDecl {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        match self {
+            Self::Declaration(ref declaration) => declaration.run(interpreter),
+            Self::Default(ref node) if self.declaration().is_some() => node.run(interpreter),
+            Self::Default(ref expr) => {
+                let value = expr.run(interpreter)?;
+                let environment = &mut interpreter.realm_mut().environment;
+                environment.create_immutable_binding(
+                    DEFAULT_BINDING.to_owned(),
+                    true,
+                    VariableScope::Block,
+                );
+                environment.initialize_binding(DEFAULT_BINDING, value);
+                Ok(Value::undefined())
+            }
+            Self::Named(_, _) | Self::All(_, _) => Ok(Value::undefined()),
+        }
+    }
+}
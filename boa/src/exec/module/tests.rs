@@ -0,0 +1,309 @@
+use super::ModuleLoader;
+use crate::{forward, Interpreter, Realm};
+use rustc_hash::FxHashMap;
+
+/// A loader of modules stored in memory, by name.
+#[derive(Debug, Default)]
+struct MemoryLoader(FxHashMap<String, String>);
+
+impl MemoryLoader {
+    fn with(mut self, name: &str, source: &str) -> Self {
+        self.0.insert(name.to_owned(), source.to_owned());
+        self
+    }
+}
+
+impl ModuleLoader for MemoryLoader {
+    fn resolve(&self, specifier: &str, _: Option<&str>) -> Result<String, String> {
+        if self.0.contains_key(specifier) {
+            Ok(specifier.to_owned())
+        } else {
+            Err("not found".to_owned())
+        }
+    }
+
+    fn fetch(&self, key: &str) -> Result<String, String> {
+        Ok(self.0[key].clone())
+    }
+}
+
+/// Creates an interpreter that imports the given modules as `ns`, and evaluates `src`.
+fn import(loader: MemoryLoader, specifier: &str, src: &str) -> String {
+    let mut engine = Interpreter::new(Realm::create());
+    engine.set_module_loader(loader);
+    match engine.import_module(specifier) {
+        Ok(namespace) => {
+            let global = engine.global().clone();
+            global.set_field("ns", namespace);
+            forward(&mut engine, src)
+        }
+        Err(err) => format!("Error: {}", err),
+    }
+}
+
+#[test]
+fn named_imports_and_exports() {
+    let loader = MemoryLoader::default()
+        .with(
+            "lib",
+            r#"
+            export const a = 1;
+            let b = 2;
+            export { b as c };
+            export function f() { return a + b; }
+            "#,
+        )
+        .with(
+            "main",
+            r#"
+            import { a, c as d, f } from "lib";
+            export const result = a + d + f();
+            "#,
+        );
+
+    assert_eq!(import(loader, "main", "ns.result"), "6");
+}
+
+#[test]
+fn default_and_namespace_imports() {
+    let loader = MemoryLoader::default()
+        .with("expr", "export default 40 + 2;")
+        .with("func", "export default function () { return 'function'; }")
+        .with(
+            "class",
+            "export default class { get name() { return 'class'; } }",
+        )
+        .with(
+            "main",
+            r#"
+            import answer from "expr";
+            import func from "func";
+            import Class, * as classes from "class";
+            export const result = [answer, func(), new Class().name, classes.default === Class];
+            "#,
+        );
+
+    assert_eq!(
+        import(loader, "main", "ns.result.join()"),
+        "\"42,function,class,true\""
+    );
+}
+
+#[test]
+fn live_bindings() {
+    let loader = MemoryLoader::default()
+        .with(
+            "counter",
+            r#"
+            export let count = 0;
+            export function increment() { count += 1; }
+            "#,
+        )
+        .with(
+            "main",
+            r#"
+            import { count, increment } from "counter";
+            increment();
+            increment();
+            export const result = count;
+            "#,
+        );
+
+    assert_eq!(import(loader, "main", "ns.result"), "2");
+}
+
+#[test]
+fn re_exports() {
+    let loader = MemoryLoader::default()
+        .with("a", "export const a = 'a'; export default 'default';")
+        .with("b", "export const b = 'b';")
+        .with(
+            "all",
+            r#"
+            export * from "a";
+            export * from "b";
+            export * as nsB from "b";
+            export { a as renamed } from "a";
+            "#,
+        );
+
+    assert_eq!(
+        import(
+            loader,
+            "all",
+            "[ns.a, ns.b, ns.nsB.b, ns.renamed, ns.default === undefined].join()"
+        ),
+        "\"a,b,b,a,true\""
+    );
+}
+
+#[test]
+fn namespace_object() {
+    let loader = MemoryLoader::default().with("lib", "export let b = 1, a = 2;");
+
+    let mut engine = Interpreter::new(Realm::create());
+    engine.set_module_loader(loader);
+    let namespace = engine.import_module("lib").expect("failed to import");
+    engine.global().clone().set_field("ns", namespace);
+
+    assert_eq!(
+        forward(
+            &mut engine,
+            "let keys = []; for (let key in ns) keys.push(key); keys.join()"
+        ),
+        "\"a,b\""
+    );
+    assert_eq!(forward(&mut engine, "Object.getPrototypeOf(ns)"), "null");
+    assert_eq!(forward(&mut engine, "ns[Symbol.toStringTag]"), "\"Module\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "(function () { 'use strict'; try { ns.a = 3; } catch (e) { return e.name; } })()"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn modules_are_evaluated_once_in_order() {
+    let loader = MemoryLoader::default()
+        .with("log", "export const log = [];")
+        .with("a", r#"import { log } from "log"; log.push("a");"#)
+        .with(
+            "b",
+            r#"import { log } from "log"; import "a"; log.push("b");"#,
+        )
+        .with(
+            "main",
+            r#"
+            import { log } from "log";
+            import "a";
+            import "b";
+            log.push("main");
+            export { log };
+            "#,
+        );
+
+    assert_eq!(import(loader, "main", "ns.log.join()"), "\"a,b,main\"");
+}
+
+#[test]
+fn circular_imports() {
+    let loader = MemoryLoader::default()
+        .with(
+            "even",
+            r#"
+            import { isOdd } from "odd";
+            export function isEven(n) { if (n === 0) { return true; } return isOdd(n - 1); }
+            "#,
+        )
+        .with(
+            "odd",
+            r#"
+            import { isEven } from "even";
+            export function isOdd(n) { if (n === 0) { return false; } return isEven(n - 1); }
+            "#,
+        );
+
+    assert_eq!(import(loader, "even", "ns.isEven(10)"), "true");
+}
+
+#[test]
+fn module_code_is_strict() {
+    let loader = MemoryLoader::default().with(
+        "main",
+        r#"
+        let thrown;
+        try { undeclared = 1; } catch (e) { thrown = e.name; }
+        export const result = [thrown, this === undefined];
+        "#,
+    );
+
+    assert_eq!(
+        import(loader, "main", "ns.result.join()"),
+        "\"ReferenceError,true\""
+    );
+}
+
+#[test]
+fn link_errors() {
+    let loader = MemoryLoader::default()
+        .with("lib", "export const a = 1;")
+        .with("missing_export", r#"import { b } from "lib";"#)
+        .with("missing_module", r#"import { a } from "nowhere";"#)
+        .with("syntax", "export export;")
+        .with(
+            "duplicate_import",
+            r#"import { a } from "lib"; import { a as b, a as b } from "lib";"#,
+        )
+        .with("import_and_let", r#"import { a } from "lib"; let a = 2;"#);
+
+    let mut engine = Interpreter::new(Realm::create());
+    engine.set_module_loader(loader);
+    for specifier in &[
+        "missing_export",
+        "syntax",
+        "duplicate_import",
+        "import_and_let",
+    ] {
+        let err = engine
+            .import_module(specifier)
+            .expect_err("import succeeded");
+        assert_eq!(err.get_field("name").to_string(), "\"SyntaxError\"");
+    }
+    let err = engine
+        .import_module("missing_module")
+        .expect_err("import succeeded");
+    assert_eq!(err.get_field("name").to_string(), "\"TypeError\"");
+}
+
+#[test]
+fn evaluation_errors_are_rethrown() {
+    let loader = MemoryLoader::default().with(
+        "main",
+        r#"
+        export let count = 0;
+        count += 1;
+        throw new Error("failed " + count);
+        "#,
+    );
+
+    let mut engine = Interpreter::new(Realm::create());
+    engine.set_module_loader(loader);
+    for _ in 0..2 {
+        let err = engine.import_module("main").expect_err("import succeeded");
+        assert_eq!(err.get_field("message").to_string(), "\"failed 1\"");
+    }
+}
+
+#[test]
+fn import_bindings_are_immutable() {
+    let loader = MemoryLoader::default()
+        .with("./a.js", "export let x = 0;")
+        .with(
+            "main",
+            r#"
+            import { x } from "./a.js";
+            export let result = [];
+            try {
+                x = 1;
+            } catch (err) {
+                result.push(err.name);
+            }
+            try {
+                x += 1;
+            } catch (err) {
+                result.push(err.name);
+            }
+            try {
+                [x] = [1];
+            } catch (err) {
+                result.push(err.name, x);
+            }
+            "#,
+        );
+    assert_eq!(
+        import(loader, "main", "ns.result.join()"),
+        "\"TypeError,TypeError,TypeError,0\""
+    );
+}
@@ -1,9 +1,6 @@
-use super::{Executable, Interpreter};
+use super::{call::evaluate_arguments, Executable, Interpreter};
 use crate::{
-    builtins::{
-        object::{ObjectData, PROTOTYPE},
-        value::{ResultValue, Value},
-    },
+    builtins::{generator::Frame, value::ResultValue},
     syntax::ast::node::New,
     BoaProfiler,
 };
@@ -16,26 +13,20 @@ impl Executable for New {
         //     _ => unreachable!("Node::New(ref call): 'call' must only be Node::Call type."),
         // };
 
-        let func_object = self.expr().run(interpreter)?;
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            v_args.push(arg.run(interpreter)?);
-        }
-        let this = Value::new_object(None);
-        // Create a blank object, then set its __proto__ property to the [Constructor].prototype
-        this.as_object_mut()
-            .expect("this was not an object")
-            .set_prototype(func_object.get_field(PROTOTYPE));
-
-        match func_object {
-            Value::Object(ref obj) => {
-                let obj = obj.borrow();
-                if let ObjectData::Function(ref func) = obj.data {
-                    return func.construct(func_object.clone(), &this, &v_args, interpreter);
-                }
-                interpreter.throw_type_error("not a constructor")
+        // In a generator body, step 0 is the constructor and step 1 the arguments.
+        let func_object = match interpreter.resume_frame() {
+            Some(mut frame) if frame.step == 1 => {
+                frame.values.pop().expect("the constructor is saved")
             }
-            _ => Ok(Value::undefined()),
-        }
+            _ => self
+                .expr()
+                .run(interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?,
+        };
+        let v_args = evaluate_arguments(self.args(), interpreter).map_err(|err| {
+            interpreter.save_frame(err, || Frame::new(1, vec![func_object.clone()]))
+        })?;
+
+        interpreter.construct(&func_object, &v_args, &func_object)
     }
 }
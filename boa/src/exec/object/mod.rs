@@ -2,36 +2,114 @@
 
 use super::{Executable, Interpreter};
 use crate::{
-    builtins::value::{ResultValue, Value},
+    builtins::{
+        function::ThisMode,
+        generator::Frame,
+        property::{Attribute, Property, PropertyKey},
+        value::{ResultValue, Value},
+    },
     syntax::ast::node::MethodDefinitionKind,
-    syntax::ast::node::{Object, PropertyDefinition},
+    syntax::ast::node::{Object, PropertyDefinition, PropertyName},
 };
 
 use std::borrow::Borrow;
 
 impl Executable for Object {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        let global_val = &interpreter
-            .realm()
-            .environment
-            .get_global_object()
-            .expect("Could not get the global object");
-        let obj = Value::new_object(Some(global_val));
+        // In a generator body, step `2 * i` is the name of the property `i` and step `2 * i + 1` its
+        // value, whose frame has the object and the key.
+        let (start, obj, mut resumed_key) = match interpreter.resume_frame() {
+            Some(frame) => {
+                let mut values = frame.values.into_iter();
+                let obj = values.next().expect("the object is saved");
+                (frame.step, obj, values.next())
+            }
+            None => {
+                let global_val = &interpreter
+                    .realm()
+                    .environment
+                    .get_global_object()
+                    .expect("Could not get the global object");
+                (0, Value::new_object(Some(global_val)), None)
+            }
+        };
 
         // TODO: Implement the rest of the property types.
-        for property in self.properties().iter() {
+        for (i, property) in self.properties().iter().enumerate().skip(start / 2) {
+            let save_name = |err, interpreter: &mut Interpreter| {
+                interpreter.save_frame(err, || Frame::new(2 * i, vec![obj.clone()]))
+            };
             match property {
-                PropertyDefinition::Property(key, value) => {
-                    obj.borrow().set_field(key.clone(), value.run(interpreter)?);
+                PropertyDefinition::Property(name, value) => {
+                    let key = match resumed_key.take() {
+                        Some(key) => interpreter.to_property_key(&key)?,
+                        None => name
+                            .to_property_key(interpreter)
+                            .map_err(|err| save_name(err, interpreter))?,
+                    };
+                    let value = value.run(interpreter).map_err(|err| {
+                        interpreter.save_frame(err, || {
+                            Frame::new(2 * i + 1, vec![obj.clone(), Value::from(key.clone())])
+                        })
+                    })?;
+                    obj.borrow().set_field(key, value);
                 }
                 PropertyDefinition::MethodDefinition(kind, name, func) => {
-                    if let MethodDefinitionKind::Ordinary = kind {
-                        obj.borrow().set_field(name.clone(), func.run(interpreter)?);
-                    } else {
-                        // TODO: Implement other types of MethodDefinitionKinds.
-                        unimplemented!("other types of property method definitions.");
+                    let key = name
+                        .to_property_key(interpreter)
+                        .map_err(|err| save_name(err, interpreter))?;
+                    match kind {
+                        MethodDefinitionKind::Ordinary => {
+                            obj.borrow().set_field(key, func.run(interpreter)?);
+                        }
+                        MethodDefinitionKind::Generator => {
+                            let method = interpreter.create_generator_function(
+                                func.parameters().to_vec(),
+                                func.body().clone(),
+                            );
+                            obj.borrow().set_field(key, method);
+                        }
+                        MethodDefinitionKind::Async => {
+                            let method = interpreter.create_async_function(
+                                func.parameters().to_vec(),
+                                func.body().clone(),
+                                ThisMode::NonLexical,
+                            );
+                            obj.borrow().set_field(key, method);
+                        }
+                        kind => {
+                            let accessor = interpreter.create_function(
+                                func.parameters().to_vec(),
+                                func.body().clone(),
+                                ThisMode::NonLexical,
+                                false,
+                                true,
+                            );
+                            if let Some(mut object) = obj.as_object_mut() {
+                                // A getter and a setter with the same name are merged into a
+                                // single property.
+                                let mut property = object.get_own_property(&key);
+                                if !property.is_accessor_descriptor() {
+                                    property = Property::empty();
+                                }
+                                property.attribute =
+                                    Attribute::ENUMERABLE | Attribute::CONFIGURABLE;
+                                let property = if let MethodDefinitionKind::Get = kind {
+                                    property.get(accessor)
+                                } else {
+                                    property.set(accessor)
+                                };
+                                object.insert_property(key, property);
+                            }
+                        }
                     }
                 }
+                PropertyDefinition::SpreadObject(value) => {
+                    let value = value.run(interpreter).map_err(|err| {
+                        interpreter.save_frame(err, || Frame::new(2 * i + 1, vec![obj.clone()]))
+                    })?;
+                    interpreter.copy_data_properties(&obj, &value, &[])?;
+                }
                 i => unimplemented!("{:?} type of property", i),
             }
         }
@@ -39,3 +117,24 @@ impl Executable for Object {
         Ok(obj)
     }
 }
+
+impl PropertyName {
+    /// Gets the key of the property with this name, evaluating it if it's computed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object-initializer-runtime-semantics-evaluation
+    pub(crate) fn to_property_key(
+        &self,
+        interpreter: &mut Interpreter,
+    ) -> Result<PropertyKey, Value> {
+        match self {
+            Self::Literal(ref name) => Ok(PropertyKey::from(name.clone())),
+            Self::Computed(ref node) => {
+                let name = node.run(interpreter)?;
+                interpreter.to_property_key(&name)
+            }
+        }
+    }
+}
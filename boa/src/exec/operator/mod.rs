@@ -2,9 +2,17 @@
 #[cfg(test)]
 mod tests;
 
-use super::{Executable, Interpreter};
+use super::{
+    class::{get_super_field, put_super_field},
+    field::evaluate_object_and_field,
+    BindingKind, Executable, Interpreter,
+};
 use crate::{
-    builtins::value::{ResultValue, Value},
+    builtins::{
+        generator::Frame,
+        object::PROTOTYPE,
+        value::{ResultValue, Value},
+    },
     environment::lexical_environment::VariableScope,
     syntax::ast::{
         node::{Assign, BinOp, Node, UnaryOp},
@@ -16,45 +24,73 @@ use crate::{
 impl Executable for Assign {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         let _timer = BoaProfiler::global().start_event("Assign", "exec");
-        let val = self.rhs().run(interpreter)?;
-        match self.lhs() {
-            Node::Identifier(ref name) => {
-                let environment = &mut interpreter.realm_mut().environment;
+        // In a generator body, step 0 is the right-hand side and step 1 the assignment.
+        let val = match interpreter.resume_frame() {
+            Some(mut frame) if frame.step == 1 => frame.values.pop().expect("the value is saved"),
+            _ => self
+                .rhs()
+                .run(interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?,
+        };
+        assign_to(self.lhs(), val.clone(), interpreter)
+            .map_err(|err| interpreter.save_frame(err, || Frame::new(1, vec![val.clone()])))?;
+        Ok(val)
+    }
+}
 
-                if environment.has_binding(name.as_ref()) {
-                    // Binding already exists
-                    environment.set_mutable_binding(name.as_ref(), val.clone(), true);
-                } else {
-                    environment.create_mutable_binding(
-                        name.as_ref().to_owned(),
-                        true,
-                        VariableScope::Function,
-                    );
-                    environment.initialize_binding(name.as_ref(), val.clone());
-                }
-            }
-            Node::GetConstField(ref get_const_field) => {
-                let val_obj = get_const_field.obj().run(interpreter)?;
-                val_obj.set_field(get_const_field.field(), val.clone());
-            }
-            Node::GetField(ref get_field) => {
-                let object = get_field.obj().run(interpreter)?;
-                let field = get_field.field().run(interpreter)?;
-                let key = interpreter.to_property_key(&field)?;
-                object.set_field(key, val.clone());
+/// Assigns a value to the left-hand side of an assignment.
+///
+/// Undeclared identifiers are created as variables of the current function.
+pub(super) fn assign_to(
+    lhs: &Node,
+    val: Value,
+    interpreter: &mut Interpreter,
+) -> Result<(), Value> {
+    match lhs {
+        Node::Identifier(ref name) => {
+            if interpreter.realm().environment.has_binding(name.as_ref()) {
+                // Binding already exists
+                interpreter.set_mutable_binding(name.as_ref(), val)?;
+            } else if interpreter.is_strict() {
+                // Strict mode code can't create global variables by assigning to them.
+                interpreter.throw_reference_error(format!("{} is not defined", name))?;
+            } else {
+                let environment = &mut interpreter.realm_mut().environment;
+                environment.create_mutable_binding(
+                    name.as_ref().to_owned(),
+                    true,
+                    VariableScope::Function,
+                );
+                environment.initialize_binding(name.as_ref(), val);
             }
-            _ => (),
         }
-        Ok(val)
+        Node::GetConstField(ref get_const_field) => {
+            let val_obj = get_const_field.obj().run(interpreter)?;
+            interpreter.put_field(&val_obj, get_const_field.field(), val)?;
+        }
+        Node::GetField(ref get_field) => {
+            let (object, field) = evaluate_object_and_field(get_field, interpreter)?;
+            let key = interpreter.to_property_key(&field)?;
+            interpreter.put_field(&object, key, val)?;
+        }
+        Node::GetSuperField(ref get_super_field) => {
+            let field = get_super_field.field().run(interpreter)?;
+            let key = interpreter.to_property_key(&field)?;
+            put_super_field(key, val, interpreter)?;
+        }
+        Node::Pattern(ref pattern) => pattern.bind(val, BindingKind::Assignment, interpreter)?,
+        _ => {
+            interpreter.throw_reference_error(format!("invalid assignment to {}", lhs))?;
+        }
     }
+    Ok(())
 }
 
 impl Executable for BinOp {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         match self.op() {
             op::BinOp::Num(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 match op {
                     NumOp::Add => x.add(&y, interpreter),
                     NumOp::Sub => x.sub(&y, interpreter),
@@ -65,8 +101,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Bit(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 match op {
                     BitOp::And => x.bitand(&y, interpreter),
                     BitOp::Or => x.bitor(&y, interpreter),
@@ -77,8 +112,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Comp(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 Ok(Value::from(match op {
                     CompOp::Equal => x.equals(&y, interpreter)?,
                     CompOp::NotEqual => !x.equals(&y, interpreter)?,
@@ -96,100 +130,308 @@ impl Executable for BinOp {
                             ));
                         }
                         let key = interpreter.to_property_key(&x)?;
-                        interpreter.has_property(&y, &key)
+                        interpreter.has_property(&y, &key)?
+                    }
+                    CompOp::InstanceOf => {
+                        if !y.is_object() {
+                            return interpreter.throw_type_error(format!(
+                                "right-hand side of 'instanceof' should be an object, got {}",
+                                y.get_type().as_str()
+                            ));
+                        }
+                        if !y.is_function() {
+                            return interpreter.throw_type_error(
+                                "right-hand side of 'instanceof' is not callable",
+                            );
+                        }
+                        Self::ordinary_has_instance(&y, &x, interpreter)?
                     }
                 }))
             }
             op::BinOp::Log(op) => {
-                // turn a `Value` into a `bool`
-                let to_bool = |value| bool::from(&value);
-                Ok(match op {
-                    LogOp::And => Value::from(
-                        to_bool(self.lhs().run(interpreter)?)
-                            && to_bool(self.rhs().run(interpreter)?),
-                    ),
-                    LogOp::Or => Value::from(
-                        to_bool(self.lhs().run(interpreter)?)
-                            || to_bool(self.rhs().run(interpreter)?),
-                    ),
-                })
+                // The right-hand side is only evaluated if the left-hand side doesn't decide the
+                // result, which is one of the operands.
+                // In a generator body, step 0 is the left-hand side and step 1 the right-hand side.
+                if let Some(Frame { step: 1, .. }) = interpreter.resume_frame() {
+                    return self.rhs().run(interpreter);
+                }
+                let x = self
+                    .lhs()
+                    .run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+                let short_circuits = match op {
+                    LogOp::And => !x.to_boolean(),
+                    LogOp::Or => x.to_boolean(),
+                    LogOp::Coalesce => !x.is_null_or_undefined(),
+                };
+                if short_circuits {
+                    Ok(x)
+                } else {
+                    self.rhs()
+                        .run(interpreter)
+                        .map_err(|err| interpreter.save_frame(err, || Frame::new(1, Vec::new())))
+                }
+            }
+            op::BinOp::Assign(op) => self.run_compound_assign(op, interpreter),
+            op::BinOp::Comma => {
+                // In a generator body, step 0 is the left-hand side and step 1 the right-hand side.
+                if let Some(Frame { step: 1, .. }) = interpreter.resume_frame() {
+                    return self.rhs().run(interpreter);
+                }
+                self.lhs()
+                    .run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+                self.rhs()
+                    .run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(1, Vec::new())))
+            }
+        }
+    }
+}
+
+impl BinOp {
+    /// Evaluates both operands of a binary operator.
+    ///
+    /// In a generator body, step 0 is the left operand and step 1 the right operand, whose frame
+    /// has the value of the left operand.
+    fn run_operands(&self, interpreter: &mut Interpreter) -> Result<(Value, Value), Value> {
+        let x = match interpreter.resume_frame() {
+            Some(mut frame) if frame.step == 1 => {
+                frame.values.pop().expect("the left operand is saved")
             }
-            op::BinOp::Assign(op) => match self.lhs() {
-                Node::Identifier(ref name) => {
-                    let v_a = interpreter
+            _ => self
+                .lhs()
+                .run(interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?,
+        };
+        let y = self
+            .rhs()
+            .run(interpreter)
+            .map_err(|err| interpreter.save_frame(err, || Frame::new(1, vec![x.clone()])))?;
+        Ok((x, y))
+    }
+
+    /// Runs the compound assignment operators, like `+=`.
+    ///
+    /// In a generator body, step 0 is the target and step 1 the right-hand side, whose frame has
+    /// the object and the key of the target and its value.
+    fn run_compound_assign(&self, op: AssignOp, interpreter: &mut Interpreter) -> ResultValue {
+        let frame = interpreter.resume_frame();
+        match self.lhs() {
+            Node::Identifier(ref name) => {
+                let v_a = match frame {
+                    Some(mut frame) => frame.values.pop().expect("the target value is saved"),
+                    None => interpreter
                         .realm()
                         .environment
                         .get_binding_value(name.as_ref())
-                        .ok_or_else(|| interpreter.construct_reference_error(name.as_ref()))?;
-                    let v_b = self.rhs().run(interpreter)?;
-                    let value = Self::run_assign(op, v_a, v_b, interpreter)?;
-                    interpreter.realm.environment.set_mutable_binding(
-                        name.as_ref(),
-                        value.clone(),
-                        true,
-                    );
-                    Ok(value)
+                        .ok_or_else(|| {
+                            interpreter
+                                .construct_reference_error(format!("{} is not defined", name))
+                        })?,
+                };
+                let value = Self::run_assign(op, &v_a, self.rhs(), interpreter).map_err(|err| {
+                    interpreter.save_frame(err, || Frame::new(1, vec![v_a.clone()]))
+                })?;
+                match value {
+                    Some(value) => {
+                        interpreter.set_mutable_binding(name.as_ref(), value.clone())?;
+                        Ok(value)
+                    }
+                    None => Ok(v_a),
                 }
-                Node::GetConstField(ref get_const_field) => {
-                    let v_r_a = get_const_field.obj().run(interpreter)?;
-                    let v_a = v_r_a.get_field(get_const_field.field());
-                    let v_b = self.rhs().run(interpreter)?;
-                    let value = Self::run_assign(op, v_a, v_b, interpreter)?;
-                    v_r_a.set_field(get_const_field.field(), value.clone());
-                    Ok(value)
+            }
+            Node::GetConstField(ref get_const_field) => {
+                let (v_r_a, v_a) = match frame {
+                    Some(frame) if frame.step == 1 => {
+                        let mut values = frame.values.into_iter();
+                        let v_r_a = values.next().expect("the object is saved");
+                        (v_r_a, values.next().expect("the target value is saved"))
+                    }
+                    _ => {
+                        let v_r_a = get_const_field.obj().run(interpreter).map_err(|err| {
+                            interpreter.save_frame(err, || Frame::new(0, Vec::new()))
+                        })?;
+                        let v_a = interpreter.get_field(&v_r_a, get_const_field.field())?;
+                        (v_r_a, v_a)
+                    }
+                };
+                let value = Self::run_assign(op, &v_a, self.rhs(), interpreter).map_err(|err| {
+                    interpreter.save_frame(err, || Frame::new(1, vec![v_r_a.clone(), v_a.clone()]))
+                })?;
+                match value {
+                    Some(value) => {
+                        interpreter.put_field(&v_r_a, get_const_field.field(), value.clone())?;
+                        Ok(value)
+                    }
+                    None => Ok(v_a),
                 }
-                _ => Ok(Value::undefined()),
-            },
-            op::BinOp::Comma => {
-                self.lhs().run(interpreter)?;
-                Ok(self.rhs().run(interpreter)?)
             }
+            Node::GetField(ref get_field) => {
+                let (v_r_a, key, v_a) = match frame {
+                    Some(frame) if frame.step == 1 => {
+                        let mut values = frame.values.into_iter();
+                        let v_r_a = values.next().expect("the object is saved");
+                        let key = values.next().expect("the key is saved");
+                        let key = interpreter.to_property_key(&key)?;
+                        (
+                            v_r_a,
+                            key,
+                            values.next().expect("the target value is saved"),
+                        )
+                    }
+                    _ => {
+                        let (v_r_a, field) = evaluate_object_and_field(get_field, interpreter)
+                            .map_err(|err| {
+                                interpreter.save_frame(err, || Frame::new(0, Vec::new()))
+                            })?;
+                        let key = interpreter.to_property_key(&field)?;
+                        let v_a = interpreter.get_field(&v_r_a, key.clone())?;
+                        (v_r_a, key, v_a)
+                    }
+                };
+                let value = Self::run_assign(op, &v_a, self.rhs(), interpreter).map_err(|err| {
+                    interpreter.save_frame(err, || {
+                        Frame::new(
+                            1,
+                            vec![v_r_a.clone(), Value::from(key.clone()), v_a.clone()],
+                        )
+                    })
+                })?;
+                match value {
+                    Some(value) => {
+                        interpreter.put_field(&v_r_a, key, value.clone())?;
+                        Ok(value)
+                    }
+                    None => Ok(v_a),
+                }
+            }
+            Node::GetSuperField(ref super_field) => {
+                let (key, v_a) = match frame {
+                    Some(mut frame) if frame.step == 1 => {
+                        let v_a = frame.values.pop().expect("the target value is saved");
+                        let key = frame.values.pop().expect("the key is saved");
+                        (interpreter.to_property_key(&key)?, v_a)
+                    }
+                    _ => {
+                        let field = super_field.field().run(interpreter).map_err(|err| {
+                            interpreter.save_frame(err, || Frame::new(0, Vec::new()))
+                        })?;
+                        let key = interpreter.to_property_key(&field)?;
+                        let v_a = get_super_field(key.clone(), interpreter)?;
+                        (key, v_a)
+                    }
+                };
+                let value = Self::run_assign(op, &v_a, self.rhs(), interpreter).map_err(|err| {
+                    interpreter.save_frame(err, || {
+                        Frame::new(1, vec![Value::from(key.clone()), v_a.clone()])
+                    })
+                })?;
+                match value {
+                    Some(value) => {
+                        put_super_field(key, value.clone(), interpreter)?;
+                        Ok(value)
+                    }
+                    None => Ok(v_a),
+                }
+            }
+            _ => Ok(Value::undefined()),
         }
     }
-}
 
-impl BinOp {
-    /// Runs the assignment operators.
-    fn run_assign(op: AssignOp, x: Value, y: Value, interpreter: &mut Interpreter) -> ResultValue {
-        match op {
-            AssignOp::Add => x.add(&y, interpreter),
-            AssignOp::Sub => x.sub(&y, interpreter),
-            AssignOp::Mul => x.mul(&y, interpreter),
-            AssignOp::Exp => x.pow(&y, interpreter),
-            AssignOp::Div => x.div(&y, interpreter),
-            AssignOp::Mod => x.rem(&y, interpreter),
-            AssignOp::And => x.bitand(&y, interpreter),
-            AssignOp::Or => x.bitor(&y, interpreter),
-            AssignOp::Xor => x.bitxor(&y, interpreter),
-            AssignOp::Shl => x.shl(&y, interpreter),
-            AssignOp::Shr => x.shr(&y, interpreter),
+    /// Checks if `object` inherits from the `prototype` property of the function `constructor`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryhasinstance
+    fn ordinary_has_instance(
+        constructor: &Value,
+        object: &Value,
+        interpreter: &mut Interpreter,
+    ) -> Result<bool, Value> {
+        if !object.is_object() {
+            return Ok(false);
+        }
+        let prototype = interpreter.get_field(constructor, PROTOTYPE)?;
+        if !prototype.is_object() {
+            return Err(interpreter
+                .construct_type_error("function has non-object prototype in instanceof check"));
+        }
+
+        // The prototypes of proxies are looked up through their `getPrototypeOf` traps.
+        let mut current = object.clone();
+        loop {
+            let parent = interpreter.get_prototype_of(&current)?;
+            if parent.is_null() {
+                return Ok(false);
+            }
+            if parent.strict_equals(&prototype) {
+                return Ok(true);
+            }
+            current = parent;
         }
     }
+
+    /// Runs the assignment operators, evaluating the right-hand side `rhs`.
+    ///
+    /// Returns `None` when a logical assignment (`&&=`, `||=` or `??=`) short-circuits, in which
+    /// case the right-hand side isn't evaluated and nothing must be assigned.
+    fn run_assign(
+        op: AssignOp,
+        x: &Value,
+        rhs: &Node,
+        interpreter: &mut Interpreter,
+    ) -> Result<Option<Value>, Value> {
+        let short_circuits = match op {
+            AssignOp::BoolAnd => !x.to_boolean(),
+            AssignOp::BoolOr => x.to_boolean(),
+            AssignOp::Coalesce => !x.is_null_or_undefined(),
+            _ => false,
+        };
+        if short_circuits {
+            return Ok(None);
+        }
+
+        let y = rhs.run(interpreter)?;
+        let value = match op {
+            AssignOp::Add => x.add(&y, interpreter)?,
+            AssignOp::Sub => x.sub(&y, interpreter)?,
+            AssignOp::Mul => x.mul(&y, interpreter)?,
+            AssignOp::Exp => x.pow(&y, interpreter)?,
+            AssignOp::Div => x.div(&y, interpreter)?,
+            AssignOp::Mod => x.rem(&y, interpreter)?,
+            AssignOp::And => x.bitand(&y, interpreter)?,
+            AssignOp::Or => x.bitor(&y, interpreter)?,
+            AssignOp::Xor => x.bitxor(&y, interpreter)?,
+            AssignOp::Shl => x.shl(&y, interpreter)?,
+            AssignOp::Shr => x.shr(&y, interpreter)?,
+            AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce => y,
+        };
+        Ok(Some(value))
+    }
+}
+
+/// Checks if the operand of `delete` is a proxy, whose `deleteProperty` trap has to be called.
+fn is_proxy(value: &Value) -> bool {
+    value.as_object().map_or(false, |object| object.is_proxy())
 }
 
 impl Executable for UnaryOp {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        let x = self.target().run(interpreter)?;
+        match self.op() {
+            op::UnaryOp::Delete => return self.run_delete(interpreter),
+            op::UnaryOp::IncrementPost => return Ok(self.run_update(1.0, interpreter)?.0),
+            op::UnaryOp::IncrementPre => return Ok(self.run_update(1.0, interpreter)?.1),
+            op::UnaryOp::DecrementPost => return Ok(self.run_update(-1.0, interpreter)?.0),
+            op::UnaryOp::DecrementPre => return Ok(self.run_update(-1.0, interpreter)?.1),
+            _ => {}
+        }
 
+        let x = self.target().run(interpreter)?;
         Ok(match self.op() {
             op::UnaryOp::Minus => x.neg(interpreter)?,
             op::UnaryOp::Plus => Value::from(x.to_number()),
-            op::UnaryOp::IncrementPost => {
-                let ret = x.clone();
-                interpreter.set_value(self.target(), Value::from(x.to_number() + 1.0))?;
-                ret
-            }
-            op::UnaryOp::IncrementPre => {
-                interpreter.set_value(self.target(), Value::from(x.to_number() + 1.0))?
-            }
-            op::UnaryOp::DecrementPost => {
-                let ret = x.clone();
-                interpreter.set_value(self.target(), Value::from(x.to_number() - 1.0))?;
-                ret
-            }
-            op::UnaryOp::DecrementPre => {
-                interpreter.set_value(self.target(), Value::from(x.to_number() - 1.0))?
-            }
             op::UnaryOp::Not => x.not(interpreter)?,
             op::UnaryOp::Tilde => {
                 let num_v_a = x.to_number();
@@ -201,31 +443,79 @@ impl Executable for UnaryOp {
                 })
             }
             op::UnaryOp::Void => Value::undefined(),
-            op::UnaryOp::Delete => match *self.target() {
-                Node::GetConstField(ref get_const_field) => Value::boolean(
-                    get_const_field
-                        .obj()
-                        .run(interpreter)?
-                        .remove_property(get_const_field.field()),
-                ),
-                Node::GetField(ref get_field) => {
-                    let obj = get_field.obj().run(interpreter)?;
-                    let field = &get_field.field().run(interpreter)?;
-                    let res = obj.remove_property(interpreter.to_string(field)?.as_str());
-                    return Ok(Value::boolean(res));
-                }
-                Node::Identifier(_) => Value::boolean(false),
-                Node::ArrayDecl(_)
-                | Node::Block(_)
-                | Node::Const(_)
-                | Node::FunctionDecl(_)
-                | Node::FunctionExpr(_)
-                | Node::New(_)
-                | Node::Object(_)
-                | Node::UnaryOp(_) => Value::boolean(true),
-                _ => panic!("SyntaxError: wrong delete argument {}", self),
-            },
             op::UnaryOp::TypeOf => Value::from(x.get_type().as_str()),
+            _ => unreachable!("update and delete operators are run separately"),
+        })
+    }
+}
+
+impl UnaryOp {
+    /// Runs the increment and decrement operators, adding `delta` to the target, which is only
+    /// evaluated once.
+    ///
+    /// Returns the old and the new value of the target.
+    fn run_update(
+        &self,
+        delta: f64,
+        interpreter: &mut Interpreter,
+    ) -> Result<(Value, Value), Value> {
+        match self.target() {
+            Node::GetConstField(ref get_const_field) => {
+                let obj = get_const_field.obj().run(interpreter)?;
+                let old = interpreter.get_field(&obj, get_const_field.field())?;
+                let new = Value::from(old.to_number() + delta);
+                interpreter.put_field(&obj, get_const_field.field(), new.clone())?;
+                Ok((old, new))
+            }
+            Node::GetField(ref get_field) => {
+                let (obj, field) = evaluate_object_and_field(get_field, interpreter)?;
+                let key = interpreter.to_property_key(&field)?;
+                let old = interpreter.get_field(&obj, key.clone())?;
+                let new = Value::from(old.to_number() + delta);
+                interpreter.put_field(&obj, key, new.clone())?;
+                Ok((old, new))
+            }
+            target => {
+                let old = target.run(interpreter)?;
+                let new = Value::from(old.to_number() + delta);
+                interpreter.set_value(target, new.clone())?;
+                Ok((old, new))
+            }
+        }
+    }
+
+    /// Runs the `delete` operator.
+    ///
+    /// The deleted property is not read, which would call its getter.
+    fn run_delete(&self, interpreter: &mut Interpreter) -> ResultValue {
+        Ok(match *self.target() {
+            Node::GetConstField(ref get_const_field) => {
+                let obj = get_const_field.obj().run(interpreter)?;
+                if is_proxy(&obj) {
+                    let deleted = interpreter.delete_property(&obj, get_const_field.field())?;
+                    return Ok(Value::boolean(deleted));
+                }
+                Value::boolean(obj.remove_property(get_const_field.field()))
+            }
+            Node::GetField(ref get_field) => {
+                let (obj, field) = evaluate_object_and_field(get_field, interpreter)?;
+                if is_proxy(&obj) {
+                    let key = interpreter.to_property_key(&field)?;
+                    return Ok(Value::boolean(interpreter.delete_property(&obj, key)?));
+                }
+                let res = obj.remove_property(interpreter.to_string(&field)?.as_str());
+                return Ok(Value::boolean(res));
+            }
+            Node::Identifier(_) => Value::boolean(false),
+            Node::ArrayDecl(_)
+            | Node::Block(_)
+            | Node::Const(_)
+            | Node::FunctionDecl(_)
+            | Node::FunctionExpr(_)
+            | Node::New(_)
+            | Node::Object(_)
+            | Node::UnaryOp(_) => Value::boolean(true),
+            _ => panic!("SyntaxError: wrong delete argument {}", self),
         })
     }
 }
@@ -26,3 +26,30 @@ fn assignmentoperator_rhs_throws_error() {
 
     assert_eq!(&exec(scenario), "\"ReferenceError: b is not defined\"");
 }
+
+#[test]
+fn instanceofoperator_inherit() {
+    let scenario = r#"
+        function Foo() {}
+        let foo = new Foo();
+        [foo instanceof Foo, foo instanceof Object, foo instanceof Array, 1 instanceof Foo].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,false,false\"");
+}
+
+#[test]
+fn instanceofoperator_rhs_not_object() {
+    let scenario = r#"
+        try {
+            ({}) instanceof 1
+        } catch (err) {
+            err.toString()
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"TypeError: right-hand side of 'instanceof' should be an object, got number\""
+    );
+}
@@ -0,0 +1,88 @@
+//! Optional chain execution.
+
+use super::{
+    call::{evaluate_arguments, evaluate_callee},
+    Executable, Interpreter, InterpreterState,
+};
+use crate::{
+    builtins::{
+        generator::Frame,
+        value::{ResultValue, Value},
+    },
+    syntax::ast::node::{Optional, OptionalOperationKind},
+    BoaProfiler,
+};
+
+impl Executable for Optional {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("Optional", "exec");
+        let (_, value) = evaluate_chain(self, interpreter)?;
+        Ok(value)
+    }
+}
+
+/// Evaluates an optional chain, along with the `this` value its result is called with.
+///
+/// A chain accessing a property, like `(o?.f)()`, calls the function with the object of the last
+/// property access as `this`.
+pub(super) fn evaluate_chain(
+    optional: &Optional,
+    interpreter: &mut Interpreter,
+) -> Result<(Value, Value), Value> {
+    // `this` is the object of the last property access, for the calls of the chain.
+    // In a generator body, step 0 is the target and step `i + 1` the operation `i`, whose frame
+    // has the this value and the value of the chain so far.
+    let (start, mut this, mut value) = match interpreter.resume_frame() {
+        Some(frame) if frame.step > 0 => {
+            let mut values = frame.values.into_iter();
+            let this = values.next().expect("the this value is saved");
+            let value = values.next().expect("the value is saved");
+            (frame.step - 1, this, value)
+        }
+        _ => {
+            let (this, value) = evaluate_callee(optional.target(), interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+            (0, this, value)
+        }
+    };
+
+    for (i, operation) in optional.chain().iter().enumerate().skip(start) {
+        // `?.` short-circuits the whole rest of the chain.
+        if operation.shorted() && value.is_null_or_undefined() {
+            return Ok((Value::undefined(), Value::undefined()));
+        }
+
+        match operation.kind() {
+            OptionalOperationKind::GetConstField(ref field) => {
+                let obj = interpreter.to_object(&value)?;
+                value = interpreter.get_field(&obj, field.as_ref())?;
+                this = obj;
+            }
+            OptionalOperationKind::GetField(ref field) => {
+                let obj = interpreter.to_object(&value)?;
+                let field = field.run(interpreter).map_err(|err| {
+                    interpreter
+                        .save_frame(err, || Frame::new(i + 1, vec![this.clone(), value.clone()]))
+                })?;
+                let key = interpreter.to_property_key(&field)?;
+                value = interpreter.get_field(&obj, key)?;
+                this = obj;
+            }
+            OptionalOperationKind::Call(ref args) => {
+                let args = evaluate_arguments(args, interpreter).map_err(|err| {
+                    interpreter
+                        .save_frame(err, || Frame::new(i + 1, vec![this.clone(), value.clone()]))
+                })?;
+                let result = interpreter.call(&value, &this, &args);
+
+                // unset the early return flag
+                interpreter.set_current_state(InterpreterState::Executing);
+
+                value = result?;
+                this = Value::undefined();
+            }
+        }
+    }
+
+    Ok((this, value))
+}
@@ -0,0 +1,357 @@
+//! Destructuring pattern execution.
+
+#[cfg(test)]
+mod tests;
+
+use super::{operator::assign_to, Executable, Interpreter};
+use crate::{
+    builtins::{
+        generator::Frame,
+        iterable::{get_iterator, IteratorRecord},
+        property::PropertyKey,
+        value::Value,
+        Array,
+    },
+    environment::lexical_environment::VariableScope,
+    syntax::ast::node::{
+        ArrayPattern, BindingTarget, Node, ObjectPattern, Pattern, PatternElement,
+    },
+    BoaProfiler,
+};
+use gc::GcCell;
+
+/// How the names in a binding target or a pattern are bound to their values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BindingKind {
+    /// An assignment expression, the targets are assigned like the left-hand side of `=`.
+    Assignment,
+    /// A `var` declaration.
+    Var,
+    /// A `let` declaration, or a `catch` parameter.
+    Let,
+    /// A `const` declaration.
+    Const,
+    /// A parameter of a function, bound in the function environment.
+    Parameter,
+}
+
+impl BindingTarget {
+    /// Binds the given value to the target, taking it apart if the target is a pattern.
+    pub(crate) fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        interpreter: &mut Interpreter,
+    ) -> Result<(), Value> {
+        match self {
+            Self::Identifier(ref name) => bind_name(name.as_ref(), value, kind, interpreter),
+            Self::Pattern(ref pattern) => pattern.bind(value, kind, interpreter),
+        }
+    }
+}
+
+impl Pattern {
+    /// Takes the given value apart, binding the pieces to the targets of the pattern.
+    pub(crate) fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        interpreter: &mut Interpreter,
+    ) -> Result<(), Value> {
+        let _timer = BoaProfiler::global().start_event("Pattern", "exec");
+        match self {
+            Self::Object(ref pattern) => pattern.bind(value, kind, interpreter),
+            Self::Array(ref pattern) => pattern.bind(value, kind, interpreter),
+        }
+    }
+}
+
+impl ObjectPattern {
+    /// Binds the properties of the given value.
+    ///
+    /// In a generator body, step `2 * i` is the name of the property `i` and step `2 * i + 1` its
+    /// binding, the rest object is bound in the last step.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-propertybindinginitialization
+    fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        interpreter: &mut Interpreter,
+    ) -> Result<(), Value> {
+        let (start, object, mut resumed_value, mut bound_keys) = match interpreter.resume_frame() {
+            Some(frame) => {
+                let step = frame.step;
+                let mut values = frame.values.into_iter();
+                let object = values.next().expect("the object is saved");
+                let value = values.next().expect("the value is saved");
+                let bound_keys = values
+                    .map(|key| interpreter.to_property_key(&key))
+                    .collect::<Result<Vec<_>, _>>()?;
+                // The value of a property is only saved while it is being bound.
+                let value = Some(value).filter(|_| step % 2 == 1);
+                (step, object, value, bound_keys)
+            }
+            None => {
+                if value.is_null_or_undefined() {
+                    return Err(
+                        interpreter.construct_type_error(format!("cannot destructure '{}'", value))
+                    );
+                }
+                let object = interpreter.to_object(&value)?;
+                let bound_keys = Vec::with_capacity(self.properties().len());
+                (0, object, None, bound_keys)
+            }
+        };
+
+        for (i, property) in self.properties().iter().enumerate().skip(start / 2) {
+            let value = match resumed_value.take() {
+                Some(value) => value,
+                None => {
+                    let key = property
+                        .name()
+                        .to_property_key(interpreter)
+                        .map_err(|err| {
+                            interpreter.save_frame(err, || {
+                                object_pattern_frame(
+                                    2 * i,
+                                    &object,
+                                    Value::undefined(),
+                                    &bound_keys,
+                                )
+                            })
+                        })?;
+                    bound_keys.push(key.clone());
+                    interpreter.get_field(&object, key)?
+                }
+            };
+            bind_element(property.element(), value.clone(), kind, interpreter).map_err(|err| {
+                interpreter.save_frame(err, || {
+                    object_pattern_frame(2 * i + 1, &object, value.clone(), &bound_keys)
+                })
+            })?;
+        }
+
+        if let Some(rest) = self.rest() {
+            let rest_object = match resumed_value {
+                Some(rest_object) => rest_object,
+                None => {
+                    let rest_object = Value::new_object(Some(interpreter.global()));
+                    interpreter.copy_data_properties(&rest_object, &object, &bound_keys)?;
+                    rest_object
+                }
+            };
+            let step = 2 * self.properties().len() + 1;
+            bind_node(rest, rest_object.clone(), kind, interpreter).map_err(|err| {
+                interpreter.save_frame(err, || {
+                    object_pattern_frame(step, &object, rest_object.clone(), &bound_keys)
+                })
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ArrayPattern {
+    /// Binds the values produced by iterating over the given value.
+    ///
+    /// The iterator is closed if the pattern doesn't consume all of its values.
+    ///
+    /// In a generator body, step `i` is the binding of the element `i`, whose frame has the
+    /// iterator and the value of the element, the rest array is bound in the last step.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-iteratorbindinginitialization
+    fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        interpreter: &mut Interpreter,
+    ) -> Result<(), Value> {
+        let (start, iterator, mut done, mut resumed_value) = match interpreter.resume_frame() {
+            Some(frame) => {
+                let mut values = frame.values.into_iter();
+                let iterator_object = values.next().expect("the iterator is saved");
+                let next_function = values.next().expect("the next method is saved");
+                let done = values.next().expect("the iterator state is saved");
+                let iterator = IteratorRecord::new(iterator_object, next_function);
+                (frame.step, iterator, done.to_boolean(), values.next())
+            }
+            None => (0, get_iterator(interpreter, &value)?, false, None),
+        };
+
+        let result = (|| {
+            for (i, element) in self.elements().iter().enumerate().skip(start) {
+                let value = if let Some(value) = resumed_value.take() {
+                    value
+                } else if done {
+                    Value::undefined()
+                } else {
+                    // An error thrown by the iterator itself leaves it done, so it isn't closed.
+                    done = true;
+                    let next = iterator.next(interpreter)?;
+                    done = next.is_done();
+                    if done {
+                        Value::undefined()
+                    } else {
+                        next.into_value()
+                    }
+                };
+
+                if let Some(ref element) = element {
+                    bind_element(element, value.clone(), kind, interpreter).map_err(|err| {
+                        interpreter.save_frame(err, || {
+                            array_pattern_frame(i, &iterator, done, value.clone())
+                        })
+                    })?;
+                }
+            }
+
+            if let Some(rest) = self.rest() {
+                let array = match resumed_value.take() {
+                    Some(array) => array,
+                    None => {
+                        let mut values = Vec::new();
+                        while !done {
+                            done = true;
+                            let next = iterator.next(interpreter)?;
+                            done = next.is_done();
+                            if !done {
+                                values.push(next.into_value());
+                            }
+                        }
+
+                        let array = Array::new_array(interpreter)?;
+                        Array::add_to_array_object(&array, &values)?;
+                        array
+                    }
+                };
+                let step = self.elements().len();
+                bind_node(rest, array.clone(), kind, interpreter).map_err(|err| {
+                    interpreter.save_frame(err, || {
+                        array_pattern_frame(step, &iterator, done, array.clone())
+                    })
+                })?;
+            }
+
+            Ok(())
+        })();
+
+        // A suspended generator body continues with the iterator when it is resumed.
+        if done || interpreter.is_suspending() {
+            result
+        } else {
+            iterator.close(result, interpreter)
+        }
+    }
+}
+
+/// Creates the frame of an object pattern, with the object, the value being bound and the keys of
+/// the properties bound so far.
+fn object_pattern_frame(
+    step: usize,
+    object: &Value,
+    value: Value,
+    bound_keys: &[PropertyKey],
+) -> Frame {
+    let mut values = vec![object.clone(), value];
+    values.extend(bound_keys.iter().cloned().map(Value::from));
+    Frame::new(step, values)
+}
+
+/// Creates the frame of an array pattern, with the iterator and the value being bound.
+fn array_pattern_frame(step: usize, iterator: &IteratorRecord, done: bool, value: Value) -> Frame {
+    let values = vec![
+        iterator.iterator_object().clone(),
+        iterator.next_function().clone(),
+        Value::from(done),
+        value,
+    ];
+    Frame::new(step, values)
+}
+
+/// Binds a value to an element of a pattern, using the default value of the element if the value
+/// is `undefined`.
+///
+/// In a generator body, step 0 is the default value and step 1 the binding, whose frame has the
+/// value.
+fn bind_element(
+    element: &PatternElement,
+    value: Value,
+    kind: BindingKind,
+    interpreter: &mut Interpreter,
+) -> Result<(), Value> {
+    let value = match (interpreter.resume_frame(), element.init()) {
+        (Some(mut frame), _) if frame.step == 1 => frame.values.pop().expect("the value is saved"),
+        (_, Some(init)) if value.is_undefined() => init
+            .run(interpreter)
+            .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?,
+        _ => value,
+    };
+    bind_node(element.target(), value.clone(), kind, interpreter)
+        .map_err(|err| interpreter.save_frame(err, || Frame::new(1, vec![value.clone()])))
+}
+
+/// Binds a value to the target of an element, or to the rest target, of a pattern.
+fn bind_node(
+    target: &Node,
+    value: Value,
+    kind: BindingKind,
+    interpreter: &mut Interpreter,
+) -> Result<(), Value> {
+    match target {
+        Node::Pattern(ref pattern) => pattern.bind(value, kind, interpreter),
+        Node::Identifier(ref name) => bind_name(name.as_ref(), value, kind, interpreter),
+        _ => assign_to(target, value, interpreter),
+    }
+}
+
+/// Binds a value to a name, creating the binding in the environment the kind of binding lives in.
+fn bind_name(
+    name: &str,
+    value: Value,
+    kind: BindingKind,
+    interpreter: &mut Interpreter,
+) -> Result<(), Value> {
+    if let BindingKind::Assignment | BindingKind::Var = kind {
+        if interpreter.realm().environment.has_binding(name) {
+            return interpreter.set_mutable_binding(name, value);
+        }
+    }
+
+    let environment = &mut interpreter.realm_mut().environment;
+    match kind {
+        BindingKind::Assignment | BindingKind::Var => {
+            // Assigning to an undeclared name creates a deletable variable.
+            let deletion = kind == BindingKind::Assignment;
+            environment.create_mutable_binding(name.to_owned(), deletion, VariableScope::Function);
+            environment.initialize_binding(name, value);
+        }
+        BindingKind::Let => {
+            environment.create_mutable_binding(name.to_owned(), false, VariableScope::Block);
+            environment.initialize_binding(name, value);
+        }
+        BindingKind::Const => {
+            environment.create_immutable_binding(name.to_owned(), false, VariableScope::Block);
+            environment.initialize_binding(name, value);
+        }
+        BindingKind::Parameter => {
+            // A repeated parameter name refers to the same binding.
+            let declared =
+                GcCell::borrow(environment.get_current_environment_ref()).has_binding(name);
+            if declared {
+                environment.set_mutable_binding(name, value, true);
+            } else {
+                environment.create_mutable_binding(name.to_owned(), false, VariableScope::Block);
+                environment.initialize_binding(name, value);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use crate::exec;
+
+#[test]
+fn object_pattern() {
+    let scenario = r#"
+        const { a, b: c, d = 3, e = 4 } = { a: 1, b: 2, e: undefined };
+        [a, c, d, e].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,2,3,4\"");
+}
+
+#[test]
+fn object_pattern_computed_key() {
+    let scenario = r#"
+        let key = "x";
+        let { [key + "y"]: value } = { xy: 5 };
+        value;
+    "#;
+    assert_eq!(&exec(scenario), "5");
+}
+
+#[test]
+fn object_pattern_rest() {
+    let scenario = r#"
+        var { a, ...rest } = { a: 1, b: 2, c: 3 };
+        [a, rest.a === undefined, rest.b, rest.c].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,true,2,3\"");
+}
+
+#[test]
+fn object_pattern_null() {
+    let scenario = r#"
+        try {
+            let { a } = null;
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn array_pattern() {
+    let scenario = r#"
+        let [a, , b, c = 4, ...rest] = [1, 2, 3, undefined, 5, 6];
+        [a, b, c, rest.length, rest[0], rest[1]].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,3,4,2,5,6\"");
+}
+
+#[test]
+fn array_pattern_string() {
+    let scenario = r#"
+        const [a, b, ...c] = "abcd";
+        a + b + c.length;
+    "#;
+    assert_eq!(&exec(scenario), "\"ab2\"");
+}
+
+#[test]
+fn array_pattern_not_iterable() {
+    let scenario = r#"
+        try {
+            let [a] = {};
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn array_pattern_closes_iterator() {
+    let scenario = r#"
+        let closed = false;
+        let iterable = {};
+        iterable[Symbol.iterator] = function () {
+            let i = 0;
+            return {
+                next: function () { return { value: i++, done: false }; },
+                return: function () { closed = true; return {}; }
+            };
+        };
+        let [a, b] = iterable;
+        [a, b, closed].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"0,1,true\"");
+}
+
+#[test]
+fn nested_patterns() {
+    let scenario = r#"
+        const { a: [b, { c }], d: { e = 5 } = {} } = { a: [1, { c: 2 }] };
+        [b, c, e].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,2,5\"");
+}
+
+#[test]
+fn parameter_patterns() {
+    let scenario = r#"
+        function f({ a, b = 2 }, [c, d] = [3, 4], ...[e, f]) {
+            return [a, b, c, d, e, f].join();
+        }
+        f({ a: 1 }, undefined, 5, 6);
+    "#;
+    assert_eq!(&exec(scenario), "\"1,2,3,4,5,6\"");
+}
+
+#[test]
+fn parameter_defaults() {
+    let scenario = r#"
+        function f(a, b = a + 1) {
+            return b;
+        }
+        [f(1), f(1, 5), f(1, undefined)].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"2,5,2\"");
+}
+
+#[test]
+fn arrow_function_pattern() {
+    let scenario = r#"
+        let f = ({ a }, [b]) => a + b;
+        f({ a: 1 }, [2]);
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn catch_pattern() {
+    let scenario = r#"
+        try {
+            throw { message: "error", code: 3 };
+        } catch ({ message, code }) {
+            message + code;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "\"error3\"");
+}
+
+#[test]
+fn assignment_pattern() {
+    let scenario = r#"
+        let a = 1, b = 2;
+        [a, b] = [b, a];
+        let obj = {};
+        ({ x: obj.x, y: obj["y"] = 3 } = { x: 4 });
+        [a, b, obj.x, obj.y].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"2,1,4,3\"");
+}
+
+#[test]
+fn assignment_pattern_value() {
+    let scenario = r#"
+        let a;
+        let result = [a] = [1, 2];
+        result.length;
+    "#;
+    assert_eq!(&exec(scenario), "2");
+}
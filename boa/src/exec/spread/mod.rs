@@ -3,7 +3,8 @@ use crate::{builtins::value::ResultValue, syntax::ast::node::Spread};
 
 impl Executable for Spread {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
-        // TODO: for now we can do nothing but return the value as-is
+        // The values are expanded by the array literal, call or object literal containing the
+        // spread element, which only evaluate its operand.
         self.val().run(interpreter)
     }
 }
@@ -2,7 +2,10 @@
 
 use super::{Executable, Interpreter, InterpreterState};
 use crate::{
-    builtins::value::{ResultValue, Value},
+    builtins::{
+        generator::Frame,
+        value::{ResultValue, Value},
+    },
     syntax::ast::node::StatementList,
     BoaProfiler,
 };
@@ -10,31 +13,48 @@ use crate::{
 impl Executable for StatementList {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         let _timer = BoaProfiler::global().start_event("StatementList", "exec");
-        let mut obj = Value::null();
-        interpreter.set_current_state(InterpreterState::Executing);
-        for (i, item) in self.statements().iter().enumerate() {
-            let val = item.run(interpreter)?;
-            match interpreter.get_current_state() {
-                InterpreterState::Return => {
-                    // Early return.
-                    obj = val;
-                    break;
-                }
-                InterpreterState::Break(_label) => {
-                    // TODO, break to a label.
 
-                    // Early break.
-                    break;
-                }
-                _ => {
-                    // Continue execution
-                }
-            }
-            if i + 1 == self.statements().len() {
+        // A strict statement list, like a script or a function body starting with a
+        // `"use strict"` directive, runs all of its statements in strict mode.
+        if self.is_strict() && !interpreter.is_strict() {
+            interpreter.set_strict(true);
+            let result = run_statements(self, interpreter);
+            interpreter.set_strict(false);
+            return result;
+        }
+
+        run_statements(self, interpreter)
+    }
+}
+
+/// Runs the statements of a statement list, until one of them ends the list early.
+fn run_statements(list: &StatementList, interpreter: &mut Interpreter) -> ResultValue {
+    let mut obj = Value::null();
+    interpreter.set_current_state(InterpreterState::Executing);
+    // The step of a suspended generator body is the index of the statement it was suspended in.
+    let start = interpreter.resume_frame().map_or(0, |frame| frame.step);
+    for (i, item) in list.statements().iter().enumerate().skip(start) {
+        let val = item
+            .run(interpreter)
+            .map_err(|err| interpreter.save_frame(err, || Frame::new(i, Vec::new())))?;
+        match interpreter.get_current_state() {
+            InterpreterState::Return => {
+                // Early return.
                 obj = val;
+                break;
+            }
+            InterpreterState::Break(_) | InterpreterState::Continue(_) => {
+                // Early break, the enclosing statements handle the label.
+                break;
+            }
+            _ => {
+                // Continue execution
             }
         }
-
-        Ok(obj)
+        if i + 1 == list.statements().len() {
+            obj = val;
+        }
     }
+
+    Ok(obj)
 }
@@ -1,6 +1,9 @@
 use super::{Executable, Interpreter, InterpreterState};
 use crate::{
-    builtins::value::{ResultValue, Value},
+    builtins::{
+        generator::Frame,
+        value::{ResultValue, Value},
+    },
     syntax::ast::node::Switch,
 };
 
@@ -10,41 +13,78 @@ mod tests;
 impl Executable for Switch {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         let default = self.default();
-        let val = self.val().run(interpreter)?;
+        // Step 0 is the discriminant, step `2 * i + 1` the condition of the case `i` and step
+        // `2 * i + 2` its body, and the last step is the default body. The frame has the
+        // discriminant.
+        let (step, val) = match interpreter.resume_frame() {
+            Some(mut frame) if frame.step > 0 => (frame.step, frame.values.remove(0)),
+            _ => {
+                let val = self
+                    .val()
+                    .run(interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+                (1, val)
+            }
+        };
+        let save = |err, step, interpreter: &mut Interpreter| {
+            interpreter.save_frame(err, || Frame::new(step, vec![val.clone()]))
+        };
+
         let mut result = Value::null();
-        let mut matched = false;
+        let mut matched = step > 1;
         interpreter.set_current_state(InterpreterState::Executing);
 
         // If a case block does not end with a break statement then subsequent cases will be run without
         // checking their conditions until a break is encountered.
-        let mut fall_through: bool = false;
+        let mut fall_through: bool = step > 1 && step % 2 == 0;
 
-        for case in self.cases().iter() {
+        let start = (step - 1) / 2;
+        for (i, case) in self.cases().iter().enumerate().skip(start) {
             let cond = case.condition();
             let block = case.body();
-            if fall_through || val.strict_equals(&cond.run(interpreter)?) {
-                matched = true;
-                let result = block.run(interpreter)?;
-                match interpreter.get_current_state() {
-                    InterpreterState::Return => {
-                        // Early return.
-                        return Ok(result);
-                    }
-                    InterpreterState::Break(_label) => {
-                        // Break statement encountered so therefore end switch statement.
-                        interpreter.set_current_state(InterpreterState::Executing);
-                        break;
-                    }
-                    _ => {
-                        // Continuing execution / falling through to next case statement(s).
-                        fall_through = true;
-                    }
+            let resumed_body = i == start && step == 2 * i + 2;
+            if !fall_through && !resumed_body {
+                let cond = cond
+                    .run(interpreter)
+                    .map_err(|err| save(err, 2 * i + 1, interpreter))?;
+                if !val.strict_equals(&cond) {
+                    continue;
+                }
+            }
+
+            matched = true;
+            let result = block
+                .run(interpreter)
+                .map_err(|err| save(err, 2 * i + 2, interpreter))?;
+            match interpreter.get_current_state() {
+                InterpreterState::Return => {
+                    // Early return.
+                    return Ok(result);
+                }
+                InterpreterState::Break(None) => {
+                    // Break statement encountered so therefore end switch statement.
+                    interpreter.set_current_state(InterpreterState::Executing);
+                    break;
+                }
+                InterpreterState::Break(Some(_)) | InterpreterState::Continue(_) => {
+                    // Labelled breaks and continues target an enclosing statement.
+                    return Ok(result);
+                }
+                _ => {
+                    // Continuing execution / falling through to next case statement(s).
+                    fall_through = true;
                 }
             }
         }
-        if !matched {
+        let default_step = 2 * self.cases().len() + 1;
+        if !matched || step == default_step {
             if let Some(default) = default {
-                result = default.run(interpreter)?;
+                result = default
+                    .run(interpreter)
+                    .map_err(|err| save(err, default_step, interpreter))?;
+                if let InterpreterState::Break(None) = interpreter.get_current_state() {
+                    interpreter.set_current_state(InterpreterState::Executing);
+                }
             }
         }
         Ok(result)
@@ -0,0 +1,150 @@
+//! Template literal execution.
+
+use super::{call::evaluate_callee, Executable, Interpreter, InterpreterState};
+use crate::{
+    builtins::{
+        generator::Frame,
+        property::{Attribute, Property},
+        value::{ResultValue, Value},
+        Array,
+    },
+    syntax::ast::node::{TaggedTemplate, TemplateElement, TemplateLit},
+    BoaProfiler,
+};
+
+#[cfg(test)]
+mod tests;
+
+impl Executable for TemplateLit {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "exec");
+        // In a generator body, the step is the index of the element, and the frame has the string
+        // of the previous elements.
+        let (start, mut result) = match interpreter.resume_frame() {
+            Some(frame) => {
+                let result = frame.values[0]
+                    .as_string()
+                    .expect("the string is saved")
+                    .to_string();
+                (frame.step, result)
+            }
+            None => (0, String::new()),
+        };
+
+        for (i, element) in self.elements().iter().enumerate().skip(start) {
+            match element {
+                TemplateElement::String(s) => result.push_str(s),
+                TemplateElement::Expr(expr) => {
+                    let value = expr.run(interpreter).map_err(|err| {
+                        interpreter
+                            .save_frame(err, || Frame::new(i, vec![Value::from(result.clone())]))
+                    })?;
+                    result.push_str(&interpreter.to_string(&value)?);
+                }
+            }
+        }
+
+        Ok(Value::from(result))
+    }
+}
+
+impl Executable for TaggedTemplate {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
+        // In a generator body, step 0 is the tag and step `i + 1` the expression `i`, whose frame
+        // has the this value, the function and the previous arguments.
+        let (start, this, func, mut args) = match interpreter.resume_frame() {
+            Some(frame) if frame.step > 0 => {
+                let mut values = frame.values.into_iter();
+                let this = values.next().expect("the this value is saved");
+                let func = values.next().expect("the function is saved");
+                (frame.step - 1, this, func, values.collect())
+            }
+            _ => {
+                let (this, func) = evaluate_callee(self.tag(), interpreter)
+                    .map_err(|err| interpreter.save_frame(err, || Frame::new(0, Vec::new())))?;
+                let mut args = Vec::with_capacity(self.exprs().len() + 1);
+                args.push(get_template_object(self, interpreter)?);
+                (0, this, func, args)
+            }
+        };
+        for (i, expr) in self.exprs().iter().enumerate().skip(start) {
+            let value = expr.run(interpreter).map_err(|err| {
+                interpreter.save_frame(err, || {
+                    let mut values = vec![this.clone(), func.clone()];
+                    values.extend(args.iter().cloned());
+                    Frame::new(i + 1, values)
+                })
+            })?;
+            args.push(value);
+        }
+
+        let result = interpreter.call(&func, &this, &args);
+
+        // unset the early return flag
+        interpreter.set_current_state(InterpreterState::Executing);
+
+        result
+    }
+}
+
+/// Gets the strings array passed to the tag function of a tagged template.
+///
+/// The array is created the first time the template is evaluated, and reused afterwards.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-gettemplateobject
+fn get_template_object(template: &TaggedTemplate, interpreter: &mut Interpreter) -> ResultValue {
+    if let Some(strings) = interpreter.realm().template_object(template.site()) {
+        return Ok(strings);
+    }
+
+    let raws: Vec<Value> = template
+        .raws()
+        .iter()
+        .map(|raw| Value::from(raw.as_ref()))
+        .collect();
+    let raw_obj = Array::new_array(interpreter)?;
+    Array::add_to_array_object(&raw_obj, &raws)?;
+
+    let cookeds: Vec<Value> = template
+        .cookeds()
+        .iter()
+        .map(|cooked| cooked.as_deref().map_or_else(Value::undefined, Value::from))
+        .collect();
+    let template_obj = Array::new_array(interpreter)?;
+    Array::add_to_array_object(&template_obj, &cookeds)?;
+
+    template_obj.set_property(
+        "raw",
+        Property::data_descriptor(
+            raw_obj.clone(),
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        ),
+    );
+
+    freeze(&raw_obj);
+    freeze(&template_obj);
+
+    interpreter
+        .realm_mut()
+        .set_template_object(template.site(), template_obj.clone());
+
+    Ok(template_obj)
+}
+
+/// Makes all the properties of an object read-only and non-configurable, and prevents new
+/// properties from being added to it.
+fn freeze(object: &Value) {
+    if let Some(mut object) = object.as_object_mut() {
+        for property in object.properties_mut().values_mut() {
+            property.attribute.set_configurable(false);
+            if property.is_data_descriptor() {
+                property.attribute.set_writable(false);
+            }
+        }
+        object.prevent_extensions();
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{exec, exec::Interpreter, forward, realm::Realm};
+
+#[test]
+fn template_literal() {
+    let scenario = r#"
+        let a = 10;
+        let obj = { toString() { return "obj"; } };
+        `a = ${a}, a * 2 = ${a * 2}, ${obj} and ${null}`;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"a = 10, a * 2 = 20, obj and null\"");
+}
+
+#[test]
+fn template_literal_nested() {
+    let scenario = r#"
+        let items = ["a", "b"];
+        `<${items.map(item => `<${item}>`).join("")}>`;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"<<a><b>>\"");
+}
+
+#[test]
+fn template_literal_escapes() {
+    let scenario = r#"
+        `\x41B\u{43}\$\{\`${"\n"}` === "ABC${`\n";
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn tagged_template() {
+    let scenario = r#"
+        function tag(strings, ...values) {
+            return strings.raw.join("|") + ":" + strings.join("|") + ":" + values.join("|");
+        }
+        tag`a\n${1}b${2 + 3}c`;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"a\\n|b|c:a\n|b|c:1|5\"");
+}
+
+#[test]
+fn tagged_template_invalid_escape() {
+    let scenario = r#"
+        function tag(strings) {
+            return strings[0] === undefined && strings.raw[0] === "\\unicode";
+        }
+        tag`\unicode`;
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn tagged_template_this() {
+    let scenario = r#"
+        let obj = {
+            prefix: "> ",
+            tag(strings, value) {
+                return this.prefix + strings[0] + value;
+            }
+        };
+        obj.tag`value: ${42}`;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"> value: 42\"");
+}
+
+#[test]
+fn tagged_template_cached() {
+    let scenario = r#"
+        function tag(strings) {
+            return strings;
+        }
+        function get() {
+            return tag`a${1}b`;
+        }
+        let first = get();
+        let second = get();
+        let other = tag`a${1}b`;
+        (first === second) + " " + (first === other);
+    "#;
+
+    assert_eq!(&exec(scenario), "\"true false\"");
+}
+
+#[test]
+fn tagged_template_of_dropped_code_is_collected() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let weak = new WeakRef(eval("(strings => strings)`a`"));
+        for (let i = 0; i < 100; i++) {
+            eval("(strings => strings)`b`");
+        }
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    gc::force_collect();
+    assert_eq!(forward(&mut engine, "weak.deref()"), "undefined");
+}
+
+#[test]
+fn tagged_template_frozen() {
+    let scenario = r#"
+        function tag(strings) {
+            return strings;
+        }
+        let strings = tag`a${1}b`;
+        strings[0] = "changed";
+        strings.raw[1] = "changed";
+        strings.extra = 1;
+        strings[0] + strings.raw[1] + strings.length + strings.extra;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"ab2undefined\"");
+}
@@ -1,8 +1,11 @@
 use crate::{
-    builtins::{Number, Value},
+    builtins::{
+        property::{Attribute, Property},
+        Number, Value,
+    },
     exec,
     exec::Interpreter,
-    forward,
+    forward, forward_val,
     realm::Realm,
 };
 
@@ -148,6 +151,119 @@ fn array_rest_with_arguments() {
     assert_eq!(one, String::from("[ 1, 2, 3, 4, 5, 6 ]"));
 }
 
+#[test]
+fn spread_arguments_after_spread() {
+    let scenario = r#"
+        function join(...values) {
+            return values.join("-");
+        }
+        var a = [1, 2];
+        var b = [4];
+        join(...a, 3, ...b, 5);
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1-2-3-4-5\"");
+}
+
+#[test]
+fn spread_iterables() {
+    let scenario = r#"
+        function* numbers() {
+            yield 1;
+            yield 2;
+        }
+        var map = new Map([["a", 1]]);
+        [...numbers(), ..."xy", ...map.keys(), ...[].values()].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,x,y,a\"");
+
+    let scenario = r#"
+        function Point(x, y) {
+            this.x = x;
+            this.y = y;
+        }
+        var point = new Point(...[1, 2]);
+        point.x + point.y;
+        "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn spread_not_iterable() {
+    let scenario = r#"
+        function f() {}
+        var errors = [];
+        try { f(...1); } catch (e) { errors.push(e instanceof TypeError); }
+        try { [...{}]; } catch (e) { errors.push(e instanceof TypeError); }
+        try { f(...undefined); } catch (e) { errors.push(e instanceof TypeError); }
+        errors.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,true\"");
+}
+
+#[test]
+fn object_spread() {
+    let scenario = r#"
+        var symbol = Symbol("s");
+        var source = { a: 1, b: 2, get c() { return this.a + 2; } };
+        source[symbol] = 4;
+        Object.defineProperty(source, "hidden", { value: 5, enumerable: false });
+        var copy = { b: 0, ...source, a: 10, ...null, ...undefined };
+        source.a = 100;
+        [
+            copy.a,
+            copy.b,
+            copy.c,
+            copy[symbol],
+            "hidden" in copy,
+            copy.c,
+        ].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"10,2,3,4,false,3\"");
+}
+
+#[test]
+fn object_spread_string_and_proxy() {
+    let scenario = r#"
+        var log = [];
+        var target = { a: 1, b: 2 };
+        Object.defineProperty(target, "hidden", { value: 3, enumerable: false });
+        var proxy = new Proxy(target, {
+            ownKeys(t) { log.push("ownKeys"); return Reflect.ownKeys(t); },
+            getOwnPropertyDescriptor(t, key) {
+                log.push("getOwnPropertyDescriptor " + key);
+                return Reflect.getOwnPropertyDescriptor(t, key);
+            },
+            get(t, key, receiver) { log.push("get " + key); return Reflect.get(t, key, receiver); }
+        });
+        var copy = { ...proxy };
+        var string = { ..."xy" };
+        const { ...rest } = "ab";
+        const { a, ...others } = proxy;
+        [
+            copy.a,
+            copy.b,
+            "hidden" in copy,
+            string[0] + string[1],
+            Reflect.ownKeys(string).length,
+            rest[0] + rest[1],
+            Reflect.ownKeys(others).join("|"),
+            log.join("|"),
+        ].join();
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"1,2,false,xy,2,ab,b,ownKeys|getOwnPropertyDescriptor a|get a|getOwnPropertyDescriptor b|\
+         get b|getOwnPropertyDescriptor hidden|get a|ownKeys|getOwnPropertyDescriptor b|get b|\
+         getOwnPropertyDescriptor hidden\""
+    );
+}
+
 #[test]
 fn array_field_set() {
     let element_changes = r#"
@@ -886,6 +1002,19 @@ fn to_bigint() {
     assert!(engine.to_bigint(&Value::string("100")).is_ok());
 }
 
+#[test]
+fn to_bigint_strict() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+
+    assert!(engine.to_bigint_strict(&Value::null()).is_err());
+    assert!(engine.to_bigint_strict(&Value::undefined()).is_err());
+    assert!(engine.to_bigint_strict(&Value::integer(55)).is_err());
+    assert!(engine.to_bigint_strict(&Value::rational(10.0)).is_err());
+    assert!(engine.to_bigint_strict(&Value::boolean(true)).is_ok());
+    assert!(engine.to_bigint_strict(&Value::string("100")).is_ok());
+}
+
 #[test]
 fn to_index() {
     let realm = Realm::create();
@@ -1190,3 +1319,648 @@ fn comma_operator() {
     "#;
     assert_eq!(&exec(scenario), "2");
 }
+
+#[test]
+fn object_literal_accessors() {
+    let scenario = r#"
+        var log = [];
+        var obj = {
+            _value: 1,
+            get value() { return this._value * 10; },
+            set value(v) { log.push(v); this._value = v; }
+        };
+        obj.value = 5;
+        obj.value += 1;
+        log.push(obj.value);
+        log.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"5,51,510\"");
+}
+
+#[test]
+fn inherited_accessors_receiver() {
+    let scenario = r#"
+        var proto = {
+            get value() { return this._value; },
+            set value(v) { this._value = v * 2; }
+        };
+        var obj = Object.create(proto);
+        obj.value = 2;
+        [obj.value, obj.hasOwnProperty("_value"), typeof proto._value].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"4,true,undefined\"");
+}
+
+#[test]
+fn accessor_without_setter() {
+    let scenario = r#"
+        var obj = { get value() { return 1; } };
+        obj.value = 2;
+        obj.value;
+        "#;
+
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn iteration_protocol_accessors() {
+    let scenario = r#"
+        var log = [];
+        var iterable = {
+            get [Symbol.iterator]() {
+                log.push("iterator");
+                return function () {
+                    var i = 0;
+                    return {
+                        get next() {
+                            log.push("next");
+                            return () => ({
+                                get done() { return i >= 2; },
+                                get value() { return i++; }
+                            });
+                        }
+                    };
+                };
+            }
+        };
+        var values = [];
+        for (var x of iterable) { values.push(x); }
+        [...values, ...log].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"0,1,iterator,next\"");
+}
+
+#[test]
+fn iteration_protocol_getter_errors() {
+    let scenario = r#"
+        var errors = [];
+        var throwing = { get [Symbol.iterator]() { throw "iterator"; } };
+        try { [...throwing]; } catch (e) { errors.push(e); }
+
+        var closed = {
+            [Symbol.iterator]() {
+                return {
+                    next() { return { done: false, value: 1 }; },
+                    get return() { throw "return"; }
+                };
+            }
+        };
+        try { for (var x of closed) { break; } } catch (e) { errors.push(e); }
+
+        var result = {
+            [Symbol.iterator]() {
+                return { next() { return { get done() { throw "done"; } }; } };
+            }
+        };
+        try { for (var x of result) {} } catch (e) { errors.push(e); }
+        errors.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"iterator,return,done\"");
+}
+
+#[test]
+fn assign_to_constant() {
+    let scenario = r#"
+        const a = 1;
+        let error;
+        try {
+            a = 2;
+        } catch (e) {
+            error = e;
+        }
+        [error instanceof TypeError, error.message, a].join();
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"true,cannot assign to the constant a,1\""
+    );
+}
+
+#[test]
+fn optional_chaining() {
+    let scenario = r#"
+        var obj = {
+            value: 1,
+            nested: { method() { return this.value; }, value: 2 },
+            f() { return this.value; }
+        };
+        var nothing = null;
+        var key = "value";
+        [
+            obj?.value,
+            obj?.[key],
+            obj.nested?.method(),
+            obj.f?.(),
+            obj.missing?.() === undefined,
+            nothing?.a.b.c() === undefined,
+            nothing?.[key] === undefined,
+            typeof obj.missing?.value.deeper,
+        ].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,1,2,1,true,true,true,undefined\"");
+}
+
+#[test]
+fn parenthesized_optional_call() {
+    let scenario = r#"
+        var o = {
+            value: 1,
+            f() { return this.value; },
+            nested: { value: 2, g() { return this.value; } }
+        };
+        [(o?.f)(), (o?.["f"])(), (o.nested?.g)(), (o?.nested.g)()].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,1,2,2\"");
+
+    let scenario = r#"
+        var error;
+        var nothing = null;
+        try {
+            (nothing?.f)();
+        } catch (e) {
+            error = e;
+        }
+        error instanceof TypeError;
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn optional_chaining_short_circuits() {
+    let scenario = r#"
+        var calls = 0;
+        function count() { calls++; return "a"; }
+        var nothing;
+        nothing?.[count()];
+        nothing?.a(count());
+        nothing?.(count());
+        calls;
+        "#;
+
+    assert_eq!(&exec(scenario), "0");
+
+    let scenario = r#"
+        var error;
+        try {
+            var obj = { a: null };
+            obj?.a.b;
+        } catch (e) {
+            error = e;
+        }
+        error instanceof TypeError;
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn invalid_assignment_targets() {
+    let scenario = r#"
+        function f() { return 1; }
+        let errors = [];
+        try {
+            f()++;
+        } catch (e) {
+            errors.push(e.name);
+        }
+        try {
+            f() = 1;
+        } catch (e) {
+            errors.push(e.name);
+        }
+        errors.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"ReferenceError,ReferenceError\"");
+}
+
+#[test]
+fn nullish_coalescing() {
+    let scenario = r#"
+        var calls = 0;
+        function count() { calls++; return "rhs"; }
+        [
+            null ?? "a",
+            undefined ?? "b",
+            0 ?? count(),
+            "" ?? count(),
+            false ?? count(),
+            calls,
+        ].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a,b,0,,false,0\"");
+}
+
+#[test]
+fn logical_operators_return_operands() {
+    let scenario = r#"
+        [0 || "a", "b" || "c", 0 && "d", "e" && "f"].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a,b,0,f\"");
+}
+
+#[test]
+fn logical_assignment() {
+    let scenario = r#"
+        var a = null;
+        var b = 0;
+        var c = 1;
+        a ??= "a";
+        b ||= "b";
+        c &&= "c";
+        var obj = { x: 0 };
+        obj.x ||= 5;
+        obj["y"] ??= 6;
+        [a, b, c, obj.x, obj.y].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a,b,c,5,6\"");
+}
+
+#[test]
+fn logical_assignment_short_circuits() {
+    let scenario = r#"
+        var log = [];
+        var obj = {
+            get value() { log.push("get"); return 1; },
+            set value(v) { log.push("set"); }
+        };
+        function rhs() { log.push("rhs"); return 2; }
+        obj.value ||= rhs();
+        obj.value ??= rhs();
+        obj.value &&= rhs();
+        log.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"get,get,get,rhs,set\"");
+}
+
+#[test]
+fn object_literal_computed_names() {
+    let scenario = r#"
+        var key = "b";
+        var obj = { a: 1, [key]: 2, [key + "c"]() { return 3; }, 'd e': 4, 5: 5, 0.5: 6 };
+        [obj.a, obj.b, obj.bc(), obj["d e"], obj[5], obj["0.5"]].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,3,4,5,6\"");
+}
+
+#[test]
+fn object_literal_computed_names_evaluation_order() {
+    let scenario = r#"
+        var log = [];
+        var obj = {
+            [(log.push("a"), "a")]: log.push("1"),
+            [(log.push("b"), "b")]: log.push("2")
+        };
+        log.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a,1,b,2\"");
+}
+
+#[test]
+fn object_literal_symbol_names() {
+    let scenario = r#"
+        var sym = Symbol("s");
+        var iterable = {
+            [sym]: 1,
+            *[Symbol.iterator]() {
+                yield 1;
+                yield 2;
+            }
+        };
+        var sum = 0;
+        for (var value of iterable) {
+            sum += value;
+        }
+        [iterable[sym], sum, [...iterable].length].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,3,2\"");
+}
+
+#[test]
+fn object_literal_computed_accessors() {
+    let scenario = r#"
+        var key = "value";
+        var obj = {
+            _value: 1,
+            get [key]() { return this._value; },
+            set [key](v) { this._value = v; }
+        };
+        obj.value = 7;
+        obj.value;
+        "#;
+
+    assert_eq!(&exec(scenario), "7");
+}
+
+#[test]
+fn strict_mode_implicit_globals() {
+    let sloppy = r#"
+        implicit = 1;
+        implicit;
+        "#;
+    assert_eq!(&exec(sloppy), "1");
+
+    let strict = r#"
+        "use strict";
+        var error;
+        try { implicit = 1; } catch (e) { error = e; }
+        error instanceof ReferenceError;
+        "#;
+    assert_eq!(&exec(strict), "true");
+
+    let strict_function = r#"
+        function f() {
+            "use strict";
+            implicit = 1;
+        }
+        var error;
+        try { f(); } catch (e) { error = e; }
+        error instanceof ReferenceError;
+        "#;
+    assert_eq!(&exec(strict_function), "true");
+}
+
+#[test]
+fn strict_mode_read_only_properties() {
+    let scenario = r#"
+        var obj = Object.defineProperty({}, "a", { value: 1, writable: false });
+        var getter = { get b() { return 2; } };
+        function sloppy() {
+            obj.a = 2;
+            getter.b = 3;
+            return obj.a + getter.b;
+        }
+        function strict() {
+            "use strict";
+            var errors = [];
+            try { obj.a = 2; } catch (e) { errors.push(e instanceof TypeError); }
+            try { getter["b"] = 3; } catch (e) { errors.push(e instanceof TypeError); }
+            try { obj.a += 1; } catch (e) { errors.push(e instanceof TypeError); }
+            return errors;
+        }
+        [sloppy(), strict()].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"3,true,true,true\"");
+}
+
+#[test]
+fn strict_mode_this() {
+    let scenario = r#"
+        function sloppy() { return this; }
+        function strict() { "use strict"; return this; }
+        var arrow = () => { "use strict"; return typeof this; };
+        [sloppy() === this, strict() === undefined, arrow()].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,object\"");
+}
+
+#[test]
+fn strict_mode_class_code() {
+    let scenario = r#"
+        class A {
+            m() { return this; }
+            assign() { implicitInClass = 1; }
+        }
+        var m = new A().m;
+        var error;
+        try { new A().assign(); } catch (e) { error = e; }
+        [m() === undefined, error instanceof ReferenceError].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true\"");
+}
+
+#[test]
+fn embedder_calls_and_constructs() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    forward(
+        &mut engine,
+        r#"
+        let offset = 10;
+        function add(a, b) { return this.base + a + b + offset; }
+        class Point { constructor(x) { this.x = x; } }
+        "#,
+    );
+
+    let add = engine.get_global("add").unwrap();
+    let this = Value::new_object(Some(engine.global()));
+    this.set_field("base", 100);
+    let sum = engine
+        .call(&add, &this, &[Value::from(1), Value::from(2)])
+        .unwrap();
+    assert_eq!(sum.to_number(), 113.0);
+    assert_eq!(engine.get_global("offset").unwrap().to_number(), 10.0);
+
+    let point = engine.get_global("Point").unwrap();
+    let instance = engine.construct(&point, &[Value::from(3)], &point).unwrap();
+    assert_eq!(engine.get_field(&instance, "x").unwrap().to_number(), 3.0);
+
+    let err = engine.get_global("missing").unwrap_err();
+    assert_eq!(err.get_field("name").to_string(), "\"ReferenceError\"");
+    let err = engine
+        .call(&Value::from(1), &Value::undefined(), &[])
+        .unwrap_err();
+    assert_eq!(err.get_field("name").to_string(), "\"TypeError\"");
+}
+
+#[test]
+fn embedder_properties() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let object = forward_val(
+        &mut engine,
+        r#"
+        var log = [];
+        var object = {
+            b: 1,
+            get a() { log.push("get"); return 2; },
+            set a(value) { log.push("set " + value); },
+            [Symbol.iterator]: null,
+            0: 'zero',
+        };
+        object
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(engine.get_field(&object, "a").unwrap().to_number(), 2.0);
+    assert!(engine.set_field(&object, "a", 3).unwrap());
+    assert_eq!(forward(&mut engine, "log.join()"), "\"get,set 3\"");
+
+    let keys: Vec<String> = engine
+        .own_property_keys(&object)
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(keys, ["0", "b", "a", "Symbol(Symbol.iterator)"]);
+
+    let read_only = Property::data_descriptor(Value::from(4), Attribute::READONLY);
+    assert!(engine
+        .define_property(&object, "c", read_only.clone())
+        .unwrap());
+    assert!(!engine.set_field(&object, "c", 5).unwrap());
+    assert!(engine.define_property(&object, "c", read_only).unwrap());
+    let redefined = Property::data_descriptor(Value::from(6), Attribute::READONLY);
+    assert!(!engine.define_property(&object, "c", redefined).unwrap());
+    assert!(!engine.delete_property(&object, "c").unwrap());
+    assert!(engine.delete_property(&object, "b").unwrap());
+    assert!(engine.delete_property(&object, "missing").unwrap());
+    assert_eq!(
+        forward(&mut engine, "typeof object.b + object.c"),
+        "\"undefined4\""
+    );
+
+    let err = engine
+        .define_property(&Value::from(1), "a", Property::empty())
+        .unwrap_err();
+    assert_eq!(err.get_field("name").to_string(), "\"TypeError\"");
+}
+
+#[test]
+fn native_closures() {
+    use crate::builtins::function::make_closure_fn;
+    use gc::{Gc, GcCell};
+    use std::{cell::Cell, rc::Rc};
+
+    let calls = Rc::new(Cell::new(0));
+    let counted_calls = calls.clone();
+    let history = Gc::new(GcCell::new(Vec::new()));
+    let realm = Realm::create()
+        .register_global_func("count", move |_, _, _| {
+            counted_calls.set(counted_calls.get() + 1);
+            Ok(Value::from(counted_calls.get()))
+        })
+        .register_global_closure(
+            "remember",
+            |_, args, history: &Gc<GcCell<Vec<Value>>>, _| {
+                history.borrow_mut().extend(args.iter().cloned());
+                Ok(Value::from(history.borrow().len()))
+            },
+            history.clone(),
+        );
+    let mut engine = Interpreter::new(realm);
+
+    assert_eq!(forward(&mut engine, "count(); count()"), "2");
+    assert_eq!(calls.get(), 2);
+
+    // The captured objects are traced, so they outlive the code that created them.
+    forward(&mut engine, "remember({ name: 'a' }, { name: 'b' })");
+    gc::force_collect();
+    assert_eq!(history.borrow()[1].get_field("name").to_string(), "\"b\"");
+
+    let object = forward_val(&mut engine, "var object = {}; object").unwrap();
+    let prefix = Value::from("value: ");
+    make_closure_fn(
+        |this, args, prefix: &Value, interpreter| {
+            let value = interpreter.get_field(this, "value")?;
+            let suffix = args.first().cloned().unwrap_or_default();
+            Ok(Value::from(format!(
+                "{}{}{}",
+                interpreter.to_string(prefix)?,
+                interpreter.to_string(&value)?,
+                interpreter.to_string(&suffix)?
+            )))
+        },
+        prefix,
+        "describe",
+        &object,
+        1,
+        &engine,
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "object.value = 1; [object.describe('!'), object.describe.length].join()"
+        ),
+        "\"value: 1!,1\""
+    );
+}
+
+#[test]
+fn function_constructor() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        let add = new Function("a", "b", "return a + b");
+        let sum = Function("a, b", "c", "return a + b + c");
+        function local() {
+            let hidden = 1;
+            return Function("try { return hidden } catch (e) { return e.toString() }")();
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "add(1, 2)"), "3");
+    assert_eq!(forward(&mut engine, "sum(1, 2, 3)"), "6");
+    assert_eq!(forward(&mut engine, "Function('return 1')()"), "1");
+    assert_eq!(forward(&mut engine, "Function()()"), "undefined");
+    assert_eq!(forward(&mut engine, "add.length"), "2");
+    assert_eq!(forward(&mut engine, "add.name"), "\"anonymous\"");
+    assert_eq!(forward(&mut engine, "add instanceof Function"), "true");
+    assert_eq!(
+        forward(&mut engine, "local()"),
+        "\"ReferenceError: hidden is not defined\""
+    );
+}
+
+#[test]
+fn function_constructor_as_method() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let init = r#"
+        var o = { m: Function };
+        var f = o.m("return 3");
+        class Sub extends Function {}
+        var sub = new Sub("return 4");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "f()"), "3");
+    assert_eq!(forward(&mut engine, "f !== o"), "true");
+    assert_eq!(forward(&mut engine, "typeof o"), "\"object\"");
+    assert_eq!(forward(&mut engine, "o.m === Function"), "true");
+    assert_eq!(forward(&mut engine, "sub()"), "4");
+    assert_eq!(forward(&mut engine, "sub instanceof Sub"), "true");
+}
+
+#[test]
+fn function_constructor_syntax_error() {
+    let realm = Realm::create();
+    let mut engine = Interpreter::new(realm);
+    let check = |source: &str| {
+        format!(
+            "try {{ {}; 'no error' }} catch (e) {{ e instanceof SyntaxError }}",
+            source
+        )
+    };
+    assert_eq!(forward(&mut engine, &check("Function('return (')")), "true");
+    assert_eq!(
+        forward(&mut engine, &check("Function('}); (function() {')")),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, &check("Function('/*', '*/) {')")),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, &check("Function('a', 'b', 'return a')")),
+        "\"no error\""
+    );
+}
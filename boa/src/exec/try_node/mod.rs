@@ -1,9 +1,12 @@
 //! Try..catch node execution.
 
-use super::{Executable, Interpreter};
+use super::{BindingKind, Executable, Interpreter, InterpreterState};
 use crate::{
-    builtins::value::ResultValue,
-    environment::lexical_environment::{new_declarative_environment, VariableScope},
+    builtins::{
+        generator::Frame,
+        value::{ResultValue, Value},
+    },
+    environment::lexical_environment::new_declarative_environment,
     syntax::ast::node::Try,
     BoaProfiler,
 };
@@ -14,41 +17,106 @@ mod tests;
 impl Executable for Try {
     fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
         let _timer = BoaProfiler::global().start_event("Try", "exec");
-        let res = self.block().run(interpreter).map_or_else(
-            |err| {
-                if let Some(catch) = self.catch() {
-                    {
-                        let env = &mut interpreter.realm_mut().environment;
-                        env.push(new_declarative_environment(Some(
-                            env.get_current_environment_ref().clone(),
-                        )));
-
-                        if let Some(param) = catch.parameter() {
-                            env.create_mutable_binding(
-                                param.to_owned(),
-                                false,
-                                VariableScope::Block,
-                            );
-
-                            env.initialize_binding(param, err);
-                        }
-                    }
-
-                    let res = catch.block().run(interpreter);
-
-                    // pop the block env
-                    let _ = interpreter.realm_mut().environment.pop();
-
-                    res
-                } else {
-                    Err(err)
+        // Step 0 is the `try` block, step 1 the binding of the `catch` parameter, step 2 the
+        // `catch` block, and steps 3 and 4 the `finally` block after a result or an error. The
+        // frame of the `catch` parameter has the error, and the frame of the `finally` block has
+        // the pending result and the state it was entered with.
+        let frame = interpreter.resume_frame();
+        let step = frame.as_ref().map_or(0, |frame| frame.step);
+
+        let res = if step >= 3 {
+            let mut frame = frame.expect("the finally block was resumed");
+            let value = frame.values.remove(0);
+            return self.run_finally(
+                if step == 3 { Ok(value) } else { Err(value) },
+                frame.state,
+                interpreter,
+            );
+        } else if let Some(mut frame) = frame.filter(|frame| frame.step > 0) {
+            let err = frame.values.pop().unwrap_or_default();
+            self.run_catch(Some(frame.step), err, interpreter)
+        } else {
+            match self.block().run(interpreter) {
+                Err(err) if interpreter.is_suspending() => {
+                    Err(interpreter.save_frame(err, || Frame::new(0, Vec::new())))
                 }
-            },
-            Ok,
-        );
+                Err(err) if self.catch().is_some() => self.run_catch(None, err, interpreter),
+                res => res,
+            }
+        };
+        if interpreter.is_suspending() {
+            return res;
+        }
+
+        if self.finally().is_some() {
+            // The `finally` block runs even if the `try` or `catch` blocks were left by a `break`,
+            // `continue` or `return`, which carries on unless the `finally` block is left too.
+            let state = interpreter.get_current_state().clone();
+            interpreter.set_current_state(InterpreterState::Executing);
+            return self.run_finally(res, state, interpreter);
+        }
+
+        res
+    }
+}
+
+impl Try {
+    /// Runs the `catch` block with the given error, or resumes it from the given step.
+    fn run_catch(
+        &self,
+        step: Option<usize>,
+        err: Value,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let catch = self.catch().expect("the try statement has a catch block");
+        // A `catch` block resumed in a generator body already has its environment.
+        if step.is_none() {
+            let env = &mut interpreter.realm_mut().environment;
+            env.push(new_declarative_environment(Some(
+                env.get_current_environment_ref().clone(),
+            )));
+        }
+
+        let res = match catch.parameter() {
+            Some(param) if step.unwrap_or(1) == 1 => param
+                .bind(err.clone(), BindingKind::Let, interpreter)
+                .map_err(|e| interpreter.save_frame(e, || Frame::new(1, vec![err]))),
+            _ => Ok(()),
+        }
+        .and_then(|_| {
+            catch
+                .block()
+                .run(interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(2, Vec::new())))
+        });
+
+        // pop the block env, unless it is kept by a suspended generator body
+        if !interpreter.is_suspending() {
+            let _ = interpreter.realm_mut().environment.pop();
+        }
 
-        if let Some(finally) = self.finally() {
-            finally.run(interpreter)?;
+        res
+    }
+
+    /// Runs the `finally` block, which the `try` or `catch` blocks were left with the given
+    /// result and state.
+    fn run_finally(
+        &self,
+        res: ResultValue,
+        state: InterpreterState,
+        interpreter: &mut Interpreter,
+    ) -> ResultValue {
+        let finally = self
+            .finally()
+            .expect("the try statement has a finally block");
+        if let Err(err) = finally.run(interpreter) {
+            return Err(interpreter.save_frame(err, || match res {
+                Ok(ref value) => Frame::with_state(3, vec![value.clone()], state.clone()),
+                Err(ref error) => Frame::with_state(4, vec![error.clone()], state.clone()),
+            }));
+        }
+        if let InterpreterState::Executing = interpreter.get_current_state() {
+            interpreter.set_current_state(state);
         }
 
         res
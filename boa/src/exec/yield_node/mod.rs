@@ -0,0 +1,122 @@
+//! Yield expression execution.
+
+use super::{Executable, Interpreter, InterpreterState};
+use crate::{
+    builtins::{
+        generator::{resumed, suspend, Frame, ResumeKind},
+        iterable::{get_iterator, IteratorRecord},
+        value::{ResultValue, Value},
+    },
+    syntax::ast::node::Yield,
+    BoaProfiler,
+};
+
+impl Executable for Yield {
+    fn run(&self, interpreter: &mut Interpreter) -> ResultValue {
+        let _timer = BoaProfiler::global().start_event("Yield", "exec");
+        // Step 0 is the suspension at this expression, step 1 the evaluation of the operand, and
+        // step 2 the suspension while delegating to the iterator saved in the frame.
+        match interpreter.resume_frame() {
+            Some(Frame { step: 0, .. }) => {
+                return match resumed(interpreter) {
+                    ResumeKind::Next(value) => Ok(value),
+                    ResumeKind::Throw(value) => Err(value),
+                    ResumeKind::Return(value) => {
+                        interpreter.set_current_state(InterpreterState::Return);
+                        Ok(value)
+                    }
+                };
+            }
+            Some(Frame {
+                step: 2,
+                mut values,
+                ..
+            }) => {
+                let next_function = values.pop().expect("the frame has the next method");
+                let iterator_object = values.pop().expect("the frame has the iterator");
+                let iterator = IteratorRecord::new(iterator_object, next_function);
+                let received = resumed(interpreter);
+                return delegate(&iterator, received, interpreter);
+            }
+            _ => {}
+        }
+
+        let value = match self.expr() {
+            Some(expr) => expr
+                .run(interpreter)
+                .map_err(|err| interpreter.save_frame(err, || Frame::new(1, Vec::new())))?,
+            None => Value::undefined(),
+        };
+
+        if self.delegate() {
+            let iterator = get_iterator(interpreter, &value)?;
+            return delegate(&iterator, ResumeKind::Next(Value::undefined()), interpreter);
+        }
+
+        Err(suspend(interpreter, value, Frame::new(0, Vec::new())))
+    }
+}
+
+/// Runs a step of a `yield*` expression, which yields the values of the iterator until it is
+/// done.
+///
+/// The value the generator was resumed with is forwarded to the `next()`, `throw()` or `return()`
+/// method of the iterator, and the generator is suspended again with the next value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluation
+fn delegate(
+    iterator: &IteratorRecord,
+    received: ResumeKind,
+    interpreter: &mut Interpreter,
+) -> ResultValue {
+    let iterator_object = iterator.iterator_object();
+    let (result, returning) = match received {
+        ResumeKind::Next(value) => {
+            let next = iterator.next_function();
+            (interpreter.call(next, iterator_object, &[value])?, false)
+        }
+        ResumeKind::Throw(value) => {
+            let throw = interpreter.get_field(iterator_object, "throw")?;
+            if throw.is_null_or_undefined() {
+                // The iterator can't handle the error, so it is closed, and the protocol
+                // violation is reported instead.
+                let error = interpreter
+                    .construct_type_error("the delegated iterator does not have a throw method");
+                return iterator.close(Err(error), interpreter);
+            }
+            (interpreter.call(&throw, iterator_object, &[value])?, false)
+        }
+        ResumeKind::Return(value) => {
+            let r#return = interpreter.get_field(iterator_object, "return")?;
+            if r#return.is_null_or_undefined() {
+                interpreter.set_current_state(InterpreterState::Return);
+                return Ok(value);
+            }
+            (
+                interpreter.call(&r#return, iterator_object, &[value])?,
+                true,
+            )
+        }
+    };
+
+    if !result.is_object() {
+        return interpreter.throw_type_error("iterator result is not an object");
+    }
+    let done = interpreter.get_field(&result, "done")?.to_boolean();
+    let value = interpreter.get_field(&result, "value")?;
+    if done {
+        if returning {
+            interpreter.set_current_state(InterpreterState::Return);
+        }
+        return Ok(value);
+    }
+
+    let frame = Frame::new(
+        2,
+        vec![iterator_object.clone(), iterator.next_function().clone()],
+    );
+    Err(suspend(interpreter, value, frame))
+}
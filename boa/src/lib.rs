@@ -34,7 +34,13 @@
     missing_doc_code_examples
 )]
 
+// Lets the code generated by the derive macros in the tests name this crate `boa`.
+#[cfg(test)]
+extern crate self as boa;
+
 pub mod builtins;
+pub mod class;
+pub mod convert;
 pub mod environment;
 pub mod exec;
 pub mod profiler;
@@ -6,8 +6,8 @@
 
 use crate::{
     builtins::{
-        function::{Function, NativeFunctionData},
-        value::Value,
+        function::Function,
+        value::{ResultValue, Value},
     },
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
@@ -15,10 +15,13 @@ use crate::{
         lexical_environment::LexicalEnvironment,
         object_environment_record::ObjectEnvironmentRecord,
     },
+    exec::Interpreter,
+    syntax::ast::node::TemplateSite,
     BoaProfiler,
 };
-use gc::{Gc, GcCell};
+use gc::{Gc, GcCell, Trace};
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::rc::{Rc, Weak};
 
 /// Representation of a Realm.
 ///
@@ -28,6 +31,9 @@ pub struct Realm {
     pub global_obj: Value,
     pub global_env: Gc<GcCell<GlobalEnvironmentRecord>>,
     pub environment: LexicalEnvironment,
+    /// The strings arrays of the tagged templates that have been evaluated, by the address of
+    /// their template site.
+    template_map: FxHashMap<usize, (Weak<TemplateSite>, Value)>,
 }
 
 impl Realm {
@@ -47,12 +53,57 @@ impl Realm {
             global_obj: global.clone(),
             global_env,
             environment: LexicalEnvironment::new(global),
+            template_map: FxHashMap::default(),
         }
     }
 
+    /// Gets the strings array of the tagged template `site`, if it has been evaluated.
+    pub(crate) fn template_object(&self, site: &Rc<TemplateSite>) -> Option<Value> {
+        self.template_map
+            .get(&(Rc::as_ptr(site) as usize))
+            .map(|(_, strings)| strings.clone())
+    }
+
+    /// Caches the strings array of the tagged template `site`.
+    ///
+    /// The arrays of templates whose code has been dropped are removed before the cache grows.
+    /// The cache holds weak references to the sites, which keep their addresses from being
+    /// reused while they are in it.
+    pub(crate) fn set_template_object(&mut self, site: &Rc<TemplateSite>, strings: Value) {
+        if self.template_map.len() == self.template_map.capacity() {
+            self.template_map
+                .retain(|_, (site, _)| site.strong_count() > 0);
+        }
+        self.template_map
+            .insert(Rc::as_ptr(site) as usize, (Rc::downgrade(site), strings));
+    }
+
     /// Utility to add a function to the global object
-    pub fn register_global_func(self, func_name: &str, func: NativeFunctionData) -> Self {
-        let func = Function::builtin(Vec::new(), func);
+    ///
+    /// The function can be a closure, which owns the data it captures. Values that need to be
+    /// traced by the garbage collector, like objects, must be captured with
+    /// `register_global_closure` instead.
+    pub fn register_global_func<F>(self, func_name: &str, func: F) -> Self
+    where
+        F: Fn(&Value, &[Value], &mut Interpreter) -> ResultValue + 'static,
+    {
+        self.register_global_closure(
+            func_name,
+            move |this, args, _: &(), interpreter| func(this, args, interpreter),
+            (),
+        )
+    }
+
+    /// Utility to add a function backed by a closure to the global object, which is passed
+    /// `captures` every time it's called.
+    ///
+    /// The captured data is traced by the garbage collector, see `Function::closure`.
+    pub fn register_global_closure<F, C>(self, func_name: &str, func: F, captures: C) -> Self
+    where
+        F: Fn(&Value, &[Value], &C, &mut Interpreter) -> ResultValue + 'static,
+        C: Trace + 'static,
+    {
+        let func = Function::closure(func, captures);
         self.global_obj.set_field(func_name, Value::from_func(func));
 
         self
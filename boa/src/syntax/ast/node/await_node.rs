@@ -0,0 +1,55 @@
+use super::Node;
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `await` operator is used to wait for a promise, inside of an async function.
+///
+/// Syntax: `await expression`
+///
+/// The async function is suspended until the promise is settled, and the `await` expression
+/// evaluates to its value, or throws its rejection reason. Values that aren't promises are
+/// awaited like a promise fulfilled with the value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AwaitExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/await
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Await {
+    expr: Box<Node>,
+}
+
+impl Await {
+    /// Gets the awaited expression.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    /// Creates an `Await` AST node.
+    pub fn new<E>(expr: E) -> Self
+    where
+        E: Into<Node>,
+    {
+        Self {
+            expr: Box::new(expr.into()),
+        }
+    }
+}
+
+impl From<Await> for Node {
+    fn from(await_expr: Await) -> Node {
+        Node::Await(await_expr)
+    }
+}
+
+impl fmt::Display for Await {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "await {}", self.expr)
+    }
+}
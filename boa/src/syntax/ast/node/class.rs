@@ -0,0 +1,303 @@
+//! Class declaration and expression nodes.
+
+use super::{
+    join_nodes, FormalParameter, FunctionExpr, Identifier, MethodDefinitionKind, Node,
+    PropertyName, Spread, StatementList, SuperCall,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `class` declaration creates a new class with a given name using prototype-based
+/// inheritance.
+///
+/// Unlike function declarations, class declarations are not hoisted: the class binding is
+/// block-scoped, and it can't be used before the declaration is evaluated.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassDecl {
+    name: Box<str>,
+    tail: ClassTail,
+}
+
+impl ClassDecl {
+    /// Creates a new class declaration.
+    pub(in crate::syntax) fn new<N>(name: N, tail: ClassTail) -> Self
+    where
+        N: Into<Box<str>>,
+    {
+        Self {
+            name: name.into(),
+            tail,
+        }
+    }
+
+    /// Gets the name of the class.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the heritage, constructor and elements of the class.
+    pub fn tail(&self) -> &ClassTail {
+        &self.tail
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        write!(f, "class {} ", self.name)?;
+        self.tail.display(f, indentation)
+    }
+}
+
+impl fmt::Display for ClassDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ClassDecl> for Node {
+    fn from(decl: ClassDecl) -> Self {
+        Self::ClassDecl(decl)
+    }
+}
+
+/// The `class` expression is one way to define a class, optionally giving it a name that is
+/// only visible inside the class body.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassExpr {
+    name: Option<Box<str>>,
+    tail: ClassTail,
+}
+
+impl ClassExpr {
+    /// Creates a new class expression.
+    pub(in crate::syntax) fn new<N>(name: N, tail: ClassTail) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+    {
+        Self {
+            name: name.into(),
+            tail,
+        }
+    }
+
+    /// Gets the name of the class, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the heritage, constructor and elements of the class.
+    pub fn tail(&self) -> &ClassTail {
+        &self.tail
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        f.write_str("class ")?;
+        if let Some(ref name) = self.name {
+            write!(f, "{} ", name)?;
+        }
+        self.tail.display(f, indentation)
+    }
+}
+
+impl fmt::Display for ClassExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ClassExpr> for Node {
+    fn from(expr: ClassExpr) -> Self {
+        Self::ClassExpr(expr)
+    }
+}
+
+/// The part of a class definition following its name: the optional `extends` clause and the
+/// class body.
+///
+/// If the class body has no `constructor` method, a default one is created, as if the class
+/// contained `constructor() {}` (or `constructor(...args) { super(...args); }` for derived
+/// classes).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassTail {
+    heritage: Option<Box<Node>>,
+    constructor: FunctionExpr,
+    elements: Box<[ClassElement]>,
+}
+
+impl ClassTail {
+    /// Creates a new class tail, adding the default constructor if none was given.
+    pub(in crate::syntax) fn new<H, C, E>(heritage: H, constructor: C, elements: E) -> Self
+    where
+        H: Into<Option<Node>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        let heritage = heritage.into();
+        // The default constructor is class code, so it is strict mode code.
+        let constructor = constructor.into().unwrap_or_else(|| {
+            if heritage.is_some() {
+                FunctionExpr::new(
+                    None,
+                    vec![FormalParameter::new("args", None, true)],
+                    StatementList::new(
+                        vec![
+                            SuperCall::new(vec![Spread::new(Identifier::from("args")).into()])
+                                .into(),
+                        ],
+                        true,
+                    ),
+                )
+            } else {
+                FunctionExpr::new(None, vec![], StatementList::new(Vec::new(), true))
+            }
+        });
+
+        Self {
+            heritage: heritage.map(Box::new),
+            constructor,
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the expression in the `extends` clause, if any.
+    pub fn heritage(&self) -> Option<&Node> {
+        self.heritage.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the constructor of the class.
+    pub fn constructor(&self) -> &FunctionExpr {
+        &self.constructor
+    }
+
+    /// Gets the methods of the class, excluding the constructor.
+    pub fn elements(&self) -> &[ClassElement] {
+        &self.elements
+    }
+
+    /// Implements the display formatting with indentation.
+    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(ref heritage) = self.heritage {
+            write!(f, "extends {} ", heritage)?;
+        }
+        f.write_str("{\n")?;
+        let indent = "    ".repeat(indentation + 1);
+        write!(f, "{}constructor", indent)?;
+        display_method(f, &self.constructor, indentation + 1)?;
+        for element in self.elements.iter() {
+            f.write_str(&indent)?;
+            element.display(f, indentation + 1)?;
+        }
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
+}
+
+/// A method, getter or setter defined in a class body.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes#Prototype_methods
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassElement {
+    kind: MethodDefinitionKind,
+    name: PropertyName,
+    function: FunctionExpr,
+    is_static: bool,
+}
+
+impl ClassElement {
+    /// Creates a new class element.
+    pub(in crate::syntax) fn new<N>(
+        kind: MethodDefinitionKind,
+        name: N,
+        function: FunctionExpr,
+        is_static: bool,
+    ) -> Self
+    where
+        N: Into<PropertyName>,
+    {
+        Self {
+            kind,
+            name: name.into(),
+            function,
+            is_static,
+        }
+    }
+
+    /// Gets the kind of the method.
+    pub fn kind(&self) -> MethodDefinitionKind {
+        self.kind
+    }
+
+    /// Gets the name of the method.
+    pub fn name(&self) -> &PropertyName {
+        &self.name
+    }
+
+    /// Gets the function implementing the method.
+    pub fn function(&self) -> &FunctionExpr {
+        &self.function
+    }
+
+    /// Gets whether the method is defined on the constructor instead of the prototype.
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    /// Implements the display formatting with indentation.
+    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if self.is_static {
+            f.write_str("static ")?;
+        }
+        match self.kind {
+            MethodDefinitionKind::Get => f.write_str("get ")?,
+            MethodDefinitionKind::Set => f.write_str("set ")?,
+            MethodDefinitionKind::Generator => f.write_str("*")?,
+            MethodDefinitionKind::Async => f.write_str("async ")?,
+            MethodDefinitionKind::Ordinary => {}
+        }
+        write!(f, "{}", self.name)?;
+        display_method(f, &self.function, indentation)
+    }
+}
+
+/// Displays the parameters and body of a method, without the `function` keyword.
+fn display_method(
+    f: &mut fmt::Formatter<'_>,
+    function: &FunctionExpr,
+    indentation: usize,
+) -> fmt::Result {
+    f.write_str("(")?;
+    join_nodes(f, function.parameters())?;
+    f.write_str(") {\n")?;
+    function.display_body(f, indentation + 1)?;
+    writeln!(f, "{}}}", "    ".repeat(indentation))
+}
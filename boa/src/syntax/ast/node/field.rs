@@ -131,3 +131,47 @@ impl From<GetField> for Node {
         Self::GetField(get_field)
     }
 }
+
+/// This property accessor provides access to the properties of the parent object of the
+/// current method's home object, using either `super.property` or `super[property]`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetSuperField {
+    field: Box<Node>,
+}
+
+impl GetSuperField {
+    pub fn field(&self) -> &Node {
+        &self.field
+    }
+
+    /// Creates a `GetSuperField` AST node.
+    pub fn new<F>(field: F) -> Self
+    where
+        F: Into<Node>,
+    {
+        Self {
+            field: Box::new(field.into()),
+        }
+    }
+}
+
+impl fmt::Display for GetSuperField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "super[{}]", self.field())
+    }
+}
+
+impl From<GetSuperField> for Node {
+    fn from(get_super_field: GetSuperField) -> Self {
+        Self::GetSuperField(get_super_field)
+    }
+}
@@ -1,4 +1,4 @@
-use super::Node;
+use super::{BindingTarget, Node};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -138,6 +138,164 @@ impl InnerForLoop {
     }
 }
 
+/// The head of a `for...in` or `for...of` loop, which receives the value of each iteration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForInOfStatement
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum IterableLoopInitializer {
+    /// A `var` declaration, like `for (var x of xs)`.
+    Var(BindingTarget),
+    /// A `let` declaration, bound again for each iteration, like `for (let x of xs)`.
+    Let(BindingTarget),
+    /// A `const` declaration, bound again for each iteration, like `for (const x of xs)`.
+    Const(BindingTarget),
+    /// An assignment target, like `for (x of xs)`, `for (obj.x of xs)` or `for ([a, b] of xs)`.
+    Expression(Node),
+}
+
+impl fmt::Display for IterableLoopInitializer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Var(ref target) => write!(f, "var {}", target),
+            Self::Let(ref target) => write!(f, "let {}", target),
+            Self::Const(ref target) => write!(f, "const {}", target),
+            Self::Expression(ref node) => fmt::Display::fmt(node, f),
+        }
+    }
+}
+
+/// The `for...in` statement iterates over the enumerable string properties of an object,
+/// including the inherited ones.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-for-in-and-for-of-statements
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for...in
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ForInLoop {
+    init: Box<IterableLoopInitializer>,
+    expr: Box<Node>,
+    body: Box<Node>,
+}
+
+impl ForInLoop {
+    /// Creates a new `for...in` loop AST node.
+    pub(in crate::syntax) fn new<E, B>(init: IterableLoopInitializer, expr: E, body: B) -> Self
+    where
+        E: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            init: Box::new(init),
+            expr: Box::new(expr.into()),
+            body: Box::new(body.into()),
+        }
+    }
+
+    /// Gets the head of the loop, receiving the property names.
+    pub fn init(&self) -> &IterableLoopInitializer {
+        &self.init
+    }
+
+    /// Gets the object whose properties are iterated over.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    /// Gets the body of the loop.
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        write!(f, "for ({} in {}) ", self.init, self.expr)?;
+        self.body.display(f, indentation)
+    }
+}
+
+impl fmt::Display for ForInLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ForInLoop> for Node {
+    fn from(for_in: ForInLoop) -> Self {
+        Self::ForInLoop(for_in)
+    }
+}
+
+/// The `for...of` statement iterates over the values produced by an iterable object, using its
+/// `Symbol.iterator` method.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-for-in-and-for-of-statements
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for...of
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ForOfLoop {
+    init: Box<IterableLoopInitializer>,
+    iterable: Box<Node>,
+    body: Box<Node>,
+}
+
+impl ForOfLoop {
+    /// Creates a new `for...of` loop AST node.
+    pub(in crate::syntax) fn new<I, B>(init: IterableLoopInitializer, iterable: I, body: B) -> Self
+    where
+        I: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            init: Box::new(init),
+            iterable: Box::new(iterable.into()),
+            body: Box::new(body.into()),
+        }
+    }
+
+    /// Gets the head of the loop, receiving the iterated values.
+    pub fn init(&self) -> &IterableLoopInitializer {
+        &self.init
+    }
+
+    /// Gets the iterable object.
+    pub fn iterable(&self) -> &Node {
+        &self.iterable
+    }
+
+    /// Gets the body of the loop.
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        write!(f, "for ({} of {}) ", self.init, self.iterable)?;
+        self.body.display(f, indentation)
+    }
+}
+
+impl fmt::Display for ForOfLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ForOfLoop> for Node {
+    fn from(for_of: ForOfLoop) -> Self {
+        Self::ForOfLoop(for_of)
+    }
+}
+
 /// The `while` statement creates a loop that executes a specified statement as long as the
 /// test condition evaluates to `true`.
 ///
@@ -0,0 +1,67 @@
+use super::Node;
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A labelled statement can be used with `break` or `continue` statements, to choose the
+/// statement they apply to.
+///
+/// A `continue` statement can only use the label of a loop, while a `break` statement can use
+/// the label of any statement, like a block.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-LabelledStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/label
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Labelled {
+    label: Box<str>,
+    item: Box<Node>,
+}
+
+impl Labelled {
+    /// Creates a `Labelled` AST node.
+    pub fn new<L, I>(label: L, item: I) -> Self
+    where
+        L: Into<Box<str>>,
+        I: Into<Node>,
+    {
+        Self {
+            label: label.into(),
+            item: Box::new(item.into()),
+        }
+    }
+
+    /// Gets the label of the statement.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets the labelled statement.
+    pub fn item(&self) -> &Node {
+        &self.item
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        write!(f, "{}: ", self.label)?;
+        self.item.display_no_indent(f, indentation)
+    }
+}
+
+impl fmt::Display for Labelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<Labelled> for Node {
+    fn from(labelled: Labelled) -> Node {
+        Self::Labelled(labelled)
+    }
+}
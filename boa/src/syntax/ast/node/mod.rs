@@ -1,44 +1,66 @@
 //! This module implements the `Node` structure, which composes the AST.
 
 pub mod array;
+pub mod await_node;
 pub mod block;
 pub mod break_node;
+pub mod class;
 pub mod conditional;
 pub mod declaration;
 pub mod expression;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod labelled;
+pub mod module;
 pub mod object;
 pub mod operator;
+pub mod optional;
+pub mod pattern;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
 pub mod switch;
+pub mod template;
 pub mod throw;
 pub mod try_node;
+pub mod yield_node;
 
 pub use self::{
     array::ArrayDecl,
+    await_node::Await,
     block::Block,
     break_node::Break,
+    class::{ClassDecl, ClassElement, ClassExpr, ClassTail},
     conditional::{ConditionalOp, If},
     declaration::{
-        ArrowFunctionDecl, ConstDecl, ConstDeclList, FunctionDecl, FunctionExpr, LetDecl,
+        ArrowFunctionDecl, AsyncArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, ConstDecl,
+        ConstDeclList, FunctionDecl, FunctionExpr, GeneratorDecl, GeneratorExpr, LetDecl,
         LetDeclList, VarDecl, VarDeclList,
     },
-    expression::{Call, New},
-    field::{GetConstField, GetField},
+    expression::{Call, New, SuperCall},
+    field::{GetConstField, GetField, GetSuperField},
     identifier::Identifier,
-    iteration::{Continue, DoWhileLoop, ForLoop, WhileLoop},
+    iteration::{
+        Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, IterableLoopInitializer, WhileLoop,
+    },
+    labelled::Labelled,
+    module::{ExportDecl, ExportSpecifier, ImportDecl, ImportSpecifier, Module, DEFAULT_BINDING},
     object::Object,
     operator::{Assign, BinOp, UnaryOp},
+    optional::{Optional, OptionalOperation, OptionalOperationKind},
+    pattern::{
+        ArrayPattern, BindingTarget, ObjectPattern, Pattern, PatternElement, PropertyName,
+        PropertyPattern,
+    },
     return_smt::Return,
     spread::Spread,
     statement_list::StatementList,
     switch::{Case, Switch},
+    template::{TaggedTemplate, TemplateElement, TemplateLit, TemplateSite},
     throw::Throw,
     try_node::{Catch, Finally, Try},
+    yield_node::Yield,
 };
 use super::Const;
 use gc::{unsafe_empty_trace, Finalize, Trace};
@@ -62,6 +84,18 @@ pub enum Node {
     /// An assignment operator node. [More information](./operator/struct.Assign.html).
     Assign(Assign),
 
+    /// An async arrow function expression node. [More information](./declaration/struct.AsyncArrowFunctionDecl.html).
+    AsyncArrowFunctionDecl(AsyncArrowFunctionDecl),
+
+    /// An async function declaration node. [More information](./declaration/struct.AsyncFunctionDecl.html).
+    AsyncFunctionDecl(AsyncFunctionDecl),
+
+    /// An async function expression node. [More information](./declaration/struct.AsyncFunctionExpr.html).
+    AsyncFunctionExpr(AsyncFunctionExpr),
+
+    /// An `await` expression. [More information](./await_node/struct.Await.html).
+    Await(Await),
+
     /// A binary operator node. [More information](./operator/struct.BinOp.html).
     BinOp(BinOp),
 
@@ -74,6 +108,12 @@ pub enum Node {
     /// A function call. [More information](./expression/struct.Call.html).
     Call(Call),
 
+    /// A class declaration. [More information](./class/struct.ClassDecl.html).
+    ClassDecl(ClassDecl),
+
+    /// A class expression. [More information](./class/struct.ClassExpr.html).
+    ClassExpr(ClassExpr),
+
     /// A javascript conditional operand ( x ? y : z ). [More information](./conditional/struct.ConditionalOp.html).
     ConditionalOp(ConditionalOp),
 
@@ -98,6 +138,19 @@ pub enum Node {
     /// A do ... while statement. [More information](./iteration/struct.DoWhileLoop.html).
     DoWhileLoop(DoWhileLoop),
 
+    /// An empty statement, which is a lone semicolon, like the body of `label: ;`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-EmptyStatement
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/Empty
+    Empty,
+
+    /// An `export` declaration. [More information](./module/enum.ExportDecl.html).
+    ExportDecl(ExportDecl),
+
     /// A function declaration node. [More information](./declaration/struct.FunctionDecl.html).
     FunctionDecl(FunctionDecl),
 
@@ -110,24 +163,51 @@ pub enum Node {
     /// Provides access to object fields. [More information](./declaration/struct.GetField.html).
     GetField(GetField),
 
+    /// Provides access to the fields of the parent of a method's home object. [More information](./field/struct.GetSuperField.html).
+    GetSuperField(GetSuperField),
+
+    /// A generator declaration node. [More information](./declaration/struct.GeneratorDecl.html).
+    GeneratorDecl(GeneratorDecl),
+
+    /// A generator expression node. [More information](./declaration/struct.GeneratorExpr.html).
+    GeneratorExpr(GeneratorExpr),
+
     /// A `for` statement. [More information](./iteration/struct.ForLoop.html).
     ForLoop(ForLoop),
 
+    /// A `for...in` statement. [More information](./iteration/struct.ForInLoop.html).
+    ForInLoop(ForInLoop),
+
+    /// A `for...of` statement. [More information](./iteration/struct.ForOfLoop.html).
+    ForOfLoop(ForOfLoop),
+
     /// An 'if' statement. [More information](./conditional/struct.If.html).
     If(If),
 
+    /// An `import` declaration. [More information](./module/struct.ImportDecl.html).
+    ImportDecl(ImportDecl),
+
     /// A `let` declaration list. [More information](./declaration/struct.LetDeclList.html).
     LetDeclList(LetDeclList),
 
     /// A local identifier node. [More information](./identifier/struct.Identifier.html).
     Identifier(Identifier),
 
+    /// A labelled statement. [More information](./labelled/struct.Labelled.html).
+    Labelled(Labelled),
+
     /// A `new` expression. [More information](./expression/struct.New.html).
     New(New),
 
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// An optional chain. [More information](./optional/struct.Optional.html).
+    Optional(Optional),
+
+    /// A destructuring pattern. [More information](./pattern/enum.Pattern.html).
+    Pattern(Pattern),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
@@ -137,6 +217,15 @@ pub enum Node {
     /// A spread (...x) statement. [More information](./spread/struct.Spread.html).
     Spread(Spread),
 
+    /// A call to the parent class constructor. [More information](./expression/struct.SuperCall.html).
+    SuperCall(SuperCall),
+
+    /// A tagged template. [More information](./template/struct.TaggedTemplate.html).
+    TaggedTemplate(TaggedTemplate),
+
+    /// A template literal. [More information](./template/struct.TemplateLit.html).
+    TemplateLit(TemplateLit),
+
     /// A throw statement. [More information](./throw/struct.Throw.html).
     Throw(Throw),
 
@@ -165,6 +254,9 @@ pub enum Node {
 
     /// A 'while {...}' node. [More information](./iteration/struct.WhileLoop.html).
     WhileLoop(WhileLoop),
+
+    /// A `yield` expression. [More information](./yield_node/struct.Yield.html).
+    Yield(Yield),
 }
 
 impl Display for Node {
@@ -182,11 +274,16 @@ impl From<Const> for Node {
 impl Node {
     /// Returns a node ordering based on the hoistability of each node.
     pub(crate) fn hoistable_order(a: &Node, b: &Node) -> Ordering {
-        match (a, b) {
-            (Node::FunctionDecl(_), Node::FunctionDecl(_)) => Ordering::Equal,
-            (_, Node::FunctionDecl(_)) => Ordering::Greater,
-            (Node::FunctionDecl(_), _) => Ordering::Less,
-
+        fn is_hoistable(node: &Node) -> bool {
+            match node {
+                Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_) => true,
+                Node::ExportDecl(ref export) => export.declaration().map_or(false, is_hoistable),
+                _ => false,
+            }
+        }
+        match (is_hoistable(a), is_hoistable(b)) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
             (_, _) => Ordering::Equal,
         }
     }
@@ -198,37 +295,58 @@ impl Node {
 
     /// Implements the display formatting with indentation.
     fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        let indent = "    ".repeat(indentation);
         match *self {
             Self::Block(_) => {}
-            _ => write!(f, "{}", indent)?,
+            _ => write!(f, "{}", "    ".repeat(indentation))?,
         }
+        self.display_no_indent(f, indentation)
+    }
 
+    /// Implements the display formatting with indentation, without indenting the first line.
+    fn display_no_indent(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         match *self {
             Self::Const(ref c) => write!(f, "{}", c),
             Self::ConditionalOp(ref cond_op) => Display::fmt(cond_op, f),
             Self::ForLoop(ref for_loop) => for_loop.display(f, indentation),
+            Self::ForInLoop(ref for_in) => for_in.display(f, indentation),
+            Self::ForOfLoop(ref for_of) => for_of.display(f, indentation),
             Self::This => write!(f, "this"),
+            // The semicolon is written by the enclosing statement list.
+            Self::Empty => Ok(()),
             Self::Try(ref try_catch) => try_catch.display(f, indentation),
             Self::Break(ref break_smt) => Display::fmt(break_smt, f),
             Self::Continue(ref cont) => Display::fmt(cont, f),
             Self::Spread(ref spread) => Display::fmt(spread, f),
             Self::Block(ref block) => block.display(f, indentation),
             Self::Identifier(ref s) => Display::fmt(s, f),
+            Self::Labelled(ref labelled) => labelled.display(f, indentation),
             Self::GetConstField(ref get_const_field) => Display::fmt(get_const_field, f),
             Self::GetField(ref get_field) => Display::fmt(get_field, f),
+            Self::GetSuperField(ref get_super_field) => Display::fmt(get_super_field, f),
             Self::Call(ref expr) => Display::fmt(expr, f),
+            Self::Optional(ref optional) => Display::fmt(optional, f),
             Self::New(ref expr) => Display::fmt(expr, f),
+            Self::SuperCall(ref expr) => Display::fmt(expr, f),
+            Self::TemplateLit(ref template) => Display::fmt(template, f),
+            Self::TaggedTemplate(ref template) => Display::fmt(template, f),
             Self::WhileLoop(ref while_loop) => while_loop.display(f, indentation),
             Self::DoWhileLoop(ref do_while) => do_while.display(f, indentation),
             Self::If(ref if_smt) => if_smt.display(f, indentation),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
+            Self::Pattern(ref pattern) => Display::fmt(pattern, f),
             Self::ArrayDecl(ref arr) => Display::fmt(arr, f),
             Self::VarDeclList(ref list) => Display::fmt(list, f),
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
             Self::FunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::GeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::GeneratorExpr(ref expr) => expr.display(f, indentation),
+            Self::ClassDecl(ref decl) => decl.display(f, indentation),
+            Self::ClassExpr(ref expr) => expr.display(f, indentation),
             Self::ArrowFunctionDecl(ref decl) => decl.display(f, indentation),
+            Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
+            Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::AsyncArrowFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::BinOp(ref op) => Display::fmt(op, f),
             Self::UnaryOp(ref op) => Display::fmt(op, f),
             Self::Return(ref ret) => Display::fmt(ret, f),
@@ -236,6 +354,10 @@ impl Node {
             Self::Assign(ref op) => Display::fmt(op, f),
             Self::LetDeclList(ref decl) => Display::fmt(decl, f),
             Self::ConstDeclList(ref decl) => Display::fmt(decl, f),
+            Self::Yield(ref yield_expr) => Display::fmt(yield_expr, f),
+            Self::Await(ref await_expr) => Display::fmt(await_expr, f),
+            Self::ImportDecl(ref import) => Display::fmt(import, f),
+            Self::ExportDecl(ref export) => Display::fmt(export, f),
         }
     }
 }
@@ -258,8 +380,8 @@ where
 
 /// "Formal parameter" is a fancy way of saying "function parameter".
 ///
-/// In the declaration of a function, the parameters must be identifiers,
-/// not any value like numbers, strings, or objects.
+/// In the declaration of a function, the parameters must be identifiers or destructuring
+/// patterns, not any value like numbers, strings, or objects.
 ///```text
 ///function foo(formalParameter1, formalParameter2) {
 ///}
@@ -274,27 +396,27 @@ where
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub struct FormalParameter {
-    name: Box<str>,
+    target: BindingTarget,
     init: Option<Node>,
     is_rest_param: bool,
 }
 
 impl FormalParameter {
     /// Creates a new formal parameter.
-    pub(in crate::syntax) fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
+    pub(in crate::syntax) fn new<T>(target: T, init: Option<Node>, is_rest_param: bool) -> Self
     where
-        N: Into<Box<str>>,
+        T: Into<BindingTarget>,
     {
         Self {
-            name: name.into(),
+            target: target.into(),
             init,
             is_rest_param,
         }
     }
 
-    /// Gets the name of the formal parameter.
-    pub fn name(&self) -> &str {
-        &self.name
+    /// Gets the target of the formal parameter, its name or a destructuring pattern.
+    pub fn target(&self) -> &BindingTarget {
+        &self.target
     }
 
     /// Gets the initialization node of the formal parameter, if any.
@@ -313,7 +435,7 @@ impl Display for FormalParameter {
         if self.is_rest_param {
             write!(f, "...")?;
         }
-        write!(f, "{}", self.name)?;
+        write!(f, "{}", self.target)?;
         if let Some(n) = self.init.as_ref() {
             write!(f, " = {}", n)?;
         }
@@ -355,7 +477,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Property_definitions
-    Property(Box<str>, Node),
+    Property(PropertyName, Node),
 
     /// A property of an object can also refer to a function or a getter or setter method.
     ///
@@ -365,7 +487,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Method_definitions
-    MethodDefinition(MethodDefinitionKind, Box<str>, FunctionExpr),
+    MethodDefinition(MethodDefinitionKind, PropertyName, FunctionExpr),
 
     /// The Rest/Spread Properties for ECMAScript proposal (stage 4) adds spread properties to object literals.
     /// It copies own enumerable properties from a provided object onto a new object.
@@ -393,7 +515,7 @@ impl PropertyDefinition {
     /// Creates a `Property` definition.
     pub fn property<N, V>(name: N, value: V) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
         V: Into<Node>,
     {
         Self::Property(name.into(), value.into())
@@ -402,7 +524,7 @@ impl PropertyDefinition {
     /// Creates a `MethodDefinition`.
     pub fn method_definition<N>(kind: MethodDefinitionKind, name: N, body: FunctionExpr) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
     {
         Self::MethodDefinition(kind, name.into(), body)
     }
@@ -470,7 +592,26 @@ pub enum MethodDefinitionKind {
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions#Method_definition_syntax
     Ordinary,
-    // TODO: support other method definition kinds, like `Generator`.
+
+    /// A generator method, defined with a `*` before its name, like `*entries() { ... }`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-GeneratorMethod
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions#Generator_methods
+    Generator,
+
+    /// An async method, defined with `async` before its name, like `async load() { ... }`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-AsyncMethod
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions#Async_methods
+    Async,
 }
 
 unsafe impl Trace for MethodDefinitionKind {
@@ -0,0 +1,374 @@
+//! Module nodes, the `import` and `export` declarations of a module.
+
+use super::{join_nodes, Node, StatementList};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A parsed module, its body is strict mode code that can contain `import` and `export`
+/// declarations.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-modules
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Module {
+    body: StatementList,
+}
+
+impl Module {
+    /// Creates a new module.
+    pub(in crate::syntax) fn new<B>(body: B) -> Self
+    where
+        B: Into<StatementList>,
+    {
+        Self { body: body.into() }
+    }
+
+    /// Gets the body of the module.
+    pub fn body(&self) -> &StatementList {
+        &self.body
+    }
+
+    /// Gets the specifiers of the modules requested by the `import` and `export` declarations of
+    /// the module, in the order they appear in the source, without duplicates.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
+    pub fn requested_modules(&self) -> Vec<&str> {
+        let mut requests = Vec::new();
+        for node in self.body.statements() {
+            let request = match node {
+                Node::ImportDecl(ref import) => Some(import.module()),
+                Node::ExportDecl(ref export) => export.module(),
+                _ => None,
+            };
+            if let Some(request) = request {
+                if !requests.contains(&request) {
+                    requests.push(request);
+                }
+            }
+        }
+        requests
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.body, f)
+    }
+}
+
+/// An `import` declaration binds the exports of another module in the module that imports them.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportDecl {
+    specifiers: Box<[ImportSpecifier]>,
+    module: Box<str>,
+}
+
+impl ImportDecl {
+    /// Creates a new `import` declaration.
+    pub(in crate::syntax) fn new<S, M>(specifiers: S, module: M) -> Self
+    where
+        S: Into<Box<[ImportSpecifier]>>,
+        M: Into<Box<str>>,
+    {
+        Self {
+            specifiers: specifiers.into(),
+            module: module.into(),
+        }
+    }
+
+    /// Gets the bindings created by the declaration, which are empty for an import that only
+    /// evaluates the module, like `import "module"`.
+    pub fn specifiers(&self) -> &[ImportSpecifier] {
+        &self.specifiers
+    }
+
+    /// Gets the specifier of the imported module.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+}
+
+impl fmt::Display for ImportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("import ")?;
+        let (named, unnamed): (Vec<_>, Vec<_>) = self
+            .specifiers
+            .iter()
+            .partition(|specifier| matches!(specifier, ImportSpecifier::Named { .. }));
+        join_nodes(f, &unnamed)?;
+        if !named.is_empty() {
+            if !unnamed.is_empty() {
+                f.write_str(", ")?;
+            }
+            f.write_str("{ ")?;
+            join_nodes(f, &named)?;
+            f.write_str(" }")?;
+        }
+        if !self.specifiers.is_empty() {
+            f.write_str(" from ")?;
+        }
+        write!(f, "{:?}", self.module)
+    }
+}
+
+impl From<ImportDecl> for Node {
+    fn from(decl: ImportDecl) -> Self {
+        Self::ImportDecl(decl)
+    }
+}
+
+/// A binding created by an `import` declaration.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ImportSpecifier {
+    /// The default export of the module, like `a` in `import a from "module"`.
+    Default(Box<str>),
+    /// The namespace object of the module, like `* as ns`.
+    Namespace(Box<str>),
+    /// A named export of the module, like `a` or `a as b`.
+    Named {
+        /// The name exported by the module.
+        imported: Box<str>,
+        /// The name of the local binding.
+        local: Box<str>,
+    },
+}
+
+impl ImportSpecifier {
+    /// Gets the name of the local binding created by the specifier.
+    pub fn local(&self) -> &str {
+        match self {
+            Self::Default(ref local)
+            | Self::Namespace(ref local)
+            | Self::Named { ref local, .. } => local,
+        }
+    }
+}
+
+impl fmt::Display for ImportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default(ref local) => f.write_str(local),
+            Self::Namespace(ref local) => write!(f, "* as {}", local),
+            Self::Named {
+                ref imported,
+                ref local,
+            } if imported == local => f.write_str(local),
+            Self::Named {
+                ref imported,
+                ref local,
+            } => write!(f, "{} as {}", imported, local),
+        }
+    }
+}
+
+/// An `export` declaration makes bindings of a module, or of the modules it requests, available
+/// to the modules that import it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ExportDecl {
+    /// An exported declaration, like `export let a = 1;` or `export function f() {}`.
+    Declaration(Box<Node>),
+    /// The default export, like `export default 1;` or `export default class A {}`.
+    ///
+    /// It is a declaration if it is a named function or class, otherwise it is an expression.
+    Default(Box<Node>),
+    /// A list of exported names, like `export { a, b as c };`, re-exported from another module
+    /// if it has a `from` clause.
+    Named(Box<[ExportSpecifier]>, Option<Box<str>>),
+    /// The re-export of all the named exports of another module, like `export * from "module";`,
+    /// or of its namespace object, like `export * as ns from "module";`.
+    All(Option<Box<str>>, Box<str>),
+}
+
+impl ExportDecl {
+    /// Gets the specifier of the module the declaration re-exports from, if any.
+    pub fn module(&self) -> Option<&str> {
+        match self {
+            Self::Named(_, ref module) => module.as_deref(),
+            Self::All(_, ref module) => Some(module),
+            Self::Declaration(_) | Self::Default(_) => None,
+        }
+    }
+
+    /// Gets the exported declaration, if any, including a named default export.
+    pub fn declaration(&self) -> Option<&Node> {
+        match self {
+            Self::Declaration(ref node) => Some(node),
+            Self::Default(ref node) if is_default_declaration(node) => Some(node),
+            _ => None,
+        }
+    }
+
+    /// Gets the local bindings created by the declaration.
+    ///
+    /// The value of a default export that is an expression is bound to `*default*`.
+    pub fn bound_names(&self) -> Vec<&str> {
+        match self {
+            Self::Declaration(ref node) => declaration_names(node),
+            Self::Default(ref node) if is_default_declaration(node) => declaration_names(node),
+            Self::Default(_) => vec![DEFAULT_BINDING],
+            Self::Named(_, _) | Self::All(_, _) => Vec::new(),
+        }
+    }
+
+    /// Gets the names exported by the declaration, which don't include the names of a
+    /// `export * from "module";` re-export.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
+    pub fn exported_names(&self) -> Vec<&str> {
+        match self {
+            Self::Declaration(ref node) => declaration_names(node),
+            Self::Default(_) => vec!["default"],
+            Self::Named(ref specifiers, _) => {
+                specifiers.iter().map(ExportSpecifier::exported).collect()
+            }
+            Self::All(ref namespace, _) => namespace.as_deref().into_iter().collect(),
+        }
+    }
+}
+
+/// The name of the local binding of a default export that is an expression.
+///
+/// It can't clash with the other bindings of the module since it is not a valid identifier.
+pub const DEFAULT_BINDING: &str = "*default*";
+
+/// Gets the names bound by a declaration.
+pub(crate) fn declaration_names(node: &Node) -> Vec<&str> {
+    match node {
+        Node::FunctionDecl(ref decl) => vec![decl.name()],
+        Node::GeneratorDecl(ref decl) => vec![decl.name()],
+        Node::AsyncFunctionDecl(ref decl) => vec![decl.name()],
+        Node::ClassDecl(ref decl) => vec![decl.name()],
+        Node::VarDeclList(ref list) => list
+            .as_ref()
+            .iter()
+            .flat_map(|decl| decl.target().bound_names())
+            .collect(),
+        Node::LetDeclList(ref list) => list
+            .as_ref()
+            .iter()
+            .flat_map(|decl| decl.target().bound_names())
+            .collect(),
+        Node::ConstDeclList(ref list) => list
+            .as_ref()
+            .iter()
+            .flat_map(|decl| decl.target().bound_names())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Checks if the node of a default export is a declaration instead of an expression.
+fn is_default_declaration(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::FunctionDecl(_)
+            | Node::GeneratorDecl(_)
+            | Node::AsyncFunctionDecl(_)
+            | Node::ClassDecl(_)
+    )
+}
+
+impl fmt::Display for ExportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("export ")?;
+        match self {
+            Self::Declaration(ref node) => fmt::Display::fmt(node, f),
+            Self::Default(ref node) => write!(f, "default {}", node),
+            Self::Named(ref specifiers, ref module) => {
+                f.write_str("{ ")?;
+                join_nodes(f, specifiers)?;
+                f.write_str(" }")?;
+                if let Some(ref module) = module {
+                    write!(f, " from {:?}", module)?;
+                }
+                Ok(())
+            }
+            Self::All(ref namespace, ref module) => {
+                f.write_str("*")?;
+                if let Some(ref namespace) = namespace {
+                    write!(f, " as {}", namespace)?;
+                }
+                write!(f, " from {:?}", module)
+            }
+        }
+    }
+}
+
+impl From<ExportDecl> for Node {
+    fn from(decl: ExportDecl) -> Self {
+        Self::ExportDecl(decl)
+    }
+}
+
+/// A name in the list of an `export` declaration, like `a` or `a as b`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ExportSpecifier {
+    local: Box<str>,
+    exported: Box<str>,
+}
+
+impl ExportSpecifier {
+    /// Creates a new export specifier.
+    pub(in crate::syntax) fn new<L, E>(local: L, exported: E) -> Self
+    where
+        L: Into<Box<str>>,
+        E: Into<Box<str>>,
+    {
+        Self {
+            local: local.into(),
+            exported: exported.into(),
+        }
+    }
+
+    /// Gets the local name, or the name exported by the other module for a re-export.
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    /// Gets the name the binding is exported as.
+    pub fn exported(&self) -> &str {
+        &self.exported
+    }
+}
+
+impl fmt::Display for ExportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.local == self.exported {
+            f.write_str(&self.local)
+        } else {
+            write!(f, "{} as {}", self.local, self.exported)
+        }
+    }
+}
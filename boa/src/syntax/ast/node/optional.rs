@@ -0,0 +1,137 @@
+//! Optional chaining AST nodes.
+
+use super::{join_nodes, Node};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An operation of an optional chain, accessing a property or calling a function.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalChain
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum OptionalOperationKind {
+    /// A property access with the dot notation, like `.b` or `?.b`.
+    GetConstField(Box<str>),
+
+    /// A property access with the bracket notation, like `[k]` or `?.[k]`.
+    GetField(Box<Node>),
+
+    /// A function call, like `(x)` or `?.(x)`.
+    Call(Box<[Node]>),
+}
+
+/// An operation of an optional chain, along with whether it starts with `?.`.
+///
+/// An operation starting with `?.` short-circuits the rest of the chain when the value it
+/// operates on is `null` or `undefined`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalOperation {
+    kind: OptionalOperationKind,
+    shorted: bool,
+}
+
+impl OptionalOperation {
+    /// Creates a new `OptionalOperation`.
+    pub fn new(kind: OptionalOperationKind, shorted: bool) -> Self {
+        Self { kind, shorted }
+    }
+
+    /// Gets the kind of operation.
+    pub fn kind(&self) -> &OptionalOperationKind {
+        &self.kind
+    }
+
+    /// Returns `true` if the operation starts with `?.`, and short-circuits the chain when the
+    /// value it operates on is `null` or `undefined`.
+    pub fn shorted(&self) -> bool {
+        self.shorted
+    }
+}
+
+impl fmt::Display for OptionalOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shorted {
+            f.write_str("?.")?;
+        }
+        match self.kind {
+            OptionalOperationKind::GetConstField(ref field) => {
+                if !self.shorted {
+                    f.write_str(".")?;
+                }
+                write!(f, "{}", field)
+            }
+            OptionalOperationKind::GetField(ref field) => write!(f, "[{}]", field),
+            OptionalOperationKind::Call(ref args) => {
+                f.write_str("(")?;
+                join_nodes(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The optional chaining operator (`?.`) accesses a property or calls a function, unless the
+/// value it operates on is `null` or `undefined`.
+///
+/// In that case, the whole chain of property accesses and calls following it evaluates to
+/// `undefined` instead of throwing, like `a?.b.c()` when `a` is `null`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Optional {
+    target: Box<Node>,
+    chain: Box<[OptionalOperation]>,
+}
+
+impl Optional {
+    /// Creates a new `Optional` AST node.
+    pub fn new<T, C>(target: T, chain: C) -> Self
+    where
+        T: Into<Node>,
+        C: Into<Box<[OptionalOperation]>>,
+    {
+        Self {
+            target: Box::new(target.into()),
+            chain: chain.into(),
+        }
+    }
+
+    /// Gets the expression the chain operates on.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the operations of the chain.
+    pub fn chain(&self) -> &[OptionalOperation] {
+        &self.chain
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.target)?;
+        for operation in self.chain.iter() {
+            write!(f, "{}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Optional> for Node {
+    fn from(optional: Optional) -> Self {
+        Self::Optional(optional)
+    }
+}
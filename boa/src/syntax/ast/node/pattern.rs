@@ -0,0 +1,428 @@
+//! Destructuring pattern nodes.
+
+use super::{join_nodes, Identifier, Node};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A destructuring pattern takes apart an object or an iterable, and stores the pieces in its
+/// targets.
+///
+/// In declarations and parameters the targets are names, or nested patterns. In assignments
+/// they can be any expression that can be assigned to, such as a property access.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-binding-patterns
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum Pattern {
+    /// An object pattern, like `{ a, b: c = 1, ...rest }`.
+    Object(ObjectPattern),
+    /// An array pattern, like `[a, , b = 1, ...rest]`.
+    Array(ArrayPattern),
+}
+
+impl Pattern {
+    /// Gets the names bound by the pattern, including the ones in nested patterns.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+    pub fn bound_names(&self) -> Vec<&str> {
+        let (targets, rest): (Vec<&Node>, _) = match self {
+            Self::Object(ref pattern) => (
+                pattern
+                    .properties()
+                    .iter()
+                    .map(|property| property.element().target())
+                    .collect(),
+                pattern.rest(),
+            ),
+            Self::Array(ref pattern) => (
+                pattern
+                    .elements()
+                    .iter()
+                    .flatten()
+                    .map(PatternElement::target)
+                    .collect(),
+                pattern.rest(),
+            ),
+        };
+
+        let mut names = Vec::new();
+        for target in targets.into_iter().chain(rest) {
+            match target {
+                Node::Identifier(ref ident) => names.push(ident.as_ref()),
+                Node::Pattern(ref pattern) => names.extend(pattern.bound_names()),
+                _ => {}
+            }
+        }
+        names
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(ref pattern) => fmt::Display::fmt(pattern, f),
+            Self::Array(ref pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+impl From<ObjectPattern> for Pattern {
+    fn from(pattern: ObjectPattern) -> Self {
+        Self::Object(pattern)
+    }
+}
+
+impl From<ArrayPattern> for Pattern {
+    fn from(pattern: ArrayPattern) -> Self {
+        Self::Array(pattern)
+    }
+}
+
+impl From<Pattern> for Node {
+    fn from(pattern: Pattern) -> Self {
+        Self::Pattern(pattern)
+    }
+}
+
+/// An object pattern gets the values of the properties it names.
+///
+/// The rest target, if any, receives a new object with the remaining own enumerable properties.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ObjectPattern {
+    properties: Box<[PropertyPattern]>,
+    rest: Option<Box<Node>>,
+}
+
+impl ObjectPattern {
+    /// Creates a new object pattern.
+    pub(in crate::syntax) fn new<P, R>(properties: P, rest: R) -> Self
+    where
+        P: Into<Box<[PropertyPattern]>>,
+        R: Into<Option<Node>>,
+    {
+        Self {
+            properties: properties.into(),
+            rest: rest.into().map(Box::new),
+        }
+    }
+
+    /// Gets the properties taken from the object.
+    pub fn properties(&self) -> &[PropertyPattern] {
+        &self.properties
+    }
+
+    /// Gets the target of the remaining properties, if any.
+    pub fn rest(&self) -> Option<&Node> {
+        self.rest.as_deref()
+    }
+}
+
+impl fmt::Display for ObjectPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        join_nodes(f, &self.properties)?;
+        if let Some(ref rest) = self.rest {
+            if !self.properties.is_empty() {
+                f.write_str(", ")?;
+            }
+            write!(f, "...{}", rest)?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// A property of an object pattern, like `a`, `b: c` or `[key]: d = 1`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct PropertyPattern {
+    name: PropertyName,
+    element: PatternElement,
+}
+
+impl PropertyPattern {
+    /// Creates a new property pattern.
+    pub(in crate::syntax) fn new<N, E>(name: N, element: E) -> Self
+    where
+        N: Into<PropertyName>,
+        E: Into<PatternElement>,
+    {
+        Self {
+            name: name.into(),
+            element: element.into(),
+        }
+    }
+
+    /// Gets the name of the property.
+    pub fn name(&self) -> &PropertyName {
+        &self.name
+    }
+
+    /// Gets the target of the value of the property.
+    pub fn element(&self) -> &PatternElement {
+        &self.element
+    }
+}
+
+impl fmt::Display for PropertyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.element)
+    }
+}
+
+/// The name of a property, either written literally or computed from an expression.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum PropertyName {
+    /// A property name written as an identifier, a string or a number, like `a`, `"b"` or `1`.
+    Literal(Box<str>),
+    /// A computed property name, like `[key]`.
+    Computed(Node),
+}
+
+impl fmt::Display for PropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(ref name) => {
+                let is_identifier = name.starts_with(|ch: char| !ch.is_ascii_digit())
+                    && name
+                        .chars()
+                        .all(|ch| ch.is_alphanumeric() || ch == '_' || ch == '$');
+                if is_identifier || name.parse::<f64>().is_ok() {
+                    f.write_str(name)
+                } else {
+                    write!(f, "{:?}", name)
+                }
+            }
+            Self::Computed(ref node) => write!(f, "[{}]", node),
+        }
+    }
+}
+
+impl From<&str> for PropertyName {
+    fn from(name: &str) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<Box<str>> for PropertyName {
+    fn from(name: Box<str>) -> Self {
+        Self::Literal(name)
+    }
+}
+
+impl From<Node> for PropertyName {
+    fn from(node: Node) -> Self {
+        Self::Computed(node)
+    }
+}
+
+/// An array pattern gets values from an iterator, one for each element.
+///
+/// Holes in the pattern skip values, and the rest target, if any, receives an array with the
+/// remaining values.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ArrayPattern {
+    elements: Box<[Option<PatternElement>]>,
+    rest: Option<Box<Node>>,
+}
+
+impl ArrayPattern {
+    /// Creates a new array pattern.
+    ///
+    /// Holes are represented by `None` elements.
+    pub(in crate::syntax) fn new<E, R>(elements: E, rest: R) -> Self
+    where
+        E: Into<Box<[Option<PatternElement>]>>,
+        R: Into<Option<Node>>,
+    {
+        Self {
+            elements: elements.into(),
+            rest: rest.into().map(Box::new),
+        }
+    }
+
+    /// Gets the elements of the pattern, holes are `None`.
+    pub fn elements(&self) -> &[Option<PatternElement>] {
+        &self.elements
+    }
+
+    /// Gets the target of the remaining values, if any.
+    pub fn rest(&self) -> Option<&Node> {
+        self.rest.as_deref()
+    }
+}
+
+impl fmt::Display for ArrayPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, element) in self.elements.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            if let Some(ref element) = element {
+                fmt::Display::fmt(element, f)?;
+            }
+        }
+        match self.rest {
+            Some(ref rest) => {
+                if !self.elements.is_empty() {
+                    f.write_str(", ")?;
+                }
+                write!(f, "...{}", rest)?;
+            }
+            // A trailing hole needs a trailing comma to be kept.
+            None if matches!(self.elements.last(), Some(None)) => f.write_str(",")?,
+            None => {}
+        }
+        f.write_str("]")
+    }
+}
+
+/// A target in a pattern, along with the default value it gets if the value taken apart by the
+/// pattern is `undefined`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct PatternElement {
+    target: Node,
+    init: Option<Node>,
+}
+
+impl PatternElement {
+    /// Creates a new pattern element.
+    pub(in crate::syntax) fn new<T, I>(target: T, init: I) -> Self
+    where
+        T: Into<Node>,
+        I: Into<Option<Node>>,
+    {
+        Self {
+            target: target.into(),
+            init: init.into(),
+        }
+    }
+
+    /// Gets the target of the element, an identifier or nested pattern in bindings, or any
+    /// assignable expression in assignments.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the default value of the element, if any.
+    pub fn init(&self) -> Option<&Node> {
+        self.init.as_ref()
+    }
+}
+
+impl fmt::Display for PatternElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.target, f)?;
+        if let Some(ref init) = self.init {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> From<T> for PatternElement
+where
+    T: Into<Node>,
+{
+    fn from(target: T) -> Self {
+        Self::new(target, None)
+    }
+}
+
+/// The target of a declaration or a parameter: a name, or a pattern taking the bound value
+/// apart.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForBinding
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum BindingTarget {
+    /// A single name, like `a`.
+    Identifier(Identifier),
+    /// A destructuring pattern, like `{ a, b }` or `[a, b]`.
+    Pattern(Pattern),
+}
+
+impl BindingTarget {
+    /// Gets the names bound by the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+    pub fn bound_names(&self) -> Vec<&str> {
+        match self {
+            Self::Identifier(ref ident) => vec![ident.as_ref()],
+            Self::Pattern(ref pattern) => pattern.bound_names(),
+        }
+    }
+}
+
+impl fmt::Display for BindingTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(ref ident) => fmt::Display::fmt(ident, f),
+            Self::Pattern(ref pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+impl From<&str> for BindingTarget {
+    fn from(name: &str) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Box<str>> for BindingTarget {
+    fn from(name: Box<str>) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Identifier> for BindingTarget {
+    fn from(ident: Identifier) -> Self {
+        Self::Identifier(ident)
+    }
+}
+
+impl From<Pattern> for BindingTarget {
+    fn from(pattern: Pattern) -> Self {
+        Self::Pattern(pattern)
+    }
+}
+
+impl From<BindingTarget> for Node {
+    fn from(target: BindingTarget) -> Self {
+        match target {
+            BindingTarget::Identifier(ref ident) => Self::Identifier(ident.clone()),
+            BindingTarget::Pattern(ref pattern) => Self::Pattern(pattern.clone()),
+        }
+    }
+}
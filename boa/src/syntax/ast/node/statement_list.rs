@@ -20,14 +20,36 @@ use serde::{Deserialize, Serialize};
 pub struct StatementList {
     #[cfg_attr(feature = "serde", serde(flatten))]
     statements: Box<[Node]>,
+    strict: bool,
 }
 
 impl StatementList {
+    /// Creates a new list of statements, which may be strict mode code.
+    pub(in crate::syntax) fn new<S>(statements: S, strict: bool) -> Self
+    where
+        S: Into<Box<[Node]>>,
+    {
+        Self {
+            statements: statements.into(),
+            strict,
+        }
+    }
+
     /// Gets the list of statements.
     pub fn statements(&self) -> &[Node] {
         &self.statements
     }
 
+    /// Checks if the statements are strict mode code.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-strict-mode-code
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
     /// Implements the display formatting with indentation.
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         let indent = "    ".repeat(indentation);
@@ -53,6 +75,7 @@ where
     fn from(stm: T) -> Self {
         Self {
             statements: stm.into(),
+            strict: false,
         }
     }
 }
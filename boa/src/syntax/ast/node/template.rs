@@ -0,0 +1,201 @@
+//! Template literal nodes.
+
+use super::Node;
+use gc::{Finalize, Trace};
+use std::{fmt, rc::Rc};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Template literals are string literals allowing embedded expressions.
+///
+/// The values of the embedded expressions are converted to strings and concatenated with the
+/// rest of the template.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-template-literals
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct TemplateLit {
+    elements: Box<[TemplateElement]>,
+}
+
+impl TemplateLit {
+    /// Creates a new template literal.
+    pub(in crate::syntax) fn new<E>(elements: E) -> Self
+    where
+        E: Into<Box<[TemplateElement]>>,
+    {
+        Self {
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the strings and substitutions of the template, in source order.
+    pub fn elements(&self) -> &[TemplateElement] {
+        &self.elements
+    }
+}
+
+impl fmt::Display for TemplateLit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("`")?;
+        for element in self.elements.iter() {
+            match element {
+                TemplateElement::String(ref s) => {
+                    // Escape the characters that would end the string or start a substitution.
+                    let mut chars = s.chars().peekable();
+                    while let Some(ch) = chars.next() {
+                        match ch {
+                            '`' | '\\' => write!(f, "\\{}", ch)?,
+                            '$' if chars.peek() == Some(&'{') => f.write_str("\\$")?,
+                            _ => write!(f, "{}", ch)?,
+                        }
+                    }
+                }
+                TemplateElement::Expr(ref expr) => write!(f, "${{{}}}", expr)?,
+            }
+        }
+        f.write_str("`")
+    }
+}
+
+impl From<TemplateLit> for Node {
+    fn from(template: TemplateLit) -> Self {
+        Self::TemplateLit(template)
+    }
+}
+
+/// A piece of a template literal: either a string or an embedded expression.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum TemplateElement {
+    /// The cooked value of a string in the template.
+    String(Box<str>),
+    /// An expression embedded with `${...}`.
+    Expr(Node),
+}
+
+/// Identifies a tagged template in the parsed source code.
+///
+/// The realm caches the strings array of a template by its site, for as long as the site is
+/// alive. The site isn't a garbage collected value, because the code of functions is shared with
+/// `Rc`, which isn't traced.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TemplateSite;
+
+/// A tagged template is a template literal preceded by a function, which is called with the
+/// strings of the template and the values of its substitutions.
+///
+/// The strings are passed as a frozen array, with a `raw` property containing the strings as
+/// they were written in the source code. The same array is passed every time a given tagged
+/// template is evaluated.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#Tagged_templates
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize)]
+pub struct TaggedTemplate {
+    tag: Box<Node>,
+    raws: Box<[Box<str>]>,
+    cookeds: Box<[Option<Box<str>>]>,
+    exprs: Box<[Node]>,
+    /// Identifies this template in the source code, its strings array is cached under this key.
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    site: Rc<TemplateSite>,
+}
+
+impl TaggedTemplate {
+    /// Creates a new tagged template.
+    ///
+    /// There must be exactly one more string than there are expressions.
+    pub(in crate::syntax) fn new<T, R, C, E>(tag: T, raws: R, cookeds: C, exprs: E) -> Self
+    where
+        T: Into<Node>,
+        R: Into<Box<[Box<str>]>>,
+        C: Into<Box<[Option<Box<str>>]>>,
+        E: Into<Box<[Node]>>,
+    {
+        let raws = raws.into();
+        let cookeds = cookeds.into();
+        let exprs = exprs.into();
+        debug_assert_eq!(raws.len(), cookeds.len());
+        debug_assert_eq!(raws.len(), exprs.len() + 1);
+
+        Self {
+            tag: Box::new(tag.into()),
+            raws,
+            cookeds,
+            exprs,
+            site: Rc::default(),
+        }
+    }
+
+    /// Gets the function the template is passed to.
+    pub fn tag(&self) -> &Node {
+        &self.tag
+    }
+
+    /// Gets the strings of the template as they were written in the source code.
+    pub fn raws(&self) -> &[Box<str>] {
+        &self.raws
+    }
+
+    /// Gets the strings of the template with their escape sequences interpreted.
+    ///
+    /// Strings containing invalid escape sequences have no cooked value.
+    pub fn cookeds(&self) -> &[Option<Box<str>>] {
+        &self.cookeds
+    }
+
+    /// Gets the expressions embedded in the template.
+    pub fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
+
+    /// Gets the identifier of this template in the source code.
+    pub(crate) fn site(&self) -> &Rc<TemplateSite> {
+        &self.site
+    }
+}
+
+// The site only identifies where the template was parsed, so it is not compared.
+impl PartialEq for TaggedTemplate {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag == other.tag
+            && self.raws == other.raws
+            && self.cookeds == other.cookeds
+            && self.exprs == other.exprs
+    }
+}
+
+impl fmt::Display for TaggedTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}`", self.tag)?;
+        for (raw, expr) in self.raws.iter().zip(self.exprs.iter()) {
+            write!(f, "{}${{{}}}", raw, expr)?;
+        }
+        write!(
+            f,
+            "{}`",
+            self.raws
+                .last()
+                .expect("templates have at least one string")
+        )
+    }
+}
+
+impl From<TaggedTemplate> for Node {
+    fn from(template: TaggedTemplate) -> Self {
+        Self::TaggedTemplate(template)
+    }
+}
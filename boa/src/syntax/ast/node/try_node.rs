@@ -1,4 +1,4 @@
-use super::{Block, Identifier, Node};
+use super::{BindingTarget, Block, Node};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -95,7 +95,7 @@ impl From<Try> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Catch {
-    parameter: Option<Identifier>,
+    parameter: Option<BindingTarget>,
     block: Block,
 }
 
@@ -104,7 +104,7 @@ impl Catch {
     pub(in crate::syntax) fn new<OI, I, B>(parameter: OI, block: B) -> Self
     where
         OI: Into<Option<I>>,
-        I: Into<Identifier>,
+        I: Into<BindingTarget>,
         B: Into<Block>,
     {
         Self {
@@ -114,8 +114,8 @@ impl Catch {
     }
 
     /// Gets the parameter of the catch block.
-    pub fn parameter(&self) -> Option<&str> {
-        self.parameter.as_ref().map(Identifier::as_ref)
+    pub fn parameter(&self) -> Option<&BindingTarget> {
+        self.parameter.as_ref()
     }
 
     /// Retrieves the catch execution block.
@@ -0,0 +1,67 @@
+use super::Node;
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `yield` keyword is used to pause and resume a generator function.
+///
+/// Syntax: `yield [expression]` or `yield* expression`
+///
+/// The value of the expression is returned to the caller of the generator's `next()` method,
+/// and the `yield` expression evaluates to the argument of the `next()` call that resumes the
+/// generator. `yield*` delegates to another iterable, yielding each of its values in turn.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Yield {
+    expr: Option<Box<Node>>,
+    delegate: bool,
+}
+
+impl Yield {
+    /// Gets the yielded expression, if any.
+    pub fn expr(&self) -> Option<&Node> {
+        self.expr.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets whether this is a `yield*` expression, delegating to another iterable.
+    pub fn delegate(&self) -> bool {
+        self.delegate
+    }
+
+    /// Creates a `Yield` AST node.
+    pub fn new<E, OE>(expr: OE, delegate: bool) -> Self
+    where
+        E: Into<Node>,
+        OE: Into<Option<E>>,
+    {
+        Self {
+            expr: expr.into().map(E::into).map(Box::new),
+            delegate,
+        }
+    }
+}
+
+impl From<Yield> for Node {
+    fn from(yield_expr: Yield) -> Node {
+        Node::Yield(yield_expr)
+    }
+}
+
+impl fmt::Display for Yield {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keyword = if self.delegate { "yield*" } else { "yield" };
+        match self.expr() {
+            Some(expr) => write!(f, "{} {}", keyword, expr),
+            None => f.write_str(keyword),
+        }
+    }
+}
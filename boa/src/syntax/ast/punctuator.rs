@@ -35,6 +35,12 @@ pub enum Punctuator {
     AssignAdd,
     /// `&=`
     AssignAnd,
+    /// `&&=`
+    AssignBoolAnd,
+    /// `||=`
+    AssignBoolOr,
+    /// `??=`
+    AssignCoalesce,
     /// `/=`
     AssignDiv,
     /// `<<=`
@@ -61,6 +67,8 @@ pub enum Punctuator {
     BoolOr,
     /// `}`
     CloseBlock,
+    /// `??`
+    Coalesce,
     /// `]`
     CloseBracket,
     /// `)`
@@ -105,6 +113,8 @@ pub enum Punctuator {
     OpenBracket,
     /// `(`
     OpenParen,
+    /// `?.`
+    Optional,
     /// `|`
     Or,
     /// `**`
@@ -137,6 +147,9 @@ impl Punctuator {
         match self {
             Self::AssignAdd => Some(BinOp::Assign(AssignOp::Add)),
             Self::AssignAnd => Some(BinOp::Assign(AssignOp::And)),
+            Self::AssignBoolAnd => Some(BinOp::Assign(AssignOp::BoolAnd)),
+            Self::AssignBoolOr => Some(BinOp::Assign(AssignOp::BoolOr)),
+            Self::AssignCoalesce => Some(BinOp::Assign(AssignOp::Coalesce)),
             Self::AssignDiv => Some(BinOp::Assign(AssignOp::Div)),
             Self::AssignLeftSh => Some(BinOp::Assign(AssignOp::Shl)),
             Self::AssignMod => Some(BinOp::Assign(AssignOp::Mod)),
@@ -156,6 +169,7 @@ impl Punctuator {
             Self::Xor => Some(BinOp::Bit(BitOp::Xor)),
             Self::BoolAnd => Some(BinOp::Log(LogOp::And)),
             Self::BoolOr => Some(BinOp::Log(LogOp::Or)),
+            Self::Coalesce => Some(BinOp::Log(LogOp::Coalesce)),
             Self::Eq => Some(BinOp::Comp(CompOp::Equal)),
             Self::NotEq => Some(BinOp::Comp(CompOp::NotEqual)),
             Self::StrictEq => Some(BinOp::Comp(CompOp::StrictEqual)),
@@ -193,6 +207,9 @@ impl Display for Punctuator {
                 Self::Assign => "=",
                 Self::AssignAdd => "+=",
                 Self::AssignAnd => "&=",
+                Self::AssignBoolAnd => "&&=",
+                Self::AssignBoolOr => "||=",
+                Self::AssignCoalesce => "??=",
                 Self::AssignDiv => "/=",
                 Self::AssignLeftSh => "<<=",
                 Self::AssignMod => "%=",
@@ -206,6 +223,7 @@ impl Display for Punctuator {
                 Self::BoolAnd => "&&",
                 Self::BoolOr => "||",
                 Self::CloseBlock => "}",
+                Self::Coalesce => "??",
                 Self::CloseBracket => "]",
                 Self::CloseParen => ")",
                 Self::Colon => ":",
@@ -228,6 +246,7 @@ impl Display for Punctuator {
                 Self::OpenBlock => "{",
                 Self::OpenBracket => "[",
                 Self::OpenParen => "(",
+                Self::Optional => "?.",
                 Self::Or => "|",
                 Self::Exp => "**",
                 Self::Question => "?",
@@ -19,6 +19,47 @@ use std::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+/// The text of a template literal, between two substitutions.
+///
+/// The raw string is the text exactly as it was written in the source code, while the cooked
+/// string has its escape sequences interpreted. Templates are allowed to contain invalid escape
+/// sequences, in which case there is no cooked string (this is only valid in tagged templates).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-tv-and-trv
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateString {
+    raw: Box<str>,
+    cooked: Option<Box<str>>,
+}
+
+impl TemplateString {
+    /// Creates a new template string.
+    pub fn new<R, C>(raw: R, cooked: Option<C>) -> Self
+    where
+        R: Into<Box<str>>,
+        C: Into<Box<str>>,
+    {
+        Self {
+            raw: raw.into(),
+            cooked: cooked.map(Into::into),
+        }
+    }
+
+    /// Gets the raw string, as written in the source code.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Gets the cooked string, if it doesn't contain invalid escape sequences.
+    pub fn cooked(&self) -> Option<&str> {
+        self.cooked.as_ref().map(Box::as_ref)
+    }
+}
+
 /// This represents the smallest individual words, phrases, or characters that JavaScript can understand.
 ///
 /// More information:
@@ -32,12 +73,19 @@ pub struct Token {
     pub(crate) kind: TokenKind,
     /// The token position in the original source code.
     pub(crate) span: Span,
+    /// Whether the token is a numeric literal or a string literal using the legacy octal syntax,
+    /// like `017` or `"\017"`, which is not allowed in strict mode code.
+    pub(crate) legacy_octal: bool,
 }
 
 impl Token {
     /// Create a new detailed token from the token data, line number and column number
     pub fn new(kind: TokenKind, span: Span) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            legacy_octal: false,
+        }
     }
 
     /// Gets the kind of the token.
@@ -49,6 +97,11 @@ impl Token {
     pub fn span(&self) -> Span {
         self.span
     }
+
+    /// Checks if the token is a literal using the legacy octal syntax.
+    pub fn is_legacy_octal(&self) -> bool {
+        self.legacy_octal
+    }
 }
 
 impl Display for Token {
@@ -240,7 +293,17 @@ pub enum TokenKind {
     /// A string literal.
     StringLiteral(Box<str>),
 
-    TemplateLiteral(Box<str>),
+    /// A template without substitutions (`` `text` ``).
+    TemplateNoSubstitution(TemplateString),
+
+    /// The start of a template, up to its first substitution (`` `text${ ``).
+    TemplateHead(TemplateString),
+
+    /// The part of a template between two substitutions (`}text${`).
+    TemplateMiddle(TemplateString),
+
+    /// The end of a template, after its last substitution (`` }text` ``).
+    TemplateTail(TemplateString),
 
     /// A regular expression, consisting of body and flags.
     RegularExpressionLiteral(Box<str>, RegExpFlags),
@@ -312,12 +375,9 @@ impl TokenKind {
         Self::StringLiteral(lit.into())
     }
 
-    /// Creates a `TemplateLiteral` token type.
-    pub fn template_literal<S>(lit: S) -> Self
-    where
-        S: Into<Box<str>>,
-    {
-        Self::TemplateLiteral(lit.into())
+    /// Creates a `TemplateNoSubstitution` token type.
+    pub fn template_no_substitution(template: TemplateString) -> Self {
+        Self::TemplateNoSubstitution(template)
     }
 
     /// Creates a `RegularExpressionLiteral` token kind.
@@ -347,7 +407,10 @@ impl Display for TokenKind {
             Self::NumericLiteral(NumericLiteral::BigInt(ref num)) => write!(f, "{}n", num),
             Self::Punctuator(ref punc) => write!(f, "{}", punc),
             Self::StringLiteral(ref lit) => write!(f, "{}", lit),
-            Self::TemplateLiteral(ref lit) => write!(f, "{}", lit),
+            Self::TemplateNoSubstitution(ref template) => write!(f, "`{}`", template.raw()),
+            Self::TemplateHead(ref template) => write!(f, "`{}${{", template.raw()),
+            Self::TemplateMiddle(ref template) => write!(f, "}}{}${{", template.raw()),
+            Self::TemplateTail(ref template) => write!(f, "}}{}`", template.raw()),
             Self::RegularExpressionLiteral(ref body, ref flags) => write!(f, "/{}/{}", body, flags),
             Self::LineTerminator => write!(f, "line terminator"),
         }
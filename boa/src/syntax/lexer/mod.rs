@@ -9,7 +9,7 @@ mod tests;
 use crate::builtins::BigInt;
 use crate::{
     syntax::ast::{
-        token::{NumericLiteral, Token, TokenKind},
+        token::{NumericLiteral, TemplateString, Token, TokenKind},
         Position, Punctuator, Span,
     },
     BoaProfiler,
@@ -128,6 +128,10 @@ pub struct Lexer<'a> {
     position: Position,
     /// The full Peekable buffer, an array of [Char]s
     buffer: Peekable<Chars<'a>>,
+    /// The number of unclosed `{` in each of the template substitutions (`${ ... }`) being lexed.
+    ///
+    /// A `}` only ends a substitution if all the blocks opened inside of it have been closed.
+    template_substitutions: Vec<usize>,
 }
 
 impl<'a> Lexer<'a> {
@@ -139,6 +143,7 @@ impl<'a> Lexer<'a> {
             tokens: Vec::new(),
             position: Position::new(1, 1),
             buffer: buffer.chars().peekable(),
+            template_substitutions: Vec::new(),
         }
     }
 
@@ -155,6 +160,14 @@ impl<'a> Lexer<'a> {
         self.tokens.push(Token::new(tk, Span::new(start, end)))
     }
 
+    /// Marks the last token pushed as using the legacy octal syntax, which is not allowed in
+    /// strict mode code.
+    fn mark_legacy_octal(&mut self) {
+        if let Some(token) = self.tokens.last_mut() {
+            token.legacy_octal = true;
+        }
+    }
+
     /// Push a punctuation token
     fn push_punc(&mut self, punc: Punctuator, start: Position) {
         self.push_token(TokenKind::Punctuator(punc), start);
@@ -304,11 +317,9 @@ impl<'a> Lexer<'a> {
             }
         }
 
-        // TODO: Setup strict mode.
-        let strict_mode = false;
-
         let mut buf = ch.to_string();
         let mut kind = NumericKind::Integer(10);
+        let mut legacy_octal = false;
         let start_pos = self.position;
         if ch == '0' {
             match self.preview_next() {
@@ -345,20 +356,11 @@ impl<'a> Lexer<'a> {
                         }
                         buf.push(self.next());
                     }
-                    if !strict_mode {
-                        if is_implicit_octal {
-                            kind = NumericKind::Integer(8);
-                        }
-                    } else {
-                        return Err(if is_implicit_octal {
-                            LexerError::new(
-                                "Implicit octal literals are not allowed in strict mode.",
-                            )
-                        } else {
-                            LexerError::new(
-                                "Decimals with leading zeros are not allowed in strict mode.",
-                            )
-                        });
+                    // Both implicit octal literals and decimals with leading zeros are errors in
+                    // strict mode code, which is checked by the parser.
+                    legacy_octal = true;
+                    if is_implicit_octal {
+                        kind = NumericKind::Integer(8);
                     }
                 }
                 Some(_) => {}
@@ -472,10 +474,194 @@ impl<'a> Lexer<'a> {
 
         self.move_columns(buf.len() as u32);
         self.push_token(TokenKind::NumericLiteral(num), start_pos);
+        if legacy_octal {
+            self.mark_legacy_octal();
+        }
+
+        Ok(())
+    }
+
+    /// Reads a piece of a template literal, up to the next `` ` `` or `${`.
+    ///
+    /// The opening `` ` `` (if `head` is `true`) or `}` has already been consumed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-template-literal-lexical-components
+    fn read_template(&mut self, start_pos: Position, head: bool) -> Result<(), LexerError> {
+        let mut raw = String::new();
+        let mut cooked = Some(String::new());
+        let substitution = loop {
+            let ch = self
+                .buffer
+                .next()
+                .ok_or_else(|| LexerError::new("Unterminated template literal"))?;
+            match ch {
+                '`' => {
+                    self.next_column();
+                    break false;
+                }
+                '$' if self.preview_next() == Some('{') => {
+                    self.buffer.next();
+                    self.move_columns(2);
+                    break true;
+                }
+                '\\' => {
+                    self.next_column();
+                    raw.push('\\');
+                    self.read_template_escape(&mut raw, &mut cooked)?;
+                }
+                _ => {
+                    let ch = self.read_template_char(ch);
+                    raw.push(ch);
+                    if let Some(ref mut cooked) = cooked {
+                        cooked.push(ch);
+                    }
+                }
+            }
+        };
+
+        let template = TemplateString::new(raw, cooked);
+        let kind = match (head, substitution) {
+            (true, false) => TokenKind::TemplateNoSubstitution(template),
+            (true, true) => TokenKind::TemplateHead(template),
+            (false, true) => TokenKind::TemplateMiddle(template),
+            (false, false) => TokenKind::TemplateTail(template),
+        };
+        if substitution {
+            self.template_substitutions.push(0);
+        }
+        self.push_token(kind, start_pos);
+
+        Ok(())
+    }
+
+    /// Updates the position after a character of a template has been consumed.
+    ///
+    /// Line terminators are normalized, so both `\r\n` and `\r` are read as `\n`.
+    fn read_template_char(&mut self, ch: char) -> char {
+        match ch {
+            '\r' => {
+                let _ = self.next_is('\n');
+                self.next_line();
+                '\n'
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {
+                self.next_line();
+                ch
+            }
+            _ => {
+                self.next_column();
+                ch
+            }
+        }
+    }
+
+    /// Reads an escape sequence of a template literal, after the `\`.
+    ///
+    /// Invalid escape sequences are not an error here, since they are allowed in tagged templates,
+    /// but they make the template have no cooked string.
+    fn read_template_escape(
+        &mut self,
+        raw: &mut String,
+        cooked: &mut Option<String>,
+    ) -> Result<(), LexerError> {
+        let escape = self
+            .buffer
+            .next()
+            .ok_or_else(|| LexerError::new("Unterminated template literal"))?;
+        let escape = self.read_template_char(escape);
+        raw.push(escape);
+
+        let escaped = match escape {
+            'n' => Some('\n'),
+            'r' => Some('\r'),
+            't' => Some('\t'),
+            'b' => Some('\x08'),
+            'f' => Some('\x0c'),
+            'v' => Some('\x0b'),
+            '0' if !matches!(self.preview_next(), Some(ch) if ch.is_ascii_digit()) => Some('\0'),
+            '0'..='9' => None,
+            'x' => {
+                let digits = self.read_template_hex_digits(2, raw);
+                if digits.len() == 2 {
+                    u32::from_str_radix(&digits, 16).ok().and_then(from_u32)
+                } else {
+                    None
+                }
+            }
+            'u' if self.next_is('{') => {
+                raw.push('{');
+                let digits = self.read_template_hex_digits(usize::MAX, raw);
+                if self.next_is('}') {
+                    raw.push('}');
+                    u32::from_str_radix(&digits, 16).ok().and_then(from_u32)
+                } else {
+                    None
+                }
+            }
+            'u' => {
+                let digits = self.read_template_hex_digits(4, raw);
+                if digits.len() == 4 {
+                    let mut code_units =
+                        vec![u16::from_str_radix(&digits, 16).expect("invalid hex digits")];
+
+                    // A surrogate pair is written as two consecutive escape sequences.
+                    let mut lookahead = self.buffer.clone();
+                    if (0xD800..0xDC00).contains(&code_units[0])
+                        && lookahead.next() == Some('\\')
+                        && lookahead.next() == Some('u')
+                    {
+                        let low: String = lookahead.take(4).collect();
+                        if let Ok(low) = u16::from_str_radix(&low, 16) {
+                            if (0xDC00..0xE000).contains(&low) {
+                                for _ in 0..6 {
+                                    raw.push(self.next());
+                                }
+                                self.move_columns(6);
+                                code_units.push(low);
+                            }
+                        }
+                    }
+
+                    // Unpaired surrogates can't be represented in a Rust string.
+                    decode_utf16(code_units)
+                        .next()
+                        .map(|ch| ch.unwrap_or(std::char::REPLACEMENT_CHARACTER))
+                } else {
+                    None
+                }
+            }
+            // Line continuation
+            '\n' | '\u{2028}' | '\u{2029}' => return Ok(()),
+            ch => Some(ch),
+        };
+
+        match (cooked.as_mut(), escaped) {
+            (Some(cooked), Some(ch)) => cooked.push(ch),
+            _ => *cooked = None,
+        }
 
         Ok(())
     }
 
+    /// Reads up to `max` hexadecimal digits of a template escape sequence.
+    fn read_template_hex_digits(&mut self, max: usize, raw: &mut String) -> String {
+        let mut digits = String::new();
+        while digits.len() < max {
+            match self.preview_next() {
+                Some(ch) if ch.is_ascii_hexdigit() => {
+                    digits.push(self.next());
+                    self.next_column();
+                }
+                _ => break,
+            }
+        }
+        raw.push_str(&digits);
+        digits
+    }
+
     /// Runs the lexer until completion, returning a [LexerError] if there's a syntax issue, or an empty unit result
     ///
     /// # Example
@@ -502,6 +688,7 @@ impl<'a> Lexer<'a> {
                  // StringLiteral
                 '"' | '\'' => {
                     let mut buf = String::new();
+                    let mut legacy_octal = false;
                     loop {
                         if self.preview_next().is_none() {
                             return Err(LexerError::new("Unterminated String"));
@@ -526,7 +713,28 @@ impl<'a> Lexer<'a> {
                                         't' => '\t',
                                         'b' => '\x08',
                                         'f' => '\x0c',
-                                        '0' => '\0',
+                                        '0' if !matches!(self.preview_next(), Some(ch) if ch.is_ascii_digit()) => '\0',
+                                        '0'..='7' => {
+                                            // Legacy octal escape sequences have up to three
+                                            // digits, with a value of at most `\377`.
+                                            legacy_octal = true;
+                                            let max_len = if escape <= '3' { 3 } else { 2 };
+                                            let mut value = escape.to_digit(8).expect("octal digit");
+                                            for _ in 1..max_len {
+                                                match self.preview_next().and_then(|ch| ch.to_digit(8)) {
+                                                    Some(digit) => {
+                                                        self.next();
+                                                        value = value * 8 + digit;
+                                                    }
+                                                    None => break,
+                                                }
+                                            }
+                                            from_u32(value).expect("octal escapes are valid characters")
+                                        }
+                                        '8' | '9' => {
+                                            legacy_octal = true;
+                                            escape
+                                        }
                                         'x' => {
                                             let mut nums = String::with_capacity(2);
                                             for _ in 0_u8..2 {
@@ -623,29 +831,11 @@ impl<'a> Lexer<'a> {
                     // to compensate for the incrementing at the top
                     self.move_columns( str_length.wrapping_add(1));
                     self.push_token(TokenKind::string_literal(buf), start_pos);
-                }
-                // TemplateLiteral
-                '`' => {
-                    let mut buf = String::new();
-                    loop {
-                        if self.preview_next().is_none() {
-                            return Err(LexerError::new("Unterminated template literal"));
-                        }
-                        match self.next() {
-                            '`' => {
-                                break;
-                            }
-                            next_ch => buf.push(next_ch),
-                            // TODO when there is an expression inside the literal
-                        }
+                    if legacy_octal {
+                        self.mark_legacy_octal();
                     }
-                    let str_length = buf.len() as u32;
-                    // Why +1? Quotation marks are not included,
-                    // So technically it would be +2, (for both " ") but we want to be 1 less
-                    // to compensate for the incrementing at the top
-                    self.move_columns( str_length.wrapping_add(1));
-                    self.push_token(TokenKind::template_literal(buf), start_pos);
                 }
+                '`' => self.read_template(start_pos, true)?,
                 _ if ch.is_digit(10) => self.reed_numerical_literal(ch)?,
                 _ if ch.is_alphabetic() || ch == '$' || ch == '_' => {
                     let mut buf = ch.to_string();
@@ -691,11 +881,48 @@ impl<'a> Lexer<'a> {
                 '(' => self.push_punc(Punctuator::OpenParen, start_pos),
                 ')' => self.push_punc(Punctuator::CloseParen, start_pos),
                 ',' => self.push_punc(Punctuator::Comma, start_pos),
-                '{' => self.push_punc(Punctuator::OpenBlock, start_pos),
-                '}' => self.push_punc(Punctuator::CloseBlock, start_pos),
+                '{' => {
+                    if let Some(open_blocks) = self.template_substitutions.last_mut() {
+                        *open_blocks += 1;
+                    }
+                    self.push_punc(Punctuator::OpenBlock, start_pos)
+                }
+                '}' => match self.template_substitutions.last_mut() {
+                    // The substitution ends here, and the template continues
+                    Some(0) => {
+                        self.template_substitutions.pop();
+                        self.read_template(start_pos, false)?
+                    }
+                    Some(open_blocks) => {
+                        *open_blocks -= 1;
+                        self.push_punc(Punctuator::CloseBlock, start_pos)
+                    }
+                    None => self.push_punc(Punctuator::CloseBlock, start_pos),
+                },
                 '[' => self.push_punc(Punctuator::OpenBracket, start_pos),
                 ']' => self.push_punc(Punctuator::CloseBracket, start_pos),
-                '?' => self.push_punc(Punctuator::Question, start_pos),
+                '?' => match self.preview_next() {
+                    Some('?') => {
+                        self.next();
+                        self.next_column();
+                        if self.next_is('=') {
+                            self.push_punc(Punctuator::AssignCoalesce, start_pos);
+                        } else {
+                            self.push_punc(Punctuator::Coalesce, start_pos);
+                        }
+                    }
+                    // `?.5` is a conditional operator followed by a number.
+                    Some('.')
+                        if !self
+                            .preview_multiple_next(2)
+                            .map_or(false, |ch| ch.is_ascii_digit()) =>
+                    {
+                        self.next();
+                        self.next_column();
+                        self.push_punc(Punctuator::Optional, start_pos);
+                    }
+                    _ => self.push_punc(Punctuator::Question, start_pos),
+                },
                 // Comments
                 '/' => {
                     if let Some(ch) = self.preview_next() {
@@ -815,10 +1042,22 @@ impl<'a> Lexer<'a> {
                 }),
                 '%' => op!(self, start_pos, Punctuator::AssignMod, Punctuator::Mod),
                 '|' => op!(self, start_pos, Punctuator::AssignOr, Punctuator::Or, {
-                    '|' => Punctuator::BoolOr
+                    '|' => {
+                        if self.next_is('=') {
+                            Punctuator::AssignBoolOr
+                        } else {
+                            Punctuator::BoolOr
+                        }
+                    }
                 }),
                 '&' => op!(self, start_pos, Punctuator::AssignAnd, Punctuator::And, {
-                    '&' => Punctuator::BoolAnd
+                    '&' => {
+                        if self.next_is('=') {
+                            Punctuator::AssignBoolAnd
+                        } else {
+                            Punctuator::BoolAnd
+                        }
+                    }
                 }),
                 '^' => op!(self, start_pos, Punctuator::AssignXor, Punctuator::Xor),
                 '=' => op!(self, start_pos, if self.next_is('=') {
@@ -2,7 +2,7 @@
 #![allow(clippy::indexing_slicing)]
 
 use super::*;
-use crate::syntax::ast::Keyword;
+use crate::syntax::ast::{token::TemplateString, Keyword};
 
 fn span(start: (u32, u32), end: (u32, u32)) -> Span {
     Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
@@ -44,10 +44,78 @@ fn check_template_literal_simple() {
     lexer.lex().expect("failed to lex");
     assert_eq!(
         lexer.tokens[0].kind,
-        TokenKind::template_literal("I'm a template literal")
+        TokenKind::template_no_substitution(TemplateString::new(
+            "I'm a template literal",
+            Some("I'm a template literal")
+        ))
     );
 }
 
+#[test]
+fn check_template_literal_substitutions() {
+    let s = "`a${b}c${ {d: `e${f}`} }g`";
+    let mut lexer = Lexer::new(s);
+    lexer.lex().expect("failed to lex");
+    let template = |s| TemplateString::new(s, Some(s));
+    let expected = [
+        TokenKind::TemplateHead(template("a")),
+        TokenKind::identifier("b"),
+        TokenKind::TemplateMiddle(template("c")),
+        TokenKind::Punctuator(Punctuator::OpenBlock),
+        TokenKind::identifier("d"),
+        TokenKind::Punctuator(Punctuator::Colon),
+        TokenKind::TemplateHead(template("e")),
+        TokenKind::identifier("f"),
+        TokenKind::TemplateTail(template("")),
+        TokenKind::Punctuator(Punctuator::CloseBlock),
+        TokenKind::TemplateTail(template("g")),
+    ];
+
+    for (i, kind) in expected.iter().enumerate() {
+        assert_eq!(&lexer.tokens[i].kind, kind);
+    }
+    assert_eq!(lexer.tokens.len(), expected.len());
+}
+
+#[test]
+fn check_template_literal_escapes() {
+    let s = "`\\n\\x41\\u0042\\u{43}\\uD83D\\uDE00\\\r\nd\r\n\\``";
+    let mut lexer = Lexer::new(s);
+    lexer.lex().expect("failed to lex");
+    assert_eq!(
+        lexer.tokens[0].kind,
+        TokenKind::template_no_substitution(TemplateString::new(
+            "\\n\\x41\\u0042\\u{43}\\uD83D\\uDE00\\\nd\n\\`",
+            Some("\nABC\u{1F600}d\n`")
+        ))
+    );
+}
+
+#[test]
+fn check_template_literal_invalid_escape() {
+    let s = "`\\unicode and \\u{55}`";
+    let mut lexer = Lexer::new(s);
+    lexer.lex().expect("failed to lex");
+    assert_eq!(
+        lexer.tokens[0].kind,
+        TokenKind::template_no_substitution(TemplateString::new(
+            "\\unicode and \\u{55}",
+            None::<&str>
+        ))
+    );
+}
+
+#[test]
+fn check_template_literal_positions() {
+    let s = "`a\nb${c}` d";
+    let mut lexer = Lexer::new(s);
+    lexer.lex().expect("failed to lex");
+    assert_eq!(lexer.tokens[0].span(), span((1, 1), (2, 3)));
+    assert_eq!(lexer.tokens[1].span(), span((2, 4), (2, 4)));
+    assert_eq!(lexer.tokens[2].span(), span((2, 5), (2, 6)));
+    assert_eq!(lexer.tokens[3].span(), span((2, 8), (2, 8)));
+}
+
 #[test]
 fn check_template_literal_unterminated() {
     let s = "`I'm a template";
@@ -257,6 +325,32 @@ fn check_punctuators() {
     );
 }
 
+#[test]
+fn check_short_circuit_punctuators() {
+    let mut lexer = Lexer::new("?. ?? ??= ||= &&= ?");
+    lexer.lex().expect("failed to lex");
+    let kinds: Vec<_> = lexer.tokens.iter().map(|tok| tok.kind.clone()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Punctuator(Punctuator::Optional),
+            TokenKind::Punctuator(Punctuator::Coalesce),
+            TokenKind::Punctuator(Punctuator::AssignCoalesce),
+            TokenKind::Punctuator(Punctuator::AssignBoolOr),
+            TokenKind::Punctuator(Punctuator::AssignBoolAnd),
+            TokenKind::Punctuator(Punctuator::Question),
+        ]
+    );
+
+    // `?.` followed by a digit is a conditional operator.
+    let mut lexer = Lexer::new("a?.5:b");
+    lexer.lex().expect("failed to lex");
+    assert_eq!(
+        lexer.tokens[1].kind,
+        TokenKind::Punctuator(Punctuator::Question)
+    );
+}
+
 #[test]
 fn check_keywords() {
     // https://tc39.es/ecma262/#sec-keywords
@@ -531,3 +625,21 @@ fn addition_no_spaces_e_number() {
         TokenKind::numeric_literal(100_000_000_000.0)
     );
 }
+
+#[test]
+fn legacy_octal_literals() {
+    let mut lexer = Lexer::new("017 0o17 09 '\\17' '\\0' '\\8'");
+
+    lexer.lex().expect("failed to lex");
+    assert_eq!(lexer.tokens[0].kind, TokenKind::numeric_literal(15));
+    assert!(lexer.tokens[0].is_legacy_octal());
+    assert!(!lexer.tokens[1].is_legacy_octal());
+    assert_eq!(lexer.tokens[2].kind, TokenKind::numeric_literal(9));
+    assert!(lexer.tokens[2].is_legacy_octal());
+    assert_eq!(lexer.tokens[3].kind, TokenKind::string_literal("\u{f}"));
+    assert!(lexer.tokens[3].is_legacy_octal());
+    assert_eq!(lexer.tokens[4].kind, TokenKind::string_literal("\0"));
+    assert!(!lexer.tokens[4].is_legacy_octal());
+    assert_eq!(lexer.tokens[5].kind, TokenKind::string_literal("8"));
+    assert!(lexer.tokens[5].is_legacy_octal());
+}
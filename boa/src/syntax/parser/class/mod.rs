@@ -0,0 +1,178 @@
+//! Class definition parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+//! [spec]: https://tc39.es/ecma262/#sec-class-definitions
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{self, MethodDefinitionKind, PropertyDefinition, PropertyName},
+            Keyword, Punctuator, TokenKind,
+        },
+        parser::{
+            expression::{LeftHandSideExpression, MethodDefinition},
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+/// Class tail parsing.
+///
+/// Parses the optional `extends` clause and the class body.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ClassTail {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassTail {
+    /// Creates a new `ClassTail` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for ClassTail {
+    type Output = node::ClassTail;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassTail", "Parsing");
+
+        // All parts of a class are strict mode code.
+        let strict_mode = cursor.strict_mode();
+        cursor.set_strict_mode(true);
+        let tail = self.parse_strict(cursor);
+        cursor.set_strict_mode(strict_mode);
+        tail
+    }
+}
+
+impl ClassTail {
+    /// Parses the class tail, once the cursor is in strict mode.
+    fn parse_strict(self, cursor: &mut Cursor<'_>) -> Result<node::ClassTail, ParseError> {
+        let heritage = if cursor.next_if(Keyword::Extends).is_some() {
+            Some(LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?)
+        } else {
+            None
+        };
+
+        cursor.expect(Punctuator::OpenBlock, "class body")?;
+
+        let mut constructor = None;
+        let mut elements = Vec::new();
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock).is_some() {
+                break;
+            }
+            if cursor.next_if(Punctuator::Semicolon).is_some() {
+                continue;
+            }
+
+            let position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
+            let element = ClassElement::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+            if !element.is_static() && is_literal_name(element.name(), "constructor") {
+                if element.kind() != MethodDefinitionKind::Ordinary {
+                    return Err(ParseError::general(
+                        "class constructor may not be an accessor or a generator",
+                        position,
+                    ));
+                }
+                if constructor.is_some() {
+                    return Err(ParseError::general(
+                        "a class may only have one constructor",
+                        position,
+                    ));
+                }
+                constructor = Some(element.function().clone());
+            } else {
+                if element.is_static() && is_literal_name(element.name(), "prototype") {
+                    return Err(ParseError::general(
+                        "classes may not have a static property named 'prototype'",
+                        position,
+                    ));
+                }
+                elements.push(element);
+            }
+        }
+
+        Ok(node::ClassTail::new(heritage, constructor, elements))
+    }
+}
+
+/// Checks if a property name is the given literal name.
+///
+/// Computed names never match, even if they evaluate to the same string.
+fn is_literal_name(name: &PropertyName, literal: &str) -> bool {
+    matches!(name, PropertyName::Literal(ref name) if name.as_ref() == literal)
+}
+
+/// Class element parsing.
+///
+/// Parses a method, generator method, getter or setter of a class, which may be preceded by `static`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+#[derive(Debug, Clone, Copy)]
+struct ClassElement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassElement {
+    /// Creates a new `ClassElement` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for ClassElement {
+    type Output = node::ClassElement;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        // `static` is only a modifier if it is not the name of the method itself.
+        let is_static = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind
+            == TokenKind::identifier("static")
+            && cursor.peek(1).ok_or(ParseError::AbruptEnd)?.kind
+                != TokenKind::Punctuator(Punctuator::OpenParen);
+        if is_static {
+            let _ = cursor.next();
+        }
+
+        match MethodDefinition::new(self.allow_yield, self.allow_await).parse(cursor)? {
+            PropertyDefinition::MethodDefinition(kind, ref name, ref function) => Ok(
+                node::ClassElement::new(kind, name.clone(), function.clone(), is_static),
+            ),
+            _ => unreachable!("method definitions always produce methods"),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::syntax::{
+    ast::node::{
+        Assign, BinOp, Call, ClassDecl, ClassElement, ClassExpr, ClassTail, ConstDecl,
+        ConstDeclList, FormalParameter, FunctionExpr, GetConstField, GetSuperField, Identifier,
+        MethodDefinitionKind, Node, Return, Spread, StatementList, SuperCall,
+    },
+    ast::{op::NumOp, Const},
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Creates the body of a class method, which is always strict mode code.
+fn strict<B>(statements: B) -> StatementList
+where
+    B: Into<Box<[Node]>>,
+{
+    StatementList::new(statements, true)
+}
+
+/// Checks a class declaration with a constructor and a method.
+#[test]
+fn check_basic_class() {
+    check_parser(
+        "class Foo { constructor(a) { this.a = a; } get() { return this.a; } }",
+        vec![ClassDecl::new(
+            "Foo",
+            ClassTail::new(
+                None,
+                FunctionExpr::new(
+                    None,
+                    vec![FormalParameter::new("a", None, false)],
+                    strict(vec![Assign::new(
+                        GetConstField::new(Node::This, "a"),
+                        Identifier::from("a"),
+                    )
+                    .into()]),
+                ),
+                vec![ClassElement::new(
+                    MethodDefinitionKind::Ordinary,
+                    "get",
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        strict(vec![
+                            Return::new(GetConstField::new(Node::This, "a"), None).into()
+                        ]),
+                    ),
+                    false,
+                )],
+            ),
+        )
+        .into()],
+    );
+}
+
+/// Checks static methods, accessors and stray semicolons in a class body.
+#[test]
+fn check_static_and_accessors() {
+    check_parser(
+        "class Foo { static static() {}; static get x() {} set x(v) {} }",
+        vec![ClassDecl::new(
+            "Foo",
+            ClassTail::new(
+                None,
+                None,
+                vec![
+                    ClassElement::new(
+                        MethodDefinitionKind::Ordinary,
+                        "static",
+                        FunctionExpr::new(None, vec![], strict(vec![])),
+                        true,
+                    ),
+                    ClassElement::new(
+                        MethodDefinitionKind::Get,
+                        "x",
+                        FunctionExpr::new(None, vec![], strict(vec![])),
+                        true,
+                    ),
+                    ClassElement::new(
+                        MethodDefinitionKind::Set,
+                        "x",
+                        FunctionExpr::new(
+                            None,
+                            vec![FormalParameter::new("v", None, false)],
+                            strict(vec![]),
+                        ),
+                        false,
+                    ),
+                ],
+            ),
+        )
+        .into()],
+    );
+}
+
+/// Checks that derived classes get a default constructor forwarding to the parent.
+#[test]
+fn check_default_derived_constructor() {
+    check_parser(
+        "class Foo extends Bar {}",
+        vec![ClassDecl::new(
+            "Foo",
+            ClassTail::new(
+                Node::from(Identifier::from("Bar")),
+                FunctionExpr::new(
+                    None,
+                    vec![FormalParameter::new("args", None, true)],
+                    strict(vec![SuperCall::new(vec![Spread::new(Identifier::from(
+                        "args",
+                    ))
+                    .into()])
+                    .into()]),
+                ),
+                vec![],
+            ),
+        )
+        .into()],
+    );
+}
+
+/// Checks `super` calls and `super` property accesses.
+#[test]
+fn check_super() {
+    check_parser(
+        "const Foo = class extends Bar { constructor() { super(1); } m() { return super.m() + super[1]; } };",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "Foo",
+            ClassExpr::new(
+                None,
+                ClassTail::new(
+                    Node::from(Identifier::from("Bar")),
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        strict(vec![SuperCall::new(vec![Const::from(1).into()]).into()]),
+                    ),
+                    vec![ClassElement::new(
+                        MethodDefinitionKind::Ordinary,
+                        "m",
+                        FunctionExpr::new(
+                            None,
+                            vec![],
+                            strict(vec![Return::new(
+                                BinOp::new(
+                                    NumOp::Add,
+                                    Call::new(
+                                        GetSuperField::new(Const::from("m")),
+                                        vec![],
+                                    ),
+                                    GetSuperField::new(Const::from(1)),
+                                ),
+                                None,
+                            )
+                            .into()]),
+                        ),
+                        false,
+                    )],
+                ),
+            ),
+        )])
+        .into()],
+    );
+}
+
+/// Checks invalid class bodies.
+#[test]
+fn check_invalid_classes() {
+    check_invalid("class Foo { constructor() {} constructor() {} }");
+    check_invalid("class Foo { get constructor() {} }");
+    check_invalid("class Foo { static prototype() {} }");
+    check_invalid("class { }");
+    check_invalid("class Foo { a: 1 }");
+}
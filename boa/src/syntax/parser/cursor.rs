@@ -15,6 +15,28 @@ pub(super) struct Cursor<'a> {
     tokens: &'a [Token],
     /// The current position within the tokens.
     pos: usize,
+    /// Whether the code being parsed is strict mode code.
+    strict_mode: bool,
+    /// The statements that the `break` and `continue` statements being parsed can jump to.
+    jump_targets: JumpTargets,
+}
+
+/// The statements that `break` and `continue` statements can jump to, which are the enclosing
+/// statements of the function being parsed.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-containsundefinedbreaktarget
+#[derive(Debug, Clone, Default)]
+struct JumpTargets {
+    /// The labels of the enclosing labelled statements, along with whether they label an
+    /// iteration statement.
+    labels: Vec<(Box<str>, bool)>,
+    /// Whether the code is in the body of an iteration statement.
+    in_iteration: bool,
+    /// Whether the code is in the case block of a `switch` statement.
+    in_switch: bool,
 }
 
 impl<'a> Cursor<'a> {
@@ -38,6 +60,86 @@ impl<'a> Cursor<'a> {
         self.pos = pos
     }
 
+    /// Checks if the code being parsed is strict mode code.
+    pub(super) fn strict_mode(&self) -> bool {
+        self.strict_mode
+    }
+
+    /// Sets whether the code being parsed is strict mode code.
+    pub(super) fn set_strict_mode(&mut self, strict_mode: bool) {
+        self.strict_mode = strict_mode
+    }
+
+    /// Checks if an unlabelled `break` statement can be parsed, which must be in an iteration
+    /// statement or a `switch` statement.
+    pub(super) fn can_break(&self) -> bool {
+        self.jump_targets.in_iteration || self.jump_targets.in_switch
+    }
+
+    /// Checks if an unlabelled `continue` statement can be parsed, which must be in an iteration
+    /// statement.
+    pub(super) fn can_continue(&self) -> bool {
+        self.jump_targets.in_iteration
+    }
+
+    /// Gets whether the enclosing statement labelled `label` is an iteration statement, or
+    /// `None` if there is no such statement.
+    pub(super) fn label(&self, label: &str) -> Option<bool> {
+        self.jump_targets
+            .labels
+            .iter()
+            .rev()
+            .find(|(name, _)| name.as_ref() == label)
+            .map(|&(_, iteration)| iteration)
+    }
+
+    /// Parses the body of a labelled statement with `parse`, with `label` in the label set.
+    pub(super) fn with_label<T, F>(&mut self, label: Box<str>, iteration: bool, parse: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        self.jump_targets.labels.push((label, iteration));
+        let result = parse(self);
+        self.jump_targets.labels.pop();
+        result
+    }
+
+    /// Parses the body of an iteration statement with `parse`.
+    pub(super) fn with_iteration<T, F>(&mut self, parse: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let in_iteration = self.jump_targets.in_iteration;
+        self.jump_targets.in_iteration = true;
+        let result = parse(self);
+        self.jump_targets.in_iteration = in_iteration;
+        result
+    }
+
+    /// Parses the case block of a `switch` statement with `parse`.
+    pub(super) fn with_switch<T, F>(&mut self, parse: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let in_switch = self.jump_targets.in_switch;
+        self.jump_targets.in_switch = true;
+        let result = parse(self);
+        self.jump_targets.in_switch = in_switch;
+        result
+    }
+
+    /// Parses the body of a function with `parse`, which can't jump to the statements enclosing
+    /// the function.
+    pub(super) fn with_function_body<T, F>(&mut self, parse: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let jump_targets = std::mem::take(&mut self.jump_targets);
+        let result = parse(self);
+        self.jump_targets = jump_targets;
+        result
+    }
+
     /// Moves the cursor to the next token and returns the token.
     pub(super) fn next(&mut self) -> Option<&'a Token> {
         loop {
@@ -84,13 +186,13 @@ impl<'a> Cursor<'a> {
         );
 
         self.pos -= 1;
-        while self
-            .tokens
-            .get(self.pos - 1)
-            .expect("token disappeared")
-            .kind
-            == TokenKind::LineTerminator
-            && self.pos > 0
+        while self.pos > 0
+            && self
+                .tokens
+                .get(self.pos - 1)
+                .expect("token disappeared")
+                .kind
+                == TokenKind::LineTerminator
         {
             self.pos -= 1;
         }
@@ -223,6 +325,64 @@ impl<'a> Cursor<'a> {
         }
     }
 
+    /// Checks if the directive prologue at the current position, at the start of a script or of
+    /// a function body, contains a `"use strict"` directive.
+    ///
+    /// The directives are not consumed, they are parsed as expression statements afterwards.
+    /// Directives before the `"use strict"` directive are strict mode code too, so they can't use
+    /// legacy octal escape sequences.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-directive-prologues-and-the-use-strict-directive
+    pub(super) fn peek_use_strict_directive(&self) -> Result<bool, ParseError> {
+        let mut pos = self.pos;
+        let mut legacy_octal = None;
+        loop {
+            while self.tokens.get(pos).map(|tk| &tk.kind) == Some(&TokenKind::LineTerminator) {
+                pos += 1;
+            }
+            let directive = match self.tokens.get(pos) {
+                Some(tk) => tk,
+                None => return Ok(false),
+            };
+            let is_use_strict = match directive.kind {
+                TokenKind::StringLiteral(ref string) => string.as_ref() == "use strict",
+                _ => return Ok(false),
+            };
+            pos += 1;
+
+            // The string literal has to be a whole expression statement, ended by a semicolon, by
+            // the end of the body or by a line terminator.
+            let mut line_terminator = false;
+            while self.tokens.get(pos).map(|tk| &tk.kind) == Some(&TokenKind::LineTerminator) {
+                line_terminator = true;
+                pos += 1;
+            }
+            match self.tokens.get(pos).map(|tk| &tk.kind) {
+                Some(TokenKind::Punctuator(Punctuator::Semicolon)) => pos += 1,
+                None | Some(TokenKind::Punctuator(Punctuator::CloseBlock)) => {}
+                Some(TokenKind::Punctuator(_)) => return Ok(false),
+                Some(_) if line_terminator => {}
+                Some(_) => return Ok(false),
+            }
+
+            if is_use_strict {
+                return match legacy_octal {
+                    Some(token) => Err(ParseError::unexpected(
+                        Token::clone(token),
+                        "octal escape sequences are not allowed in strict mode",
+                    )),
+                    None => Ok(true),
+                };
+            }
+            if directive.is_legacy_octal() {
+                legacy_octal = Some(directive);
+            }
+        }
+    }
+
     /// Advance the cursor to the next token and retrieve it, only if it's of `kind` type.
     ///
     /// When the next token is a `kind` token, get the token, otherwise return `None`. This
@@ -11,12 +11,15 @@ use super::AssignmentExpression;
 use crate::{
     syntax::{
         ast::{
-            node::{ArrowFunctionDecl, FormalParameter, Node, Return, StatementList},
+            node::{
+                ArrowFunctionDecl, AsyncArrowFunctionDecl, FormalParameter, Node, Return,
+                StatementList,
+            },
             Punctuator, TokenKind,
         },
         parser::{
             error::{ErrorContext, ParseError, ParseResult},
-            function::{FormalParameters, FunctionBody},
+            function::{check_parameters, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             AllowAwait, AllowIn, AllowYield, Cursor, TokenParser,
         },
@@ -65,6 +68,7 @@ impl TokenParser for ArrowFunction {
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ArrowFunction", "Parsing");
         let next_token = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        let params_position = next_token.span().start();
         let params = if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind {
             // CoverParenthesizedExpressionAndArrowParameterList
             cursor.expect(Punctuator::OpenParen, "arrow function")?;
@@ -82,26 +86,94 @@ impl TokenParser for ArrowFunction {
 
         cursor.expect(Punctuator::Arrow, "arrow function")?;
 
-        let body = ConciseBody::new(self.allow_in).parse(cursor)?;
+        let body = ConciseBody::new(self.allow_in, false).parse(cursor)?;
+        check_parameters(&params, &body, params_position)?;
 
         Ok(ArrowFunctionDecl::new(params, body))
     }
 }
 
+/// Async arrow function parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncArrowFunction
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct AsyncArrowFunction {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+}
+
+impl AsyncArrowFunction {
+    /// Creates a new `AsyncArrowFunction` parser.
+    pub(in crate::syntax::parser) fn new<I, Y>(allow_in: I, allow_yield: Y) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+        }
+    }
+}
+
+impl TokenParser for AsyncArrowFunction {
+    type Output = AsyncArrowFunctionDecl;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AsyncArrowFunction", "Parsing");
+        cursor.expect(TokenKind::identifier("async"), "async arrow function")?;
+        cursor.peek_expect_no_lineterminator(0)?;
+
+        let next_token = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        let params_position = next_token.span().start();
+        let params = if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind {
+            cursor.expect(Punctuator::OpenParen, "async arrow function")?;
+            let params = FormalParameters::new(self.allow_yield, false).parse(cursor)?;
+            cursor.expect(Punctuator::CloseParen, "async arrow function")?;
+            params
+        } else {
+            let param = BindingIdentifier::new(self.allow_yield, true)
+                .parse(cursor)
+                .context("async arrow function")?;
+            Box::new([FormalParameter::new(param, None, false)])
+        };
+
+        cursor.peek_expect_no_lineterminator(0)?;
+
+        cursor.expect(Punctuator::Arrow, "async arrow function")?;
+
+        let body = ConciseBody::new(self.allow_in, true).parse(cursor)?;
+        check_parameters(&params, &body, params_position)?;
+
+        Ok(AsyncArrowFunctionDecl::new(params, body))
+    }
+}
+
 /// <https://tc39.es/ecma262/#prod-ConciseBody>
+///
+/// The body of an async arrow function is an `AsyncConciseBody`, which can contain `await`
+/// expressions.
 #[derive(Debug, Clone, Copy)]
 struct ConciseBody {
     allow_in: AllowIn,
+    allow_await: AllowAwait,
 }
 
 impl ConciseBody {
     /// Creates a new `ConcideBody` parser.
-    fn new<I>(allow_in: I) -> Self
+    fn new<I, A>(allow_in: I, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
+        A: Into<AllowAwait>,
     {
         Self {
             allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
         }
     }
 }
@@ -113,15 +185,18 @@ impl TokenParser for ConciseBody {
         match cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let _ = cursor.next();
-                let body = FunctionBody::new(false, false).parse(cursor)?;
+                let body = FunctionBody::new(false, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
-            _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
-                None,
-            )
-            .into()])),
+            _ => Ok(StatementList::new(
+                vec![Return::new(
+                    ExpressionBody::new(self.allow_in, self.allow_await).parse(cursor)?,
+                    None,
+                )
+                .into()],
+                cursor.strict_mode(),
+            )),
         }
     }
 }
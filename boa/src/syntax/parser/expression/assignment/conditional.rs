@@ -11,7 +11,7 @@ use crate::{
     syntax::{
         ast::{node::ConditionalOp, Node, Punctuator, TokenKind},
         parser::{
-            expression::{AssignmentExpression, LogicalORExpression},
+            expression::{AssignmentExpression, ShortCircuitExpression},
             AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser,
         },
     },
@@ -58,8 +58,7 @@ impl TokenParser for ConditionalExpression {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("Conditional", "Parsing");
-        // TODO: coalesce expression
-        let lhs = LogicalORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+        let lhs = ShortCircuitExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor)?;
 
         if let Some(tok) = cursor.next() {
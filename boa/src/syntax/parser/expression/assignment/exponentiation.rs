@@ -52,9 +52,10 @@ impl ExponentiationExpression {
 
 impl ExponentiationExpression {
     /// Checks by looking at the next token to see whether it's a unary operator or not.
-    fn is_unary_expression(cursor: &mut Cursor<'_>) -> bool {
+    fn is_unary_expression(&self, cursor: &mut Cursor<'_>) -> bool {
         if let Some(tok) = cursor.peek(0) {
             match tok.kind {
+                TokenKind::Keyword(Keyword::Await) => self.allow_await.0,
                 TokenKind::Keyword(Keyword::Delete)
                 | TokenKind::Keyword(Keyword::Void)
                 | TokenKind::Keyword(Keyword::TypeOf)
@@ -75,7 +76,7 @@ impl TokenParser for ExponentiationExpression {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("ExponentiationExpression", "Parsing");
-        if Self::is_unary_expression(cursor) {
+        if self.is_unary_expression(cursor) {
             return UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor);
         }
 
@@ -10,15 +10,24 @@
 mod arrow_function;
 mod conditional;
 mod exponentiation;
+mod yield_expression;
 
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
+use self::{
+    arrow_function::{ArrowFunction, AsyncArrowFunction},
+    conditional::ConditionalExpression,
+    yield_expression::YieldExpression,
+};
+use super::check_assignment_target;
 use crate::{
     syntax::{
         ast::{
             node::{Assign, BinOp, Node},
             Keyword, Punctuator, TokenKind,
         },
-        parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
+        parser::{
+            pattern::AssignmentPattern, AllowAwait, AllowIn, AllowYield, Cursor, ParseError,
+            ParseResult, TokenParser,
+        },
     },
     BoaProfiler,
 };
@@ -29,9 +38,9 @@ pub(super) use exponentiation::ExponentiationExpression;
 /// This can be one of the following:
 ///
 ///  - [`ConditionalExpression`](../conditional_operator/struct.ConditionalExpression.html)
-///  - `YieldExpression`
+///  - [`YieldExpression`](./yield_expression/struct.YieldExpression.html)
 ///  - [`ArrowFunction`](../../function/arrow_function/struct.ArrowFunction.html)
-///  - `AsyncArrowFunction`
+///  - [`AsyncArrowFunction`](../../function/arrow_function/struct.AsyncArrowFunction.html)
 ///  - [`LeftHandSideExpression`][lhs] `=` `AssignmentExpression`
 ///  - [`LeftHandSideExpression`][lhs] `AssignmentOperator` `AssignmentExpression`
 ///
@@ -76,7 +85,26 @@ impl TokenParser for AssignmentExpression {
         let _timer = BoaProfiler::global().start_event("AssignmentExpression", "Parsing");
         // Arrow function
         let next_token = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        let position = next_token.span().start();
         match next_token.kind {
+            // yield a
+            TokenKind::Keyword(Keyword::Yield) if self.allow_yield.0 => {
+                return YieldExpression::new(self.allow_in, self.allow_await).parse(cursor);
+            }
+            // async a => {}, async (a, b) => {}
+            TokenKind::Identifier(ref name)
+                if name.as_ref() == "async"
+                    && cursor.peek(1).map(|tok| &tok.kind)
+                        != Some(&TokenKind::Punctuator(Punctuator::Arrow))
+                    && cursor.peek_expect_no_lineterminator(1).is_ok() =>
+            {
+                if let Some(node) = AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                    .try_parse(cursor)
+                    .map(Node::from)
+                {
+                    return Ok(node);
+                }
+            }
             // a=>{}
             TokenKind::Identifier(_)
             | TokenKind::Keyword(Keyword::Yield)
@@ -105,6 +133,20 @@ impl TokenParser for AssignmentExpression {
                     return Ok(node);
                 }
             }
+            // [a, b] = [b, a]
+            TokenKind::Punctuator(Punctuator::OpenBracket)
+            | TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                // This is an array or object literal unless it's followed by `=`.
+                let initial_pos = cursor.pos();
+                if let Some(pattern) =
+                    AssignmentPattern::new(self.allow_yield, self.allow_await).try_parse(cursor)
+                {
+                    if cursor.next_if(Punctuator::Assign).is_some() {
+                        return Ok(Assign::new(pattern, self.parse(cursor)?).into());
+                    }
+                }
+                cursor.seek(initial_pos);
+            }
             _ => {}
         }
 
@@ -114,9 +156,19 @@ impl TokenParser for AssignmentExpression {
         if let Some(tok) = cursor.next() {
             match tok.kind {
                 TokenKind::Punctuator(Punctuator::Assign) => {
+                    check_assignment_target(&lhs, position)?;
+                    // Array and object literals followed by `=` are the patterns that failed to
+                    // parse, because of an invalid target like `[a?.b] = c`.
+                    if let Node::ArrayDecl(_) | Node::Object(_) = lhs {
+                        return Err(ParseError::general(
+                            "invalid destructuring assignment target",
+                            position,
+                        ));
+                    }
                     lhs = Assign::new(lhs, self.parse(cursor)?).into();
                 }
                 TokenKind::Punctuator(p) if p.as_binop().is_some() && p != Punctuator::Comma => {
+                    check_assignment_target(&lhs, position)?;
                     let expr = self.parse(cursor)?;
                     let binop = p.as_binop().expect("binop disappeared");
                     lhs = BinOp::new(binop, lhs, expr).into();
@@ -0,0 +1,88 @@
+//! Yield expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+//! [spec]: https://tc39.es/ecma262/#sec-generator-function-definitions
+
+use super::AssignmentExpression;
+use crate::{
+    syntax::{
+        ast::{node::Yield, Keyword, Node, Punctuator, TokenKind},
+        parser::{AllowAwait, AllowIn, Cursor, ParseResult, TokenParser},
+    },
+    BoaProfiler,
+};
+
+/// Yield expression parsing.
+///
+/// It is only parsed in the body of generators, where `yield` is a keyword.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct YieldExpression {
+    allow_in: AllowIn,
+    allow_await: AllowAwait,
+}
+
+impl YieldExpression {
+    /// Creates a new `YieldExpression` parser.
+    pub(super) fn new<I, A>(allow_in: I, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for YieldExpression {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "Parsing");
+        cursor.expect(Keyword::Yield, "yield expression")?;
+
+        // The operand has to start on the same line as the `yield` keyword.
+        if cursor.peek_expect_no_lineterminator(0).is_err() {
+            return Ok(Yield::new::<Node, _>(None, false).into());
+        }
+
+        let delegate = cursor.next_if(Punctuator::Mul).is_some();
+        if !delegate {
+            let next = cursor.peek(0).map(|tok| &tok.kind);
+            let has_operand = match next {
+                Some(TokenKind::Punctuator(punctuator)) => !matches!(
+                    punctuator,
+                    Punctuator::CloseParen
+                        | Punctuator::CloseBracket
+                        | Punctuator::CloseBlock
+                        | Punctuator::Comma
+                        | Punctuator::Semicolon
+                        | Punctuator::Colon
+                ),
+                // The end of a substitution, like in `${yield}`.
+                Some(TokenKind::TemplateMiddle(_)) | Some(TokenKind::TemplateTail(_)) => false,
+                Some(_) => true,
+                None => false,
+            };
+            if !has_operand {
+                return Ok(Yield::new::<Node, _>(None, false).into());
+            }
+        }
+
+        let expr =
+            AssignmentExpression::new(self.allow_in, true, self.allow_await).parse(cursor)?;
+        Ok(Yield::new(expr, delegate).into())
+    }
+}
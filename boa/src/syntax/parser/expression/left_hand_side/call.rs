@@ -19,8 +19,8 @@ use crate::{
             Punctuator, TokenKind,
         },
         parser::{
-            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
-            TokenParser,
+            expression::{primary::TaggedTemplateLiteral, Expression},
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
     BoaProfiler,
@@ -105,6 +105,10 @@ impl TokenParser for CallExpression {
                     cursor.expect(Punctuator::CloseBracket, "call expression")?;
                     lhs = GetField::new(lhs, idx).into();
                 }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateHead(_) => {
+                    lhs = TaggedTemplateLiteral::new(self.allow_yield, self.allow_await, lhs)
+                        .parse(cursor)?;
+                }
                 _ => break,
             }
         }
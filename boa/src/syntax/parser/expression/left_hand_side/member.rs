@@ -11,13 +11,16 @@ use crate::{
     syntax::{
         ast::{
             node::{
-                field::{GetConstField, GetField},
-                Call, New, Node,
+                field::{GetConstField, GetField, GetSuperField},
+                Call, New, Node, SuperCall,
             },
-            Keyword, Punctuator, TokenKind,
+            Const, Keyword, Punctuator, TokenKind,
         },
         parser::{
-            expression::{primary::PrimaryExpression, Expression},
+            expression::{
+                primary::{PrimaryExpression, TaggedTemplateLiteral},
+                Expression,
+            },
             AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
@@ -64,6 +67,50 @@ impl TokenParser for MemberExpression {
             let call_node = Call::new(lhs, args);
 
             Node::from(New::from(call_node))
+        } else if cursor.next_if(Keyword::Super).is_some() {
+            let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+            match &tok.kind {
+                TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    Node::from(SuperCall::new(args))
+                }
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    let _ = cursor.next().ok_or(ParseError::AbruptEnd)?; // We move the cursor forward.
+                    match &cursor.next().ok_or(ParseError::AbruptEnd)?.kind {
+                        TokenKind::Identifier(name) => {
+                            GetSuperField::new(Const::from(name.as_ref())).into()
+                        }
+                        TokenKind::Keyword(kw) => {
+                            GetSuperField::new(Const::from(kw.as_str())).into()
+                        }
+                        _ => {
+                            return Err(ParseError::expected(
+                                vec![TokenKind::identifier("identifier")],
+                                tok.clone(),
+                                "super property",
+                            ));
+                        }
+                    }
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let _ = cursor.next().ok_or(ParseError::AbruptEnd)?; // We move the cursor forward.
+                    let idx =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "super property")?;
+                    GetSuperField::new(idx).into()
+                }
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::Punctuator(Punctuator::OpenParen),
+                            TokenKind::Punctuator(Punctuator::Dot),
+                            TokenKind::Punctuator(Punctuator::OpenBracket),
+                        ],
+                        tok.clone(),
+                        "super",
+                    ));
+                }
+            }
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
         };
@@ -94,6 +141,10 @@ impl TokenParser for MemberExpression {
                     cursor.expect(Punctuator::CloseBracket, "member expression")?;
                     lhs = GetField::new(lhs, idx).into();
                 }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateHead(_) => {
+                    lhs = TaggedTemplateLiteral::new(self.allow_yield, self.allow_await, lhs)
+                        .parse(cursor)?;
+                }
                 _ => break,
             }
         }
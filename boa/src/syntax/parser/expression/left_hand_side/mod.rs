@@ -10,8 +10,9 @@
 mod arguments;
 mod call;
 mod member;
+mod optional;
 
-use self::{call::CallExpression, member::MemberExpression};
+use self::{call::CallExpression, member::MemberExpression, optional::OptionalExpression};
 use crate::{
     syntax::{
         ast::{Node, Punctuator, TokenKind},
@@ -29,14 +30,14 @@ use crate::{
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Expressions_and_Operators#Left-hand-side_expressions
 /// [spec]: https://tc39.es/ecma262/#prod-LeftHandSideExpression
 #[derive(Debug, Clone, Copy)]
-pub(super) struct LeftHandSideExpression {
+pub(in crate::syntax::parser) struct LeftHandSideExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl LeftHandSideExpression {
     /// Creates a new `LeftHandSideExpression` parser.
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -54,12 +55,18 @@ impl TokenParser for LeftHandSideExpression {
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("LeftHandSIdeExpression", "Parsing");
         // TODO: Implement NewExpression: new MemberExpression
-        let lhs = MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let mut lhs = MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        if let Some(tok) = cursor.peek(0) {
+            if tok.kind == TokenKind::Punctuator(Punctuator::OpenParen) {
+                lhs = CallExpression::new(self.allow_yield, self.allow_await, lhs).parse(cursor)?;
+            }
+        }
+
         match cursor.peek(0) {
-            Some(ref tok) if tok.kind == TokenKind::Punctuator(Punctuator::OpenParen) => {
-                CallExpression::new(self.allow_yield, self.allow_await, lhs).parse(cursor)
+            Some(tok) if tok.kind == TokenKind::Punctuator(Punctuator::Optional) => {
+                OptionalExpression::new(self.allow_yield, self.allow_await, lhs).parse(cursor)
             }
-            _ => Ok(lhs), // TODO: is this correct?
+            _ => Ok(lhs),
         }
     }
 }
@@ -0,0 +1,144 @@
+//! Optional chain parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+//! [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+
+use super::arguments::Arguments;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, Optional, OptionalOperation, OptionalOperationKind},
+            Punctuator, TokenKind,
+        },
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+/// Parses an optional expression, a member or call expression followed by an optional chain.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+#[derive(Debug)]
+pub(super) struct OptionalExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    target: Node,
+}
+
+impl OptionalExpression {
+    /// Creates a new `OptionalExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, target: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            target,
+        }
+    }
+
+    /// Parses the operation following a `.` or a `?.`.
+    fn parse_operation(
+        &self,
+        cursor: &mut Cursor<'_>,
+        shorted: bool,
+    ) -> Result<OptionalOperation, ParseError> {
+        let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        let kind = match tok.kind {
+            TokenKind::Identifier(ref name) => {
+                let _ = cursor.next().expect("token disappeared");
+                OptionalOperationKind::GetConstField(name.clone())
+            }
+            TokenKind::Keyword(kw) => {
+                let _ = cursor.next().expect("token disappeared");
+                OptionalOperationKind::GetConstField(kw.to_string().into())
+            }
+            // `?.(args)`
+            TokenKind::Punctuator(Punctuator::OpenParen) if shorted => {
+                let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                OptionalOperationKind::Call(args)
+            }
+            // `?.[expr]`
+            TokenKind::Punctuator(Punctuator::OpenBracket) if shorted => {
+                let _ = cursor.next().expect("token disappeared");
+                let field =
+                    Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                OptionalOperationKind::GetField(Box::new(field))
+            }
+            TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateHead(_) => {
+                return Err(ParseError::unexpected(
+                    tok.clone(),
+                    "tagged template cannot be used in optional chain",
+                ));
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    vec![TokenKind::identifier("identifier")],
+                    tok.clone(),
+                    "optional chain",
+                ));
+            }
+        };
+
+        Ok(OptionalOperation::new(kind, shorted))
+    }
+}
+
+impl TokenParser for OptionalExpression {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("OptionalExpression", "Parsing");
+        let mut chain = Vec::new();
+
+        while let Some(tok) = cursor.peek(0) {
+            let operation = match tok.kind {
+                TokenKind::Punctuator(Punctuator::Optional) => {
+                    let _ = cursor.next().expect("token disappeared");
+                    self.parse_operation(cursor, true)?
+                }
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    let _ = cursor.next().expect("token disappeared");
+                    self.parse_operation(cursor, false)?
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let _ = cursor.next().expect("token disappeared");
+                    let field =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                    OptionalOperation::new(OptionalOperationKind::GetField(Box::new(field)), false)
+                }
+                TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    OptionalOperation::new(OptionalOperationKind::Call(args), false)
+                }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateHead(_) => {
+                    return Err(ParseError::unexpected(
+                        tok.clone(),
+                        "tagged template cannot be used in optional chain",
+                    ));
+                }
+                _ => break,
+            };
+            chain.push(operation);
+        }
+
+        Ok(Optional::new(self.target, chain).into())
+    }
+}
@@ -16,13 +16,18 @@ mod unary;
 mod update;
 
 use self::assignment::ExponentiationExpression;
-pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
-use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser};
+pub(super) use self::{
+    assignment::AssignmentExpression,
+    left_hand_side::LeftHandSideExpression,
+    primary::{Initializer, MethodDefinition, PropertyName},
+};
+use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser};
 use crate::{
     profiler::BoaProfiler,
     syntax::ast::{
         node::{BinOp, Node},
-        Keyword, Punctuator, TokenKind,
+        op::LogOp,
+        Keyword, Position, Punctuator, TokenKind,
     },
 };
 
@@ -42,6 +47,20 @@ impl PartialEq<Punctuator> for Keyword {
     }
 }
 
+/// Checks that an expression can be the target of an assignment or of an update expression.
+///
+/// Optional chains, like in `a?.b = 1` or `a?.b++`, can't be assigned to, which is an early
+/// error.
+fn check_assignment_target(target: &Node, position: Position) -> Result<(), ParseError> {
+    match target {
+        Node::Optional(_) => Err(ParseError::general(
+            "invalid assignment to an optional chain",
+            position,
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Generates an expression parser.
 ///
 /// This macro has 2 mandatory identifiers:
@@ -120,23 +139,27 @@ expression!(
     [allow_in, allow_yield, allow_await]
 );
 
-/// Parses a logical `OR` expression.
+/// Parses a short-circuit expression, either a logical `OR` expression or a nullish coalescing
+/// expression.
+///
+/// The operands of `??` can't be `&&` or `||` expressions, and the other way around, unless they
+/// are in parentheses.
 ///
 /// More information:
 ///  - [MDN documentation][mdn]
 ///  - [ECMAScript specification][spec]
 ///
-/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Logical_Operators#Logical_OR_2
-/// [spec]: https://tc39.es/ecma262/#prod-LogicalORExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Nullish_coalescing_operator
+/// [spec]: https://tc39.es/ecma262/#prod-ShortCircuitExpression
 #[derive(Debug, Clone, Copy)]
-struct LogicalORExpression {
+struct ShortCircuitExpression {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
-impl LogicalORExpression {
-    /// Creates a new `LogicalORExpression` parser.
+impl ShortCircuitExpression {
+    /// Creates a new `ShortCircuitExpression` parser.
     pub(super) fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
@@ -151,12 +174,63 @@ impl LogicalORExpression {
     }
 }
 
-expression!(
-    LogicalORExpression,
-    LogicalANDExpression,
-    [Punctuator::BoolOr],
-    [allow_in, allow_yield, allow_await]
-);
+impl TokenParser for ShortCircuitExpression {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("ShortCircuitExpression", "Parsing");
+        let mut lhs = BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+            .parse(cursor)?;
+
+        // CoalesceExpression
+        if cursor.next_if(Punctuator::Coalesce).is_some() {
+            loop {
+                let rhs =
+                    BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor)?;
+                lhs = BinOp::new(LogOp::Coalesce, lhs, rhs).into();
+                if cursor.next_if(Punctuator::Coalesce).is_none() {
+                    break;
+                }
+            }
+
+            return match cursor.peek(0) {
+                Some(tok)
+                    if tok.kind == TokenKind::Punctuator(Punctuator::BoolAnd)
+                        || tok.kind == TokenKind::Punctuator(Punctuator::BoolOr) =>
+                {
+                    Err(ParseError::unexpected(
+                        tok.clone(),
+                        "cannot mix '??' with '&&' or '||' without parentheses",
+                    ))
+                }
+                _ => Ok(lhs),
+            };
+        }
+
+        // LogicalORExpression
+        while cursor.next_if(Punctuator::BoolAnd).is_some() {
+            let rhs = BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                .parse(cursor)?;
+            lhs = BinOp::new(LogOp::And, lhs, rhs).into();
+        }
+        while cursor.next_if(Punctuator::BoolOr).is_some() {
+            let rhs = LogicalANDExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                .parse(cursor)?;
+            lhs = BinOp::new(LogOp::Or, lhs, rhs).into();
+        }
+
+        match cursor.peek(0) {
+            Some(tok) if tok.kind == TokenKind::Punctuator(Punctuator::Coalesce) => {
+                Err(ParseError::unexpected(
+                    tok.clone(),
+                    "cannot mix '??' with '&&' or '||' without parentheses",
+                ))
+            }
+            _ => Ok(lhs),
+        }
+    }
+}
 
 /// Parses a logical `AND` expression.
 ///
@@ -392,7 +466,8 @@ expression!(
         Punctuator::GreaterThan,
         Punctuator::LessThanOrEq,
         Punctuator::GreaterThanOrEq,
-        Keyword::In
+        Keyword::In,
+        Keyword::InstanceOf
     ],
     [allow_yield, allow_await]
 );
@@ -0,0 +1,59 @@
+//! Async function expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function
+//! [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionExpression
+
+use crate::{
+    syntax::{
+        ast::{node::AsyncFunctionExpr, Keyword, Punctuator},
+        parser::{
+            function::{check_parameters, FormalParameters, FunctionBody},
+            statement::BindingIdentifier,
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+/// Async function expression parsing.
+///
+/// The `async` keyword has already been consumed.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/async_function
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AsyncFunctionExpression;
+
+impl TokenParser for AsyncFunctionExpression {
+    type Output = AsyncFunctionExpr;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AsyncFunctionExpression", "Parsing");
+        cursor.expect(Keyword::Function, "async function expression")?;
+
+        let name = BindingIdentifier::new(false, true).try_parse(cursor);
+
+        cursor.expect(Punctuator::OpenParen, "async function expression")?;
+
+        let params_position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
+        let params = FormalParameters::new(false, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "async function expression")?;
+        cursor.expect(Punctuator::OpenBlock, "async function expression")?;
+
+        let body = FunctionBody::new(false, true).parse(cursor)?;
+        check_parameters(&params, &body, params_position)?;
+
+        cursor.expect(Punctuator::CloseBlock, "async function expression")?;
+
+        Ok(AsyncFunctionExpr::new(name, params, body))
+    }
+}
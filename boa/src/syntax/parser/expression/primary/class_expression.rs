@@ -0,0 +1,64 @@
+//! Class expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+//! [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+
+use crate::{
+    syntax::{
+        ast::{node::ClassExpr, Keyword, TokenKind},
+        parser::{
+            class::ClassTail, statement::BindingIdentifier, AllowAwait, AllowYield, Cursor,
+            ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+/// Class expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ClassExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassExpression {
+    /// Creates a new `ClassExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for ClassExpression {
+    type Output = ClassExpr;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassExpression", "Parsing");
+        // `extends` is a keyword, so it can't be mistaken for the name of the class.
+        let name = match cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind {
+            TokenKind::Keyword(Keyword::Extends) | TokenKind::Punctuator(_) => None,
+            _ => Some(BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?),
+        };
+
+        let tail = ClassTail::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(ClassExpr::new(name, tail))
+    }
+}
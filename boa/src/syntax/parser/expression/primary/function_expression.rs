@@ -11,7 +11,7 @@ use crate::{
     syntax::{
         ast::{node::FunctionExpr, Punctuator},
         parser::{
-            function::{FormalParameters, FunctionBody},
+            function::{check_parameters, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             Cursor, ParseError, TokenParser,
         },
@@ -39,12 +39,14 @@ impl TokenParser for FunctionExpression {
 
         cursor.expect(Punctuator::OpenParen, "function expression")?;
 
+        let params_position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
         let params = FormalParameters::new(false, false).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseParen, "function expression")?;
         cursor.expect(Punctuator::OpenBlock, "function expression")?;
 
         let body = FunctionBody::new(false, false).parse(cursor)?;
+        check_parameters(&params, &body, params_position)?;
 
         cursor.expect(Punctuator::CloseBlock, "function expression")?;
 
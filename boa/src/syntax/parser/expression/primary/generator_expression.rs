@@ -0,0 +1,57 @@
+//! Generator expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function*
+//! [spec]: https://tc39.es/ecma262/#prod-GeneratorExpression
+
+use crate::{
+    syntax::{
+        ast::{node::GeneratorExpr, Punctuator},
+        parser::{
+            function::{check_parameters, FormalParameters, FunctionBody},
+            statement::BindingIdentifier,
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+/// Generator expression parsing.
+///
+/// The `function` keyword and the `*` have already been consumed.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function*
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct GeneratorExpression;
+
+impl TokenParser for GeneratorExpression {
+    type Output = GeneratorExpr;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("GeneratorExpression", "Parsing");
+        let name = BindingIdentifier::new(true, false).try_parse(cursor);
+
+        cursor.expect(Punctuator::OpenParen, "generator expression")?;
+
+        let params_position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
+        let params = FormalParameters::new(false, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "generator expression")?;
+        cursor.expect(Punctuator::OpenBlock, "generator expression")?;
+
+        let body = FunctionBody::new(true, false).parse(cursor)?;
+        check_parameters(&params, &body, params_position)?;
+
+        cursor.expect(Punctuator::CloseBlock, "generator expression")?;
+
+        Ok(GeneratorExpr::new(name, params, body))
+    }
+}
@@ -8,14 +8,24 @@
 //! [spec]: https://tc39.es/ecma262/#prod-PrimaryExpression
 
 mod array_initializer;
+mod async_function_expression;
+mod class_expression;
 mod function_expression;
+mod generator_expression;
 mod object_initializer;
+mod template;
 #[cfg(test)]
 mod tests;
 
 use self::{
-    array_initializer::ArrayLiteral, function_expression::FunctionExpression,
-    object_initializer::ObjectLiteral,
+    array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
+    class_expression::ClassExpression, function_expression::FunctionExpression,
+    generator_expression::GeneratorExpression, object_initializer::ObjectLiteral,
+    template::TemplateLiteral,
+};
+pub(in crate::syntax::parser) use self::{
+    object_initializer::{Initializer, MethodDefinition, PropertyName},
+    template::TaggedTemplateLiteral,
 };
 use super::Expression;
 use crate::syntax::{
@@ -24,9 +34,11 @@ use crate::syntax::{
         token::NumericLiteral,
         Const, Keyword, Punctuator, TokenKind,
     },
-    parser::{AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
+    parser::{
+        statement::is_strict_reserved_word, AllowAwait, AllowYield, Cursor, ParseError,
+        ParseResult, TokenParser,
+    },
 };
-pub(in crate::syntax::parser) use object_initializer::Initializer;
 
 /// Parses a primary expression.
 ///
@@ -61,12 +73,30 @@ impl TokenParser for PrimaryExpression {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+        if tok.is_legacy_octal() && cursor.strict_mode() {
+            let message = match tok.kind {
+                TokenKind::StringLiteral(_) => {
+                    "octal escape sequences are not allowed in strict mode"
+                }
+                _ => "octal literals are not allowed in strict mode",
+            };
+            return Err(ParseError::unexpected(tok.clone(), message));
+        }
 
         match &tok.kind {
             TokenKind::Keyword(Keyword::This) => Ok(Node::This),
             // TokenKind::Keyword(Keyword::Arguments) => Ok(Node::new(NodeBase::Arguments, tok.pos)),
             TokenKind::Keyword(Keyword::Function) => {
-                FunctionExpression.parse(cursor).map(Node::from)
+                if cursor.next_if(Punctuator::Mul).is_some() {
+                    GeneratorExpression.parse(cursor).map(Node::from)
+                } else {
+                    FunctionExpression.parse(cursor).map(Node::from)
+                }
+            }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Node::from)
             }
             TokenKind::Punctuator(Punctuator::OpenParen) => {
                 let expr =
@@ -86,6 +116,22 @@ impl TokenParser for PrimaryExpression {
             }
             TokenKind::BooleanLiteral(boolean) => Ok(Const::from(*boolean).into()),
             TokenKind::NullLiteral => Ok(Const::Null.into()),
+            TokenKind::Identifier(ident)
+                if ident.as_ref() == "async"
+                    && cursor.peek(0).map(|tok| &tok.kind)
+                        == Some(&TokenKind::Keyword(Keyword::Function))
+                    && cursor.peek_expect_no_lineterminator(0).is_ok() =>
+            {
+                AsyncFunctionExpression.parse(cursor).map(Node::from)
+            }
+            TokenKind::Identifier(ident)
+                if cursor.strict_mode() && is_strict_reserved_word(ident) =>
+            {
+                Err(ParseError::unexpected(
+                    tok.clone(),
+                    "unexpected reserved word in strict mode",
+                ))
+            }
             TokenKind::Identifier(ident) => Ok(Identifier::from(ident.as_ref()).into()), // TODO: IdentifierReference
             TokenKind::StringLiteral(s) => Ok(Const::from(s.as_ref()).into()),
             TokenKind::NumericLiteral(NumericLiteral::Integer(num)) => Ok(Const::from(*num).into()),
@@ -95,6 +141,12 @@ impl TokenParser for PrimaryExpression {
             TokenKind::NumericLiteral(NumericLiteral::BigInt(num)) => {
                 Ok(Const::from(num.clone()).into())
             }
+            TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateHead(_) => {
+                cursor.back();
+                TemplateLiteral::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::RegularExpressionLiteral(body, flags) => {
                 Ok(Node::from(New::from(Call::new(
                     Identifier::from("RegExp"),
@@ -14,12 +14,12 @@ use crate::{
     syntax::{
         ast::{
             node::{self, FunctionExpr, MethodDefinitionKind, Node, Object},
-            token::{Token, TokenKind},
+            token::{NumericLiteral, TokenKind},
             Punctuator,
         },
         parser::{
             expression::AssignmentExpression,
-            function::{FormalParameters, FunctionBody},
+            function::{check_parameters, FormalParameters, FunctionBody},
             AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
@@ -126,58 +126,51 @@ impl TokenParser for PropertyDefinition {
             return Ok(node::PropertyDefinition::SpreadObject(node));
         }
 
-        let prop_name = cursor
-            .next()
-            .map(Token::to_string)
-            .ok_or(ParseError::AbruptEnd)?;
+        if cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind
+            == TokenKind::Punctuator(Punctuator::Mul)
+        {
+            return MethodDefinition::new(self.allow_yield, self.allow_await).parse(cursor);
+        }
+
+        let start = cursor.pos();
+        let prop_name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
         if cursor.next_if(Punctuator::Colon).is_some() {
             let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
             return Ok(node::PropertyDefinition::property(prop_name, val));
         }
 
-        if cursor
-            .next_if(TokenKind::Punctuator(Punctuator::OpenParen))
-            .is_some()
-            || ["get", "set"].contains(&prop_name.as_str())
-        {
-            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
-                .parse(cursor);
-        }
-
-        let pos = cursor
-            .peek(0)
-            .map(|tok| tok.span().start())
-            .ok_or(ParseError::AbruptEnd)?;
-        Err(ParseError::general("expected property definition", pos))
+        // Anything else must be a method, which is parsed again from its first token, since the
+        // name could have been a modifier.
+        cursor.seek(start);
+        MethodDefinition::new(self.allow_yield, self.allow_await).parse(cursor)
     }
 }
 
 /// Parses a method definition.
 ///
+/// This includes the `get`, `set`, `*` or `async` modifier of the method, if any.
+///
 /// More information:
 ///  - [ECMAScript specification][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
-#[derive(Debug, Clone)]
-struct MethodDefinition {
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct MethodDefinition {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    identifier: String,
 }
 
 impl MethodDefinition {
     /// Creates a new `MethodDefinition` parser.
-    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
-        I: Into<String>,
     {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            identifier: identifier.into(),
         }
     }
 }
@@ -186,53 +179,65 @@ impl TokenParser for MethodDefinition {
     type Output = node::PropertyDefinition;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
-        let (methodkind, prop_name, params) = match self.identifier.as_str() {
-            idn @ "get" | idn @ "set" => {
-                let prop_name = cursor
-                    .next()
-                    .map(Token::to_string)
-                    .ok_or(ParseError::AbruptEnd)?;
-                cursor.expect(
-                    TokenKind::Punctuator(Punctuator::OpenParen),
-                    "property method definition",
-                )?;
-                let first_param = cursor.peek(0).expect("current token disappeared").clone();
-                let params = FormalParameters::new(false, false).parse(cursor)?;
-                cursor.expect(Punctuator::CloseParen, "method definition")?;
-                if idn == "get" {
-                    if !params.is_empty() {
-                        return Err(ParseError::unexpected(
-                            first_param,
-                            "getter functions must have no arguments",
-                        ));
-                    }
-                    (MethodDefinitionKind::Get, prop_name, params)
-                } else {
-                    if params.len() != 1 {
-                        return Err(ParseError::unexpected(
-                            first_param,
-                            "setter functions must have one argument",
-                        ));
-                    }
-                    (MethodDefinitionKind::Set, prop_name, params)
-                }
+        let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        // `get`, `set` and `async` are only modifiers if they are not the name of the method
+        // itself.
+        let is_modifier = cursor.peek(1).ok_or(ParseError::AbruptEnd)?.kind
+            != TokenKind::Punctuator(Punctuator::OpenParen);
+        let methodkind = match tok.kind {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                let _ = cursor.next();
+                MethodDefinitionKind::Generator
+            }
+            TokenKind::Identifier(ref name) if is_modifier && name.as_ref() == "async" => {
+                let _ = cursor.next();
+                // There can't be a line terminator between `async` and the name of the method.
+                cursor.peek_expect_no_lineterminator(0)?;
+                MethodDefinitionKind::Async
+            }
+            TokenKind::Identifier(ref name) if is_modifier && name.as_ref() == "get" => {
+                let _ = cursor.next();
+                MethodDefinitionKind::Get
             }
-            prop_name => {
-                let params = FormalParameters::new(false, false).parse(cursor)?;
-                cursor.expect(Punctuator::CloseParen, "method definition")?;
-                (
-                    MethodDefinitionKind::Ordinary,
-                    prop_name.to_string(),
-                    params,
-                )
+            TokenKind::Identifier(ref name) if is_modifier && name.as_ref() == "set" => {
+                let _ = cursor.next();
+                MethodDefinitionKind::Set
             }
+            _ => MethodDefinitionKind::Ordinary,
         };
 
+        let prop_name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        cursor.expect(
+            TokenKind::Punctuator(Punctuator::OpenParen),
+            "property method definition",
+        )?;
+        let first_param = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.clone();
+        let params = FormalParameters::new(false, false).parse(cursor)?;
+        cursor.expect(Punctuator::CloseParen, "method definition")?;
+        match methodkind {
+            MethodDefinitionKind::Get if !params.is_empty() => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "getter functions must have no arguments",
+                ));
+            }
+            MethodDefinitionKind::Set if params.len() != 1 => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "setter functions must have one argument",
+                ));
+            }
+            _ => {}
+        }
+
         cursor.expect(
             TokenKind::Punctuator(Punctuator::OpenBlock),
             "property method definition",
         )?;
-        let body = FunctionBody::new(false, false).parse(cursor)?;
+        let is_generator = methodkind == MethodDefinitionKind::Generator;
+        let is_async = methodkind == MethodDefinitionKind::Async;
+        let body = FunctionBody::new(is_generator, is_async).parse(cursor)?;
+        check_parameters(&params, &body, first_param.span().start())?;
         cursor.expect(
             TokenKind::Punctuator(Punctuator::CloseBlock),
             "property method definition",
@@ -246,6 +251,74 @@ impl TokenParser for MethodDefinition {
     }
 }
 
+/// Parses a property name.
+///
+/// This is either a literal name, written as an identifier, a string or a number, or a computed
+/// name like `[expr]`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct PropertyName {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl PropertyName {
+    /// Creates a new `PropertyName` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for PropertyName {
+    type Output = node::PropertyName;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+        if tok.is_legacy_octal() && cursor.strict_mode() {
+            return Err(ParseError::unexpected(
+                tok.clone(),
+                "octal literals are not allowed in strict mode",
+            ));
+        }
+        let name = match tok.kind {
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                cursor.expect(Punctuator::CloseBracket, "computed property name")?;
+                return Ok(node.into());
+            }
+            TokenKind::Identifier(ref name) | TokenKind::StringLiteral(ref name) => {
+                name.clone().into()
+            }
+            TokenKind::Keyword(keyword) => keyword.as_str().into(),
+            TokenKind::NumericLiteral(NumericLiteral::Integer(num)) => {
+                num.to_string().into_boxed_str().into()
+            }
+            TokenKind::NumericLiteral(NumericLiteral::Rational(num)) => {
+                ryu_js::Buffer::new().format(num).into()
+            }
+            TokenKind::NumericLiteral(NumericLiteral::BigInt(ref num)) => {
+                num.to_string().into_boxed_str().into()
+            }
+            TokenKind::BooleanLiteral(boolean) => if boolean { "true" } else { "false" }.into(),
+            TokenKind::NullLiteral => "null".into(),
+            _ => return Err(ParseError::unexpected(tok.clone(), "property name")),
+        };
+        Ok(name)
+    }
+}
+
 /// Initializer parsing.
 ///
 /// More information:
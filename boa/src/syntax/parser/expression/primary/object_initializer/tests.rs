@@ -1,8 +1,8 @@
 use crate::syntax::{
     ast::{
         node::{
-            ConstDecl, ConstDeclList, FormalParameter, FunctionExpr, MethodDefinitionKind, Object,
-            PropertyDefinition,
+            ConstDecl, ConstDeclList, FormalParameter, FunctionExpr, GetConstField, Identifier,
+            MethodDefinitionKind, Node, Object, PropertyDefinition,
         },
         Const,
     },
@@ -130,3 +130,55 @@ fn check_object_setter() {
         ],
     );
 }
+
+#[test]
+fn check_object_literal_names() {
+    let object_properties = vec![
+        PropertyDefinition::property("a b", Const::from(1)),
+        PropertyDefinition::property("1", Const::from(2)),
+        PropertyDefinition::property("0.5", Const::from(3)),
+        PropertyDefinition::property("null", Const::from(4)),
+    ];
+
+    check_parser(
+        "const x = {
+            'a b': 1,
+            1: 2,
+            0.5: 3,
+            null: 4
+        };
+        ",
+        vec![
+            ConstDeclList::from(vec![ConstDecl::new("x", Object::from(object_properties))]).into(),
+        ],
+    );
+}
+
+#[test]
+fn check_object_computed_names() {
+    let object_properties = vec![
+        PropertyDefinition::property(Node::from(Identifier::from("key")), Const::from(1)),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            Node::from(GetConstField::new(Identifier::from("Symbol"), "iterator")),
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Get,
+            Node::from(Identifier::from("key")),
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+    ];
+
+    check_parser(
+        "const x = {
+            [key]: 1,
+            [Symbol.iterator]() {},
+            get [key]() {}
+        };
+        ",
+        vec![
+            ConstDeclList::from(vec![ConstDecl::new("x", Object::from(object_properties))]).into(),
+        ],
+    );
+}
@@ -0,0 +1,161 @@
+//! Template literal parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+//! [spec]: https://tc39.es/ecma262/#sec-template-literals
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, TaggedTemplate, TemplateElement, TemplateLit},
+            token::TemplateString,
+            Position, TokenKind,
+        },
+        parser::{expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
+    },
+    BoaProfiler,
+};
+
+/// Parses a template literal.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+/// [spec]: https://tc39.es/ecma262/#prod-TemplateLiteral
+#[derive(Debug, Clone, Copy)]
+pub(super) struct TemplateLiteral {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl TemplateLiteral {
+    /// Creates a new `TemplateLiteral` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for TemplateLiteral {
+    type Output = TemplateLit;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Parsing");
+        let (strings, exprs) = parse_template(cursor, self.allow_yield, self.allow_await)?;
+
+        let mut elements = Vec::with_capacity(strings.len() + exprs.len());
+        let mut exprs = exprs.into_iter();
+        for (position, string) in strings {
+            // Invalid escape sequences are only allowed in tagged templates.
+            let cooked = string.cooked().ok_or_else(|| {
+                ParseError::general("invalid escape sequence in template literal", position)
+            })?;
+            if !cooked.is_empty() {
+                elements.push(TemplateElement::String(cooked.into()));
+            }
+            if let Some(expr) = exprs.next() {
+                elements.push(TemplateElement::Expr(expr));
+            }
+        }
+
+        Ok(TemplateLit::new(elements))
+    }
+}
+
+/// Parses a tagged template, the template literal following its tag function.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#Tagged_templates
+/// [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+#[derive(Debug)]
+pub(in crate::syntax::parser) struct TaggedTemplateLiteral {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    tag: Node,
+}
+
+impl TaggedTemplateLiteral {
+    /// Creates a new `TaggedTemplateLiteral` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A, tag: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            tag,
+        }
+    }
+}
+
+impl TokenParser for TaggedTemplateLiteral {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplateLiteral", "Parsing");
+        let (strings, exprs) = parse_template(cursor, self.allow_yield, self.allow_await)?;
+
+        let raws: Vec<Box<str>> = strings.iter().map(|(_, s)| s.raw().into()).collect();
+        let cookeds: Vec<Option<Box<str>>> = strings
+            .iter()
+            .map(|(_, s)| s.cooked().map(Box::from))
+            .collect();
+
+        Ok(TaggedTemplate::new(self.tag, raws, cookeds, exprs).into())
+    }
+}
+
+/// The strings of a template, along with their position, and the expressions between them.
+type TemplateParts<'a> = (Vec<(Position, &'a TemplateString)>, Vec<Node>);
+
+/// Parses the strings and substitutions of a template, starting at its first token.
+fn parse_template<'a>(
+    cursor: &mut Cursor<'a>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+) -> Result<TemplateParts<'a>, ParseError> {
+    let mut strings = Vec::new();
+    let mut exprs = Vec::new();
+
+    let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+    match tok.kind {
+        TokenKind::TemplateNoSubstitution(ref string) => {
+            strings.push((tok.span().start(), string));
+            return Ok((strings, exprs));
+        }
+        TokenKind::TemplateHead(ref string) => strings.push((tok.span().start(), string)),
+        _ => return Err(ParseError::unexpected(tok.clone(), "template literal")),
+    }
+
+    loop {
+        exprs.push(Expression::new(true, allow_yield, allow_await).parse(cursor)?);
+
+        let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+        match tok.kind {
+            TokenKind::TemplateMiddle(ref string) => strings.push((tok.span().start(), string)),
+            TokenKind::TemplateTail(ref string) => {
+                strings.push((tok.span().start(), string));
+                return Ok((strings, exprs));
+            }
+            _ => return Err(ParseError::unexpected(tok.clone(), "template literal")),
+        }
+    }
+}
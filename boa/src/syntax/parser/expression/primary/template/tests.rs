@@ -0,0 +1,113 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            field::GetConstField, BinOp, Call, Identifier, Node, TaggedTemplate, TemplateElement,
+            TemplateLit,
+        },
+        op::NumOp,
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks a template without substitutions.
+#[test]
+fn check_no_substitution() {
+    check_parser(
+        "`hello world`",
+        vec![TemplateLit::new(vec![TemplateElement::String("hello world".into())]).into()],
+    );
+}
+
+/// Checks an empty template.
+#[test]
+fn check_empty() {
+    check_parser("``", vec![TemplateLit::new(vec![]).into()]);
+}
+
+/// Checks a template with substitutions.
+#[test]
+fn check_substitutions() {
+    check_parser(
+        "`a${b}c${1 + 2}`",
+        vec![TemplateLit::new(vec![
+            TemplateElement::String("a".into()),
+            TemplateElement::Expr(Identifier::from("b").into()),
+            TemplateElement::String("c".into()),
+            TemplateElement::Expr(BinOp::new(NumOp::Add, Const::from(1), Const::from(2)).into()),
+        ])
+        .into()],
+    );
+}
+
+/// Checks a template nested in the substitution of another template.
+#[test]
+fn check_nested() {
+    check_parser(
+        "`a${`b${c}`}`",
+        vec![TemplateLit::new(vec![
+            TemplateElement::String("a".into()),
+            TemplateElement::Expr(
+                TemplateLit::new(vec![
+                    TemplateElement::String("b".into()),
+                    TemplateElement::Expr(Identifier::from("c").into()),
+                ])
+                .into(),
+            ),
+        ])
+        .into()],
+    );
+}
+
+/// Checks tagged templates, which keep both raw and cooked strings.
+#[test]
+fn check_tagged() {
+    check_parser(
+        r"tag`a\n${b}\unicode`",
+        vec![TaggedTemplate::new(
+            Identifier::from("tag"),
+            vec![Box::from(r"a\n"), Box::from(r"\unicode")],
+            vec![Some(Box::from("a\n")), None],
+            vec![Identifier::from("b").into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that tagged templates bind like property accesses.
+#[test]
+fn check_tagged_member() {
+    check_parser(
+        "a.b`c`.d",
+        vec![GetConstField::new(
+            TaggedTemplate::new(
+                GetConstField::new(Identifier::from("a"), "b"),
+                vec![Box::from("c")],
+                vec![Some(Box::from("c"))],
+                vec![],
+            ),
+            "d",
+        )
+        .into()],
+    );
+    check_parser(
+        "f()`x`",
+        vec![TaggedTemplate::new(
+            Call::new(Identifier::from("f"), vec![]),
+            vec![Box::from("x")],
+            vec![Some(Box::from("x"))],
+            Vec::<Node>::new(),
+        )
+        .into()],
+    );
+}
+
+/// Checks invalid templates.
+#[test]
+fn check_invalid_templates() {
+    // Invalid escape sequences are only allowed in tagged templates.
+    check_invalid(r"`\unicode`");
+    check_invalid(r"`\01`");
+    // Empty substitution.
+    check_invalid("`a${}b`");
+}
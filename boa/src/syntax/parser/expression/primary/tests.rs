@@ -1,4 +1,10 @@
-use crate::syntax::{ast::Const, parser::tests::check_parser};
+use crate::syntax::{
+    ast::{
+        node::{AsyncFunctionExpr, Await, FormalParameter, GeneratorExpr, Identifier, Node, Yield},
+        Const,
+    },
+    parser::tests::check_parser,
+};
 
 #[test]
 fn check_string() {
@@ -8,3 +14,39 @@ fn check_string() {
     // Check non-empty string
     check_parser("\"hello\"", vec![Const::from("hello").into()]);
 }
+
+#[test]
+fn check_generator_expression() {
+    check_parser(
+        "(function* () { yield; })",
+        vec![GeneratorExpr::new(
+            None,
+            vec![],
+            vec![Yield::new::<Node, Option<Node>>(None, false).into()],
+        )
+        .into()],
+    );
+
+    check_parser(
+        "(function* gen(a) { yield a; })",
+        vec![GeneratorExpr::new(
+            Some(Box::from("gen")),
+            vec![FormalParameter::new("a", None, false)],
+            vec![Yield::new(Identifier::from("a"), false).into()],
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_async_function_expression() {
+    check_parser(
+        "(async function (a) { await a; })",
+        vec![AsyncFunctionExpr::new(
+            None,
+            vec![FormalParameter::new("a", None, false)],
+            vec![Await::new(Identifier::from("a")).into()],
+        )
+        .into()],
+    );
+}
@@ -1,10 +1,10 @@
 use crate::syntax::{
-    ast::op::{AssignOp, BitOp, CompOp, NumOp},
+    ast::op::{AssignOp, BitOp, CompOp, LogOp, NumOp},
     ast::{
-        node::{BinOp, Identifier},
+        node::{BinOp, Identifier, Optional, OptionalOperation, OptionalOperationKind},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks numeric operations
@@ -178,6 +178,33 @@ fn check_assign_operations() {
         "a >>= b",
         vec![BinOp::new(AssignOp::Shr, Identifier::from("a"), Identifier::from("b")).into()],
     );
+    check_parser(
+        "a &&= b",
+        vec![BinOp::new(
+            AssignOp::BoolAnd,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ||= b",
+        vec![BinOp::new(
+            AssignOp::BoolOr,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ??= b",
+        vec![BinOp::new(
+            AssignOp::Coalesce,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
     check_parser(
         "a %= 10 / 2",
         vec![BinOp::new(
@@ -231,4 +258,104 @@ fn check_relational_operations() {
         "p in o",
         vec![BinOp::new(CompOp::In, Identifier::from("p"), Identifier::from("o")).into()],
     );
+    check_parser(
+        "a instanceof b",
+        vec![BinOp::new(
+            CompOp::InstanceOf,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+}
+
+/// Checks the nullish coalescing operator.
+#[test]
+fn check_coalesce() {
+    check_parser(
+        "a ?? b ?? c",
+        vec![BinOp::new(
+            LogOp::Coalesce,
+            BinOp::new(
+                LogOp::Coalesce,
+                Identifier::from("a"),
+                Identifier::from("b"),
+            ),
+            Identifier::from("c"),
+        )
+        .into()],
+    );
+    check_parser(
+        "(a || b) ?? c",
+        vec![BinOp::new(
+            LogOp::Coalesce,
+            BinOp::new(LogOp::Or, Identifier::from("a"), Identifier::from("b")),
+            Identifier::from("c"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a && b || c",
+        vec![BinOp::new(
+            LogOp::Or,
+            BinOp::new(LogOp::And, Identifier::from("a"), Identifier::from("b")),
+            Identifier::from("c"),
+        )
+        .into()],
+    );
+
+    check_invalid("a ?? b || c");
+    check_invalid("a || b ?? c");
+    check_invalid("a ?? b && c");
+    check_invalid("a && b ?? c");
+}
+
+/// Checks optional chains.
+#[test]
+fn check_optional_chain() {
+    check_parser(
+        "a?.b.c",
+        vec![Optional::new(
+            Identifier::from("a"),
+            vec![
+                OptionalOperation::new(OptionalOperationKind::GetConstField("b".into()), true),
+                OptionalOperation::new(OptionalOperationKind::GetConstField("c".into()), false),
+            ],
+        )
+        .into()],
+    );
+    check_parser(
+        "a?.[b]?.(c)",
+        vec![Optional::new(
+            Identifier::from("a"),
+            vec![
+                OptionalOperation::new(
+                    OptionalOperationKind::GetField(Box::new(Identifier::from("b").into())),
+                    true,
+                ),
+                OptionalOperation::new(
+                    OptionalOperationKind::Call(vec![Identifier::from("c").into()].into()),
+                    true,
+                ),
+            ],
+        )
+        .into()],
+    );
+
+    check_invalid("a?.`template`");
+    check_invalid("a?.b`template`");
+}
+
+/// Checks that optional chains can't be assigned to or updated.
+#[test]
+fn check_optional_chain_assignment() {
+    check_invalid("a?.b = 1");
+    check_invalid("a?.[b] += 1");
+    check_invalid("a?.b ??= 4");
+    check_invalid("a?.b++");
+    check_invalid("--a?.b");
+    check_invalid("[a?.b] = [3]");
+    check_invalid("({ c: a?.b } = {})");
+    check_invalid("for (a?.b of []);");
+    check_invalid("for (a?.b in {});");
 }
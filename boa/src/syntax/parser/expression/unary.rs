@@ -54,7 +54,15 @@ impl TokenParser for UnaryExpression {
         let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
         match tok.kind {
             TokenKind::Keyword(Keyword::Delete) => {
-                Ok(node::UnaryOp::new(UnaryOp::Delete, self.parse(cursor)?).into())
+                let target = self.parse(cursor)?;
+                // Unqualified identifiers can't be deleted in strict mode code.
+                if cursor.strict_mode() && matches!(target, Node::Identifier(_)) {
+                    return Err(ParseError::general(
+                        "delete of an unqualified identifier in strict mode",
+                        tok.span().start(),
+                    ));
+                }
+                Ok(node::UnaryOp::new(UnaryOp::Delete, target).into())
             }
             TokenKind::Keyword(Keyword::Void) => {
                 Ok(node::UnaryOp::new(UnaryOp::Void, self.parse(cursor)?).into())
@@ -74,6 +82,10 @@ impl TokenParser for UnaryExpression {
             TokenKind::Punctuator(Punctuator::Not) => {
                 Ok(node::UnaryOp::new(UnaryOp::Not, self.parse(cursor)?).into())
             }
+            // https://tc39.es/ecma262/#prod-AwaitExpression
+            TokenKind::Keyword(Keyword::Await) if self.allow_await.0 => {
+                Ok(node::Await::new(self.parse(cursor)?).into())
+            }
             _ => {
                 cursor.back();
                 UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor)
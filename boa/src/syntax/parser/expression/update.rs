@@ -5,7 +5,7 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-update-expressions
 
-use super::left_hand_side::LeftHandSideExpression;
+use super::{check_assignment_target, left_hand_side::LeftHandSideExpression};
 use crate::syntax::{
     ast::{node, op::UnaryOp, Node, Punctuator, TokenKind},
     parser::{AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
@@ -42,40 +42,31 @@ impl TokenParser for UpdateExpression {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
-        match tok.kind {
-            TokenKind::Punctuator(Punctuator::Inc) => {
-                cursor.next().expect("token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::IncrementPre,
-                    LeftHandSideExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor)?,
-                )
-                .into());
-            }
-            TokenKind::Punctuator(Punctuator::Dec) => {
-                cursor.next().expect("token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::DecrementPre,
-                    LeftHandSideExpression::new(self.allow_yield, self.allow_await)
-                        .parse(cursor)?,
-                )
-                .into());
-            }
-            _ => {}
+        let position = tok.span().start();
+        let op = match tok.kind {
+            TokenKind::Punctuator(Punctuator::Inc) => Some(UnaryOp::IncrementPre),
+            TokenKind::Punctuator(Punctuator::Dec) => Some(UnaryOp::DecrementPre),
+            _ => None,
+        };
+        if let Some(op) = op {
+            cursor.next().expect("token disappeared");
+            let target =
+                LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            check_assignment_target(&target, position)?;
+            return Ok(node::UnaryOp::new(op, target).into());
         }
 
         let lhs = LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
         if let Some(tok) = cursor.peek(0) {
-            match tok.kind {
-                TokenKind::Punctuator(Punctuator::Inc) => {
-                    cursor.next().expect("token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::IncrementPost, lhs).into());
-                }
-                TokenKind::Punctuator(Punctuator::Dec) => {
-                    cursor.next().expect("token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::DecrementPost, lhs).into());
-                }
-                _ => {}
+            let op = match tok.kind {
+                TokenKind::Punctuator(Punctuator::Inc) => Some(UnaryOp::IncrementPost),
+                TokenKind::Punctuator(Punctuator::Dec) => Some(UnaryOp::DecrementPost),
+                _ => None,
+            };
+            if let Some(op) = op {
+                check_assignment_target(&lhs, position)?;
+                cursor.next().expect("token disappeared");
+                return Ok(node::UnaryOp::new(op, lhs).into());
             }
         }
 
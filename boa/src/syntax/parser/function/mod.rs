@@ -13,11 +13,12 @@ mod tests;
 use crate::syntax::{
     ast::{
         node::{self},
-        Punctuator, TokenKind,
+        Const, Position, Punctuator, TokenKind,
     },
     parser::{
         expression::Initializer,
-        statement::{BindingIdentifier, StatementList},
+        pattern::BindingTarget,
+        statement::{is_strict_reserved_word, StatementList},
         AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
     },
 };
@@ -140,8 +141,7 @@ impl TokenParser for BindingRestElement {
         // FIXME: we are reading the spread operator before the rest element.
         // cursor.expect(Punctuator::Spread, "rest parameter")?;
 
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        // TODO: BindingPattern
+        let param = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         Ok(Self::Output::new(param, None, true))
     }
@@ -179,9 +179,7 @@ impl TokenParser for FormalParameter {
     type Output = node::FormalParameter;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
-        // TODO: BindingPattern
-
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let param = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = Initializer::new(true, self.allow_yield, self.allow_await).try_parse(cursor);
 
@@ -227,12 +225,137 @@ impl TokenParser for FunctionStatementList {
     type Output = node::StatementList;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
-        if let Some(tk) = cursor.peek(0) {
-            if tk.kind == Punctuator::CloseBlock.into() {
-                return Ok(Vec::new().into());
+        // The body is strict mode code if the function is in strict mode code, or if the body
+        // starts with a `"use strict"` directive.
+        let strict_mode = cursor.strict_mode();
+        if cursor.peek_use_strict_directive()? {
+            cursor.set_strict_mode(true);
+        }
+
+        let body = match cursor.peek(0) {
+            Some(tk) if tk.kind == Punctuator::CloseBlock.into() => {
+                Ok(node::StatementList::new(Vec::new(), cursor.strict_mode()))
             }
+            _ => cursor.with_function_body(|cursor| {
+                StatementList::new(self.allow_yield, self.allow_await, true, true).parse(cursor)
+            }),
+        };
+
+        cursor.set_strict_mode(strict_mode);
+        body
+    }
+}
+
+/// Parses the parameters and the body of a function created at runtime by the `Function`
+/// constructor, written as `( FormalParameters ) { FunctionBody }` with nothing after it.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createdynamicfunction
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct DynamicFunction;
+
+impl TokenParser for DynamicFunction {
+    type Output = (Box<[node::FormalParameter]>, node::StatementList);
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        cursor.expect(Punctuator::OpenParen, "function parameters")?;
+
+        let params_position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
+        let params = FormalParameters::new(false, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "function parameters")?;
+        cursor.expect(Punctuator::OpenBlock, "function body")?;
+
+        let body = FunctionBody::new(false, false).parse(cursor)?;
+        check_parameters(&params, &body, params_position)?;
+
+        cursor.expect(Punctuator::CloseBlock, "function body")?;
+        if let Some(token) = cursor.peek(0) {
+            return Err(ParseError::unexpected(
+                token.clone(),
+                "unexpected token after the function body",
+            ));
         }
 
-        StatementList::new(self.allow_yield, self.allow_await, true, true).parse(cursor)
+        Ok((params, body))
+    }
+}
+
+/// Checks the early errors of the parameters of a function that depend on its body being strict
+/// mode code.
+///
+/// In strict mode code, parameters can't be named `eval`, `arguments` or a reserved word, and
+/// they can't have duplicate names. A `"use strict"` directive in the body also applies to the
+/// parameters, so this is checked once the body has been parsed. Such a directive is not allowed
+/// at all if the parameters are not simple, with default values, a rest parameter or patterns.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_parameters(
+    params: &[node::FormalParameter],
+    body: &node::StatementList,
+    position: Position,
+) -> Result<(), ParseError> {
+    if !body.is_strict() {
+        return Ok(());
+    }
+
+    let is_simple = params.iter().all(|param| {
+        param.init().is_none()
+            && !param.is_rest_param()
+            && matches!(param.target(), node::BindingTarget::Identifier(_))
+    });
+    if !is_simple && has_use_strict_directive(body) {
+        return Err(ParseError::general(
+            "\"use strict\" not allowed in function with non-simple parameters",
+            position,
+        ));
+    }
+
+    let mut names = Vec::new();
+    for param in params {
+        for name in param.target().bound_names() {
+            if name == "eval" || name == "arguments" {
+                return Err(ParseError::general(
+                    "parameters can't be named 'eval' or 'arguments' in strict mode",
+                    position,
+                ));
+            }
+            if is_strict_reserved_word(name) {
+                return Err(ParseError::general(
+                    "parameters can't be named with a reserved word in strict mode",
+                    position,
+                ));
+            }
+            if names.contains(&name) {
+                return Err(ParseError::general(
+                    "duplicate parameter names are not allowed in strict mode",
+                    position,
+                ));
+            }
+            names.push(name);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if the directive prologue of a function body contains a `"use strict"` directive.
+///
+/// The directives are the string literal expression statements at the start of the body.
+fn has_use_strict_directive(body: &node::StatementList) -> bool {
+    for statement in body.statements() {
+        match statement {
+            node::Node::Const(Const::String(string)) if string.as_ref() == "use strict" => {
+                return true
+            }
+            node::Node::Const(Const::String(_)) => {}
+            _ => return false,
+        }
     }
+    false
 }
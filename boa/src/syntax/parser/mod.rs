@@ -1,15 +1,21 @@
 //! Boa parser implementation.
 
+mod class;
 mod cursor;
 pub mod error;
 mod expression;
 mod function;
+mod module;
+mod pattern;
 mod statement;
 #[cfg(test)]
 mod tests;
 
 use self::error::{ParseError, ParseResult};
-use crate::syntax::ast::{node::StatementList, Token};
+use crate::syntax::ast::{
+    node::{FormalParameter, Module, StatementList},
+    Token,
+};
 use cursor::Cursor;
 
 /// Trait implemented by parsers.
@@ -106,6 +112,25 @@ impl<'a> Parser<'a> {
     pub fn parse_all(&mut self) -> Result<StatementList, ParseError> {
         Script.parse(&mut self.cursor)
     }
+
+    /// Parse the token array as a module, which can contain `import` and `export` declarations
+    pub fn parse_module(&mut self) -> Result<Module, ParseError> {
+        module::Module.parse(&mut self.cursor)
+    }
+
+    /// Parse the token array as the parameters and body of a function created at runtime, like
+    /// `(a, b) { return a + b; }`
+    pub fn parse_function(
+        &mut self,
+    ) -> Result<(Box<[FormalParameter]>, StatementList), ParseError> {
+        function::DynamicFunction.parse(&mut self.cursor)
+    }
+
+    /// Sets whether the tokens are parsed as strict mode code, like the source of a direct
+    /// `eval()` call in strict mode code.
+    pub fn set_strict_mode(&mut self, strict_mode: bool) {
+        self.cursor.set_strict_mode(strict_mode);
+    }
 }
 
 /// Parses a full script.
@@ -142,6 +167,9 @@ impl TokenParser for ScriptBody {
     type Output = StatementList;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
-        self::statement::StatementList::new(false, false, false, false).parse(cursor)
+        if cursor.peek_use_strict_directive()? {
+            cursor.set_strict_mode(true);
+        }
+        statement::StatementList::new(false, false, false, false).parse(cursor)
     }
 }
@@ -0,0 +1,423 @@
+//! Module parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    expression::AssignmentExpression,
+    statement::{
+        BindingIdentifier, ClassDeclaration, HoistableDeclaration, StatementListItem,
+        VariableStatement,
+    },
+    Cursor, ParseError, TokenParser,
+};
+use crate::{
+    syntax::ast::{
+        node::{
+            self, module::declaration_names, ExportDecl, ExportSpecifier, ImportDecl,
+            ImportSpecifier, Node,
+        },
+        Keyword, Position, Punctuator, TokenKind,
+    },
+    BoaProfiler,
+};
+
+/// Parses a full module.
+///
+/// Module code is always strict mode code.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Module;
+
+impl TokenParser for Module {
+    type Output = node::Module;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("Module", "Parsing");
+        cursor.set_strict_mode(true);
+
+        let mut items = Vec::new();
+        let mut exported_names = Vec::new();
+        let mut declared_names = DeclaredNames::default();
+        while let Some(tok) = cursor.peek(0) {
+            let item = match tok.kind {
+                TokenKind::Keyword(Keyword::Import) => ImportDeclaration.parse(cursor)?.into(),
+                TokenKind::Keyword(Keyword::Export) => {
+                    let export = ExportDeclaration.parse(cursor)?;
+                    for name in export.exported_names() {
+                        if exported_names.contains(&name.to_owned()) {
+                            return Err(ParseError::general(
+                                "duplicate export name",
+                                tok.span().start(),
+                            ));
+                        }
+                        exported_names.push(name.to_owned());
+                    }
+                    export.into()
+                }
+                _ => StatementListItem::new(false, false, false).parse(cursor)?,
+            };
+            declared_names.declare(&item, tok.span().start())?;
+            items.push(item);
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon).is_some() {}
+        }
+
+        items.sort_by(Node::hoistable_order);
+
+        Ok(node::Module::new(node::StatementList::new(items, true)))
+    }
+}
+
+/// The names declared by the top level declarations of a module, used to report early errors.
+///
+/// The imported bindings and the top level functions of a module are lexically declared, like
+/// the `let`, `const` and `class` declarations. A lexically declared name can't be declared
+/// again, neither lexically nor with `var`. Only the `var` declarations at the top level of the
+/// module are checked.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-module-semantics-static-semantics-early-errors
+#[derive(Debug, Default)]
+struct DeclaredNames {
+    lexical: Vec<Box<str>>,
+    var: Vec<Box<str>>,
+}
+
+impl DeclaredNames {
+    /// Adds the names declared by a top level item of a module, returning an error if one of them
+    /// clashes with a name that is already declared.
+    fn declare(&mut self, item: &Node, position: Position) -> Result<(), ParseError> {
+        let (lexical, var) = match item {
+            Node::ImportDecl(ref import) => (
+                import
+                    .specifiers()
+                    .iter()
+                    .map(ImportSpecifier::local)
+                    .collect(),
+                Vec::new(),
+            ),
+            Node::ExportDecl(ref export) => match export.declaration() {
+                Some(Node::VarDeclList(_)) => (Vec::new(), export.bound_names()),
+                _ => (export.bound_names(), Vec::new()),
+            },
+            Node::VarDeclList(_) => (Vec::new(), declaration_names(item)),
+            _ => (declaration_names(item), Vec::new()),
+        };
+
+        for name in lexical {
+            if self
+                .lexical
+                .iter()
+                .chain(&self.var)
+                .any(|declared| **declared == *name)
+            {
+                return Err(ParseError::general(
+                    "lexically declared name has already been declared",
+                    position,
+                ));
+            }
+            self.lexical.push(name.into());
+        }
+        for name in var {
+            if self.lexical.iter().any(|declared| **declared == *name) {
+                return Err(ParseError::general(
+                    "var declared name has already been lexically declared",
+                    position,
+                ));
+            }
+            self.var.push(name.into());
+        }
+        Ok(())
+    }
+}
+
+/// Parses an `import` declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ImportDeclaration;
+
+impl TokenParser for ImportDeclaration {
+    type Output = ImportDecl;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ImportDeclaration", "Parsing");
+        cursor.expect(Keyword::Import, "import declaration")?;
+
+        // `import "module";` only evaluates the module.
+        if let Some(TokenKind::StringLiteral(_)) = cursor.peek(0).map(|tok| &tok.kind) {
+            let module = ModuleSpecifier.parse(cursor)?;
+            cursor.expect_semicolon(false, "import declaration")?;
+            return Ok(ImportDecl::new(Vec::new(), module));
+        }
+
+        let mut specifiers = Vec::new();
+        if let Some(TokenKind::Identifier(_)) = cursor.peek(0).map(|tok| &tok.kind) {
+            let local = BindingIdentifier::new(false, false).parse(cursor)?;
+            specifiers.push(ImportSpecifier::Default(local));
+            if cursor.next_if(Punctuator::Comma).is_none() {
+                return finish_import(cursor, specifiers);
+            }
+        }
+
+        let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+        match tok.kind {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.expect(TokenKind::identifier("as"), "import declaration")?;
+                let local = BindingIdentifier::new(false, false).parse(cursor)?;
+                specifiers.push(ImportSpecifier::Namespace(local));
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => loop {
+                if cursor.next_if(Punctuator::CloseBlock).is_some() {
+                    break;
+                }
+                let name_pos = cursor.pos();
+                let name_tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+                let imported = IdentifierName.parse(cursor)?;
+                let local = if cursor.next_if(TokenKind::identifier("as")).is_some() {
+                    BindingIdentifier::new(false, false).parse(cursor)?
+                } else if let TokenKind::Identifier(_) = name_tok.kind {
+                    // Checks the name as a binding, it could be `eval` or a reserved word.
+                    cursor.seek(name_pos);
+                    BindingIdentifier::new(false, false).parse(cursor)?
+                } else {
+                    return Err(ParseError::unexpected(
+                        name_tok.clone(),
+                        "keywords can only be imported with a local name",
+                    ));
+                };
+                specifiers.push(ImportSpecifier::Named { imported, local });
+                if cursor.next_if(Punctuator::Comma).is_none() {
+                    cursor.expect(Punctuator::CloseBlock, "import declaration")?;
+                    break;
+                }
+            },
+            _ => {
+                return Err(ParseError::expected(
+                    vec![
+                        TokenKind::Punctuator(Punctuator::Mul),
+                        TokenKind::Punctuator(Punctuator::OpenBlock),
+                    ],
+                    tok.clone(),
+                    "import declaration",
+                ))
+            }
+        }
+
+        finish_import(cursor, specifiers)
+    }
+}
+
+/// Parses the `from` clause at the end of an `import` declaration.
+fn finish_import(
+    cursor: &mut Cursor<'_>,
+    specifiers: Vec<ImportSpecifier>,
+) -> Result<ImportDecl, ParseError> {
+    cursor.expect(TokenKind::identifier("from"), "import declaration")?;
+    let module = ModuleSpecifier.parse(cursor)?;
+    cursor.expect_semicolon(false, "import declaration")?;
+
+    Ok(ImportDecl::new(specifiers, module))
+}
+
+/// Parses an `export` declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDeclaration;
+
+impl TokenParser for ExportDeclaration {
+    type Output = ExportDecl;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ExportDeclaration", "Parsing");
+        cursor.expect(Keyword::Export, "export declaration")?;
+
+        let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        match tok.kind {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next();
+                let namespace = if cursor.next_if(TokenKind::identifier("as")).is_some() {
+                    Some(IdentifierName.parse(cursor)?)
+                } else {
+                    None
+                };
+                cursor.expect(TokenKind::identifier("from"), "export declaration")?;
+                let module = ModuleSpecifier.parse(cursor)?;
+                cursor.expect_semicolon(false, "export declaration")?;
+
+                Ok(ExportDecl::All(namespace, module))
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                cursor.next();
+                let mut specifiers = Vec::new();
+                let mut keyword = None;
+                loop {
+                    if cursor.next_if(Punctuator::CloseBlock).is_some() {
+                        break;
+                    }
+                    let name_tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+                    if let TokenKind::Keyword(_) = name_tok.kind {
+                        keyword = keyword.or(Some(name_tok));
+                    }
+                    let local = IdentifierName.parse(cursor)?;
+                    let exported = if cursor.next_if(TokenKind::identifier("as")).is_some() {
+                        IdentifierName.parse(cursor)?
+                    } else {
+                        local.clone()
+                    };
+                    specifiers.push(ExportSpecifier::new(local, exported));
+                    if cursor.next_if(Punctuator::Comma).is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "export declaration")?;
+                        break;
+                    }
+                }
+
+                let module = if cursor.next_if(TokenKind::identifier("from")).is_some() {
+                    Some(ModuleSpecifier.parse(cursor)?)
+                } else if let Some(keyword) = keyword {
+                    // Only the exports of another module can be named with a keyword.
+                    return Err(ParseError::unexpected(
+                        keyword.clone(),
+                        "keywords can only be exported from another module",
+                    ));
+                } else {
+                    None
+                };
+                cursor.expect_semicolon(false, "export declaration")?;
+
+                Ok(ExportDecl::Named(specifiers.into(), module))
+            }
+            TokenKind::Keyword(Keyword::Default) => {
+                cursor.next();
+                let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+                let node = match tok.kind {
+                    TokenKind::Keyword(Keyword::Function) => {
+                        HoistableDeclaration::new(false, false, true).parse(cursor)?
+                    }
+                    TokenKind::Identifier(ref name)
+                        if name.as_ref() == "async"
+                            && cursor.peek(1).map(|tok| &tok.kind)
+                                == Some(&TokenKind::Keyword(Keyword::Function))
+                            && cursor.peek_expect_no_lineterminator(1).is_ok() =>
+                    {
+                        HoistableDeclaration::new(false, false, true).parse(cursor)?
+                    }
+                    TokenKind::Keyword(Keyword::Class) => ClassDeclaration::new(false, false, true)
+                        .parse(cursor)?
+                        .into(),
+                    _ => {
+                        let expr = AssignmentExpression::new(true, false, false).parse(cursor)?;
+                        cursor.expect_semicolon(false, "export declaration")?;
+                        expr
+                    }
+                };
+
+                Ok(ExportDecl::Default(Box::new(node)))
+            }
+            TokenKind::Keyword(Keyword::Var) => {
+                let decl = VariableStatement::new(false, false).parse(cursor)?;
+                Ok(ExportDecl::Declaration(Box::new(decl.into())))
+            }
+            TokenKind::Keyword(Keyword::Function)
+            | TokenKind::Keyword(Keyword::Class)
+            | TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Identifier(_) => {
+                let decl = StatementListItem::new(false, false, false).parse(cursor)?;
+                match decl {
+                    Node::FunctionDecl(_)
+                    | Node::GeneratorDecl(_)
+                    | Node::AsyncFunctionDecl(_)
+                    | Node::ClassDecl(_)
+                    | Node::LetDeclList(_)
+                    | Node::ConstDeclList(_) => Ok(ExportDecl::Declaration(Box::new(decl))),
+                    _ => Err(ParseError::general(
+                        "expected a declaration after export",
+                        tok.span().start(),
+                    )),
+                }
+            }
+            _ => Err(ParseError::unexpected(tok.clone(), "export declaration")),
+        }
+    }
+}
+
+/// Parses an identifier name, which can be any identifier, including reserved words.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierName
+#[derive(Debug, Clone, Copy)]
+struct IdentifierName;
+
+impl TokenParser for IdentifierName {
+    type Output = Box<str>;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+        match tok.kind {
+            TokenKind::Identifier(ref name) => Ok(name.clone()),
+            TokenKind::Keyword(keyword) => Ok(keyword.as_str().into()),
+            TokenKind::BooleanLiteral(true) => Ok("true".into()),
+            TokenKind::BooleanLiteral(false) => Ok("false".into()),
+            TokenKind::NullLiteral => Ok("null".into()),
+            _ => Err(ParseError::expected(
+                vec![TokenKind::identifier("identifier")],
+                tok.clone(),
+                "identifier name",
+            )),
+        }
+    }
+}
+
+/// Parses the specifier of a module, which is a string literal.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleSpecifier
+#[derive(Debug, Clone, Copy)]
+struct ModuleSpecifier;
+
+impl TokenParser for ModuleSpecifier {
+    type Output = Box<str>;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+        match tok.kind {
+            TokenKind::StringLiteral(ref module) => Ok(module.clone()),
+            _ => Err(ParseError::expected(
+                vec![TokenKind::string_literal("module specifier")],
+                tok.clone(),
+                "module specifier",
+            )),
+        }
+    }
+}
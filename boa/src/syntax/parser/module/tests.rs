@@ -0,0 +1,165 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            ExportDecl, ExportSpecifier, FunctionDecl, Identifier, ImportDecl, ImportSpecifier,
+            LetDecl, LetDeclList, Module, Node, StatementList, VarDecl, VarDeclList,
+        },
+        Const,
+    },
+    lexer::Lexer,
+    parser::{ParseError, Parser},
+};
+
+/// Parses the given JavaScript string as a module.
+fn parse_module(js: &str) -> Result<Module, ParseError> {
+    let mut lexer = Lexer::new(js);
+    lexer.lex().expect("failed to lex");
+
+    Parser::new(&lexer.tokens).parse_module()
+}
+
+/// Checks that the given JavaScript string gives the expected module body.
+fn check_module<L>(js: &str, body: L)
+where
+    L: Into<Vec<Node>>,
+{
+    assert_eq!(
+        parse_module(js).expect("failed to parse"),
+        Module::new(StatementList::new(body.into(), true))
+    );
+}
+
+/// Checks that the given JavaScript string is not a valid module.
+fn check_invalid_module(js: &str) {
+    assert!(parse_module(js).is_err());
+}
+
+#[test]
+fn import_declarations() {
+    check_module(
+        r#"import "a";
+        import b from "b";
+        import * as c from "c";
+        import d, { e, f as g, default as h } from "d";"#,
+        vec![
+            ImportDecl::new(Vec::new(), "a").into(),
+            ImportDecl::new(vec![ImportSpecifier::Default("b".into())], "b").into(),
+            ImportDecl::new(vec![ImportSpecifier::Namespace("c".into())], "c").into(),
+            ImportDecl::new(
+                vec![
+                    ImportSpecifier::Default("d".into()),
+                    ImportSpecifier::Named {
+                        imported: "e".into(),
+                        local: "e".into(),
+                    },
+                    ImportSpecifier::Named {
+                        imported: "f".into(),
+                        local: "g".into(),
+                    },
+                    ImportSpecifier::Named {
+                        imported: "default".into(),
+                        local: "h".into(),
+                    },
+                ],
+                "d",
+            )
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn export_declarations() {
+    check_module(
+        r#"export var a = 1;
+        export let b;
+        export { a as c, b as d };
+        export { d as default, e } from "e";
+        export * from "f";
+        export * as g from "g";"#,
+        vec![
+            ExportDecl::Declaration(Box::new(
+                VarDeclList::from(vec![VarDecl::new("a", Some(Const::from(1).into()))]).into(),
+            ))
+            .into(),
+            ExportDecl::Declaration(Box::new(
+                LetDeclList::from(vec![LetDecl::new("b", None)]).into(),
+            ))
+            .into(),
+            ExportDecl::Named(
+                vec![
+                    ExportSpecifier::new("a", "c"),
+                    ExportSpecifier::new("b", "d"),
+                ]
+                .into(),
+                None,
+            )
+            .into(),
+            ExportDecl::Named(
+                vec![
+                    ExportSpecifier::new("d", "default"),
+                    ExportSpecifier::new("e", "e"),
+                ]
+                .into(),
+                Some("e".into()),
+            )
+            .into(),
+            ExportDecl::All(None, "f".into()).into(),
+            ExportDecl::All(Some("g".into()), "g".into()).into(),
+        ],
+    );
+}
+
+#[test]
+fn export_default() {
+    check_module(
+        "export default a;",
+        vec![ExportDecl::Default(Box::new(Identifier::from("a").into())).into()],
+    );
+
+    // Function declarations are hoisted, even when exported.
+    check_module(
+        "a; export default function () {}",
+        vec![
+            ExportDecl::Default(Box::new(
+                FunctionDecl::new("default", Vec::new(), StatementList::new(Vec::new(), true))
+                    .into(),
+            ))
+            .into(),
+            Identifier::from("a").into(),
+        ],
+    );
+}
+
+#[test]
+fn module_is_strict() {
+    check_invalid_module("with (a) {}");
+    check_invalid_module("var eval = 1;");
+    check_invalid_module("export var yield;");
+}
+
+#[test]
+fn invalid_module_declarations() {
+    check_invalid_module("import { default } from \"a\";");
+    check_invalid_module("import a;");
+    check_invalid_module("export { default };");
+    check_invalid_module("export a;");
+    check_invalid_module("export var a; export { b as a };");
+    check_invalid_module("export default 1; export default 2;");
+}
+
+#[test]
+fn duplicate_declarations() {
+    check_invalid_module("import { a } from \"d\"; import { getC as a } from \"d\";");
+    check_invalid_module("import a, { b as a } from \"d\";");
+    check_invalid_module("import * as a from \"d\"; import a from \"d\";");
+    check_invalid_module("import { a } from \"d\"; let a = 1;");
+    check_invalid_module("import { a } from \"d\"; function a() {}");
+    check_invalid_module("import { a } from \"d\"; export class a {}");
+    check_invalid_module("import { a } from \"d\"; var a;");
+    check_invalid_module("var a; export const a = 1;");
+    check_invalid_module("function a() {} function a() {}");
+
+    assert!(parse_module("var a; var a; export { a };").is_ok());
+    assert!(parse_module("import { a } from \"d\"; export { a }; { let a; }").is_ok());
+}
@@ -0,0 +1,370 @@
+//! Destructuring pattern parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+//! [spec]: https://tc39.es/ecma262/#sec-destructuring-binding-patterns
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{
+                self, ArrayPattern, Identifier, Node, ObjectPattern, PatternElement,
+                PropertyPattern,
+            },
+            Punctuator, TokenKind,
+        },
+        parser::{
+            expression::{Initializer, LeftHandSideExpression, PropertyName},
+            statement::BindingIdentifier,
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+/// Binding target parsing.
+///
+/// This is either a `BindingIdentifier` or a `BindingPattern`, as found in declarations,
+/// parameters and `catch` clauses.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForBinding
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingTarget {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingTarget {
+    /// Creates a new `BindingTarget` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for BindingTarget {
+    type Output = node::BindingTarget;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        match cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind {
+            TokenKind::Punctuator(Punctuator::OpenBlock)
+            | TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                BindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(node::BindingTarget::Pattern)
+            }
+            _ => BindingIdentifier::new(self.allow_yield, self.allow_await)
+                .parse(cursor)
+                .map(node::BindingTarget::from),
+        }
+    }
+}
+
+/// Binding pattern parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+/// [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingPattern {
+    /// Creates a new `BindingPattern` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for BindingPattern {
+    type Output = node::Pattern;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingPattern", "Parsing");
+        PatternParser {
+            kind: PatternKind::Binding,
+            allow_yield: self.allow_yield,
+            allow_await: self.allow_await,
+        }
+        .parse_pattern(cursor)
+    }
+}
+
+/// Assignment pattern parsing.
+///
+/// This is the left-hand side of a destructuring assignment, like `[a, b] = [b, a]`. Its targets
+/// can be any expression that can be assigned to.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+/// [spec]: https://tc39.es/ecma262/#prod-AssignmentPattern
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct AssignmentPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl AssignmentPattern {
+    /// Creates a new `AssignmentPattern` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for AssignmentPattern {
+    type Output = node::Pattern;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AssignmentPattern", "Parsing");
+        PatternParser {
+            kind: PatternKind::Assignment,
+            allow_yield: self.allow_yield,
+            allow_await: self.allow_await,
+        }
+        .parse_pattern(cursor)
+    }
+}
+
+/// The kind of pattern being parsed, which decides what its targets can be.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PatternKind {
+    /// Targets are binding identifiers or nested patterns.
+    Binding,
+    /// Targets are assignable expressions or nested patterns.
+    Assignment,
+}
+
+/// The parser shared by binding and assignment patterns.
+#[derive(Debug, Clone, Copy)]
+struct PatternParser {
+    kind: PatternKind,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl PatternParser {
+    /// Parses an object or array pattern.
+    fn parse_pattern(self, cursor: &mut Cursor<'_>) -> Result<node::Pattern, ParseError> {
+        let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        match tok.kind {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                self.parse_object_pattern(cursor).map(node::Pattern::from)
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                self.parse_array_pattern(cursor).map(node::Pattern::from)
+            }
+            _ => Err(ParseError::expected(
+                vec![
+                    TokenKind::Punctuator(Punctuator::OpenBlock),
+                    TokenKind::Punctuator(Punctuator::OpenBracket),
+                ],
+                tok.clone(),
+                "destructuring pattern",
+            )),
+        }
+    }
+
+    /// Parses an object pattern, like `{ a, b: c = 1, [d]: e, ...f }`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+    fn parse_object_pattern(self, cursor: &mut Cursor<'_>) -> Result<ObjectPattern, ParseError> {
+        cursor.expect(Punctuator::OpenBlock, "object pattern")?;
+
+        let mut properties = Vec::new();
+        let mut rest = None;
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock).is_some() {
+                break;
+            }
+
+            if cursor.next_if(Punctuator::Spread).is_some() {
+                // The rest of an object can't be taken apart by a nested pattern.
+                rest = Some(match self.kind {
+                    PatternKind::Binding => self.parse_binding_identifier(cursor)?,
+                    PatternKind::Assignment => self.parse_assignment_target(cursor)?,
+                });
+                cursor.expect(Punctuator::CloseBlock, "rest element must be last")?;
+                break;
+            }
+
+            properties.push(self.parse_property_pattern(cursor)?);
+
+            if cursor.next_if(Punctuator::Comma).is_none() {
+                cursor.expect(Punctuator::CloseBlock, "object pattern")?;
+                break;
+            }
+        }
+
+        Ok(ObjectPattern::new(properties, rest))
+    }
+
+    /// Parses a property of an object pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-BindingProperty
+    fn parse_property_pattern(
+        self,
+        cursor: &mut Cursor<'_>,
+    ) -> Result<PropertyPattern, ParseError> {
+        let start = cursor.pos();
+        let name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        if cursor.next_if(Punctuator::Colon).is_some() {
+            return Ok(PropertyPattern::new(name, self.parse_element(cursor)?));
+        }
+
+        // `{ a = 1 }` is the same as `{ a: a = 1 }`, only for names that can be bound.
+        if let node::PropertyName::Computed(_) = name {
+            cursor.expect(Punctuator::Colon, "object pattern")?;
+        }
+        cursor.seek(start);
+        let target = self.parse_binding_identifier(cursor)?;
+        let init = self.parse_initializer(cursor)?;
+        Ok(PropertyPattern::new(
+            name,
+            PatternElement::new(target, init),
+        ))
+    }
+
+    /// Parses an array pattern, like `[a, , b = 1, ...c]`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+    fn parse_array_pattern(self, cursor: &mut Cursor<'_>) -> Result<ArrayPattern, ParseError> {
+        cursor.expect(Punctuator::OpenBracket, "array pattern")?;
+
+        let mut elements = Vec::new();
+        let mut rest = None;
+        loop {
+            if cursor.next_if(Punctuator::CloseBracket).is_some() {
+                break;
+            }
+
+            if cursor.next_if(Punctuator::Comma).is_some() {
+                elements.push(None);
+                continue;
+            }
+
+            if cursor.next_if(Punctuator::Spread).is_some() {
+                rest = Some(self.parse_target(cursor)?);
+                cursor.expect(Punctuator::CloseBracket, "rest element must be last")?;
+                break;
+            }
+
+            elements.push(Some(self.parse_element(cursor)?));
+
+            if cursor.next_if(Punctuator::Comma).is_none() {
+                cursor.expect(Punctuator::CloseBracket, "array pattern")?;
+                break;
+            }
+        }
+
+        Ok(ArrayPattern::new(elements, rest))
+    }
+
+    /// Parses an element of a pattern: a target, with an optional default value.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+    fn parse_element(self, cursor: &mut Cursor<'_>) -> Result<PatternElement, ParseError> {
+        let target = self.parse_target(cursor)?;
+        let init = self.parse_initializer(cursor)?;
+        Ok(PatternElement::new(target, init))
+    }
+
+    /// Parses the default value of an element, if there is one.
+    fn parse_initializer(self, cursor: &mut Cursor<'_>) -> Result<Option<Node>, ParseError> {
+        if cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind
+            == TokenKind::Punctuator(Punctuator::Assign)
+        {
+            Initializer::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor)
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parses the target of an element, which can be a nested pattern.
+    fn parse_target(self, cursor: &mut Cursor<'_>) -> Result<Node, ParseError> {
+        match cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind {
+            TokenKind::Punctuator(Punctuator::OpenBlock)
+            | TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                self.parse_pattern(cursor).map(Node::from)
+            }
+            _ => match self.kind {
+                PatternKind::Binding => self.parse_binding_identifier(cursor),
+                PatternKind::Assignment => self.parse_assignment_target(cursor),
+            },
+        }
+    }
+
+    /// Parses a target of a binding pattern, which has to be a name.
+    fn parse_binding_identifier(self, cursor: &mut Cursor<'_>) -> Result<Node, ParseError> {
+        BindingIdentifier::new(self.allow_yield, self.allow_await)
+            .parse(cursor)
+            .map(|name| Identifier::from(name).into())
+    }
+
+    /// Parses a target of an assignment pattern, which has to be a name or a property access.
+    fn parse_assignment_target(self, cursor: &mut Cursor<'_>) -> Result<Node, ParseError> {
+        let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        let target =
+            LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        match target {
+            Node::Identifier(_)
+            | Node::GetConstField(_)
+            | Node::GetField(_)
+            | Node::GetSuperField(_) => Ok(target),
+            _ => Err(ParseError::unexpected(
+                tok.clone(),
+                "invalid destructuring assignment target",
+            )),
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            field::GetConstField, ArrayPattern, Assign, Catch, ConstDecl, ConstDeclList,
+            FormalParameter, FunctionDecl, Identifier, LetDecl, LetDeclList, Node, ObjectPattern,
+            Pattern, PatternElement, PropertyPattern, Try, VarDecl, VarDeclList,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks an object binding pattern with shorthand, renamed and default properties.
+#[test]
+fn check_object_binding_pattern() {
+    check_parser(
+        "const {a, b: c, d = 1} = obj;",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            Pattern::from(ObjectPattern::new(
+                vec![
+                    PropertyPattern::new("a", Identifier::from("a")),
+                    PropertyPattern::new("b", Identifier::from("c")),
+                    PropertyPattern::new(
+                        "d",
+                        PatternElement::new(Identifier::from("d"), Some(Const::from(1).into())),
+                    ),
+                ],
+                None,
+            )),
+            Identifier::from("obj"),
+        )])
+        .into()],
+    );
+}
+
+/// Checks an object binding pattern with literal and computed property names.
+#[test]
+fn check_object_binding_pattern_names() {
+    check_parser(
+        "let {\"a b\": a, 1: b, if: c, [key]: d} = obj;",
+        vec![LetDeclList::from(vec![LetDecl::new(
+            Pattern::from(ObjectPattern::new(
+                vec![
+                    PropertyPattern::new("a b", Identifier::from("a")),
+                    PropertyPattern::new("1", Identifier::from("b")),
+                    PropertyPattern::new("if", Identifier::from("c")),
+                    PropertyPattern::new(
+                        Node::from(Identifier::from("key")),
+                        Identifier::from("d"),
+                    ),
+                ],
+                None,
+            )),
+            Some(Identifier::from("obj").into()),
+        )])
+        .into()],
+    );
+}
+
+/// Checks an object binding pattern with a rest element.
+#[test]
+fn check_object_binding_pattern_rest() {
+    check_parser(
+        "var {a, ...rest} = obj;",
+        vec![VarDeclList::from(vec![VarDecl::new(
+            Pattern::from(ObjectPattern::new(
+                vec![PropertyPattern::new("a", Identifier::from("a"))],
+                Node::from(Identifier::from("rest")),
+            )),
+            Some(Identifier::from("obj").into()),
+        )])
+        .into()],
+    );
+}
+
+/// Checks an array binding pattern with holes, defaults, nested patterns and a rest element.
+#[test]
+fn check_array_binding_pattern() {
+    check_parser(
+        "let [a, , b = 2, {c}, ...[d]] = arr;",
+        vec![LetDeclList::from(vec![LetDecl::new(
+            Pattern::from(ArrayPattern::new(
+                vec![
+                    Some(Identifier::from("a").into()),
+                    None,
+                    Some(PatternElement::new(
+                        Identifier::from("b"),
+                        Some(Const::from(2).into()),
+                    )),
+                    Some(
+                        Pattern::from(ObjectPattern::new(
+                            vec![PropertyPattern::new("c", Identifier::from("c"))],
+                            None,
+                        ))
+                        .into(),
+                    ),
+                ],
+                Node::from(Pattern::from(ArrayPattern::new(
+                    vec![Some(Identifier::from("d").into())],
+                    None,
+                ))),
+            )),
+            Some(Identifier::from("arr").into()),
+        )])
+        .into()],
+    );
+}
+
+/// Checks patterns in function parameters.
+#[test]
+fn check_parameter_patterns() {
+    check_parser(
+        "function f({a}, [b] = c, ...[d]) {}",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![
+                FormalParameter::new(
+                    Pattern::from(ObjectPattern::new(
+                        vec![PropertyPattern::new("a", Identifier::from("a"))],
+                        None,
+                    )),
+                    None,
+                    false,
+                ),
+                FormalParameter::new(
+                    Pattern::from(ArrayPattern::new(
+                        vec![Some(Identifier::from("b").into())],
+                        None,
+                    )),
+                    Some(Identifier::from("c").into()),
+                    false,
+                ),
+                FormalParameter::new(
+                    Pattern::from(ArrayPattern::new(
+                        vec![Some(Identifier::from("d").into())],
+                        None,
+                    )),
+                    None,
+                    true,
+                ),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks a pattern in a `catch` clause.
+#[test]
+fn check_catch_pattern() {
+    check_parser(
+        "try {} catch ({message}) {}",
+        vec![Try::new(
+            vec![],
+            Some(Catch::new(
+                Pattern::from(ObjectPattern::new(
+                    vec![PropertyPattern::new("message", Identifier::from("message"))],
+                    None,
+                )),
+                vec![],
+            )),
+            None,
+        )
+        .into()],
+    );
+}
+
+/// Checks a destructuring assignment, whose targets can be property accesses.
+#[test]
+fn check_assignment_pattern() {
+    check_parser(
+        "[a, b.c] = [b, a];",
+        vec![Assign::new(
+            Pattern::from(ArrayPattern::new(
+                vec![
+                    Some(Identifier::from("a").into()),
+                    Some(GetConstField::new(Identifier::from("b"), "c").into()),
+                ],
+                None,
+            )),
+            Node::ArrayDecl(
+                vec![Identifier::from("b").into(), Identifier::from("a").into()].into(),
+            ),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "({a: b.c = 1} = obj);",
+        vec![Assign::new(
+            Pattern::from(ObjectPattern::new(
+                vec![PropertyPattern::new(
+                    "a",
+                    PatternElement::new(
+                        GetConstField::new(Identifier::from("b"), "c"),
+                        Some(Const::from(1).into()),
+                    ),
+                )],
+                None,
+            )),
+            Identifier::from("obj"),
+        )
+        .into()],
+    );
+}
+
+/// Checks that array and object literals are still parsed when they aren't assigned to.
+#[test]
+fn check_literals_are_not_patterns() {
+    check_parser(
+        "[a, b];",
+        vec![Node::ArrayDecl(
+            vec![Identifier::from("a").into(), Identifier::from("b").into()].into(),
+        )],
+    );
+}
+
+/// Checks invalid patterns.
+#[test]
+fn check_invalid_patterns() {
+    // Rest elements must be last.
+    check_invalid("let [...a, b] = arr;");
+    check_invalid("let {...a, b} = obj;");
+    // Object rest elements can't be patterns.
+    check_invalid("let {...{a}} = obj;");
+    // Patterns need an initializer.
+    check_invalid("let [a];");
+    check_invalid("var {a};");
+    // Binding patterns only contain names.
+    check_invalid("let [a.b] = arr;");
+    // Computed names need a target.
+    check_invalid("let {[a]} = obj;");
+    // Keywords can't be bound.
+    check_invalid("let {if} = obj;");
+}
@@ -53,6 +53,7 @@ impl TokenParser for BreakStatement {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("BreakStatement", "Parsing");
+        let position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
         cursor.expect(Keyword::Break, "break statement")?;
 
         let label = if let (true, tok) = cursor.peek_semicolon(false) {
@@ -63,10 +64,22 @@ impl TokenParser for BreakStatement {
                 _ => {}
             }
 
+            if !cursor.can_break() {
+                return Err(ParseError::general(
+                    "illegal break statement outside of an iteration or switch statement",
+                    position,
+                ));
+            }
             None
         } else {
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-            cursor.expect_semicolon(false, "continue statement")?;
+            cursor.expect_semicolon(false, "break statement")?;
+            if cursor.label(&label).is_none() {
+                return Err(ParseError::general(
+                    "undefined label in break statement",
+                    position,
+                ));
+            }
 
             Some(label)
         };
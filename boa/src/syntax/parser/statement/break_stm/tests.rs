@@ -1,6 +1,6 @@
 use crate::syntax::{
     ast::{
-        node::{Block, Break, Node, WhileLoop},
+        node::{Block, Break, Labelled, Node, WhileLoop},
         Const,
     },
     parser::tests::check_parser,
@@ -42,12 +42,15 @@ fn inline_block_semicolon_insertion() {
 #[test]
 fn new_line_semicolon_insertion() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Break::new("test").into()]),
+        vec![Labelled::new(
+            "test",
+            WhileLoop::new(
+                Const::from(true),
+                Block::from(vec![Break::new("test").into()]),
+            ),
         )
         .into()],
     );
@@ -68,12 +71,15 @@ fn inline_block() {
 #[test]
 fn new_line_block() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Break::new("test").into()]),
+        vec![Labelled::new(
+            "test",
+            WhileLoop::new(
+                Const::from(true),
+                Block::from(vec![Break::new("test").into()]),
+            ),
         )
         .into()],
     );
@@ -82,23 +88,29 @@ fn new_line_block() {
 #[test]
 fn reserved_label() {
     check_parser(
-        "while (true) {
+        "await: while (true) {
             break await;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Break::new("await").into()]),
+        vec![Labelled::new(
+            "await",
+            WhileLoop::new(
+                Const::from(true),
+                Block::from(vec![Break::new("await").into()]),
+            ),
         )
         .into()],
     );
 
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             break yield;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Break::new("yield").into()]),
+        vec![Labelled::new(
+            "yield",
+            WhileLoop::new(
+                Const::from(true),
+                Block::from(vec![Break::new("yield").into()]),
+            ),
         )
         .into()],
     );
@@ -53,6 +53,7 @@ impl TokenParser for ContinueStatement {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ContinueStatement", "Parsing");
+        let position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
         cursor.expect(Keyword::Continue, "continue statement")?;
 
         let label = if let (true, tok) = cursor.peek_semicolon(false) {
@@ -63,10 +64,31 @@ impl TokenParser for ContinueStatement {
                 _ => {}
             }
 
+            if !cursor.can_continue() {
+                return Err(ParseError::general(
+                    "illegal continue statement outside of an iteration statement",
+                    position,
+                ));
+            }
             None
         } else {
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
             cursor.expect_semicolon(false, "continue statement")?;
+            match cursor.label(&label) {
+                Some(true) => {}
+                Some(false) => {
+                    return Err(ParseError::general(
+                        "continue statement label is not the label of an iteration statement",
+                        position,
+                    ))
+                }
+                None => {
+                    return Err(ParseError::general(
+                        "undefined label in continue statement",
+                        position,
+                    ))
+                }
+            }
 
             Some(label)
         };
@@ -1,6 +1,6 @@
 use crate::syntax::{
     ast::{
-        node::{Block, Continue, WhileLoop},
+        node::{Block, Continue, Labelled, WhileLoop},
         Const,
     },
     parser::tests::check_parser,
@@ -38,12 +38,15 @@ fn inline_block_semicolon_insertion() {
 #[test]
 fn new_line_semicolon_insertion() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Continue::new("test").into()]),
+        vec![Labelled::new(
+            "test",
+            WhileLoop::new(
+                Const::from(true),
+                Block::from(vec![Continue::new("test").into()]),
+            ),
         )
         .into()],
     );
@@ -64,12 +67,15 @@ fn inline_block() {
 #[test]
 fn new_line_block() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Continue::new("test").into()]),
+        vec![Labelled::new(
+            "test",
+            WhileLoop::new(
+                Const::from(true),
+                Block::from(vec![Continue::new("test").into()]),
+            ),
         )
         .into()],
     );
@@ -78,23 +84,29 @@ fn new_line_block() {
 #[test]
 fn reserved_label() {
     check_parser(
-        "while (true) {
+        "await: while (true) {
             continue await;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Continue::new("await").into()]),
+        vec![Labelled::new(
+            "await",
+            WhileLoop::new(
+                Const::from(true),
+                Block::from(vec![Continue::new("await").into()]),
+            ),
         )
         .into()],
     );
 
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             continue yield;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Continue::new("yield").into()]),
+        vec![Labelled::new(
+            "yield",
+            WhileLoop::new(
+                Const::from(true),
+                Block::from(vec![Continue::new("yield").into()]),
+            ),
         )
         .into()],
     );
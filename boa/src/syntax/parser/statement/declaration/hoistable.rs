@@ -7,15 +7,27 @@
 
 use crate::{
     syntax::{
-        ast::{node::FunctionDecl, Keyword, Node, Punctuator},
+        ast::{
+            node::{AsyncFunctionDecl, FunctionDecl, GeneratorDecl},
+            Keyword, Node, Punctuator, TokenKind,
+        },
         parser::{
-            function::FormalParameters, function::FunctionBody, statement::BindingIdentifier,
+            function::{check_parameters, FormalParameters, FunctionBody},
+            statement::declaration::parse_declaration_name,
             AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
     BoaProfiler,
 };
 
+/// Checks if the next tokens start an async function declaration, which is `async` followed by
+/// `function` on the same line.
+pub(in crate::syntax::parser::statement) fn is_async_function(cursor: &mut Cursor<'_>) -> bool {
+    cursor.peek(0).map(|tok| &tok.kind) == Some(&TokenKind::identifier("async"))
+        && cursor.peek(1).map(|tok| &tok.kind) == Some(&TokenKind::Keyword(Keyword::Function))
+        && cursor.peek_expect_no_lineterminator(1).is_ok()
+}
+
 /// Hoistable declaration parsing.
 ///
 /// More information:
@@ -23,7 +35,7 @@ use crate::{
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionDeclaration
 #[derive(Debug, Clone, Copy)]
-pub(super) struct HoistableDeclaration {
+pub(in crate::syntax::parser) struct HoistableDeclaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     is_default: AllowDefault,
@@ -31,7 +43,11 @@ pub(super) struct HoistableDeclaration {
 
 impl HoistableDeclaration {
     /// Creates a new `HoistableDeclaration` parser.
-    pub(super) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A, D>(
+        allow_yield: Y,
+        allow_await: A,
+        is_default: D,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -50,7 +66,20 @@ impl TokenParser for HoistableDeclaration {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("HoistableDeclaration", "Parsing");
-        // TODO: check for generators and async functions + generators
+        if is_async_function(cursor) {
+            return AsyncFunctionDeclaration::new(
+                self.allow_yield,
+                self.allow_await,
+                self.is_default,
+            )
+            .parse(cursor)
+            .map(Node::from);
+        }
+        if cursor.peek(1).map(|tok| &tok.kind) == Some(&TokenKind::Punctuator(Punctuator::Mul)) {
+            return GeneratorDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
+                .parse(cursor)
+                .map(Node::from);
+        }
         FunctionDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
             .parse(cursor)
             .map(Node::from)
@@ -94,20 +123,138 @@ impl TokenParser for FunctionDeclaration {
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
         cursor.expect(Keyword::Function, "function declaration")?;
 
-        // TODO: If self.is_default, then this can be empty.
-        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let name =
+            parse_declaration_name(cursor, self.allow_yield, self.allow_await, self.is_default)?;
 
         cursor.expect(Punctuator::OpenParen, "function declaration")?;
 
+        let params_position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
         let params = FormalParameters::new(false, false).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseParen, "function declaration")?;
         cursor.expect(Punctuator::OpenBlock, "function declaration")?;
 
-        let body = FunctionBody::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let body = FunctionBody::new(false, false).parse(cursor)?;
+        check_parameters(&params, &body, params_position)?;
 
         cursor.expect(Punctuator::CloseBlock, "function declaration")?;
 
         Ok(FunctionDecl::new(name, params, body))
     }
 }
+
+/// Generator declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function*
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorDeclaration
+#[derive(Debug, Clone, Copy)]
+struct GeneratorDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl GeneratorDeclaration {
+    /// Creates a new `GeneratorDeclaration` parser.
+    fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl TokenParser for GeneratorDeclaration {
+    type Output = GeneratorDecl;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        cursor.expect(Keyword::Function, "generator declaration")?;
+        cursor.expect(Punctuator::Mul, "generator declaration")?;
+
+        let name =
+            parse_declaration_name(cursor, self.allow_yield, self.allow_await, self.is_default)?;
+
+        cursor.expect(Punctuator::OpenParen, "generator declaration")?;
+
+        let params_position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
+        let params = FormalParameters::new(false, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "generator declaration")?;
+        cursor.expect(Punctuator::OpenBlock, "generator declaration")?;
+
+        let body = FunctionBody::new(true, false).parse(cursor)?;
+        check_parameters(&params, &body, params_position)?;
+
+        cursor.expect(Punctuator::CloseBlock, "generator declaration")?;
+
+        Ok(GeneratorDecl::new(name, params, body))
+    }
+}
+
+/// Async function declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncFunctionDeclaration
+#[derive(Debug, Clone, Copy)]
+struct AsyncFunctionDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl AsyncFunctionDeclaration {
+    /// Creates a new `AsyncFunctionDeclaration` parser.
+    fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl TokenParser for AsyncFunctionDeclaration {
+    type Output = AsyncFunctionDecl;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        cursor.expect(TokenKind::identifier("async"), "async function declaration")?;
+        cursor.expect(Keyword::Function, "async function declaration")?;
+
+        let name =
+            parse_declaration_name(cursor, self.allow_yield, self.allow_await, self.is_default)?;
+
+        cursor.expect(Punctuator::OpenParen, "async function declaration")?;
+
+        let params_position = cursor.peek(0).ok_or(ParseError::AbruptEnd)?.span().start();
+        let params = FormalParameters::new(false, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "async function declaration")?;
+        cursor.expect(Punctuator::OpenBlock, "async function declaration")?;
+
+        let body = FunctionBody::new(false, true).parse(cursor)?;
+        check_parameters(&params, &body, params_position)?;
+
+        cursor.expect(Punctuator::CloseBlock, "async function declaration")?;
+
+        Ok(AsyncFunctionDecl::new(name, params, body))
+    }
+}
@@ -10,11 +10,11 @@
 use crate::{
     syntax::{
         ast::{
-            node::{ConstDecl, ConstDeclList, LetDecl, LetDeclList, Node},
+            node::{self, ConstDecl, ConstDeclList, LetDecl, LetDeclList, Node},
             Keyword, Punctuator, TokenKind,
         },
         parser::{
-            expression::Initializer, statement::BindingIdentifier, AllowAwait, AllowIn, AllowYield,
+            expression::Initializer, pattern::BindingTarget, AllowAwait, AllowIn, AllowYield,
             Cursor, ParseError, ParseResult, TokenParser,
         },
     },
@@ -115,13 +115,13 @@ impl TokenParser for BindingList {
         let mut const_decls = Vec::new();
 
         loop {
-            let (ident, init) =
+            let (target, init) =
                 LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                     .parse(cursor)?;
 
             if self.is_const {
                 if let Some(init) = init {
-                    const_decls.push(ConstDecl::new(ident, init));
+                    const_decls.push(ConstDecl::new(target, init));
                 } else {
                     return Err(ParseError::expected(
                         vec![TokenKind::Punctuator(Punctuator::Assign)],
@@ -130,7 +130,15 @@ impl TokenParser for BindingList {
                     ));
                 }
             } else {
-                let_decls.push(LetDecl::new(ident, init));
+                // Patterns need a value to take apart.
+                if let (node::BindingTarget::Pattern(_), None) = (&target, &init) {
+                    return Err(ParseError::expected(
+                        vec![TokenKind::Punctuator(Punctuator::Assign)],
+                        cursor.next().ok_or(ParseError::AbruptEnd)?.clone(),
+                        "let declaration",
+                    ));
+                }
+                let_decls.push(LetDecl::new(target, init));
             }
 
             match cursor.peek_semicolon(false) {
@@ -188,13 +196,18 @@ impl LexicalBinding {
 }
 
 impl TokenParser for LexicalBinding {
-    type Output = (Box<str>, Option<Node>);
+    type Output = (node::BindingTarget, Option<Node>);
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
-        let ident = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        let initializer =
-            Initializer::new(self.allow_in, self.allow_yield, self.allow_await).try_parse(cursor);
-
-        Ok((ident, initializer))
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let initializer = match cursor.peek(0) {
+            Some(tk) if tk.kind == Punctuator::Assign.into() => Some(
+                Initializer::new(self.allow_in, self.allow_yield, self.allow_await)
+                    .parse(cursor)?,
+            ),
+            _ => None,
+        };
+
+        Ok((target, initializer))
     }
 }
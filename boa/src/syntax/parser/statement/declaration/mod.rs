@@ -7,17 +7,26 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements#Declarations
 //! [spec]:https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
 
+mod class;
 mod hoistable;
 mod lexical;
 #[cfg(test)]
 mod tests;
 
-use self::{hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
+pub(super) use self::hoistable::is_async_function;
+pub(in crate::syntax::parser) use self::{
+    class::ClassDeclaration, hoistable::HoistableDeclaration,
+};
+
+use self::lexical::LexicalDeclaration;
 
 use crate::{
     syntax::{
         ast::{Keyword, Node, TokenKind},
-        parser::{AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
+        parser::{
+            statement::BindingIdentifier, AllowAwait, AllowDefault, AllowYield, Cursor, ParseError,
+            TokenParser,
+        },
     },
     BoaProfiler,
 };
@@ -55,9 +64,14 @@ impl TokenParser for Declaration {
         let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind {
-            TokenKind::Keyword(Keyword::Function) => {
+            TokenKind::Keyword(Keyword::Function) | TokenKind::Identifier(_) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false).parse(cursor)
             }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassDeclaration::new(self.allow_yield, self.allow_await, false)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
                 LexicalDeclaration::new(true, self.allow_yield, self.allow_await).parse(cursor)
             }
@@ -65,3 +79,23 @@ impl TokenParser for Declaration {
         }
     }
 }
+
+/// Parses the name of a function or class declaration.
+///
+/// The name can be omitted in an `export default` declaration, the declaration is then named
+/// `default`, which can't clash with the other bindings of the module since it is a keyword.
+fn parse_declaration_name(
+    cursor: &mut Cursor<'_>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+) -> Result<Box<str>, ParseError> {
+    if is_default.0 {
+        if let Some(TokenKind::Punctuator(_)) | Some(TokenKind::Keyword(Keyword::Extends)) =
+            cursor.peek(0).map(|tok| &tok.kind)
+        {
+            return Ok("default".into());
+        }
+    }
+    BindingIdentifier::new(allow_yield, allow_await).parse(cursor)
+}
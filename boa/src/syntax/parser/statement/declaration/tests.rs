@@ -1,8 +1,9 @@
 use crate::syntax::{
     ast::{
         node::{
-            ConstDecl, ConstDeclList, FunctionDecl, LetDecl, LetDeclList, Node, VarDecl,
-            VarDeclList,
+            AsyncFunctionDecl, Await, Call, ConstDecl, ConstDeclList, FunctionDecl, GeneratorDecl,
+            Identifier, LetDecl, LetDeclList, Node, Return, TemplateElement, TemplateLit, VarDecl,
+            VarDeclList, Yield,
         },
         Const,
     },
@@ -192,3 +193,94 @@ fn function_declaration_keywords() {
         vec![FunctionDecl::new(Box::from("await"), vec![], vec![]).into()],
     );
 }
+
+/// Generator declaration parsing.
+#[test]
+fn generator_declaration() {
+    check_parser(
+        "function* gen() {}",
+        vec![GeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+
+    check_parser(
+        "function *gen() {}",
+        vec![GeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+}
+
+/// Generator declaration parsing with `yield` expressions.
+#[test]
+fn generator_declaration_yield() {
+    check_parser(
+        "function* gen() { yield 1; yield; yield* other(); }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![
+                Yield::new(Const::from(1), false).into(),
+                Yield::new::<Node, Option<Node>>(None, false).into(),
+                Yield::new(Call::new(Identifier::from("other"), vec![]), true).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks `yield` expressions without an operand in template substitutions.
+#[test]
+fn generator_declaration_yield_in_template() {
+    check_parser(
+        "function* gen() { return `a${yield}b${yield}`; }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![Return::new(
+                TemplateLit::new(vec![
+                    TemplateElement::String("a".into()),
+                    TemplateElement::Expr(Yield::new::<Node, Option<Node>>(None, false).into()),
+                    TemplateElement::String("b".into()),
+                    TemplateElement::Expr(Yield::new::<Node, Option<Node>>(None, false).into()),
+                ]),
+                None,
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a generator declaration needs a name.
+#[test]
+fn generator_declaration_no_name() {
+    check_invalid("function* () {}");
+}
+
+/// Async function declaration parsing with `await` expressions.
+#[test]
+fn async_function_declaration() {
+    check_parser(
+        "async function load() { return await fetch(); }",
+        vec![AsyncFunctionDecl::new(
+            Box::from("load"),
+            vec![],
+            vec![Return::new(
+                Await::new(Call::new(Identifier::from("fetch"), vec![])),
+                None,
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a line terminator after `async` doesn't start an async function declaration.
+#[test]
+fn async_function_declaration_line_terminator() {
+    check_parser(
+        "async\nfunction load() {}",
+        vec![
+            FunctionDecl::new(Box::from("load"), vec![], vec![]).into(),
+            Identifier::from("async").into(),
+        ],
+    );
+}
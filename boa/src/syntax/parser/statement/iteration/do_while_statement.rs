@@ -60,8 +60,9 @@ impl TokenParser for DoWhileStatement {
         let _timer = BoaProfiler::global().start_event("DoWhileStatement", "Parsing");
         cursor.expect(Keyword::Do, "do while statement")?;
 
-        let body =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let body = cursor.with_iteration(|cursor| {
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)
+        })?;
 
         let next_token = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
 
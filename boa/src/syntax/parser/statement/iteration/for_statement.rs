@@ -1,5 +1,7 @@
 //! For statement parsing.
 //!
+//! This also parses the `for...in` and `for...of` statements.
+//!
 //! More information:
 //!  - [MDN documentation][mdn]
 //!  - [ECMAScript specification][spec]
@@ -10,11 +12,12 @@
 use crate::{
     syntax::{
         ast::{
-            node::{ForLoop, Node},
+            node::{ForInLoop, ForLoop, ForOfLoop, IterableLoopInitializer, Node},
             Const, Keyword, Punctuator, TokenKind,
         },
         parser::{
-            expression::Expression,
+            expression::{AssignmentExpression, Expression, LeftHandSideExpression},
+            pattern::{AssignmentPattern, BindingTarget},
             statement::declaration::Declaration,
             statement::{variable::VariableDeclarationList, Statement},
             AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
@@ -25,6 +28,9 @@ use crate::{
 
 /// For statement parsing
 ///
+/// This parses the `for`, `for...in` and `for...of` statements, which are told apart by their
+/// heads.
+///
 /// More information:
 ///  - [MDN documentation][mdn]
 ///  - [ECMAScript specification][spec]
@@ -59,13 +65,34 @@ impl ForStatement {
 }
 
 impl TokenParser for ForStatement {
-    type Output = ForLoop;
+    type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ForStatement", "Parsing");
         cursor.expect(Keyword::For, "for statement")?;
         cursor.expect(Punctuator::OpenParen, "for statement")?;
 
+        if let Some((init, kind)) = self.parse_iterable_head(cursor) {
+            let expr = match kind {
+                IterableLoopKind::In => {
+                    Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?
+                }
+                IterableLoopKind::Of => {
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                        .parse(cursor)?
+                }
+            };
+            cursor.expect(Punctuator::CloseParen, "for statement")?;
+            let body = cursor.with_iteration(|cursor| {
+                Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)
+            })?;
+
+            return Ok(match kind {
+                IterableLoopKind::In => ForInLoop::new(init, expr, body).into(),
+                IterableLoopKind::Of => ForOfLoop::new(init, expr, body).into(),
+            });
+        }
+
         let init = match cursor.peek(0).ok_or(ParseError::AbruptEnd)?.kind {
             TokenKind::Keyword(Keyword::Var) => Some(
                 VariableDeclarationList::new(false, self.allow_yield, self.allow_await)
@@ -76,7 +103,7 @@ impl TokenParser for ForStatement {
                 Some(Declaration::new(self.allow_yield, self.allow_await).parse(cursor)?)
             }
             TokenKind::Punctuator(Punctuator::Semicolon) => None,
-            _ => Some(Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?),
+            _ => Some(Expression::new(false, self.allow_yield, self.allow_await).parse(cursor)?),
         };
 
         cursor.expect(Punctuator::Semicolon, "for statement")?;
@@ -100,10 +127,72 @@ impl TokenParser for ForStatement {
             Some(step)
         };
 
-        let body =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let body = cursor.with_iteration(|cursor| {
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)
+        })?;
 
         // TODO: do not encapsulate the `for` in a block just to have an inner scope.
-        Ok(ForLoop::new(init, cond, step, body))
+        Ok(ForLoop::new(init, cond, step, body).into())
+    }
+}
+
+/// The kind of a `for...in` or `for...of` loop.
+#[derive(Debug, Clone, Copy)]
+enum IterableLoopKind {
+    In,
+    Of,
+}
+
+impl ForStatement {
+    /// Tries to parse the head of a `for...in` or `for...of` loop, up to the `in` or `of`
+    /// keyword.
+    ///
+    /// It returns the cursor to the initial position if the head belongs to a `for` loop.
+    fn parse_iterable_head(
+        self,
+        cursor: &mut Cursor<'_>,
+    ) -> Option<(IterableLoopInitializer, IterableLoopKind)> {
+        let initial_pos = cursor.pos();
+        let init = match cursor.peek(0)?.kind {
+            TokenKind::Keyword(keyword @ Keyword::Var)
+            | TokenKind::Keyword(keyword @ Keyword::Let)
+            | TokenKind::Keyword(keyword @ Keyword::Const) => {
+                let _ = cursor.next();
+                BindingTarget::new(self.allow_yield, self.allow_await)
+                    .try_parse(cursor)
+                    .map(|target| match keyword {
+                        Keyword::Var => IterableLoopInitializer::Var(target),
+                        Keyword::Let => IterableLoopInitializer::Let(target),
+                        _ => IterableLoopInitializer::Const(target),
+                    })
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket)
+            | TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                AssignmentPattern::new(self.allow_yield, self.allow_await)
+                    .try_parse(cursor)
+                    .map(|pattern| IterableLoopInitializer::Expression(pattern.into()))
+            }
+            // Optional chains can't be assigned to.
+            _ => LeftHandSideExpression::new(self.allow_yield, self.allow_await)
+                .try_parse(cursor)
+                .filter(|target| !matches!(target, Node::Optional(_)))
+                .map(IterableLoopInitializer::Expression),
+        };
+
+        let kind = match cursor.peek(0).map(|tok| &tok.kind) {
+            Some(TokenKind::Keyword(Keyword::In)) => Some(IterableLoopKind::In),
+            Some(TokenKind::Identifier(ref name)) if name.as_ref() == "of" => {
+                Some(IterableLoopKind::Of)
+            }
+            _ => None,
+        };
+
+        if let (Some(init), Some(kind)) = (init, kind) {
+            let _ = cursor.next();
+            Some((init, kind))
+        } else {
+            cursor.seek(initial_pos);
+            None
+        }
     }
 }
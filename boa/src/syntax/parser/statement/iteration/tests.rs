@@ -1,13 +1,14 @@
 use crate::syntax::{
     ast::{
         node::{
-            field::GetConstField, BinOp, Block, Call, DoWhileLoop, Identifier, UnaryOp, VarDecl,
-            VarDeclList,
+            field::GetConstField, ArrayPattern, Assign, BinOp, Block, Call, DoWhileLoop, ForInLoop,
+            ForLoop, ForOfLoop, Identifier, IterableLoopInitializer, Node, Pattern, UnaryOp,
+            VarDecl, VarDeclList,
         },
         op::{self, AssignOp, CompOp},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks do-while statement parsing.
@@ -59,3 +60,99 @@ fn check_do_while_semicolon_insertion() {
         ],
     );
 }
+
+/// Checks `for...in` statements with declarations and assignment targets as heads.
+#[test]
+fn check_for_in() {
+    check_parser(
+        "for (var key in obj) {}",
+        vec![ForInLoop::new(
+            IterableLoopInitializer::Var("key".into()),
+            Identifier::from("obj"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "for (a.b in obj) {}",
+        vec![ForInLoop::new(
+            IterableLoopInitializer::Expression(
+                GetConstField::new(Identifier::from("a"), "b").into(),
+            ),
+            Identifier::from("obj"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+}
+
+/// Checks `for...of` statements with declarations, patterns and assignment targets as heads.
+#[test]
+fn check_for_of() {
+    check_parser(
+        "for (const x of xs) x;",
+        vec![ForOfLoop::new(
+            IterableLoopInitializer::Const("x".into()),
+            Identifier::from("xs"),
+            Identifier::from("x"),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "for (let [a, b] of pairs) {}",
+        vec![ForOfLoop::new(
+            IterableLoopInitializer::Let(
+                Pattern::from(ArrayPattern::new(
+                    vec![
+                        Some(Identifier::from("a").into()),
+                        Some(Identifier::from("b").into()),
+                    ],
+                    None,
+                ))
+                .into(),
+            ),
+            Identifier::from("pairs"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "for (x of xs) {}",
+        vec![ForOfLoop::new(
+            IterableLoopInitializer::Expression(Identifier::from("x").into()),
+            Identifier::from("xs"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+}
+
+/// Checks that `for` statements whose initializer uses `in` or `of` aren't taken for
+/// `for...in` or `for...of` statements.
+#[test]
+fn check_for_not_iterable() {
+    check_parser(
+        "for (of = 0; of < 1;) {}",
+        vec![ForLoop::new(
+            Node::from(Assign::new(Identifier::from("of"), Const::from(0))),
+            Node::from(BinOp::new(
+                CompOp::LessThan,
+                Identifier::from("of"),
+                Const::from(1),
+            )),
+            None,
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+}
+
+/// Checks invalid `for...in` and `for...of` statements.
+#[test]
+fn check_invalid_for_in_of() {
+    check_invalid("for (let x of) {}");
+    check_invalid("for (let x in xs, ys {}");
+}
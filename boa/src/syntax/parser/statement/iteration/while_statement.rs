@@ -56,8 +56,9 @@ impl TokenParser for WhileStatement {
 
         cursor.expect(Punctuator::CloseParen, "while statement")?;
 
-        let body =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let body = cursor.with_iteration(|cursor| {
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)
+        })?;
 
         Ok(WhileLoop::new(cond, body))
     }
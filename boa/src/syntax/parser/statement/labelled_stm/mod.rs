@@ -0,0 +1,107 @@
+//! Labelled statement parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/label
+//! [spec]: https://tc39.es/ecma262/#sec-labelled-statements
+
+#[cfg(test)]
+mod tests;
+
+use super::{declaration::Declaration, LabelIdentifier, Statement};
+use crate::{
+    syntax::{
+        ast::{node::Labelled, Keyword, Punctuator, TokenKind},
+        parser::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser},
+    },
+    BoaProfiler,
+};
+
+/// Labelled statement parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/label
+/// [spec]: https://tc39.es/ecma262/#prod-LabelledStatement
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LabelledStatement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    allow_return: AllowReturn,
+}
+
+impl LabelledStatement {
+    /// Creates a new `LabelledStatement` parser.
+    pub(super) fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        R: Into<AllowReturn>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            allow_return: allow_return.into(),
+        }
+    }
+}
+
+impl TokenParser for LabelledStatement {
+    type Output = Labelled;
+
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("LabelledStatement", "Parsing");
+        let label_tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+        let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        cursor.expect(Punctuator::Colon, "labelled statement")?;
+        if cursor.label(&label).is_some() {
+            return Err(ParseError::general(
+                "label has already been declared",
+                label_tok.span().start(),
+            ));
+        }
+
+        let iteration = labels_iteration(cursor);
+        let item = cursor.with_label(label.clone(), iteration, |cursor| {
+            let tok = cursor.peek(0).ok_or(ParseError::AbruptEnd)?;
+            match tok.kind {
+                // Only function declarations can be labelled, other declarations are parsed as
+                // statements, and rejected there.
+                TokenKind::Keyword(Keyword::Function) => {
+                    Declaration::new(self.allow_yield, self.allow_await).parse(cursor)
+                }
+                _ => Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor),
+            }
+        })?;
+
+        Ok(Labelled::new(label, item))
+    }
+}
+
+/// Checks if the statement after a label is an iteration statement, skipping the other labels
+/// of the statement, so that `continue` statements can jump to all of its labels.
+fn labels_iteration(cursor: &Cursor<'_>) -> bool {
+    let mut skip = 0;
+    loop {
+        let kind = cursor.peek(skip).map(|tok| &tok.kind);
+        match kind {
+            Some(TokenKind::Identifier(_))
+            | Some(TokenKind::Keyword(Keyword::Yield))
+            | Some(TokenKind::Keyword(Keyword::Await))
+                if cursor.peek(skip + 1).map(|tok| &tok.kind)
+                    == Some(&TokenKind::Punctuator(Punctuator::Colon)) =>
+            {
+                skip += 2;
+            }
+            Some(TokenKind::Keyword(Keyword::For))
+            | Some(TokenKind::Keyword(Keyword::While))
+            | Some(TokenKind::Keyword(Keyword::Do)) => return true,
+            _ => return false,
+        }
+    }
+}
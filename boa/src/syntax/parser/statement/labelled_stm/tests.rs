@@ -0,0 +1,132 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            Block, Break, Continue, ForLoop, FunctionDecl, Identifier, Labelled, Node, WhileLoop,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks a labelled loop, with a labelled `continue` in its body.
+#[test]
+fn labelled_loop() {
+    check_parser(
+        "outer: while (true) continue outer;",
+        vec![Labelled::new(
+            "outer",
+            WhileLoop::new(Const::from(true), Continue::new("outer")),
+        )
+        .into()],
+    );
+}
+
+/// Checks a labelled block, with a labelled `break` in its body.
+#[test]
+fn labelled_block() {
+    check_parser(
+        "block: { break block; }",
+        vec![Labelled::new("block", Block::from(vec![Break::new("block").into()])).into()],
+    );
+}
+
+/// Checks nested labels.
+#[test]
+fn nested_labels() {
+    check_parser(
+        "a: b: c;",
+        vec![Labelled::new("a", Labelled::new("b", Identifier::from("c"))).into()],
+    );
+}
+
+/// Checks that `yield` can be used as a label outside of generators.
+#[test]
+fn yield_label() {
+    check_parser(
+        "yield: while (true) break yield;",
+        vec![Labelled::new(
+            "yield",
+            WhileLoop::new(Const::from(true), Break::new("yield")),
+        )
+        .into()],
+    );
+}
+
+/// Checks a labelled function declaration.
+#[test]
+fn labelled_function() {
+    check_parser(
+        "label: function f() {}",
+        vec![Labelled::new(
+            "label",
+            Node::from(FunctionDecl::new(Box::from("f"), vec![], vec![])),
+        )
+        .into()],
+    );
+}
+
+/// Checks that keywords can't be used as labels, and that lexical declarations can't be
+/// labelled.
+#[test]
+fn invalid_labels() {
+    check_invalid("if: while (true) break;");
+    check_invalid("label: let a = 1;");
+    check_invalid("label:");
+}
+
+/// Checks a labelled empty statement.
+#[test]
+fn labelled_empty_statement() {
+    check_parser("a: ;", vec![Labelled::new("a", Node::Empty).into()]);
+}
+
+/// Checks that `continue` can jump to any of the labels of an iteration statement.
+#[test]
+fn continue_nested_labels() {
+    check_parser(
+        "a: b: for (;;) continue a;",
+        vec![Labelled::new(
+            "a",
+            Labelled::new(
+                "b",
+                ForLoop::new(
+                    None,
+                    Node::from(Const::from(true)),
+                    None,
+                    Continue::new("a"),
+                ),
+            ),
+        )
+        .into()],
+    );
+}
+
+/// Checks that `break` and `continue` can only jump to the labels of the statements that
+/// enclose them, in the same function.
+#[test]
+fn undefined_labels() {
+    check_invalid("break nope;");
+    check_invalid("continue nope;");
+    check_invalid("while (true) { break nope; }");
+    check_invalid("a: { } while (true) { break a; }");
+    check_invalid("a: while (true) { function f() { break a; } }");
+    check_invalid("a: a: ;");
+}
+
+/// Checks that `continue` can only jump to the label of an iteration statement.
+#[test]
+fn continue_non_iteration_label() {
+    check_invalid("foo: { for (;;) { continue foo; } }");
+    check_invalid("foo: if (true) while (true) continue foo;");
+}
+
+/// Checks that `break` and `continue` without a label must be in an iteration statement, or in
+/// a `switch` statement for `break`.
+#[test]
+fn unlabelled_jumps() {
+    check_invalid("break;");
+    check_invalid("continue;");
+    check_invalid("a: { break; }");
+    check_invalid("switch (1) { case 1: continue; }");
+    check_invalid("while (true) { (function () { break; }); }");
+}
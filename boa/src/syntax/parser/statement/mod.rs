@@ -14,6 +14,7 @@ mod declaration;
 mod expression;
 mod if_stm;
 mod iteration;
+mod labelled_stm;
 mod return_stm;
 mod switch;
 mod throw;
@@ -24,16 +25,21 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::Declaration,
+    declaration::{is_async_function, Declaration},
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
+    labelled_stm::LabelledStatement,
     return_stm::ReturnStatement,
     switch::SwitchStatement,
     throw::ThrowStatement,
     try_stm::TryStatement,
+};
+pub(super) use self::{
+    declaration::{ClassDeclaration, HoistableDeclaration},
     variable::VariableStatement,
 };
+
 use super::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
 use crate::{
     syntax::ast::{node, Keyword, Node, Punctuator, TokenKind},
@@ -162,10 +168,27 @@ impl TokenParser for Statement {
                     .parse(cursor)
                     .map(Node::from)
             }
-            // TODO: https://tc39.es/ecma262/#prod-LabelledStatement
-            // TokenKind::Punctuator(Punctuator::Semicolon) => {
-            //     return Ok(Node::new(NodeBase::Nope, tok.pos))
-            // }
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(Keyword::Yield)
+            | TokenKind::Keyword(Keyword::Await)
+                if matches!(
+                    cursor.peek(1).map(|tok| &tok.kind),
+                    Some(TokenKind::Punctuator(Punctuator::Colon))
+                ) =>
+            {
+                LabelledStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
+            TokenKind::Keyword(Keyword::With) if cursor.strict_mode() => Err(ParseError::general(
+                "with statement not allowed in strict mode",
+                tok.span().start(),
+            )),
+            // https://tc39.es/ecma262/#prod-EmptyStatement
+            TokenKind::Punctuator(Punctuator::Semicolon) => {
+                let _ = cursor.next();
+                Ok(Node::Empty)
+            }
             _ => ExpressionStatement::new(self.allow_yield, self.allow_await).parse(cursor),
         }
     }
@@ -244,7 +267,7 @@ impl StatementList {
 
         items.sort_by(Node::hoistable_order);
 
-        Ok(items.into())
+        Ok(node::StatementList::new(items, cursor.strict_mode()))
     }
 }
 
@@ -285,7 +308,7 @@ impl TokenParser for StatementList {
 
         items.sort_by(Node::hoistable_order);
 
-        Ok(items.into())
+        Ok(node::StatementList::new(items, cursor.strict_mode()))
     }
 }
 
@@ -300,7 +323,7 @@ impl TokenParser for StatementList {
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 /// [spec]: https://tc39.es/ecma262/#prod-StatementListItem
 #[derive(Debug, Clone, Copy)]
-struct StatementListItem {
+pub(super) struct StatementListItem {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
@@ -308,7 +331,7 @@ struct StatementListItem {
 
 impl StatementListItem {
     /// Creates a new `StatementListItem` parser.
-    fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R) -> Self
+    pub(super) fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -331,10 +354,14 @@ impl TokenParser for StatementListItem {
 
         match tok.kind {
             TokenKind::Keyword(Keyword::Function)
+            | TokenKind::Keyword(Keyword::Class)
             | TokenKind::Keyword(Keyword::Const)
             | TokenKind::Keyword(Keyword::Let) => {
                 Declaration::new(self.allow_yield, self.allow_await).parse(cursor)
             }
+            TokenKind::Identifier(_) if is_async_function(cursor) => {
+                Declaration::new(self.allow_yield, self.allow_await).parse(cursor)
+            }
             _ => {
                 Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)
             }
@@ -383,13 +410,30 @@ impl TokenParser for BindingIdentifier {
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("BindingIdentifier", "Parsing");
-        // TODO: strict mode.
 
         let next_token = cursor.next().ok_or(ParseError::AbruptEnd)?;
 
         match next_token.kind {
+            TokenKind::Identifier(ref s) if cursor.strict_mode() && is_strict_reserved_word(s) => {
+                Err(ParseError::unexpected(
+                    next_token.clone(),
+                    "unexpected reserved word in strict mode",
+                ))
+            }
+            TokenKind::Identifier(ref s)
+                if cursor.strict_mode() && (s.as_ref() == "eval" || s.as_ref() == "arguments") =>
+            {
+                Err(ParseError::unexpected(
+                    next_token.clone(),
+                    "cannot bind 'eval' or 'arguments' in strict mode",
+                ))
+            }
             TokenKind::Identifier(ref s) => Ok(s.clone()),
-            TokenKind::Keyword(k @ Keyword::Yield) if !self.allow_yield.0 => Ok(k.as_str().into()),
+            TokenKind::Keyword(k @ Keyword::Yield)
+                if !self.allow_yield.0 && !cursor.strict_mode() =>
+            {
+                Ok(k.as_str().into())
+            }
             TokenKind::Keyword(k @ Keyword::Await) if !self.allow_await.0 => Ok(k.as_str().into()),
             _ => Err(ParseError::expected(
                 vec![TokenKind::identifier("identifier")],
@@ -399,3 +443,19 @@ impl TokenParser for BindingIdentifier {
         }
     }
 }
+
+/// Checks if an identifier is a reserved word in strict mode code.
+///
+/// `yield` and `let` are also reserved in strict mode code, but they are always lexed as
+/// keywords.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-keywords-and-reserved-words
+pub(in crate::syntax::parser) fn is_strict_reserved_word(identifier: &str) -> bool {
+    matches!(
+        identifier,
+        "implements" | "interface" | "package" | "private" | "protected" | "public" | "static"
+    )
+}
@@ -62,8 +62,9 @@ impl TokenParser for SwitchStatement {
 
         cursor.expect(Punctuator::CloseParen, "switch statement")?;
 
-        let (cases, default) =
-            CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let (cases, default) = cursor.with_switch(|cursor| {
+            CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)
+        })?;
 
         Ok(Switch::new(condition, cases, default))
     }
@@ -132,6 +133,7 @@ impl TokenParser for CaseBlock {
                         Token {
                             kind: TokenKind::Keyword(Keyword::Default),
                             span: s,
+                            ..
                         },
                     context: _,
                 }) => {
@@ -160,7 +162,7 @@ impl TokenParser for CaseBlock {
                     found:
                         Token {
                             kind: TokenKind::Punctuator(Punctuator::CloseBlock),
-                            span: _,
+                            ..
                         },
                     context: _,
                 }) => {
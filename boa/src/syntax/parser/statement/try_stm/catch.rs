@@ -1,12 +1,12 @@
 use crate::{
     syntax::{
         ast::{
-            node::{self, Identifier},
+            node::{self, BindingTarget},
             Keyword, Punctuator,
         },
         parser::{
-            statement::{block::Block, BindingIdentifier},
-            AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
+            pattern, statement::block::Block, AllowAwait, AllowReturn, AllowYield, Cursor,
+            ParseError, TokenParser,
         },
     },
     BoaProfiler,
@@ -59,7 +59,7 @@ impl TokenParser for Catch {
         };
 
         // Catch block
-        Ok(node::Catch::new::<_, Identifier, _>(
+        Ok(node::Catch::new::<_, BindingTarget, _>(
             catch_param,
             Block::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?,
         ))
@@ -95,12 +95,9 @@ impl CatchParameter {
 }
 
 impl TokenParser for CatchParameter {
-    type Output = Identifier;
+    type Output = BindingTarget;
 
-    fn parse(self, cursor: &mut Cursor<'_>) -> Result<Identifier, ParseError> {
-        // TODO: should accept BindingPattern
-        BindingIdentifier::new(self.allow_yield, self.allow_await)
-            .parse(cursor)
-            .map(Identifier::from)
+    fn parse(self, cursor: &mut Cursor<'_>) -> Result<BindingTarget, ParseError> {
+        pattern::BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)
     }
 }
@@ -2,11 +2,11 @@
 use crate::{
     syntax::{
         ast::{
-            node::{VarDecl, VarDeclList},
+            node::{self, VarDecl, VarDeclList},
             Keyword, Punctuator, TokenKind,
         },
         parser::{
-            expression::Initializer, statement::BindingIdentifier, AllowAwait, AllowIn, AllowYield,
+            expression::Initializer, pattern::BindingTarget, AllowAwait, AllowIn, AllowYield,
             Cursor, ParseError, TokenParser,
         },
     },
@@ -24,14 +24,14 @@ use crate::{
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/var
 /// [spec]: https://tc39.es/ecma262/#prod-VariableStatement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct VariableStatement {
+pub(in crate::syntax::parser) struct VariableStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl VariableStatement {
     /// Creates a new `VariableStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -161,13 +161,25 @@ impl TokenParser for VariableDeclaration {
     type Output = VarDecl;
 
     fn parse(self, cursor: &mut Cursor<'_>) -> Result<Self::Output, ParseError> {
-        // TODO: BindingPattern
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
-        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-
-        let ident =
-            Initializer::new(self.allow_in, self.allow_yield, self.allow_await).try_parse(cursor);
+        let init = match cursor.peek(0) {
+            Some(tk) if tk.kind == Punctuator::Assign.into() => Some(
+                Initializer::new(self.allow_in, self.allow_yield, self.allow_await)
+                    .parse(cursor)?,
+            ),
+            _ => None,
+        };
+
+        // Patterns need a value to take apart.
+        if let (node::BindingTarget::Pattern(_), None) = (&target, &init) {
+            return Err(ParseError::expected(
+                vec![TokenKind::Punctuator(Punctuator::Assign)],
+                cursor.next().ok_or(ParseError::AbruptEnd)?.clone(),
+                "variable declaration",
+            ));
+        }
 
-        Ok(VarDecl::new(name, ident))
+        Ok(VarDecl::new(target, init))
     }
 }
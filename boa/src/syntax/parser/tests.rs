@@ -104,3 +104,90 @@ fn hoisting() {
         ],
     );
 }
+
+/// Parses the given JavaScript string, which must be valid.
+fn parse(js: &str) -> StatementList {
+    let mut lexer = Lexer::new(js);
+    lexer.lex().expect("failed to lex");
+
+    Parser::new(&lexer.tokens)
+        .parse_all()
+        .expect("failed to parse")
+}
+
+#[test]
+fn check_use_strict_directive() {
+    assert!(parse("\"use strict\"; a = 1").is_strict());
+    assert!(parse("'use strict'\na = 1").is_strict());
+    assert!(parse("\"a\"; \"use strict\";").is_strict());
+    assert!(!parse("a; \"use strict\";").is_strict());
+    assert!(!parse("\"use strict\" + a;").is_strict());
+    assert!(!parse("\"use strict\"\n.length").is_strict());
+}
+
+#[test]
+fn check_function_use_strict_directive() {
+    let script = parse("function f() { \"use strict\"; }");
+    assert!(!script.is_strict());
+    match script.statements()[0] {
+        Node::FunctionDecl(ref function) => assert!(function.body().is_strict()),
+        ref node => panic!("expected a function declaration, got {}", node),
+    }
+
+    // Function declarations are hoisted before the directive.
+    let script = parse("\"use strict\"; function f() {}");
+    match script.statements()[0] {
+        Node::FunctionDecl(ref function) => assert!(function.body().is_strict()),
+        ref node => panic!("expected a function declaration, got {}", node),
+    }
+}
+
+#[test]
+fn check_strict_mode_errors() {
+    check_invalid("\"use strict\"; with (a) {}");
+    check_invalid("\"use strict\"; 017");
+    check_invalid("\"use strict\"; '\\017'");
+    check_invalid("'\\017'; \"use strict\";");
+    check_invalid("\"use strict\"; var eval = 1;");
+    check_invalid("\"use strict\"; var public;");
+    check_invalid("\"use strict\"; interface;");
+    check_invalid("\"use strict\"; function f(a, a) {}");
+    check_invalid("function f(a, a) { \"use strict\"; }");
+    check_invalid("function f(arguments) { \"use strict\"; }");
+    check_invalid("(a, [a]) => { \"use strict\"; }");
+    check_invalid("class A { m() { return 010; } }");
+    check_invalid("function f(a = 1) { \"use strict\"; }");
+    check_invalid("function f(...a) { \"use strict\"; }");
+    check_invalid("function f({ a }) { \"use strict\"; }");
+    check_invalid("(a = 1) => { \"use strict\"; }");
+    check_invalid("({ m(a = 1) { \"use strict\"; } })");
+    check_invalid("\"use strict\"; function f(a = 1) { \"use strict\"; }");
+    check_invalid("\"use strict\"; var x; delete x;");
+    check_invalid("\"use strict\"; delete (x);");
+    check_invalid("function f() { \"use strict\"; delete x; }");
+
+    parse("function f(a, a) {}");
+    parse("var public = 010;");
+    parse("\"use strict\"; function f(a = 1) {}");
+    parse("function f(a = 1) { \"use\" + \" strict\"; }");
+    parse("var x; delete x;");
+    parse("\"use strict\"; delete x.y;");
+}
+
+#[test]
+fn check_strict_mode_error_messages() {
+    fn parse_error(js: &str) -> String {
+        let mut lexer = Lexer::new(js);
+        lexer.lex().expect("failed to lex");
+
+        Parser::new(&lexer.tokens)
+            .parse_all()
+            .expect_err("parsing should fail")
+            .to_string()
+    }
+
+    assert!(parse_error("\"use strict\"; var s = \"\\01\";").contains("octal escape"));
+    assert!(parse_error("\"use strict\"; let s = 01;").contains("octal"));
+    assert!(parse_error("\"use strict\"; var x; delete x;").contains("delete"));
+    assert!(parse_error("function f(a = 1) { \"use strict\"; }").contains("use strict"));
+}
@@ -0,0 +1,451 @@
+//! CommonJS modules, loaded with `require()` the way Node.js does.
+//!
+//! Each module is run in a function wrapper, that gives it its own `exports`, `require`, `module`,
+//! `__filename` and `__dirname` bindings. Modules are cached by their canonical path, and are
+//! added to the cache before they run, so a cyclic `require()` gets the exports of the module as
+//! they are when the cycle is closed, like in Node.js.
+//!
+//! More information:
+//!  - [Node.js documentation][node]
+//!
+//! [node]: https://nodejs.org/api/modules.html
+
+use boa::{
+    builtins::{
+        function::make_capturing_fn,
+        value::{ResultValue, Value},
+    },
+    environment::lexical_environment::LexicalEnvironment,
+    exec::{Executable, Interpreter},
+    syntax::{ast::node::StatementList, lexer::Lexer, parser::Parser},
+};
+use std::{
+    env,
+    ffi::OsStr,
+    fs::{canonicalize, read_to_string},
+    mem,
+    path::{Path, PathBuf},
+};
+
+/// The extensions tried, in order, when a required path isn't a file.
+const EXTENSIONS: [&str; 2] = [".js", ".json"];
+
+/// The parameters of the function wrapping the code of a module.
+const WRAPPER_PARAMETERS: &str = "exports, require, module, __filename, __dirname";
+
+/// The CommonJS module system of the CLI.
+#[derive(Debug)]
+pub(crate) struct CommonJs {
+    /// The loaded modules, by filename, shared by the `require.cache` of all the modules.
+    cache: Value,
+}
+
+impl CommonJs {
+    /// Creates a module system with an empty cache.
+    pub(crate) fn new() -> Self {
+        Self {
+            cache: Value::new_object(None),
+        }
+    }
+
+    /// Runs the given file as a CommonJS module, and returns its exports.
+    ///
+    /// The file is resolved relative to the current directory, so it can be a directory with an
+    /// `index.js` file or a path missing its extension.
+    pub(crate) fn run_main(&self, engine: &mut Interpreter, file: &Path) -> ResultValue {
+        let filename = env::current_dir()
+            .ok()
+            .and_then(|dir| resolve_path(&dir.join(file)));
+        match filename {
+            Some(filename) => load(engine, &filename, &self.cache),
+            None => Err(module_not_found(engine, &file.to_string_lossy())),
+        }
+    }
+}
+
+/// `require(id)`
+///
+/// Loads the module `id`, resolved relative to the directory of the module calling it, and returns
+/// its exports.
+fn require(_: &Value, args: &[Value], captures: &Value, engine: &mut Interpreter) -> ResultValue {
+    let filename = resolve_request(args, captures, engine)?;
+    load(engine, &filename, &captures.get_field("cache"))
+}
+
+/// `require.resolve(request)`
+///
+/// Returns the filename of the module that `require(request)` would load.
+fn require_resolve(
+    _: &Value,
+    args: &[Value],
+    captures: &Value,
+    engine: &mut Interpreter,
+) -> ResultValue {
+    let filename = resolve_request(args, captures, engine)?;
+    Ok(Value::from(filename.to_string_lossy().as_ref()))
+}
+
+/// Resolves the module requested by the first argument, from the module captured by a `require`
+/// function.
+fn resolve_request(
+    args: &[Value],
+    captures: &Value,
+    engine: &mut Interpreter,
+) -> Result<PathBuf, Value> {
+    let request = match args.first() {
+        Some(Value::String(ref request)) => request.to_string(),
+        _ => {
+            return Err(engine.construct_type_error("the module id must be a string"));
+        }
+    };
+
+    let dirname = engine.to_string(&captures.get_field("module").get_field("path"))?;
+    resolve(Path::new(dirname.as_str()), &request).ok_or_else(|| module_not_found(engine, &request))
+}
+
+/// Loads the module `filename`, or gets it from the cache, and returns its exports.
+fn load(engine: &mut Interpreter, filename: &Path, cache: &Value) -> ResultValue {
+    let key = filename.to_string_lossy();
+    let cached = cache.get_field(key.as_ref());
+    if cached.is_object() {
+        return Ok(cached.get_field("exports"));
+    }
+
+    let dirname = filename.parent().unwrap_or(filename).to_string_lossy();
+    let global = engine.global().clone();
+    let module = Value::new_object(Some(&global));
+    module.set_field("id", key.as_ref());
+    module.set_field("filename", key.as_ref());
+    module.set_field("path", dirname.as_ref());
+    module.set_field("exports", Value::new_object(Some(&global)));
+    module.set_field("loaded", false);
+
+    // The module is cached before it runs, so the modules it requires can require it back.
+    cache.set_field(key.as_ref(), module.clone());
+
+    let result = if filename.extension() == Some(OsStr::new("json")) {
+        load_json(engine, filename, &module)
+    } else {
+        load_script(engine, filename, &module, cache)
+    };
+
+    match result {
+        Ok(()) => {
+            module.set_field("loaded", true);
+            Ok(module.get_field("exports"))
+        }
+        Err(err) => {
+            cache.remove_property(key.as_ref());
+            Err(err)
+        }
+    }
+}
+
+/// Runs the JavaScript file of `module` in its function wrapper.
+fn load_script(
+    engine: &mut Interpreter,
+    filename: &Path,
+    module: &Value,
+    cache: &Value,
+) -> Result<(), Value> {
+    let source = read_module(engine, filename)?;
+    let wrapper = format!("(function ({}) {{{}\n}})", WRAPPER_PARAMETERS, source);
+    let wrapper = parse(&wrapper).map_err(|err| {
+        engine.construct_syntax_error(format!("{}: {}", filename.to_string_lossy(), err))
+    })?;
+    let function = run_in_global_scope(engine, &wrapper)?;
+
+    let captures = Value::new_object(None);
+    captures.set_field("module", module.clone());
+    captures.set_field("cache", cache.clone());
+    let require = make_capturing_fn(require, 1, captures.clone(), engine);
+    require.set_field(
+        "resolve",
+        make_capturing_fn(require_resolve, 1, captures, engine),
+    );
+    require.set_field("cache", cache.clone());
+
+    let exports = module.get_field("exports");
+    let args = [
+        exports.clone(),
+        require,
+        module.clone(),
+        module.get_field("filename"),
+        module.get_field("path"),
+    ];
+    engine.call(&function, &exports, &args)?;
+
+    Ok(())
+}
+
+/// Parses the JSON file of `module` as its exports.
+fn load_json(engine: &mut Interpreter, filename: &Path, module: &Value) -> Result<(), Value> {
+    let source = read_module(engine, filename)?;
+    let json = serde_json::from_str(&source).map_err(|err| {
+        engine.construct_syntax_error(format!("{}: {}", filename.to_string_lossy(), err))
+    })?;
+    let exports = Value::from_json(json, engine);
+    module.set_field("exports", exports);
+
+    Ok(())
+}
+
+/// Reads the source code of a module.
+fn read_module(engine: &mut Interpreter, filename: &Path) -> Result<String, Value> {
+    read_to_string(filename).map_err(|err| {
+        engine.construct_error(format!(
+            "cannot read module '{}': {}",
+            filename.to_string_lossy(),
+            err
+        ))
+    })
+}
+
+/// Parses the source code of a script.
+fn parse(source: &str) -> Result<StatementList, String> {
+    let mut lexer = Lexer::new(source);
+    lexer.lex().map_err(|err| err.to_string())?;
+    Parser::new(&lexer.tokens)
+        .parse_all()
+        .map_err(|err| err.to_string())
+}
+
+/// Runs a script in the global scope, even when `require()` is called from a function, so modules
+/// don't see the bindings of the code requiring them.
+fn run_in_global_scope(engine: &mut Interpreter, script: &StatementList) -> ResultValue {
+    let global_env = engine
+        .realm
+        .environment
+        .environments()
+        .last()
+        .expect("there is always a global environment")
+        .clone();
+    let caller_env = mem::replace(
+        &mut engine.realm.environment,
+        LexicalEnvironment::from_scope(global_env),
+    );
+
+    let result = script.run(engine);
+
+    engine.realm.environment = caller_env;
+    result
+}
+
+/// Creates the error thrown when a module can't be found.
+fn module_not_found(engine: &mut Interpreter, request: &str) -> Value {
+    let err = engine.construct_error(format!("Cannot find module '{}'", request));
+    err.set_field("code", "MODULE_NOT_FOUND");
+    err
+}
+
+/// Resolves a request for a module, made from the module in the directory `dir`.
+///
+/// Only relative and absolute paths are supported, there is no lookup in `node_modules`.
+fn resolve(dir: &Path, request: &str) -> Option<PathBuf> {
+    let is_relative = request == "."
+        || request == ".."
+        || request.starts_with("./")
+        || request.starts_with("../");
+    if is_relative || Path::new(request).is_absolute() {
+        resolve_path(&dir.join(request))
+    } else {
+        None
+    }
+}
+
+/// Resolves a path to the canonical path of the module file it refers to.
+///
+/// The path can be the module file, the module file without its extension, or a directory with
+/// an `index` module file.
+fn resolve_path(path: &Path) -> Option<PathBuf> {
+    let file = if path.is_file() {
+        Some(path.to_path_buf())
+    } else {
+        with_extensions(path).or_else(|| with_extensions(&path.join("index")))
+    };
+
+    file.and_then(|file| canonicalize(file).ok())
+}
+
+/// Finds the file named `path` with one of the module extensions.
+fn with_extensions(path: &Path) -> Option<PathBuf> {
+    EXTENSIONS.iter().find_map(|extension| {
+        let mut file = path.as_os_str().to_owned();
+        file.push(extension);
+        let file = PathBuf::from(file);
+        if file.is_file() {
+            Some(file)
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommonJs;
+    use boa::{
+        builtins::value::{ResultValue, Value},
+        exec::Interpreter,
+        realm::Realm,
+    };
+    use std::{env, fs, path::PathBuf, process};
+
+    /// A temporary directory of module files, removed when the fixture is dropped.
+    struct Fixture {
+        dir: PathBuf,
+    }
+
+    impl Fixture {
+        /// Creates an empty directory, unique to the test and the process.
+        fn new(test: &str) -> Self {
+            let dir = env::temp_dir().join(format!("boa_commonjs_{}_{}", test, process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).expect("failed to create the fixture directory");
+            Self { dir }
+        }
+
+        /// Adds a file, creating the directories it is in.
+        fn with(self, path: &str, source: &str) -> Self {
+            let file = self.dir.join(path);
+            if let Some(parent) = file.parent() {
+                fs::create_dir_all(parent).expect("failed to create the module directory");
+            }
+            fs::write(file, source).expect("failed to write the module");
+            self
+        }
+
+        /// Runs the module `main` with a new interpreter, and returns its exports.
+        fn run(&self, main: &str) -> ResultValue {
+            let mut engine = Interpreter::new(Realm::create());
+            CommonJs::new().run_main(&mut engine, &self.dir.join(main))
+        }
+
+        /// Gets the canonical path of a file of the fixture.
+        fn path(&self, path: &str) -> String {
+            fs::canonicalize(self.dir.join(path))
+                .expect("the file exists")
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    /// Gets the string an exported value converts to.
+    fn exported(exports: &Value, name: &str) -> String {
+        exports.get_field(name).to_string()
+    }
+
+    #[test]
+    fn resolution() {
+        let fixture = Fixture::new("resolution")
+            .with(
+                "main.js",
+                r#"
+                exports.relative = require("./lib/a").name;
+                exports.parent = require("./lib/b").fromParent;
+                exports.index = require("./dir").name;
+                exports.json = require("./data.json").list.join();
+                exports.jsonExtension = require("./data") === require("./data.json");
+                exports.filename = require.resolve("./lib/a");
+                "#,
+            )
+            .with("lib/a.js", r#"exports.name = "a";"#)
+            .with(
+                "lib/b.js",
+                r#"exports.fromParent = require("../dir/index.js").name;"#,
+            )
+            .with("dir/index.js", r#"exports.name = "index";"#)
+            .with("data.json", r#"{ "list": [1, 2] }"#);
+
+        let exports = fixture.run("main").expect("failed to run the module");
+        assert_eq!(exported(&exports, "relative"), "\"a\"");
+        assert_eq!(exported(&exports, "parent"), "\"index\"");
+        assert_eq!(exported(&exports, "index"), "\"index\"");
+        assert_eq!(exported(&exports, "json"), "\"1,2\"");
+        assert_eq!(exported(&exports, "jsonExtension"), "true");
+        assert_eq!(
+            exported(&exports, "filename"),
+            format!("\"{}\"", fixture.path("lib/a.js"))
+        );
+    }
+
+    #[test]
+    fn cache() {
+        let fixture = Fixture::new("cache")
+            .with(
+                "main.js",
+                r#"
+                const counter = require("./counter");
+                counter.count += 1;
+                exports.same = require("./counter.js") === counter;
+                exports.count = require("./counter").count;
+                exports.cached = require.cache[require.resolve("./counter")].exports === counter;
+                exports.loaded = require.cache[require.resolve("./counter")].loaded;
+                "#,
+            )
+            .with("counter.js", "exports.count = 0;");
+
+        let exports = fixture.run("main.js").expect("failed to run the module");
+        assert_eq!(exported(&exports, "same"), "true");
+        assert_eq!(exported(&exports, "count"), "1");
+        assert_eq!(exported(&exports, "cached"), "true");
+        assert_eq!(exported(&exports, "loaded"), "true");
+    }
+
+    #[test]
+    fn cycles() {
+        let fixture = Fixture::new("cycles")
+            .with(
+                "a.js",
+                r#"
+                exports.early = 1;
+                exports.seenByB = require("./b").seenA;
+                exports.late = 2;
+                "#,
+            )
+            .with(
+                "b.js",
+                r#"
+                const a = require("./a");
+                exports.seenA = [a.early, "late" in a].join();
+                "#,
+            );
+
+        let exports = fixture.run("a.js").expect("failed to run the module");
+        assert_eq!(exported(&exports, "seenByB"), "\"1,false\"");
+        assert_eq!(exported(&exports, "late"), "2");
+    }
+
+    #[test]
+    fn module_not_found() {
+        let fixture = Fixture::new("not_found").with(
+            "main.js",
+            r#"
+            exports.codes = [];
+            for (const request of ["./missing", "./dir", "package"]) {
+                try {
+                    require(request);
+                } catch (err) {
+                    exports.codes.push(err.code);
+                }
+            }
+            "#,
+        );
+        fs::create_dir(fixture.dir.join("dir")).expect("failed to create the directory");
+
+        let exports = fixture.run("main.js").expect("failed to run the module");
+        assert_eq!(
+            exported(&exports, "codes").as_str(),
+            "[ \"MODULE_NOT_FOUND\", \"MODULE_NOT_FOUND\", \"MODULE_NOT_FOUND\" ]"
+        );
+
+        let err = fixture.run("missing.js").expect_err("the module was found");
+        assert_eq!(err.get_field("code").to_string(), "\"MODULE_NOT_FOUND\"");
+    }
+}
@@ -0,0 +1,30 @@
+use boa::exec::ModuleLoader;
+use std::{
+    env,
+    fs::{canonicalize, read_to_string},
+    path::Path,
+};
+
+/// A module loader that imports modules from the filesystem.
+///
+/// Module specifiers are paths, relative to the directory of the importing module, or to the
+/// current directory for the modules imported from the command line. Modules are identified by
+/// their canonical path.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct FileSystemLoader;
+
+impl ModuleLoader for FileSystemLoader {
+    fn resolve(&self, specifier: &str, referrer: Option<&str>) -> Result<String, String> {
+        let base = match referrer.and_then(|referrer| Path::new(referrer).parent()) {
+            Some(dir) => dir.to_path_buf(),
+            None => env::current_dir().map_err(|e| e.to_string())?,
+        };
+
+        let path = canonicalize(base.join(specifier)).map_err(|e| e.to_string())?;
+        Ok(path.to_string_lossy().into_owned())
+    }
+
+    fn fetch(&self, key: &str) -> Result<String, String> {
+        read_to_string(key).map_err(|e| e.to_string())
+    }
+}
@@ -26,6 +26,7 @@
 )]
 
 use boa::{
+    builtins::{promise::PromiseRejectionOperation, value::Value},
     exec::Interpreter,
     forward_val,
     realm::Realm,
@@ -33,10 +34,12 @@ use boa::{
 };
 use colored::*;
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
-use std::{fs::read_to_string, path::PathBuf};
+use std::{cell::RefCell, fs::read_to_string, path::PathBuf, rc::Rc};
 use structopt::{clap::arg_enum, StructOpt};
 
+mod commonjs;
 mod helper;
+mod loader;
 
 #[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
 #[cfg_attr(
@@ -85,6 +88,15 @@ struct Opt {
     /// Use vi mode in the REPL
     #[structopt(long = "vi")]
     vi_mode: bool,
+
+    /// Evaluate the file(s) as ES modules, which can import other modules from the filesystem.
+    #[structopt(long, short = "m")]
+    module: bool,
+
+    /// Evaluate the file(s) as CommonJS modules, which can `require()` other modules from the
+    /// filesystem.
+    #[structopt(long, short = "c", conflicts_with = "module")]
+    commonjs: bool,
 }
 
 impl Opt {
@@ -178,6 +190,25 @@ fn dump(src: &str, args: &Opt) -> Result<(), String> {
     Ok(())
 }
 
+/// The promises that were rejected without a handler, along with their rejection reason.
+type UnhandledRejections = Rc<RefCell<Vec<(Value, Value)>>>;
+
+/// Runs the pending jobs of the interpreter, like promise reactions, and reports the errors thrown
+/// by jobs and the promises that are still rejected without a handler afterwards.
+fn run_jobs(engine: &mut Interpreter, unhandled_rejections: &UnhandledRejections) {
+    while let Err(e) = engine.run_jobs() {
+        eprintln!("{}: {}", "Uncaught".red(), e.to_string().red());
+    }
+
+    for (_, reason) in unhandled_rejections.borrow_mut().drain(..) {
+        eprintln!(
+            "{}: {}",
+            "Uncaught (in promise)".red(),
+            reason.to_string().red()
+        );
+    }
+}
+
 pub fn main() -> Result<(), std::io::Error> {
     let args = Opt::from_args();
 
@@ -185,18 +216,45 @@ pub fn main() -> Result<(), std::io::Error> {
 
     let mut engine = Interpreter::new(realm);
 
-    for file in &args.files {
-        let buffer = read_to_string(file)?;
+    let unhandled_rejections = UnhandledRejections::default();
+    let tracked_rejections = unhandled_rejections.clone();
+    engine.set_promise_rejection_tracker(move |promise, reason, operation, _| {
+        let mut rejections = tracked_rejections.borrow_mut();
+        match operation {
+            PromiseRejectionOperation::Reject => rejections.push((promise.clone(), reason.clone())),
+            PromiseRejectionOperation::Handle => {
+                rejections.retain(|(rejected, _)| !rejected.strict_equals(promise))
+            }
+        }
+    });
+
+    engine.set_module_loader(loader::FileSystemLoader);
 
+    let commonjs = commonjs::CommonJs::new();
+
+    for file in &args.files {
         if args.has_dump_flag() {
+            let buffer = read_to_string(file)?;
             if let Err(e) = dump(&buffer, &args) {
                 eprintln!("{}", e);
             }
+        } else if args.module {
+            if let Err(v) = engine.import_module(&file.to_string_lossy()) {
+                eprintln!("{}: {}", "Uncaught".red(), v.to_string().red());
+            }
+            run_jobs(&mut engine, &unhandled_rejections);
+        } else if args.commonjs {
+            if let Err(v) = commonjs.run_main(&mut engine, file) {
+                eprintln!("{}: {}", "Uncaught".red(), v.to_string().red());
+            }
+            run_jobs(&mut engine, &unhandled_rejections);
         } else {
+            let buffer = read_to_string(file)?;
             match forward_val(&mut engine, &buffer) {
                 Ok(v) => print!("{}", v),
                 Err(v) => eprint!("{}", v),
             }
+            run_jobs(&mut engine, &unhandled_rejections);
         }
     }
 
@@ -233,6 +291,7 @@ pub fn main() -> Result<(), std::io::Error> {
                             Ok(v) => println!("{}", v),
                             Err(v) => eprintln!("{}: {}", "Uncaught".red(), v.to_string().red()),
                         }
+                        run_jobs(&mut engine, &unhandled_rejections);
                     }
                 }
 
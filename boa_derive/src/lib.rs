@@ -0,0 +1,303 @@
+//! Derive macros for the `TryFromJs` and `IntoJs` conversion traits of Boa.
+//!
+//! Structs with named fields are converted from and to JavaScript objects, tuple structs from and
+//! to arrays, and newtype structs like the value they wrap. Unit variants of enums are converted
+//! from and to their name as a string, and the other variants to an object with a single property,
+//! named after the variant, holding the fields of the variant.
+
+#![deny(
+    unused_qualifications,
+    clippy::all,
+    unused_import_braces,
+    unused_lifetimes,
+    unreachable_pub,
+    trivial_numeric_casts,
+    missing_debug_implementations,
+    missing_copy_implementations,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style
+)]
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, parse_macro_input, parse_quote, Data, DataEnum, DataStruct, DeriveInput, Error,
+    Fields, Generics, Ident, Index,
+};
+
+/// Derives `TryFromJs`, to convert a JavaScript value to the type.
+#[proc_macro_derive(TryFromJs)]
+pub fn derive_try_from_js(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let body = match input.data {
+        Data::Struct(ref data) => try_from_struct(name, data),
+        Data::Enum(ref data) => try_from_enum(name, data),
+        Data::Union(_) => {
+            return Error::new(
+                Span::call_site(),
+                "unions can't be converted from JavaScript",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let generics = with_bound(&input.generics, quote!(::boa::convert::TryFromJs));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let expanded = quote! {
+        impl #impl_generics ::boa::convert::TryFromJs for #name #ty_generics #where_clause {
+            fn try_from_js(
+                value: &::boa::builtins::value::Value,
+                interpreter: &mut ::boa::exec::Interpreter,
+            ) -> ::std::result::Result<Self, ::boa::builtins::value::Value> {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}
+
+/// Derives `IntoJs`, to convert the type to a JavaScript value.
+#[proc_macro_derive(IntoJs)]
+pub fn derive_into_js(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let body = match input.data {
+        Data::Struct(ref data) => into_struct(data),
+        Data::Enum(ref data) => into_enum(data),
+        Data::Union(_) => {
+            return Error::new(Span::call_site(), "unions can't be converted to JavaScript")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let generics = with_bound(&input.generics, quote!(::boa::convert::IntoJs));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let expanded = quote! {
+        impl #impl_generics ::boa::convert::IntoJs for #name #ty_generics #where_clause {
+            fn into_js(
+                self,
+                interpreter: &mut ::boa::exec::Interpreter,
+            ) -> ::boa::builtins::value::Value {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}
+
+/// Adds the conversion trait `bound` to all the type parameters.
+fn with_bound(generics: &Generics, bound: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    let params: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: #bound));
+    }
+    generics
+}
+
+/// Converts the JavaScript `value` to the struct `name`.
+fn try_from_struct(name: &Ident, data: &DataStruct) -> TokenStream {
+    let type_name = name.unraw().to_string();
+    let constructor = try_from_fields(&type_name, quote!(Self), &data.fields, quote!(value));
+    match data.fields {
+        Fields::Unit => quote! {
+            if value.is_null_or_undefined() {
+                Ok(Self)
+            } else {
+                Err(::boa::convert::__private::unexpected(value, #type_name, interpreter))
+            }
+        },
+        _ => quote!(Ok(#constructor)),
+    }
+}
+
+/// Converts the JavaScript `value` to the enum `name`.
+fn try_from_enum(name: &Ident, data: &DataEnum) -> TokenStream {
+    let type_name = name.unraw().to_string();
+    let variants = data.variants.iter().map(|variant| {
+        let variant_name = variant.ident.unraw().to_string();
+        let has_fields = !matches!(variant.fields, Fields::Unit);
+        quote!((#variant_name, #has_fields))
+    });
+    let arms = data.variants.iter().enumerate().map(|(index, variant)| {
+        let ident = &variant.ident;
+        let constructor = try_from_fields(
+            &format!("{}::{}", name.unraw(), ident.unraw()),
+            quote!(Self::#ident),
+            &variant.fields,
+            quote!(&payload),
+        );
+        quote!(#index => Ok(#constructor),)
+    });
+
+    quote! {
+        let (index, payload) = ::boa::convert::__private::variant(
+            value,
+            &[#(#variants),*],
+            #type_name,
+            interpreter,
+        )?;
+        match index {
+            #(#arms)*
+            _ => unreachable!("the variant index is checked"),
+        }
+    }
+}
+
+/// Converts the JavaScript value `source` to the fields of the struct or variant `path`.
+fn try_from_fields(
+    type_name: &str,
+    path: TokenStream,
+    fields: &Fields,
+    source: TokenStream,
+) -> TokenStream {
+    match *fields {
+        Fields::Named(ref fields) => {
+            let fields = fields.named.iter().map(|field| {
+                let ident = field.ident.as_ref().expect("named fields have a name");
+                let field_name = ident.unraw().to_string();
+                quote! {
+                    #ident: ::boa::convert::__private::field(
+                        #source,
+                        #field_name,
+                        #type_name,
+                        interpreter,
+                    )?
+                }
+            });
+            quote! {{
+                ::boa::convert::__private::expect_object(#source, #type_name, interpreter)?;
+                #path { #(#fields),* }
+            }}
+        }
+        Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => quote! {
+            #path(::boa::convert::TryFromJs::try_from_js(#source, interpreter)?)
+        },
+        Fields::Unnamed(ref fields) => {
+            let len = fields.unnamed.len();
+            let elements = (0..len).map(
+                |index| quote!(::boa::convert::__private::element(#source, #index, interpreter)?),
+            );
+            quote! {{
+                ::boa::convert::__private::expect_array(#source, #len, #type_name, interpreter)?;
+                #path(#(#elements),*)
+            }}
+        }
+        Fields::Unit => path,
+    }
+}
+
+/// Converts the struct `self` to a JavaScript value.
+fn into_struct(data: &DataStruct) -> TokenStream {
+    match data.fields {
+        Fields::Named(ref fields) => {
+            let idents: Vec<&Ident> = fields
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().expect("named fields have a name"))
+                .collect();
+            let values: Vec<TokenStream> = idents.iter().map(|ident| quote!(self.#ident)).collect();
+            into_named(&idents, &values)
+        }
+        Fields::Unnamed(ref fields) => {
+            let indexes: Vec<Index> = (0..fields.unnamed.len()).map(Index::from).collect();
+            into_unnamed(&indexes, |index| quote!(self.#index))
+        }
+        Fields::Unit => quote!(::boa::builtins::value::Value::null()),
+    }
+}
+
+/// Converts the enum `self` to a JavaScript value.
+fn into_enum(data: &DataEnum) -> TokenStream {
+    let arms = data.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let variant_name = ident.unraw().to_string();
+        let (pattern, payload) = match variant.fields {
+            Fields::Named(ref fields) => {
+                let idents: Vec<&Ident> = fields
+                    .named
+                    .iter()
+                    .map(|field| field.ident.as_ref().expect("named fields have a name"))
+                    .collect();
+                let bindings: Vec<Ident> = idents
+                    .iter()
+                    .map(|field| format_ident!("__{}", field.unraw()))
+                    .collect();
+                let values: Vec<TokenStream> =
+                    bindings.iter().map(|binding| quote!(#binding)).collect();
+                let payload = into_named(&idents, &values);
+                (quote!(Self::#ident { #(#idents: #bindings),* }), payload)
+            }
+            Fields::Unnamed(ref fields) => {
+                let bindings: Vec<Ident> = (0..fields.unnamed.len())
+                    .map(|index| format_ident!("__field{}", index))
+                    .collect();
+                let payload = into_unnamed(&bindings, |binding| quote!(#binding));
+                (quote!(Self::#ident(#(#bindings),*)), payload)
+            }
+            Fields::Unit => {
+                return quote! {
+                    Self::#ident => ::boa::builtins::value::Value::from(#variant_name),
+                };
+            }
+        };
+        quote! {
+            #pattern => {
+                let payload = #payload;
+                let object = ::boa::convert::__private::object(interpreter);
+                object.set_field(#variant_name, payload);
+                object
+            }
+        }
+    });
+
+    quote! {
+        match self {
+            #(#arms)*
+        }
+    }
+}
+
+/// Converts named fields, with their values, to a JavaScript object.
+fn into_named(idents: &[&Ident], values: &[TokenStream]) -> TokenStream {
+    let fields = idents.iter().zip(values).map(|(ident, value)| {
+        let field_name = ident.unraw().to_string();
+        quote! {
+            object.set_field(#field_name, ::boa::convert::IntoJs::into_js(#value, interpreter));
+        }
+    });
+    quote! {{
+        let object = ::boa::convert::__private::object(interpreter);
+        #(#fields)*
+        object
+    }}
+}
+
+/// Converts unnamed fields to a JavaScript array, or to the value of the field if there is only
+/// one.
+fn into_unnamed<T, F>(fields: &[T], access: F) -> TokenStream
+where
+    F: Fn(&T) -> TokenStream,
+{
+    if let [field] = fields {
+        let field = access(field);
+        return quote!(::boa::convert::IntoJs::into_js(#field, interpreter));
+    }
+
+    let elements = fields.iter().map(|field| {
+        let field = access(field);
+        quote!(::boa::convert::IntoJs::into_js(#field, interpreter))
+    });
+    quote! {{
+        let elements = vec![#(#elements),*];
+        ::boa::convert::__private::array(&elements, interpreter)
+    }}
+}